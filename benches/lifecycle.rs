@@ -0,0 +1,73 @@
+// Benchmarks for worktree lifecycle operations across repo sizes
+//
+// Each operation runs against a synthetic `sized-W-C-F` repo (W worktrees ×
+// C commits × F files) with cold caches; per-iteration setup restores the
+// pre-op state and is excluded from the timing. The operations and their
+// setup are shared with `wt-perf bench`, which prints the same measurements
+// as JSON for ad-hoc before/after comparisons.
+//
+// Benchmark variants:
+//   - lifecycle/switch-create/<size> — `wt switch --create` of a fresh branch
+//   - lifecycle/list/<size>          — `wt list`
+//   - lifecycle/prune/<size>         — `wt step prune --dry-run`
+//
+// Sizes:
+//   - small — 4 worktrees × 100 commits × 50 files
+//   - large — 16 worktrees × 1000 commits × 500 files
+//
+// Run examples:
+//   cargo bench --bench lifecycle                 # All variants
+//   cargo bench --bench lifecycle switch-create   # One operation, both sizes
+//   cargo bench --bench lifecycle small           # Every operation, small repo
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::path::Path;
+use wt_perf::{LifecycleOp, RepoConfig, create_repo_at};
+
+const SIZES: [(&str, RepoConfig); 2] = [
+    ("small", RepoConfig::sized(4, 100, 50)),
+    ("large", RepoConfig::sized(16, 1000, 500)),
+];
+
+fn bench_lifecycle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lifecycle");
+    let binary = Path::new(env!("CARGO_BIN_EXE_wt"));
+
+    for (label, config) in &SIZES {
+        let temp = tempfile::tempdir().unwrap();
+        let repo = temp.path().join("repo");
+        create_repo_at(config, &repo);
+
+        for op in LifecycleOp::ALL {
+            // `BatchSize::PerIteration` so every measured iteration starts
+            // cold — see benches/CLAUDE.md "Cache Handling".
+            group.bench_with_input(BenchmarkId::new(op.name(), label), &repo, |b, repo| {
+                b.iter_batched(
+                    || op.prepare(repo),
+                    |()| {
+                        let output = op.command(binary, repo).output().unwrap();
+                        assert!(
+                            output.status.success(),
+                            "{} failed: {}",
+                            op.name(),
+                            String::from_utf8_lossy(&output.stderr)
+                        );
+                    },
+                    criterion::BatchSize::PerIteration,
+                );
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .sample_size(20)
+        .measurement_time(std::time::Duration::from_secs(15))
+        .warm_up_time(std::time::Duration::from_secs(3));
+    targets = bench_lifecycle
+}
+criterion_main!(benches);
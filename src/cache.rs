@@ -40,11 +40,12 @@ use crate::git::Repository;
 
 /// The root directory for a named cache kind.
 ///
-/// Returns `<git-common-dir>/wt/cache/<kind>/`. All worktrunk caches live
-/// here; the `kind` is the subdirectory name (e.g. `"ci-status"`,
-/// `"summary"`, `"is-ancestor"`).
+/// Returns `<wt-state-dir>/cache/<kind>/` (typically
+/// `<git-common-dir>/wt/cache/<kind>/`; see [`Repository::wt_state_dir`]). All
+/// worktrunk caches live here; the `kind` is the subdirectory name (e.g.
+/// `"ci-status"`, `"summary"`, `"is-ancestor"`).
 pub fn cache_dir(repo: &Repository, kind: &str) -> PathBuf {
-    repo.wt_dir().join("cache").join(kind)
+    repo.wt_state_dir().join("cache").join(kind)
 }
 
 /// Read and deserialize a JSON cache entry.
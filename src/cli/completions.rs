@@ -0,0 +1,18 @@
+use clap::Subcommand;
+
+use crate::commands::Shell;
+
+/// Subcommands for `wt completions`
+#[derive(Subcommand)]
+pub enum CompletionsCommand {
+    /// Write completion files for installed shells
+    Install {
+        /// Shell to install completions for (default: bash, zsh, and fish, where installed)
+        #[arg(value_enum)]
+        shell: Option<Shell>,
+
+        /// Show what would be changed
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
@@ -1,6 +1,6 @@
 use clap::{Args, Subcommand};
 
-use super::SwitchFormat;
+use super::{ConfigShowTopic, SwitchFormat};
 use crate::commands::Shell;
 
 /// Shared global `--format` flag for the `wt config state` subcommands whose
@@ -230,13 +230,16 @@ This leaves any already-installed Worktrunk plugin unchanged."#
     Uninstall,
 }
 
-// Ordering: action + inverse adjacent (add, clear).
+// Ordering: action + inverse adjacent (add, clear; export, import).
 #[derive(Subcommand)]
 pub enum ApprovalsCommand {
     /// Store approvals in approvals.toml
     #[command(
         after_long_help = r#"Prompts for approval of all project commands and saves them to approvals.toml.
 
+Remote config includes come first: each is fetched, and new or changed content
+is shown for approval. Approved content is kept in `.git/wt/includes/`.
+
 By default, shows only unapproved commands. Use `--all` to review all commands
 including previously approved ones."#
     )]
@@ -258,6 +261,25 @@ all approvals across all projects."#
         #[arg(short, long)]
         global: bool,
     },
+
+    /// Print approved commands as a shareable snippet
+    #[command(
+        after_long_help = r#"Prints the current project's approved commands to stdout as TOML, each with its hash. Commit the snippet or send it to teammates; `wt config approvals import` approves the same commands on their machines.
+
+Approved remote includes aren't exported — `wt config approvals add` fetches and approves those."#
+    )]
+    Export,
+
+    /// Approve the commands in an exported snippet
+    #[command(
+        after_long_help = r#"Reads a snippet written by `wt config approvals export` and adds its commands to approvals.toml, without prompting. Pass `-` to read from stdin.
+
+Each command is checked against its hash first: a snippet edited after export is rejected, as is one exported from a different project. The imported commands are listed so they can be reviewed."#
+    )]
+    Import {
+        /// Snippet file (`-` for stdin)
+        file: std::path::PathBuf,
+    },
 }
 
 // Ordering: alphabetical. Equal-weight sibling plugins with no natural
@@ -480,12 +502,28 @@ $ wt config show --full
 
 This tests:
 - **CI tool status** — Whether `gh` (GitHub) or `glab` (GitLab) is installed and authenticated
+- **Repository indexes** — Whether the commit-graph and multi-pack-index exist (write them with `wt step optimize-repo`)
+- **Per-worktree git config** — Whether every worktree resolves the same default branch, remote URLs, and identity (`includeIf` conditions and `config.worktree` files can make them differ)
 - **Commit generation** — Whether the LLM command can generate commit messages
-- **Version check** — Whether a newer version is available on GitHub"#
+- **Version check** — Whether a newer version is available on GitHub
+
+## Theme
+
+`wt config show theme` lists the `[theme]` palette — each color worktrunk styles with, shown in what it renders as, and whether a preset or the config set it:
+
+```console
+$ wt config show theme
+```
+
+Preview every message type with `wt config shell show-theme`."#
     )]
     Show {
-        /// Run diagnostic checks (CI tools, commit generation, version)
-        #[arg(long)]
+        /// Show only this section
+        #[arg(value_enum)]
+        topic: Option<ConfigShowTopic>,
+
+        /// Run diagnostic checks (CI tools, repository indexes, commit generation, version)
+        #[arg(long, conflicts_with = "topic")]
         full: bool,
 
         /// Output format
@@ -546,9 +584,19 @@ Clear global approvals:
 $ wt config approvals clear --global
 ```
 
+Share approvals with a teammate:
+```console
+$ wt config approvals export > approvals.toml
+$ wt config approvals import approvals.toml
+```
+
 ## How approvals work
 
-Approved commands are saved to `~/.config/worktrunk/approvals.toml`. Re-approval is required when the command template changes or the project moves. Use `--yes` to bypass prompts in CI."#
+Approved commands are saved to `~/.config/worktrunk/approvals.toml`. Re-approval is required when the command template changes or the project moves. Use `--yes` to bypass prompts in CI.
+
+## Sharing approvals
+
+Rather than each teammate reviewing the same hooks, one person can approve them and run `wt config approvals export`. It prints the project's approved commands, each with a hash. Others review the snippet and run `wt config approvals import` to approve the same commands without prompts. Import rejects a command whose hash doesn't match, and a snippet exported from a different project."#
     )]
     Approvals {
         #[command(subcommand)]
@@ -616,6 +664,7 @@ $ wt config plugins opencode install
 - **marker**: [Custom status marker for a branch (shown in `wt list`)](@/config.md#wt-config-state-marker)
 - **vars**: [experimental] [Custom variables per branch](@/config.md#wt-config-state-vars)
 - **logs**: [Operation and debug logs](@/config.md#wt-config-state-logs)
+- **set** / **list** `--ns`: [Namespaced values with optional expiry](@/config.md#wt-config-state-set)
 
 ## Examples
 
@@ -639,6 +688,11 @@ Store arbitrary data:
 $ wt config state vars set env=staging
 ```
 
+Store a namespaced value that expires:
+```console
+$ wt config state set --ns prs feature 1234 --ttl 1h
+```
+
 Drop the regenerable caches:
 ```console
 $ wt config state cache clear
@@ -653,6 +707,14 @@ Clear all stored state:
 ```console
 $ wt config state clear
 ```
+
+Remove data left behind by deleted branches:
+```console
+$ wt config state gc
+```
+<!-- subdoc: set -->
+<!-- subdoc: list -->
+<!-- subdoc: gc -->
 <!-- subdoc: cache -->
 <!-- subdoc: default-branch -->
 <!-- subdoc: logs -->
@@ -666,8 +728,9 @@ $ wt config state clear
     },
 }
 
-// Ordering: aggregate operations first (get, clear, cache) — entry points for
-// exploring, wiping, or refreshing state. Then authoritative state managed by
+// Ordering: aggregate operations first (get, list, set, clear, cache) — entry
+// points for exploring, wiping, or refreshing state, and for namespaced
+// entries. Then authoritative state managed by
 // the user (default-branch override, marker, vars) and the operation logs.
 //
 // `cache` is the home for every regenerable cache (ci-status, summaries, git
@@ -684,20 +747,101 @@ pub enum StateCommand {
 - **Previous branch**: Previous branch for `wt switch -`
 - **Branch markers**: User-defined branch notes
 - **Vars**: Custom variables per branch
+- **Merge journals**: Progress of interrupted `wt merge` runs, for `--continue` / `--abort`
 - **CI status**: Cached GitHub/GitLab CI status per branch (30-60s TTL), plus the largest PR/MR number seen (sizes the `wt list` CI column)
 - **Summaries**: Cached LLM-generated branch summaries (shown in `wt list --full` and `wt switch` preview)
 - **Git commands cache**: SHA-keyed disk caches — merge-tree, ancestry, diff-stats, and `wt switch` preview renders
 - **Hints**: One-time hints that have been shown
 - **Log files**: Operation and debug logs
 - **Trash**: Staged worktree directories awaiting background deletion
+- **Namespaced state**: Entries stored with `wt config state set --ns`, with their expiry
 
 Every category that `wt config state clear` sweeps is shown here.
 
-CI cache entries show status, age, and the commit SHA they were fetched for."#)]
+CI cache entries show status, age, and the commit SHA they were fetched for.
+
+With `--ns`, prints the value stored under one namespaced key instead (empty when the key is missing or expired)."#)]
     Get {
         /// Output format
         #[arg(long, value_enum, default_value = "table")]
         format: super::OutputFormat,
+
+        /// Namespace to read KEY from
+        #[arg(long, value_name = "NAMESPACE", requires = "key")]
+        ns: Option<String>,
+
+        /// Key to read (requires --ns)
+        #[arg(requires = "ns")]
+        key: Option<String>,
+    },
+
+    /// List namespaced entries
+    #[command(
+        after_long_help = r#"Lists entries stored with `wt config state set --ns`. Expired entries are removed as they're listed.
+
+## Examples
+
+List one namespace:
+```console
+$ wt config state list --ns prs
+```
+
+List every namespace:
+```console
+$ wt config state list
+```"#
+    )]
+    List {
+        /// Namespace to list (defaults to all)
+        #[arg(long, value_name = "NAMESPACE")]
+        ns: Option<String>,
+
+        /// Output format
+        #[arg(long, default_value = "text", help_heading = "Output")]
+        format: SwitchFormat,
+    },
+
+    /// Set a namespaced value
+    #[command(
+        after_long_help = r#"Namespaces keep unrelated data apart: a PR cache, a last-visited record, and script state can reuse the same key names without colliding, and `wt config state clear --ns` drops one namespace without touching the others.
+
+With `--ttl`, the entry expires after that long (`30s`, `15m`, `2h`, `7d`). Expired entries read as missing and are deleted on the next read or listing.
+
+## Examples
+
+Store a value:
+```console
+$ wt config state set --ns prs feature 1234
+```
+
+Store a value for an hour:
+```console
+$ wt config state set --ns prs feature 1234 --ttl 1h
+```
+
+Read it back:
+```console
+$ wt config state get --ns prs feature
+```
+
+## Storage format
+
+Each entry is a JSON file at `.git/wt/state/<namespace>/<key>.json`. Namespaces and keys may contain letters, digits, `-`, `_`, and `.`, and may not start with `.`."#
+    )]
+    Set {
+        /// Namespace for the key
+        #[arg(long, value_name = "NAMESPACE")]
+        ns: String,
+
+        /// Key name
+        key: String,
+
+        /// Value to store
+        value: String,
+
+        /// Expire the entry after this long (e.g. 30m, 2h, 7d)
+        #[arg(long, value_name = "DURATION")]
+        ttl: Option<String>,
     },
 
     /// Clear all stored state
@@ -707,19 +851,47 @@ CI cache entries show status, age, and the commit SHA they were fetched for."#)]
 - Previous branch
 - All branch markers
 - All variables
+- All merge journals
+- All merge records (used by `wt step revert-merge`)
 - All caches (CI status, summaries, git commands)
 - All hints
 - All log files
 - Stale trash from worktree removal (`.git/wt/trash/`)
+- All namespaced entries
 
 Prompts for confirmation before clearing, since this removes hand-authored
 markers and vars. Pass `--yes` to skip the prompt.
 
+With `--ns`, clears only that namespace — or one key in it — without a
+prompt.
+
 To drop only the regenerable caches (CI status, summaries, git commands,
 hints, previous branch), run `wt config state cache clear` — it needs no
 confirmation and leaves markers, vars, and the default-branch override
 untouched."#)]
-    Clear,
+    Clear {
+        /// Clear only this namespace
+        #[arg(long, value_name = "NAMESPACE")]
+        ns: Option<String>,
+
+        /// Clear only this key in the namespace (requires --ns)
+        #[arg(requires = "ns")]
+        key: Option<String>,
+    },
+
+    /// Remove data left behind by deleted branches
+    #[command(
+        after_long_help = r#"Deleting a branch with `wt remove`, `wt merge`, or `wt step prune` also clears its markers, pins, vars, and journals. Branches deleted any other way — `git branch -D`, a rename — keep that state, and every deleted branch leaves its cache entries and logs behind. `gc` finds data whose branch no longer exists and removes it:
+
+- Branch markers, pins, vars, and journals
+- CI status and summary cache entries
+- Per-branch log directories (`.git/wt/logs/<branch>/`), unless written to within the last hour
+
+Merge records are kept, so `wt step revert-merge --restore` can still bring a merged branch back. Approvals belong to the project, not a branch, and are never touched.
+
+`wt step prune` runs the same sweep, minus logs, after its removals. `wt debug gc` is another name for this command."#
+    )]
+    Gc,
 
     /// Regenerable caches
     #[command(
@@ -847,7 +1019,7 @@ Hook output lives in per-branch subtrees under `.git/wt/logs/{branch}/`:
 | Background hooks | `{branch}/{source}/{hook-type}/{name}.log` |
 | Background removal | `{branch}/internal/remove.log` |
 
-All `post-*` hooks (post-start, post-switch, post-commit, post-merge) run in the background and produce log files. Source is `user` or `project`. Branch and hook names are sanitized for filesystem safety (invalid characters → `-`; short collision-avoidance hash appended). Same operation on same branch overwrites the previous log. Removing a branch clears its subtree; orphans from deleted branches can be swept with `wt config state logs clear`.
+All `post-*` hooks (post-start, post-switch, post-commit, post-merge) run in the background and produce log files. Source is `user` or `project`. Branch and hook names are sanitized for filesystem safety (invalid characters → `-`; short collision-avoidance hash appended). Same operation on same branch replaces the previous log, which is kept as `{name}.log.1.gz` — gzipped and cut to its last 10MB. When a repository's logs pass 100MB, the oldest files are deleted; both limits are set by [`[logs]`](@/config.md#log-size-limits) in user config. Removing a branch clears its subtree; orphans from deleted branches can be swept with `wt config state logs prune` (logs older than a week) or `wt config state logs clear` (everything).
 
 ### Diagnostic files
 
@@ -862,7 +1034,7 @@ All `post-*` hooks (post-start, post-switch, post-commit, post-merge) run in the
 
 ## Location
 
-All logs are stored in `.git/wt/logs/` (in the main worktree's git directory). All worktrees write to the same directory. Top-level files are shared logs (command audit + diagnostics); top-level directories are per-branch log trees.
+All logs are stored in `.git/wt/logs/` (in the main worktree's git directory). All worktrees write to the same directory. Top-level files are shared logs (command audit + diagnostics); top-level directories are per-branch log trees. With [`state-dir`](@/config.md#logs-and-caches-location) set in user config, logs live under `<state-dir>/<project>/logs/` instead.
 
 ## Structured output
 
@@ -908,15 +1080,9 @@ $ wt config state logs clear
         hide = true,
         after_long_help = r#"**Deprecated** — hints are now part of [`wt config state cache`](@/config.md#wt-config-state-cache). This subcommand still works but prints a deprecation notice.
 
-Some hints show once per repo on first use, then are recorded in git config
-as `worktrunk.hints.<name>`, a count of times the hint has been shown.
-
-## Current hints
-
-| Name | Trigger | Message |
-|------|---------|---------|
-| `worktree-path` | First `wt switch --create` | Customize worktree locations: wt config create |
-| `skip-bare-repo-prompt` | Declining the bare-repo worktree-path prompt | Records the opt-out (no message shown) |
+Hints are recorded in git config as `worktrunk.hints.<name>`, a count of
+times the hint has been shown. `wt hints` lists every hint with
+its cadence.
 
 ## Examples
 
@@ -1306,7 +1472,9 @@ $ wt config state logs --format=json | jq '.hook_output[] | select(.branch | sta
     #[command(
         after_long_help = r#"Summarize where a single `wt` invocation spent its time, reading the records captured to `trace.jsonl` by a `-vv` run.
 
-Reads `.git/wt/logs/trace.jsonl` by default, or a trace given as an argument (e.g. a CI artifact, or `-` for stdin). The report answers three questions: where time goes (subprocess time by command type, plus the slowest individual jobs), how parallel the run was (concurrency factor and peak concurrency), and where work was wasted (commands re-run with the same context). For a `wt list` capture it also shows derived latencies (time to skeleton, time to first result) and a timeline of collect milestones; the skeleton/first-result markers need a terminal (TTY) capture. `--format=json` emits the same data for scripting.
+Reads `.git/wt/logs/trace.jsonl` by default, or a trace given as an argument (e.g. a CI artifact, or `-` for stdin). The report answers three questions: where time goes (subprocess time by command type, plus the slowest individual jobs), how parallel the run was (concurrency factor, peak concurrency, and how busy each thread was), and where work was wasted (commands re-run with the same context). For a `wt list` capture it also shows derived latencies (time to skeleton, time to first result) and a timeline of collect milestones; the skeleton/first-result markers need a terminal (TTY) capture. `--format=json` emits the same data for scripting.
+
+`--flame FILE` writes the subprocess time as folded stacks instead — one line per program, subcommand, and worktree — for `inferno-flamegraph` or `flamegraph.pl` to render as an SVG.
 
 For an interactive timeline or a Perfetto trace, use the `wt-perf` helper (`cargo run -p wt-perf -- timeline`); both read the same `trace.jsonl`.
 
@@ -1324,6 +1492,12 @@ $ wt config state logs profile ci-run.jsonl
 $ wt config state logs profile - < ci-run.jsonl
 ```
 
+Flamegraph of git time by subcommand:
+```console
+$ wt config state logs profile --flame wt.folded
+$ inferno-flamegraph wt.folded > flame.svg
+```
+
 JSON for scripting:
 ```console
 $ wt config state logs profile --format=json | jq '.by_type[0]'
@@ -1332,6 +1506,32 @@ $ wt config state logs profile --format=json | jq '.by_type[0]'
     Profile {
         /// Trace to read (defaults to `.git/wt/logs/trace.jsonl`; `-` for stdin)
         file: Option<std::path::PathBuf>,
+
+        /// Write folded stacks for a flamegraph to this file
+        #[arg(long, value_name = "FILE")]
+        flame: Option<std::path::PathBuf>,
+    },
+
+    /// Delete old log files
+    #[command(
+        after_long_help = r#"Deletes log files last written before `--older-than`, including rotated ones, then deletes the oldest remaining files until the directory fits in `[logs] max-total-size`. Per-branch directories left empty are removed.
+
+## Examples
+
+Delete logs older than a week:
+```console
+$ wt config state logs prune
+```
+
+Delete logs older than a day:
+```console
+$ wt config state logs prune --older-than 1d
+```"#
+    )]
+    Prune {
+        /// Age cutoff (e.g. `12h`, `7d`, `2weeks`)
+        #[arg(long, default_value = "7d")]
+        older_than: String,
     },
 
     /// Clear all log files
@@ -1424,6 +1624,7 @@ impl StateWrite for LogsAction {
     fn write_verb(&self) -> Option<&'static str> {
         match self {
             Self::Get | Self::Profile { .. } => None,
+            Self::Prune { .. } => Some("prune"),
             Self::Clear => Some("clear"),
         }
     }
@@ -0,0 +1,21 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt daemon`
+#[derive(Subcommand)]
+pub enum DaemonCommand {
+    /// Watch this repository, starting the daemon if needed
+    Start {
+        /// Run in the foreground instead of detaching
+        #[arg(long)]
+        foreground: bool,
+    },
+
+    /// Stop the daemon
+    Stop,
+
+    /// Show whether the daemon runs and what it watches
+    Status,
+
+    /// Stop watching this repository
+    Forget,
+}
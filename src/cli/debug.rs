@@ -0,0 +1,25 @@
+use std::ffi::OsString;
+
+use clap::Subcommand;
+
+/// Subcommands for `wt debug`
+#[derive(Subcommand)]
+pub enum DebugCommand {
+    /// Run a wt command and summarize the git and jj calls it made
+    GitCalls {
+        /// The command to audit, without the leading `wt`
+        #[arg(
+            required = true,
+            trailing_var_arg = true,
+            allow_hyphen_values = true,
+            value_name = "ARGS"
+        )]
+        args: Vec<OsString>,
+    },
+
+    /// Remove data left behind by deleted branches
+    #[command(
+        after_long_help = "The same sweep as `wt config state gc`: markers, pins, vars, journals, cache entries, and logs whose branch no longer exists."
+    )]
+    Gc,
+}
@@ -0,0 +1,14 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt hints`
+#[derive(Subcommand)]
+pub enum HintsCommand {
+    /// List hints with their cadence and display record
+    List,
+
+    /// Show hints again on their next trigger
+    Reset {
+        /// Hint to reset (resets all if not specified)
+        name: Option<String>,
+    },
+}
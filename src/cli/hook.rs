@@ -33,7 +33,7 @@
 //! | `--help` | Clap-rendered via injected stubs (both `wt hook --help` and `wt hook <type> --help`) | `wt <alias> --help` redirects to `wt config alias show` / `dry-run` |
 //! | Inspection | `wt hook show [type] [--expanded]` | `wt config alias show <name>` / `dry-run <name>` |
 //! | Trust / approval | User hooks trusted; project hooks require approval per-hook-type | User aliases trusted; project aliases require approval per-alias |
-//! | Hook-specific flags | `--dry-run`, `--foreground`, `--var` parsed by [`HookOptions::parse`] | None — aliases have no CLI-level knobs beyond smart routing |
+//! | Hook-specific flags | `--dry-run`, `--foreground`, `--all`, `--var` parsed by [`HookOptions::parse`] | None — aliases have no CLI-level knobs beyond smart routing |
 //! | Template-context extras | `hook_type`, `hook_name`, per-type operation vars (`base`, `target`, `pr_number`, …) | `args` only, on top of the shared base vars |
 
 use std::ffi::OsString;
@@ -61,6 +61,7 @@ pub const HOOK_TYPE_NAMES: &[&str] = &[
     "post-commit",
     "pre-merge",
     "post-merge",
+    "pre-push",
     "pre-remove",
     "post-remove",
 ];
@@ -81,7 +82,7 @@ fn hook_show_possible_values() -> Vec<clap::builder::PossibleValue> {
         .collect()
 }
 
-// Ordering: `show` first (read-only introspection), then the external
+// Ordering: `show` and `status` first (read-only introspection), then the external
 // subcommand catch-all, then hidden commands. Hook types aren't listed
 // as clap variants — `Run` catches them.
 /// Run configured hooks
@@ -106,6 +107,34 @@ pub enum HookCommand {
         format: crate::cli::SwitchFormat,
     },
 
+    /// Show the last run of background hooks
+    ///
+    /// Background hooks (`post-start`, `post-switch`, …) record each command's
+    /// state, duration, and exit code per branch. `wt list --full` summarizes
+    /// them in its Hooks column; this lists every command. Output is in the
+    /// per-command log under `.git/wt/logs/` (see `wt config state logs`).
+    #[command(after_long_help = r#"## Examples
+
+Check why a worktree's setup hooks failed:
+```console
+$ wt hook status feature
+```
+
+States: `succeeded`, `failed`, `running`, `pending` (waiting on an earlier
+step), and `skipped` (an earlier step failed). A run whose runner died without
+recording an outcome shows as failed."#)]
+    Status {
+        /// Branch to show (default: current)
+        #[arg(add = crate::completion::branch_value_completer(), value_parser = crate::cli::non_empty_branch)]
+        branch: Option<String>,
+
+        /// Output format
+        ///
+        /// JSON prints one record per pipeline to stdout.
+        #[arg(long, default_value = "text", help_heading = "Automation")]
+        format: crate::cli::SwitchFormat,
+    },
+
     /// Internal: run a serialized pipeline from stdin
     #[command(hide = true, name = "run-pipeline")]
     RunPipeline,
@@ -134,6 +163,8 @@ pub struct HookOptions {
     pub hook_type: HookType,
     pub yes: bool,
     pub dry_run: bool,
+    /// Run in every worktree instead of only the current one.
+    pub all: bool,
     /// `Some(true)` forces foreground for `post-*` hooks that normally run
     /// in the background. `None` defers to the hook type's default.
     pub foreground: Option<bool>,
@@ -165,6 +196,7 @@ pub fn parse_hook_type(name: &str) -> anyhow::Result<HookType> {
         "post-commit" => Ok(HookType::PostCommit),
         "pre-merge" => Ok(HookType::PreMerge),
         "post-merge" => Ok(HookType::PostMerge),
+        "pre-push" => Ok(HookType::PrePush),
         "pre-remove" => Ok(HookType::PreRemove),
         "post-remove" => Ok(HookType::PostRemove),
         other => {
@@ -190,6 +222,7 @@ impl HookOptions {
     /// - `--yes` / `-y` — set `yes` (equivalent to the global `-y` flag,
     ///   supported post-type so `wt hook pre-merge --yes` works).
     /// - `--dry-run` — set `dry_run`.
+    /// - `--all` — set `all` (run in every worktree).
     /// - `--foreground` — set `foreground = Some(true)` (post-* hooks).
     /// - `--var KEY=VALUE` / `--var=KEY=VALUE` — explicit force-bind;
     ///   appended to `explicit_vars`. Deprecated; dispatch warns.
@@ -207,6 +240,7 @@ impl HookOptions {
 
         let mut yes = false;
         let mut dry_run = false;
+        let mut all = false;
         let mut foreground: Option<bool> = None;
         let mut name_filters: Vec<String> = Vec::new();
         let mut explicit_vars: Vec<(String, String)> = Vec::new();
@@ -240,6 +274,11 @@ impl HookOptions {
                     i += 1;
                     continue;
                 }
+                "--all" => {
+                    all = true;
+                    i += 1;
+                    continue;
+                }
                 "--foreground" => {
                     foreground = Some(true);
                     i += 1;
@@ -288,6 +327,7 @@ impl HookOptions {
             hook_type,
             yes,
             dry_run,
+            all,
             foreground,
             name_filters,
             explicit_vars,
@@ -325,6 +365,7 @@ mod tests {
         assert_eq!(opts.hook_type, HookType::PreMerge);
         assert!(!opts.yes);
         assert!(!opts.dry_run);
+        assert!(!opts.all);
         assert_eq!(opts.foreground, None);
         assert!(opts.name_filters.is_empty());
         assert!(opts.explicit_vars.is_empty());
@@ -334,10 +375,11 @@ mod tests {
 
     #[test]
     fn test_parse_flags() {
-        let opts = parse(&["post-create", "--yes", "--dry-run", "--foreground"]).unwrap();
+        let opts = parse(&["post-create", "--yes", "--dry-run", "--all", "--foreground"]).unwrap();
         assert_eq!(opts.hook_type, HookType::PostCreate);
         assert!(opts.yes);
         assert!(opts.dry_run);
+        assert!(opts.all);
         assert_eq!(opts.foreground, Some(true));
 
         // `-y` alias for `--yes`.
@@ -1,8 +1,14 @@
+mod completions;
 mod config;
+mod daemon;
+mod debug;
+mod hints;
 mod hook;
 mod list;
+mod snapshot;
 mod step;
 
+pub(crate) use completions::CompletionsCommand;
 pub(crate) use config::{
     ApprovalsCommand, CacheAction, CiStatusAction, ConfigAliasCommand, ConfigCommand,
     ConfigPluginsClaudeCommand, ConfigPluginsCodexCommand, ConfigPluginsCommand,
@@ -10,8 +16,12 @@ pub(crate) use config::{
     HintsAction, LogsAction, MarkerAction, PreviousBranchAction, StateCommand, StateWrite,
     VarsAction,
 };
+pub(crate) use daemon::DaemonCommand;
+pub(crate) use debug::DebugCommand;
+pub(crate) use hints::HintsCommand;
 pub(crate) use hook::{HOOK_TYPE_NAMES, HookCommand, HookOptions, parse_hook_type};
 pub(crate) use list::ListSubcommand;
+pub(crate) use snapshot::SnapshotCommand;
 pub(crate) use step::StepCommand;
 
 use clap::builder::styling::{AnsiColor, Color, Styles};
@@ -211,6 +221,76 @@ pub(crate) enum SwitchFormat {
     Json,
 }
 
+/// How `wt merge` handles a branch cut from another feature branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum StackedBaseMode {
+    /// Rebase onto the target without the parent branch's commits
+    Rebase,
+    /// Merge into the parent branch instead
+    Parent,
+    /// Merge the parent branch's commits too
+    Include,
+}
+
+/// Editor presets for `wt open --editor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum OpenEditor {
+    /// VS Code
+    Code,
+    /// Zed
+    Zed,
+    /// IntelliJ IDEA
+    Idea,
+    /// First of code, zed, idea on PATH, then `$VISUAL` / `$EDITOR`
+    Auto,
+}
+
+/// Operations `wt serve` can expose as MCP tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ServeOperation {
+    List,
+    Switch,
+    Remove,
+    Merge,
+    Rebase,
+    Squash,
+    Push,
+    PruneCandidates,
+}
+
+/// `--color` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ColorWhen {
+    Auto,
+    Always,
+    Never,
+}
+
+impl From<ColorWhen> for worktrunk::styling::ColorChoice {
+    fn from(when: ColorWhen) -> Self {
+        match when {
+            ColorWhen::Auto => Self::Auto,
+            ColorWhen::Always => Self::Always,
+            ColorWhen::Never => Self::Never,
+        }
+    }
+}
+
+/// A single section `wt config show` can show instead of everything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ConfigShowTopic {
+    /// The `[theme]` palette
+    Theme,
+}
+
+/// Output format for `wt map`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum MapFormat {
+    Json,
+    /// `KEY=value` lines for `.env` files and Makefiles
+    Env,
+}
+
 /// Output format for `wt list` and `wt config state get` (table or JSON).
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
 pub(crate) enum OutputFormat {
@@ -293,16 +373,91 @@ pub(crate) struct Cli {
     )]
     pub verbose: u8,
 
+    /// Quiet output (only errors, warnings, and primary output)
+    #[arg(
+        long,
+        short = 'q',
+        global = true,
+        conflicts_with = "verbose",
+        display_order = 104,
+        help_heading = "Global Options"
+    )]
+    pub quiet: bool,
+
+    /// Emit newline-delimited JSON progress events for editor integrations
+    ///
+    /// Bare: on stderr, replacing status lines. `=FD`: on that inherited file
+    /// descriptor, leaving stderr unchanged.
+    #[arg(
+        long,
+        global = true,
+        value_name = "FD",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "2",
+        hide = true
+    )]
+    pub progress_json: Option<u32>,
+
     /// Skip approval prompts
     #[arg(
         long,
         short = 'y',
         global = true,
-        display_order = 104,
+        display_order = 105,
         help_heading = "Global Options"
     )]
     pub yes: bool,
 
+    /// Approve a project command by hash, without prompting
+    ///
+    /// Repeatable. The hash is printed when approval is needed but no prompt
+    /// can be shown. `WORKTRUNK_APPROVE` sets the same from the environment:
+    /// `never` (decline unapproved commands), `all` (like `--yes`), or
+    /// comma-separated `hash:<hash>` entries.
+    #[arg(
+        long,
+        global = true,
+        value_name = "HASH",
+        display_order = 106,
+        help_heading = "Global Options"
+    )]
+    pub approve: Vec<String>,
+
+    /// Disable forge queries, version checks, and LLM commands (same as `network = false`)
+    #[arg(
+        long,
+        global = true,
+        display_order = 107,
+        help_heading = "Global Options"
+    )]
+    pub offline: bool,
+
+    /// When to color output (auto, always, never) [default: auto]
+    #[arg(
+        long,
+        global = true,
+        value_name = "WHEN",
+        default_value = "auto",
+        hide_default_value = true,
+        hide_possible_values = true,
+        display_order = 108,
+        help_heading = "Global Options"
+    )]
+    pub color: ColorWhen,
+
+    /// Print ISO 8601 timestamps instead of relative ages
+    ///
+    /// For scripts and snapshot tests, together with `WT_NOW`, which pins the
+    /// current time (Unix seconds or RFC 3339).
+    #[arg(
+        long,
+        global = true,
+        display_order = 109,
+        help_heading = "Global Options"
+    )]
+    pub absolute_times: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -313,9 +468,10 @@ pub(crate) struct Cli {
 ///
 /// `wt merge` does not flatten this struct: its hooks flag is tri-state
 /// (`Option<bool>`, so config `[merge] verify` can still apply) and it carries
-/// a positive `--verify` override. It declares its own flags but routes the
-/// `--no-verify` deprecation through `crate::warn_no_verify_deprecated`, so the
-/// warning text lives in exactly one place.
+/// a positive `--verify` override. Its `--no-verify` skips the `[merge] verify`
+/// command, and only without one falls back to the deprecated `--no-hooks`
+/// alias, routed through `crate::warn_no_verify_deprecated` so the warning text
+/// lives in exactly one place.
 #[derive(Args)]
 pub(crate) struct HookFlags {
     /// Skip hooks
@@ -345,7 +501,7 @@ pub(crate) struct SwitchArgs {
     /// Branch name, shortcut, or PR/MR URL
     ///
     /// Opens interactive picker if omitted.
-    /// Shortcuts: `^` (default branch), `-` (previous), `@` (current), `pr:{N}` (GitHub PR), `mr:{N}` (GitLab MR)
+    /// Shortcuts: `^` (default branch), `-` (previous), `@` (current), `%N` (row N of the last `wt list`), `pr:{N}` (GitHub PR), `mr:{N}` (GitLab MR)
     #[arg(add = crate::completion::worktree_branch_completer(), value_parser = crate::cli::non_empty_branch)]
     pub(crate) branch: Option<String>,
 
@@ -372,6 +528,24 @@ pub(crate) struct SwitchArgs {
     #[arg(short = 'b', long, requires = "branch", add = crate::completion::branch_value_completer(), value_parser = crate::cli::non_empty_branch)]
     pub(crate) base: Option<String>,
 
+    /// Upstream for the new branch
+    ///
+    /// `base` tracks the branch it was created from, `remote` the
+    /// same-named branch on the primary remote (so `git push` publishes
+    /// it there), `none` nothing. Default from `[switch] track` in user
+    /// config.
+    #[arg(
+        long,
+        value_name = "MODE",
+        requires = "create",
+        overrides_with = "no_track"
+    )]
+    pub(crate) track: Option<worktrunk::config::TrackMode>,
+
+    /// Set no upstream for the new branch
+    #[arg(long, requires = "create", overrides_with = "track")]
+    pub(crate) no_track: bool,
+
     /// Command to run after switch
     ///
     /// Replaces the wt process with the command after switching, giving
@@ -411,6 +585,28 @@ pub(crate) struct SwitchArgs {
     #[arg(long, requires = "branch")]
     pub(crate) clobber: bool,
 
+    /// Create the worktrees listed in a manifest
+    ///
+    /// A TOML file of `[[worktree]]` entries, or `-` to read the same as
+    /// JSON from stdin. See [Batch creation](@/switch.md#batch-creation).
+    #[arg(
+        long,
+        value_name = "MANIFEST",
+        help_heading = "Automation",
+        conflicts_with_all = ["branch", "branches", "remotes", "prs", "tmux_window"]
+    )]
+    pub(crate) batch: Option<std::path::PathBuf>,
+
+    /// Pre-start hooks to run at once with --batch
+    #[arg(
+        long,
+        value_name = "N",
+        requires = "batch",
+        default_value_t = crate::commands::DEFAULT_BATCH_JOBS,
+        help_heading = "Automation"
+    )]
+    pub(crate) jobs: usize,
+
     /// Skip directory change after switching
     ///
     /// Hooks still run normally. Useful when hooks handle navigation
@@ -422,6 +618,24 @@ pub(crate) struct SwitchArgs {
     #[arg(long, overrides_with = "no_cd", hide = true)]
     pub(crate) cd: bool,
 
+    /// Open in a tmux window or Zellij tab
+    ///
+    /// Inside tmux or Zellij, focuses the window (tab) named after the
+    /// branch, or opens one rooted at the worktree, instead of changing
+    /// directory in the current pane. Outside a multiplexer, switches as
+    /// usual. Default from `[switch] tmux-window` in user config.
+    #[arg(
+        long,
+        requires = "branch",
+        conflicts_with = "execute",
+        overrides_with = "no_tmux_window"
+    )]
+    pub(crate) tmux_window: bool,
+
+    /// Don't open a tmux window or Zellij tab
+    #[arg(long, overrides_with = "tmux_window", hide = true)]
+    pub(crate) no_tmux_window: bool,
+
     #[command(flatten)]
     pub(crate) hooks: HookFlags,
 
@@ -450,10 +664,33 @@ pub(crate) struct ListArgs {
     #[arg(long)]
     pub(crate) remotes: bool,
 
-    /// Show CI status and LLM summaries
+    /// Show CI status, re-cut bases, hook results, stash counts, and LLM summaries
     #[arg(long)]
     pub(crate) full: bool,
 
+    /// Show disk usage per worktree
+    ///
+    /// Adds a Size column with the bytes each worktree's checked-out files
+    /// take, and reports the shared object store in the summary line.
+    #[arg(long)]
+    pub(crate) size: bool,
+
+    /// Group worktree paths by directory
+    ///
+    /// Shows the Path column as a tree: worktrees in the same directory
+    /// sit together, and the directory prefix a row shares with the row
+    /// above is blanked so children appear indented under it.
+    #[arg(long)]
+    pub(crate) tree: bool,
+
+    /// Show only branches authored by the current git user
+    ///
+    /// Keeps rows whose branch tip, or most of whose commits since the
+    /// merge base with the default branch, were authored by `user.email`.
+    /// The main worktree is always shown.
+    #[arg(long)]
+    pub(crate) only_mine: bool,
+
     /// Show fast info immediately, update with slow info
     ///
     /// Displays local data (branches, paths, status) first, then updates
@@ -465,6 +702,47 @@ pub(crate) struct ListArgs {
     /// Force buffered rendering
     #[arg(long = "no-progressive", overrides_with = "progressive", hide = true)]
     pub(crate) no_progressive: bool,
+
+    /// Write JSON output to a file instead of stdout
+    ///
+    /// Requires `--format=json`.
+    #[arg(long, value_name = "path")]
+    pub(crate) output: Option<std::path::PathBuf>,
+
+    /// Replace the `--output` file atomically
+    ///
+    /// Writes to a temporary file in the same directory, then renames it
+    /// over the target, so readers never see a partial write.
+    #[arg(long, requires = "output")]
+    pub(crate) atomic: bool,
+
+    /// Show only rows that changed since the last `--changed` run
+    ///
+    /// Marks new rows `+`, removed rows `-`, and rows with new commits or a
+    /// working tree that turned dirty or clean `~`.
+    #[arg(long)]
+    pub(crate) changed: bool,
+
+    /// Print one tab-separated line per row, for scripts
+    ///
+    /// Fields are branch, path, ahead, behind, and flags; the format is
+    /// stable across versions.
+    #[arg(long, conflicts_with_all = ["format", "output", "changed"])]
+    pub(crate) porcelain: bool,
+
+    /// Print a one-line summary of all worktrees
+    ///
+    /// Counts dirty, rebasing, and ahead-of-default-branch worktrees, for
+    /// agent prompts and dashboards. With `--full`, also failing CI.
+    #[arg(long, conflicts_with_all = ["format", "output", "changed", "porcelain"])]
+    pub(crate) summary: bool,
+
+    /// List every repository in a `[repo-groups]` entry
+    ///
+    /// One table per repository, each under its name; with
+    /// `--format=json`, one object per repository.
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["output", "changed", "porcelain", "summary"])]
+    pub(crate) group: Option<String>,
 }
 
 #[derive(Args)]
@@ -500,10 +778,26 @@ pub(crate) struct RemoveArgs {
     ///
     /// Remove a dirty worktree, including staged, modified, and untracked
     /// files. Without this flag, removal fails if the worktree has any
-    /// uncommitted changes.
+    /// uncommitted changes, or if a process (dev server, agent, shell) is
+    /// running inside it.
     #[arg(short, long)]
     pub(crate) force: bool,
 
+    /// Stash uncommitted changes, then remove
+    ///
+    /// Saves staged, modified, and untracked files as a stash named
+    /// `wt remove: <branch>`, recorded under the `removed-stash` namespace of
+    /// `wt config state`. Restore with `git stash apply <sha>`.
+    #[arg(long, conflicts_with_all = ["discard", "force"])]
+    pub(crate) stash: bool,
+
+    /// Discard uncommitted changes, then remove
+    ///
+    /// Shows the diffstat and asks for confirmation first; `--yes` skips the
+    /// prompt.
+    #[arg(long, conflicts_with = "force")]
+    pub(crate) discard: bool,
+
     /// Output format
     ///
     /// JSON prints structured result to stdout after removal completes.
@@ -559,26 +853,49 @@ pub(crate) struct MergeArgs {
     #[arg(long, overrides_with = "no_ff", hide = true)]
     pub(crate) ff: bool,
 
-    /// Force running hooks
+    /// Force running hooks and the verification command
     #[arg(long, overrides_with_all = ["no_hooks", "no_verify"], hide = true)]
     pub(crate) verify: bool,
 
     /// Skip hooks
     #[arg(
         long = "no-hooks",
-        overrides_with_all = ["verify", "no_verify"],
+        overrides_with = "verify",
         help_heading = "Automation"
     )]
     pub(crate) no_hooks: bool,
 
-    /// Skip hooks (deprecated alias for --no-hooks)
-    #[arg(long = "no-verify", overrides_with_all = ["verify", "no_hooks"], hide = true)]
+    /// Skip the verification command
+    ///
+    /// Skips the `[merge] verify` command; hooks still run. Without a
+    /// configured command, a deprecated alias for --no-hooks.
+    #[arg(
+        long = "no-verify",
+        overrides_with = "verify",
+        help_heading = "Automation"
+    )]
     pub(crate) no_verify: bool,
 
     /// What to stage before committing [default: all]
     #[arg(long)]
     pub(crate) stage: Option<crate::commands::commit::StageMode>,
 
+    /// Handling of a branch cut from another feature branch
+    #[arg(long, value_name = "MODE", conflicts_with_all = ["resume", "abort"])]
+    pub(crate) stacked_base: Option<StackedBaseMode>,
+
+    /// Resume an interrupted merge
+    #[arg(long = "continue", conflicts_with_all = ["target", "abort", "stage"])]
+    pub(crate) resume: bool,
+
+    /// Roll back an interrupted merge
+    #[arg(long, conflicts_with_all = ["target", "stage"])]
+    pub(crate) abort: bool,
+
+    /// Wait for other queued merges into the target to finish first
+    #[arg(long, conflicts_with = "abort")]
+    pub(crate) queue: bool,
+
     /// Output format
     ///
     /// JSON prints structured result to stdout after merge completes.
@@ -612,6 +929,21 @@ $ wt switch https://github.com/owner/repo/pull/123   # ...or paste the PR's URL
 
 The `--create` flag creates a new branch from `--base` — the default branch unless specified. Without `--create`, the branch must already exist. Switching to a remote branch (e.g., `wt switch feature` when only `origin/feature` exists) creates a local tracking branch.
 
+`--base` also accepts a remote-tracking ref, so a new branch can start from the upstream trunk even when the local `main` lags behind. Before branching from one, worktrunk fetches it if the last fetch is more than five minutes old; `fetch-base` under `[switch]` in user config sets that age (`"0s"` fetches every time) or turns fetching `"off"`. Offline, or if the fetch fails, the existing remote-tracking ref is used.
+
+```console
+$ wt switch --create fix --base origin/main   # New branch from the freshly fetched upstream main
+```
+
+To keep new branches off a stale local trunk, set `max-base-behind` under `[switch]` in project config (or user config, which takes precedence). When the base is more than that many commits behind its upstream — fetched first under the same `fetch-base` rule — `--create` offers to fast-forward it; `--yes` fast-forwards without asking, and a non-interactive switch warns and branches from the base as it is. A base checked out in a worktree is fast-forwarded there, which fails rather than overwrite local changes.
+
+A new branch tracks nothing by default — not even a remote-tracking base, since `git push` would then push to it. `--track base` sets its upstream to the branch it was created from, so `git status` counts commits against it; `--track remote` sets the same-named branch on the primary remote, so a plain `git push` publishes it there (git reports that upstream as gone until the first push). Set `track` under `[switch]` in user config to change the default; `--no-track` overrides it. A `--base pr:N` branch tracks the PR's branch unless told otherwise. The success message names the upstream when one is set:
+
+```console
+$ wt switch --create feature --track remote
+✓ Created branch feature from main (tracking origin/feature) and worktree @ ../repo.feature
+```
+
 ## Creating worktrees
 
 If the branch already has a worktree, `wt switch` changes directories to it. Otherwise, it creates one:
@@ -629,6 +961,10 @@ $ wt switch --create fix --base release    # New branch from release
 $ wt switch --create temp --no-hooks       # Skip hooks
 ```
 
+If creation is interrupted before the pre-start hooks finish — Ctrl-C, a failing hook, a closed terminal — the next `wt switch` to that branch offers to run the pre-start hooks again or remove the partial worktree. Removal is forced only while the worktree is as creation left it; once it has edits or commits, git's usual checks keep a dirty worktree or an unmerged branch. `--yes` runs the hooks again; without a terminal, it warns and switches as usual.
+
+Switching also repairs common breakage: a worktree whose directory was deleted, or whose removal was interrupted partway, has its entry pruned and is created again (unless locked; a partly removed directory still needs `--clobber`), a `.git` file left pointing at a moved repository is relinked with `git worktree repair`, and an `index.lock` untouched for over an hour is removed. A more recent `index.lock` is only reported, since a running git command may hold it.
+
 ## Shortcuts
 
 | Shortcut | Meaning |
@@ -636,6 +972,7 @@ $ wt switch --create temp --no-hooks       # Skip hooks
 | `^` | Default branch (`main`/`master`) |
 | `@` | Current branch/worktree |
 | `-` | Previous worktree (like `cd -`) |
+| `%N` | Row N of this shell's last `wt list` |
 | `pr:{N}` | GitHub PR #N's branch |
 | `mr:{N}` | GitLab MR !N's branch |
 
@@ -646,10 +983,33 @@ $ wt switch --create fix --base=@       # Branch from current HEAD
 $ wt switch --create fix --base=pr:123  # Branch from PR #123's head
 $ wt switch pr:123                      # PR #123's branch
 $ wt switch mr:101                      # MR !101's branch
+$ wt switch %2                          # Second row of the last wt list
 ```
 
 Shortcuts also apply to `--base`. For a fork PR/MR, the head commit is fetched and used as the base SHA without creating a tracking branch.
 
+`--base` additionally accepts `snapshot:{name}`, the commit pinned by `wt snapshot create {name}` — so several worktrees can start from the same base while trunk moves on.
+
+Rows for `%N` are counted from 1 in the order the last table `wt list` in the current shell printed them, so they stay stable until that shell lists again. `wt 2` is shorthand for `wt switch %2`. `%N` works anywhere a branch is accepted, including `wt remove %3`.
+
+## Partial names
+
+A name that matches no branch is tried as part of one: `wt switch login` switches to `feature/login-page` when that's the only local branch containing `login`, ignoring case. Worktree directory names match too, and a directory named exactly the argument wins over partial matches. When several branches match, a terminal gets a numbered list to pick from; without one, `wt switch` lists them and exits. Remote-only branches aren't searched, and `--create` always takes the name as given.
+
+```console
+$ wt switch login                        # feature/login-page, if nothing else matches
+```
+
+## jj revsets
+
+In a colocated [jj](https://jj-vcs.github.io/jj/) repository (`.jj/` beside `.git/` in the main worktree), a name that matches no branch is resolved with `jj log -r` as a change ID or revset, which must match a single revision. A revision with a bookmark switches to that bookmark. Otherwise worktrunk creates a branch named after the short change ID at that commit; jj picks it up as a bookmark. To choose the bookmark name, pass the revset as `--base`:
+
+```console
+$ wt switch kntq                                 # Change ID prefix
+$ wt switch 'description(glob:"fix*")'           # Any revset matching one revision
+$ wt switch --create fix-login --base kntq       # Name the bookmark
+```
+
 ## Interactive picker
 
 When called without arguments, `wt switch` opens an interactive picker to browse and select worktrees with live preview. The candidate set widens with `--branches` (local branches without worktrees), `--remotes` (remote branches), and `--prs` (open PRs/MRs — see below).
@@ -722,6 +1082,22 @@ The `--prs` flag adds the repository's open PRs (GitHub) or MRs (GitLab) to the
 
 Requires `gh` (GitHub), `glab` (GitLab), or an equivalent CLI installed and authenticated; see [forge platform](@/config.md#forge-platform) for Gitea, Azure DevOps, and other supported platforms.
 
+## tmux and Zellij windows
+
+Inside tmux or Zellij, `--tmux-window` gives each branch its own window (a tab, in Zellij) instead of changing directory in the current pane. The window is named after the branch: if one exists it's focused, otherwise a new one opens rooted at the worktree. Set `tmux-window = true` under `[switch]` in user config to make it the default; outside a multiplexer, `wt switch` changes directory as usual.
+
+```console
+$ wt switch --create feature --tmux-window
+```
+
+## Terminal titles
+
+With `terminal-title = true` under `[switch]` in user config, switching sets the terminal's tab and window title to the branch, so a row of worktree tabs reads at a glance. iTerm2 also shows the branch as a badge. Removing the worktree the shell is in clears the title. The title is set with a standard escape sequence (OSC 0) that iTerm2, WezTerm, Kitty, and most other terminals understand, and only when stderr is a terminal.
+
+## WIP commits
+
+With `auto-wip = true` under `[switch]` in user config, switching away from a worktree with uncommitted changes commits them, untracked files included, as a `wip:` commit on its branch (commit hooks are skipped). Switching back undoes that commit with a soft reset, so the changes return staged. A WIP commit that is no longer the branch's `HEAD` — the branch was committed to or reset in the meantime — stays as an ordinary commit. Worktrees mid-rebase or mid-merge are left as they are.
+
 ## When wt switch fails
 
 - **Branch doesn't exist** — Use `--create`, or check `wt list --branches`
@@ -730,6 +1106,27 @@ Requires `gh` (GitHub), `glab` (GitLab), or an equivalent CLI installed and auth
 
 To change which branch a worktree is on, use `git switch` inside that worktree.
 
+## Batch creation
+
+`--batch` creates several worktrees in one invocation — for example, one per attempt when fanning an agent out over the same task. The manifest lists them; `branch` is a template over `{{ n }}` when an entry has a `count`, and `base` defaults to the default branch:
+
+```toml
+[[worktree]]
+branch = "attempt-{{ n }}"
+count = 3
+
+[[worktree]]
+branch = "fix-docs"
+base = "release"
+```
+
+```console
+$ wt switch --batch attempts.toml
+$ echo '{"worktree": [{"branch": "spike", "count": 2}]}' | wt switch --batch - --format=json
+```
+
+Creation is all or nothing: every entry is checked before any worktree is created, and if creating one fails, the worktrees the batch already created are removed. Hooks are approved once for the whole batch. Pre-start hooks then run in parallel, at most `--jobs` (default 4) at once, followed by the background hooks. The result is a table of each worktree's branch, base, path, and whether its pre-start hooks passed; with `--format=json`, an array of the same. A failed hook leaves its worktree in place and makes `wt` exit 1. The shell stays where it is.
+
 ## See also
 
 - [`wt list`](@/list.md) — View all worktrees
@@ -777,7 +1174,7 @@ $ wt list --full
 + fix-auth         ↕|                ↑2  ↓1   +25  -11  Harden auth with constant-time token validation           |     #408  b772e68b
 + fix-typos        _|                                                                                             |     #410  41ee0834
 
-○ Showing 4 worktrees, 1 with changes, 2 ahead, 3 columns hidden
+○ Showing 4 worktrees, 1 with changes, 2 ahead, 4 columns hidden
 ```
 
 Include branches that don't have worktrees:
@@ -793,7 +1190,7 @@ $ wt list --branches --full
 / exp             /↕                 ↑2  ↓1  +137       Explore GraphQL schema and resolvers                                  96379229
 / wip             /↕                 ↑1  ↓1   +33       Start API documentation                                               b40716dc
 
-○ Showing 4 worktrees, 2 branches, 1 with changes, 4 ahead, 3 columns hidden
+○ Showing 4 worktrees, 2 branches, 1 with changes, 4 ahead, 4 columns hidden
 ```
 
 Output as JSON for scripting:
@@ -812,17 +1209,23 @@ $ wt list --format=json
 | main↕ | Commits ahead/behind default branch |
 | main…± | Line diffs since the merge-base (three-dot) with the default branch |
 | Summary | LLM-generated branch summary; requires `--full`, `summary = true`, and [`commit.generation`](@/config.md#commit) [experimental] |
-| Remote⇅ | Commits ahead/behind tracking branch |
+| Remote⇅ | Commits ahead/behind tracking branch; `gone` when it was deleted from the remote |
 | CI | PR/MR number colored by pipeline status; `--full` only |
+| Base | Base branch that was [re-cut or deleted](#re-cut-bases) since the branch was cut; `--full` only |
+| Hooks | Outcome of the last [background hook](#background-hook-results) runs; `--full` only, once any run is recorded |
+| Stash | Number of `git stash` entries made on the branch; `--full` only, when the stash list isn't empty |
 | Path | Worktree directory |
+| Size | Bytes on disk for the worktree's checked-out files; `--size` only |
 | URL | Dev server URL from project config; dimmed if port is not listening |
-| *(custom)* | User-defined [custom columns](#custom-columns) from `[list.custom-columns]` user config [experimental] |
+| *(custom)* | User-defined [custom columns](#custom-columns) from `[list.custom-columns]` user config or `[list.columns]` project config [experimental] |
 | Commit | Short hash (8 chars) |
-| Age | Time since last commit |
+| Age | Time since last commit; the commit time itself with `--absolute-times` |
 | Message | Last commit message (truncated) |
 
 The `main` header label is used regardless of the default branch's actual name.
 
+Branch names and paths too long for their column are shortened in the middle — `feature/paym…PROJ-1234` — keeping the prefix and the suffix that usually tells branches apart. `branch-width` and `path-width` under [`[list]`](@/config.md#list) cap the columns.
+
 `main↕` and `main…±` measure against the default branch's upstream tip when the local copy lags it — so in a fork whose local `main` trails `origin/main`, a branch reads as ahead of the real mainline, not of a stale local checkout. The `↑`/`↓`/`↕` Status symbols derive from these counts, so they track the upstream tip too.
 
 ### Gutter
@@ -859,6 +1262,39 @@ Color precedence resolves the fold: changes-requested (magenta) outranks running
 
 CI cells are clickable links to the PR or pipeline page, and appear dimmed for a draft PR/MR (`"draft"`) or when unpushed local changes make the status stale (`ci.stale`). PRs/MRs are checked first, then branch workflows/pipelines for branches with an upstream. Local-only branches show blank; remote-only branches — visible with `--remotes` — get CI status detection. Results are cached for 30-60 seconds; use `wt config state` to view or clear.
 
+### Re-cut bases
+
+Release-train repos re-cut release branches: `release/1.2` is force-pushed to a new history, or deleted. Branches cut from the old history still carry it, and need rebasing onto the new one. With `--full`, the Base column flags them — `rewritten origin/release/1.2` when the base no longer contains the commit the branch started from, `deleted release/1.2` when the base is gone. For a local base that tracks a remote, the remote side is checked too, so a force-push shows after `git fetch`. The flag clears once the branch is rebased off the old history.
+
+The base comes from `wt switch --create`, which records the `--base` ref (the default branch when omitted) and its commit. For branches created outside worktrunk, git's reflog entry (`branch: Created from …`) stands in until reflog expiry removes it. A branch checked out from its own upstream (`wt switch feature` tracking `origin/feature`) isn't flagged; the Remote⇅ column covers that.
+
+### Background hook results
+
+Background hooks (`post-start`, `post-switch`, …) run detached, so a failure only reaches a log file. Each run records every command's state, duration, and exit code for its branch, and with `--full` the Hooks column summarizes the latest runs: `✓` when all succeeded, `✗ build` naming the failed command (`✗ build +1` when more failed), `running` while in progress, or `skipped`. `wt hook status` lists every command.
+
+### Disk usage
+
+`--size` adds the Size column: the checked-out files in each worktree, excluding `.git`. Worktrees of one repository share a single object store, so history is not counted per worktree — the footer reports the shared store once, along with any [alternates](https://git-scm.com/docs/gitrepository-layout#Documentation/gitrepository-layout.txt-objectsinfoalternates) it borrows objects from (e.g. a clone made with `--reference`). Walking every worktree's files takes time on large checkouts, so the column is off by default.
+
+### Tree view
+
+`--tree` shows the Path column as a tree, for worktrees spread over several directories. Worktrees in the same directory sit on adjacent rows, and the directory prefix a row shares with the row above is blanked, so children line up under the first path that spells it out:
+
+```
+.
+../repo.feature-a
+   repo.feature-b
+./.worktrees/spike
+             docs
+../scratch/hotfix
+```
+
+With `--tree`, the Path column is kept at the priority it gets when a worktree sits outside its configured location, rather than yielding to other columns. JSON output is unaffected.
+
+### Own branches
+
+On a clone shared by several people or bots, `--only-mine` keeps the rows that belong to the current git user (`user.email`, compared case-insensitively). A branch counts when its tip commit was authored by that user, or when most of its commits since the merge base with the default branch were. The main worktree is always listed. The filter applies to `--branches` and `--remotes` rows as well, and to JSON output.
+
 ### LLM summaries [experimental]
 
 Reuses the [`commit.generation`](@/config.md#commit) command — the same LLM that generates commit messages. Enable with `summary = true` in `[list]` config; requires `--full`. Results are cached until the branch's diff changes.
@@ -874,9 +1310,18 @@ template = "{{ vars.ticket }}"
 
 A column that renders empty for every row is dropped from the table. Templates, widths, and drop priority: [custom columns config](@/config.md#custom-columns).
 
+A project can define columns backed by a command in `.config/wt.toml`. The command runs in each worktree, in parallel, and the first line of its output is the cell; branches without a worktree show an empty cell:
+
+```toml
+[list.columns.TODOs]
+command = "git grep -c TODO | awk -F: '{n += $2} END {print n}'"
+```
+
+Like hooks, these commands need [approval](@/hook.md#security) — `wt list` asks on a terminal, and otherwise skips unapproved columns with a warning. A command that fails or exceeds `timeout-ms` (default 5000) leaves its cell empty. `width` (default 10) is reserved up front, since values arrive after the table is drawn. A user template column with the same header takes precedence.
+
 ## Status symbols
 
-The Status column packs several subcolumns, left to right, each mapping to a field in `--format=json`. Working-tree flags are independent and co-occur — any combination shows at once. The other subcolumns are mutually exclusive: each shows a single symbol, the highest-priority state in top-to-bottom table order, and is blank when nothing applies.
+The Status column packs several subcolumns, left to right, each mapping to a field in `--format=json`. Working-tree flags are independent and co-occur — any combination shows at once. The other subcolumns are mutually exclusive: each shows a single symbol, the highest-priority state in top-to-bottom table order, and is blank when nothing applies. With [`output.ascii`](@/config.md#ascii-output), each symbol is drawn as an ASCII character instead.
 
 ### Working tree
 
@@ -892,13 +1337,14 @@ Independent flags from `git status`; several can show at once (e.g. `+!?`). Each
 
 ### Worktree
 
-An in-progress git operation, a worktree-location attribute, or a branch with no worktree. One symbol shows, highest priority first (`✘ > ⤴ > ⤵ > ⚑ > ⊟ > ⊞ > /`):
+An in-progress git operation, a worktree-location attribute, or a branch with no worktree. One symbol shows, highest priority first (`✘ > ⤴ > ⤵ > ↯ > ⚑ > ⊟ > ⊞ > /`):
 
 | Symbol | JSON | Meaning |
 |--------|------|---------|
-| `✘` | `operation_state` `"conflicts"` | Merge conflicts |
+| `✘` | `operation_state` `"conflicts"` | Merge conflicts, with the number of conflicted files (e.g. `✘3`) |
 | `⤴` | `operation_state` `"rebase"` | Rebase in progress |
 | `⤵` | `operation_state` `"merge"` | Merge in progress |
+| `↯` | `operation_state` `"interrupted_merge"` | `wt merge` stopped partway; see [resuming](@/merge.md#resuming-a-failed-merge) |
 | `⚑` | `worktree.state` `"branch_worktree_mismatch"` | Branch name doesn't match the worktree path |
 | `⊟` | `worktree.state` `"prunable"` | Prunable (worktree directory missing) |
 | `⊞` | `worktree.state` `"locked"` | Locked worktree |
@@ -932,6 +1378,7 @@ Relation to the tracking branch, derived from the `remote.ahead` / `remote.behin
 | `⇡` | `ahead` > 0 | Ahead of remote |
 | `⇣` | `behind` > 0 | Behind remote |
 | `⇅` | `ahead` > 0, `behind` > 0 | Diverged from remote |
+| `⊘` | `gone` | Upstream deleted from the remote (`[gone]` in `git branch -vv`) |
 
 ### Placeholder symbols
 
@@ -941,6 +1388,31 @@ These appear across all columns while the table is loading:
 |--------|---------|
 | `·` | Data is loading, or collection timed out / branch too stale |
 
+## Changes since the last run
+
+`--changed` prints only the rows that differ from the previous `wt list --changed` — handy for polling a fleet of agent worktrees:
+
+```console
+$ wt list --changed
++ feature-auth  +  ↑1
+~ fix-typo  !  ↑2  (new commits, now dirty)
+- old-spike
+```
+
+`+` marks a new row, `-` a row that's gone, and `~` a row with new commits or a working tree that turned dirty or clean. Each row shows its compact statusline. The first run has nothing to compare against, so every row is new; plain `wt list` doesn't touch the stored snapshot.
+
+With `--format=json`, each entry has `change` (`added`, `changed`, `removed`), `reasons` (`commits`, `dirty`, `clean`), `branch`, `path`, and — except for removed rows — the full `item`.
+
+## Repository groups
+
+`--group` lists every repository of a [`[repo-groups]`](@/config.md#repository-groups) entry in user config, one table per repository under its name:
+
+```console
+$ wt list --group backend
+```
+
+With `--format=json`, the output is an array with one object per repository: `repo` (the group entry), `path`, and `worktrees` (the rows `wt list --format=json` prints for it).
+
 ---
 
 ## JSON output
@@ -973,6 +1445,12 @@ $ wt list --format=json | jq '.[] | select(.remote.ahead > 0) | {branch, ahead:
 $ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 ```
 
+For status bar widgets (waybar, polybar, sketchybar), write a snapshot from cron or a loop with `--output`; `--atomic` writes a temporary file and renames it into place, so a widget reading the file never sees a partial write:
+
+```console
+$ wt list --format=json --output ~/.cache/wt-list.json --atomic
+```
+
 **Fields:**
 
 | Field | Type | Description |
@@ -984,7 +1462,7 @@ $ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `working_tree` | object | Working tree state (see below) |
 | `main_state` | string | Relation to the default branch (see below) |
 | `integration_reason` | string | Why branch is integrated (see below) |
-| `operation_state` | string | `"conflicts"`, `"rebase"`, or `"merge"` (see [Worktree](#worktree)); absent when clean |
+| `operation_state` | string | `"conflicts"`, `"rebase"`, `"merge"`, or `"interrupted_merge"` (see [Worktree](#worktree)); absent when clean |
 | `main` | object | Relationship to the default branch (see below); absent when is_main |
 | `remote` | object | Tracking branch info (see below); absent when no tracking |
 | `worktree` | object | Worktree metadata (see below) |
@@ -992,6 +1470,9 @@ $ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `is_current` | boolean | Is the current worktree |
 | `is_previous` | boolean | Previous worktree from wt switch |
 | `ci` | object | CI status (see below); `--full` only, then absent when no PR/MR or branch workflow |
+| `base` | object | Re-cut or deleted base (see below); `--full` only, then absent while the base holds |
+| `hooks` | array | Last run of each background hook pipeline (see below); `--full` only, then absent when none is recorded |
+| `stashes` | number | Stash entries made on the branch; `--full` only, then absent when there are none |
 | `repo_url` | string | Repository web URL derived from the primary remote; absent when the remote URL cannot be parsed |
 | `repo` | object | Structured repository metadata (see below); includes `remote` |
 | `url` | string | Dev server URL from project config; absent when not configured |
@@ -1010,6 +1491,7 @@ $ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `short_sha` | string | Short commit SHA, abbreviated per `core.abbrev` (auto-extends for ambiguous prefixes) |
 | `message` | string | Commit message (first line) |
 | `timestamp` | number | Unix timestamp |
+| `date` | string | ISO 8601 commit time; `--absolute-times` only |
 
 ### working_tree object
 
@@ -1022,6 +1504,7 @@ The five change flags map to the [Working tree](#working-tree) symbols (`renamed
 | `untracked` | boolean | Has untracked files |
 | `renamed` | boolean | Has renamed files |
 | `deleted` | boolean | Has deleted files |
+| `conflicted_files` | number | Files with unresolved merge conflicts (absent when none) |
 | `diff` | object | Lines changed vs HEAD: `{added, deleted}` |
 
 ### main object
@@ -1031,6 +1514,7 @@ The five change flags map to the [Working tree](#working-tree) symbols (`renamed
 | `ahead` | number | Commits ahead of the default branch |
 | `behind` | number | Commits behind the default branch |
 | `diff` | object | Lines changed vs the default branch: `{added, deleted}` |
+| `merge_base` | string | Merge-base commit SHA with the default branch; absent for orphan branches |
 
 ### remote object
 
@@ -1040,8 +1524,10 @@ The five change flags map to the [Working tree](#working-tree) symbols (`renamed
 |-------|------|-------------|
 | `name` | string | Remote name (e.g., `"origin"`) |
 | `branch` | string | Remote branch name |
+| `ref` | string | Upstream tracking ref (e.g., `"origin/feature"`) |
 | `ahead` | number | Commits ahead of remote |
 | `behind` | number | Commits behind remote |
+| `gone` | boolean | Upstream deleted from the remote; `ahead` and `behind` are 0. Absent otherwise |
 
 ### worktree object
 
@@ -1052,6 +1538,7 @@ Present only for worktree-kind items. `state` is the worktree-location attribute
 | `state` | string | `"branch_worktree_mismatch"`, `"prunable"`, or `"locked"` (absent when normal) |
 | `reason` | string | Reason for locked/prunable state |
 | `detached` | boolean | HEAD is detached |
+| `disk_usage` | number | Bytes on disk for the checked-out files; `--size` only |
 
 ### ci object
 
@@ -1066,6 +1553,26 @@ Present only for worktree-kind items. `state` is the worktree-location attribute
 | `repo` | object | Structured metadata for the repository the PR/MR targets; never includes `remote` |
 | `review_state` | string | Review state (see below); absent when the forge reports no review signal |
 
+### base object
+
+| Field | Type | Description |
+|-------|------|-------------|
+| `ref` | string | Ref the branch was created from (e.g. `"origin/release/1.2"`) |
+| `sha` | string | Commit the branch started from |
+| `state` | string | `"rewritten"` (force-pushed or re-cut) or `"deleted"` |
+
+### hooks array
+
+One entry per `(hook type, source)` pipeline, the same records `wt hook status --format=json` prints.
+
+| Field | Type | Description |
+|-------|------|-------------|
+| `hook_type` | string | Hook type (e.g. `"post-start"`) |
+| `source` | string | `"user"` or `"project"` |
+| `state` | string | Most severe command state: `"failed"`, `"running"`, `"pending"`, `"skipped"`, or `"succeeded"` |
+| `started_at` | number | Unix timestamp when the run started |
+| `commands` | array | Each command in order: `name`, `state`, and, once finished, `duration_ms` and `exit_code` |
+
 ### repo object
 
 Top-level `repo` describes the local checkout's repository as derived from the primary remote. `ci.repo` describes the repository targeted by the PR/MR URL in `ci.url` (for fork PRs, this is the upstream target). Existing `repo_url` and `ci.repo_url` fields remain available and carry the same URL as `repo.url` / `ci.repo.url`.
@@ -1102,6 +1609,42 @@ The [CI status](#ci-status) section above is the single source for both fields:
 
 Missing a field that would be generally useful? Open an issue at https://github.com/max-sixty/worktrunk.
 
+## Porcelain output
+
+For scripts that shouldn't depend on `jq`, `--porcelain` prints one line per row, in the same order as the table:
+
+```text
+branch<TAB>path<TAB>ahead<TAB>behind<TAB>flags
+```
+
+| Field | Value |
+|-------|-------|
+| `branch` | Branch name, or `-` for a detached worktree |
+| `path` | Worktree path, or `-` for a branch without a worktree |
+| `ahead` / `behind` | Commits ahead of / behind the default branch, or `-` for the default branch itself |
+| `flags` | Comma-separated flags, or `-` when none apply |
+
+Flags, in this order: `current`, `previous`, `main`, `detached`, `dirty`, the `operation_state` value (`conflicts`, `rebase`, `merge`, `interrupted_merge`), `integrated` (`main_state` is `integrated` or `empty`), and the `worktree.state` value when `locked` or `prunable`.
+
+The format is stable across versions: fields keep their order and meaning, and flag names don't change. New flags may be added, so match them individually:
+
+```console
+$ wt list --porcelain | while IFS=$'\t' read -r branch path ahead behind flags; do
+    case ",$flags," in *,integrated,*) echo "$branch" ;; esac
+  done
+```
+
+## Summary line
+
+`--summary` condenses the listing to one line — small enough to embed in an agent's system prompt or a dashboard:
+
+```console
+$ wt list --summary
+5 worktrees: 2 dirty, 1 rebasing, 3 ahead of main
+```
+
+Only worktrees count, and categories at zero are left out: `dirty`, `with conflicts`, `rebasing`, `merging`, `ahead of <default>`, `behind <default>`, and, with `--full`, `with failing CI`. Each comes from the same fields as `--format=json`. Without `--full` it skips CI and other network calls, so it's cheap enough to run on every prompt.
+
 ## See also
 
 - [`wt switch`](@/switch.md) — Switch worktrees or open interactive picker
@@ -1175,7 +1718,7 @@ Worktrunk has two force flags for different situations:
 
 | Flag | Scope | When to use |
 |------|-------|-------------|
-| `--force` (`-f`) | Worktree | Worktree has uncommitted changes |
+| `--force` (`-f`) | Worktree | Worktree has uncommitted changes or running processes |
 | `--force-delete` (`-D`) | Branch | Branch has unmerged commits |
 
 ```console
@@ -1186,6 +1729,37 @@ $ wt remove feature --force -D    # Both
 
 Use `--no-delete-branch` to keep the branch regardless of merge status.
 
+## Unmerged branches
+
+When a worktree's branch isn't integrated, `wt remove` warns how many commits it has that the target lacks. In a terminal, it then offers to:
+
+1. Merge it first — skips the removal and suggests `wt merge`
+2. Archive it as a bundle under `.git/wt/bundles/`, then delete it
+3. Delete it anyway, as with `-D`
+
+Enter, `--yes`, and non-interactive runs keep the branch. Bundles are named after the branch and its tip commit, so archiving a branch again keeps the earlier archive. Restore an archived branch with `git fetch .git/wt/bundles/<branch>-<sha>.bundle <branch>:<branch>`.
+
+## Uncommitted changes
+
+Rather than `--force`, which deletes a dirty worktree's changes along with it, pick what happens to them:
+
+```console
+$ wt remove feature --stash      # Save changes as a stash, then remove
+$ wt remove feature --discard    # Show the diffstat, confirm, then remove
+```
+
+`--stash` stashes staged, modified, and untracked files as `wt remove: <branch>` and records the stash's SHA under `wt config state list --ns removed-stash`; bring the changes back with `git stash apply <sha>`. `--discard` asks before throwing anything away (`--yes` skips the prompt). Both apply only to worktrees that are actually dirty, and only once every other check has passed and hooks are approved — a worktree that running processes or an unmerged-branch prompt keep in place keeps its changes too.
+
+## Stashes
+
+Stashes outlive their branch, but only their subject (`On feature: …`) ties them to it. Before deleting a branch that stashes were made on, `wt remove` lists them and offers to export each as a patch under `.git/wt/stashes/<branch>/`; `--yes` exports without asking. `wt list --full` counts each branch's stashes.
+
+## Running processes
+
+Removal is refused while any process has its working directory inside the worktree — a dev server, an agent, another shell — since it would be left running in a deleted directory. The error lists each PID and command; stop them, or pass `--force` to remove anyway. The shell running `wt remove` itself doesn't count. Detection uses `/proc` on Linux and `lsof` on macOS; it's skipped on Windows.
+
+Processes the branch's background hooks started — a dev server launched by `post-start` — don't block removal. After `pre-remove` hooks run, `wt remove` sends SIGTERM to the process group of each hook pipeline that is still running and waits up to 5 seconds before SIGKILL. Pipelines that already finished are left alone, so a process a hook backgrounded and then returned from counts as running. A process that detached into its own session (`setsid`) is out of reach and still counts as running.
+
 ## Background removal
 
 Removal runs in the background by default — the command returns immediately. The worktree is renamed into `.git/wt/trash/` (instant same-filesystem rename), git metadata is pruned, the branch is deleted, and a detached `rm -rf` finishes cleanup. Cross-filesystem worktrees fall back to `git worktree remove`. Logs: `.git/wt/logs/{branch}/internal/remove.log`. Use `--foreground` to run in the foreground.
@@ -1213,6 +1787,8 @@ Detached worktrees have no branch name. Pass the worktree path instead: `wt remo
     #[command(
         after_long_help = r#"Unlike `git merge`, this merges the current branch into the target branch — not the target into current. Similar to clicking "Merge pull request" on GitHub, but locally. The target defaults to the default branch.
 
+The target needn't be checked out anywhere: without a worktree, the branch ref is updated in place. A target that exists only on a remote gets a local branch tracking it first. Tags and commits can't be targets — there's no branch to update.
+
 <!-- demo: wt-merge.gif 1600x900 -->
 ## Examples
 
@@ -1271,32 +1847,75 @@ $ wt merge --no-commit
 1. **Commit** — Pre-commit hooks run, then uncommitted changes are committed. Post-commit hooks run in background. Skipped when squashing (the default) — changes are staged during the squash step instead. With `--no-squash`, this is the only commit step.
 2. **Squash** — Combines all commits since target into one (like GitHub's "Squash and merge"). Use `--stage` to control what gets staged: `all` (default), `tracked`, or `none`. A backup ref is saved to `refs/wt-backup/<branch>`. With `--no-squash`, individual commits are preserved.
 3. **Rebase** — Rebases onto target if behind. Skipped if already up-to-date. Conflicts abort immediately.
-4. **Pre-merge hooks** — Hooks run after rebase, before merge. Failures abort. See [`wt hook`](@/hook.md).
-5. **Merge** — Fast-forward merge to the target branch. With `--no-ff`, a merge commit is created instead — semi-linear history with rebased commits plus a merge commit. Non-fast-forward merges are rejected.
-6. **Pre-remove hooks** — Hooks run before removing worktree. Failures abort.
-7. **Cleanup** — Removes the worktree and branch. Use `--no-remove` to keep the worktree. When already on the target branch or in the primary worktree, the worktree is preserved.
-8. **Post-remove + post-merge hooks** — Run in background after cleanup.
+4. **Verify** — The `[merge] verify` command from [user config](@/config.md#merge) runs in the worktree, e.g. `cargo test`, and its time is reported. Failures abort. `--no-verify` skips it; `--no-hooks` doesn't.
+5. **Pre-merge hooks** — Hooks run after rebase, before merge. Failures abort. See [`wt hook`](@/hook.md).
+6. **Pre-push hooks** — Hooks run once the commits landing on the target are known (`{{ commit_range }}`), right before the target branch moves. Failures abort with the target untouched. Skipped when the target is already up to date.
+7. **Merge** — Fast-forward merge to the target branch. With `--no-ff`, a merge commit is created instead — semi-linear history with rebased commits plus a merge commit. Non-fast-forward merges are rejected.
+8. **Pre-remove hooks** — Hooks run before removing worktree. Failures abort.
+9. **Cleanup** — Removes the worktree and branch. Use `--no-remove` to keep the worktree. When already on the target branch or in the primary worktree, the worktree is preserved.
+10. **Post-remove + post-merge hooks** — Run in background after cleanup.
+11. **Rebase other worktrees** — With `merge.rebase-others` set to `prompt` or `auto` in [user config](@/config.md#merge), clean worktrees now behind the target are rebased onto it in parallel. Worktrees with uncommitted changes, and ones whose rebase conflicts, are left as they were and listed for a manual rebase. Off by default.
 
 Use `--no-commit` to skip committing uncommitted changes and squashing; rebase still runs by default and can rewrite commits unless `--no-rebase` is passed. Useful after preparing commits manually with `wt step commit`. Requires a clean working tree.
 
-## Local CI
+## Resuming a failed merge
 
-For personal projects, pre-merge hooks open up the possibility of a workflow with much faster iteration — an order of magnitude more small changes instead of fewer large ones.
+Each step is recorded in a journal as it runs. When a step fails — a rebase conflict, a failing pre-merge hook, a rejected fast-forward — the journal keeps the failed step, and `wt list` shows `↯` for the branch until the merge is resumed or rolled back. Another `wt merge` on the branch refuses to start over while the journal is pending.
 
-Historically, ensuring tests ran before merging was difficult to enforce locally. Remote CI was valuable for the process as much as the checks: it guaranteed validation happened. `wt merge` brings that guarantee local.
+```console
+$ wt merge --continue   # Resume from the failed step
+$ wt merge --abort      # Restore the branch to where the merge started
+```
 
-The full workflow: start an agent (one of many) on a task, work elsewhere, return when it's ready. Review the diff, run `wt merge`, move on. Pre-merge hooks validate before merging — if they pass, the branch goes to the default branch and the worktree cleans up.
+`--continue` replays the original target and flags; completed commit and squash steps aren't repeated. After a rebase conflict, finish the rebase with `git rebase --continue` first. `--abort` stops an in-progress rebase, resets the branch to its pre-merge commit with `git reset --keep`, and restores uncommitted changes from the backup ref saved when the merge started. Once the target branch has been updated, abort is refused — `--continue` finishes the cleanup instead.
 
-```toml
-[[pre-merge]]
-test = "cargo test"
-lint = "cargo clippy"
-```
+To undo a merge that completed, [`wt step revert-merge`](@/step.md#wt-step-revert-merge) reverts it on the target and can bring the branch back with `--restore`.
 
-## See also
+## Stacked branches
 
-- [`wt step`](@/step.md) — Run individual operations (commit, squash, rebase, push)
-- [`wt remove`](@/remove.md) — Remove worktrees without merging
+A branch cut from another feature branch carries that branch's commits. Before merging, `wt merge` checks where the branch was cut (recorded by `wt switch --create --base`, else the branch's reflog); when that parent has commits the target lacks — it hasn't landed yet, or landed as a squash — merging would bring them along. `wt merge` asks how to proceed, or fails without a terminal; `--stacked-base` chooses up front:
+
+| Mode | Effect |
+|------|--------|
+| `rebase` | Rebase onto the target without the parent's commits (`git rebase --onto <target> <base>`) |
+| `parent` | Merge into the parent branch instead |
+| `include` | Merge everything, the parent's commits included |
+
+```console
+$ wt merge --stacked-base=rebase
+```
+
+Once the parent lands with its commits intact, the branch merges without asking.
+
+## Merge queue
+
+Parallel merges into one target race: each rebases onto the tip it saw, and the second fast-forward is rejected. With `--queue`, merges into the same target take turns — each waits for those queued ahead to finish, then rebases onto the latest target, runs pre-merge hooks, and updates the target before the next starts. Committing and squashing happen before joining the queue; removal after leaving it.
+
+```console
+$ wt merge --queue
+◎ Waiting for feature-auth to merge into main
+```
+
+The queue is local to the repository, under `.git/wt/merge-queue/`. A merge that exits early — a failing hook, Ctrl-C — leaves the queue, and `wt merge --continue --queue` rejoins at the back.
+
+## Local CI
+
+For personal projects, pre-merge hooks open up the possibility of a workflow with much faster iteration — an order of magnitude more small changes instead of fewer large ones.
+
+Historically, ensuring tests ran before merging was difficult to enforce locally. Remote CI was valuable for the process as much as the checks: it guaranteed validation happened. `wt merge` brings that guarantee local.
+
+The full workflow: start an agent (one of many) on a task, work elsewhere, return when it's ready. Review the diff, run `wt merge`, move on. Pre-merge hooks validate before merging — if they pass, the branch goes to the default branch and the worktree cleans up.
+
+```toml
+[[pre-merge]]
+test = "cargo test"
+lint = "cargo clippy"
+```
+
+## See also
+
+- [`wt step`](@/step.md) — Run individual operations (commit, squash, rebase, push)
+- [`wt remove`](@/remove.md) — Remove worktrees without merging
 - [`wt switch`](@/switch.md) — Navigate to other worktrees
 "#
     )]
@@ -1349,12 +1968,16 @@ $ wt step push
 - `rebase` — Rebase onto target branch
 - `push` — Fast-forward target to current branch
 - [`diff`](#wt-step-diff) — Show all changes since branching (committed, staged, unstaged, untracked)
+- [`diff-branches`](#wt-step-diff-branches) — Diff two worktrees, uncommitted changes included
 - [`copy-ignored`](#wt-step-copy-ignored) — Copy gitignored files between worktrees
+- [`clone`](#wt-step-clone) — [experimental] Clone a repository that borrows objects from another clone, or fetches them on demand
 - [`eval`](#wt-step-eval) — [experimental] Evaluate a template expression
 - [`for-each`](#wt-step-for-each) — [experimental] Run a command in every worktree
+- [`optimize-repo`](#wt-step-optimize-repo) — [experimental] Write the commit-graph and multi-pack-index
 - [`promote`](#wt-step-promote) — [experimental] Swap a branch into the main worktree
 - [`prune`](#wt-step-prune) — Remove worktrees and branches merged into the default branch
 - [`relocate`](#wt-step-relocate) — [experimental] Move worktrees to expected paths
+- [`revert-merge`](#wt-step-revert-merge) — [experimental] Back out a merged branch
 - [`tether`](#wt-step-tether) — [experimental] Run a command; kill its whole process tree when its worktree is removed
 - [`<alias>`](@/extending.md#aliases) — Run a configured command alias
 
@@ -1366,12 +1989,16 @@ $ wt step push
 <!-- subdoc: commit -->
 <!-- subdoc: squash -->
 <!-- subdoc: diff -->
+<!-- subdoc: diff-branches -->
 <!-- subdoc: copy-ignored -->
+<!-- subdoc: clone -->
 <!-- subdoc: eval -->
 <!-- subdoc: for-each -->
+<!-- subdoc: optimize-repo -->
 <!-- subdoc: promote -->
 <!-- subdoc: prune -->
 <!-- subdoc: relocate -->
+<!-- subdoc: revert-merge -->
 <!-- subdoc: tether -->"#
     )]
     Step {
@@ -1392,9 +2019,10 @@ $ wt step push
 | **create** | `pre-start` | `post-start` |
 | **commit** | `pre-commit` | `post-commit` |
 | **merge** | `pre-merge` | `post-merge` |
+| **push** | `pre-push` | — |
 | **remove** | `pre-remove` | `post-remove` |
 
-`pre-*` hooks block — failure aborts the operation. `post-*` hooks run in the background with output logged (use [`wt config state logs`](@/config.md#wt-config-state-logs) to find and manage log files). Use `-v` to see the template variables for background hooks; `wt hook <type> --dry-run` previews the commands.
+`pre-*` hooks block — failure aborts the operation. `post-*` hooks run in the background with output logged (use [`wt config state logs`](@/config.md#wt-config-state-logs) to find and manage log files); `wt hook status` shows how each command's last run ended. Use `-v` to see the template variables for background hooks; `wt hook <type> --dry-run` previews the commands.
 
 The most common creation hook is `post-start` — it runs background tasks (dev servers, file copying, builds) without blocking worktree creation. Prefer `post-start` over `pre-start` unless a later step needs the work completed first.
 
@@ -1408,10 +2036,11 @@ The most common creation hook is `post-start` — it runs background tasks (dev
 | `post-commit` | CI triggers, notifications, background linting |
 | `pre-merge` | Tests, security scans, build verification — runs after rebase, before merge to target |
 | `post-merge` | Deployment, notifications, installing updated binaries. Runs in the target branch worktree if it exists, otherwise the primary worktree |
+| `pre-push` | Policy checks on exactly the commits about to land — commit message format, ticket references, linting `{{ commit_range }}`. Runs right before the target branch moves in `wt merge` and `wt step push` |
 | `pre-remove` | Cleanup before worktree deletion: saving test artifacts, backing up state. Runs in the worktree being removed |
 | `post-remove` | Stopping dev servers, removing containers, notifying external systems. Template variables reference the removed worktree |
 
-During `wt merge`, hooks run in this order: pre-commit → post-commit → pre-merge → pre-remove → post-remove + post-merge. See [`wt merge`](@/merge.md#pipeline) for the complete pipeline.
+During `wt merge`, hooks run in this order: pre-commit → post-commit → pre-merge → pre-push → pre-remove → post-remove + post-merge. See [`wt merge`](@/merge.md#pipeline) for the complete pipeline.
 
 # Security
 
@@ -1436,7 +2065,17 @@ Project commands require approval on first run:
 - Use `--yes` to bypass prompts — useful for CI and automation
 - Use `--no-hooks` to skip hooks
 
-Manage approvals with `wt config approvals add` and `wt config approvals clear`.
+Manage approvals with `wt config approvals add` and `wt config approvals clear`. To share them with a team, `wt config approvals export` prints the project's approved commands with their hashes, and teammates approve the same commands with `wt config approvals import`.
+
+Without a terminal to prompt on (CI, agents, SSH sessions), an unapproved command fails the run, and the error lists each command's hash. Approve just those commands with `--approve <hash>` (repeatable), or set `WORKTRUNK_APPROVE`:
+
+| Value | Effect |
+|-------|--------|
+| `never` | Decline unapproved commands without asking; the operation continues without them |
+| `all` | Approve everything, like `--yes` |
+| `hash:<hash>,…` | Approve the commands with these hashes, same as `--approve` |
+
+A command's hash covers its template, so an edited command needs a new hash. Approvals given this way aren't saved.
 
 # Configuration
 
@@ -1477,6 +2116,29 @@ Templates are syntax-checked before the pipeline starts and rendered as each ste
 
 Most hooks don't need `[[hook]]` blocks. Reach for them when there's a dependency chain — typically setup that must complete before later steps, like installing dependencies before running a build and dev server concurrently.
 
+## Conditional commands
+
+A named command can be a table with `command` and `when-changed`. It then runs only when at least one of the listed files differs from the base branch — `{{ base }}` on `wt switch --create`, otherwise the default branch:
+
+```toml
+[post-start]
+install = { command = "npm ci", when-changed = ["package-lock.json"] }
+```
+
+When every listed file matches, the command is skipped with a note. Naming the command — `wt hook post-start install` — runs it regardless.
+
+## Working directory
+
+Hooks run in the worktree they belong to. A named command's table can set `cwd` to run elsewhere — a template, resolved against the worktree when relative:
+
+```toml
+[pre-merge]
+deploy-check = { command = "./check.sh", cwd = "infra" }
+sync = { command = "make sync", cwd = "{{ repo_path }}" }
+```
+
+The directory must exist when the command runs; otherwise the hook fails before running it. `{{ cwd }}` and the JSON context on stdin report the directory the command runs in.
+
 ## Project vs user hooks
 
 | Aspect | Project hooks | User hooks |
@@ -1504,6 +2166,10 @@ Hooks can use template variables that expand at runtime:
 |           | `{{ base_worktree_path }}`    | Base worktree path |
 |           | `{{ target }}`                | Target branch name |
 |           | `{{ target_worktree_path }}`  | Target worktree path (when target has a worktree) |
+|           | `{{ target_commit }}`         | Target branch SHA before the push (pre-push only) |
+|           | `{{ commit_range }}`          | Commits being pushed, as `target_commit..commit` (pre-push only) |
+|           | `{{ changed_files }}`         | Files being committed or merged, shell-escaped and space-separated (pre-commit, pre-merge) |
+|           | `{{ changed_files_json }}`    | The same files as a JSON array (pre-commit, pre-merge) |
 |           | `{{ pr_number }}`             | PR/MR number (post-switch, pre-start, post-start; when creating via `pr:N` / `mr:N`) |
 |           | `{{ pr_url }}`                | PR/MR web URL (post-switch, pre-start, post-start; when creating via `pr:N` / `mr:N`) |
 | repo      | `{{ repo }}`                  | Repository directory name |
@@ -1513,6 +2179,9 @@ Hooks can use template variables that expand at runtime:
 |           | `{{ default_branch }}`        | Default branch name |
 |           | `{{ remote }}`                | Primary remote name |
 |           | `{{ remote_url }}`            | Remote URL |
+| jj        | `{{ change_id }}`             | Change ID of the working-copy commit (jj workspaces only) |
+|           | `{{ bookmark }}`              | Nearest bookmark on the working copy or its ancestors (jj workspaces only) |
+|           | `{{ workspace }}`             | jj workspace name (jj workspaces only) |
 | exec      | `{{ cwd }}`                   | Directory where the hook command runs |
 |           | `{{ hook_type }}`             | Hook type being run (e.g. `pre-start`, `pre-merge`) |
 |           | `{{ hook_name }}`             | Hook command name (if named) |
@@ -1521,6 +2190,17 @@ Hooks can use template variables that expand at runtime:
 
 The `repo` variables (`repo`, `repo_path`, `owner`, `primary_worktree_path`, `default_branch`, `remote`, `remote_url`) are constant across the whole repository — `default_branch` is the same in every worktree. The `active` variables (`branch`, `worktree_path`, `worktree_name`, `commit`, `short_commit`, `upstream`) vary per worktree.
 
+`changed_files` lets a hook check only what changed instead of the whole repository. In pre-commit it's what the commit will record (per the [stage mode](@/config.md#commit)); in pre-merge it's everything the branch changed since it forked from the target. Paths are relative to the worktree root, and deleted files are left out:
+
+```toml
+[pre-commit]
+lint = "npx eslint --no-warn-ignored {{ changed_files }}"
+```
+
+An empty list renders as nothing, so guard commands that lint everything when given no paths: `{% if changed_files %}ruff check {{ changed_files }}{% endif %}`.
+
+The `jj` variables describe the worktree where the command runs (`cwd`) when it's a colocated [jj](https://jj-vcs.github.io/jj/) workspace, read through the `jj` CLI. Everywhere else they're empty strings rather than undefined, so one config can serve git and jj users — `{{ bookmark or branch }}` picks whichever applies.
+
 Bare variables (`branch`, `worktree_path`, `commit`) refer to the branch the operation acts on: the destination for switch/create, the source for merge/remove. `base` and `target` give the other side:
 
 | Operation | Bare vars | `base` | `target` |
@@ -1528,6 +2208,7 @@ Bare variables (`branch`, `worktree_path`, `commit`) refer to the branch the ope
 | switch/create | destination | where you came from | = bare vars |
 | commit (during merge/squash) | worktree being squashed | = bare vars | integration target |
 | merge | feature being merged | = bare vars | merge target |
+| push (pre-push) | branch being pushed | = bare vars | push target |
 | remove | branch being removed | = bare vars | where you end up |
 
 All hooks share the same perspective — `{{ branch | hash_port }}` produces the same port in `post-start` and `post-remove`.
@@ -1665,12 +2346,15 @@ $ wt hook pre-merge user:        # Run all user hooks
 $ wt hook pre-merge project:     # Run all project hooks
 $ wt hook pre-merge user:test    # Run only user's "test" hook
 $ wt hook pre-merge --yes        # Skip approval prompts (for CI)
+$ wt hook post-start install --all        # Re-run "install" in every worktree
 $ wt hook pre-start --branch=feature/test    # Override a template variable
 $ wt hook pre-merge -- --extra args     # Forward tokens into {{ args }}
 ```
 
 The `user:` and `project:` prefixes filter by source. Use `user:` or `project:` alone to run all hooks from that source, or `user:name` / `project:name` to run a specific hook.
 
+`--all` runs the hook in every worktree — useful for re-provisioning existing worktrees after dependencies or tooling change. Commands come from the current worktree's config and are approved once; each worktree gets its own `{{ branch }}`, `{{ worktree_path }}`, and working directory. Background hooks (`post-*`) spawn in every worktree at once, each logging to its own file — this is the parallel path. `pre-*` hooks and `--foreground` stream output live to the terminal, so they run one worktree at a time to keep each worktree's output together; they continue past failures and summarize which worktrees failed.
+
 <!-- wt hook pre-merge (docs-example) -->
 ```console
 $ wt hook pre-merge
@@ -1808,6 +2492,7 @@ Controls where new worktrees are created.
 - `{{ branch | sanitize }}` — filesystem-safe: `/` and `\` become `-` (e.g., `feature-auth`)
 - `{{ branch | sanitize_db }}` — database-safe: lowercase, underscores, hash suffix (e.g., `feature_auth_x7k`)
 - `{{ branch | codename(2) }}` — deterministic friendly name from a ~1.26M-combo pool (e.g., `malleable-opah`)
+- `{{ change_id }}`, `{{ bookmark }}`, `{{ workspace }}` — always empty here, since the worktree doesn't exist yet; defined so templates shared with jj-aware hooks render
 
 This is a smaller set than [the variables hooks and aliases get](@/hook.md#template-variables).
 
@@ -1857,6 +2542,8 @@ worktree-path = "{{ repo_path }}/../{{ branch | sanitize }}"
 
 `~` expands to the home directory. Relative paths resolve from `repo_path`.
 
+Changing `worktree-path` doesn't move existing worktrees; `wt list` flags them with `⚑`. `wt step relocate --dry-run` previews the new paths, and `wt step relocate` moves them.
+
 ## LLM commit messages
 
 Generate commit messages automatically during merge. Requires an external CLI tool.
@@ -1908,7 +2595,7 @@ Persistent flag values for `wt list`. Override on command line as needed.
 [list]
 summary = false    # Enable LLM branch summaries (requires [commit.generation])
 
-full = false       # Show CI status and LLM summaries (--full)
+full = false       # Show CI status, re-cut bases, hook results, stash counts, and LLM summaries (--full)
 branches = false   # Include branches without worktrees (--branches)
 remotes = false    # Include remote-only branches (--remotes)
 
@@ -1916,6 +2603,9 @@ columns = ["branch", "status", "ci", "path"]   # Columns to show, in order — b
 
 task-timeout-ms = 0   # Kill individual git commands after N ms; 0 disables
 timeout-ms = 0        # Wall-clock budget for the entire collect phase; 0 disables
+
+branch-width = 40     # Cap the Branch column; longer names become `feature/lo…PROJ-1234`
+path-width = 40       # Cap the Path column, shortened the same way
 ```
 
 `columns` selects and orders the columns to render; omit it for the default set.
@@ -1934,6 +2624,9 @@ Valid built-in names:
 - `summary` — An LLM-generated summary of the branch
 - `upstream` — Commits ahead of and behind the upstream tracking branch (header `Remote⇅`)
 - `ci` — CI status of the head commit
+- `base` — The branch's base, when it was re-cut or deleted
+- `hooks` — Outcome of the last background hook runs
+- `stash` — Number of stash entries made on the branch
 - `path` — The worktree's path
 - `url` — Dev-server URL from the `[list] url` template
 - `commit` — The head commit's short hash
@@ -2003,6 +2696,7 @@ Shared by `wt step commit`, `wt step squash`, and `wt merge`.
 ```toml
 [commit]
 stage = "all"      # What to stage before commit: "all", "tracked", or "none"
+squash-trailers = ["Co-authored-by"]  # Trailers kept when squashing
 ```
 
 ### Merge
@@ -2015,8 +2709,9 @@ squash = true      # Squash commits into one (--no-squash to preserve history)
 commit = true      # Commit uncommitted changes first (--no-commit to skip)
 rebase = true      # Rebase onto target before merge (--no-rebase to skip)
 remove = true      # Remove worktree after merge (--no-remove to keep)
-verify = true      # Run project hooks (--no-hooks to skip)
+verify = true      # Run project hooks (--no-hooks to skip); a command such as "cargo test" also runs it after rebasing (--no-verify to skip)
 ff = true          # Fast-forward merge (--no-ff to create a merge commit instead)
+rebase-others = "never"  # After merging, rebase other worktrees now behind the target: "prompt", "auto", or "never"
 ```
 
 ### Remove
@@ -2033,6 +2728,12 @@ delete-branch = true   # Delete branch after removal (--no-delete-branch to keep
 ```toml
 [switch]
 cd = true          # Change directory after switching (--no-cd to skip)
+tmux-window = false  # Inside tmux/Zellij, open a window per branch (--tmux-window)
+terminal-title = false  # Title the terminal tab after the branch; cleared on remove
+fetch-base = "5m"  # Fetch a remote --base (origin/main) when the last fetch is older; "off" never fetches
+auto-wip = false  # Commit uncommitted changes as WIP when switching away; undone when switching back
+track = "none"  # Upstream for --create branches: "base", "remote", or "none" (--track, --no-track)
+max-base-behind = 20  # Offer to fast-forward a base this far behind its upstream before --create
 
 [switch.picker]
 pager = "delta --paging=never"   # Example: override git's core.pager for diff preview
@@ -2059,6 +2760,19 @@ url = "echo http://localhost:{{ branch | hash_port }}"
 
 Aliases defined here apply to all projects. For project-specific aliases, use the [project config](@/config.md#project-configuration) `[aliases]` section instead.
 
+### Repository groups
+
+`[repo-groups]` names sets of repositories for `wt list --group` and `wt step for-each --group`. An entry is a name from `[repos]` or a path.
+
+```toml
+[repos]
+api = "~/code/api"
+workers = "~/code/workers"
+
+[repo-groups]
+backend = ["api", "workers"]
+```
+
 ### User project-specific settings
 
 User config can include a `[projects]` table for project-specific settings — worktree layout, setting overrides, anything else — separate from the [project config](@/config.md#project-configuration) shared with teammates.
@@ -2110,6 +2824,7 @@ Available variables:
 - `{{ git_diff }}`, `{{ git_diff_stat }}` — diff content
 - `{{ branch }}`, `{{ repo }}` — context
 - `{{ recent_commits }}` — recent commit messages
+- `{{ scope }}` — conventional-commit scope inferred from the changed paths, when the project config has [`[commit.scope]`](@/config.md#commit-scope)
 - `{{ user_guidance }}`, `{{ project_guidance }}` — rendered append fragments (see [Appending to the prompt](@/config.md#appending-to-the-prompt))
 
 Default template:
@@ -2150,7 +2865,8 @@ template = """
 
 <context>
 Branch: {{ branch }}
-{% if recent_commits %}<recent_commits>
+{% if scope %}Conventional-commit scope: {{ scope }}
+{% endif %}{% if recent_commits %}<recent_commits>
 {% for commit in recent_commits %}- {{ commit }}
 {% endfor %}</recent_commits>{% endif %}
 </context>
@@ -2223,6 +2939,145 @@ template-append = """
 
 The [project config](@/config.md#project-configuration) has a `template-append` of its own; it renders into a separate `<project-guidance>` block right after `<user-guidance>`.
 
+#### Timeouts and retries
+
+Each run of the command is killed after `timeout-ms` (default 5 minutes; `0` disables), so a hung command can't stall `wt merge`. `retries` re-runs a failed or timed-out command, waiting 1s, 2s, 4s, … between attempts; a command the shell can't find isn't retried. `stream` shows the message as it arrives instead of a waiting status:
+
+```toml
+[commit.generation]
+timeout-ms = 60000
+retries = 2
+stream = true
+```
+
+## Logs and caches location
+
+Background hook logs, caches (CI status, LLM summaries, git results), hook run records, [`wt config state`](@/config.md#wt-config-state) entries, usage stats, and approved includes live in `.git/wt/` by default, so they travel with backups of the repository and disappear on re-clone. `state-dir` moves them to a directory keyed by project identifier — `<state-dir>/<project>/logs/`, `<state-dir>/<project>/cache/`, and so on. Existing data is moved over the first time wt runs in each repository.
+
+```toml
+state-dir = "~/.local/state/worktrunk"
+```
+
+Must be absolute; `~` expands to the home directory. There is no default outside the repository — nothing moves until `state-dir` is set, and `$XDG_STATE_HOME` isn't consulted, so spell out its path (`~/.local/state` unless overridden) as above.
+
+Some state stays with the repository regardless:
+
+- Staging for background removal, since it relies on renaming worktrees within one filesystem, along with archived branch bundles and `wt lock-file` locks, which are in `.git/wt/` too.
+- Values kept in git config — the cached default branch, `wt switch -` history, branch markers and recorded bases, `vars`, and hint counts — which live under `worktrunk.*` keys in `.git/config`.
+
+## Log size limits
+
+Each background hook run replaces that hook's previous log; the previous one is kept as `<name>.log.1.gz`, cut to its last `max-file-size`. Once a repository's logs pass `max-total-size`, the oldest files are deleted.
+
+```toml
+[logs]
+max-file-size = "10M"    # default
+max-total-size = "100M"  # default
+compress = true          # gzip rotated logs (default)
+```
+
+Sizes take a `K`, `M`, or `G` suffix. Delete logs older than a cutoff with `wt config state logs prune --older-than 7d`.
+
+## Editor
+
+`wt open` runs this command to open a worktree. Without it, `wt open` uses the first of `code`, `zed`, and `idea` on `PATH`, then `$VISUAL` / `$EDITOR`.
+
+```toml
+[open]
+editor = "code --new-window {{ worktree_path }}"
+```
+
+## Theme
+
+Messages and `wt list` are styled with five terminal colors: cyan (progress, prompts, working-tree changes), green (success, additions, commits ahead), red (errors, deletions, commits behind), yellow (warnings, states needing attention), and magenta (review requests in the CI column). `[theme]` recolors them, starting from a preset — `solarized`, `high-contrast`, or `monochrome` — or from the terminal's own colors.
+
+```toml
+[theme]
+preset = "solarized"
+cyan = "blue"            # override one color
+```
+
+Colors are ANSI names (`blue`, `bright-red`), 256-color indexes (`208`), `#rrggbb`, or `none` for the terminal's default. Bold, dim, and underline are unchanged, so `monochrome` still sets apart what matters. `wt config show theme` shows the resulting palette.
+
+## ASCII output
+
+Some fonts draw worktrunk's glyphs poorly, and CJK locales render many of them double-width, pushing `wt list` columns out of line. `output.ascii` draws each with an ASCII character of the same width — in message prefixes, `wt list`, and the progress spinner:
+
+```toml
+[output]
+ascii = true
+```
+
+Messages start with `+` (success), `x` (error), `!` (warning), `*` (progress), `o` (info), `>` (hint), or `?` (prompt). In `wt list`:
+
+| Subcolumn | Unicode | ASCII |
+|-----------|---------|-------|
+| Worktree | `⚑` `⊟` `⊞` | `M` `P` `L` |
+| Default branch | `✗` `–` `⊂` `∅` `↕` `↑` `↓` | `x` `-` `c` `0` `~` `>` `<` |
+| Operation | `✘` `⤴` `⤵` `↯` | `X` `r` `m` `&` |
+| Remote | `⇡` `⇣` `⇅` `⊘` | `A` `B` `D` `G` |
+
+Ahead/behind counts use `>` `<` against the default branch and `^` `v` against the remote (headers `main<>` and `Remote^v`), a loading cell shows `.`, and CI errors show `!`.
+
+Only decoration changes: paths, branch names, file contents, and JSON output are printed as they are.
+
+## Usage stats
+
+Record command runs and worktree activity for `wt stats`: worktrees created and merged per week, time to merge, and how often each command runs. Events stay in the repository's `.git/wt/stats/` and are never sent anywhere. Off by default.
+
+```toml
+[stats]
+enabled = true
+```
+
+## Offline mode
+
+For air-gapped machines, turn off everything that reaches the network. `--offline` does the same for one command.
+
+```toml
+network = false
+```
+
+Offline, commit and squash messages use the deterministic fallback instead of `[commit.generation]`, `wt list` has no CI column or LLM summaries, `pr:` / `mr:` lookups fail, `wt switch --base` branches from remote-tracking refs without fetching them, and `wt config show --full` skips its version and tool checks.
+
+## Project hook sandbox
+
+Approving a project's hooks trusts that `.config/wt.toml` from then on. `[sandbox]` limits what project hooks can do; user hooks and aliases run unrestricted.
+
+```toml
+[sandbox]
+nice = true               # low CPU priority, and idle I/O priority on Linux
+clear-env = true          # only PATH, HOME, USER, SHELL, TERM, locale, TMPDIR
+keep-env = ["NPM_TOKEN"]  # extra variables kept with clear-env
+isolate = true            # writes limited to the worktree and temp directory
+writable = ["~/.npm"]     # extra writable directories under isolate
+```
+
+`isolate` uses `bwrap` on Linux and `sandbox-exec` on macOS; project hooks fail when neither is installed rather than running unconfined. Reads and network access are unaffected, so isolation reduces a malicious hook's reach without replacing the approval prompt.
+
+## Fork workflows
+
+When `origin` is a personal fork and another remote holds the canonical repository, the default branch's `@{upstream}` usually tracks the fork's copy, so `main↕`, integration status, and `wt remove`'s merged check compare against a stale mainline. `remote.upstream` names the canonical remote; wt then compares against `<upstream>/<default-branch>` and detects the default branch from that remote.
+
+```toml
+[remote]
+upstream = "upstream"
+```
+
+Fetching stays with git — run `git fetch upstream` (or `git fetch --all`) to refresh it. Pushing is unaffected: `git push` follows `remote.pushDefault` / `branch.<name>.pushRemote` as usual, and CI status looks up each branch on its push remote. Set it per project with `[projects."<id>".remote]` when only some repositories are forks; a remote name that doesn't exist in the current clone is ignored.
+
+## Per-worktree git config
+
+`[worktree-git-config]` sets git config on each new worktree, scoped to that worktree alone — a separate identity for agent commits, or per-client settings in consulting setups.
+
+```toml
+[worktree-git-config]
+user.email = "bot@corp.com"
+commit.gpgsign = false
+```
+
+Settings are written with `git config --worktree` before pre-start hooks run; existing worktrees are unaffected. The first use turns on git's `extensions.worktreeConfig` for the repository (moving a bare repository's `core.bare` into its own `config.worktree`, as git requires). Set them per project with `[projects."<id>".worktree-git-config]`; project keys override matching global ones.
+
 ## Hooks
 
 See [`wt hook`](@/hook.md) for hook types, execution order, template variables, and examples. User hooks apply to all projects; [project hooks](@/config.md#project-configuration) apply only to that repository.
@@ -2253,6 +3108,18 @@ URL column in `wt list` (dimmed when port not listening):
 url = "http://localhost:{{ branch | hash_port }}"
 ```
 
+## List columns
+
+Extra `wt list` columns, filled by running a command in each worktree. Commands need approval, like hooks. See [`wt list`](@/list.md#custom-columns):
+
+```toml
+[list.columns.TODOs]
+command = "git grep -c TODO | awk -F: '{n += $2} END {print n}'"
+width = 6            # Reserved width (default 10)
+priority = 9         # Drop order when narrow; lower is kept longer
+timeout-ms = 5000    # Empty cell when exceeded
+```
+
 ## Forge platform
 
 Name the forge explicitly for SSH aliases or self-hosted instances, where it can't be detected from the remote URL:
@@ -2277,6 +3144,21 @@ template-append = """
 
 Only `template-append` is honored from the project file. The LLM command and the main prompt template stay in [user config](@/config.md) — they describe per-developer environment (which CLI is installed, which agent the developer prefers). User config has a `[commit.generation] template-append` of its own; it renders into a separate `<user-guidance>` block immediately before this one.
 
+## Commit scope [experimental]
+
+Conventional-commit scope for `wt step commit`, inferred from the directory holding most of the changed lines: a directory listed in `map` takes its mapped scope (the longest match wins), any other its top-level directory's name. Root-level files count as no scope. The LLM prompt gets the scope as context (`{{ scope }}` in custom templates); the deterministic fallback message gets it as a prefix, after `fallback-type`:
+
+```toml
+[commit.scope]
+fallback-type = "chore"  # default; fallback reads "chore(web): Changes to app.ts"
+
+[commit.scope.map]
+"apps/web" = "web"
+"crates/worktrunk-cli" = "cli"
+```
+
+Scopes are limited to letters, digits, `-`, `_`, `.`, and `/`, so unlike `template-append` they need no approval before reaching the LLM.
+
 ## Copy-ignored excludes
 
 Additional excludes for `wt step copy-ignored`:
@@ -2288,6 +3170,52 @@ exclude = [".cache/", ".turbo/"]
 
 Built-in excludes always apply: VCS metadata directories (`.bzr/`, `.hg/`, `.jj/`, `.pijul/`, `.sl/`, `.svn/`) and tool-state directories (`.conductor/`, `.entire/`, `.worktrees/`). User config and project config exclusions are combined.
 
+## Prune rules
+
+Per-branch cleanup policy for `wt step prune`. The first rule whose `branch` glob matches applies; `keep = true` protects the branch, and `min-age` replaces the 1-day age guard:
+
+```toml
+[[step.prune.rules]]
+branch = "release/*"
+keep = true
+
+[[step.prune.rules]]
+branch = "dependabot/*"
+min-age = "0s"
+```
+
+See [`wt step prune`](@/step.md#wt-step-prune) for details.
+
+## Scaffold files
+
+Files to create in each new worktree, each mapping a destination to a template. Templates are read relative to the new worktree (or from an absolute path), and both the path and the contents expand [template variables](@/hook.md#template-variables). Files are written before pre-start hooks run, so hooks can rely on them; a destination that already exists is left alone:
+
+```toml
+[scaffold]
+".env" = ".env.example"
+".vscode/settings.json" = "{{ primary_worktree_path }}/.vscode/settings.json"
+```
+
+## Submodules
+
+Submodules to keep on the worktree's branch. Each new worktree initializes them and checks them out on a branch with the worktree's branch name — the existing one if the submodule has it (locally or on its remote), otherwise a new one at the commit the parent records. Switching to an existing worktree re-applies this, skipping a submodule with uncommitted changes. After `wt merge`, the target worktree's copy is fast-forwarded to the commit the merge recorded:
+
+```toml
+[submodules]
+follow = ["libs/core", "libs/ui"]
+```
+
+Each worktree has its own clone of its submodules, so the branches go away with `wt remove`.
+
+## Switch
+
+A team-wide default for how far behind its upstream a base may fall before `wt switch --create` offers to fast-forward it. A user config `max-base-behind` overrides it:
+
+```toml
+[switch]
+max-base-behind = 20
+```
+
 ## Aliases
 
 Command templates that run as `wt <name>`. See the [Extending Worktrunk guide](@/extending.md#aliases) for usage and flags.
@@ -2299,6 +3227,18 @@ url = "echo http://localhost:{{ branch | hash_port }}"
 ```
 
 Aliases defined here are shared with teammates. For personal aliases, use the [user config](@/config.md#aliases) `[aliases]` section instead.
+
+## Shared includes
+
+Config files to layer under this one, so many repositories can share standard hooks, aliases, and templates. Each entry is a path relative to `.config/wt.toml` or an `https://` URL:
+
+```toml
+include = ["../shared/wt-common.toml", "https://example.com/wt-team.toml"]
+```
+
+Includes load in order, then this file on top: tables merge key by key, and any other setting in a later file replaces the earlier one. Included files can't include others.
+
+A remote include takes effect only once approved. `wt config approvals add` fetches it, shows its content, and on approval keeps a copy in `.git/wt/includes/` with its hash in `approvals.toml`. Until then it's skipped with a warning. Commands always run from the approved copy, so a change upstream needs a fresh `wt config approvals add`. Commands from includes still need approval like any project command.
 <!-- PROJECT_CONFIG_END -->
 
 # Shell Integration
@@ -2317,6 +3257,8 @@ Without shell integration, `wt switch` prints the target directory but cannot `c
 
 On first run without shell integration, Worktrunk offers to install it. On first commit without LLM configuration, it offers to configure a detected tool (`claude`, `codex`). Declining sets `skip-shell-integration-prompt` or `skip-commit-generation-prompt` automatically.
 
+`wt setup` walks through the same choices up front — shell integration, `worktree-path`, LLM commit messages, and CI tools — regardless of those flags.
+
 # Other
 
 ## Environment variables
@@ -2355,12 +3297,16 @@ $ WORKTRUNK_COMMIT__GENERATION__COMMAND="echo 'test: automated commit'" wt merge
 | `WORKTRUNK_DIRECTIVE_CD_FILE` | Internal: set by shell wrappers. wt writes a raw path; the wrapper `cd`s to it |
 | `WORKTRUNK_DIRECTIVE_EXEC_FILE` | Internal: set by shell wrappers. wt writes shell commands; the wrapper sources the file |
 | `WORKTRUNK_SHELL` | Internal: set by shell wrappers to indicate shell type (e.g., `powershell`) |
+| `WORKTRUNK_COMMAND_SUBSTITUTION` | Internal: set by shell wrappers when wt runs inside `$( )` or a pipeline, where the wrapper can't `cd` |
 | `WORKTRUNK_MAX_CONCURRENT_COMMANDS` | Max parallel git commands (default: 32). Lower if hitting file descriptor limits. |
-| `WORKTRUNK_VERBOSE` | Verbosity level (`0`/`1`/`2`), like `-v`/`-vv` but applied everywhere — including shell completion, which no flag can reach |
+| `WORKTRUNK_VERBOSE` | Verbosity level (`0`–`3`), like `-v`/`-vv`/`-vvv` but applied everywhere — including shell completion, which no flag can reach |
 | `RUST_LOG` | Logging directive (e.g. `worktrunk=debug`); overrides the verbosity baseline for what reaches stderr |
+| `WORKTRUNK_LOG` | Per-module log levels on top of the verbosity baseline (e.g. `git=debug,hooks=trace`), so one subsystem can be turned up alone |
 | `NO_COLOR` | Disable colored output ([standard](https://no-color.org/)) |
 | `CLICOLOR_FORCE` | Force colored output even when not a TTY |
 
+The global `--color=always` or `--color=never` flag overrides both, for all of worktrunk's output and for hooks and aliases writing to the terminal, which see `CLICOLOR_FORCE=1` or `NO_COLOR=1`.
+
 ## Inline config overrides (`--config-set`)
 
 `--config-set <toml>` overrides any user config key for a single invocation, with higher priority than both config files and `WORKTRUNK_` env vars. The value is a TOML fragment, so arrays and tables work directly; the flag is global (works before or after the subcommand), repeatable, and a later `--config-set` replaces an earlier one for the same key.
@@ -2380,6 +3326,615 @@ This composes with aliases — an alias body can invoke `wt --config-set … <co
         action: ConfigCommand,
     },
 
+    /// Set up Worktrunk interactively
+    ///
+    /// Installs shell integration, picks where worktrees go, configures and tests LLM commit messages, and checks CI tools. Writes the user config as it goes.
+    #[command(after_long_help = r#"## Examples
+
+```console
+$ wt setup
+```
+
+Accept every default without prompting — install shell integration, keep the current `worktree-path`, configure the detected LLM tool:
+
+```console
+$ wt setup --yes
+```
+
+## Steps
+
+1. **Shell integration** — same as `wt config shell install`
+2. **Worktree paths** — choose a `worktree-path` layout, previewed against the current repository
+3. **Commit messages** — configure a detected LLM tool (`claude`, `codex`, `opencode`), then run it once to confirm it works
+4. **CI status** — check that `gh`/`glab` are installed and authenticated
+
+Each step can be skipped. Run `wt setup` again any time; it shows the current choices and changes only what's picked."#)]
+    Setup,
+
+    /// \[experimental\] Search every worktree
+    ///
+    /// Runs `git grep` (or `rg` when it's on PATH) across all worktrees in parallel and prefixes each match with its branch.
+    #[command(
+        after_long_help = r#"Tracked and untracked files are searched; gitignored files are skipped. Output is `<branch>:<file>:<line>:<text>`, grouped by worktree in `wt list` order — detached worktrees show their directory name instead of a branch.
+
+Exits 0 when anything matched and 1 when nothing did, like `grep`. A worktree where the search fails is reported and the exit code is 2.
+
+## Examples
+
+Which branches touch a function:
+
+```console
+$ wt grep parse_config
+```
+
+Only worktrees with uncommitted changes:
+
+```console
+$ wt grep --dirty-only parse_config
+```
+
+Pass options through to the search tool after `--`:
+
+```console
+$ wt grep parse_config -- --ignore-case
+```"#
+    )]
+    Grep {
+        /// Pattern to search for
+        pattern: String,
+
+        /// Only search worktrees with uncommitted changes
+        #[arg(long)]
+        dirty_only: bool,
+
+        /// Arguments for `git grep` or `rg`
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+
+    /// Open a worktree in an editor
+    ///
+    /// Creates the worktree first if the branch doesn't have one. Defaults to the current worktree.
+    #[command(
+        after_long_help = r#"The editor command comes from `--editor`, then `[open] editor` in user config, then the first of `code`, `zed`, and `idea` found on `PATH`, then `$VISUAL` / `$EDITOR`.
+
+A branch without a worktree gets one through the same steps as `wt switch`, including hooks, without changing directory.
+
+## Configuration
+
+```toml
+[open]
+editor = "code --new-window {{ worktree_path }}"
+```
+
+The command runs in the worktree and supports [hook template variables](@/hook.md#template-variables) (`{{ branch }}`, `{{ worktree_path }}`, etc.).
+
+## Examples
+
+Open the current worktree:
+
+```console
+$ wt open
+```
+
+Open another branch's worktree in Zed, creating it if needed:
+
+```console
+$ wt open feature --editor zed
+```"#
+    )]
+    Open {
+        /// Branch whose worktree to open
+        ///
+        /// Defaults to the current worktree. Accepts `^`, `-`, `@`, and `%N`.
+        #[arg(add = crate::completion::worktree_branch_completer(), value_parser = crate::cli::non_empty_branch)]
+        branch: Option<String>,
+
+        /// Editor to launch
+        ///
+        /// Defaults to `[open] editor` in user config, else `auto`.
+        #[arg(long)]
+        editor: Option<OpenEditor>,
+    },
+
+    /// Keep a branch out of `wt step prune`
+    ///
+    /// Pinned branches are marked 📌 in `wt list`. Defaults to the current branch.
+    #[command(
+        after_long_help = r#"A pin is for "I'll get back to this": unlike `git worktree lock`, the worktree can still be removed with `wt remove`, but `wt step prune` never selects it — nor the branch, once its worktree is gone — even after it's merged.
+
+Pins are stored in git config as `worktrunk.state.<branch>.pinned`. `wt config state clear` removes them along with other stored state.
+
+## Examples
+
+Pin the current branch:
+
+```console
+$ wt pin
+```
+
+Pin another branch, and later release it:
+
+```console
+$ wt pin experiment
+$ wt unpin experiment
+```"#
+    )]
+    Pin {
+        /// Branch to pin
+        ///
+        /// Defaults to the current branch.
+        #[arg(add = crate::completion::local_branches_completer())]
+        branch: Option<String>,
+    },
+
+    /// Remove a branch's pin
+    ///
+    /// Lets `wt step prune` select the branch again. Defaults to the current branch.
+    Unpin {
+        /// Branch to unpin
+        ///
+        /// Defaults to the current branch.
+        #[arg(add = crate::completion::local_branches_completer())]
+        branch: Option<String>,
+    },
+
+    /// Take an advisory lock shared by every worktree
+    ///
+    /// Lets worktrees that regenerate the same external resource — a local database schema, a port, fixtures — take turns instead of clobbering each other.
+    #[command(
+        after_long_help = r#"A lock is named by a path, as written — `db/schema` names the same lock from every worktree, whether or not anything exists there. It is held by the process that ran `wt lock-file`: the shell, or the hook command. When that process exits, the lock is stale, and the next `wt lock-file` takes it over; `--release` gives it up sooner. Locks are advisory: they bind only those who take them.
+
+Without a path, lists the locks held in this repository.
+
+## In hooks
+
+A hook command that takes the lock holds it until the command finishes, so a failing regeneration can't leave it held:
+
+```toml
+[post-start]
+fixtures = "wt lock-file --wait fixtures && make fixtures"
+```
+
+`--check` only reports: it fails while another process holds the lock, for hooks that would rather skip the work than wait.
+
+Locks are stored in `.git/wt/locks/`, one file each, recording the holder's branch, worktree, and PID.
+
+## Examples
+
+Lock, regenerate, release:
+
+```console
+$ wt lock-file db/schema
+$ ./scripts/migrate
+$ wt lock-file --release db/schema
+```
+
+See who holds what:
+
+```console
+$ wt lock-file
+$ wt lock-file --format=json
+```"#
+    )]
+    LockFile {
+        /// Path naming the lock
+        path: Option<String>,
+
+        /// Release the lock instead of taking it
+        #[arg(long, requires = "path", conflicts_with_all = ["check", "wait"])]
+        release: bool,
+
+        /// Fail if another process holds the lock, without taking it
+        #[arg(long, requires = "path", conflicts_with = "wait")]
+        check: bool,
+
+        /// Wait for the lock instead of failing while it's held
+        #[arg(long, requires = "path")]
+        wait: bool,
+
+        #[command(flatten)]
+        format: GlobalFormatFlag,
+    },
+
+    /// Pin a base commit under a name
+    ///
+    /// Records the default branch's current commit so several worktrees can start from exactly the same base, however far trunk moves in between.
+    #[command(
+        after_long_help = r#"Comparing agents, prompts, or approaches side by side needs each worktree to start from the same commit. A snapshot pins one under a name; `--base snapshot:<name>` branches from it.
+
+Snapshots are stored as refs under `refs/worktrunk/snapshots/`, which keeps the pinned commit from being garbage-collected after trunk is force-pushed or rebased. A snapshot stays until deleted.
+
+## Examples
+
+Pin trunk, then start two experiments from it:
+
+```console
+$ wt snapshot create baseline
+$ wt switch --create try-a --base snapshot:baseline
+$ wt switch --create try-b --base snapshot:baseline
+```
+
+Pin another ref:
+
+```console
+$ wt snapshot create before-refactor --base origin/main
+```
+
+List and delete snapshots:
+
+```console
+$ wt snapshot
+$ wt snapshot delete baseline
+```"#
+    )]
+    Snapshot {
+        #[command(subcommand)]
+        action: Option<SnapshotCommand>,
+
+        #[command(flatten)]
+        format: GlobalFormatFlag,
+    },
+
+    /// Relink worktrees after the repository moved
+    ///
+    /// Points every worktree's `.git` file back at the repository, so git works in them again after the repository directory was moved or renamed.
+    #[command(
+        after_long_help = r#"Each linked worktree's `.git` file records where the repository lives. Moving or renaming the repository directory leaves them pointing at the old location, and git fails in every worktree. Run `wt repair` from the repository's new location to relink them all with one `git worktree repair`.
+
+Every `wt` command checks for this state and suggests `wt repair` when it finds it — from the repository, it lists the worktrees left pointing at the old location; from one of those worktrees, it names the missing path. Worktrees that were moved themselves aren't found this way; run `git worktree repair <path>` with their new paths.
+
+## Examples
+
+```console
+$ mv ~/code/app ~/src/app
+$ cd ~/src/app
+$ wt repair
+```"#
+    )]
+    Repair,
+
+    /// Print a branch's worktree path
+    ///
+    /// Prints the path and nothing else, creating nothing — for `cd $(wt path feature)`, editor tasks, and scripts that don't want `wt switch`'s hooks or shell integration.
+    #[command(
+        after_long_help = r#"Fails when the branch has no worktree; `wt map <branch>` prints where `wt switch` would create one.
+
+## Examples
+
+```console
+$ cd "$(wt path feature)"
+$ code "$(wt path ^)"
+```"#
+    )]
+    Path {
+        /// Branch whose worktree path to print
+        ///
+        /// Defaults to the current worktree. Accepts `^`, `-`, `@`, and `%N`.
+        #[arg(add = crate::completion::worktree_only_completer(), value_parser = crate::cli::non_empty_branch)]
+        branch: Option<String>,
+    },
+
+    /// Print each branch's worktree path
+    ///
+    /// Emits the branch → worktree path mapping, plus the primary worktree and a repository identifier, for Makefiles, compose files, and CI scripts.
+    #[command(
+        after_long_help = r#"Without arguments, every worktree on a branch is listed. Naming branches limits the output to them; a named branch without a worktree maps to the path `wt switch` would create it at, so scripts never re-implement the `worktree-path` template.
+
+## Examples
+
+Look up a worktree from a script:
+
+```console
+$ wt map | jq -r '.worktrees["feature"]'
+```
+
+Load the paths into a Makefile or compose `.env` file:
+
+```console
+$ wt map --format=env > .worktrees.env
+```
+
+## Formats
+
+`json` (the default):
+
+```json
+{
+  "repo": "github.com/acme/app",
+  "primary": "/code/app",
+  "worktrees": {
+    "main": "/code/app",
+    "feature/auth": "/code/app.feature-auth"
+  }
+}
+```
+
+`env`, one `KEY=value` line each, quoted only when the value needs it:
+
+```
+WT_REPO=github.com/acme/app
+WT_PRIMARY=/code/app
+WT_WORKTREE_MAIN=/code/app
+WT_WORKTREE_FEATURE_AUTH=/code/app.feature-auth
+```
+
+Branch names are uppercased with every other character replaced by `_`; when two branches collapse to the same name, the one sorting first keeps it and the other is skipped with a warning.
+
+`repo` is the remote's `host/owner/name`, or the repository path when there's no remote — the same identifier approvals are recorded under."#
+    )]
+    Map {
+        /// Branches to include (defaults to all worktrees)
+        #[arg(add = crate::completion::branch_value_completer(), value_parser = crate::cli::non_empty_branch)]
+        branches: Vec<String>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "json")]
+        format: MapFormat,
+    },
+
+    /// Hints and warnings that show once per repo, day, or version
+    ///
+    /// Lists each hint with how often it may show and when it last did; `wt hints reset` makes them show again.
+    #[command(
+        after_long_help = r#"Some hints and warnings would be noise if they repeated on every command, so each has a cadence:
+
+| Cadence | Shows |
+|---------|-------|
+| `once` | The first time it applies in a repository |
+| `daily` | At most once every 24 hours |
+| `per-version` | Once per worktrunk release |
+| `always` | Every time; the count only escalates the message |
+
+| Name | Cadence | Trigger |
+|------|---------|---------|
+| `worktree-path` | once | First `wt switch --create` without a custom `worktree-path` |
+| `skip-bare-repo-prompt` | once | Declining the bare-repo worktree-path prompt (records the opt-out; no message) |
+| `shell-integration` | always | Switching without shell integration; after 5 shows it points at `wt config show` |
+| `no-verify-deprecated` | daily | Passing `--no-verify` |
+| `state-subcommand-deprecated` | per-version | Running `wt config state ci-status`, `hints`, or `previous-branch` |
+
+Displays are recorded per repository in git config, as `worktrunk.hints.<name>`. Outside a repository there's nowhere to record them, so every hint shows.
+
+## Examples
+
+See which hints have shown:
+
+```console
+$ wt hints
+```
+
+Show every hint again:
+
+```console
+$ wt hints reset
+```
+
+Show one hint again:
+
+```console
+$ wt hints reset worktree-path
+```"#
+    )]
+    Hints {
+        #[command(subcommand)]
+        action: Option<HintsCommand>,
+
+        #[command(flatten)]
+        format: GlobalFormatFlag,
+    },
+
+    /// Install shell completion files
+    ///
+    /// Writes each shell's completion script to the directory it loads completions from, or updates a stale one, and reports what changed.
+    #[command(
+        after_long_help = r#"Shell integration (`wt config shell install`) already registers completions for every shell. Completion files suit setups without it — a zsh `fpath` managed by a plugin manager, fish without the `wt` function, bash with `bash-completion`. The scripts call `wt` at TAB time, so branch names stay current without reinstalling; run it again after upgrading to pick up changes to the scripts themselves.
+
+## Examples
+
+Install for every detected shell:
+
+```console
+$ wt completions install
+```
+
+Preview the zsh install:
+
+```console
+$ wt completions install zsh --dry-run
+```
+
+## Locations
+
+| Shell | File |
+|-------|------|
+| bash | `$XDG_DATA_HOME/bash-completion/completions/wt` (default `~/.local/share/…`), loaded by `bash-completion` |
+| zsh | `~/.zfunc/_wt`; add `fpath=(~/.zfunc $fpath)` before `compinit` in `.zshrc` |
+| fish | `$XDG_CONFIG_HOME/fish/completions/wt.fish` (default `~/.config/fish/…`) |
+
+Without a shell argument, each of bash, zsh, and fish that's on `PATH` is installed. Nushell and PowerShell have no separate completion file; their completions come with `wt config shell install`."#
+    )]
+    Completions {
+        #[command(subcommand)]
+        action: CompletionsCommand,
+    },
+
+    /// \[experimental\] Keep caches warm in the background
+    ///
+    /// Watches refs, HEADs, and indexes of registered repositories and refreshes the shell-completion and `wt list` caches whenever they change, so completions and statuslines stay fast in large repositories.
+    #[command(
+        after_long_help = r#"`wt daemon start` registers the current repository and starts the daemon if it isn't running; one daemon serves every registered repository.
+
+## Examples
+
+Watch the current repository:
+
+```console
+$ wt daemon start
+```
+
+Check what's watched:
+
+```console
+$ wt daemon status
+```
+
+Stop watching the current repository, or stop the daemon entirely:
+
+```console
+$ wt daemon forget
+$ wt daemon stop
+```
+
+## How it works
+
+The daemon polls the stat data of each repository's `HEAD`, `index`, `packed-refs`, `refs/` tree, and linked worktrees a few times a second — no platform watcher is needed, so it behaves the same on Linux, macOS, and Windows. When that fingerprint changes and then settles, it:
+
+1. Rebuilds the branch list for shell completion, stored under `.git/wt/cache/completion/` with the fingerprint it was computed at
+2. Runs `wt list --format=json` at low priority, filling the caches `wt list` and `wt list statusline` read (ahead/behind counts, diff stats, merge probes, CI status)
+
+Completion uses the cached branch list only while its fingerprint still matches the repository, so a stopped or lagging daemon never serves stale branches — commands just compute them as they would without it.
+
+The registry, status, and log live beside the user config, in `~/.config/worktrunk/daemon/`. `wt daemon stop` removes the status file, and the daemon exits at its next poll."#
+    )]
+    Daemon {
+        #[command(subcommand)]
+        action: DaemonCommand,
+    },
+
+    /// \[experimental\] Serve worktrunk operations to agents over MCP
+    ///
+    /// Speaks the Model Context Protocol on stdin/stdout, so LLM agents and editors can list, create, merge, and remove worktrees and get structured results.
+    #[command(
+        after_long_help = r#"Messages are JSON-RPC 2.0, one per line. Each tool runs the matching `wt` command with `--format=json` and returns its parsed output; a command that fails returns its error messages with `isError` set.
+
+## Examples
+
+Serve the read-only operations (the default):
+
+```console
+$ wt serve
+```
+
+Let an agent create, merge, and remove worktrees:
+
+```console
+$ wt serve --allow list,switch,merge,remove
+```
+
+Register with Claude Code:
+
+```console
+$ claude mcp add worktrunk -- wt serve --allow list,switch
+```
+
+## Tools
+
+| Tool | Runs |
+|------|------|
+| `list` | `wt list` |
+| `switch` | `wt switch --no-cd` |
+| `remove` | `wt remove --foreground` |
+| `merge` | `wt merge` |
+| `rebase` | `wt step rebase` |
+| `squash` | `wt step squash` |
+| `push` | `wt step push` |
+| `prune-candidates` | `wt step prune --dry-run` |
+
+Every tool takes an optional `worktree` path to run in; without one, it runs where the server started. Tools outside `--allow` aren't listed, and calls to them fail.
+
+The server never passes `--yes`: an operation whose hooks haven't been approved fails with the approval error. Approve them ahead of time with `wt config approvals add`."#
+    )]
+    Serve {
+        /// Operations clients may call
+        ///
+        /// Comma-separated. Defaults to the read-only `list` and
+        /// `prune-candidates`.
+        #[arg(
+            long,
+            value_enum,
+            value_delimiter = ',',
+            default_values_t = [ServeOperation::List, ServeOperation::PruneCandidates]
+        )]
+        allow: Vec<ServeOperation>,
+    },
+
+    /// Diagnose worktrunk's own behavior
+    ///
+    /// Runs a `wt` command under inspection; `wt debug git-calls` lists every git and jj call it made, so redundant ones stand out. `wt debug gc` removes data left behind by deleted branches.
+    #[command(
+        after_long_help = r#"`wt debug git-calls` runs the command after it (everything following `--` is passed through) and then prints each distinct git or jj invocation with how many times it ran, how long it took in total, and the directory it ran in. The most repeated calls come first, so a command that asks git the same question many times is easy to spot. Failed calls are marked with their count.
+
+Nested `wt` processes the command starts, such as hooks that run `wt`, are included. The command's own output and exit code pass through unchanged; the summary goes to stderr. Nothing needs enabling first — for a timeline of every subprocess, with in-process spans, use `-vv` and `wt config state logs profile`.
+
+`wt debug gc` runs the same sweep as [`wt config state gc`](@/config.md#wt-config-state-gc), deleting markers, pins, vars, journals, cache entries, and logs whose branch no longer exists.
+
+## Examples
+
+Audit `wt list`:
+
+```console
+$ wt debug git-calls -- list
+```
+
+Audit a switch to an existing worktree:
+
+```console
+$ wt debug git-calls -- switch feature --no-cd
+```
+
+Clear out what deleted branches left behind:
+
+```console
+$ wt debug gc
+```"#
+    )]
+    Debug {
+        #[command(subcommand)]
+        action: DebugCommand,
+    },
+
+    /// Show local usage stats
+    ///
+    /// Worktrees created, merged, and removed per week, how long branches take to merge, and how often each command runs. Recording is opt-in and nothing leaves the machine.
+    #[command(
+        after_long_help = r#"Stats are recorded only with `[stats] enabled = true` in user config, from then on. Each repository keeps its own, in `.git/wt/stats/events.jsonl` — nothing is sent anywhere.
+
+## Examples
+
+Show the last eight weeks:
+
+```console
+$ wt stats
+```
+
+Show a quarter as JSON:
+
+```console
+$ wt stats --weeks 13 --format json
+```
+
+## Columns
+
+| Column | Meaning |
+|--------|---------|
+| Created | Worktrees created by `wt switch --create` (or a switch to a branch without one) |
+| Merged | Branches merged by `wt merge` |
+| Removed | Worktrees removed, by `wt remove`, `wt merge`, or `wt step prune` |
+| Merge latency | Average time from a branch's worktree being created to its merge |
+
+Weeks start on Monday (UTC). Merge latency only covers branches whose worktree was created while recording was on."#
+    )]
+    Stats {
+        /// Number of weeks to show, ending with the current one
+        #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u32).range(1..))]
+        weeks: u32,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "table")]
+        format: OutputFormat,
+    },
+
     /// Run a custom `wt-<name>` command found on PATH.
     ///
     /// Captured by clap when the first positional argument doesn't match any
@@ -0,0 +1,24 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt snapshot`
+#[derive(Subcommand)]
+pub enum SnapshotCommand {
+    /// Pin the default branch's current commit under a name
+    Create {
+        /// Snapshot name
+        name: String,
+
+        /// Ref to pin instead of the default branch
+        #[arg(long, add = crate::completion::branch_value_completer())]
+        base: Option<String>,
+    },
+
+    /// List snapshots with the commits they pin
+    List,
+
+    /// Delete a snapshot
+    Delete {
+        /// Snapshot name
+        name: String,
+    },
+}
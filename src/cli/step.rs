@@ -51,6 +51,10 @@ pub struct SquashArgs {
     #[arg(long, hide = true)]
     pub(crate) show_prompt: bool,
 
+    /// Squash even on the default branch
+    #[arg(long)]
+    pub(crate) allow_primary: bool,
+
     /// Output format
     ///
     /// JSON prints structured result to stdout after the squash completes.
@@ -59,8 +63,8 @@ pub struct SquashArgs {
 }
 
 // Ordering: `wt merge` pipeline steps first (commit → squash → rebase → push),
-// then standalone utilities (diff, copy-ignored), then experimentals
-// (alphabetical: eval, for-each, promote, prune, relocate, tether). Keep this
+// then standalone utilities (diff, diff-branches, copy-ignored), then experimentals
+// (alphabetical: clone, eval, for-each, optimize-repo, promote, prune, relocate, revert-merge, tether). Keep this
 // enum, the `## Operations` bullet list in `src/cli/mod.rs`, and the
 // `<!-- subdoc: -->` markers in the same relative order.
 /// Run individual operations
@@ -136,6 +140,15 @@ Configure the default in user config:
 stage = "tracked"
 ```
 
+### Trailers
+
+Trailers on the squashed commits whose key is in the `squash-trailers` allowlist — `Co-authored-by` by default — are appended to the squash message, once each, so attribution survives the squash. Trailers the generated message already carries aren't repeated. Keys match case-insensitively:
+
+```toml
+[commit]
+squash-trailers = ["Co-authored-by", "Signed-off-by"]  # [] drops them all
+```
+
 ### Dry run
 
 Render the prompt, print the LLM command, generate the squash message, and exit without resetting, running hooks, or committing:
@@ -145,6 +158,10 @@ $ wt step squash --dry-run
 ```
 
 Three sections are printed: the rendered prompt, the shell command that would invoke the LLM, and the message returned. The LLM call still happens — only the squash and commit are skipped.
+
+### On the default branch
+
+Squashing soft-resets the current branch to where it left the target, so on the default branch it would rewrite commits other branches and the remote already share. `wt step squash` refuses there; `--allow-primary` overrides. `wt step rebase` has the same guard.
 "#
     )]
     Squash(SquashArgs),
@@ -153,11 +170,20 @@ Three sections are printed: the rendered prompt, the shell command that would in
     #[command(
         after_long_help = r#"Rebases the current branch onto the target branch. Conflicts abort immediately; use `git rebase --abort` to recover.
 
+## Stacked branches
+
+Local branches whose tips are among the commits being rebased — branches stacked under the current one — keep pointing at the old commits after a plain rebase, and a hint lists them. `--update-refs` passes git's `--update-refs` (git 2.38+) so they move with the rebase, and reports which were updated. Git skips branches checked out in another worktree.
+
+## On the default branch
+
+Rebasing the default branch rewrites history other branches and the remote already share, so `wt step rebase` refuses to run there. Pass `--allow-primary` to rebase it anyway.
+
 ## Examples
 
 ```console
-$ wt step rebase            # Rebase onto default branch
-$ wt step rebase develop    # Rebase onto develop
+$ wt step rebase                  # Rebase onto default branch
+$ wt step rebase develop          # Rebase onto develop
+$ wt step rebase --update-refs    # Also move stacked branches
 ```
 "#
     )]
@@ -168,6 +194,17 @@ $ wt step rebase develop    # Rebase onto develop
         #[arg(add = crate::completion::branch_value_completer(), value_parser = crate::cli::non_empty_branch)]
         target: Option<String>,
 
+        /// Move stacked branches along with the rebase
+        ///
+        /// Passes `--update-refs` to git, so local branches pointing into the
+        /// rebased commits are updated too. Requires git 2.38+.
+        #[arg(long)]
+        update_refs: bool,
+
+        /// Rebase even on the default branch
+        #[arg(long)]
+        allow_primary: bool,
+
         /// Output format
         ///
         /// JSON prints structured result to stdout after the rebase completes.
@@ -187,6 +224,10 @@ $ wt step push develop     # Fast-forward develop instead
 ```
 
 Similar to `git push . HEAD:<target>`, but uses `receive.denyCurrentBranch=updateInstead` internally.
+
+## Hooks
+
+`pre-push` hooks run right before the target moves, with the commits being pushed available as `{{ commit_range }}` — a failure leaves the target untouched. Skip them with `--no-hooks`. See [`wt hook`](@/hook.md#hook-types).
 "#
     )]
     Push {
@@ -204,6 +245,9 @@ Similar to `git push . HEAD:<target>`, but uses `receive.denyCurrentBranch=updat
         #[arg(long, overrides_with = "no_ff", hide = true)]
         ff: bool,
 
+        #[command(flatten)]
+        hooks: crate::cli::HookFlags,
+
         /// Output format
         ///
         /// JSON prints structured result to stdout after the push completes.
@@ -272,6 +316,61 @@ $ GIT_INDEX_FILE=/tmp/idx git diff $(git merge-base HEAD $(wt config state defau
         extra_args: Vec<String>,
     },
 
+    /// Diff two worktrees, uncommitted changes included
+    ///
+    /// Compares what's on disk in each worktree — committed, staged, unstaged,
+    /// and untracked files — without committing or switching.
+    #[command(
+        name = "diff-branches",
+        after_long_help = r#"Useful for comparing two attempts at the same task, such as agents working in parallel worktrees.
+
+## Examples
+
+What does `attempt-b` have that `attempt-a` doesn't?
+
+```console
+$ wt step diff-branches attempt-a attempt-b
+```
+
+Compare the current worktree with another:
+
+```console
+$ wt step diff-branches @ feature
+```
+
+Arguments after `--` are forwarded to `git diff`:
+
+```console
+$ wt step diff-branches attempt-a attempt-b -- --stat
+```
+
+## How it works
+
+Each worktree's files are staged into a copy of its index and written as a tree, so the real index is never modified. A branch without a worktree contributes its latest commit. Equivalent to:
+
+```console
+$ cp "$(git -C ../repo.attempt-a rev-parse --git-dir)/index" /tmp/idx
+$ GIT_INDEX_FILE=/tmp/idx git -C ../repo.attempt-a add -A
+$ GIT_INDEX_FILE=/tmp/idx git -C ../repo.attempt-a write-tree
+```
+
+for each side, then `git diff <tree-a> <tree-b>`. Gitignored files are left out.
+"#
+    )]
+    DiffBranches {
+        /// Worktree or branch to diff from
+        #[arg(add = crate::completion::branch_value_completer(), value_parser = crate::cli::non_empty_branch)]
+        from: String,
+
+        /// Worktree or branch to diff to
+        #[arg(add = crate::completion::branch_value_completer(), value_parser = crate::cli::non_empty_branch)]
+        to: String,
+
+        /// Extra arguments forwarded to `git diff`
+        #[arg(last = true)]
+        extra_args: Vec<String>,
+    },
+
     /// Copy gitignored files to another worktree
     ///
     /// Eliminates cold starts by copying build caches and dependencies.
@@ -408,6 +507,58 @@ The `.worktreeinclude` pattern is shared with [Claude Code on desktop](https://c
         format: crate::cli::SwitchFormat,
     },
 
+    /// \[experimental\] Clone a repository that shares or defers its objects
+    ///
+    /// For very large repositories checked out more than once.
+    #[command(
+        after_long_help = r#"Worktrees of one repository already share its object store, so `wt switch --create` never copies history. Separate clones of the same project don't — each downloads and stores every object. This clones with one of git's two ways to avoid that:
+
+- `--reference <repo>` — borrow objects from an existing local clone through `objects/info/alternates`. Only objects the reference lacks are downloaded and stored.
+- `--filter <spec>` — partial clone. With `blob:none`, file contents are fetched when a checkout or diff first needs them; commits and trees are all present, so ahead/behind counts don't need the network.
+
+Both can be combined. Without a directory, the clone goes where `git clone` would put it: the last component of the URL, without `.git`.
+
+`wt list --size` shows how much each repository stores and which stores it borrows from.
+
+## Keeping the reference
+
+A clone made with `--reference` depends on the reference repository's objects. Don't delete the reference, and don't run `git gc --prune=now` in it while borrowed objects may be unreachable there. To make the clone self-contained later, run `git repack -a -d` in it, then delete `.git/objects/info/alternates`.
+
+## Examples
+
+Clone a second copy that borrows from the first:
+
+```console
+$ wt step clone --reference ~/code/monorepo https://github.com/org/monorepo ~/code/monorepo-review
+```
+
+Partial clone that fetches file contents on demand:
+
+```console
+$ wt step clone --filter blob:none https://github.com/org/monorepo
+```
+
+Note: This command is experimental and may change in future versions.
+"#
+    )]
+    Clone {
+        /// Repository URL or path
+        url: String,
+
+        /// Directory to clone into
+        ///
+        /// Defaults to the repository name from the URL.
+        directory: Option<std::path::PathBuf>,
+
+        /// Borrow objects from this local repository
+        #[arg(long, value_name = "REPO")]
+        reference: Option<std::path::PathBuf>,
+
+        /// Partial-clone filter, e.g. `blob:none`
+        #[arg(long, value_name = "SPEC")]
+        filter: Option<String>,
+    },
+
     /// \[experimental\] Evaluate a template expression
     ///
     /// Prints the result to stdout for use in scripts and shell substitutions.
@@ -512,6 +663,12 @@ Pull updates in worktrees with upstreams (skips others):
 $ git fetch --prune && wt step for-each -- sh -c '[ "$(git rev-parse @{u} 2>/dev/null)" ] || exit 0; git pull --autostash'
 ```
 
+Check status across every repository of a [`[repo-groups]`](@/config.md#repository-groups) entry; each worktree is named `<repo>/<worktree>`, and JSON results gain a `repo` field:
+
+```console
+$ wt step for-each --group backend -- git status --short
+```
+
 Note: This command is experimental and may change in future versions.
 "#
     )]
@@ -520,11 +677,37 @@ Note: This command is experimental and may change in future versions.
         #[arg(long, default_value = "text")]
         format: crate::cli::SwitchFormat,
 
+        /// Run in every worktree of each repository in a `[repo-groups]` entry
+        #[arg(long, value_name = "NAME")]
+        group: Option<String>,
+
         /// Command template (see --help for all variables)
         #[arg(required = true, last = true, num_args = 1..)]
         args: Vec<String>,
     },
 
+    /// \[experimental\] Write the commit-graph and multi-pack-index
+    #[command(
+        after_long_help = r#"Writes the indexes git uses to speed up history walks. wt runs many of these per worktree — ahead/behind counts, merge-base lookups, integration checks — so in large repos `wt list` and `wt merge` get noticeably faster.
+
+- **commit-graph** — commit ancestry in one file, so `rev-list` and merge-base don't parse each commit object. Written with `--changed-paths`, which also speeds up path-limited history.
+- **multi-pack-index** — one lookup table across all packfiles. Only written when the repository has packs.
+
+Git only writes these during `git gc` or `git maintenance`, so a clone that has never run either usually has neither. `wt config show --full` reports which are missing.
+
+The indexes cover the shared object store, so every worktree benefits. The command also sets `fetch.writeCommitGraph` in the repository config, so fetches keep the commit-graph current. Re-run it any time to refresh; for ongoing upkeep, `git maintenance start` schedules it in the background.
+
+## Examples
+
+```console
+$ wt step optimize-repo
+```
+
+Note: This command is experimental and may change in future versions.
+"#
+    )]
+    OptimizeRepo,
+
     /// \[experimental\] Swap a branch into the main worktree
     ///
     /// Exchanges branches and gitignored files between two worktrees.
@@ -584,17 +767,39 @@ The swap uses `rename()` for each entry — fast regardless of entry size, since
 
 In `wt list`, candidates show `_` (same commit) or `⊂` (content integrated). Run `--dry-run` to preview. See `wt remove --help` for the full integration criteria.
 
-Locked worktrees and the main worktree are always skipped. The current worktree is removed last, triggering cd to the primary worktree. Pre-remove and post-remove hooks run for each removal; a candidate whose hooks include an unapproved project command is skipped with `(approval required)` (pre-approve with `wt config approvals add`, or pass `--yes`).
+Locked worktrees, pinned branches (`wt pin`), the main worktree, and worktrees with a running process inside them (a dev server, agent, or shell) are always skipped. The current worktree is removed last, triggering cd to the primary worktree. Pre-remove and post-remove hooks run for each removal; a candidate whose hooks include an unapproved project command is skipped with `(approval required)` (pre-approve with `wt config approvals add`, or pass `--yes`). A branch with stashes made on it is skipped with `(has stashes)`; `--yes` exports them to `.git/wt/stashes/<branch>/` and prunes it.
 
 ## Min-age guard
 
 Worktrees younger than `--min-age` (default: 1 day) are skipped. This prevents removing a worktree just created from the default branch — it looks "merged" because its branch points at the same commit.
 
+A branch whose upstream is gone — deleted from the remote and pruned by `git fetch --prune`, `⊘` in `wt list` — bypasses the guard: integrated and gone, it was pushed, merged, and cleaned up, so it can't be a fresh branch. `--dry-run` marks these `upstream gone`.
+
 ```console
 $ wt step prune --min-age=0s     # no age guard
 $ wt step prune --min-age=2d     # skip worktrees younger than 2 days
 ```
 
+## Prune rules
+
+`[step.prune]` rules in project config (`.config/wt.toml`) codify a team's cleanup policy per branch pattern. The first rule whose `branch` glob matches applies: `keep = true` never prunes the branch, and `min-age` replaces the 1-day default for it. An explicit `--min-age` still wins over rule ages.
+
+```toml
+[[step.prune.rules]]
+branch = "release/*"
+keep = true
+
+[[step.prune.rules]]
+branch = "dependabot/*"
+min-age = "0s"
+
+[[step.prune.rules]]
+branch = "experiment/*"
+min-age = "14d"
+```
+
+Rules in user config are checked before the project's.
+
 ## Examples
 
 Preview what would be removed:
@@ -608,6 +813,15 @@ Remove all merged worktrees:
 ```console
 $ wt step prune
 ```
+
+## Reports
+
+`--report` records each pruned branch with its commit, integration reason, and the disk space its worktree took, plus what was skipped and why. Markdown suits a cleanup summary; JSON suits audit logs. With `--dry-run`, it reports what would be pruned.
+
+```console
+$ wt step prune --report=prune.md
+$ wt step prune --report=- | jq '.bytes'
+```
 "#
     )]
     Prune {
@@ -616,8 +830,10 @@ $ wt step prune
         dry_run: bool,
 
         /// Skip worktrees younger than this
-        #[arg(long, default_value = "1d")]
-        min_age: String,
+        ///
+        /// Defaults to 1d. Overrides `min-age` from `[step.prune]` rules.
+        #[arg(long)]
+        min_age: Option<String>,
 
         /// Run removal in foreground (block until complete)
         #[arg(long)]
@@ -626,6 +842,12 @@ $ wt step prune
         /// Output format
         #[arg(long, default_value = "text")]
         format: crate::cli::SwitchFormat,
+
+        /// Write a report of what was pruned to this file (`-` for stdout)
+        ///
+        /// Markdown when the file ends in `.md`, JSON otherwise.
+        #[arg(long, value_name = "FILE")]
+        report: Option<std::path::PathBuf>,
     },
 
     /// \[experimental\] Move worktrees to expected paths
@@ -718,6 +940,49 @@ Note: This command is experimental and may change in future versions.
         format: crate::cli::SwitchFormat,
     },
 
+    /// \[experimental\] Back out a merged branch
+    #[command(
+        after_long_help = r#"Reverts what `wt merge` landed on the target branch, leaving a revert commit on top — the target's history is never rewritten. With `--restore`, the branch comes back at its pre-merge commit, with its worktree and any uncommitted changes, so work can continue.
+
+Each completed `wt merge` records the target before and after the merge, the branch's original tip, and the backup of its uncommitted changes. Without an argument, the most recent merge is reverted.
+
+## Examples
+
+Undo the last merge:
+
+```console
+$ wt step revert-merge
+```
+
+Undo the merge of `feature` and bring its worktree back:
+
+```console
+$ wt step revert-merge feature --restore
+```
+
+## How the merge is reverted
+
+- A squash (or single-commit) merge gets a plain `git revert`
+- A `--no-ff` merge commit is reverted against its first parent
+- Several fast-forwarded commits are reverted together in one commit
+
+The revert runs in the target branch's worktree, which must be clean. If later commits conflict with the revert, it stops for `git revert --continue` or `git revert --abort`.
+
+Note: This command is experimental and may change in future versions.
+"#
+    )]
+    RevertMerge {
+        /// Merged branch to revert
+        ///
+        /// Defaults to the most recently merged branch.
+        #[arg(add = crate::completion::branch_value_completer(), value_parser = crate::cli::non_empty_branch)]
+        branch: Option<String>,
+
+        /// Re-create the branch and its worktree
+        #[arg(long)]
+        restore: bool,
+    },
+
     /// \[experimental\] Run a command; kill its whole process tree when its worktree is removed
     ///
     /// Teardown is automatic and needs no `pre-remove` hook; the group gets `SIGTERM` then `SIGKILL`.
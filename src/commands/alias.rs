@@ -72,16 +72,20 @@ use crate::commands::{build_invalid_subcommand_error, similar_subcommands};
 /// Built-in `wt step` subcommand names. Aliases with these names are
 /// reachable via `wt <name>` (top-level) but shadowed via `wt step <name>`.
 const BUILTIN_STEP_COMMANDS: &[&str] = &[
+    "clone",
     "commit",
     "copy-ignored",
     "diff",
+    "diff-branches",
     "eval",
     "for-each",
+    "optimize-repo",
     "promote",
     "prune",
     "push",
     "rebase",
     "relocate",
+    "revert-merge",
     "squash",
     "tether",
 ];
@@ -92,7 +96,30 @@ const BUILTIN_STEP_COMMANDS: &[&str] = &[
 /// if `[aliases] list = …` is configured. Kept in sync with `Cli` via
 /// `test_top_level_builtins_match_clap`.
 pub(crate) const TOP_LEVEL_BUILTINS: &[&str] = &[
-    "config", "hook", "list", "merge", "remove", "select", "step", "switch",
+    "completions",
+    "config",
+    "daemon",
+    "debug",
+    "grep",
+    "hints",
+    "hook",
+    "list",
+    "lock-file",
+    "map",
+    "merge",
+    "open",
+    "path",
+    "pin",
+    "remove",
+    "repair",
+    "select",
+    "serve",
+    "setup",
+    "snapshot",
+    "stats",
+    "step",
+    "switch",
+    "unpin",
 ];
 
 /// Whether `--help` or `-h` appears in `args` before any `--` literal-forward
@@ -645,6 +672,7 @@ fn run_alias(
                     context: context_map.clone(),
                     template_name: alias_name.clone(),
                     label: alias_name.clone(),
+                    cwd: cmd.cwd.clone(),
                 })
             })?;
             for step in steps {
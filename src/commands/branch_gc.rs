@@ -0,0 +1,147 @@
+//! Sweep stored data left behind by deleted branches.
+//!
+//! Deleting a branch through wt drops its `worktrunk.state.<branch>.*` keys
+//! on the spot (see [`worktrunk::git::forget_deleted_branch`]). Branches
+//! deleted any other way — `git branch -D`, a detached background removal,
+//! a rename — leave their data behind: state keys, the CI and summary cache
+//! entries, the per-branch log tree, and hook status records. This module
+//! finds data whose branch no longer exists and removes it.
+//!
+//! `wt step prune` runs the sweep without logs after its removals; `wt config
+//! state gc` runs all of it. Merge records stay — `wt step revert-merge
+//! --restore` reads them to bring a deleted branch back — and approvals are
+//! per project, not per branch, so neither is touched.
+
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use worktrunk::git::Repository;
+use worktrunk::path::sanitize_for_filename;
+
+use super::list::ci_status::CachedCiStatus;
+use crate::summary::CachedSummary;
+
+/// A log tree written to this recently may belong to a removal still running
+/// in the background (`{branch}/internal/remove.log`, post-remove hooks).
+const RECENT_LOG_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// What a sweep removed.
+#[derive(Debug, Default)]
+pub(crate) struct SweptBranchData {
+    /// Deleted branches that had data removed.
+    pub branches: BTreeSet<String>,
+    pub state_keys: usize,
+    pub ci_entries: usize,
+    pub summaries: usize,
+    /// Log files removed (per-branch log directories are named after the
+    /// sanitized branch, so they don't count towards `branches`).
+    pub log_files: usize,
+    /// Hook status records removed (keyed like the log tree).
+    pub hook_records: usize,
+}
+
+impl SweptBranchData {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.state_keys + self.ci_entries + self.summaries + self.log_files + self.hook_records == 0
+    }
+}
+
+/// Remove the stored data of every branch that no longer exists, plus its
+/// log tree when `include_logs` is set.
+pub(crate) fn sweep_deleted_branches(
+    repo: &Repository,
+    include_logs: bool,
+) -> anyhow::Result<SweptBranchData> {
+    let refs = repo.capture_refs()?;
+    // Remote-only rows in `wt list` cache CI under the remote-qualified name
+    let live: HashSet<&str> = refs
+        .local_branches()
+        .iter()
+        .map(|b| b.name.as_str())
+        .chain(refs.remote_branches().iter().map(|b| b.short_name.as_str()))
+        .collect();
+    let mut swept = SweptBranchData::default();
+
+    for (branch, key) in repo.branch_state_keys()? {
+        if !live.contains(branch.as_str()) {
+            repo.unset_config(&key)?;
+            swept.state_keys += 1;
+            swept.branches.insert(branch);
+        }
+    }
+
+    for entry in CachedCiStatus::list_all(repo) {
+        if !live.contains(entry.branch.as_str()) && CachedCiStatus::clear_one(repo, &entry.branch)?
+        {
+            swept.ci_entries += 1;
+            swept.branches.insert(entry.branch);
+        }
+    }
+
+    for entry in CachedSummary::list_all(repo) {
+        if !live.contains(entry.branch.as_str()) {
+            swept.summaries += CachedSummary::clear_branch(repo, &entry.branch)?;
+            swept.branches.insert(entry.branch);
+        }
+    }
+
+    if include_logs {
+        let kept: HashSet<String> = live
+            .iter()
+            .map(|branch| sanitize_for_filename(branch))
+            // Detached worktrees log under `HEAD`
+            .chain(["HEAD".to_string()])
+            .collect();
+        swept.log_files = sweep_log_dirs(&repo.wt_logs_dir(), &kept)?;
+        swept.hook_records = sweep_log_dirs(&super::hook_status::status_dir(repo), &kept)?;
+    }
+
+    Ok(swept)
+}
+
+/// Remove per-branch log directories not named in `kept`, skipping any
+/// written to within [`RECENT_LOG_AGE`]. Returns the number of files removed.
+fn sweep_log_dirs(log_dir: &Path, kept: &HashSet<String>) -> anyhow::Result<usize> {
+    let entries = match std::fs::read_dir(log_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut removed = 0;
+    for entry in entries {
+        let entry = entry?;
+        // Top-level files are shared logs, never per-branch
+        if !entry.file_type()?.is_dir() || kept.contains(&*entry.file_name().to_string_lossy()) {
+            continue;
+        }
+        let path = entry.path();
+        let (files, newest) = scan_tree(&path)?;
+        if newest.elapsed().unwrap_or_default() < RECENT_LOG_AGE {
+            continue;
+        }
+        std::fs::remove_dir_all(&path)?;
+        removed += files;
+    }
+    Ok(removed)
+}
+
+/// Count the files under `dir` and find the newest modification time.
+fn scan_tree(dir: &Path) -> anyhow::Result<(usize, SystemTime)> {
+    let mut files = 0;
+    let mut newest = SystemTime::UNIX_EPOCH;
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_dir() {
+            let (sub_files, sub_newest) = scan_tree(&entry.path())?;
+            files += sub_files;
+            newest = newest.max(sub_newest);
+        } else {
+            files += 1;
+            newest = newest.max(metadata.modified()?);
+        }
+    }
+    Ok((files, newest))
+}
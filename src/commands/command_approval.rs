@@ -16,21 +16,131 @@
 //!
 //! This ensures approval happens exactly once at the command entry point,
 //! eliminating the need to thread `auto_trust` through execution layers.
+//!
+//! # Approving without a prompt
+//!
+//! Where no prompt can be shown (CI, agents, SSH without a TTY), the
+//! [`ApprovePolicy`] from `--approve` and `WORKTRUNK_APPROVE` decides instead:
+//!
+//! - `never` — decline every command that isn't already approved
+//! - `all` — approve everything, like `--yes`
+//! - `hash:<hash>` (comma-separated, or `--approve <hash>`) — approve just the
+//!   commands with those [`command_hash`]es
+//!
+//! Unset, an unapproved command still needs a prompt, and a non-interactive
+//! run fails with the hashes it would need. Approvals granted by policy are
+//! not saved.
 
+use std::collections::HashSet;
 use std::io::{self, IsTerminal, Write};
 use std::path::Path;
+use std::sync::OnceLock;
 
 use anyhow::Context;
 use color_print::cformat;
-use worktrunk::config::{Approvals, require_approvals_path};
-use worktrunk::git::{GitError, HookType};
+use worktrunk::config::{Approvals, command_hash, require_approvals_path};
+use worktrunk::git::{GitError, HookType, Repository};
 use worktrunk::styling::{
-    INFO_SYMBOL, WARNING_SYMBOL, eprint, eprintln, format_bash_with_gutter, format_with_gutter,
-    hint_message, prompt_message, stderr, warning_message,
+    INFO_SYMBOL, WARNING_SYMBOL, eprint, eprintln, format_bash_with_gutter, format_toml,
+    format_with_gutter, hint_message, prompt_message, stderr, warning_message,
 };
 
 use super::hook_filter::{HookSource, ParsedFilter};
-use super::project_config::{ApprovableCommand, Phase, collect_commands_for_hooks};
+use super::project_config::{
+    ApprovableCommand, Phase, collect_commands_for_hooks, collect_commands_for_list_columns,
+};
+
+/// Environment variable holding the non-interactive approval policy.
+const APPROVE_ENV: &str = "WORKTRUNK_APPROVE";
+
+static APPROVE_POLICY: OnceLock<ApprovePolicy> = OnceLock::new();
+
+/// How unapproved project commands resolve without a prompt. See the module
+/// docs.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ApprovePolicy {
+    /// Approve every command.
+    all: bool,
+    /// Decline instead of prompting.
+    never: bool,
+    /// Hashes of commands approved for this run.
+    hashes: HashSet<String>,
+}
+
+impl ApprovePolicy {
+    /// Build the policy from a `WORKTRUNK_APPROVE` value and `--approve`
+    /// hashes. Unrecognized entries are returned so the caller can warn —
+    /// ignoring them keeps the default of asking.
+    fn parse(env: Option<&str>, flags: &[String]) -> (Self, Vec<String>) {
+        let mut policy = Self::default();
+        let mut invalid = Vec::new();
+        let entries = env
+            .into_iter()
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty());
+        for entry in entries {
+            match entry {
+                "never" => policy.never = true,
+                "all" => policy.all = true,
+                _ => match entry.strip_prefix("hash:").and_then(parse_hash) {
+                    Some(hash) => {
+                        policy.hashes.insert(hash);
+                    }
+                    None => invalid.push(entry.to_string()),
+                },
+            }
+        }
+        for flag in flags {
+            match parse_hash(flag.strip_prefix("hash:").unwrap_or(flag)) {
+                Some(hash) => {
+                    policy.hashes.insert(hash);
+                }
+                None => invalid.push(flag.clone()),
+            }
+        }
+        // A policy asking for both is contradictory; the safe reading wins.
+        if policy.never {
+            policy.all = false;
+        }
+        (policy, invalid)
+    }
+
+    /// Whether `command` may run without asking.
+    pub fn approves(&self, command: &str) -> bool {
+        self.all || self.hashes.contains(&command_hash(command))
+    }
+}
+
+fn parse_hash(hash: &str) -> Option<String> {
+    (hash.len() == 16 && hash.chars().all(|c| c.is_ascii_hexdigit()))
+        .then(|| hash.to_ascii_lowercase())
+}
+
+/// Set the approval policy from `--approve` flags and `WORKTRUNK_APPROVE`.
+/// Call once at startup, before any approval; invalid entries are warned
+/// about and ignored.
+pub fn init_approve_policy(flags: &[String]) {
+    let env = std::env::var(APPROVE_ENV).ok();
+    let (policy, invalid) = ApprovePolicy::parse(env.as_deref(), flags);
+    for entry in invalid {
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Ignoring approval <bold>{entry}</>: expected <bold>never</>, <bold>all</>, or a 16-character command hash"
+            ))
+        );
+    }
+    let _ = APPROVE_POLICY.set(policy);
+}
+
+/// The approval policy for this process.
+pub fn approve_policy() -> &'static ApprovePolicy {
+    APPROVE_POLICY.get_or_init(|| {
+        let env = std::env::var(APPROVE_ENV).ok();
+        ApprovePolicy::parse(env.as_deref(), &[]).0
+    })
+}
 
 /// Batch approval helper used when multiple commands are queued for execution.
 /// Returns `Ok(true)` when execution may continue, `Ok(false)` when the user
@@ -60,8 +170,21 @@ pub fn approve_command_batch(
         return Ok(true);
     }
 
+    // Commands the policy approves run without asking; only the rest reach
+    // the prompt
+    let policy = approve_policy();
+    let needs_approval: Vec<&ApprovableCommand> = needs_approval
+        .into_iter()
+        .filter(|cmd| !policy.approves(&cmd.command.template))
+        .collect();
+    if needs_approval.is_empty() {
+        return Ok(true);
+    }
+
     let approved = if yes {
         true
+    } else if policy.never {
+        false
     } else {
         prompt_for_batch_approval(&needs_approval, project_id)?
     };
@@ -99,11 +222,7 @@ fn prompt_for_batch_approval(
     commands: &[&ApprovableCommand],
     project_id: &str,
 ) -> anyhow::Result<bool> {
-    // Extract just the directory name for display
-    let project_name = Path::new(project_id)
-        .file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or(project_id);
+    let project_name = project_display_name(project_id);
     let count = commands.len();
     let plural = if count == 1 { "" } else { "s" };
 
@@ -137,7 +256,11 @@ fn prompt_for_batch_approval(
     // This happens AFTER showing the commands so they appear in CI/CD logs
     // even when the prompt cannot be displayed (fail-fast principle)
     if !io::stdin().is_terminal() {
-        return Err(GitError::NotInteractive.into());
+        let hashes = commands
+            .iter()
+            .map(|cmd| command_hash(&cmd.command.template))
+            .collect();
+        return Err(GitError::NotInteractive { hashes }.into());
     }
 
     // Blank line before prompt for visual separation
@@ -156,6 +279,58 @@ fn prompt_for_batch_approval(
     Ok(response.trim().eq_ignore_ascii_case("y"))
 }
 
+/// Ask whether to trust `content`, fetched from the remote project config
+/// include `url`.
+///
+/// The same gates as commands: the approval policy first (the content's
+/// [`command_hash`] works with `--approve`), then a prompt; without a TTY it
+/// fails with the hash. Saving is up to the caller.
+pub fn approve_remote_include(project_id: &str, url: &str, content: &str) -> anyhow::Result<bool> {
+    let policy = approve_policy();
+    if policy.approves(content) {
+        return Ok(true);
+    }
+    if policy.never {
+        return Ok(false);
+    }
+
+    let project_name = project_display_name(project_id);
+    eprintln!(
+        "{}",
+        cformat!(
+            "{WARNING_SYMBOL} <yellow><bold>{project_name}</> needs approval to include <bold>{url}</>:</>"
+        )
+    );
+    eprintln!("{}", format_toml(content));
+
+    if !io::stdin().is_terminal() {
+        return Err(GitError::NotInteractive {
+            hashes: vec![command_hash(content)],
+        }
+        .into());
+    }
+
+    worktrunk::styling::eprintln!();
+    eprint!(
+        "{} ",
+        prompt_message(cformat!("Allow and remember? <bold>[y/N]</>"))
+    );
+    stderr().flush()?;
+
+    let mut response = String::new();
+    io::stdin().read_line(&mut response)?;
+
+    Ok(response.trim().eq_ignore_ascii_case("y"))
+}
+
+/// The directory name of a project identifier, for prompts.
+fn project_display_name(project_id: &str) -> &str {
+    Path::new(project_id)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(project_id)
+}
+
 /// Approve a project-config alias before execution.
 ///
 /// Returns `Ok(true)` if approved (or already approved), `Ok(false)` if declined.
@@ -181,6 +356,26 @@ pub fn approve_alias_commands(
     approve_command_batch(&cmds, project_id, &approvals, yes, false)
 }
 
+/// Approve the project's `[list.columns]` commands before `wt list` runs them.
+///
+/// Only prompts when stdin is a terminal: a piped or scripted `wt list` never
+/// blocks on a question. Declining is non-fatal. Either way `collect` skips
+/// the unapproved columns and says so.
+pub fn approve_list_columns(repo: &Repository) -> anyhow::Result<()> {
+    let Some(project_config) = repo.load_project_config()? else {
+        return Ok(());
+    };
+    let commands = collect_commands_for_list_columns(&project_config);
+    if commands.is_empty() || !io::stdin().is_terminal() {
+        return Ok(());
+    }
+
+    let project_id = repo.project_identifier()?;
+    let approvals = Approvals::load().context("Failed to load approvals")?;
+    approve_command_batch(&commands, &project_id, &approvals, false, false)?;
+    Ok(())
+}
+
 /// Collect project commands for hooks and request batch approval.
 ///
 /// This is the "gate" function that should be called at command entry points
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 
@@ -5,10 +6,11 @@ use anyhow::Result;
 use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::{
-    Command, CommandConfig, HookStep, UserConfig, expand_template, format_hook_variables,
+    Command, CommandConfig, HookStep, JJ_VARS, UserConfig, expand_template, format_hook_variables,
     template_references_var, validate_template_syntax,
 };
 use worktrunk::git::{ErrorExt, Repository, WorktrunkError};
+use worktrunk::jj::JjMetadata;
 use worktrunk::path::{format_path_for_display, to_posix_path};
 use worktrunk::shell_exec::ShellEscapeMode;
 use worktrunk::styling::{
@@ -19,6 +21,7 @@ use worktrunk::trace::Span;
 
 use super::format_command_label;
 use super::hook_filter::HookSource;
+use super::hook_sandbox::sandbox_command;
 use crate::output::concurrent::{ConcurrentCommand, run_concurrent_commands};
 use crate::output::{DirectivePassthrough, execute_shell_command};
 
@@ -39,14 +42,14 @@ pub struct PreparedCommand {
     /// Label for the per-command announcement summary and render span.
     /// For hooks: `"user:foo"` for named, `"user"` for unnamed. For aliases: alias name.
     pub label: String,
+    /// Raw `cwd` template, rendered when the command runs; `None` runs in the
+    /// worktree.
+    pub cwd: Option<String>,
 }
 
-impl PreparedCommand {
-    /// The JSON form of `context` piped to the child's stdin.
-    pub fn context_json(&self) -> String {
-        serde_json::to_string(&self.context)
-            .expect("HashMap<String, String> serialization should never fail")
-    }
+/// The JSON form of a command's context, piped to the child's stdin.
+fn context_json(context: &HashMap<String, String>) -> String {
+    serde_json::to_string(context).expect("HashMap<String, String> serialization should never fail")
 }
 
 /// A step in a prepared pipeline, mirroring `HookStep`.
@@ -134,6 +137,8 @@ pub struct ForegroundStep {
     /// than per-pipeline so a merged user+project alias relaxes the user's
     /// own steps without leaking the project's body into the parent shell.
     pub directives: DirectivePassthrough,
+    /// Run under the user's `[sandbox]` restrictions (project hook steps).
+    pub sandboxed: bool,
 }
 
 /// Controls how foreground execution responds to command failures.
@@ -346,6 +351,16 @@ pub fn build_hook_context(
         }
     }
 
+    // jj workspace metadata, empty outside a jj workspace so shared templates
+    // still render. Describes where the command runs, like `cwd`.
+    if JJ_VARS.iter().any(|var| want(var)) {
+        let _span = Span::new("var_jj");
+        let jj = JjMetadata::for_path(ctx.worktree_path);
+        map.insert("change_id".into(), jj.change_id);
+        map.insert("bookmark".into(), jj.bookmark);
+        map.insert("workspace".into(), jj.workspace);
+    }
+
     // Execution directory — always where the hook command runs, even when
     // worktree_path points to an Active identity that doesn't exist on disk.
     map.insert(
@@ -412,12 +427,37 @@ pub fn expand_shell_template(
     )?)
 }
 
-/// Resolve the shell string to execute for a prepared command.
+/// Resolve where a command runs: its rendered `cwd` template, relative to
+/// `wt_path` unless absolute, or `wt_path` itself when there's none.
 ///
-/// Templates render at execution time against the frozen `context`, so they
-/// see fresh git-config state (`vars.*` set by earlier steps).
-fn resolve_command_str(cmd: &PreparedCommand, repo: &Repository) -> Result<String> {
-    expand_shell_template(&cmd.template, &cmd.context, repo, &cmd.template_name)
+/// Returns the directory and the context with `cwd` pointing at it, so the
+/// command and its stdin JSON describe where it actually runs. A directory
+/// that doesn't exist fails the command before it's announced.
+pub fn resolve_working_dir<'a>(
+    cwd: Option<&str>,
+    context: &'a HashMap<String, String>,
+    repo: &Repository,
+    label: &str,
+    wt_path: &Path,
+) -> Result<(PathBuf, Cow<'a, HashMap<String, String>>)> {
+    let Some(cwd) = cwd else {
+        return Ok((wt_path.to_path_buf(), Cow::Borrowed(context)));
+    };
+    let vars: HashMap<&str, &str> = context
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    let rendered = expand_template(cwd, &vars, ShellEscapeMode::Literal, repo, label)?;
+    let dir = wt_path.join(rendered.trim());
+    if !dir.is_dir() {
+        anyhow::bail!(cformat!(
+            "Working directory for <bold>{label}</> not found: <bold>{}</>",
+            format_path_for_display(&dir)
+        ));
+    }
+    let mut context = context.clone();
+    context.insert("cwd".into(), to_posix_path(&dir.to_string_lossy()));
+    Ok((dir, Cow::Owned(context)))
 }
 
 /// Render a template for dry-run / preview display. Mirrors execution-time
@@ -493,17 +533,35 @@ fn run_concurrent_group(
 ) -> anyhow::Result<()> {
     let directives = &fg_step.directives;
 
-    let expanded: Vec<String> = cmds
+    let resolved: Vec<_> = cmds
         .iter()
         .map(|cmd| {
+            resolve_working_dir(
+                cmd.cwd.as_deref(),
+                &cmd.context,
+                repo,
+                &cmd.template_name,
+                wt_path,
+            )
+        })
+        .collect::<Result<_>>()?;
+    let expanded: Vec<String> = cmds
+        .iter()
+        .zip(&resolved)
+        .map(|(cmd, (_, context))| {
             let _span = Span::new(format!("template_render:{}", cmd.label));
-            resolve_command_str(cmd, repo)
+            expand_shell_template(&cmd.template, context, repo, &cmd.template_name)
         })
         .collect::<Result<_>>()?;
 
-    for (cmd, command_str) in cmds.iter().zip(&expanded) {
-        announce_command(cmd, &fg_step.announce, command_str);
+    for ((cmd, command_str), (dir, _)) in cmds.iter().zip(&expanded).zip(&resolved) {
+        let dir = cmd.cwd.is_some().then_some(dir.as_path());
+        announce_command(cmd, &fg_step.announce, command_str, dir);
     }
+    let expanded: Vec<String> = expanded
+        .into_iter()
+        .map(|command_str| sandboxed_command(fg_step, repo, wt_path, command_str))
+        .collect::<Result<_>>()?;
 
     // Both alias tables and hook tables produce named commands (TOML keys
     // become `name`), so `cmd.name` is always `Some` here.
@@ -516,7 +574,10 @@ fn run_concurrent_group(
         })
         .collect();
 
-    let context_jsons: Vec<String> = cmds.iter().map(PreparedCommand::context_json).collect();
+    let context_jsons: Vec<String> = resolved
+        .iter()
+        .map(|(_, context)| context_json(context))
+        .collect();
     let log_labels: Vec<Option<String>> = cmds
         .iter()
         .map(|cmd| fg_step.announce.log_label(cmd))
@@ -526,7 +587,7 @@ fn run_concurrent_group(
         .map(|i| ConcurrentCommand {
             label: labels[i],
             expanded: &expanded[i],
-            working_dir: wt_path,
+            working_dir: &resolved[i].0,
             context_json: &context_jsons[i],
             log_label: log_labels[i].as_deref(),
             directives,
@@ -563,19 +624,28 @@ fn run_one_command(
 ) -> anyhow::Result<()> {
     let directives = &fg_step.directives;
 
+    let (dir, context) = resolve_working_dir(
+        cmd.cwd.as_deref(),
+        &cmd.context,
+        repo,
+        &cmd.template_name,
+        wt_path,
+    )?;
     let command_str = {
         let _span = Span::new(format!("template_render:{}", cmd.label));
-        resolve_command_str(cmd, repo)?
+        expand_shell_template(&cmd.template, &context, repo, &cmd.template_name)?
     };
-    announce_command(cmd, &fg_step.announce, &command_str);
+    let dir_override = cmd.cwd.is_some().then_some(dir.as_path());
+    announce_command(cmd, &fg_step.announce, &command_str, dir_override);
+    let command_str = sandboxed_command(fg_step, repo, wt_path, command_str)?;
 
     // Hooks get a documented JSON context on stdin; aliases inherit stdin so
     // interactive children (e.g. `wt switch`'s picker) keep their controlling
     // terminal. Piping JSON into an interactive alias body steals the tty.
-    let stdin_json = fg_step.pipe_stdin.then(|| cmd.context_json());
+    let stdin_json = fg_step.pipe_stdin.then(|| context_json(&context));
     let log_label = fg_step.announce.log_label(cmd);
     let result = execute_shell_command(
-        wt_path,
+        &dir,
         &command_str,
         stdin_json.as_deref(),
         log_label.as_deref(),
@@ -589,13 +659,33 @@ fn run_one_command(
     }
 }
 
+/// Apply the user's `[sandbox]` restrictions to a project hook command. The
+/// announcement shows the command as written; only the spawned one is wrapped.
+fn sandboxed_command(
+    fg_step: &ForegroundStep,
+    repo: &Repository,
+    wt_path: &Path,
+    command_str: String,
+) -> anyhow::Result<String> {
+    if !fg_step.sandboxed {
+        return Ok(command_str);
+    }
+    sandbox_command(&repo.user_config().sandbox, &command_str, wt_path)
+}
+
 /// Announce a command before execution, formatted per the step's pipeline kind.
 ///
 /// Hook pipelines emit a per-command "Running …" line plus a bash gutter
 /// showing `command_str` — the rendered command about to run. Alias pipelines
 /// emit nothing — the alias caller renders a single pipeline summary
-/// externally.
-fn announce_command(cmd: &PreparedCommand, kind: &PipelineKind, command_str: &str) {
+/// externally. A command with its own `cwd` shows that directory in place of
+/// the pipeline's display path.
+fn announce_command(
+    cmd: &PreparedCommand,
+    kind: &PipelineKind,
+    command_str: &str,
+    cwd: Option<&Path>,
+) {
     let PipelineKind::Hook {
         hook_type,
         display_path,
@@ -603,12 +693,13 @@ fn announce_command(cmd: &PreparedCommand, kind: &PipelineKind, command_str: &st
     else {
         return;
     };
+    let display_path = cwd.or(display_path.as_deref());
 
     let full_label = match &cmd.name {
         Some(_) => format_command_label(&hook_type.to_string(), Some(&cmd.label)),
         None => format!("Running {hook_type} {} hook", cmd.label),
     };
-    let message = match display_path.as_deref() {
+    let message = match display_path {
         Some(path) => {
             let path_display = format_path_for_display(path);
             cformat!("{full_label} @ <bold>{path_display}</>")
@@ -715,6 +806,55 @@ pub fn map_config_steps(
         .collect()
 }
 
+/// Drop commands whose `when-changed` files all match the base branch.
+///
+/// The base is the `base` var when the caller supplies one (`wt switch
+/// --create`), otherwise the default branch. Files are compared as they stand
+/// in `ctx.worktree_path` — a single `git diff --quiet <base> -- <files>`.
+/// Any doubt (no base, unknown ref, git failure) keeps the command: a
+/// redundant install is cheaper than a missing one. Each skipped command gets
+/// an info line.
+pub fn skip_unchanged_commands<'a>(
+    config: &'a CommandConfig,
+    ctx: &CommandContext<'_>,
+    extra_vars: &[(&str, &str)],
+) -> Cow<'a, CommandConfig> {
+    if config.commands().all(|cmd| cmd.when_changed.is_empty()) {
+        return Cow::Borrowed(config);
+    }
+    let base = extra_vars
+        .iter()
+        .find(|(k, _)| *k == "base")
+        .map(|(_, v)| v.to_string())
+        .or_else(|| ctx.repo.default_branch());
+    let Some(base) = base else {
+        return Cow::Borrowed(config);
+    };
+
+    let worktree = ctx.repo.worktree_at(ctx.worktree_path);
+    Cow::Owned(config.retain_commands(|cmd| {
+        if cmd.when_changed.is_empty() {
+            return true;
+        }
+        let mut args = vec!["diff", "--quiet", base.as_str(), "--"];
+        args.extend(cmd.when_changed.iter().map(String::as_str));
+        let unchanged = worktree
+            .run_command_output(&args)
+            .is_ok_and(|output| output.status.success());
+        if unchanged {
+            let name = cmd.name.as_deref().unwrap_or("command");
+            eprintln!(
+                "{}",
+                info_message(cformat!(
+                    "Skipping <bold>{name}</>: {} unchanged from <bold>{base}</>",
+                    cmd.when_changed.join(", ")
+                ))
+            );
+        }
+        !unchanged
+    }))
+}
+
 /// Prepare hook pipeline steps for execution, preserving serial/concurrent
 /// structure. All hook preparation goes through this function (both
 /// foreground and background paths).
@@ -763,6 +903,7 @@ pub fn prepare_steps(
             context: cmd_context,
             template_name,
             label: command_summary_name(cmd.name.as_deref(), source),
+            cwd: cmd.cwd.clone(),
         })
     })
 }
@@ -779,6 +920,7 @@ mod tests {
             context: HashMap::new(),
             template_name: label.clone(),
             label,
+            cwd: None,
         }
     }
 
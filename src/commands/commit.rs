@@ -2,6 +2,7 @@ use anyhow::Context;
 use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::CommitGenerationConfig;
+use worktrunk::git::WorkingTree;
 use worktrunk::styling::{
     eprintln, format_with_gutter, hint_message, info_message, progress_message, success_message,
 };
@@ -241,11 +242,18 @@ impl CommitOptions<'_> {
             .map_or_else(TemplateVars::new, |t| TemplateVars::new().with_target(t));
 
         if self.hooks.run() {
-            // Run pre-commit hooks (user first, then project).
+            // Run pre-commit hooks (user first, then project). They run before
+            // staging, so the file list is what the stage mode will pick up.
+            let wt = self.ctx.repo.worktree_at(self.ctx.worktree_path);
+            let files = files_to_commit(&wt, self.stage_mode)?;
+            let pre_commit_vars = self
+                .target_branch
+                .map_or_else(TemplateVars::new, |t| TemplateVars::new().with_target(t))
+                .with_changed_files(&files);
             execute_hook(
                 self.ctx,
                 HookType::PreCommit,
-                &template_vars.as_extra_vars(),
+                &pre_commit_vars.as_extra_vars(),
                 FailureStrategy::FailFast,
             )?;
         }
@@ -307,6 +315,27 @@ impl CommitOptions<'_> {
     }
 }
 
+/// Files a commit with `stage_mode` will record, for the pre-commit
+/// `changed_files` var. Computed before staging: the index (`git diff
+/// --cached` also covers an unborn HEAD), plus unstaged tracked changes
+/// unless only the index is committed, plus untracked files when everything
+/// is staged. Deletions are left out.
+pub(crate) fn files_to_commit(
+    wt: &WorkingTree<'_>,
+    stage_mode: StageMode,
+) -> anyhow::Result<Vec<String>> {
+    let mut files = wt.changed_files(&["--cached"])?;
+    if stage_mode != StageMode::None {
+        files.extend(wt.changed_files(&[])?);
+    }
+    if stage_mode == StageMode::All {
+        files.extend(wt.untracked_files()?);
+    }
+    let mut seen = std::collections::HashSet::new();
+    files.retain(|file| seen.insert(file.clone()));
+    Ok(files)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
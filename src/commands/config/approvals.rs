@@ -1,24 +1,41 @@
 //! Approvals commands for `wt config approvals` subcommand.
 //!
-//! - `add_approvals` - Approve all project commands (hooks and aliases)
+//! - `add_approvals` - Approve remote config includes, then all project commands
+//!   (hooks, aliases, and list columns)
 //! - `clear_approvals` - Clear approved commands
+//! - `export_approvals` / `import_approvals` - Share approvals as a TOML snippet
+
+use std::path::Path;
 
 use anyhow::Context;
+use color_print::cformat;
 use strum::IntoEnumIterator;
 use worktrunk::HookType;
-use worktrunk::config::{Approvals, require_approvals_path};
+use worktrunk::config::{
+    Approvals, ApprovalsSnippet, command_hash, remote_includes, require_approvals_path,
+    store_include, stored_include_path,
+};
 use worktrunk::git::{GitError, Repository};
-use worktrunk::styling::{eprintln, info_message, success_message};
+use worktrunk::path::format_path_for_display;
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{
+    eprintln, format_bash_with_gutter, hint_message, info_message, print, success_message,
+    warning_message,
+};
 
-use crate::commands::command_approval::approve_command_batch;
+use crate::commands::command_approval::{approve_command_batch, approve_remote_include};
 use crate::commands::project_config::{
     ApprovableCommand, collect_commands_for_aliases, collect_commands_for_hooks,
+    collect_commands_for_list_columns,
 };
 
-/// Handle `wt config approvals add` command - approve all hook and alias commands in the project
+/// Handle `wt config approvals add` command - approve all project commands
 pub fn add_approvals(show_all: bool) -> anyhow::Result<()> {
     let repo = Repository::current()?;
     let project_id = repo.project_identifier()?;
+
+    // Includes first: approving one changes which commands the config has
+    approve_remote_includes(&repo, &project_id, show_all)?;
     let approvals = Approvals::load().context("Failed to load approvals")?;
 
     // Load project config (error if missing - this command requires it)
@@ -30,10 +47,12 @@ pub fn add_approvals(show_all: bool) -> anyhow::Result<()> {
         .ok_or(GitError::ProjectConfigNotFound { config_path })?;
 
     // Collect all commands from the project config: hooks first (lifecycle order),
-    // then aliases (alphabetical via BTreeMap), then any commit-message guidance.
+    // then aliases and list columns (alphabetical via BTreeMap), then any
+    // commit-message guidance.
     let all_hooks: Vec<_> = HookType::iter().collect();
     let mut commands = collect_commands_for_hooks(&project_config, &all_hooks);
     commands.extend(collect_commands_for_aliases(&project_config));
+    commands.extend(collect_commands_for_list_columns(&project_config));
     if let Some(fragment) = project_config.commit_template_append() {
         commands.push(ApprovableCommand::commit_template_append(
             fragment.to_string(),
@@ -79,6 +98,165 @@ pub fn add_approvals(show_all: bool) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Fetch each remote `include` of the project config and ask to approve its
+/// content, unless that exact content is already approved (with `show_all`,
+/// ask regardless).
+fn approve_remote_includes(
+    repo: &Repository,
+    project_id: &str,
+    show_all: bool,
+) -> anyhow::Result<()> {
+    let urls = remote_includes(repo)?;
+    if urls.is_empty() {
+        return Ok(());
+    }
+    if repo.user_config().offline() {
+        eprintln!(
+            "{}",
+            warning_message("Offline; skipping remote project config includes")
+        );
+        return Ok(());
+    }
+
+    let approvals = Approvals::load().context("Failed to load approvals")?;
+    for url in urls {
+        let content = fetch_include(&url)?;
+        let hash = command_hash(&content);
+        let approved = approvals.approved_include_hash(project_id, &url) == Some(hash.as_str())
+            && stored_include_path(repo, &hash).exists();
+        if approved && !show_all {
+            continue;
+        }
+
+        if !approve_remote_include(project_id, &url, &content)? {
+            eprintln!(
+                "{}",
+                info_message(cformat!("Include <bold>{url}</> declined"))
+            );
+            continue;
+        }
+        store_include(repo, &content).context("Failed to store include")?;
+        Approvals::load()
+            .context("Failed to load approvals")?
+            .approve_include(
+                project_id.to_string(),
+                url.clone(),
+                hash,
+                &require_approvals_path()?,
+            )
+            .context("Failed to save include approval")?;
+        eprintln!(
+            "{}",
+            success_message(cformat!("Include <bold>{url}</> approved & saved"))
+        );
+    }
+    Ok(())
+}
+
+/// Download a remote include with `curl`.
+fn fetch_include(url: &str) -> anyhow::Result<String> {
+    let output = Cmd::new("curl")
+        .args([
+            "--silent",
+            "--show-error",
+            "--fail",
+            "--location",
+            "--connect-timeout",
+            "10",
+            "--max-time",
+            "60",
+            url,
+        ])
+        .run()
+        .with_context(|| format!("Failed to fetch project config include {url}"))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to fetch project config include {url}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    String::from_utf8(output.stdout)
+        .with_context(|| format!("Project config include {url} isn't UTF-8"))
+}
+
+/// Handle `wt config approvals export` - print the current project's approved
+/// commands as a TOML snippet a teammate can import
+pub fn export_approvals() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let project_id = repo.project_identifier()?;
+    let approvals = Approvals::load().context("Failed to load approvals")?;
+
+    let snippet = ApprovalsSnippet::new(&project_id, &approvals);
+    if snippet.commands.is_empty() {
+        eprintln!(
+            "{}",
+            info_message("No approvals to export for this project")
+        );
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To approve the project's commands, run <underline>wt config approvals add</>"
+            ))
+        );
+        return Ok(());
+    }
+
+    print!("{}", snippet.to_toml());
+    Ok(())
+}
+
+/// Handle `wt config approvals import` - approve the commands of an exported
+/// snippet, after checking each against its hash
+pub fn import_approvals(file: &Path) -> anyhow::Result<()> {
+    let content = if file == Path::new("-") {
+        std::io::read_to_string(std::io::stdin()).context("Failed to read snippet from stdin")?
+    } else {
+        std::fs::read_to_string(file)
+            .with_context(|| format!("Failed to read {}", format_path_for_display(file)))?
+    };
+    let snippet = ApprovalsSnippet::parse(&content)?;
+
+    let repo = Repository::current()?;
+    let project_id = repo.project_identifier()?;
+    if snippet.project != project_id {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Snippet approves commands for <bold>{}</>, not this project (<bold>{project_id}</>)",
+                snippet.project
+            ),
+        }
+        .into());
+    }
+
+    let mut approvals = Approvals::load().context("Failed to load approvals")?;
+    let new: Vec<String> = snippet
+        .commands
+        .into_iter()
+        .map(|entry| entry.command)
+        .filter(|command| !approvals.is_command_approved(&project_id, command))
+        .collect();
+    if new.is_empty() {
+        eprintln!("{}", info_message("All commands already approved"));
+        return Ok(());
+    }
+
+    approvals
+        .approve_commands(project_id, new.clone(), &require_approvals_path()?)
+        .context("Failed to save approvals")?;
+    eprintln!(
+        "{}",
+        success_message(format!(
+            "Imported {} approval{} for this project:",
+            new.len(),
+            if new.len() == 1 { "" } else { "s" }
+        ))
+    );
+    for command in &new {
+        eprintln!("{}", format_bash_with_gutter(command));
+    }
+    Ok(())
+}
+
 /// Handle `wt config approvals clear` command - clear approved commands
 pub fn clear_approvals(global: bool) -> anyhow::Result<()> {
     let mut approvals = Approvals::load().context("Failed to load approvals")?;
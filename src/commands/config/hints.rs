@@ -1,12 +1,84 @@
 //! Hint management commands.
 //!
-//! Commands for viewing and clearing shown hints.
+//! Commands for viewing and clearing shown hints: `wt hints`, and the
+//! deprecated `wt config state hints`.
 
 use color_print::cformat;
+use serde::Serialize;
 use worktrunk::git::Repository;
 use worktrunk::styling::{eprintln, info_message, println, success_message};
 
 use crate::cli::SwitchFormat;
+use crate::display::format_relative_time_short;
+use crate::output::notice::{Cadence, NOTICES, find};
+
+/// One row of `wt hints`.
+#[derive(Serialize)]
+struct HintStatus {
+    name: String,
+    /// `once`, `daily`, `per-version`, or `always`; absent for hints this
+    /// version doesn't know
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cadence: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trigger: Option<&'static str>,
+    /// Times shown in this repo
+    shown: u32,
+    /// Unix time (daily) or version (per-version) of the last display
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last: Option<String>,
+}
+
+/// Handle `wt hints` (list every hint with its cadence and display record)
+pub fn handle_hints_list(format: SwitchFormat) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let recorded = repo.list_shown_hints();
+    let status = |name: &str, cadence: Option<Cadence>, trigger| HintStatus {
+        name: name.to_string(),
+        cadence: cadence.map(Cadence::label),
+        trigger,
+        shown: repo
+            .hint_count(name)
+            .max(u32::from(repo.has_shown_hint(name))),
+        last: repo.hint_stamp(name),
+    };
+    let mut rows: Vec<HintStatus> = NOTICES
+        .iter()
+        .map(|notice| status(notice.name, Some(notice.cadence), Some(notice.trigger)))
+        .collect();
+    rows.extend(
+        recorded
+            .iter()
+            .filter(|name| find(name).is_none())
+            .map(|name| status(name, None, None)),
+    );
+
+    if format == SwitchFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+        return Ok(());
+    }
+
+    let width = rows.iter().map(|row| row.name.len()).max().unwrap_or(0);
+    for row in rows {
+        let shown = match (row.shown, row.cadence, row.last.as_deref()) {
+            (0, _, _) => cformat!("<dim>not shown</>"),
+            (n, Some("daily"), Some(last)) => match last.parse::<i64>() {
+                Ok(epoch) => format!("shown {n}×, last {} ago", format_relative_time_short(epoch)),
+                Err(_) => format!("shown {n}×"),
+            },
+            (n, Some("per-version"), Some(last)) => format!("shown {n}×, last in {last}"),
+            (n, _, _) => format!("shown {n}×"),
+        };
+        let cadence = row.cadence.unwrap_or("unknown");
+        println!(
+            "{:<width$}  {:<11}  {shown}",
+            row.name,
+            cadence,
+            width = width
+        );
+    }
+    Ok(())
+}
 
 /// Handle the hints get command (list shown hints)
 pub fn handle_hints_get(format: SwitchFormat) -> anyhow::Result<()> {
@@ -15,19 +15,23 @@ mod update;
 
 // Re-export public functions
 pub use alias::{handle_alias_dry_run, handle_alias_show};
-pub use approvals::{add_approvals, clear_approvals};
+pub use approvals::{add_approvals, clear_approvals, export_approvals, import_approvals};
 pub use codex::{handle_codex_install, handle_codex_uninstall};
 pub use create::handle_config_create;
-pub use hints::{handle_hints_clear, handle_hints_get};
+pub use hints::{handle_hints_clear, handle_hints_get, handle_hints_list};
 pub use opencode::{handle_opencode_install, handle_opencode_uninstall};
 pub use plugins::{
     handle_claude_install, handle_claude_install_statusline, handle_claude_uninstall,
 };
-pub use show::handle_config_show;
+pub use show::{handle_config_show, handle_config_show_theme};
+pub(crate) use show::{
+    render_ci_platform_status, render_ci_tool_status, render_commit_generation_status,
+};
 pub use state::{
-    handle_cache_clear, handle_cache_get, handle_logs_list, handle_logs_profile,
-    handle_state_clear, handle_state_clear_all, handle_state_get, handle_state_set,
-    handle_state_show, handle_vars_clear, handle_vars_get, handle_vars_list, handle_vars_set,
+    handle_cache_clear, handle_cache_get, handle_logs_list, handle_logs_profile, handle_logs_prune,
+    handle_ns_clear, handle_ns_get, handle_ns_list, handle_ns_set, handle_state_clear,
+    handle_state_clear_all, handle_state_gc, handle_state_get, handle_state_set, handle_state_show,
+    handle_vars_clear, handle_vars_get, handle_vars_list, handle_vars_set,
 };
 pub use update::handle_config_update;
 
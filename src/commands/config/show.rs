@@ -9,16 +9,19 @@ use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 use color_print::cformat;
+use indexmap::IndexMap;
+use strum::IntoEnumIterator;
 use worktrunk::config::{
-    ProjectConfig, UserConfig, default_system_config_path, require_config_path, system_config_path,
+    CommitGenerationConfig, ProjectConfig, UserConfig, default_system_config_path,
+    require_config_path, system_config_path,
 };
 use worktrunk::git::{CiPlatform, ErrorExt, Repository};
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell::{FileDetectionResult, Shell, scan_for_detection_details};
 use worktrunk::shell_exec::Cmd;
 use worktrunk::styling::{
-    FormattedMessage, error_message, format_bash_with_gutter, format_heading, format_toml,
-    format_with_gutter, hint_message, info_message, success_message, warning_message,
+    FormattedMessage, ThemeSlot, error_message, format_bash_with_gutter, format_heading,
+    format_toml, format_with_gutter, hint_message, info_message, success_message, warning_message,
 };
 
 use crate::cli::{SwitchFormat, version_str};
@@ -93,6 +96,73 @@ pub fn handle_config_show(full: bool, format: SwitchFormat) -> anyhow::Result<()
     Ok(())
 }
 
+/// `wt config show theme`: the `[theme]` palette, each color shown in what it
+/// renders as.
+pub fn handle_config_show_theme(format: SwitchFormat) -> anyhow::Result<()> {
+    let config = UserConfig::load().context("Failed to load config")?;
+    let theme_config = &config.theme;
+    let theme = theme_config.theme();
+    let preset = theme_config.preset.as_deref();
+
+    if format == SwitchFormat::Json {
+        let colors: serde_json::Map<String, serde_json::Value> = ThemeSlot::iter()
+            .map(|slot| {
+                let name: &str = slot.into();
+                let color = theme.get(slot).map(|color| color.to_string());
+                (name.to_string(), serde_json::json!(color))
+            })
+            .collect();
+        let output = serde_json::json!({ "preset": preset, "colors": colors });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    let mut out = String::new();
+    writeln!(out, "{}", format_heading("THEME", None))?;
+    match preset {
+        Some(preset) => writeln!(
+            out,
+            "{}",
+            info_message(cformat!("Preset: <bold>{preset}</>"))
+        )?,
+        None => writeln!(out, "{}", info_message("No preset"))?,
+    }
+    for slot in ThemeSlot::iter() {
+        let name: &str = slot.into();
+        let sample = slot_style(slot);
+        let sample = format!("{sample}{name}{sample:#}");
+        let line = match theme.get(slot) {
+            Some(color) => {
+                let configured = theme_config.slot_values()[slot as usize].1.is_some();
+                let source = if configured { "config" } else { "preset" };
+                cformat!("{sample} → <bold>{color}</> ({source})")
+            }
+            None => format!("{sample} (terminal default)"),
+        };
+        writeln!(out, "{}", format_with_gutter(&line, None))?;
+    }
+    let cmd = worktrunk::styling::suggest_command("config", &["shell", "show-theme"], &[]);
+    writeln!(
+        out,
+        "{}",
+        hint_message(cformat!("To preview messages, run <underline>{cmd}</>"))
+    )?;
+    worktrunk::styling::print!("{out}");
+    Ok(())
+}
+
+/// The style worktrunk uses for `slot`, before `[theme]` recolors it.
+fn slot_style(slot: ThemeSlot) -> anstyle::Style {
+    let color = match slot {
+        ThemeSlot::Cyan => anstyle::AnsiColor::Cyan,
+        ThemeSlot::Magenta => anstyle::AnsiColor::Magenta,
+        ThemeSlot::Green => anstyle::AnsiColor::Green,
+        ThemeSlot::Red => anstyle::AnsiColor::Red,
+        ThemeSlot::Yellow => anstyle::AnsiColor::Yellow,
+    };
+    anstyle::Style::new().fg_color(Some(color.into()))
+}
+
 /// JSON output for config show: paths, existence, and parsed config contents.
 fn handle_config_show_json() -> anyhow::Result<()> {
     let user_path = require_config_path()?;
@@ -476,9 +546,47 @@ fn render_runtime_info(out: &mut String) -> anyhow::Result<()> {
 fn render_diagnostics(out: &mut String) -> anyhow::Result<()> {
     writeln!(out, "{}", format_heading("DIAGNOSTICS", None))?;
 
-    // Check the CI tool for this repo's platform (project config, else remote URL).
     let repo = Repository::current()?;
-    match repo.ci_platform(None) {
+    let config = UserConfig::load()?;
+    let offline = config.offline();
+
+    // Check the CI tool for this repo's platform (project config, else remote URL).
+    if !offline {
+        render_ci_platform_status(out, repo.ci_platform(None))?;
+    }
+
+    // Check for the commit-graph and multi-pack-index wt's history walks use
+    render_object_indexes_status(out, &repo)?;
+
+    // Check that worktrees agree on the git config wt resolves remotes and
+    // the default branch from
+    render_worktree_config_status(out, &repo)?;
+
+    if offline {
+        writeln!(
+            out,
+            "{}",
+            hint_message("Offline; skipped CI tool, version, and commit generation checks")
+        )?;
+        return Ok(());
+    }
+
+    // Check for newer version on GitHub
+    render_version_check(out)?;
+
+    // Test commit generation - use effective config for current project
+    let project_id = repo.project_identifier().ok();
+    render_commit_generation_status(out, &config.commit_generation(project_id.as_deref()))?;
+
+    Ok(())
+}
+
+/// Render whether the CI tool for `platform` is installed and authenticated.
+pub(crate) fn render_ci_platform_status(
+    out: &mut String,
+    platform: Option<CiPlatform>,
+) -> anyhow::Result<()> {
+    match platform {
         Some(CiPlatform::GitHub) => {
             let ci_tools = CiToolsStatus::detect(None);
             render_ci_tool_status(
@@ -528,14 +636,120 @@ fn render_diagnostics(out: &mut String) -> anyhow::Result<()> {
         }
     }
 
-    // Check for newer version on GitHub
-    render_version_check(out)?;
+    Ok(())
+}
 
-    // Test commit generation - use effective config for current project
-    let config = UserConfig::load()?;
-    let project_id = repo.project_identifier().ok();
-    let commit_config = config.commit_generation(project_id.as_deref());
+/// Render whether the object store has the indexes that speed up `rev-list`.
+fn render_object_indexes_status(out: &mut String, repo: &Repository) -> anyhow::Result<()> {
+    let missing = repo.object_indexes().missing();
+    if missing.is_empty() {
+        writeln!(out, "{}", success_message("Repository indexes present"))?;
+        return Ok(());
+    }
+    let missing = missing.join(", ");
+    writeln!(
+        out,
+        "{}",
+        warning_message(cformat!(
+            "Missing <bold>{missing}</>; history walks in <bright-black>wt list</> and <bright-black>wt merge</> are slower"
+        ))
+    )?;
+    writeln!(
+        out,
+        "{}",
+        hint_message(cformat!(
+            "To write them, run <underline>wt step optimize-repo</>"
+        ))
+    )?;
+    Ok(())
+}
+
+/// Whether `key` is one wt reads to resolve the default branch, remotes, or
+/// the committer, so a per-worktree difference changes what wt does.
+fn is_worktree_sensitive_key(key: &str) -> bool {
+    matches!(
+        key,
+        "user.name" | "user.email" | "init.defaultbranch" | "worktrunk.default-branch"
+    ) || (key.starts_with("remote.") && (key.ends_with(".url") || key.ends_with(".pushurl")))
+}
+
+/// Render whether every worktree resolves the same default-branch, remote,
+/// and identity config. `includeIf "onbranch:…"`/`"gitdir:…"` and
+/// `config.worktree` files can make them differ.
+fn render_worktree_config_status(out: &mut String, repo: &Repository) -> anyhow::Result<()> {
+    let worktrees: Vec<PathBuf> = repo
+        .list_worktrees()?
+        .iter()
+        .filter(|wt| !wt.bare && wt.prunable.is_none() && wt.path.exists())
+        .map(|wt| wt.path.clone())
+        .collect();
+    if worktrees.len() < 2 {
+        return Ok(());
+    }
 
+    // Last value per key wins, as in `git config --get`.
+    let mut views = Vec::new();
+    for path in &worktrees {
+        let mut values: IndexMap<String, (String, String)> = IndexMap::new();
+        for entry in repo.worktree_config_origins(path)? {
+            if is_worktree_sensitive_key(&entry.key) {
+                values.insert(entry.key, (entry.value, entry.origin));
+            }
+        }
+        views.push(values);
+    }
+
+    let mut keys: Vec<&String> = views.iter().flat_map(|view| view.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    let mut rows = Vec::new();
+    for key in keys {
+        let first = views[0].get(key).map(|(value, _)| value);
+        if views
+            .iter()
+            .all(|view| view.get(key).map(|(value, _)| value) == first)
+        {
+            continue;
+        }
+        rows.push(cformat!("<bold>{key}</>"));
+        for (path, view) in worktrees.iter().zip(&views) {
+            let shown = match view.get(key) {
+                Some((value, origin)) => cformat!("{value} <dim>({origin})</>"),
+                None => cformat!("<dim>(unset)</>"),
+            };
+            rows.push(format!("  {}: {shown}", format_path_for_display(path)));
+        }
+    }
+
+    if rows.is_empty() {
+        writeln!(
+            out,
+            "{}",
+            success_message("Git config consistent across worktrees")
+        )?;
+        return Ok(());
+    }
+    writeln!(
+        out,
+        "{}",
+        warning_message("Git config differs between worktrees")
+    )?;
+    writeln!(out, "{}", format_with_gutter(&rows.join("\n"), None))?;
+    writeln!(
+        out,
+        "{}",
+        hint_message(
+            "wt resolves these per worktree; check includeIf conditions and config.worktree files"
+        )
+    )?;
+    Ok(())
+}
+
+/// Run `commit_config`'s command against a sample diff and render the outcome.
+pub(crate) fn render_commit_generation_status(
+    out: &mut String,
+    commit_config: &CommitGenerationConfig,
+) -> anyhow::Result<()> {
     if !commit_config.is_configured() {
         writeln!(out, "{}", hint_message("Commit generation not configured"))?;
     } else {
@@ -544,7 +758,7 @@ fn render_diagnostics(out: &mut String) -> anyhow::Result<()> {
         // `Result`-returning function (the default is unreachable).
         let command_display = commit_config.command.clone().unwrap_or_default();
 
-        match test_commit_generation(&commit_config) {
+        match test_commit_generation(commit_config) {
             Ok(message) => {
                 writeln!(
                     out,
@@ -1378,7 +1592,7 @@ fn render_shell_status(out: &mut String) -> anyhow::Result<()> {
     Ok(())
 }
 
-pub(super) fn render_ci_tool_status(
+pub(crate) fn render_ci_tool_status(
     out: &mut String,
     tool: &str,
     platform: &str,
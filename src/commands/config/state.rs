@@ -17,9 +17,12 @@
 //!
 //! - Default branch override (git config `worktrunk.default_branch.*`)
 //! - Branch markers (git config `worktrunk.state.<branch>.marker`)
+//! - Pins (git config `worktrunk.state.<branch>.pinned`)
 //! - Vars (git config `worktrunk.state.<branch>.vars.*`)
+//! - Merge journals (git config `worktrunk.state.<branch>.merge`)
 //! - Logs (`.git/wt/logs/`)
 //! - Trash (`.git/wt/trash/`)
+//! - Namespaced entries (`.git/wt/state/<namespace>/`, see `state_store`)
 //!
 //! **Regenerable caches** — also surfaced by `wt config state cache get`
 //! (`handle_cache_get`) and dropped by `wt config state cache clear`
@@ -42,6 +45,10 @@
 //! subcommands are `hide`-deprecated in favour of `cache` but still resolve to
 //! the same state.
 //!
+//! `wt config state gc` (`handle_state_gc`) is the per-branch counterpart:
+//! it removes only the markers, pins, vars, journals, cache entries, and log
+//! trees of branches that no longer exist (see `commands::branch_gc`).
+//!
 //! When adding a new category, update BOTH `handle_state_show` and
 //! `handle_state_clear_all` (and `handle_cache_*` if it is a cache), plus the
 //! `after_long_help` blocks for `state get`, `state clear`, and `state cache`
@@ -72,15 +79,21 @@
 use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 
+use crate::commands::branch_gc;
+use crate::commands::create_journal::CreateJournal;
+use crate::commands::log_retention;
+use crate::commands::merge_journal::{MergeJournal, MergeRecord};
 use crate::commands::picker::preview_cache;
+use crate::commands::pin;
 use anyhow::Context;
 use color_print::cformat;
 use path_slash::PathExt as _;
 use worktrunk::git::{BranchRef, Repository, sha_cache};
 use worktrunk::path::format_path_for_display;
+use worktrunk::state_store::{self, StateEntry};
 use worktrunk::styling::{
-    eprintln, format_heading, format_with_gutter, info_message, println, success_message,
-    warning_message,
+    eprintln, format_heading, format_with_gutter, hint_message, info_message, println,
+    success_message, warning_message,
 };
 
 use crate::cli::{OutputFormat, SwitchFormat};
@@ -109,7 +122,19 @@ const DIAGNOSTIC_FILES: &[&str] = &[
 /// branch-agnostic shared files, distinct from the per-branch hook-output
 /// subtrees and the `commands.jsonl` audit log.
 fn is_diagnostic_file(name: &str) -> bool {
-    DIAGNOSTIC_FILES.contains(&name) || (name.starts_with("internal-") && name.ends_with(".log"))
+    DIAGNOSTIC_FILES.contains(&name) || (name.starts_with("internal-") && is_log_file(name))
+}
+
+/// Whether a file name is a log: `.log`, or one rotated to `.log.1[.gz]`.
+fn is_log_file(name: &str) -> bool {
+    name.ends_with(".log") || log_retention::is_rotated_log(name)
+}
+
+/// A log's name without its `.log` (and rotation) suffix.
+fn log_stem(name: &str) -> &str {
+    let name = name.strip_suffix(".gz").unwrap_or(name);
+    let name = name.strip_suffix(".1").unwrap_or(name);
+    name.strip_suffix(".log").unwrap_or(name)
 }
 
 /// Truncate a string for a display cell, counting by Unicode scalars.
@@ -146,7 +171,7 @@ struct HookOutputEntry {
 /// Walk every per-branch log file under `log_dir`.
 ///
 /// Top-level *directories* are treated as branch dirs; each is walked
-/// recursively for `.log` files. Non-directory top-level entries are ignored
+/// recursively for log files. Non-directory top-level entries are ignored
 /// (those belong to command audit / diagnostic categories).
 ///
 /// Returns entries sorted by modification time (newest first), with name as a
@@ -167,7 +192,7 @@ fn walk_hook_output_files(log_dir: &Path) -> anyhow::Result<Vec<HookOutputEntry>
     Ok(out)
 }
 
-/// Recursively collect `.log` files under a branch directory.
+/// Recursively collect log files (`.log`, and rotated `.log.1[.gz]`) under a branch directory.
 fn walk_branch_dir(
     log_dir: &Path,
     current: &Path,
@@ -179,7 +204,7 @@ fn walk_branch_dir(
         let path = entry.path();
         if file_type.is_dir() {
             walk_branch_dir(log_dir, &path, out)?;
-        } else if file_type.is_file() && path.extension().and_then(|e| e.to_str()) == Some("log") {
+        } else if file_type.is_file() && is_log_file(&entry.file_name().to_string_lossy()) {
             let metadata = entry.metadata()?;
             let relative = path.strip_prefix(log_dir).unwrap_or(&path);
             out.push(HookOutputEntry {
@@ -279,7 +304,7 @@ fn clear_trash(repo: &Repository) -> anyhow::Result<usize> {
     Ok(cleared)
 }
 
-/// Count `.log` files recursively under `dir`.
+/// Count log files recursively under `dir`.
 ///
 /// Used by `clear_logs` to report how many logs are being swept when it
 /// removes a whole branch subtree with `remove_dir_all`.
@@ -291,7 +316,7 @@ fn count_log_files_recursive(dir: &Path) -> anyhow::Result<usize> {
         let path = entry.path();
         if file_type.is_dir() {
             count += count_log_files_recursive(&path)?;
-        } else if file_type.is_file() && path.extension().and_then(|e| e.to_str()) == Some("log") {
+        } else if file_type.is_file() && is_log_file(&entry.file_name().to_string_lossy()) {
             count += 1;
         }
     }
@@ -328,7 +353,7 @@ fn clear_logs(repo: &Repository) -> anyhow::Result<usize> {
                 continue;
             };
             // Known shared files + legacy flat `.log` files from the old layout.
-            if is_command_log_file(name) || is_diagnostic_file(name) || name.ends_with(".log") {
+            if is_command_log_file(name) || is_diagnostic_file(name) || is_log_file(name) {
                 std::fs::remove_file(&path)?;
                 cleared += 1;
             }
@@ -454,16 +479,13 @@ fn parse_hook_structure(relative: &str) -> Option<HookStructure> {
             branch: (*branch).to_string(),
             source: "internal".to_string(),
             hook_type: None,
-            name: op_log.strip_suffix(".log").unwrap_or(op_log).to_string(),
+            name: log_stem(op_log).to_string(),
         }),
         [branch, source, hook_type, name_log] => Some(HookStructure {
             branch: (*branch).to_string(),
             source: (*source).to_string(),
             hook_type: Some((*hook_type).to_string()),
-            name: name_log
-                .strip_suffix(".log")
-                .unwrap_or(name_log)
-                .to_string(),
+            name: log_stem(name_log).to_string(),
         }),
         _ => None,
     }
@@ -646,8 +668,13 @@ pub fn handle_logs_list(format: SwitchFormat) -> anyhow::Result<()> {
 }
 
 /// `wt config state logs profile [FILE]` — summarize where a `-vv` run spent its
-/// time, from the records in `trace.jsonl` (or a given file / stdin).
-pub fn handle_logs_profile(file: Option<PathBuf>, format: SwitchFormat) -> anyhow::Result<()> {
+/// time, from the records in `trace.jsonl` (or a given file / stdin). With
+/// `flame`, write folded stacks there instead of showing the report.
+pub fn handle_logs_profile(
+    file: Option<PathBuf>,
+    flame: Option<PathBuf>,
+    format: SwitchFormat,
+) -> anyhow::Result<()> {
     let (input, source) = match file {
         Some(ref p) if p.as_os_str() == "-" => {
             let mut buf = String::new();
@@ -684,6 +711,27 @@ pub fn handle_logs_profile(file: Option<PathBuf>, format: SwitchFormat) -> anyho
         ));
     }
 
+    if let Some(flame) = flame {
+        std::fs::write(&flame, worktrunk::trace::to_folded(&entries)).with_context(|| {
+            format!(
+                "Failed to write folded stacks to {}",
+                format_path_for_display(&flame)
+            )
+        })?;
+        let flame_display = format_path_for_display(&flame);
+        eprintln!(
+            "{}",
+            success_message(cformat!("Wrote folded stacks to <bold>{flame_display}</>"))
+        );
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To render an SVG, run <underline>inferno-flamegraph {flame_display} > flame.svg</>"
+            ))
+        );
+        return Ok(());
+    }
+
     let profile = worktrunk::trace::Profile::from_entries(&entries);
 
     if format == SwitchFormat::Json {
@@ -694,6 +742,42 @@ pub fn handle_logs_profile(file: Option<PathBuf>, format: SwitchFormat) -> anyho
     Ok(())
 }
 
+/// `wt config state logs prune` — delete logs older than `older_than`, then
+/// trim the directory to `[logs] max-total-size`.
+pub fn handle_logs_prune(older_than: &str) -> anyhow::Result<()> {
+    let age = humantime::parse_duration(older_than).map_err(|e| {
+        anyhow::anyhow!(cformat!(
+            "Invalid <bold>--older-than</> value <bold>{older_than}</>: {e}"
+        ))
+    })?;
+    let repo = Repository::current()?;
+    let log_dir = repo.wt_logs_dir();
+
+    let cutoff = epoch_now().saturating_sub(age.as_secs());
+    let by_age =
+        log_retention::prune_older_than(&log_dir, cutoff).context("Failed to prune logs")?;
+    let by_size =
+        log_retention::enforce_total_size(&log_dir, repo.user_config().logs.max_total_size())
+            .context("Failed to prune logs")?;
+
+    let pruned = by_age.files + by_size.files;
+    if pruned == 0 {
+        eprintln!(
+            "{}",
+            info_message(cformat!("No logs older than <bold>{older_than}</>"))
+        );
+    } else {
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Pruned <bold>{pruned}</> log file{}",
+                if pruned == 1 { "" } else { "s" }
+            ))
+        );
+    }
+    Ok(())
+}
+
 // ==================== State Get/Set/Clear Commands ====================
 
 /// Handle the state get command
@@ -1013,13 +1097,18 @@ pub fn handle_state_clear_all(yes: bool) -> anyhow::Result<()> {
     cleared_any |= clear_default_branch_reported(&repo)?;
     cleared_any |= clear_previous_branch_reported(&repo)?;
     cleared_any |= clear_markers_reported(&repo)?;
+    cleared_any |= clear_pins_reported(&repo)?;
     cleared_any |= clear_ci_status_reported(&repo)?;
     cleared_any |= clear_summary_reported(&repo)?;
     cleared_any |= clear_git_commands_reported(&repo)?;
     cleared_any |= clear_vars_reported(&repo)?;
+    cleared_any |= clear_merge_journals_reported(&repo)?;
+    cleared_any |= clear_merge_records_reported(&repo)?;
+    cleared_any |= clear_create_journals_reported(&repo)?;
     cleared_any |= clear_logs_reported(&repo)?;
     cleared_any |= clear_hints_reported(&repo)?;
     cleared_any |= clear_trash_reported(&repo)?;
+    cleared_any |= clear_ns_state_reported(&repo)?;
 
     if !cleared_any {
         eprintln!("{}", info_message("No stored state to clear"));
@@ -1051,11 +1140,182 @@ pub fn handle_cache_clear() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Handle `wt config state gc`.
+///
+/// Removes the stored data of branches that no longer exist — see
+/// `commands::branch_gc` for what is swept and what is kept.
+pub fn handle_state_gc() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let swept = branch_gc::sweep_deleted_branches(&repo, true)?;
+
+    let reports = [
+        (swept.state_keys, "state entr", "y", "ies"),
+        (swept.ci_entries, "CI cache entr", "y", "ies"),
+        (swept.summaries, "summary cache entr", "y", "ies"),
+        (swept.log_files, "log file", "", "s"),
+        (swept.hook_records, "hook status file", "", "s"),
+    ];
+    for (count, noun, one, many) in reports {
+        if count > 0 {
+            let suffix = if count == 1 { one } else { many };
+            eprintln!(
+                "{}",
+                success_message(cformat!("Cleared <bold>{count}</> {noun}{suffix}"))
+            );
+        }
+    }
+
+    if swept.is_empty() {
+        eprintln!("{}", info_message("No data left by deleted branches"));
+    }
+
+    Ok(())
+}
+
+// ==================== Namespaced State ====================
+
+/// Handle `wt config state get --ns <namespace> <key>`
+pub fn handle_ns_get(namespace: &str, key: &str) -> anyhow::Result<()> {
+    state_store::validate_name("Namespace", namespace)?;
+    state_store::validate_name("Key", key)?;
+    let repo = Repository::current()?;
+    if let Some(entry) = state_store::get(&repo, namespace, key) {
+        println!("{}", entry.value);
+    }
+    Ok(())
+}
+
+/// Handle `wt config state set --ns <namespace> <key> <value>`
+pub fn handle_ns_set(
+    namespace: &str,
+    key: &str,
+    value: &str,
+    ttl: Option<&str>,
+) -> anyhow::Result<()> {
+    let ttl = ttl
+        .map(humantime::parse_duration)
+        .transpose()
+        .context("Invalid --ttl duration")?;
+    let repo = Repository::current()?;
+    state_store::set(&repo, namespace, key, value, ttl)?;
+
+    let expiry = match ttl {
+        Some(ttl) => cformat!(" for <bold>{}</>", humantime::format_duration(ttl)),
+        None => String::new(),
+    };
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Set <bold>{key}</> in <bold>{namespace}</>{expiry}"
+        ))
+    );
+    Ok(())
+}
+
+/// Handle `wt config state list [--ns <namespace>]`
+pub fn handle_ns_list(namespace: Option<&str>, format: SwitchFormat) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let namespaces = match namespace {
+        Some(namespace) => {
+            state_store::validate_name("Namespace", namespace)?;
+            vec![namespace.to_string()]
+        }
+        None => state_store::namespaces(&repo),
+    };
+    let listed: Vec<(String, Vec<(String, StateEntry)>)> = namespaces
+        .into_iter()
+        .map(|ns| {
+            let entries = state_store::list(&repo, &ns);
+            (ns, entries)
+        })
+        .filter(|(_, entries)| !entries.is_empty())
+        .collect();
+
+    if format == SwitchFormat::Json {
+        let rows: Vec<serde_json::Value> = listed
+            .iter()
+            .flat_map(|(ns, entries)| {
+                entries
+                    .iter()
+                    .map(move |(key, e)| ns_entry_json(ns, key, e))
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+    } else if listed.is_empty() {
+        let message = match namespace {
+            Some(ns) => cformat!("No entries in <bold>{ns}</>"),
+            None => "No namespaced state".to_string(),
+        };
+        eprintln!("{}", info_message(message));
+    } else {
+        for (ns, entries) in &listed {
+            for (key, entry) in entries {
+                if namespace.is_some() {
+                    println!("{key}\t{}", entry.value);
+                } else {
+                    println!("{ns}\t{key}\t{}", entry.value);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Handle `wt config state clear --ns <namespace> [<key>]`
+pub fn handle_ns_clear(namespace: &str, key: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let cleared = state_store::clear(&repo, namespace, key)?;
+    match key {
+        Some(key) if cleared > 0 => eprintln!(
+            "{}",
+            success_message(cformat!("Cleared <bold>{key}</> in <bold>{namespace}</>"))
+        ),
+        Some(key) => eprintln!(
+            "{}",
+            info_message(cformat!("No key <bold>{key}</> in <bold>{namespace}</>"))
+        ),
+        None if cleared > 0 => eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Cleared <bold>{cleared}</> entr{} in <bold>{namespace}</>",
+                if cleared == 1 { "y" } else { "ies" }
+            ))
+        ),
+        None => eprintln!(
+            "{}",
+            info_message(cformat!("No entries in <bold>{namespace}</>"))
+        ),
+    }
+    Ok(())
+}
+
+fn ns_entry_json(namespace: &str, key: &str, entry: &StateEntry) -> serde_json::Value {
+    serde_json::json!({
+        "namespace": namespace,
+        "key": key,
+        "value": entry.value,
+        "set_at": entry.set_at,
+        "expires_at": entry.expires_at,
+    })
+}
+
+/// Every live namespaced entry, for the aggregate `state get`.
+fn all_ns_entries(repo: &Repository) -> Vec<(String, String, StateEntry)> {
+    state_store::namespaces(repo)
+        .into_iter()
+        .flat_map(|ns| {
+            state_store::list(repo, &ns)
+                .into_iter()
+                .map(move |(key, entry)| (ns.clone(), key, entry))
+        })
+        .collect()
+}
+
 // ==================== Per-category clear + report ====================
 //
 // Each helper clears one category and prints its success message when it
 // removed anything, returning whether it did. `handle_state_clear_all`
-// composes all ten; `handle_cache_clear` composes the regenerable subset.
+// composes all twelve; `handle_cache_clear` composes the regenerable subset.
 // Co-locating the clear call with its message keeps the two entry points
 // reporting identically.
 
@@ -1090,6 +1350,21 @@ fn clear_markers_reported(repo: &Repository) -> anyhow::Result<bool> {
     Ok(false)
 }
 
+fn clear_pins_reported(repo: &Repository) -> anyhow::Result<bool> {
+    let cleared = pin::clear_all(repo)?;
+    if cleared > 0 {
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Cleared <bold>{cleared}</> pin{}",
+                if cleared == 1 { "" } else { "s" }
+            ))
+        );
+        return Ok(true);
+    }
+    Ok(false)
+}
+
 fn clear_ci_status_reported(repo: &Repository) -> anyhow::Result<bool> {
     // The PR-number width ratchet is part of the CI cache category — it is
     // derived from the same fetches and re-learns on the next one.
@@ -1156,6 +1431,51 @@ fn clear_vars_reported(repo: &Repository) -> anyhow::Result<bool> {
     Ok(false)
 }
 
+fn clear_merge_journals_reported(repo: &Repository) -> anyhow::Result<bool> {
+    let cleared = MergeJournal::clear_all(repo)?;
+    if cleared > 0 {
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Cleared <bold>{cleared}</> merge journal{}",
+                if cleared == 1 { "" } else { "s" }
+            ))
+        );
+        return Ok(true);
+    }
+    Ok(false)
+}
+
+fn clear_merge_records_reported(repo: &Repository) -> anyhow::Result<bool> {
+    let cleared = MergeRecord::clear_all(repo)?;
+    if cleared > 0 {
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Cleared <bold>{cleared}</> merge record{}",
+                if cleared == 1 { "" } else { "s" }
+            ))
+        );
+        return Ok(true);
+    }
+    Ok(false)
+}
+
+fn clear_create_journals_reported(repo: &Repository) -> anyhow::Result<bool> {
+    let cleared = CreateJournal::clear_all(repo)?;
+    if cleared > 0 {
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Cleared <bold>{cleared}</> interrupted worktree creation{}",
+                if cleared == 1 { "" } else { "s" }
+            ))
+        );
+        return Ok(true);
+    }
+    Ok(false)
+}
+
 fn clear_logs_reported(repo: &Repository) -> anyhow::Result<bool> {
     let cleared = clear_logs(repo)?;
     if cleared > 0 {
@@ -1201,6 +1521,21 @@ fn clear_trash_reported(repo: &Repository) -> anyhow::Result<bool> {
     Ok(false)
 }
 
+fn clear_ns_state_reported(repo: &Repository) -> anyhow::Result<bool> {
+    let cleared = state_store::clear_all(repo)?;
+    if cleared > 0 {
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Cleared <bold>{cleared}</> namespaced entr{}",
+                if cleared == 1 { "y" } else { "ies" }
+            ))
+        );
+        return Ok(true);
+    }
+    Ok(false)
+}
+
 // ==================== State Show Commands ====================
 
 /// Handle the state get command (shows all state)
@@ -1255,6 +1590,18 @@ fn handle_state_show_json(repo: &Repository) -> anyhow::Result<()> {
         })
         .collect();
 
+    let merge_journals: Vec<serde_json::Value> = MergeJournal::all(repo)?
+        .iter()
+        .map(|j| {
+            serde_json::json!({
+                "branch": j.branch(),
+                "target": j.target,
+                "step": j.in_progress.map(|s| s.as_str()),
+                "started_at": j.started_at,
+            })
+        })
+        .collect();
+
     // Get hints
     let hints = repo.list_shown_hints();
 
@@ -1285,11 +1632,16 @@ fn handle_state_show_json(repo: &Repository) -> anyhow::Result<()> {
         "summaries": summaries,
         "git_commands_cache": sha_cache::count_all(repo) + preview_cache::count_all(repo),
         "vars": vars_data,
+        "merge_journals": merge_journals,
         "command_log": command_log,
         "hook_output": hook_output,
         "diagnostic": diagnostic,
         "hints": hints,
         "trash": trash,
+        "namespaced": all_ns_entries(repo)
+            .iter()
+            .map(|(ns, key, entry)| ns_entry_json(ns, key, entry))
+            .collect::<Vec<_>>(),
     });
 
     println!("{}", serde_json::to_string_pretty(&output)?);
@@ -1355,6 +1707,29 @@ fn handle_state_show_table(repo: &Repository) -> anyhow::Result<()> {
     }
     writeln!(out)?;
 
+    // Show interrupted `wt merge` journals
+    writeln!(out, "{}", format_heading("MERGE JOURNALS", None))?;
+    let journals = MergeJournal::all(repo)?;
+    if journals.is_empty() {
+        writeln!(out, "{}", format_with_gutter("(none)", None))?;
+    } else {
+        let rows: Vec<Vec<String>> = journals
+            .iter()
+            .map(|j| {
+                vec![
+                    j.branch().to_string(),
+                    j.target.clone(),
+                    j.in_progress.map_or("-", |s| s.as_str()).to_string(),
+                    format_relative_time_short(j.started_at as i64),
+                ]
+            })
+            .collect();
+        let rendered =
+            crate::md_help::render_data_table(&["Branch", "Target", "Stopped at", "Age"], &rows);
+        writeln!(out, "{}", rendered.trim_end())?;
+    }
+    writeln!(out)?;
+
     // Show CI status cache (pre-sorted newest-first)
     render_ci_status_section(&mut out, repo)?;
     writeln!(out)?;
@@ -1403,6 +1778,40 @@ fn handle_state_show_table(repo: &Repository) -> anyhow::Result<()> {
         let rendered = crate::md_help::render_data_table(&["Entry", "Age"], &rows);
         writeln!(out, "{}", rendered.trim_end())?;
     }
+    writeln!(out)?;
+
+    // Show namespaced entries (`state set --ns`)
+    writeln!(out, "{}", format_heading("NAMESPACED STATE", None))?;
+    let ns_entries = all_ns_entries(repo);
+    if ns_entries.is_empty() {
+        writeln!(out, "{}", format_with_gutter("(none)", None))?;
+    } else {
+        let rows: Vec<Vec<String>> = ns_entries
+            .iter()
+            .map(|(ns, key, entry)| {
+                // `format_relative_time_short` renders ages; mirror the
+                // remaining time into the past to reuse its units.
+                let now = epoch_now();
+                let expires = match entry.expires_at {
+                    None => "-".to_string(),
+                    Some(at) if at.saturating_sub(now) < 60 => "in <1m".to_string(),
+                    Some(at) => format!(
+                        "in {}",
+                        format_relative_time_short(now as i64 - (at - now) as i64)
+                    ),
+                };
+                vec![
+                    ns.clone(),
+                    key.clone(),
+                    truncate_display(&entry.value, 40),
+                    expires,
+                ]
+            })
+            .collect();
+        let rendered =
+            crate::md_help::render_data_table(&["Namespace", "Key", "Value", "Expires"], &rows);
+        writeln!(out, "{}", rendered.trim_end())?;
+    }
 
     show_help_in_pager(&out, true);
 
@@ -7,7 +7,7 @@ use anstyle::Style;
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell::{self, Shell};
 use worktrunk::styling::{
-    INFO_SYMBOL, SUCCESS_SYMBOL, eprint, eprintln, format_bash_with_gutter, format_toml,
+    Glyph, INFO_SYMBOL, SUCCESS_SYMBOL, eprint, eprintln, format_bash_with_gutter, format_toml,
     format_with_gutter, hint_message, println, prompt_message, warning_message,
 };
 
@@ -78,7 +78,7 @@ impl UninstallAction {
         }
     }
 
-    pub fn symbol(&self) -> &'static str {
+    pub fn symbol(&self) -> Glyph {
         match self {
             UninstallAction::Removed => SUCCESS_SYMBOL,
             UninstallAction::WouldRemove => INFO_SYMBOL,
@@ -107,7 +107,7 @@ impl ConfigAction {
     }
 
     /// Returns the appropriate symbol for this action
-    pub fn symbol(&self) -> &'static str {
+    pub fn symbol(&self) -> Glyph {
         match self {
             ConfigAction::Added | ConfigAction::Created => SUCCESS_SYMBOL,
             ConfigAction::AlreadyExists => INFO_SYMBOL,
@@ -0,0 +1,126 @@
+//! Interrupted-creation marker for `wt switch --create`.
+//!
+//! Creating a worktree is `git worktree add` followed by the blocking
+//! pre-start hooks (dependency installs, file copies). A Ctrl-C, SIGTERM, or
+//! failing hook in between leaves a worktree that exists but was never
+//! finished — and the next `wt switch --create` of that branch fails with
+//! "branch already exists", while a plain `wt switch` quietly lands in it.
+//!
+//! # Storage
+//!
+//! One JSON value per branch in git config at `worktrunk.state.<branch>.create`,
+//! alongside markers, vars, and the merge journal.
+//!
+//! # Lifecycle
+//!
+//! The marker is written *ahead*: [`CreateJournal::begin`] runs before
+//! `git worktree add`, [`CreateJournal::record_head`] notes the commit it
+//! checked out, and [`CreateJournal::finish`] deletes it once the pre-start
+//! hooks complete (or right after creation when hooks don't run). A failed
+//! `git worktree add` deletes it too, so a directory it never created isn't
+//! later taken for a partial worktree.
+//! An interruption therefore needs no signal handler to record itself — the
+//! marker is already there when the process dies, whether from Ctrl-C during
+//! `git worktree add`, a signal forwarded to a hook, or `SIGKILL`.
+//!
+//! The next `wt switch` to the branch finds the marker and offers to run the
+//! pre-start hooks again or to remove the partial worktree
+//! (see `SwitchPipeline::run`).
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use worktrunk::git::Repository;
+use worktrunk::utils::epoch_now;
+
+/// A `wt switch --create` that started but never finished.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateJournal {
+    #[serde(skip)]
+    branch: String,
+    /// Where the worktree was being created.
+    pub path: PathBuf,
+    /// Whether the switch created the branch too (`--create`), so removing
+    /// the partial worktree should delete the branch with it.
+    pub created_branch: bool,
+    pub started_at: u64,
+    /// The commit `git worktree add` checked out, once it succeeded. Removing
+    /// the partial worktree force-deletes only while the branch still points
+    /// here.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head: Option<String>,
+}
+
+fn config_key(branch: &str) -> String {
+    format!("worktrunk.state.{branch}.create")
+}
+
+impl CreateJournal {
+    /// Read the marker for `branch`, if creating its worktree was interrupted.
+    ///
+    /// Unparseable values read as absent, like the merge journal.
+    pub fn load(repo: &Repository, branch: &str) -> Option<Self> {
+        let raw = repo
+            .config_value(&config_key(branch))
+            .ok()
+            .flatten()
+            .filter(|s| !s.is_empty())?;
+        let mut journal: Self = serde_json::from_str(&raw).ok()?;
+        journal.branch = branch.to_string();
+        Some(journal)
+    }
+
+    /// Delete every marker; returns how many were removed.
+    pub fn clear_all(repo: &Repository) -> anyhow::Result<usize> {
+        let output = repo.get_config_regexp(r"^worktrunk\.state\..+\.create$")?;
+        let mut cleared = 0;
+        for line in output.lines() {
+            if let Some(config_key) = line.split_whitespace().next() {
+                repo.unset_config(config_key)?;
+                cleared += 1;
+            }
+        }
+        Ok(cleared)
+    }
+
+    /// Record the start of creating `branch`'s worktree at `path`.
+    pub fn begin(
+        repo: &Repository,
+        branch: &str,
+        path: &Path,
+        created_branch: bool,
+    ) -> anyhow::Result<Self> {
+        let journal = Self {
+            branch: branch.to_string(),
+            path: path.to_path_buf(),
+            created_branch,
+            started_at: epoch_now(),
+            head: None,
+        };
+        journal.save(repo)?;
+        Ok(journal)
+    }
+
+    /// Record the commit the new worktree was created at.
+    pub fn record_head(&mut self, repo: &Repository, head: String) -> anyhow::Result<()> {
+        self.head = Some(head);
+        self.save(repo)
+    }
+
+    fn save(&self, repo: &Repository) -> anyhow::Result<()> {
+        let json = serde_json::to_string(self).context("Failed to serialize creation marker")?;
+        repo.set_config(&config_key(&self.branch), &json)?;
+        Ok(())
+    }
+
+    pub fn branch(&self) -> &str {
+        &self.branch
+    }
+
+    /// Delete the marker once creation completes (or is resolved).
+    pub fn finish(self, repo: &Repository) -> anyhow::Result<()> {
+        repo.unset_config(&config_key(&self.branch))?;
+        Ok(())
+    }
+}
@@ -8,10 +8,12 @@
 //!    run it via the same path as `wt step foo`. User config wins over
 //!    `wt-<name>` PATH binaries — aliases are how users customize wt, so the
 //!    user's intent should take precedence.
-//! 2. **PATH binary**: resolve `wt-<name>` via `which`. If found, run it with
+//! 2. **Row shortcut**: a bare number with no arguments (`wt 2`) is
+//!    `wt switch %2` — row 2 of this shell's last `wt list`.
+//! 3. **PATH binary**: resolve `wt-<name>` via `which`. If found, run it with
 //!    the remaining args, inheriting stdio, and propagate the exit code.
 //!    Mirrors how `git foo` finds `git-foo`.
-//! 3. Otherwise, synthesize clap's native `InvalidSubcommand` error (with
+//! 4. Otherwise, synthesize clap's native `InvalidSubcommand` error (with
 //!    aliases included in the "did you mean" candidates) and return it via
 //!    `enhance_clap_error` so the output matches what clap would have produced
 //!    without `external_subcommand` — same formatting, suggestions, Usage
@@ -29,12 +31,14 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use anyhow::{Context, Result};
+use clap::{Args, FromArgMatches};
 use worktrunk::git::WorktrunkError;
 use worktrunk::trace::CommandTrace;
 
-use crate::cli::build_command;
+use crate::cli::{SwitchArgs, build_command};
 use crate::commands::{
-    alias_names_for_suggestions, build_invalid_subcommand_error, similar_subcommands, try_alias,
+    alias_names_for_suggestions, build_invalid_subcommand_error, handle_switch_command,
+    similar_subcommands, try_alias,
 };
 use crate::enhance_clap_error;
 
@@ -89,6 +93,10 @@ pub(crate) fn handle_custom_command(
         return Ok(());
     }
 
+    if rest.is_empty() && !name.is_empty() && name.bytes().all(|b| b.is_ascii_digit()) {
+        return switch_to_row(&name, yes);
+    }
+
     // Fall through to `wt-<name>` PATH binary. Nested-subcommand hints
     // (`wt squash` → `wt step squash`) are applied by `enhance_clap_error`
     // when we fall through below, so a name that matches a nested subcommand
@@ -109,6 +117,17 @@ pub(crate) fn handle_custom_command(
     Err(enhance_clap_error(unrecognized_subcommand_error(&name)))
 }
 
+/// Run `wt switch %<row>` for the `wt <row>` shorthand, parsing through clap so
+/// every switch flag takes its normal default.
+fn switch_to_row(row: &str, yes: bool) -> Result<()> {
+    let matches = SwitchArgs::augment_args(clap::Command::new("switch"))
+        .try_get_matches_from(["switch", &format!("%{row}")])
+        .context("failed to build switch invocation")?;
+    let args =
+        SwitchArgs::from_arg_matches(&matches).context("failed to build switch invocation")?;
+    handle_switch_command(args, yes)
+}
+
 /// Build a `clap::Error` that mirrors what clap itself would have raised for
 /// an unrecognized top-level subcommand if we weren't capturing via
 /// `#[command(external_subcommand)]`. Populates `InvalidSubcommand`,
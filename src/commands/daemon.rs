@@ -0,0 +1,393 @@
+//! `wt daemon` — a background process that keeps caches warm.
+//!
+//! One daemon per user watches every registered repository. Each poll it
+//! takes [`Repository::ref_fingerprint`] of each repo; once a changed
+//! fingerprint holds for a second poll (so a rebase's burst of ref updates
+//! triggers one refresh, not dozens), it rebuilds the completion cache and
+//! runs `wt list --format=json` to fill the caches `wt list` reads.
+//!
+//! # Files
+//!
+//! Everything lives in a `daemon/` directory beside the user config:
+//!
+//! - `repos.json` — registered git common dirs
+//! - `status.json` — pid and heartbeat of the running daemon
+//! - `daemon.log` — output of the detached process
+//!
+//! # Lifecycle
+//!
+//! `status.json` is the daemon's lease. The daemon exits at the first poll
+//! that finds the file gone or naming another pid, so `wt daemon stop` only
+//! deletes it, and a second daemon started in a race retires the first. A
+//! heartbeat older than [`STALE_AFTER`] means the daemon died without
+//! cleaning up; `start` then launches a new one.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use color_print::cformat;
+use serde::{Deserialize, Serialize};
+use worktrunk::cache::{read_json, write_json};
+use worktrunk::config::require_config_path;
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{eprintln, info_message, success_message, warning_message};
+use worktrunk::utils::{epoch_now, now_iso8601};
+
+use crate::cli::DaemonCommand;
+
+/// How often the daemon checks fingerprints.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How often the daemon refreshes its heartbeat.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Heartbeat age after which a daemon is presumed dead.
+const STALE_AFTER: u64 = 30;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Registry {
+    repos: Vec<PathBuf>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DaemonStatus {
+    pid: u32,
+    /// Unix seconds
+    started_at: u64,
+    /// Unix seconds of the last poll-loop heartbeat
+    heartbeat_at: u64,
+}
+
+impl DaemonStatus {
+    fn is_live(&self) -> bool {
+        epoch_now().saturating_sub(self.heartbeat_at) <= STALE_AFTER
+    }
+}
+
+struct DaemonDir(PathBuf);
+
+impl DaemonDir {
+    fn locate() -> anyhow::Result<Self> {
+        let config = require_config_path()?;
+        let parent = config.parent().unwrap_or(Path::new("."));
+        Ok(Self(parent.join("daemon")))
+    }
+
+    fn registry_path(&self) -> PathBuf {
+        self.0.join("repos.json")
+    }
+
+    fn status_path(&self) -> PathBuf {
+        self.0.join("status.json")
+    }
+
+    fn registry(&self) -> Registry {
+        read_json(&self.registry_path()).unwrap_or_default()
+    }
+
+    fn write_registry(&self, registry: &Registry) -> anyhow::Result<()> {
+        fs::create_dir_all(&self.0)
+            .with_context(|| format!("Failed to create {}", format_path_for_display(&self.0)))?;
+        let json = serde_json::to_string_pretty(registry)?;
+        fs::write(self.registry_path(), json).with_context(|| {
+            format!(
+                "Failed to write {}",
+                format_path_for_display(&self.registry_path())
+            )
+        })
+    }
+
+    /// Status of a daemon whose heartbeat is current.
+    fn live_status(&self) -> Option<DaemonStatus> {
+        read_json::<DaemonStatus>(&self.status_path()).filter(DaemonStatus::is_live)
+    }
+}
+
+/// Handle `wt daemon <action>`.
+pub fn handle_daemon(action: DaemonCommand) -> anyhow::Result<()> {
+    let dir = DaemonDir::locate()?;
+    match action {
+        DaemonCommand::Start { foreground } => start(&dir, foreground),
+        DaemonCommand::Stop => stop(&dir),
+        DaemonCommand::Status => status(&dir),
+        DaemonCommand::Forget => forget(&dir),
+    }
+}
+
+/// The current repository's git common dir, as registered.
+fn current_repo_key() -> anyhow::Result<PathBuf> {
+    let repo = Repository::current()?;
+    let common = repo.git_common_dir();
+    Ok(dunce::canonicalize(common).unwrap_or_else(|_| common.to_path_buf()))
+}
+
+fn start(dir: &DaemonDir, foreground: bool) -> anyhow::Result<()> {
+    // The detached daemon runs from the daemon dir, outside any repository;
+    // a foreground run elsewhere may skip registering too.
+    let key = match current_repo_key() {
+        Ok(key) => key,
+        Err(_) if foreground => return run(dir),
+        Err(e) => return Err(e),
+    };
+    let mut registry = dir.registry();
+    if !registry.repos.contains(&key) {
+        registry.repos.push(key.clone());
+        dir.write_registry(&registry)?;
+    }
+
+    if foreground {
+        return run(dir);
+    }
+
+    let repo_display = format_path_for_display(&key);
+    if let Some(status) = dir.live_status() {
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Daemon (pid {}) is watching <bold>{repo_display}</>",
+                status.pid
+            ))
+        );
+        return Ok(());
+    }
+
+    let pid = spawn(dir)?;
+    // Written here as well as by the daemon, so `status` right after `start`
+    // sees it before the daemon's first poll.
+    write_status(dir, pid, epoch_now());
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Started daemon (pid {pid}), watching <bold>{repo_display}</>"
+        ))
+    );
+    Ok(())
+}
+
+fn stop(dir: &DaemonDir) -> anyhow::Result<()> {
+    let Some(status) = dir.live_status() else {
+        let _ = fs::remove_file(dir.status_path());
+        eprintln!("{}", info_message("Daemon is not running"));
+        return Ok(());
+    };
+    fs::remove_file(dir.status_path()).with_context(|| {
+        format!(
+            "Failed to remove {}",
+            format_path_for_display(&dir.status_path())
+        )
+    })?;
+    eprintln!(
+        "{}",
+        success_message(format!("Stopped daemon (pid {})", status.pid))
+    );
+    Ok(())
+}
+
+fn status(dir: &DaemonDir) -> anyhow::Result<()> {
+    match dir.live_status() {
+        Some(status) => eprintln!(
+            "{}",
+            success_message(format!("Daemon is running (pid {})", status.pid))
+        ),
+        None => eprintln!("{}", info_message("Daemon is not running")),
+    }
+
+    let registry = dir.registry();
+    if registry.repos.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "No repositories registered; run <underline>wt daemon start</> in one"
+            ))
+        );
+    }
+    for repo in &registry.repos {
+        let display = format_path_for_display(repo);
+        if repo.is_dir() {
+            eprintln!("{}", info_message(cformat!("Watching <bold>{display}</>")));
+        } else {
+            eprintln!(
+                "{}",
+                warning_message(cformat!("<bold>{display}</> no longer exists"))
+            );
+        }
+    }
+    Ok(())
+}
+
+fn forget(dir: &DaemonDir) -> anyhow::Result<()> {
+    let key = current_repo_key()?;
+    let display = format_path_for_display(&key);
+    let mut registry = dir.registry();
+    let before = registry.repos.len();
+    registry.repos.retain(|repo| repo != &key);
+    if registry.repos.len() == before {
+        eprintln!(
+            "{}",
+            info_message(cformat!("<bold>{display}</> isn't registered"))
+        );
+        return Ok(());
+    }
+    dir.write_registry(&registry)?;
+    eprintln!(
+        "{}",
+        success_message(cformat!("Stopped watching <bold>{display}</>"))
+    );
+    Ok(())
+}
+
+fn write_status(dir: &DaemonDir, pid: u32, started_at: u64) {
+    write_json(
+        &dir.status_path(),
+        &DaemonStatus {
+            pid,
+            started_at,
+            heartbeat_at: epoch_now(),
+        },
+    );
+}
+
+/// Launch `wt daemon start --foreground` detached, logging to `daemon.log`.
+fn spawn(dir: &DaemonDir) -> anyhow::Result<u32> {
+    fs::create_dir_all(&dir.0)
+        .with_context(|| format!("Failed to create {}", format_path_for_display(&dir.0)))?;
+    let log_path = dir.0.join("daemon.log");
+    let log = fs::File::create(&log_path)
+        .with_context(|| format!("Failed to create {}", format_path_for_display(&log_path)))?;
+    let exe = std::env::current_exe().context("Failed to locate the wt executable")?;
+
+    let mut cmd = worktrunk::priority::command(&exe, true);
+    cmd.args(["daemon", "start", "--foreground"])
+        .current_dir(&dir.0)
+        .stdin(Stdio::null())
+        .stdout(Stdio::from(
+            log.try_clone().context("Failed to clone log file handle")?,
+        ))
+        .stderr(Stdio::from(log));
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NEW_PROCESS_GROUP: u32 = 0x00000200;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        cmd.creation_flags(CREATE_NEW_PROCESS_GROUP | CREATE_NO_WINDOW);
+    }
+    worktrunk::shell_exec::scrub_directive_env_vars(&mut cmd);
+    let child = cmd.spawn().context("Failed to spawn daemon")?;
+    Ok(child.id())
+}
+
+/// Per-repo watch state.
+struct Watched {
+    /// Opened once; only its git common dir is used between refreshes
+    repo: Repository,
+    /// Fingerprint the caches were last refreshed at
+    refreshed: Option<String>,
+    /// Changed fingerprint waiting one poll to settle
+    pending: Option<String>,
+}
+
+/// The poll loop. Returns when the daemon's lease in `status.json` is gone.
+fn run(dir: &DaemonDir) -> anyhow::Result<()> {
+    let pid = std::process::id();
+    let started_at = epoch_now();
+    write_status(dir, pid, started_at);
+    let mut last_heartbeat = Instant::now();
+    let mut watched: HashMap<PathBuf, Watched> = HashMap::new();
+    let exe = std::env::current_exe().context("Failed to locate the wt executable")?;
+    eprintln!("{} daemon started (pid {pid})", now_iso8601());
+
+    loop {
+        match read_json::<DaemonStatus>(&dir.status_path()) {
+            Some(status) if status.pid == pid => {}
+            _ => {
+                eprintln!("{} daemon stopped", now_iso8601());
+                return Ok(());
+            }
+        }
+        if last_heartbeat.elapsed() >= HEARTBEAT_INTERVAL {
+            write_status(dir, pid, started_at);
+            last_heartbeat = Instant::now();
+        }
+
+        let repos = dir.registry().repos;
+        watched.retain(|path, _| repos.contains(path));
+        for path in repos {
+            if !path.is_dir() {
+                watched.remove(&path);
+                continue;
+            }
+            if !watched.contains_key(&path) {
+                let Ok(repo) = Repository::at(&path) else {
+                    continue;
+                };
+                let state = Watched {
+                    repo,
+                    refreshed: None,
+                    pending: None,
+                };
+                watched.insert(path.clone(), state);
+            }
+            let state = watched.get_mut(&path).expect("inserted above");
+            let fingerprint = state.repo.ref_fingerprint();
+            if state.refreshed.as_ref() == Some(&fingerprint) {
+                state.pending = None;
+                continue;
+            }
+            if state.pending.as_ref() != Some(&fingerprint) {
+                state.pending = Some(fingerprint);
+                continue;
+            }
+            state.pending = None;
+            state.refreshed = Some(fingerprint);
+            // A fresh `Repository`, since each caches its ref inventories.
+            if let Ok(repo) = Repository::at(&path) {
+                refresh(&repo, &exe);
+            }
+        }
+
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Rebuild the completion cache and warm the `wt list` caches.
+fn refresh(repo: &Repository, exe: &Path) {
+    let started = Instant::now();
+    let display = format_path_for_display(repo.git_common_dir());
+    if let Err(e) = repo.warm_completion_cache() {
+        eprintln!(
+            "{} {display}: completion cache failed: {e:#}",
+            now_iso8601()
+        );
+    }
+
+    let list = repo.repo_path().map(|dir| {
+        worktrunk::priority::command(exe, true)
+            .args(["list", "--format=json"])
+            .current_dir(dir)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+    });
+    match list {
+        Ok(Ok(status)) if status.success() => {}
+        Ok(Ok(status)) => eprintln!("{} {display}: wt list exited {status}", now_iso8601()),
+        Ok(Err(e)) => eprintln!("{} {display}: failed to run wt list: {e}", now_iso8601()),
+        Err(e) => eprintln!("{} {display}: {e:#}", now_iso8601()),
+    }
+    eprintln!(
+        "{} {display}: refreshed in {}ms",
+        now_iso8601(),
+        started.elapsed().as_millis()
+    );
+}
@@ -0,0 +1,124 @@
+//! `wt debug` — diagnostics for worktrunk itself.
+//!
+//! `wt debug git-calls -- <args>` re-runs `wt <args>` with
+//! [`AUDIT_ENV`] pointing at a scratch file, which every git and jj call the
+//! child (and any `wt` it starts) makes is appended to — see
+//! [`worktrunk::trace::audit`]. Once the child exits, the calls are grouped
+//! and printed, and the child's exit code is passed on.
+//!
+//! `wt debug gc` is `wt config state gc` under the name cleanup lives at
+//! alongside the other maintenance tools.
+
+use std::ffi::OsString;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::git::WorktrunkError;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{eprintln, format_with_gutter, info_message};
+use worktrunk::trace::CommandTrace;
+use worktrunk::trace::audit::{AUDIT_ENV, GitCallGroup, read_calls, summarize};
+
+use crate::cli::DebugCommand;
+
+/// Handle `wt debug <action>`. `working_dir` is the top-level `-C <path>`
+/// and `yes` the global `--yes`, both passed on to the audited command.
+pub(crate) fn handle_debug(
+    action: DebugCommand,
+    working_dir: Option<&Path>,
+    yes: bool,
+) -> anyhow::Result<()> {
+    match action {
+        DebugCommand::GitCalls { args } => handle_git_calls(&args, working_dir, yes),
+        DebugCommand::Gc => crate::commands::handle_state_gc(),
+    }
+}
+
+fn handle_git_calls(
+    args: &[OsString],
+    working_dir: Option<&Path>,
+    yes: bool,
+) -> anyhow::Result<()> {
+    let log = std::env::temp_dir().join(format!("wt-git-calls-{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&log);
+    let exe = std::env::current_exe().context("Failed to locate the wt executable")?;
+
+    let mut cmd = Command::new(&exe);
+    if yes {
+        cmd.arg("--yes");
+    }
+    cmd.args(args).env(AUDIT_ENV, &log);
+    if let Some(dir) = working_dir {
+        cmd.current_dir(dir);
+    }
+    let mut trace = CommandTrace::new(None, &exe.display().to_string());
+    let status = match cmd.status() {
+        Ok(status) => {
+            trace.complete_status(status);
+            status
+        }
+        Err(e) => {
+            trace.fail(&e);
+            return Err(e).context("Failed to run the audited command");
+        }
+    };
+
+    let contents = std::fs::read_to_string(&log).unwrap_or_default();
+    let _ = std::fs::remove_file(&log);
+    let calls = read_calls(&contents);
+    let groups = summarize(&calls);
+    let total_us: u64 = calls.iter().map(|call| call.dur_us).sum();
+    eprintln!(
+        "{}",
+        info_message(cformat!(
+            "<bold>{}</> git/jj call{} (<bold>{}</> distinct), {} in total",
+            calls.len(),
+            if calls.len() == 1 { "" } else { "s" },
+            groups.len(),
+            format_us(total_us)
+        ))
+    );
+    if !groups.is_empty() {
+        let rows: Vec<String> = groups.iter().map(format_group).collect();
+        eprintln!("{}", format_with_gutter(&rows.join("\n"), None));
+    }
+
+    if status.success() {
+        return Ok(());
+    }
+    // The command reported its own failure; only pass its exit code on.
+    Err(WorktrunkError::AlreadyDisplayed {
+        exit_code: status.code().unwrap_or(1),
+    }
+    .into())
+}
+
+/// One summary row: `3×   12.4ms  git rev-parse HEAD  ~/repo`.
+fn format_group(group: &GitCallGroup) -> String {
+    let dir = group
+        .cwd
+        .as_deref()
+        .map(|dir| format!("  {}", format_path_for_display(dir)))
+        .unwrap_or_default();
+    let failed = if group.failures > 0 {
+        cformat!("  <red>{} failed</>", group.failures)
+    } else {
+        String::new()
+    };
+    cformat!(
+        "{:>3}×  {:>8}  {}<dim>{dir}</>{failed}",
+        group.count,
+        format_us(group.total_us),
+        group.cmd
+    )
+}
+
+fn format_us(us: u64) -> String {
+    if us >= 1_000_000 {
+        format!("{:.2}s", us as f64 / 1_000_000.0)
+    } else {
+        format!("{:.1}ms", us as f64 / 1000.0)
+    }
+}
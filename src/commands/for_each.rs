@@ -37,25 +37,39 @@ use worktrunk::styling::{
 };
 
 use crate::commands::command_executor::{CommandContext, build_hook_context};
+use crate::commands::repo_group::{GroupRepo, open_repo_group};
 use crate::commands::worktree_display_name;
 
 /// Run a command in each worktree sequentially.
 ///
 /// Executes the given argv directly in every worktree, streaming output in
-/// real-time. Continues on errors and reports a summary at the end.
+/// real-time. Continues on errors and reports a summary at the end. With
+/// `group`, visits every worktree of each repository in that
+/// `[repo-groups]` entry, naming each `<repo>/<worktree>`.
 ///
 /// All template variables from hooks are available; values are substituted
 /// into argv elements without shell escaping. Context JSON is piped to stdin.
-pub fn step_for_each(args: Vec<String>, format: crate::cli::SwitchFormat) -> anyhow::Result<()> {
+pub fn step_for_each(
+    args: Vec<String>,
+    format: crate::cli::SwitchFormat,
+    group: Option<&str>,
+) -> anyhow::Result<()> {
     let json_mode = format == crate::cli::SwitchFormat::Json;
-    let repo = Repository::current()?;
-    // Filter out prunable worktrees (directory deleted) - can't run commands there
-    let worktrees: Vec<&WorktreeInfo> = repo
-        .list_worktrees()?
-        .iter()
-        .filter(|wt| !wt.is_prunable())
-        .collect();
     let config = UserConfig::load()?;
+    let repos = match group {
+        Some(group) => open_repo_group(&config, group)?
+            .into_iter()
+            .map(|GroupRepo { name, repo }| (Some(name), repo))
+            .collect(),
+        None => vec![(None, Repository::current()?)],
+    };
+    // Filter out prunable worktrees (directory deleted) - can't run commands there
+    let mut worktrees: Vec<(Option<&str>, &Repository, &WorktreeInfo)> = Vec::new();
+    for (repo_name, repo) in &repos {
+        for wt in repo.list_worktrees()?.iter().filter(|wt| !wt.is_prunable()) {
+            worktrees.push((repo_name.as_deref(), repo, wt));
+        }
+    }
 
     let mut failed: Vec<String> = Vec::new();
     let mut json_results: Vec<serde_json::Value> = Vec::new();
@@ -65,8 +79,12 @@ pub fn step_for_each(args: Vec<String>, format: crate::cli::SwitchFormat) -> any
     let mut interrupted: Option<i32> = None;
     let total = worktrees.len();
 
-    for &wt in &worktrees {
-        let display_name = worktree_display_name(wt, &repo, &config);
+    for &(repo_name, repo, wt) in &worktrees {
+        let display_name = worktree_display_name(wt, repo, &config);
+        let display_name = match repo_name {
+            Some(repo_name) => format!("{repo_name}/{display_name}"),
+            None => display_name.to_string(),
+        };
         eprintln!(
             "{}",
             progress_message(format!("Running in {display_name}..."))
@@ -74,7 +92,7 @@ pub fn step_for_each(args: Vec<String>, format: crate::cli::SwitchFormat) -> any
 
         // Build full hook context for this worktree
         // Pass wt.branch directly (not the display string) so detached HEAD maps to None -> "HEAD"
-        let ctx = CommandContext::new(&repo, &config, wt.branch.as_deref(), &wt.path, false);
+        let ctx = CommandContext::new(repo, &config, wt.branch.as_deref(), &wt.path, false);
         let context_map = build_hook_context(&ctx, &[], None)?;
 
         // Expand each argv element through the template engine without
@@ -91,7 +109,7 @@ pub fn step_for_each(args: Vec<String>, format: crate::cli::SwitchFormat) -> any
                     arg,
                     &vars,
                     ShellEscapeMode::Literal,
-                    &repo,
+                    repo,
                     "for-each argument",
                 )
             })
@@ -104,12 +122,15 @@ pub fn step_for_each(args: Vec<String>, format: crate::cli::SwitchFormat) -> any
         match run_argv(&wt.path, expanded, &context_json) {
             Ok(()) => {
                 if json_mode {
-                    json_results.push(serde_json::json!({
-                        "branch": wt.branch,
-                        "path": wt.path,
-                        "exit_code": 0,
-                        "success": true,
-                    }));
+                    json_results.push(with_repo(
+                        repo_name,
+                        serde_json::json!({
+                            "branch": wt.branch,
+                            "path": wt.path,
+                            "exit_code": 0,
+                            "success": true,
+                        }),
+                    ));
                 }
             }
             Err(err) => {
@@ -145,13 +166,16 @@ pub fn step_for_each(args: Vec<String>, format: crate::cli::SwitchFormat) -> any
                 }
                 failed.push(display_name.to_string());
                 if json_mode {
-                    json_results.push(serde_json::json!({
-                        "branch": wt.branch,
-                        "path": wt.path,
-                        "exit_code": exit_code,
-                        "success": false,
-                        "error": json_detail,
-                    }));
+                    json_results.push(with_repo(
+                        repo_name,
+                        serde_json::json!({
+                            "branch": wt.branch,
+                            "path": wt.path,
+                            "exit_code": exit_code,
+                            "success": false,
+                            "error": json_detail,
+                        }),
+                    ));
                 }
                 if let Some(code) = signal_exit {
                     interrupted = Some(code);
@@ -210,6 +234,14 @@ pub fn step_for_each(args: Vec<String>, format: crate::cli::SwitchFormat) -> any
     }
 }
 
+/// Tag a JSON result with its repository's group entry under `--group`.
+fn with_repo(repo_name: Option<&str>, mut result: serde_json::Value) -> serde_json::Value {
+    if let Some(repo_name) = repo_name {
+        result["repo"] = repo_name.into();
+    }
+    result
+}
+
 /// Run argv directly (no shell) with streaming output, signal forwarding,
 /// stdout→stderr redirect, and JSON context piped on stdin.
 ///
@@ -0,0 +1,149 @@
+//! `wt grep` — search every worktree at once.
+//!
+//! Runs `git grep` (or `rg` when it's on PATH) in each worktree in parallel,
+//! then prints each worktree's matches in `wt list` order, every line prefixed
+//! with the worktree's branch. Both tools search tracked and untracked files
+//! and skip gitignored ones, so uncommitted work an agent hasn't staged yet
+//! still shows up.
+
+use std::path::Path;
+
+use anyhow::Context;
+use color_print::cformat;
+use rayon::prelude::*;
+use worktrunk::git::{Repository, WorktreeInfo, WorktrunkError};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{eprintln, format_with_gutter, println, warning_message};
+
+/// The program that does the searching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Searcher {
+    GitGrep,
+    Ripgrep,
+}
+
+impl Searcher {
+    fn detect() -> Self {
+        if is_ripgrep_available() {
+            Self::Ripgrep
+        } else {
+            Self::GitGrep
+        }
+    }
+
+    /// The command searching for `pattern` in the current directory.
+    fn command(self, pattern: &str, args: &[String]) -> Cmd {
+        match self {
+            // `--untracked` matches rg's default of searching untracked files
+            // too; `-I` skips binaries, as rg does.
+            Self::GitGrep => Cmd::new("git").args([
+                "grep",
+                "--untracked",
+                "-I",
+                "-n",
+                "--color=never",
+                "--no-column",
+            ]),
+            Self::Ripgrep => {
+                Cmd::new("rg").args(["--no-heading", "--with-filename", "-n", "--color=never"])
+            }
+        }
+        .arg("-e")
+        .arg(pattern)
+        .args(args.iter().map(String::as_str))
+    }
+}
+
+/// Check if ripgrep is available
+fn is_ripgrep_available() -> bool {
+    // Allow tests to override detection
+    if let Ok(val) = std::env::var("WORKTRUNK_TEST_RG_INSTALLED") {
+        return val == "1";
+    }
+    which::which("rg").is_ok()
+}
+
+/// What searching one worktree produced.
+enum Outcome {
+    Matches(String),
+    NoMatches,
+    Failed(String),
+}
+
+fn search(searcher: Searcher, path: &Path, pattern: &str, args: &[String]) -> Outcome {
+    let output = match searcher.command(pattern, args).current_dir(path).run() {
+        Ok(output) => output,
+        Err(err) => return Outcome::Failed(err.to_string()),
+    };
+    // Both tools exit 1 for "no matches" and 2+ for errors
+    match output.status.code() {
+        Some(0) => Outcome::Matches(String::from_utf8_lossy(&output.stdout).into_owned()),
+        Some(1) => Outcome::NoMatches,
+        _ => Outcome::Failed(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+    }
+}
+
+/// Search every worktree for `pattern`.
+///
+/// Exits 0 when any worktree matched and 1 when none did, like `grep`; a
+/// worktree where the search fails is reported and the exit code is 2.
+pub fn handle_grep(pattern: &str, dirty_only: bool, args: &[String]) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    // Prunable worktrees (directory deleted) have nothing to search
+    let worktrees: Vec<&WorktreeInfo> = repo
+        .list_worktrees()?
+        .iter()
+        .filter(|wt| !wt.is_prunable())
+        .collect();
+    let searcher = Searcher::detect();
+
+    let results: Vec<(&WorktreeInfo, Option<Outcome>)> = worktrees
+        .par_iter()
+        .map(|&wt| -> anyhow::Result<_> {
+            if dirty_only {
+                let dirty = repo
+                    .worktree_at(&wt.path)
+                    .is_dirty()
+                    .with_context(|| format!("Failed to check {}", wt.path.display()))?;
+                if !dirty {
+                    return Ok((wt, None));
+                }
+            }
+            Ok((wt, Some(search(searcher, &wt.path, pattern, args))))
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    let mut matched = false;
+    let mut failed = false;
+    for (wt, outcome) in results {
+        let label = wt
+            .branch
+            .clone()
+            .unwrap_or_else(|| wt.dir_name().to_string());
+        match outcome {
+            None | Some(Outcome::NoMatches) => {}
+            Some(Outcome::Matches(stdout)) => {
+                matched = true;
+                for line in stdout.lines() {
+                    println!("{}", cformat!("<magenta>{label}</>:{line}"));
+                }
+            }
+            Some(Outcome::Failed(detail)) => {
+                failed = true;
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!("Search failed in <bold>{label}</>"))
+                );
+                if !detail.is_empty() {
+                    eprintln!("{}", format_with_gutter(&detail, None));
+                }
+            }
+        }
+    }
+
+    match (failed, matched) {
+        (true, _) => Err(WorktrunkError::AlreadyDisplayed { exit_code: 2 }.into()),
+        (false, true) => Ok(()),
+        (false, false) => Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into()),
+    }
+}
@@ -229,6 +229,7 @@ mod tests {
             context: std::collections::HashMap::new(),
             template_name: label.clone(),
             label,
+            cwd: None,
         }
     }
 
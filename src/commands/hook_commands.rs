@@ -6,6 +6,7 @@
 
 use std::collections::HashMap;
 use std::fmt::Write as _;
+use std::path::Path;
 
 use anyhow::Context;
 use color_print::cformat;
@@ -14,63 +15,22 @@ use worktrunk::HookType;
 use worktrunk::config::{
     ALIAS_ARGS_KEY, Approvals, CommandConfig, ProjectConfig, UserConfig, referenced_vars_for_config,
 };
-use worktrunk::git::Repository;
+use worktrunk::git::{ErrorExt, Repository, WorktrunkError, exit_codes};
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{
-    INFO_SYMBOL, PROMPT_SYMBOL, eprintln, format_bash_with_gutter, format_heading, hint_message,
-    info_message, println, warning_message,
+    INFO_SYMBOL, PROMPT_SYMBOL, eprintln, format_bash_with_gutter, format_heading,
+    format_with_gutter, hint_message, info_message, println, success_message, warning_message,
 };
 
 use super::command_approval::approve_hooks_filtered;
 use super::command_executor::{
     CommandContext, FailureStrategy, build_hook_context, render_template_preview,
 };
+use super::commit::files_to_commit;
 use super::context::CommandEnv;
 use super::hooks::{HookAnnouncer, prepare_and_check, run_hooks_foreground};
 use super::template_vars::TemplateVars;
-
-fn run_post_hook(
-    ctx: &CommandContext,
-    foreground: Option<bool>,
-    user_config: Option<&CommandConfig>,
-    project_config: Option<&CommandConfig>,
-    hook_type: HookType,
-    extra_vars: &[(&str, &str)],
-    name_filters: &[String],
-) -> anyhow::Result<()> {
-    // --foreground is for debugging; default is background.
-    if foreground.unwrap_or(false) {
-        return run_hooks_foreground(
-            ctx,
-            user_config,
-            project_config,
-            hook_type,
-            extra_vars,
-            name_filters,
-            FailureStrategy::Warn,
-        );
-    }
-
-    // Filter path merges user + project matches into one pipeline (the user
-    // cherry-picked specific names across sources). The default path keeps
-    // sources independent so a user hook failure doesn't abort project hooks.
-    let mut announcer = HookAnnouncer::new(ctx.repo, false);
-    if name_filters.is_empty() {
-        announcer.register(ctx, hook_type, extra_vars, None)?;
-    } else {
-        let flat = prepare_and_check(
-            ctx,
-            user_config,
-            project_config,
-            hook_type,
-            extra_vars,
-            name_filters,
-        )?;
-        // `flat` is non-empty: a filter that matches nothing errors above.
-        announcer.add_groups(ctx, hook_type, None, vec![flat]);
-    }
-    announcer.flush()
-}
+use crate::commands::worktree_display_name;
 
 /// Build best-effort directional vars for manual `wt hook` invocation.
 ///
@@ -93,12 +53,52 @@ fn build_manual_hook_template_vars(
     let worktree_path = ctx.worktree_path;
     match hook_type {
         // Merge/commit hooks: target = merge target (default branch for commit, current for merge)
-        HookType::PreCommit | HookType::PostCommit => {
+        HookType::PreCommit => {
+            let vars = default_branch
+                .map_or_else(TemplateVars::new, |t| TemplateVars::new().with_target(t));
+            // What `wt commit` would record with the configured stage mode.
+            let stage_mode = ctx.config.commit(ctx.project_id().as_deref()).stage();
+            let wt = ctx.repo.worktree_at(worktree_path);
+            vars.with_changed_files(&files_to_commit(&wt, stage_mode).unwrap_or_default())
+        }
+        HookType::PostCommit => {
             default_branch.map_or_else(TemplateVars::new, |t| TemplateVars::new().with_target(t))
         }
-        HookType::PreMerge | HookType::PostMerge => TemplateVars::new()
+        HookType::PreMerge => {
+            let vars = TemplateVars::new()
+                .with_target(branch)
+                .with_target_worktree_path(worktree_path);
+            // What merging into the default branch would bring onto it.
+            let Some(default_branch) = default_branch else {
+                return vars;
+            };
+            let range = format!("{default_branch}...HEAD");
+            let files = ctx.repo.worktree_at(worktree_path).changed_files(&[&range]);
+            vars.with_changed_files(&files.unwrap_or_default())
+        }
+        HookType::PostMerge => TemplateVars::new()
             .with_target(branch)
             .with_target_worktree_path(worktree_path),
+        // Push hooks: target = default branch, range = what pushing HEAD there adds
+        HookType::PrePush => {
+            let Some(target) = default_branch else {
+                return TemplateVars::new();
+            };
+            let mut vars = TemplateVars::new().with_target(target);
+            if let Ok(Some(path)) = ctx.repo.worktree_for_branch(target) {
+                vars = vars.with_target_worktree_path(&path);
+            }
+            let head = ctx
+                .repo
+                .worktree_at(worktree_path)
+                .head_sha()
+                .ok()
+                .flatten();
+            if let (Some(target_commit), Some(head)) = (ctx.repo.default_branch_sha(), head) {
+                vars = vars.with_push_range(&target_commit, &head);
+            }
+            vars
+        }
         // Switch hooks: base = current (we're "switching from" here)
         HookType::PreSwitch | HookType::PreCreate | HookType::PostCreate | HookType::PostSwitch => {
             TemplateVars::new()
@@ -189,11 +189,14 @@ pub struct HookCliArgs<'a> {
 /// - `None` = use default behavior for this hook type
 /// - `Some(true)` = run in foreground (for debugging)
 /// - `Some(false)` = run in background (default for post-start/post-switch)
+///
+/// With `all`, the hook runs once in every worktree (see [`run_hook_all`]).
 pub fn run_hook(
     hook_type: HookType,
     yes: bool,
     foreground: Option<bool>,
     dry_run: bool,
+    all: bool,
     cli: HookCliArgs<'_>,
 ) -> anyhow::Result<()> {
     let HookCliArgs {
@@ -269,76 +272,241 @@ pub fn run_hook(
         .map(|(k, v)| (k.as_str(), v.as_str()))
         .collect();
 
-    // Build extra vars per hook type (shared by dry-run and execution paths)
-    let default_branch = repo.default_branch();
     // Splice `args` into the template context as a JSON-encoded sequence.
     // `expand_template` rehydrates it as `ShellArgs` so bare `{{ args }}`
     // renders space-joined with per-element shell escaping. Mirrors
     // `run_alias` at `src/commands/alias.rs`.
     let args_json =
         serde_json::to_string(&args).expect("Vec<String> serialization should never fail");
-    let template_vars = build_manual_hook_template_vars(&ctx, hook_type, default_branch.as_deref());
-    let mut extra_vars = template_vars.as_extra_vars();
-    extra_vars.extend(custom_vars_refs.iter().copied());
-    // Forward positional CLI args as `{{ args }}` (empty sequence when
-    // nothing was forwarded). `expand_template` rehydrates this JSON into a
-    // `ShellArgs` sequence that renders space-joined, per-element escaped.
-    extra_vars.push((ALIAS_ARGS_KEY, &args_json));
-
-    if dry_run {
-        let steps = prepare_and_check(
-            &ctx,
-            user_config,
-            proj_config,
-            hook_type,
-            &extra_vars,
-            name_filters,
-        )?;
-
-        for sourced in steps {
-            for cmd in sourced.step.into_commands() {
-                let preview =
-                    render_template_preview(&cmd.template, &cmd.context, repo, &cmd.template_name)?;
-                let label = if cmd.name.is_some() {
-                    cformat!("{hook_type} <bold>{}</> would run:", cmd.label)
-                } else {
-                    cformat!("{hook_type} <bold>{}</> hook would run:", cmd.label)
-                };
-                // Dry-run preview is the command's answer (the hook that would
-                // run), so it goes to stdout — see /writing-user-outputs.
-                println!(
-                    "{}",
-                    info_message(cformat!("{label}\n{}", format_bash_with_gutter(&preview)))
-                );
+    let default_branch = repo.default_branch();
+
+    let hook = ManualHook {
+        hook_type,
+        user_config,
+        project_config: proj_config,
+        name_filters,
+        bindings: &custom_vars_refs,
+        args_json: &args_json,
+        default_branch: default_branch.as_deref(),
+        foreground,
+        dry_run,
+    };
+
+    if all {
+        return run_hook_all(&ctx, &hook);
+    }
+    hook.run_in(&ctx, None)
+}
+
+/// A manual `wt hook <type>` invocation with config and CLI bindings
+/// resolved, ready to run in one worktree or (with `--all`) in each.
+struct ManualHook<'a> {
+    hook_type: HookType,
+    user_config: Option<&'a CommandConfig>,
+    project_config: Option<&'a CommandConfig>,
+    name_filters: &'a [String],
+    /// Bound `--KEY=VALUE` / `--var` template variables.
+    bindings: &'a [(&'a str, &'a str)],
+    /// `{{ args }}` as a JSON-encoded sequence.
+    args_json: &'a str,
+    default_branch: Option<&'a str>,
+    foreground: Option<bool>,
+    dry_run: bool,
+}
+
+impl ManualHook<'_> {
+    /// Whether this invocation spawns background pipelines rather than
+    /// blocking on the commands.
+    fn runs_in_background(&self) -> bool {
+        !self.dry_run && !self.hook_type.is_pre() && !self.foreground.unwrap_or(false)
+    }
+
+    /// Run (or preview, under `--dry-run`) the hook in `ctx`'s worktree.
+    ///
+    /// `display_path` labels background announcements and dry-run previews
+    /// with the worktree they belong to; `None` for the single-worktree case.
+    fn run_in(&self, ctx: &CommandContext, display_path: Option<&Path>) -> anyhow::Result<()> {
+        let hook_type = self.hook_type;
+        let template_vars = build_manual_hook_template_vars(ctx, hook_type, self.default_branch);
+        let mut extra_vars = template_vars.as_extra_vars();
+        extra_vars.extend(self.bindings.iter().copied());
+        // Forward positional CLI args as `{{ args }}` (empty sequence when
+        // nothing was forwarded). `expand_template` rehydrates this JSON into a
+        // `ShellArgs` sequence that renders space-joined, per-element escaped.
+        extra_vars.push((ALIAS_ARGS_KEY, self.args_json));
+
+        if self.dry_run {
+            let steps = prepare_and_check(
+                ctx,
+                self.user_config,
+                self.project_config,
+                hook_type,
+                &extra_vars,
+                self.name_filters,
+            )?;
+
+            let location = display_path
+                .map(|path| cformat!(" @ <bold>{}</>", format_path_for_display(path)))
+                .unwrap_or_default();
+            for sourced in steps {
+                for cmd in sourced.step.into_commands() {
+                    let preview = render_template_preview(
+                        &cmd.template,
+                        &cmd.context,
+                        ctx.repo,
+                        &cmd.template_name,
+                    )?;
+                    let label = if cmd.name.is_some() {
+                        cformat!("{hook_type} <bold>{}</> would run{location}:", cmd.label)
+                    } else {
+                        cformat!(
+                            "{hook_type} <bold>{}</> hook would run{location}:",
+                            cmd.label
+                        )
+                    };
+                    // Dry-run preview is the command's answer (the hook that would
+                    // run), so it goes to stdout — see /writing-user-outputs.
+                    println!(
+                        "{}",
+                        info_message(cformat!("{label}\n{}", format_bash_with_gutter(&preview)))
+                    );
+                }
             }
+            return Ok(());
+        }
+
+        // pre-* hooks block (fail-fast); post-* hooks default to background.
+        if hook_type.is_pre() {
+            run_hooks_foreground(
+                ctx,
+                self.user_config,
+                self.project_config,
+                hook_type,
+                &extra_vars,
+                self.name_filters,
+                FailureStrategy::default_for(hook_type),
+            )
+        } else {
+            self.run_post(ctx, &extra_vars, display_path)
         }
-        return Ok(());
     }
 
-    // pre-* hooks block (fail-fast); post-* hooks default to background.
-    if hook_type.is_pre() {
-        run_hooks_foreground(
-            &ctx,
-            user_config,
-            proj_config,
-            hook_type,
-            &extra_vars,
-            name_filters,
-            FailureStrategy::default_for(hook_type),
-        )
-    } else {
-        run_post_hook(
-            &ctx,
-            foreground,
-            user_config,
-            proj_config,
-            hook_type,
-            &extra_vars,
-            name_filters,
-        )
+    /// Run a `post-*` hook: background pipelines by default, or blocking
+    /// under `--foreground` (for debugging).
+    fn run_post(
+        &self,
+        ctx: &CommandContext,
+        extra_vars: &[(&str, &str)],
+        display_path: Option<&Path>,
+    ) -> anyhow::Result<()> {
+        let hook_type = self.hook_type;
+        if self.foreground.unwrap_or(false) {
+            return run_hooks_foreground(
+                ctx,
+                self.user_config,
+                self.project_config,
+                hook_type,
+                extra_vars,
+                self.name_filters,
+                FailureStrategy::Warn,
+            );
+        }
+
+        // Filter path merges user + project matches into one pipeline (the user
+        // cherry-picked specific names across sources). The default path keeps
+        // sources independent so a user hook failure doesn't abort project hooks.
+        let mut announcer = HookAnnouncer::new(ctx.repo, false);
+        if self.name_filters.is_empty() {
+            announcer.register(ctx, hook_type, extra_vars, display_path)?;
+        } else {
+            let flat = prepare_and_check(
+                ctx,
+                self.user_config,
+                self.project_config,
+                hook_type,
+                extra_vars,
+                self.name_filters,
+            )?;
+            // `flat` is non-empty: a filter that matches nothing errors above.
+            announcer.add_groups(ctx, hook_type, display_path, vec![flat]);
+        }
+        announcer.flush()
     }
 }
 
+/// Run a manual hook in every worktree (`wt hook <type> --all`).
+///
+/// Commands come from the invoking worktree's config and were approved once
+/// by the caller; each worktree gets its own `{{ branch }}`,
+/// `{{ worktree_path }}`, and working directory. Prunable worktrees (directory
+/// missing) are skipped.
+///
+/// Background hooks spawn one pipeline per worktree, so they run in parallel
+/// and each worktree's output lands in its own log. Foreground hooks (`pre-*`,
+/// `--foreground`) run one worktree at a time: they stream straight to the
+/// terminal with stdin attached and Ctrl-C forwarded to the one running
+/// command, which concurrent worktrees can't share. A failing command doesn't
+/// stop the remaining worktrees, and the failures are summarized at the end.
+/// Ctrl-C aborts the loop.
+fn run_hook_all(ctx: &CommandContext, hook: &ManualHook) -> anyhow::Result<()> {
+    let repo = ctx.repo;
+    let worktrees: Vec<_> = repo
+        .list_worktrees()?
+        .iter()
+        .filter(|wt| !wt.is_prunable())
+        .collect();
+
+    let foreground = !hook.dry_run && !hook.runs_in_background();
+    let mut failed: Vec<String> = Vec::new();
+    for wt in &worktrees {
+        let wt_ctx = CommandContext::new(repo, ctx.config, wt.branch.as_deref(), &wt.path, ctx.yes);
+        let Err(err) = hook.run_in(&wt_ctx, Some(&wt.path)) else {
+            continue;
+        };
+        // Only command failures are per-worktree; config and template errors
+        // would repeat in every worktree, so they abort.
+        let command_failed = matches!(
+            err.downcast_ref::<WorktrunkError>(),
+            Some(WorktrunkError::HookCommandFailed { .. })
+        );
+        if !foreground || !command_failed || err.interrupt_exit_code().is_some() {
+            return Err(err);
+        }
+        let rendered = err.render_diagnostic().unwrap_or_else(|| err.to_string());
+        eprintln!("{rendered}");
+        failed.push(worktree_display_name(wt, repo, ctx.config));
+    }
+
+    if !foreground {
+        return Ok(());
+    }
+    let total = worktrees.len();
+    let plural = if total == 1 { "" } else { "s" };
+    if failed.is_empty() {
+        eprintln!(
+            "{}",
+            success_message(format!(
+                "Ran {} hooks in {total} worktree{plural}",
+                hook.hook_type
+            ))
+        );
+        return Ok(());
+    }
+    eprintln!(
+        "{}",
+        warning_message(format!(
+            "{} hooks failed in {} of {total} worktree{plural}",
+            hook.hook_type,
+            failed.len()
+        ))
+    );
+    eprintln!("{}", format_with_gutter(&failed.join("\n"), None));
+    Err(WorktrunkError::AlreadyDisplayed {
+        exit_code: exit_codes::HOOK_FAILED,
+    }
+    .into())
+}
+
 /// Handle `wt hook show` command - display configured hooks
 pub fn handle_hook_show(
     hook_type_filter: Option<&str>,
@@ -412,6 +580,92 @@ pub fn handle_hook_show(
     Ok(())
 }
 
+/// Handle `wt hook status [BRANCH]`: the last recorded run of each background
+/// hook pipeline for the branch, one line per command.
+pub fn handle_hook_status(
+    branch: Option<String>,
+    format: crate::cli::SwitchFormat,
+) -> anyhow::Result<()> {
+    use super::hook_status::{HookRunState, load_branch};
+    use super::list::json_output::JsonHookRun;
+    use super::stats::format_command_duration;
+    use crate::display::format_relative_time_short;
+
+    let repo = Repository::current()?;
+    let branch = match branch {
+        Some(b) => b,
+        None => repo.require_current_branch("show hook status for current branch")?,
+    };
+    let records = load_branch(&repo, &branch);
+
+    if format == crate::cli::SwitchFormat::Json {
+        let runs: Vec<JsonHookRun> = records.iter().map(JsonHookRun::from_record).collect();
+        println!("{}", serde_json::to_string_pretty(&runs)?);
+        return Ok(());
+    }
+
+    if records.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "No background hook runs recorded for <bold>{branch}</>"
+            ))
+        );
+        return Ok(());
+    }
+
+    let width = records
+        .iter()
+        .flat_map(|r| &r.commands)
+        .map(|c| c.name.len())
+        .max()
+        .unwrap_or(0);
+    let mut any_failed = false;
+    for record in &records {
+        let started = match format_relative_time_short(record.started_at as i64) {
+            age if age == "now" => "just now".to_string(),
+            age => format!("{age} ago"),
+        };
+        println!(
+            "{}",
+            cformat!(
+                "<bold>{}</> ({}), started {started}",
+                record.hook_type,
+                record.source,
+            )
+        );
+        for command in &record.commands {
+            let symbol = match command.state {
+                HookRunState::Succeeded => cformat!("<green>✓</>"),
+                HookRunState::Failed => cformat!("<red>✗</>"),
+                HookRunState::Running => cformat!("<yellow>⋯</>"),
+                HookRunState::Pending | HookRunState::Skipped => cformat!("<dim>○</>"),
+            };
+            let detail = match (command.state, command.duration_ms, command.exit_code) {
+                (HookRunState::Succeeded, Some(ms), _) => format_command_duration(ms),
+                (HookRunState::Failed, Some(ms), Some(code)) => {
+                    format!("{}, exit code {code}", format_command_duration(ms))
+                }
+                (HookRunState::Failed, Some(ms), None) => {
+                    format!("{}, killed", format_command_duration(ms))
+                }
+                (state, _, _) => <&'static str>::from(state).to_string(),
+            };
+            any_failed |= command.state == HookRunState::Failed;
+            println!("  {symbol} {:<width$}  {detail}", command.name);
+        }
+    }
+    if any_failed {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "Command output is in <underline>wt config state logs</>"
+            ))
+        );
+    }
+    Ok(())
+}
+
 /// Emit configured hooks as a JSON array of structured records.
 ///
 /// Each record carries the hook type, source (user or project), optional name,
@@ -11,11 +11,12 @@
 //! Selection (*which* `(source, hook_type, name, template)` tuples run),
 //! authorization (project templates ∈ [`Approvals`]) and rendering
 //! (template → shell string, needs live git) are three separate concerns.
-//! Operation-driven hooks (`pre-merge`, `post-merge`, `pre-remove`,
-//! `post-remove`, `post-switch`, `pre-start`, `post-start`) are gated *before*
-//! a state mutation (auto-rebase rewrites the feature `.config/wt.toml`; a
-//! merge moves the target ref; a removal scrubs the worktree; `git worktree
-//! add` materializes a `--create` worktree) and executed *after* it. When
+//! Operation-driven hooks (`pre-merge`, `pre-push`, `post-merge`,
+//! `pre-remove`, `post-remove`, `post-switch`, `pre-start`, `post-start`) are
+//! gated *before* a state mutation (auto-rebase rewrites the feature
+//! `.config/wt.toml`; a merge moves the target ref; a removal scrubs the
+//! worktree; `git worktree add` materializes a `--create` worktree) and
+//! executed *after* it. When
 //! selection runs a second time at execution (`load_project_config()` again),
 //! the mutated on-disk config can yield an *unapproved* command. On a fresh
 //! clone that is remote code execution.
@@ -52,6 +53,7 @@ use worktrunk::git::add_hook_skip_hint;
 use super::command_approval::approve_command_batch;
 use super::command_executor::{
     CommandContext, FailureStrategy, PipelineKind, execute_pipeline_foreground, prepare_steps,
+    skip_unchanged_commands,
 };
 use super::hook_announcement::SourcedStep;
 use super::hook_filter::HookSource;
@@ -339,7 +341,8 @@ fn render_planned(
 ) -> anyhow::Result<Vec<SourcedStep>> {
     let mut out = Vec::new();
     for (source, cfg) in entries {
-        for step in prepare_steps(cfg, ctx, extra_vars, hook_type, *source)? {
+        let cfg = skip_unchanged_commands(cfg, ctx, extra_vars);
+        for step in prepare_steps(&cfg, ctx, extra_vars, hook_type, *source)? {
             out.push(SourcedStep {
                 step,
                 source: *source,
@@ -351,7 +354,8 @@ fn render_planned(
 
 /// Foreground execution of a covered hook from the approved plan.
 ///
-/// Replaces `execute_hook` for `pre-merge` / `pre-remove` / `pre-start`. The
+/// Replaces `execute_hook` for `pre-merge` / `pre-push` / `pre-remove` /
+/// `pre-start`. The
 /// signature carries no config — only the plan, the render context, and the
 /// failure strategy.
 ///
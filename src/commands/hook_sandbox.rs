@@ -0,0 +1,230 @@
+//! `[sandbox]` — restrictions on project hooks.
+//!
+//! Approving a project's `.config/wt.toml` is a one-time decision; these
+//! settings limit what an approved hook can do afterwards. They apply only to
+//! hooks from the project config — user hooks and aliases run unrestricted.
+//!
+//! Restrictions wrap the rendered command string, so the foreground executor
+//! and the background pipeline runner apply them identically:
+//!
+//! ```text
+//! nice -n 10 ionice -c 3 env -i PATH="$PATH" … bwrap … -- sh -c '<command>'
+//! ```
+//!
+//! `nice` / `ionice` are skipped when missing. `isolate` is not: a hook that
+//! asked for isolation fails rather than running unconfined.
+
+use std::path::{Path, PathBuf};
+
+use color_print::cformat;
+use shell_escape::unix::escape;
+use worktrunk::config::SandboxConfig;
+use worktrunk::git::GitError;
+
+/// Variables `clear-env` keeps, when set.
+const BASE_ENV: &[&str] = &[
+    "PATH", "HOME", "USER", "LOGNAME", "SHELL", "TERM", "LANG", "LC_ALL", "TMPDIR",
+];
+
+/// Wrap `command` with the restrictions in `config`, for a hook running in
+/// `worktree_path`. Returns `command` unchanged when nothing is enabled.
+pub(crate) fn sandbox_command(
+    config: &SandboxConfig,
+    command: &str,
+    worktree_path: &Path,
+) -> anyhow::Result<String> {
+    wrap(
+        config,
+        command,
+        worktree_path,
+        &|bin| which::which(bin).is_ok(),
+        std::env::consts::OS,
+    )
+}
+
+fn wrap(
+    config: &SandboxConfig,
+    command: &str,
+    worktree_path: &Path,
+    installed: &dyn Fn(&str) -> bool,
+    os: &str,
+) -> anyhow::Result<String> {
+    if !config.is_enabled() {
+        return Ok(command.to_string());
+    }
+
+    let mut words: Vec<String> = Vec::new();
+    if config.nice {
+        if installed("nice") {
+            words.push("nice -n 10".into());
+        }
+        if os == "linux" && installed("ionice") {
+            words.push("ionice -c 3".into());
+        }
+    }
+    if config.clear_env {
+        words.push("env -i".into());
+        words.extend(kept_env(config).map(|name| format!("{name}=\"${name}\"")));
+    }
+    if config.isolate {
+        words.push(isolation(config, worktree_path, installed, os)?);
+    }
+    words.push("sh -c".into());
+    words.push(escape(command.into()).into_owned());
+    Ok(words.join(" "))
+}
+
+/// Names `clear-env` passes through: the base set, `keep-env`, and wt's own
+/// `WORKTRUNK_*` variables (directive files, config paths), limited to those
+/// currently set.
+fn kept_env(config: &SandboxConfig) -> impl Iterator<Item = String> {
+    let mut names: Vec<String> = BASE_ENV.iter().map(|s| s.to_string()).collect();
+    names.extend(config.keep_env.iter().cloned());
+    let mut own: Vec<String> = std::env::vars_os()
+        .filter_map(|(name, _)| name.into_string().ok())
+        .filter(|name| name.starts_with("WORKTRUNK_"))
+        .collect();
+    own.sort();
+    names.extend(own);
+
+    let mut seen = std::collections::HashSet::new();
+    names
+        .into_iter()
+        .filter(|name| is_env_name(name) && std::env::var_os(name).is_some())
+        .filter(move |name| seen.insert(name.clone()))
+}
+
+/// Whether `name` can appear unquoted in `NAME="$NAME"`.
+fn is_env_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// The filesystem-isolation prefix: `bwrap` on Linux, `sandbox-exec` on macOS.
+fn isolation(
+    config: &SandboxConfig,
+    worktree_path: &Path,
+    installed: &dyn Fn(&str) -> bool,
+    os: &str,
+) -> anyhow::Result<String> {
+    let mut writable = vec![worktree_path.to_path_buf(), std::env::temp_dir()];
+    writable.extend(
+        config
+            .writable
+            .iter()
+            .map(|dir| PathBuf::from(shellexpand::tilde(dir).as_ref()))
+            .filter(|dir| dir.exists()),
+    );
+
+    let tool = match os {
+        "linux" => "bwrap",
+        "macos" => "sandbox-exec",
+        _ => {
+            return Err(GitError::Other {
+                message: cformat!("<bold>[sandbox] isolate</> isn't supported on {os}"),
+            }
+            .into());
+        }
+    };
+    if !installed(tool) {
+        return Err(GitError::Other {
+            message: cformat!(
+                "<bold>[sandbox] isolate</> needs <bold>{tool}</>, which isn't installed"
+            ),
+        }
+        .into());
+    }
+
+    if os == "linux" {
+        let mut words =
+            vec!["bwrap --ro-bind / / --dev /dev --proc /proc --die-with-parent".to_string()];
+        for dir in &writable {
+            let dir = escape(dir.to_string_lossy()).into_owned();
+            words.push(format!("--bind {dir} {dir}"));
+        }
+        words.push("--".into());
+        Ok(words.join(" "))
+    } else {
+        // Seatbelt matches resolved paths (`/tmp` is `/private/tmp`)
+        let rules: String = writable
+            .iter()
+            .chain(std::iter::once(&PathBuf::from("/dev")))
+            .map(|dir| {
+                let dir = dunce::canonicalize(dir).unwrap_or_else(|_| dir.clone());
+                let dir = dir
+                    .to_string_lossy()
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"");
+                format!(" (subpath \"{dir}\")")
+            })
+            .collect();
+        let profile =
+            format!("(version 1)(allow default)(deny file-write*)(allow file-write*{rules})");
+        Ok(format!("sandbox-exec -p {}", escape(profile.into())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_builds_restrictions_in_order() {
+        let everything = |_: &str| true;
+        let wt = Path::new("/repo.feature");
+
+        let off = SandboxConfig::default();
+        assert_eq!(
+            wrap(&off, "make", wt, &everything, "linux").unwrap(),
+            "make"
+        );
+
+        let config = SandboxConfig {
+            nice: true,
+            clear_env: true,
+            keep_env: vec!["NOT A NAME".into()],
+            isolate: true,
+            writable: vec![],
+        };
+        let wrapped = wrap(&config, "npm ci", wt, &everything, "linux").unwrap();
+        assert!(
+            wrapped.starts_with(r#"nice -n 10 ionice -c 3 env -i PATH="$PATH""#),
+            "{wrapped}"
+        );
+        assert!(!wrapped.contains("NOT A NAME"), "{wrapped}");
+        assert!(
+            wrapped.contains("--bind /repo.feature /repo.feature"),
+            "{wrapped}"
+        );
+        assert!(wrapped.ends_with("-- sh -c 'npm ci'"), "{wrapped}");
+
+        // `ionice` is Linux-only; a missing `nice` is skipped
+        let nice = SandboxConfig {
+            nice: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            wrap(&nice, "make", wt, &everything, "macos").unwrap(),
+            "nice -n 10 sh -c make"
+        );
+        assert_eq!(
+            wrap(&nice, "make", wt, &|_| false, "linux").unwrap(),
+            "sh -c make"
+        );
+    }
+
+    #[test]
+    fn test_isolate_fails_without_tool() {
+        let config = SandboxConfig {
+            isolate: true,
+            ..Default::default()
+        };
+        let wt = Path::new("/repo.feature");
+        let err = wrap(&config, "make", wt, &|_| false, "linux").unwrap_err();
+        assert!(err.to_string().contains("bwrap"), "{err}");
+        assert!(wrap(&config, "make", wt, &|_| true, "windows").is_err());
+    }
+}
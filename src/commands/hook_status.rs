@@ -0,0 +1,526 @@
+//! Last-run results of background hook pipelines, per branch.
+//!
+//! Background hooks (`post-start`, `post-switch`, …) run detached, so a failure
+//! only lands in a log file and the `Running …` line scrolls away. The pipeline
+//! runner (`wt hook run-pipeline`) records each command's outcome here as it
+//! goes: one JSON file per pipeline at
+//! `<wt-state-dir>/hook-status/<branch>/<source>-<hook-type>.json`, rewritten
+//! when a command starts and finishes. The next run of the same pipeline
+//! replaces it, so the file always describes the latest run.
+//!
+//! `wt list --full` summarizes the records in its Hooks column; `wt hook
+//! status` lists them per command. Records of deleted branches are swept by
+//! `wt config state gc`.
+//!
+//! Records are best-effort: a failed write never fails the pipeline. A runner
+//! that dies mid-run (killed, machine restart) leaves `running` records behind;
+//! [`load_branch`] reports those as failed once the runner's pid is gone.
+//!
+//! The runner leads its own process group, so a record's pid also names the
+//! group of everything its commands started. `wt remove` uses that to stop
+//! servers a `post-start` hook left running ([`stop_branch_processes`]). Records
+//! outlive their runner and pids get reused, so a record also carries the
+//! runner's start time; a pid whose process started at a different time is
+//! someone else's.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use worktrunk::HookType;
+use worktrunk::git::Repository;
+use worktrunk::git::worktree_processes::process_start;
+use worktrunk::path::sanitize_for_filename;
+use worktrunk::utils::epoch_now;
+
+use super::hook_filter::HookSource;
+
+/// Where one command of a pipeline stands.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, strum::IntoStaticStr,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum HookRunState {
+    // Variant order is summary precedence (see `PipelineRecord::state`), lowest first.
+    Succeeded,
+    /// Not reached: an earlier step of the pipeline failed.
+    Skipped,
+    /// Queued behind an earlier step that is still running.
+    Pending,
+    Running,
+    Failed,
+}
+
+/// One command's last run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CommandRecord {
+    /// Command name from config, or `cmd-<n>` for unnamed commands (matching
+    /// the log file name).
+    pub name: String,
+    pub state: HookRunState,
+    /// Unix time the command started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    /// Exit code of a finished command; absent when it never spawned or was
+    /// killed by a signal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+}
+
+/// The latest run of one `(source, hook type)` pipeline for a branch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct PipelineRecord {
+    pub hook_type: HookType,
+    pub source: HookSource,
+    /// Pid of the `wt hook run-pipeline` process, which is also the process
+    /// group of every command it started.
+    pub pid: u32,
+    /// When that process started, as the OS reports it (see
+    /// [`process_start`]). Absent when it couldn't be read, or in records
+    /// written before it was recorded; such a runner is never signalled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid_start: Option<String>,
+    /// Unix time the pipeline started.
+    pub started_at: u64,
+    pub commands: Vec<CommandRecord>,
+}
+
+impl PipelineRecord {
+    /// The most severe command state: failed, then running, pending, skipped,
+    /// succeeded. `None` for a pipeline with no commands.
+    pub(crate) fn state(&self) -> Option<HookRunState> {
+        self.commands.iter().map(|c| c.state).max()
+    }
+
+    /// Whether any command is still running or waiting its turn.
+    fn unfinished(&self) -> bool {
+        self.commands
+            .iter()
+            .any(|c| matches!(c.state, HookRunState::Running | HookRunState::Pending))
+    }
+}
+
+/// One-cell summary of a branch's records for the `wt list` Hooks column:
+/// the most severe state across every pipeline, with the failing command's
+/// name (`✗ build`, `✗ build +1`), `running`, `skipped`, or `✓`. `None` when
+/// nothing is recorded.
+pub(crate) fn summary_cell(records: &[PipelineRecord]) -> Option<(HookRunState, String)> {
+    let state = records.iter().filter_map(PipelineRecord::state).max()?;
+    let text = match state {
+        HookRunState::Failed => {
+            let mut failed = records
+                .iter()
+                .flat_map(|r| &r.commands)
+                .filter(|c| c.state == HookRunState::Failed);
+            // `state` is Failed, so at least one command failed
+            let first = failed.next().map_or("", |c| c.name.as_str());
+            match failed.count() {
+                0 => format!("✗ {first}"),
+                more => format!("✗ {first} +{more}"),
+            }
+        }
+        HookRunState::Running | HookRunState::Pending => "running".to_string(),
+        HookRunState::Skipped => "skipped".to_string(),
+        HookRunState::Succeeded => "✓".to_string(),
+    };
+    Some((state, text))
+}
+
+/// Directory holding every branch's records, one subdirectory per sanitized
+/// branch name (swept with the log tree by `wt config state gc`).
+pub(crate) fn status_dir(repo: &Repository) -> PathBuf {
+    repo.wt_state_dir().join("hook-status")
+}
+
+fn branch_dir(repo: &Repository, branch: &str) -> PathBuf {
+    status_dir(repo).join(sanitize_for_filename(branch))
+}
+
+/// Whether any branch has a recorded hook run — the `wt list` data-source gate
+/// for the Hooks column. One directory read, no parsing.
+pub(crate) fn any_recorded(repo: &Repository) -> bool {
+    fs::read_dir(status_dir(repo)).is_ok_and(|mut entries| entries.next().is_some())
+}
+
+/// Load a branch's pipeline records, oldest first.
+///
+/// Commands still marked running or pending when their runner has exited are
+/// reported as failed and skipped respectively — the runner died before it
+/// could record an outcome.
+pub(crate) fn load_branch(repo: &Repository, branch: &str) -> Vec<PipelineRecord> {
+    let Ok(entries) = fs::read_dir(branch_dir(repo, branch)) else {
+        return Vec::new();
+    };
+    let mut records: Vec<PipelineRecord> = entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|entry| fs::read(entry.path()).ok())
+        .filter_map(|bytes| serde_json::from_slice(&bytes).ok())
+        .collect();
+    for record in &mut records {
+        if record.unfinished() && !runner_alive(record) {
+            for command in &mut record.commands {
+                command.state = match command.state {
+                    HookRunState::Running => HookRunState::Failed,
+                    HookRunState::Pending => HookRunState::Skipped,
+                    state => state,
+                };
+            }
+        }
+    }
+    records.sort_by_key(|r| (r.started_at, r.hook_type.to_string(), r.source));
+    records
+}
+
+/// Whether `record`'s runner is still going: its pid exists and, when the
+/// record has a start time, belongs to a process started at that time.
+#[cfg(unix)]
+fn runner_alive(record: &PipelineRecord) -> bool {
+    // Signal 0 checks existence without delivering anything; EPERM means the
+    // process exists under another user.
+    let exists = match nix::sys::signal::kill(nix::unistd::Pid::from_raw(record.pid as i32), None) {
+        Ok(()) => true,
+        Err(nix::errno::Errno::EPERM) => true,
+        Err(_) => false,
+    };
+    exists
+        && record
+            .pid_start
+            .as_ref()
+            .is_none_or(|start| process_start(record.pid).as_ref() == Some(start))
+}
+
+#[cfg(not(unix))]
+fn runner_alive(_record: &PipelineRecord) -> bool {
+    true
+}
+
+/// How long stopped hook processes get to exit on SIGTERM before SIGKILL.
+#[cfg(unix)]
+const STOP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Stop every process still running from `branch`'s background hooks, ahead
+/// of removing its worktree — a dev server started by `post-start` would
+/// otherwise keep serving a deleted directory.
+///
+/// Each running pipeline's process group (see [`running_pipelines`]) gets
+/// SIGTERM, then SIGKILL if anything in it outlives [`STOP_TIMEOUT`]. That
+/// covers the command the runner is waiting on and anything it backgrounded
+/// with `&`; a process that moved itself to a new session (`setsid`) is out of
+/// reach. Returns the pipelines that had something running.
+#[cfg(unix)]
+pub(crate) fn stop_branch_processes(repo: &Repository, branch: &str) -> Vec<PipelineRecord> {
+    use nix::sys::signal::{Signal, killpg};
+    use nix::unistd::Pid;
+
+    let running = running_pipelines(repo, branch);
+    if running.is_empty() {
+        return running;
+    }
+
+    let groups: Vec<Pid> = running
+        .iter()
+        .map(|record| Pid::from_raw(record.pid as i32))
+        .collect();
+    for &group in &groups {
+        let _ = killpg(group, Signal::SIGTERM);
+    }
+    let deadline = std::time::Instant::now() + STOP_TIMEOUT;
+    while groups.iter().any(|&group| group_alive(group)) {
+        if std::time::Instant::now() >= deadline {
+            for &group in &groups {
+                let _ = killpg(group, Signal::SIGKILL);
+            }
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+    running
+}
+
+#[cfg(not(unix))]
+pub(crate) fn stop_branch_processes(_repo: &Repository, _branch: &str) -> Vec<PipelineRecord> {
+    Vec::new()
+}
+
+/// `branch`'s pipelines that are still running, with the runner that wrote the
+/// record still alive.
+///
+/// Finished records stay on disk until the next run, and their pid may since
+/// have been reused by an unrelated process group, so a pipeline only counts
+/// while it has unfinished commands and its pid's start time matches the
+/// record's.
+#[cfg(unix)]
+pub(crate) fn running_pipelines(repo: &Repository, branch: &str) -> Vec<PipelineRecord> {
+    let own_group = nix::unistd::getpgrp();
+    load_branch(repo, branch)
+        .into_iter()
+        // `load_branch` already settled the commands of a dead runner.
+        .filter(PipelineRecord::unfinished)
+        .filter(|record| {
+            // Never signal init's group, or our own — `wt remove` run from
+            // inside one of the branch's hooks.
+            let group = nix::unistd::Pid::from_raw(record.pid as i32);
+            record.pid > 1 && group != own_group
+        })
+        .filter(|record| {
+            record
+                .pid_start
+                .as_ref()
+                .is_some_and(|start| process_start(record.pid).as_ref() == Some(start))
+        })
+        .collect()
+}
+
+#[cfg(not(unix))]
+pub(crate) fn running_pipelines(_repo: &Repository, _branch: &str) -> Vec<PipelineRecord> {
+    Vec::new()
+}
+
+/// Whether process `pid` was started by one of `pipelines`, i.e. is in its
+/// process group.
+#[cfg(unix)]
+pub(crate) fn started_by(pipelines: &[PipelineRecord], pid: u32) -> bool {
+    nix::unistd::getpgid(Some(nix::unistd::Pid::from_raw(pid as i32)))
+        .is_ok_and(|group| pipelines.iter().any(|r| r.pid as i32 == group.as_raw()))
+}
+
+#[cfg(not(unix))]
+pub(crate) fn started_by(_pipelines: &[PipelineRecord], _pid: u32) -> bool {
+    false
+}
+
+#[cfg(unix)]
+fn group_alive(group: nix::unistd::Pid) -> bool {
+    match nix::sys::signal::killpg(group, None) {
+        Ok(()) => true,
+        Err(nix::errno::Errno::EPERM) => true,
+        Err(_) => false,
+    }
+}
+
+/// Writes a pipeline's record as its commands run. Owned by the pipeline
+/// runner; commands are addressed by their position in the pipeline.
+pub(crate) struct PipelineRecorder {
+    path: PathBuf,
+    record: PipelineRecord,
+    started: Vec<Option<std::time::Instant>>,
+}
+
+impl PipelineRecorder {
+    /// Start a record listing every command as pending, replacing the
+    /// previous run's.
+    pub(crate) fn start(
+        repo: &Repository,
+        branch: &str,
+        source: HookSource,
+        hook_type: HookType,
+        names: Vec<String>,
+    ) -> Self {
+        let path = branch_dir(repo, branch).join(format!("{source}-{hook_type}.json"));
+        let started = vec![None; names.len()];
+        let recorder = Self {
+            path,
+            record: PipelineRecord {
+                hook_type,
+                source,
+                pid: std::process::id(),
+                pid_start: process_start(std::process::id()),
+                started_at: epoch_now(),
+                commands: names
+                    .into_iter()
+                    .map(|name| CommandRecord {
+                        name,
+                        state: HookRunState::Pending,
+                        started_at: None,
+                        duration_ms: None,
+                        exit_code: None,
+                    })
+                    .collect(),
+            },
+            started,
+        };
+        recorder.write();
+        recorder
+    }
+
+    /// Mark command `index` as running.
+    pub(crate) fn begin(&mut self, index: usize) {
+        let Some(command) = self.record.commands.get_mut(index) else {
+            return;
+        };
+        command.state = HookRunState::Running;
+        command.started_at = Some(epoch_now());
+        self.started[index] = Some(std::time::Instant::now());
+        self.write();
+    }
+
+    /// Record command `index`'s outcome. `exit_code` is `None` when the
+    /// command never produced one (spawn failure, signal).
+    pub(crate) fn finish(&mut self, index: usize, success: bool, exit_code: Option<i32>) {
+        let Some(command) = self.record.commands.get_mut(index) else {
+            return;
+        };
+        command.state = if success {
+            HookRunState::Succeeded
+        } else {
+            HookRunState::Failed
+        };
+        command.duration_ms = self.started[index].map(|t| t.elapsed().as_millis() as u64);
+        command.exit_code = exit_code;
+        self.write();
+    }
+
+    /// Close out an aborted pipeline: commands that never started are skipped,
+    /// and any left running (torn down after a sibling failed to spawn) failed.
+    pub(crate) fn abort(&mut self) {
+        for command in &mut self.record.commands {
+            command.state = match command.state {
+                HookRunState::Pending => HookRunState::Skipped,
+                HookRunState::Running => HookRunState::Failed,
+                state => state,
+            };
+        }
+        self.write();
+    }
+
+    fn write(&self) {
+        let _ = write_record(&self.path, &self.record);
+    }
+}
+
+/// Write via a temp file and rename, so readers never see a torn record.
+fn write_record(path: &Path, record: &PipelineRecord) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, serde_json::to_vec_pretty(record)?)?;
+    fs::rename(&tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use worktrunk::testing::TestRepo;
+
+    #[test]
+    fn test_recorder_lifecycle() {
+        let test = TestRepo::with_initial_commit();
+        let repo = Repository::at(test.root_path()).unwrap();
+        assert!(!any_recorded(&repo));
+
+        let mut recorder = PipelineRecorder::start(
+            &repo,
+            "feature/x",
+            HookSource::Project,
+            HookType::PostCreate,
+            vec!["install".into(), "build".into(), "serve".into()],
+        );
+        recorder.begin(0);
+        recorder.finish(0, true, Some(0));
+        recorder.begin(1);
+
+        let records = load_branch(&repo, "feature/x");
+        assert_eq!(records.len(), 1);
+        let states: Vec<_> = records[0].commands.iter().map(|c| c.state).collect();
+        assert_eq!(
+            states,
+            [
+                HookRunState::Succeeded,
+                HookRunState::Running,
+                HookRunState::Pending
+            ]
+        );
+        assert_eq!(records[0].state(), Some(HookRunState::Running));
+
+        recorder.finish(1, false, Some(2));
+        recorder.abort();
+        let record = &load_branch(&repo, "feature/x")[0];
+        assert_eq!(record.commands[1].exit_code, Some(2));
+        assert_eq!(record.commands[2].state, HookRunState::Skipped);
+        assert_eq!(record.state(), Some(HookRunState::Failed));
+        assert!(any_recorded(&repo));
+        assert!(load_branch(&repo, "other").is_empty());
+        assert_eq!(
+            summary_cell(&load_branch(&repo, "feature/x")),
+            Some((HookRunState::Failed, "✗ build".to_string()))
+        );
+        assert_eq!(summary_cell(&[]), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_dead_runner_reads_as_failed() {
+        let test = TestRepo::with_initial_commit();
+        let repo = Repository::at(test.root_path()).unwrap();
+        let mut recorder = PipelineRecorder::start(
+            &repo,
+            "main",
+            HookSource::User,
+            HookType::PostSwitch,
+            vec!["a".into(), "b".into()],
+        );
+        recorder.begin(0);
+        // A pid that can't exist (above the Linux/macOS pid ceilings).
+        recorder.record.pid = i32::MAX as u32;
+        recorder.write();
+
+        let record = &load_branch(&repo, "main")[0];
+        assert_eq!(record.commands[0].state, HookRunState::Failed);
+        assert_eq!(record.commands[1].state, HookRunState::Skipped);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_reused_pid_is_not_signalled() {
+        use std::os::unix::process::CommandExt;
+
+        let test = TestRepo::with_initial_commit();
+        let repo = Repository::at(test.root_path()).unwrap();
+        // A live process group that has nothing to do with the pipeline, as
+        // when the runner's pid was reused after it exited.
+        let mut unrelated = std::process::Command::new("sleep")
+            .arg("30")
+            .process_group(0)
+            .spawn()
+            .unwrap();
+        let pid = unrelated.id();
+        let mut recorder = PipelineRecorder::start(
+            &repo,
+            "feature",
+            HookSource::Project,
+            HookType::PostCreate,
+            vec!["serve".into()],
+        );
+        recorder.begin(0);
+        recorder.record.pid = pid;
+        recorder.record.pid_start = Some("not-its-start-time".into());
+        recorder.write();
+
+        assert!(running_pipelines(&repo, "feature").is_empty());
+        assert!(stop_branch_processes(&repo, "feature").is_empty());
+        assert!(unrelated.try_wait().unwrap().is_none());
+        assert_eq!(
+            load_branch(&repo, "feature")[0].commands[0].state,
+            HookRunState::Failed
+        );
+
+        // Matching start time, but the pipeline already finished.
+        recorder.record.pid_start = process_start(pid);
+        recorder.finish(0, true, Some(0));
+        assert!(stop_branch_processes(&repo, "feature").is_empty());
+        assert!(unrelated.try_wait().unwrap().is_none());
+
+        // Only a running pipeline whose runner is still the same process is stopped.
+        recorder.record.commands[0].state = HookRunState::Running;
+        recorder.write();
+        // Reap it as it dies, or the zombie keeps the group alive until SIGKILL.
+        let reaper = std::thread::spawn(move || unrelated.wait().unwrap());
+        assert_eq!(stop_branch_processes(&repo, "feature").len(), 1);
+        assert!(reaper.join().unwrap().code().is_none());
+    }
+}
@@ -15,8 +15,9 @@
 //! Two execution models split on whether a state mutation separates the
 //! approval gate from execution.
 //!
-//! **Plan-backed (the TOCTOU-covered set):** `pre-merge`, `post-merge`,
-//! `pre-remove`, `post-remove`, `post-switch`, `pre-start`, `post-start`. A
+//! **Plan-backed (the TOCTOU-covered set):** `pre-merge`, `pre-push` (in
+//! `wt merge`), `post-merge`, `pre-remove`, `post-remove`, `post-switch`,
+//! `pre-start`, `post-start`. A
 //! merge, rebase, removal, or `git worktree add` runs between the gate and
 //! these hooks; a rebase can even rewrite the invoking worktree's own
 //! `.config/wt.toml`, so a second config read could select a command the user
@@ -29,7 +30,7 @@
 //!
 //! | Plan-backed hook | Runs in (the anchor) | Gate |
 //! |---|---|---|
-//! | `pre-merge`, `pre-remove`, `post-remove` | the feature/removed worktree | `merge::approve_merge_plan`, `remove::handle_remove_command`'s `approve_remove`, `step::prune::approve_prune_hooks` |
+//! | `pre-merge`, `pre-push`, `pre-remove`, `post-remove` | the feature/removed worktree | `merge::approve_merge_plan`, `remove::handle_remove_command`'s `approve_remove`, `step::prune::approve_prune_hooks` |
 //! | `post-merge`, `post-switch` (after a removal) | the merge/removal destination | the same gates |
 //! | `pre-start`, `post-start`, `post-switch` (on switch) | the new/destination worktree | `worktree::switch::approve_switch_hooks` |
 //!
@@ -38,7 +39,8 @@
 //! gate runs; the config came from the invoking worktree regardless.
 //!
 //! **Invocation-resolved (no gate→exec mutation):** `pre-commit`,
-//! `post-commit`, `pre-switch`, `wt hook <type>`, aliases. They resolve config
+//! `post-commit`, `pre-switch`, `pre-push` (in `wt step push`),
+//! `wt hook <type>`, aliases. They resolve config
 //! from `ctx.repo.load_project_config()` at invocation via [`execute_hook`] /
 //! [`HookAnnouncer::register`]. Two facts make that re-read safe, and a new
 //! call site must preserve **both**: (1) nothing between the gate and the
@@ -62,12 +64,13 @@
 //! (test isolation); user config (`~/.config/worktrunk/config.toml`) is global
 //! and unaffected.
 
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 use color_print::cformat;
 use worktrunk::HookType;
-use worktrunk::config::{CommandConfig, format_hook_variables};
+use worktrunk::config::{CommandConfig, SandboxConfig, format_hook_variables};
 use worktrunk::git::{Repository, add_hook_skip_hint};
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{
@@ -77,6 +80,7 @@ use worktrunk::styling::{
 use super::command_executor::{
     CommandContext, FailureStrategy, ForegroundStep, PipelineKind, PreparedCommand, PreparedStep,
     alias_error_wrapper, execute_pipeline_foreground, hook_error_wrapper, prepare_steps,
+    skip_unchanged_commands,
 };
 use super::hook_announcement::{SourcedStep, format_pipeline_summary};
 use crate::commands::process::{HookLog, spawn_detached_exec};
@@ -126,7 +130,13 @@ pub(crate) fn prepare_and_check(
             continue;
         }
 
-        let steps = prepare_steps(config, ctx, extra_vars, hook_type, source)?;
+        // Naming a command runs it regardless of its `when-changed` files.
+        let config = if parsed_filters.is_empty() {
+            skip_unchanged_commands(config, ctx, extra_vars)
+        } else {
+            Cow::Borrowed(config)
+        };
+        let steps = prepare_steps(&config, ctx, extra_vars, hook_type, source)?;
         for step in steps {
             if let Some(filtered) = filter_step_by_name(step, source, &parsed_filters) {
                 result.push(SourcedStep {
@@ -503,6 +513,7 @@ fn spawn_hook_pipeline_quiet(repo: &Repository, pipeline: &PendingPipeline) -> a
                 name: cmd.name.clone(),
                 template_name: cmd.template_name.clone(),
                 template: cmd.template.clone(),
+                cwd: cmd.cwd.clone(),
             },
             PreparedStep::Concurrent(cmds) => PipelineStepSpec::Concurrent {
                 commands: cmds
@@ -511,6 +522,7 @@ fn spawn_hook_pipeline_quiet(repo: &Repository, pipeline: &PendingPipeline) -> a
                         name: c.name.clone(),
                         template_name: c.template_name.clone(),
                         template: c.template.clone(),
+                        cwd: c.cwd.clone(),
                     })
                     .collect(),
             },
@@ -528,6 +540,7 @@ fn spawn_hook_pipeline_quiet(repo: &Repository, pipeline: &PendingPipeline) -> a
         context,
         steps: spec_steps,
         log_dir: repo.wt_logs_dir(),
+        sandbox: project_sandbox(repo, source).cloned(),
     };
 
     let spec_json = serde_json::to_vec(&spec).context("failed to serialize pipeline spec")?;
@@ -565,6 +578,9 @@ fn spawn_hook_pipeline_quiet(repo: &Repository, pipeline: &PendingPipeline) -> a
 /// the `source` field on each step drives the per-step trust model
 /// (`DirectivePassthrough`).
 ///
+/// Project-source hook steps run under `[sandbox]` (see
+/// [`super::hook_sandbox`]).
+///
 /// Trust model:
 /// - User-source alias steps pass EXEC through. The body lives in the user's
 ///   own config, so a nested `wt switch --execute …` is no different from the
@@ -594,6 +610,8 @@ pub(crate) fn sourced_steps_to_foreground(
                 }
                 PipelineKind::Alias { name } => (false, false, alias_error_wrapper(name.clone())),
             };
+            let sandboxed =
+                matches!(kind, PipelineKind::Hook { .. }) && sourced.source == HookSource::Project;
             ForegroundStep {
                 step: sourced.step,
                 announce: kind.clone(),
@@ -601,11 +619,19 @@ pub(crate) fn sourced_steps_to_foreground(
                 redirect_stdout_to_stderr,
                 error_wrapper,
                 directives,
+                sandboxed,
             }
         })
         .collect()
 }
 
+/// The `[sandbox]` restrictions for a hook from `source`: project hooks get
+/// the user's settings when any are enabled, user hooks get none.
+fn project_sandbox(repo: &Repository, source: HookSource) -> Option<&SandboxConfig> {
+    let sandbox = &repo.user_config().sandbox;
+    (source == HookSource::Project && sandbox.is_enabled()).then_some(sandbox)
+}
+
 /// Run user and project hooks for a given hook type in the foreground.
 ///
 /// Used directly only by the `wt hook <type>` path, which intentionally
@@ -2,9 +2,14 @@ use anyhow::Context;
 use clap_complete::env::{
     Bash as EnvBash, EnvCompleter, Powershell as EnvPowershell, Zsh as EnvZsh,
 };
+use color_print::cformat;
+use std::fs;
 use std::io::{self, Write};
+use worktrunk::path::format_path_for_display;
 use worktrunk::shell;
-use worktrunk::styling::println;
+use worktrunk::styling::{
+    eprintln, format_bash_with_gutter, hint_message, info_message, println, success_message,
+};
 
 pub fn handle_init(shell: shell::Shell, cmd: String) -> Result<(), String> {
     let init = shell::ShellInit::with_prefix(shell, cmd);
@@ -49,24 +54,29 @@ pub fn handle_init(shell: shell::Shell, cmd: String) -> Result<(), String> {
 /// - Modify any files
 /// - Include shell integration (cd-on-switch functionality)
 pub fn handle_completions(shell: shell::Shell) -> anyhow::Result<()> {
-    let cmd_name = crate::binary_name();
-    let mut stdout = io::stdout();
+    let script = completion_script(shell, &crate::binary_name())?;
+    write!(io::stdout(), "{}", script).context("failed to write to stdout")?;
+    Ok(())
+}
+
+/// The completion script for `shell`, as `wt config shell completions` prints
+/// it and `wt completions install` writes it.
+fn completion_script(shell: shell::Shell, cmd_name: &str) -> anyhow::Result<String> {
+    let mut buf = Vec::new();
 
     match shell {
         shell::Shell::Bash => {
             EnvBash
-                .write_registration("COMPLETE", &cmd_name, &cmd_name, &cmd_name, &mut stdout)
+                .write_registration("COMPLETE", cmd_name, cmd_name, cmd_name, &mut buf)
                 .context("failed to write bash completion registration")?;
         }
         shell::Shell::Zsh => {
-            let mut buf = Vec::new();
             EnvZsh
-                .write_registration("COMPLETE", &cmd_name, &cmd_name, &cmd_name, &mut buf)
+                .write_registration("COMPLETE", cmd_name, cmd_name, cmd_name, &mut buf)
                 .context("failed to write zsh completion registration")?;
             let script = String::from_utf8(buf)
                 .context("zsh completion registration was not valid UTF-8")?;
-            let script = make_zsh_autoload_safe(&script, &cmd_name);
-            write!(stdout, "{}", script).context("failed to write to stdout")?;
+            return Ok(make_zsh_autoload_safe(&script, cmd_name));
         }
         shell::Shell::Fish => {
             // clap's fish registration calls the bare command (`COMPLETE=fish wt -- …`).
@@ -78,28 +88,137 @@ pub fn handle_completions(shell: shell::Shell) -> anyhow::Result<()> {
             // Emit the same binary-resolving registration as `wt config shell install`,
             // which goes through `type -P` (with `WORKTRUNK_BIN` override) to bypass the
             // wrapper.
-            let registration = super::configure_shell::fish_completion_content(&cmd_name);
-            write!(stdout, "{}", registration).context("failed to write to stdout")?;
+            return Ok(super::configure_shell::fish_completion_content(cmd_name));
         }
         shell::Shell::Nushell => {
             // Nushell uses template-based integration (shell wrapper + completions in one)
             // Unlike other shells, it doesn't use clap_complete
-            let init = shell::ShellInit::with_prefix(shell, cmd_name.clone());
-            let code = init
+            let init = shell::ShellInit::with_prefix(shell, cmd_name.to_string());
+            return init
                 .generate()
-                .context("failed to generate nushell integration")?;
-            write!(stdout, "{}", code).context("failed to write to stdout")?;
+                .context("failed to generate nushell integration");
         }
         shell::Shell::PowerShell => {
             EnvPowershell
-                .write_registration("COMPLETE", &cmd_name, &cmd_name, &cmd_name, &mut stdout)
+                .write_registration("COMPLETE", cmd_name, cmd_name, cmd_name, &mut buf)
                 .context("failed to write powershell completion registration")?;
         }
     }
 
+    String::from_utf8(buf).context("completion registration was not valid UTF-8")
+}
+
+/// Handle `wt completions install`: write each shell's completion script to
+/// the directory that shell loads completions from, and report what changed.
+///
+/// Without a shell, covers every installed shell that loads completions from
+/// files (bash, zsh, fish). Nushell and PowerShell completions live inside the
+/// shell integration, so they point at `wt config shell install` instead.
+pub fn handle_completions_install(
+    shell: Option<shell::Shell>,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let cmd_name = crate::binary_name();
+    let shells = match shell {
+        Some(shell @ (shell::Shell::Nushell | shell::Shell::PowerShell)) => {
+            let name = if shell == shell::Shell::Nushell {
+                "Nushell"
+            } else {
+                "PowerShell"
+            };
+            return Err(worktrunk::git::GitError::Other {
+                message: cformat!(
+                    "{name} completions are part of shell integration; run <underline>{cmd_name} config shell install {shell}</>"
+                ),
+            }
+            .into());
+        }
+        Some(shell) => vec![shell],
+        None => [shell::Shell::Bash, shell::Shell::Zsh, shell::Shell::Fish]
+            .into_iter()
+            .filter(|shell| shell.is_installed())
+            .collect(),
+    };
+    if shells.is_empty() {
+        return Err(worktrunk::git::GitError::Other {
+            message: "No shells with completion files found (bash, zsh, fish)".into(),
+        }
+        .into());
+    }
+
+    for shell in shells {
+        let path = shell
+            .completion_path(&cmd_name)
+            .context("failed to locate completion directory")?;
+        let script = completion_script(shell, &cmd_name)?;
+        let display = format_path_for_display(&path);
+
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing == script => {
+                eprintln!(
+                    "{}",
+                    info_message(cformat!(
+                        "Completions for <bold>{shell}</> already up to date @ <bold>{display}</>"
+                    ))
+                );
+            }
+            existing => {
+                let verb = match (existing.is_ok(), dry_run) {
+                    (false, false) => "Created",
+                    (true, false) => "Updated",
+                    (false, true) => "Will create",
+                    (true, true) => "Will update",
+                };
+                let message =
+                    cformat!("{verb} completions for <bold>{shell}</> @ <bold>{display}</>");
+                if dry_run {
+                    eprintln!("{}", info_message(message));
+                } else {
+                    if let Some(parent) = path.parent() {
+                        fs::create_dir_all(parent).with_context(|| {
+                            format!("failed to create {}", format_path_for_display(parent))
+                        })?;
+                    }
+                    fs::write(&path, &script)
+                        .with_context(|| format!("failed to write {display}"))?;
+                    eprintln!("{}", success_message(message));
+                }
+            }
+        }
+
+        if shell == shell::Shell::Zsh {
+            hint_if_zfunc_not_in_fpath(&cmd_name);
+        }
+    }
+
     Ok(())
 }
 
+/// zsh only autoloads `_<cmd>` from directories on `fpath`, and `~/.zfunc`
+/// isn't on it by default. `FPATH` is rarely exported, so look for the
+/// directory in `.zshrc` instead.
+fn hint_if_zfunc_not_in_fpath(cmd_name: &str) {
+    let zshrc = shell::Shell::Zsh
+        .config_paths(cmd_name)
+        .ok()
+        .and_then(|paths| paths.into_iter().next());
+    if zshrc
+        .as_deref()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .is_some_and(|content| content.contains(".zfunc"))
+    {
+        return;
+    }
+    let zshrc = zshrc.map_or_else(|| "~/.zshrc".to_string(), |p| format_path_for_display(&p));
+    eprintln!(
+        "{}",
+        hint_message(cformat!(
+            "zsh loads completions from <bold>fpath</>; add to <bold>{zshrc}</> before compinit:"
+        ))
+    );
+    eprintln!("{}", format_bash_with_gutter("fpath=(~/.zfunc $fpath)"));
+}
+
 /// Make clap's dynamic zsh registration safe to autoload from `fpath`.
 ///
 /// clap's registration ends with `compdef <func> <cmd>`, which assumes the script is
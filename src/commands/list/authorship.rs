@@ -0,0 +1,59 @@
+//! Commit authorship for `wt list --only-mine`.
+//!
+//! A branch counts as the current user's when its tip was authored by
+//! `user.email`, or when most of its commits since the merge base with the
+//! default branch were. The tip check is one batched `git log --no-walk`;
+//! only branches whose tip someone else authored need a walk of their own.
+
+use std::collections::HashSet;
+
+use anyhow::Context;
+use color_print::cformat;
+use rayon::prelude::*;
+use worktrunk::git::{GitError, Repository};
+
+/// The commits, among `shas`, whose branch belongs to the current git user.
+pub(super) fn mine(
+    repo: &Repository,
+    default_branch: Option<&str>,
+    shas: &[&str],
+) -> anyhow::Result<HashSet<String>> {
+    let Some(me) = repo
+        .config_value("user.email")?
+        .filter(|email| !email.is_empty())
+    else {
+        return Err(GitError::Other {
+            message: cformat!(
+                "<bold>--only-mine</> needs a git identity; set one with <bold>git config user.email</>"
+            ),
+        }
+        .into());
+    };
+    let tips = repo
+        .commit_author_emails_many(shas)
+        .context("Failed to read commit authors")?;
+    let is_me = |email: &str| email.eq_ignore_ascii_case(&me);
+
+    let (mine, others): (Vec<&str>, Vec<&str>) = shas
+        .iter()
+        .copied()
+        .filter(|sha| tips.contains_key(*sha))
+        .partition(|sha| tips.get(*sha).is_some_and(|email| is_me(email)));
+    let Some(base) = default_branch else {
+        return Ok(mine.into_iter().map(String::from).collect());
+    };
+    let majority: Vec<&str> = others
+        .into_par_iter()
+        .filter(|sha| {
+            let authors = repo
+                .author_emails(&format!("{base}..{sha}"))
+                .unwrap_or_default();
+            authors.iter().filter(|email| is_me(email)).count() * 2 > authors.len()
+        })
+        .collect();
+    Ok(mine
+        .into_iter()
+        .chain(majority)
+        .map(String::from)
+        .collect::<HashSet<_>>())
+}
@@ -0,0 +1,204 @@
+//! `wt list --changed` — rows that changed since the previous `--changed` run.
+//!
+//! Each run stores every row's commit and dirtiness under
+//! `<wt-state-dir>/cache/list-changes/`, then reports the rows that appeared,
+//! disappeared, or changed since the stored snapshot. Plain `wt list` leaves
+//! the snapshot alone, so a poller's baseline isn't reset by someone looking
+//! at the table. The first run has nothing to compare against: every row is
+//! new.
+
+use std::path::PathBuf;
+
+use color_print::cformat;
+use serde::{Deserialize, Serialize};
+use worktrunk::cache;
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+
+use super::json_output::JsonItem;
+
+const KIND: &str = "list-changes";
+const KEY: &str = "last.json";
+
+/// The parts of a row `--changed` compares.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct RowState {
+    branch: Option<String>,
+    path: Option<PathBuf>,
+    sha: String,
+    dirty: bool,
+}
+
+impl RowState {
+    fn from_item(item: &JsonItem) -> Self {
+        Self {
+            branch: item.branch.clone(),
+            path: item.path.clone(),
+            sha: item.commit.sha.clone(),
+            dirty: item.working_tree.as_ref().is_some_and(|wt| {
+                wt.staged || wt.modified || wt.untracked || wt.renamed || wt.deleted
+            }),
+        }
+    }
+
+    /// Rows match by branch; detached worktrees by path.
+    fn same_row(&self, other: &Self) -> bool {
+        match (&self.branch, &other.branch) {
+            (Some(a), Some(b)) => a == b,
+            (None, None) => self.path == other.path,
+            _ => false,
+        }
+    }
+}
+
+/// How a row differs from the stored snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum ChangeKind {
+    Added,
+    Changed,
+    Removed,
+}
+
+/// What changed about a row present in both snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum ChangeReason {
+    /// The branch points at a different commit
+    Commits,
+    /// The working tree gained uncommitted changes
+    Dirty,
+    /// The working tree's uncommitted changes are gone
+    Clean,
+}
+
+/// One row of `wt list --changed` output.
+#[derive(Debug, Serialize)]
+pub(super) struct RowChange<'a> {
+    change: ChangeKind,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    reasons: Vec<ChangeReason>,
+    branch: Option<String>,
+    path: Option<PathBuf>,
+    /// The current row; absent for removed rows
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item: Option<&'a JsonItem>,
+}
+
+/// The snapshot stored by the previous `--changed` run (empty on the first).
+pub(super) fn load_snapshot(repo: &Repository) -> Vec<RowState> {
+    cache::read(repo, KIND, KEY).unwrap_or_default()
+}
+
+/// Store `items` as the baseline for the next `--changed` run.
+pub(super) fn store_snapshot(repo: &Repository, items: &[JsonItem]) {
+    let rows: Vec<RowState> = items.iter().map(RowState::from_item).collect();
+    cache::write_json(&cache::cache_dir(repo, KIND).join(KEY), &rows);
+}
+
+/// Rows of `items` that are new or changed relative to `previous`, then rows
+/// of `previous` that are gone.
+pub(super) fn diff<'a>(previous: &[RowState], items: &'a [JsonItem]) -> Vec<RowChange<'a>> {
+    let current: Vec<RowState> = items.iter().map(RowState::from_item).collect();
+    let mut changes = Vec::new();
+    for (item, row) in items.iter().zip(&current) {
+        let (change, reasons) = match previous.iter().find(|old| old.same_row(row)) {
+            None => (ChangeKind::Added, Vec::new()),
+            Some(old) => {
+                let mut reasons = Vec::new();
+                if old.sha != row.sha {
+                    reasons.push(ChangeReason::Commits);
+                }
+                match (old.dirty, row.dirty) {
+                    (false, true) => reasons.push(ChangeReason::Dirty),
+                    (true, false) => reasons.push(ChangeReason::Clean),
+                    _ => {}
+                }
+                if reasons.is_empty() {
+                    continue;
+                }
+                (ChangeKind::Changed, reasons)
+            }
+        };
+        changes.push(RowChange {
+            change,
+            reasons,
+            branch: row.branch.clone(),
+            path: row.path.clone(),
+            item: Some(item),
+        });
+    }
+    for old in previous {
+        if !current.iter().any(|row| row.same_row(old)) {
+            changes.push(RowChange {
+                change: ChangeKind::Removed,
+                reasons: Vec::new(),
+                branch: old.branch.clone(),
+                path: old.path.clone(),
+                item: None,
+            });
+        }
+    }
+    changes
+}
+
+/// A change as one text line: marker, the row's statusline, and why it changed.
+pub(super) fn format_change(change: &RowChange<'_>) -> String {
+    let marker = match change.change {
+        ChangeKind::Added => cformat!("<green>+</>"),
+        ChangeKind::Changed => cformat!("<yellow>~</>"),
+        ChangeKind::Removed => cformat!("<red>-</>"),
+    };
+    let label = match (&change.branch, &change.path) {
+        (Some(branch), _) => cformat!("<bold>{branch}</>"),
+        (None, Some(path)) => format_path_for_display(path),
+        (None, None) => String::new(),
+    };
+    let body = change
+        .item
+        .and_then(|item| item.statusline.clone())
+        .unwrap_or(label);
+    if change.reasons.is_empty() {
+        return format!("{marker} {body}");
+    }
+    let reasons = change
+        .reasons
+        .iter()
+        .map(|reason| match reason {
+            ChangeReason::Commits => "new commits",
+            ChangeReason::Dirty => "now dirty",
+            ChangeReason::Clean => "now clean",
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    cformat!("{marker} {body}  <dim>({reasons})</>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(branch: &str, sha: &str, dirty: bool) -> RowState {
+        RowState {
+            branch: Some(branch.into()),
+            path: None,
+            sha: sha.into(),
+            dirty,
+        }
+    }
+
+    #[test]
+    fn test_same_row() {
+        assert!(row("a", "1", false).same_row(&row("a", "2", true)));
+        assert!(!row("a", "1", false).same_row(&row("b", "1", false)));
+        let detached = |path: &str| RowState {
+            branch: None,
+            path: Some(path.into()),
+            sha: "1".into(),
+            dirty: false,
+        };
+        assert!(detached("/x").same_row(&detached("/x")));
+        assert!(!detached("/x").same_row(&detached("/y")));
+        assert!(!detached("/x").same_row(&row("a", "1", false)));
+    }
+}
@@ -19,6 +19,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use worktrunk::git::{BranchRef, Repository, parse_owner_repo};
 use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::Glyph;
 use worktrunk::utils::epoch_now;
 
 /// A parsed branch name for CI status detection.
@@ -492,7 +493,7 @@ impl PrStatus {
     /// - All others: # (a PR reference with the number unavailable)
     pub fn indicator(&self) -> &'static str {
         if matches!(self.ci_status, CiStatus::Error) {
-            "⚠"
+            Glyph::new("⚠", "!").as_str()
         } else {
             "#"
         }
@@ -559,7 +560,8 @@ impl PrStatus {
 
     /// Detect CI status for a branch using the forge CLI (`gh`/`glab`/`tea`/`az`)
     /// First tries to find PR/MR status, then falls back to workflow/pipeline runs
-    /// Returns None if no CI found or CLI tools unavailable
+    /// Returns None if no CI found or CLI tools unavailable, and always when
+    /// offline (`--offline` / `network = false`), without reading the cache
     ///
     /// # Caching
     /// Results (including None) are cached in `.git/wt/cache/ci-status/<branch>.json`
@@ -576,6 +578,9 @@ impl PrStatus {
     /// * `branch` - The parsed branch name (may be local or remote).
     /// * `local_head` - The commit SHA to check CI status for.
     pub fn detect(repo: &Repository, branch: &CiBranchName, local_head: &str) -> Option<Self> {
+        if repo.user_config().offline() {
+            return None;
+        }
         let has_upstream = branch.has_upstream(repo);
         let repo_path = repo.current_worktree().root().ok()?;
 
@@ -29,10 +29,11 @@ use super::super::model::{
 };
 use super::CollectOptions;
 use super::tasks::{
-    AheadBehindTask, BranchDiffTask, CiStatusTask, CommittedTreesMatchTask, GitOperationTask,
-    HasFileChangesTask, IsAncestorTask, MergeTreeConflictsTask, SummaryGenerateTask, Task,
-    TaskContext, UpstreamTask, UrlStatusTask, UserMarkerTask, WorkingTreeConflictsTask,
-    WorkingTreeDiffTask, WouldMergeAddTask,
+    AheadBehindTask, BaseDriftTask, BranchDiffTask, CiStatusTask, CommittedTreesMatchTask,
+    CustomColumnsTask, DiskUsageTask, GitOperationTask, HasFileChangesTask, HookStatusTask,
+    IsAncestorTask, MergeTreeConflictsTask, StashCountTask, SummaryGenerateTask, Task, TaskContext,
+    UpstreamTask, UrlStatusTask, UserMarkerTask, WorkingTreeConflictsTask, WorkingTreeDiffTask,
+    WouldMergeAddTask,
 };
 use super::types::{TaskError, TaskKind, TaskResult};
 
@@ -57,6 +58,7 @@ const COMMIT_TASKS: &[TaskKind] = &[
     TaskKind::Upstream,
     TaskKind::WorkingTreeDiff,
     TaskKind::WorkingTreeConflicts,
+    TaskKind::BaseDrift,
 ];
 
 // ============================================================================
@@ -103,6 +105,11 @@ fn dispatch_task(kind: TaskKind, ctx: TaskContext) -> Result<TaskResult, TaskErr
         TaskKind::CiStatus => CiStatusTask::compute(ctx),
         TaskKind::UrlStatus => UrlStatusTask::compute(ctx),
         TaskKind::SummaryGenerate => SummaryGenerateTask::compute(ctx),
+        TaskKind::DiskUsage => DiskUsageTask::compute(ctx),
+        TaskKind::BaseDrift => BaseDriftTask::compute(ctx),
+        TaskKind::HookStatus => HookStatusTask::compute(ctx),
+        TaskKind::StashCount => StashCountTask::compute(ctx),
+        TaskKind::CustomColumns => CustomColumnsTask::compute(ctx),
     }
 }
 
@@ -168,9 +175,10 @@ impl ExpectedResults {
 /// [`seed_unborn_main_state`].
 ///
 /// Non-status-feeding tasks (`BranchDiff`, `CiStatus`, `UrlStatus`,
-/// `SummaryGenerate`) are rendered by their own columns with their own
-/// placeholders; `refresh_status_symbols` doesn't read them, so there is
-/// nothing to seed.
+/// `SummaryGenerate`, `DiskUsage`, `BaseDrift`, `HookStatus`, `StashCount`) are rendered by
+/// their own columns with their own placeholders; `refresh_status_symbols` doesn't read them, so there is
+/// nothing to seed. `CustomColumns` is the exception: its cells show a
+/// placeholder until loaded, so a skipped run empties them.
 pub(super) fn seed_skipped_task_defaults(item: &mut ListItem, kind: TaskKind) {
     match kind {
         // Not consumed by refresh_status_symbols — columns handle their own
@@ -178,8 +186,17 @@ pub(super) fn seed_skipped_task_defaults(item: &mut ListItem, kind: TaskKind) {
         TaskKind::BranchDiff
         | TaskKind::CiStatus
         | TaskKind::UrlStatus
-        | TaskKind::SummaryGenerate => {}
-
+        | TaskKind::SummaryGenerate
+        | TaskKind::DiskUsage
+        | TaskKind::BaseDrift
+        | TaskKind::HookStatus
+        | TaskKind::StashCount => {}
+
+        TaskKind::CustomColumns => {
+            for value in &mut item.custom_values {
+                value.get_or_insert_with(String::new);
+            }
+        }
         TaskKind::AheadBehind => {
             // Seed `is_orphan` (safe — not in JSON) but NOT `counts`
             // (leaks to `JsonMain`). Gate 3 callers that need counts-less
@@ -325,6 +342,7 @@ pub fn work_items_for_worktree(
     // symbol rather than seven `·` placeholders.
     if wt.is_prunable() {
         seed_prunable_item(item);
+        seed_skipped_task_defaults(item, TaskKind::CustomColumns);
         return vec![];
     }
 
@@ -375,11 +393,12 @@ pub fn work_items_for_worktree(
         integration_targets: options.integration_targets.clone(),
         snapshot: options.snapshot.clone(),
         include_untracked_in_working_diff: options.include_untracked_in_working_diff,
+        custom_columns: options.custom_columns.clone(),
     };
 
     let has_commits = wt.has_commits();
 
-    let mut items = Vec::with_capacity(15);
+    let mut items = Vec::with_capacity(18);
 
     for kind in [
         TaskKind::AheadBehind,
@@ -399,6 +418,11 @@ pub fn work_items_for_worktree(
         TaskKind::CiStatus,
         TaskKind::WouldMergeAdd,
         TaskKind::SummaryGenerate,
+        TaskKind::DiskUsage,
+        TaskKind::BaseDrift,
+        TaskKind::CustomColumns,
+        TaskKind::HookStatus,
+        TaskKind::StashCount,
     ] {
         let will_skip = !run.contains(&kind) || (!has_commits && COMMIT_TASKS.contains(&kind));
         if will_skip {
@@ -487,9 +511,10 @@ pub fn work_items_for_branch(
         // Branches have no working tree; the flag is only consumed by
         // WorkingTreeDiffTask, which doesn't run for branch items.
         include_untracked_in_working_diff: false,
+        custom_columns: options.custom_columns.clone(),
     };
 
-    let mut items = Vec::with_capacity(11);
+    let mut items = Vec::with_capacity(12);
 
     for kind in [
         TaskKind::AheadBehind,
@@ -502,6 +527,8 @@ pub fn work_items_for_branch(
         TaskKind::CiStatus,
         TaskKind::WouldMergeAdd,
         TaskKind::SummaryGenerate,
+        TaskKind::BaseDrift,
+        TaskKind::StashCount,
     ] {
         if !run.contains(&kind) {
             seed_skipped_task_defaults(item, kind);
@@ -532,6 +559,8 @@ pub fn work_items_for_branch(
     ] {
         seed_skipped_task_defaults(item, kind);
     }
+    // Project column commands run in a worktree; branches get empty cells.
+    seed_skipped_task_defaults(item, TaskKind::CustomColumns);
 
     items
 }
@@ -560,8 +589,12 @@ mod tests {
         let gates = crate::commands::list::columns::ColumnGates {
             show_full: true,
             summary_enabled: true,
+            show_size: false,
             has_llm_command: true,
             has_url_template: false,
+            has_network: true,
+            has_hook_status: false,
+            has_stashes: false,
         };
         let options = CollectOptions {
             url_template: Some("http://localhost/{{ branch }}".to_string()),
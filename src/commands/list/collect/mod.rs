@@ -37,6 +37,12 @@
 //! same is true for [`Repository::default_branch`] once
 //! `worktrunk.default-branch` is cached (the steady-state case).
 //!
+//! `--only-mine` adds forks before #6: one batched `git log --no-walk` for
+//! tip authors, then a parallel `git log <default>..<tip>` per row whose tip
+//! someone else authored (see `list/authorship.rs`). Rows it drops never
+//! reach the skeleton, so this can't be deferred; runs without the flag pay
+//! nothing.
+//!
 //! Things that fire **once per repo, ever**:
 //!
 //! - [`Repository::default_branch`] falling through to
@@ -402,13 +408,15 @@ impl TableRenderPlan {
 fn print_first_buffered_line(header: &str) -> anyhow::Result<()> {
     use std::io::Write as _;
 
-    let mut stdout = std::io::stdout();
-    writeln!(stdout, "{header}")?;
+    let mut stdout = worktrunk::styling::stdout();
+    writeln!(stdout, "{}", worktrunk::styling::themed(header))?;
     stdout.flush()?;
     Ok(())
 }
 
 fn print_buffered_table(header: &str, rows: &[String], summary: &str) {
+    use worktrunk::styling::println;
+
     println!("{header}");
     for row in rows {
         println!("{row}");
@@ -474,6 +482,10 @@ pub struct CollectOptions {
     /// `HEAD±`. Set by `wt list --full` and `wt statusline`; consumed
     /// in `tasks.rs` where the cost/cutover rationale lives.
     pub include_untracked_in_working_diff: bool,
+
+    /// Resolved custom columns. Only consulted when `tasks` includes
+    /// `CustomColumns`.
+    pub custom_columns: std::sync::Arc<[super::custom_columns::ResolvedCustomColumn]>,
 }
 
 impl CollectOptions {
@@ -503,6 +515,7 @@ impl CollectOptions {
             integration_targets: None,
             snapshot: None,
             include_untracked_in_working_diff: false,
+            custom_columns: Vec::new().into(),
         }
     }
 }
@@ -583,7 +596,7 @@ pub trait PickerProgressHandler: Send + Sync {
     fn provide_layout(&self, _layout: &super::layout::LayoutConfig) {}
 }
 
-/// Controls how show flags (branches/remotes/full) are determined in [`collect`].
+/// Controls how show flags (branches/remotes/full/size) are determined in [`collect`].
 pub enum ShowConfig {
     /// Flags already resolved by the caller (used by the picker).
     ///
@@ -613,6 +626,9 @@ pub enum ShowConfig {
         cli_branches: bool,
         cli_remotes: bool,
         cli_full: bool,
+        cli_size: bool,
+        cli_tree: bool,
+        cli_only_mine: bool,
     },
 }
 
@@ -852,6 +868,9 @@ pub fn collect(
         show_branches,
         show_remotes,
         show_full,
+        show_size,
+        show_tree,
+        only_mine,
         command_timeout,
         collect_deadline,
         list_width,
@@ -874,6 +893,14 @@ pub fn collect(
             // opts out of the untracked-inclusive working diff — the last tuple
             // field — so the two `show_full`-shaped values aren't the same bucket.
             true,
+            // Disk usage walks every file of every worktree — too slow for
+            // the picker's preview refresh; it shows only when listed.
+            false,
+            // The picker's rows are fuzzy-filtered and reordered, so blanked
+            // prefixes would lose the row they refer to.
+            false,
+            // `--only-mine` is a `wt list` flag; the picker lists every row.
+            false,
             command_timeout,
             collect_deadline,
             list_width,
@@ -884,6 +911,9 @@ pub fn collect(
             cli_branches,
             cli_remotes,
             cli_full,
+            cli_size,
+            cli_tree,
+            cli_only_mine,
         } => {
             let config = repo.config();
             let show_branches = cli_branches || config.list.branches();
@@ -901,6 +931,9 @@ pub fn collect(
                 show_branches,
                 show_remotes,
                 show_full,
+                cli_size,
+                cli_tree,
+                cli_only_mine,
                 command_timeout,
                 collect_deadline,
                 None,
@@ -1043,6 +1076,18 @@ pub fn collect(
         .chain(remote_branches.iter().map(|(_, sha)| sha.as_str()))
         .filter(|sha| *sha != worktrunk::git::NULL_OID)
         .collect();
+    // `--only-mine`: decide authorship up front so dropped rows never reach
+    // the skeleton. The main worktree stays as the reference row.
+    let mine: Option<HashSet<String>> = if only_mine {
+        Some(super::authorship::mine(
+            repo,
+            default_branch.as_deref(),
+            &all_shas,
+        )?)
+    } else {
+        None
+    };
+    let is_mine = |sha: &str| mine.as_ref().is_none_or(|mine| mine.contains(sha));
     let commit_details_map = repo.commit_details_many(&all_shas).unwrap_or_else(|err| {
         // Surface git's actual stderr (when available via the typed leaf)
         // rather than our `CommandError` summary.
@@ -1054,23 +1099,31 @@ pub fn collect(
     });
 
     // Sort worktrees: current first, main second, then by timestamp descending
-    let sorted_worktrees = sort_worktrees_with_cache(
+    let mut sorted_worktrees = sort_worktrees_with_cache(
         worktrees,
         &main_worktree,
         current_worktree_path.as_ref(),
         &commit_details_map,
     );
+    sorted_worktrees.retain(|wt| wt.path == main_worktree.path || is_mine(&wt.head));
+    // `--tree` pulls siblings in the same directory together. Done before items
+    // are built so item indices and task indices stay aligned.
+    if show_tree {
+        sorted_worktrees = super::path_tree::group_by_parent(sorted_worktrees);
+    }
 
     // Sort branches by timestamp (most recent first)
-    let branches_without_worktrees = sort_by_timestamp_desc_with_cache(
+    let mut branches_without_worktrees = sort_by_timestamp_desc_with_cache(
         branches_without_worktrees,
         &commit_details_map,
         |(_, sha)| sha.as_str(),
     );
-    let remote_branches =
+    let mut remote_branches =
         sort_by_timestamp_desc_with_cache(remote_branches, &commit_details_map, |(_, sha)| {
             sha.as_str()
         });
+    branches_without_worktrees.retain(|(_, sha)| is_mine(sha));
+    remote_branches.retain(|(_, sha)| is_mine(sha));
 
     // Pre-canonicalize main_worktree.path for is_main comparison
     // (paths from git worktree list may differ based on symlinks or working directory)
@@ -1117,8 +1170,12 @@ pub fn collect(
                 is_patch_id_match: None,
                 is_ancestor: None,
                 is_orphan: None,
+                merge_base: None,
                 upstream: None,
                 pr_status: None,
+                base_drift: None,
+                hook_status: None,
+                stash_count: None,
                 url: None,
                 url_active: None,
                 summary: None,
@@ -1132,6 +1189,19 @@ pub fn collect(
         })
         .collect();
 
+    if show_tree {
+        let shortened: Vec<String> = sorted_worktrees
+            .iter()
+            .map(|wt| crate::display::shorten_path(&wt.path, &main_worktree.path))
+            .collect();
+        let labels = super::path_tree::tree_labels(&shortened);
+        for (item, label) in all_items.iter_mut().zip(labels) {
+            if let ItemKind::Worktree(data) = &mut item.kind {
+                data.tree_path = Some(label);
+            }
+        }
+    }
+
     // Initialize branch items (local and remote) - URLs expanded post-skeleton
     let branch_start_idx = all_items.len();
     all_items.extend(
@@ -1162,15 +1232,42 @@ pub fn collect(
     // shares this path but runs collect on a background thread while skim
     // owns the terminal, so it can't surface an abort — it stashes a warning
     // (drained after the picker closes) and renders without custom columns.
-    let custom_columns =
-        match super::custom_columns::resolve_custom_columns(&config.list.custom_columns, repo) {
-            Ok(columns) => columns,
-            Err(e) if progressive_handler.is_some() => {
-                emit_warning(warning_message(format!("Custom columns disabled: {e}")).to_string());
-                Vec::new()
-            }
-            Err(e) => return Err(e),
-        };
+    //
+    // Project `[list.columns]` commands join the same list, but only once
+    // approved; their cells fill in post-skeleton from the `CustomColumns`
+    // task.
+    let (command_columns, unapproved) = super::custom_columns::approved_command_columns(repo);
+    if !unapproved.is_empty() {
+        let names = unapproved
+            .iter()
+            .map(|name| cformat!("<bold>{name}</>"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        emit_warning(
+            warning_message(cformat!(
+                "Skipping unapproved project list columns: {names}"
+            ))
+            .to_string(),
+        );
+        emit_warning(
+            hint_message(cformat!(
+                "To approve them, run <underline>wt config approvals add</>"
+            ))
+            .to_string(),
+        );
+    }
+    let custom_columns = match super::custom_columns::resolve_custom_columns(
+        &config.list.custom_columns,
+        &command_columns,
+        repo,
+    ) {
+        Ok(columns) => columns,
+        Err(e) if progressive_handler.is_some() => {
+            emit_warning(warning_message(format!("Custom columns disabled: {e}")).to_string());
+            Vec::new()
+        }
+        Err(e) => return Err(e),
+    };
     if !custom_columns.is_empty() {
         let all_vars = repo.all_vars_from_snapshot()?;
         let all_branch_config = repo.all_branch_config_from_snapshot()?;
@@ -1209,8 +1306,9 @@ pub fn collect(
     //   `all_columns` (source `Default`) when nothing narrows it. The `Listed`
     //   source lets an explicit selection override the preset gates (`--full`,
     //   `[list] summary`): listing `ci` runs its task without `--full`. The
-    //   data-source gates (`[commit.generation]`, a url template) still drop a
-    //   column whose data can't be produced, however it was requested.
+    //   data-source gates (`[commit.generation]`, a url template, the network)
+    //   still drop a column whose data can't be produced, however it was
+    //   requested.
     // - picker → `all_columns` for its preview tabs, unioned with the selection's
     //   forced-on columns so its table matches `wt list`'s. The union matters for
     //   a listed `summary` that `Default` alone wouldn't plan (LLM command set,
@@ -1225,8 +1323,12 @@ pub fn collect(
     let gates = super::columns::ColumnGates {
         show_full,
         summary_enabled: config.list.summary(),
+        show_size,
         has_llm_command: llm_command.is_some(),
         has_url_template: url_template.is_some(),
+        has_network: !repo.user_config().offline(),
+        has_hook_status: crate::commands::hook_status::any_recorded(repo),
+        has_stashes: !repo.stashes().is_empty(),
     };
     let listed_plan = || {
         super::columns::required_tasks_for_render(
@@ -1244,7 +1346,7 @@ pub fn collect(
     };
     let prune_to_selection =
         render_table && progressive_handler.is_none() && !selected_columns.is_empty();
-    let tasks = if prune_to_selection {
+    let mut tasks = if prune_to_selection {
         listed_plan()
     } else if progressive_handler.is_some() {
         let mut tasks = full_plan();
@@ -1253,6 +1355,17 @@ pub fn collect(
     } else {
         full_plan()
     };
+    // Custom columns aren't in the static column→task table: their count
+    // and sources come from config. Plan the command task when any
+    // command-backed column will render.
+    let runs_column_commands = custom_columns.iter().enumerate().any(|(i, column)| {
+        column.is_command()
+            && (!prune_to_selection
+                || selected_columns.contains(&super::columns::ColumnKind::Custom(i as u8)))
+    });
+    if runs_column_commands {
+        tasks.insert(TaskKind::CustomColumns);
+    }
 
     // The picker primes its CI cells from the local cache so the column paints
     // instantly, then the live `CiStatus` task (which the picker keeps — see
@@ -1289,6 +1402,8 @@ pub fn collect(
         super::layout::ColumnSelection {
             custom: &custom_columns,
             selected: (!selected_columns.is_empty()).then_some(selected_columns.as_slice()),
+            branch_width: config.list.branch_width,
+            path_width: config.list.path_width,
         },
     );
 
@@ -1308,6 +1423,7 @@ pub fn collect(
         integration_targets: None,
         snapshot: None,
         include_untracked_in_working_diff,
+        custom_columns: custom_columns.clone().into(),
     };
 
     // Track expected results per item - populated as spawns are queued
@@ -1344,7 +1460,7 @@ pub fn collect(
     let mut placeholder: &'static str = if progressive_active {
         super::render::PLACEHOLDER_BLANK
     } else {
-        super::render::PLACEHOLDER
+        super::render::PLACEHOLDER.as_str()
     };
 
     // Create progressive table if showing progress.
@@ -1847,7 +1963,7 @@ pub fn collect(
                     }
                 }
                 results::DrainEvent::Reveal { items } => {
-                    placeholder = super::render::PLACEHOLDER;
+                    placeholder = super::render::PLACEHOLDER.as_str();
                     let updates = render_reveal(&has_data, items, &layout, placeholder);
 
                     if let Some(state_cell) = progressive_state.as_ref() {
@@ -1911,7 +2027,7 @@ pub fn collect(
     // once the 200ms deadline has passed; if every result arrives before
     // then, the closure left `placeholder` on `PLACEHOLDER_BLANK`, and the
     // final table / finalize / timeout paths must not inherit that.
-    placeholder = super::render::PLACEHOLDER;
+    placeholder = super::render::PLACEHOLDER.as_str();
 
     // Handle timeout if it occurred. Budget-based deadlines
     // (collect_deadline) are intentional truncation — don't warn. Only
@@ -1931,6 +2047,10 @@ pub fn collect(
 
     // Count errors for summary
     let error_count = errors.len();
+    // One `git count-objects` for the footer; a failure just drops the line.
+    let object_store = (render_table && options.tasks.contains(&TaskKind::DiskUsage))
+        .then(|| repo.object_store().ok())
+        .flatten();
     let timed_out_count = errors.iter().filter(|e| e.is_timeout()).count();
 
     let table_render = render_table.then(|| TableRenderPlan {
@@ -1944,6 +2064,7 @@ pub fn collect(
             &all_items,
             show_branches || show_remotes,
             layout.hidden_column_count,
+            object_store.as_ref(),
             error_count,
             timed_out_count,
         ),
@@ -2113,8 +2234,12 @@ pub fn build_worktree_item(
         is_patch_id_match: None,
         is_ancestor: None,
         is_orphan: None,
+        merge_base: None,
         upstream: None,
         pr_status: None,
+        base_drift: None,
+        hook_status: None,
+        stash_count: None,
         url: None,
         url_active: None,
         summary: None,
@@ -2496,9 +2621,10 @@ mod tests {
             super::super::layout::ColumnSelection {
                 custom: &[],
                 selected: None,
+                ..Default::default()
             },
         );
-        let placeholder = super::super::render::PLACEHOLDER;
+        let placeholder = super::super::render::PLACEHOLDER.as_str();
 
         // Row 0 has data → format_list_item_line; row 1 doesn't → skeleton.
         let has_data = vec![true, false];
@@ -267,10 +267,14 @@ pub(super) fn drain_results_with_timings(
 
         match result {
             TaskResult::AheadBehind {
-                counts, is_orphan, ..
+                counts,
+                is_orphan,
+                merge_base,
+                ..
             } => {
                 item.counts = Some(counts);
                 item.is_orphan = Some(is_orphan);
+                item.merge_base = merge_base;
             }
             TaskResult::CommittedTreesMatch {
                 committed_trees_match,
@@ -300,13 +304,13 @@ pub(super) fn drain_results_with_timings(
             TaskResult::WorkingTreeDiff {
                 working_tree_diff,
                 working_tree_status,
-                has_conflicts,
+                conflicted_files,
                 ..
             } => {
                 if let ItemKind::Worktree(data) = &mut item.kind {
                     data.working_tree_diff = Some(working_tree_diff);
                     data.working_tree_status = Some(working_tree_status);
-                    data.has_conflicts = Some(has_conflicts);
+                    data.conflicted_files = Some(conflicted_files);
                 } else {
                     debug_assert!(false, "WorkingTreeDiff result for non-worktree item");
                 }
@@ -359,6 +363,29 @@ pub(super) fn drain_results_with_timings(
             TaskResult::SummaryGenerate { summary, .. } => {
                 item.summary = Some(summary);
             }
+            TaskResult::DiskUsage { bytes, .. } => {
+                if let ItemKind::Worktree(data) = &mut item.kind {
+                    data.disk_usage = Some(bytes);
+                } else {
+                    debug_assert!(false, "DiskUsage result for non-worktree item");
+                }
+            }
+            TaskResult::BaseDrift { base_drift, .. } => {
+                item.base_drift = Some(base_drift);
+            }
+            TaskResult::HookStatus { records, .. } => {
+                item.hook_status = Some(records);
+            }
+            TaskResult::StashCount { count, .. } => {
+                item.stash_count = Some(count);
+            }
+            TaskResult::CustomColumns { values, .. } => {
+                for (i, value) in values {
+                    if let Some(slot) = item.custom_values.get_mut(i) {
+                        *slot = Some(value);
+                    }
+                }
+            }
         }
 
         // Refresh status symbols. Each gate resolves independently once
@@ -407,7 +434,7 @@ mod tests {
         if let ItemKind::Worktree(data) = &mut item.kind {
             data.working_tree_diff = Some(LineDiff::default());
             data.working_tree_status = Some(WorkingTreeStatus::default());
-            data.has_conflicts = Some(false);
+            data.conflicted_files = Some(0);
         }
         item.counts = Some(AheadBehind {
             ahead: 3,
@@ -582,6 +609,7 @@ mod tests {
                 behind: 5,
             },
             is_orphan: false,
+            merge_base: None,
         }))
         .unwrap();
         drop(tx);
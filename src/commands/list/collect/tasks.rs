@@ -14,10 +14,13 @@ use worktrunk::git::{
 };
 
 use super::super::ci_status::{CiBranchName, PrStatus};
+use super::super::custom_columns::{CustomColumnSource, ResolvedCustomColumn, run_column_command};
 use super::super::model::{
     ActiveGitOperation, AheadBehind, BranchDiffTotals, UpstreamStatus, WorkingTreeStatus,
 };
 use super::types::{ErrorCause, TaskError, TaskKind, TaskResult};
+use crate::commands::merge_journal::MergeJournal;
+use crate::commands::pin;
 
 // ============================================================================
 // Task Context
@@ -47,8 +50,9 @@ use super::types::{ErrorCause, TaskError, TaskKind, TaskResult};
 ///   plus, when local and upstream diverge, both sides. Integration status
 ///   checks whether the work has landed in the mainline on either.
 ///
-/// All three resolve the upstream from the default branch's configured
-/// `@{upstream}` alone ([`RefSnapshot::upstream_of`]); there is no
+/// All three resolve the upstream via [`Repository::target_upstream`]: the
+/// configured canonical remote's copy of the default branch
+/// (`[remote] upstream`) when set, else its `@{upstream}`. There is no
 /// remote-name heuristic. The superset selection itself lives on
 /// [`Repository::integration_targets`].
 #[derive(Clone)]
@@ -91,6 +95,9 @@ pub struct TaskContext {
     /// Whether `WorkingTreeDiffTask` should include untracked files in
     /// its `HEAD±` line counts. See `CollectOptions` for rationale.
     pub include_untracked_in_working_diff: bool,
+    /// Resolved `wt list` custom columns; `CustomColumnsTask` runs the
+    /// command-backed ones.
+    pub custom_columns: Arc<[ResolvedCustomColumn]>,
 }
 
 impl TaskContext {
@@ -239,6 +246,7 @@ impl Task for AheadBehindTask {
                 item_idx: ctx.item_idx,
                 counts: AheadBehind::default(),
                 is_orphan: false,
+                merge_base: None,
             });
         };
         let repo = &ctx.repo;
@@ -253,18 +261,17 @@ impl Task for AheadBehindTask {
             .map_err(|e| ctx.error(Self::KIND, &e))?;
 
         // Check for orphan branch (no common ancestor with default branch).
-        let is_orphan = repo
+        let Some(merge_base) = repo
             .merge_base_by_sha(&base_sha, &head_sha)
             .map_err(|e| ctx.error(Self::KIND, &e))?
-            .is_none();
-
-        if is_orphan {
+        else {
             return Ok(TaskResult::AheadBehind {
                 item_idx: ctx.item_idx,
                 counts: AheadBehind::default(),
                 is_orphan: true,
+                merge_base: None,
             });
-        }
+        };
 
         // Snapshot's ahead/behind batch is keyed by ref names — try the
         // batched answer first, fall back to a per-pair query keyed by SHA.
@@ -286,6 +293,7 @@ impl Task for AheadBehindTask {
                 behind: counts.1,
             },
             is_orphan: false,
+            merge_base: Some(merge_base),
         })
     }
 }
@@ -579,7 +587,7 @@ impl Task for WorkingTreeDiffTask {
             .status_porcelain_cached()
             .map_err(|e| ctx.error(Self::KIND, &e))?;
 
-        let (working_tree_status, is_dirty, has_conflicts) =
+        let (working_tree_status, is_dirty, conflicted_files) =
             parse_working_tree_status(&status_output);
 
         // The default `wt list` path keeps `HEAD±` as a fast `git diff
@@ -602,7 +610,7 @@ impl Task for WorkingTreeDiffTask {
             item_idx: ctx.item_idx,
             working_tree_diff,
             working_tree_status,
-            has_conflicts,
+            conflicted_files,
         })
     }
 }
@@ -777,7 +785,8 @@ impl Task for WorkingTreeConflictsTask {
     }
 }
 
-/// Task 7 (worktree only): Git operation state detection (rebase/merge)
+/// Task 7 (worktree only): Git operation state detection (rebase/merge, plus
+/// an interrupted `wt merge`)
 pub struct GitOperationTask;
 
 impl Task for GitOperationTask {
@@ -789,7 +798,15 @@ impl Task for GitOperationTask {
             .branch_ref
             .working_tree(&ctx.repo)
             .ok_or_else(|| ctx.error(Self::KIND, &anyhow::anyhow!("requires a worktree")))?;
-        let git_operation = detect_active_git_operation(&wt);
+        let mut git_operation = detect_active_git_operation(&wt);
+        // A git-level operation outranks wt's own journal: a conflicted
+        // rebase inside an interrupted `wt merge` shows as `⤴`.
+        if git_operation == ActiveGitOperation::None
+            && let Some(branch) = ctx.branch_ref.short_name()
+            && MergeJournal::load(&ctx.repo, branch).is_some()
+        {
+            git_operation = ActiveGitOperation::InterruptedMerge;
+        }
         Ok(TaskResult::GitOperation {
             item_idx: ctx.item_idx,
             git_operation,
@@ -798,6 +815,8 @@ impl Task for GitOperationTask {
 }
 
 /// Task 8 (worktree only): User-defined status from git config
+///
+/// A branch's own marker wins; otherwise pinned branches show 📌.
 pub struct UserMarkerTask;
 
 impl Task for UserMarkerTask {
@@ -805,7 +824,12 @@ impl Task for UserMarkerTask {
 
     fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
         let repo = &ctx.repo;
-        let user_marker = repo.user_marker(ctx.branch_ref.short_name());
+        let branch = ctx.branch_ref.short_name();
+        let user_marker = repo.user_marker(branch).or_else(|| {
+            branch
+                .is_some_and(|b| pin::is_pinned(repo, b))
+                .then(|| pin::PIN_MARKER.to_string())
+        });
         Ok(TaskResult::UserMarker {
             item_idx: ctx.item_idx,
             user_marker,
@@ -836,13 +860,23 @@ impl Task for UpstreamTask {
             .upstream()
             .map_err(|e| ctx.error(Self::KIND, &e))?;
         let Some(upstream_branch) = upstream_branch else {
+            // An upstream deleted from the remote isn't counted against, but
+            // is worth flagging: the branch was likely merged and cleaned up.
+            let gone = repo
+                .branch(branch)
+                .gone_upstream()
+                .map_err(|e| ctx.error(Self::KIND, &e))?;
             return Ok(TaskResult::Upstream {
                 item_idx: ctx.item_idx,
-                upstream: UpstreamStatus::default(),
+                upstream: UpstreamStatus {
+                    gone,
+                    ..Default::default()
+                },
             });
         };
 
         let remote = upstream_branch.split_once('/').map(|(r, _)| r.to_string());
+        let tracking = Some(upstream_branch.clone());
         // Resolve upstream ref to a SHA via the snapshot, then compute
         // ahead/behind by SHA. Branch SHA is taken from `branch_ref.commit_sha`
         // — for the upstream comparison we want the branch's actual tip,
@@ -869,8 +903,10 @@ impl Task for UpstreamTask {
             item_idx: ctx.item_idx,
             upstream: UpstreamStatus {
                 remote,
+                tracking,
                 ahead,
                 behind,
+                gone: None,
             },
         })
     }
@@ -979,6 +1015,129 @@ impl Task for SummaryGenerateTask {
     }
 }
 
+/// Task 15 (worktree only): Disk usage of the checked-out files (`--size`)
+pub struct DiskUsageTask;
+
+impl Task for DiskUsageTask {
+    const KIND: TaskKind = TaskKind::DiskUsage;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        // This task is only spawned for worktree items, so worktree path is always present.
+        let wt = ctx
+            .branch_ref
+            .working_tree(&ctx.repo)
+            .ok_or_else(|| ctx.error(Self::KIND, &anyhow::anyhow!("requires a worktree")))?;
+        let bytes = wt.disk_usage().map_err(|e| ctx.error(Self::KIND, &e))?;
+        Ok(TaskResult::DiskUsage {
+            item_idx: ctx.item_idx,
+            bytes,
+        })
+    }
+}
+
+/// Task 16: Whether the branch's recorded base was re-cut or deleted (`--full`)
+///
+/// Remote branches have no creation record or reflog of their own, so they
+/// never report drift.
+pub struct BaseDriftTask;
+
+impl Task for BaseDriftTask {
+    const KIND: TaskKind = TaskKind::BaseDrift;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        let base_drift = match ctx.branch_ref.short_name() {
+            Some(branch) if !ctx.branch_ref.is_remote() => ctx
+                .repo
+                .base_drift(branch, &ctx.branch_ref.commit_sha)
+                .map_err(|e| ctx.error(Self::KIND, &e))?,
+            _ => None,
+        };
+        Ok(TaskResult::BaseDrift {
+            item_idx: ctx.item_idx,
+            base_drift,
+        })
+    }
+}
+
+/// Task 17 (worktree only): Project `[list.columns]` commands
+///
+/// Commands run one after another within a worktree; worktrees run in
+/// parallel like every other task. A failed or timed-out command leaves its
+/// cell empty rather than failing the task.
+pub struct CustomColumnsTask;
+
+impl Task for CustomColumnsTask {
+    const KIND: TaskKind = TaskKind::CustomColumns;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        // This task is only spawned for worktree items, so worktree path is always present.
+        let path = ctx
+            .branch_ref
+            .worktree_path
+            .as_deref()
+            .ok_or_else(|| ctx.error(Self::KIND, &anyhow::anyhow!("requires a worktree")))?;
+        let values = ctx
+            .custom_columns
+            .iter()
+            .enumerate()
+            .filter_map(|(i, column)| match &column.source {
+                CustomColumnSource::Command { command, timeout } => Some((
+                    i,
+                    run_column_command(&column.name, command, *timeout, path, &ctx.repo),
+                )),
+                CustomColumnSource::Template(_) => None,
+            })
+            .collect();
+        Ok(TaskResult::CustomColumns {
+            item_idx: ctx.item_idx,
+            values,
+        })
+    }
+}
+
+/// Task 18 (worktree only): Last recorded background hook runs (`--full`)
+///
+/// Detached worktrees record under `HEAD`, shared by every detached worktree,
+/// so they report nothing rather than another worktree's runs.
+pub struct HookStatusTask;
+
+impl Task for HookStatusTask {
+    const KIND: TaskKind = TaskKind::HookStatus;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        let records = ctx
+            .branch_ref
+            .short_name()
+            .map(|branch| crate::commands::hook_status::load_branch(&ctx.repo, branch))
+            .unwrap_or_default();
+        Ok(TaskResult::HookStatus {
+            item_idx: ctx.item_idx,
+            records,
+        })
+    }
+}
+
+/// Task 19: Stash entries made on the branch (`--full`)
+///
+/// Counted from the repo-wide stash list, read once and shared by every row.
+/// Remote branches and detached worktrees have no stashes of their own.
+pub struct StashCountTask;
+
+impl Task for StashCountTask {
+    const KIND: TaskKind = TaskKind::StashCount;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        let count = match ctx.branch_ref.short_name() {
+            Some(branch) if !ctx.branch_ref.is_remote() => ctx.repo.branch_stashes(branch).len(),
+            _ => 0,
+        };
+        Ok(TaskResult::StashCount {
+            item_idx: ctx.item_idx,
+            count,
+        })
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -1017,14 +1176,14 @@ pub(crate) fn parse_port_from_url(url: &str) -> Option<u16> {
 }
 
 /// Parse git status output to extract working tree status and conflict state.
-/// Returns (WorkingTreeStatus, is_dirty, has_conflicts).
-pub(super) fn parse_working_tree_status(status_output: &str) -> (WorkingTreeStatus, bool, bool) {
+/// Returns (WorkingTreeStatus, is_dirty, number of conflicted paths).
+pub(super) fn parse_working_tree_status(status_output: &str) -> (WorkingTreeStatus, bool, usize) {
     let mut has_untracked = false;
     let mut has_modified = false;
     let mut has_staged = false;
     let mut has_renamed = false;
     let mut has_deleted = false;
-    let mut has_conflicts = false;
+    let mut conflicted_files = 0;
 
     for line in status_output.lines() {
         if line.len() < 2 {
@@ -1065,7 +1224,7 @@ pub(super) fn parse_working_tree_status(status_output: &str) -> (WorkingTreeStat
             ('U', _) | (_, 'U') | ('A', 'A') | ('D', 'D')
         );
         if is_unmerged_pair {
-            has_conflicts = true;
+            conflicted_files += 1;
         }
     }
 
@@ -1079,7 +1238,7 @@ pub(super) fn parse_working_tree_status(status_output: &str) -> (WorkingTreeStat
 
     let is_dirty = working_tree_status.is_dirty();
 
-    (working_tree_status, is_dirty, has_conflicts)
+    (working_tree_status, is_dirty, conflicted_files)
 }
 
 /// Check if `git status --porcelain` output contains unmerged entries.
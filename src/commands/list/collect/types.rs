@@ -5,12 +5,13 @@
 //! - `TaskError` and `ErrorCause` - error handling for failed tasks
 //! - `DrainOutcome` and `MissingResult` - timeout diagnostic info
 
-use worktrunk::git::LineDiff;
+use worktrunk::git::{BaseDrift, BranchBase, LineDiff};
 
 use super::super::ci_status::PrStatus;
 use super::super::model::{
     ActiveGitOperation, AheadBehind, BranchDiffTotals, UpstreamStatus, WorkingTreeStatus,
 };
+use crate::commands::hook_status::PipelineRecord;
 
 /// Task results sent as each git operation completes.
 /// These enable progressive rendering - update UI as data arrives.
@@ -36,6 +37,9 @@ pub(crate) enum TaskResult {
         counts: AheadBehind,
         /// True if this is an orphan branch (no common ancestor with default branch)
         is_orphan: bool,
+        /// Merge-base with the comparison base (None for orphans, or when no
+        /// base resolves)
+        merge_base: Option<String>,
     },
     /// Whether HEAD's tree SHA matches integration target's tree SHA (committed content identical)
     CommittedTreesMatch {
@@ -66,7 +70,8 @@ pub(crate) enum TaskResult {
         working_tree_diff: LineDiff,
         /// Working tree change flags
         working_tree_status: WorkingTreeStatus,
-        has_conflicts: bool,
+        /// Paths with unresolved merge conflicts (unmerged porcelain codes)
+        conflicted_files: usize,
     },
     /// Potential merge conflicts with default branch (merge-tree simulation on committed HEAD)
     MergeTreeConflicts {
@@ -118,6 +123,25 @@ pub(crate) enum TaskResult {
         item_idx: usize,
         summary: Option<String>,
     },
+    /// Bytes on disk for the worktree's checked-out files (`--size`)
+    DiskUsage { item_idx: usize, bytes: u64 },
+    /// Whether the branch's recorded base was re-cut or deleted (`--full`)
+    BaseDrift {
+        item_idx: usize,
+        base_drift: Option<(BranchBase, BaseDrift)>,
+    },
+    /// Output of project `[list.columns]` commands, as (column index, cell)
+    CustomColumns {
+        item_idx: usize,
+        values: Vec<(usize, String)>,
+    },
+    /// Last recorded background hook runs for the branch (`--full`)
+    HookStatus {
+        item_idx: usize,
+        records: Vec<PipelineRecord>,
+    },
+    /// Number of stash entries made on the branch (`--full`)
+    StashCount { item_idx: usize, count: usize },
 }
 
 impl TaskResult {
@@ -138,7 +162,12 @@ impl TaskResult {
             | TaskResult::Upstream { item_idx, .. }
             | TaskResult::CiStatus { item_idx, .. }
             | TaskResult::UrlStatus { item_idx, .. }
-            | TaskResult::SummaryGenerate { item_idx, .. } => *item_idx,
+            | TaskResult::SummaryGenerate { item_idx, .. }
+            | TaskResult::DiskUsage { item_idx, .. }
+            | TaskResult::BaseDrift { item_idx, .. }
+            | TaskResult::CustomColumns { item_idx, .. }
+            | TaskResult::HookStatus { item_idx, .. }
+            | TaskResult::StashCount { item_idx, .. } => *item_idx,
         }
     }
 }
@@ -1,4 +1,5 @@
 use super::collect::TaskKind;
+use worktrunk::styling::Glyph;
 
 /// Logical identifier for each column rendered by `wt list`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -12,8 +13,12 @@ pub enum ColumnKind {
     Summary,
     Upstream,
     CiStatus,
+    Base,  // Recorded base that was re-cut or deleted since the branch was cut (`--full`)
+    Hooks, // Last background hook run results (`--full`)
+    Stash, // Stash entries made on the branch (`--full`)
     Path,
-    Url, // Dev server URL from project config template
+    Size, // Disk usage of the worktree's checked-out files (`--size`)
+    Url,  // Dev server URL from project config template
     Commit,
     Time,
     Message,
@@ -25,19 +30,23 @@ pub enum ColumnKind {
 }
 
 impl ColumnKind {
-    pub const fn header(self) -> &'static str {
+    pub fn header(self) -> &'static str {
         match self {
             ColumnKind::Gutter => "",
             ColumnKind::Branch => "Branch",
             ColumnKind::Status => "Status",
-            ColumnKind::WorkingDiff => "HEAD±",
-            ColumnKind::AheadBehind => "main↕",
-            ColumnKind::BranchDiff => "main…±",
+            ColumnKind::WorkingDiff => Glyph::new("HEAD±", "HEAD+-").as_str(),
+            ColumnKind::AheadBehind => Glyph::new("main↕", "main<>").as_str(),
+            ColumnKind::BranchDiff => Glyph::new("main…±", "main+-").as_str(),
             ColumnKind::Path => "Path",
-            ColumnKind::Upstream => "Remote⇅",
+            ColumnKind::Size => "Size",
+            ColumnKind::Upstream => Glyph::new("Remote⇅", "Remote^v").as_str(),
             ColumnKind::Url => "URL",
             ColumnKind::Time => "Age",
             ColumnKind::CiStatus => "CI",
+            ColumnKind::Base => "Base",
+            ColumnKind::Hooks => "Hooks",
+            ColumnKind::Stash => "Stash",
             ColumnKind::Commit => "Commit",
             ColumnKind::Summary => "Summary",
             ColumnKind::Message => "Message",
@@ -78,7 +87,11 @@ impl ColumnKind {
             ColumnKind::Summary => "summary",
             ColumnKind::Upstream => "upstream",
             ColumnKind::CiStatus => "ci",
+            ColumnKind::Base => "base",
+            ColumnKind::Hooks => "hooks",
+            ColumnKind::Stash => "stash",
             ColumnKind::Path => "path",
+            ColumnKind::Size => "size",
             ColumnKind::Url => "url",
             ColumnKind::Commit => "commit",
             ColumnKind::Time => "age",
@@ -117,7 +130,9 @@ impl ColumnKind {
     /// `Status` aggregates almost every status-feeding task (the five
     /// `refresh_status_symbols` gates); identity columns (Branch, Path, Commit,
     /// Age, Message), the always-on Gutter, and custom columns are derived
-    /// without any task.
+    /// without any task. (Command-backed custom columns do need
+    /// `CustomColumns`, which `collect` plans from config — their count isn't
+    /// static.)
     ///
     /// Drift guard: `test_required_tasks_cover_every_task` asserts the union
     /// across all built-ins is exactly the full `TaskKind` set, so a new task
@@ -150,7 +165,11 @@ impl ColumnKind {
             ColumnKind::BranchDiff => &[TaskKind::BranchDiff],
             ColumnKind::Upstream => &[TaskKind::Upstream],
             ColumnKind::CiStatus => &[TaskKind::CiStatus],
+            ColumnKind::Base => &[TaskKind::BaseDrift],
+            ColumnKind::Hooks => &[TaskKind::HookStatus],
+            ColumnKind::Stash => &[TaskKind::StashCount],
             ColumnKind::Url => &[TaskKind::UrlStatus],
+            ColumnKind::Size => &[TaskKind::DiskUsage],
             ColumnKind::Summary => &[TaskKind::SummaryGenerate],
             ColumnKind::Gutter
             | ColumnKind::Branch
@@ -198,23 +217,37 @@ pub fn all_tasks() -> std::collections::HashSet<TaskKind> {
 /// Gates that hide a column independent of the `[list] columns` selection.
 ///
 /// Two kinds, distinguished by [`column_renders`]. *Preset* gates (`show_full`,
-/// `summary_enabled`) bundle columns into the default table; a column named
+/// `summary_enabled`, `show_size`) bundle columns into the default table; a column named
 /// outright in `[list] columns` overrides them. *Data-source* gates
 /// (`has_llm_command`, `has_url_template`) are hard: without the command or
 /// template there's nothing to render, so they hold even for a listed column.
 #[derive(Clone, Copy, Debug)]
 pub struct ColumnGates {
-    /// `--full` (or `[list] full`): CI status and LLM summaries join the default
-    /// table only with it. A preset — a listed `ci`/`summary` ignores it.
+    /// `--full` (or `[list] full`): CI status, base drift, hook results, stash
+    /// counts, and LLM summaries join the default table only with it. A preset —
+    /// a listed `ci`/`base`/`hooks`/`stash`/`summary` ignores it.
     pub show_full: bool,
     /// `[list] summary`: the summary column is opt-in for the default table even
     /// under `--full`. A preset — a listed `summary` ignores it.
     pub summary_enabled: bool,
+    /// `--size`: disk usage walks every worktree's files, so the size column
+    /// joins the default table only on request. A preset — a listed `size`
+    /// ignores it.
+    pub show_size: bool,
     /// An LLM command is configured (`[commit.generation]`). A data source: no
     /// command, no summary, however the column was requested.
     pub has_llm_command: bool,
     /// A `[list] url` template is configured. A data source: no template, no url.
     pub has_url_template: bool,
+    /// Network features are on (not `--offline`). A data source: CI status
+    /// comes from the forge, so offline there is no CI column.
+    pub has_network: bool,
+    /// Some branch has a recorded background hook run. A data source: with
+    /// nothing recorded the hooks column would be empty everywhere.
+    pub has_hook_status: bool,
+    /// The stash list is non-empty. A data source: with no stashes the stash
+    /// column would be empty everywhere.
+    pub has_stashes: bool,
 }
 
 /// How a column entered the rendered set, which decides whether the preset gates
@@ -234,12 +267,16 @@ pub enum ColumnSource {
 /// A `Listed` column overrides the preset gates (`--full`, `[list] summary`):
 /// listing `ci` shows it without `--full`, listing `summary` shows it whenever an
 /// LLM command exists. The data-source gates always apply — `summary` needs an
-/// LLM command, `url` needs a template — since listing can't supply data that
-/// isn't configured. Every other column always renders.
+/// LLM command, `url` needs a template, `ci` needs the network — since listing
+/// can't supply data that isn't available. Every other column always renders.
 fn column_renders(kind: ColumnKind, source: ColumnSource, gates: &ColumnGates) -> bool {
     let listed = source == ColumnSource::Listed;
     match kind {
-        ColumnKind::CiStatus => listed || gates.show_full,
+        ColumnKind::CiStatus => gates.has_network && (listed || gates.show_full),
+        ColumnKind::Base => listed || gates.show_full,
+        ColumnKind::Hooks => gates.has_hook_status && (listed || gates.show_full),
+        ColumnKind::Stash => gates.has_stashes && (listed || gates.show_full),
+        ColumnKind::Size => listed || gates.show_size,
         ColumnKind::Summary => {
             gates.has_llm_command && (listed || (gates.show_full && gates.summary_enabled))
         }
@@ -371,14 +408,18 @@ pub const COLUMN_SPECS: &[ColumnSpec] = &[
     ColumnSpec::new(ColumnKind::WorkingDiff, 3),
     ColumnSpec::new(ColumnKind::AheadBehind, 4),
     ColumnSpec::new(ColumnKind::BranchDiff, 6),
-    ColumnSpec::new(ColumnKind::Summary, 10),
-    ColumnSpec::new(ColumnKind::Upstream, 8),
+    ColumnSpec::new(ColumnKind::Summary, 11),
+    ColumnSpec::new(ColumnKind::Upstream, 9),
     ColumnSpec::new(ColumnKind::CiStatus, 5),
+    ColumnSpec::new(ColumnKind::Base, 15),
+    ColumnSpec::new(ColumnKind::Hooks, 16),
+    ColumnSpec::new(ColumnKind::Stash, 17),
     ColumnSpec::new(ColumnKind::Path, 7),
-    ColumnSpec::new(ColumnKind::Url, 9),
-    ColumnSpec::new(ColumnKind::Commit, 11),
-    ColumnSpec::new(ColumnKind::Time, 12),
-    ColumnSpec::new(ColumnKind::Message, 13),
+    ColumnSpec::new(ColumnKind::Size, 8),
+    ColumnSpec::new(ColumnKind::Url, 10),
+    ColumnSpec::new(ColumnKind::Commit, 12),
+    ColumnSpec::new(ColumnKind::Time, 13),
+    ColumnSpec::new(ColumnKind::Message, 14),
 ];
 
 /// Sort key for display order: (slot in `COLUMN_SPECS`, sub-order).
@@ -418,7 +459,11 @@ mod tests {
             ColumnKind::Summary,
             ColumnKind::Upstream,
             ColumnKind::CiStatus,
+            ColumnKind::Base,
+            ColumnKind::Hooks,
+            ColumnKind::Stash,
             ColumnKind::Path,
+            ColumnKind::Size,
             ColumnKind::Url,
             ColumnKind::Commit,
             ColumnKind::Time,
@@ -500,6 +545,9 @@ mod tests {
             ColumnKind::Upstream,
             ColumnKind::Url,
             ColumnKind::CiStatus,
+            ColumnKind::Base,
+            ColumnKind::Hooks,
+            ColumnKind::Stash,
             ColumnKind::Commit,
             ColumnKind::Time,
             ColumnKind::Summary,
@@ -599,7 +647,11 @@ mod tests {
             .iter()
             .flat_map(|spec| spec.kind.required_tasks().iter().copied())
             .collect();
-        let all: HashSet<TaskKind> = TaskKind::iter().collect();
+        // `CustomColumns` feeds config-defined columns and is planned by
+        // `collect` directly.
+        let all: HashSet<TaskKind> = TaskKind::iter()
+            .filter(|kind| *kind != TaskKind::CustomColumns)
+            .collect();
         assert_eq!(
             covered, all,
             "required_tasks() union must equal the full TaskKind set"
@@ -615,10 +667,16 @@ mod tests {
         let open = ColumnGates {
             show_full: true,
             summary_enabled: true,
+            show_size: true,
             has_llm_command: true,
             has_url_template: true,
+            has_network: true,
+            has_hook_status: true,
+            has_stashes: true,
         };
-        let all: HashSet<TaskKind> = TaskKind::iter().collect();
+        let all: HashSet<TaskKind> = TaskKind::iter()
+            .filter(|kind| *kind != TaskKind::CustomColumns)
+            .collect();
 
         // The default set (all built-ins) under open gates needs every task —
         // the planner is exhaustive, so nothing falls out silently.
@@ -660,6 +718,25 @@ mod tests {
             HashSet::from([TaskKind::CiStatus]),
             "listing ci forces it on without --full"
         );
+        assert!(
+            required_tasks_for_render([ColumnKind::Base], Default, &no_full).is_empty(),
+            "base shares ci's --full preset"
+        );
+
+        // `size` is the same shape under its own `--size` preset.
+        let no_size = ColumnGates {
+            show_size: false,
+            ..open
+        };
+        assert!(
+            required_tasks_for_render([ColumnKind::Size], Default, &no_size).is_empty(),
+            "size stays off in the default set without --size"
+        );
+        assert_eq!(
+            required_tasks_for_render([ColumnKind::Size], Listed, &no_size),
+            HashSet::from([TaskKind::DiskUsage]),
+            "listing size forces it on without --size"
+        );
 
         // `summary`'s presets (`--full`, `[list] summary`) gate the default set
         // but yield to a listing; its LLM command is a data source that doesn't.
@@ -702,6 +779,31 @@ mod tests {
             "url needs a template even when listed"
         );
 
+        // With no hook run recorded anywhere, hooks has no data source.
+        let no_hook_runs = ColumnGates {
+            has_hook_status: false,
+            ..open
+        };
+        assert!(
+            required_tasks_for_render([ColumnKind::Hooks], Listed, &no_hook_runs).is_empty(),
+            "hooks needs a recorded run even when listed"
+        );
+        assert!(
+            required_tasks_for_render([ColumnKind::Hooks], Default, &no_full).is_empty(),
+            "hooks shares ci's --full preset"
+        );
+
+        // Offline, CI has no data source, even when listed; base stays.
+        let offline = ColumnGates {
+            has_network: false,
+            ..open
+        };
+        assert!(
+            required_tasks_for_render([ColumnKind::CiStatus], Listed, &offline).is_empty(),
+            "ci needs the network even when listed"
+        );
+        assert!(!required_tasks_for_render([ColumnKind::Base], Default, &offline).is_empty());
+
         // Every precondition holding, a listed summary runs.
         assert_eq!(
             required_tasks_for_render([ColumnKind::Summary], Listed, &open),
@@ -1,6 +1,7 @@
-//! User-defined `wt list` columns from `[list.custom-columns]` in user config.
+//! User-defined `wt list` columns: templates from `[list.custom-columns]` in
+//! user config, and commands from `[list.columns]` in project config.
 //!
-//! Resolved once per invocation, then expanded eagerly for every row before
+//! Resolved once per invocation. Template columns are expanded eagerly for every row before
 //! the table skeleton renders: the template inputs (branch, worktree
 //! identity, per-branch `vars` and `git.branch` config from the bulk config
 //! snapshot) are all in memory by then, so cells paint with the skeleton,
@@ -12,22 +13,37 @@
 //! Expansion stays off the skeleton's critical costs: one minijinja
 //! environment and one parse per column per invocation, one value conversion
 //! per branch per namespace, zero subprocesses per cell.
+//!
+//! Command columns are the exception: their cells need a subprocess per
+//! worktree, so they run post-skeleton as the `CustomColumns` task and the
+//! column is sized from its configured width rather than measured.
 
 use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::time::Duration;
 
 use ansi_str::AnsiStr;
 use minijinja::Value;
 use worktrunk::config::{
-    ListColumnConfig, template_environment, validate_list_column_template, vars_map_to_value,
+    Approvals, ListColumnConfig, ProjectListColumnConfig, template_environment,
+    validate_list_column_template, vars_map_to_value,
 };
 use worktrunk::git::Repository;
 use worktrunk::path::to_posix_path;
+use worktrunk::shell_exec::{Cmd, ShellConfig};
 
 use super::model::ListItem;
 
 /// Default maximum display width for a custom column.
 const DEFAULT_MAX_WIDTH: usize = 40;
 
+/// Default width for a command column. Its values arrive after layout, so
+/// the width is reserved up front instead of measured.
+const DEFAULT_COMMAND_WIDTH: usize = 10;
+
+/// Default time a column command may run before it's killed.
+const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Default drop priority — the URL band (see `COLUMN_SPECS`).
 const DEFAULT_PRIORITY: u8 = 9;
 
@@ -36,58 +52,176 @@ const DEFAULT_PRIORITY: u8 = 9;
 pub struct ResolvedCustomColumn {
     /// Header text (the TOML key).
     pub name: String,
-    /// minijinja template; the rendered result is the cell text.
-    pub template: String,
+    /// Where the cell text comes from.
+    pub source: CustomColumnSource,
     /// Maximum display width; longer values truncate.
     pub max_width: usize,
     /// Drop order when the terminal narrows (lower = kept longer).
     pub priority: u8,
 }
 
-/// Resolve `[list.custom-columns]` config into expansion-ready columns.
+/// What produces a custom column's cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomColumnSource {
+    /// minijinja template from `[list.custom-columns]`; the rendered result is
+    /// the cell text.
+    Template(String),
+    /// Shell command from project `[list.columns]`, run in each worktree; the
+    /// first line of its output is the cell text.
+    Command { command: String, timeout: Duration },
+}
+
+impl ResolvedCustomColumn {
+    /// Whether cells come from a command, filled in after the skeleton.
+    pub fn is_command(&self) -> bool {
+        matches!(self.source, CustomColumnSource::Command { .. })
+    }
+}
+
+/// Resolve `[list.custom-columns]` and the approved project `[list.columns]`
+/// into expansion-ready columns.
 ///
 /// Validates each definition and orders the result by (priority, name),
-/// which is also the display order among custom columns. Errors abort
-/// `wt list` — column config is consumed only here, so a broken template
-/// can't affect other commands. (The picker degrades instead; see the
-/// resolution site in `collect()`.)
+/// which is also the display order among custom columns. A template column
+/// wins over a command column with the same header — the user's own config
+/// is the more specific. Errors abort `wt list` — column config is consumed
+/// only here, so a broken template can't affect other commands. (The picker
+/// degrades instead; see the resolution site in `collect()`.)
 pub fn resolve_custom_columns(
     columns: &BTreeMap<String, ListColumnConfig>,
+    command_columns: &BTreeMap<String, ProjectListColumnConfig>,
     repo: &Repository,
 ) -> anyhow::Result<Vec<ResolvedCustomColumn>> {
+    let command_columns: Vec<_> = command_columns
+        .iter()
+        .filter(|(name, _)| !columns.contains_key(*name))
+        .collect();
     // `ColumnKind::Custom(u8)` indexes the resolved list; more entries than
     // u8 can address would silently alias columns.
+    let count = columns.len() + command_columns.len();
     anyhow::ensure!(
-        columns.len() <= usize::from(u8::MAX) + 1,
-        "[list.custom-columns] supports at most 256 columns ({} configured)",
-        columns.len()
+        count <= usize::from(u8::MAX) + 1,
+        "wt list supports at most 256 custom columns ({count} configured)"
     );
     let mut resolved: Vec<ResolvedCustomColumn> = columns
         .iter()
         .map(|(name, config)| {
-            anyhow::ensure!(
-                !name.trim().is_empty() && !name.chars().any(char::is_control),
-                "Invalid [list.custom-columns] name {name:?}: must be non-empty without control characters"
-            );
-            anyhow::ensure!(
-                config.width != Some(0),
-                "Invalid [list.custom-columns.{name}] width: must be at least 1"
-            );
-            validate_list_column_template(&config.template, repo, &format!("list.custom-columns.{name}"))?;
+            validate_column(name, config.width, "list.custom-columns")?;
+            validate_list_column_template(
+                &config.template,
+                repo,
+                &format!("list.custom-columns.{name}"),
+            )?;
             Ok(ResolvedCustomColumn {
                 name: name.clone(),
-                template: config.template.clone(),
+                source: CustomColumnSource::Template(config.template.clone()),
                 max_width: config.width.unwrap_or(DEFAULT_MAX_WIDTH),
                 priority: config.priority.unwrap_or(DEFAULT_PRIORITY),
             })
         })
+        .chain(command_columns.into_iter().map(|(name, config)| {
+            validate_column(name, config.width, "list.columns")?;
+            Ok(ResolvedCustomColumn {
+                name: name.clone(),
+                source: CustomColumnSource::Command {
+                    command: config.command.clone(),
+                    timeout: config
+                        .timeout_ms
+                        .map_or(DEFAULT_COMMAND_TIMEOUT, Duration::from_millis),
+                },
+                max_width: config.width.unwrap_or(DEFAULT_COMMAND_WIDTH),
+                priority: config.priority.unwrap_or(DEFAULT_PRIORITY),
+            })
+        }))
         .collect::<anyhow::Result<_>>()?;
     resolved.sort_by(|a, b| (a.priority, &a.name).cmp(&(b.priority, &b.name)));
     Ok(resolved)
 }
 
-/// Expand every custom column for every item, populating
-/// `ListItem::custom_values`.
+/// Split the project's `[list.columns]` into those whose commands are
+/// approved (saved, or by `--approve` / `WORKTRUNK_APPROVE`) and the names of
+/// those that aren't. Commands never run unapproved; `wt list` prompts for
+/// them up front (`approve_list_columns`).
+pub fn approved_command_columns(
+    repo: &Repository,
+) -> (BTreeMap<String, ProjectListColumnConfig>, Vec<String>) {
+    let columns = match repo.load_project_config() {
+        Ok(Some(config)) if !config.list.columns.is_empty() => config.list.columns,
+        _ => return Default::default(),
+    };
+    let approvals = Approvals::load().ok();
+    let project_id = repo.project_identifier().ok();
+    let policy = crate::commands::command_approval::approve_policy();
+    let is_approved = |command: &str| {
+        policy.approves(command)
+            || match (&approvals, &project_id) {
+                (Some(approvals), Some(project_id)) => {
+                    approvals.is_command_approved(project_id, command)
+                }
+                _ => false,
+            }
+    };
+    let (approved, unapproved): (BTreeMap<_, _>, BTreeMap<_, _>) = columns
+        .into_iter()
+        .partition(|(_, column)| is_approved(&column.command));
+    (approved, unapproved.into_keys().collect())
+}
+
+fn validate_column(name: &str, width: Option<usize>, section: &str) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        !name.trim().is_empty() && !name.chars().any(char::is_control),
+        "Invalid [{section}] name {name:?}: must be non-empty without control characters"
+    );
+    anyhow::ensure!(
+        width != Some(0),
+        "Invalid [{section}.{name}] width: must be at least 1"
+    );
+    Ok(())
+}
+
+/// Run a command column's command in `worktree_path`, returning the cell
+/// text. Failures and timeouts produce an empty cell, logged at `-vv` — the
+/// same contract as a template that fails to render.
+pub(super) fn run_column_command(
+    name: &str,
+    command: &str,
+    timeout: Duration,
+    worktree_path: &Path,
+    repo: &Repository,
+) -> String {
+    let output = ShellConfig::get().and_then(|shell| {
+        let command = crate::commands::hook_sandbox::sandbox_command(
+            &repo.user_config().sandbox,
+            command,
+            worktree_path,
+        )?;
+        Ok(Cmd::new(shell.executable.to_string_lossy())
+            .args(&shell.args)
+            .arg(command)
+            .current_dir(worktree_path)
+            .external(format!("list.columns.{name}"))
+            .timeout(timeout)
+            .run()?)
+    });
+    match output {
+        Ok(output) if output.status.success() => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            sanitize_cell(stdout.lines().next().unwrap_or(""))
+        }
+        Ok(output) => {
+            tracing::debug!(name = %name, status = %output.status, "[list.columns.{name}] failed in {}: {}", worktree_path.display(), output.status);
+            String::new()
+        }
+        Err(e) => {
+            tracing::debug!(name = %name, error = %e, "[list.columns.{name}] failed in {}: {e:#}", worktree_path.display());
+            String::new()
+        }
+    }
+}
+
+/// Expand every template column for every item, populating
+/// `ListItem::custom_values`. Command columns stay `None` until the
+/// `CustomColumns` task reports.
 ///
 /// `all_vars` and `all_branch_config` are the pre-fetched per-branch maps for
 /// the whole table (one snapshot read each — no subprocess per cell); the
@@ -113,15 +247,18 @@ pub fn expand_custom_columns(
     let templates: Vec<Option<minijinja::Template>> = columns
         .iter()
         .zip(&names)
-        .map(
-            |(column, name)| match env.template_from_named_str(name, &column.template) {
+        .map(|(column, name)| {
+            let CustomColumnSource::Template(template) = &column.source else {
+                return None;
+            };
+            match env.template_from_named_str(name, template) {
                 Ok(template) => Some(template),
                 Err(e) => {
                     tracing::debug!(name = %name, error = %e, "[{name}] parse failed after validation: {e}");
                     None
                 }
-            },
-        )
+            }
+        })
         .collect();
 
     // Convert each branch's vars / branch_config to a template value once, not
@@ -181,17 +318,21 @@ pub fn expand_custom_columns(
         item.custom_values = templates
             .iter()
             .zip(&names)
-            .map(|(template, name)| {
+            .zip(columns)
+            .map(|((template, name), column)| {
+                if column.is_command() {
+                    return None;
+                }
                 let Some(template) = template else {
-                    return String::new();
+                    return Some(String::new());
                 };
-                match template.render(&context) {
+                Some(match template.render(&context) {
                     Ok(value) => sanitize_cell(&value),
                     Err(e) => {
                         tracing::debug!(name = %name, branch = ?branch, error = %e, "[{name}] render failed for row {branch:?}: {e}");
                         String::new()
                     }
-                }
+                })
             })
             .collect();
     }
@@ -228,6 +369,10 @@ mod tests {
         assert_eq!(sanitize_cell("\n\n"), "");
     }
 
+    fn cells(item: &ListItem) -> Vec<Option<&str>> {
+        item.custom_values.iter().map(Option::as_deref).collect()
+    }
+
     fn column_config(template: &str) -> ListColumnConfig {
         ListColumnConfig {
             template: template.to_string(),
@@ -258,7 +403,7 @@ mod tests {
             },
         );
 
-        let resolved = resolve_custom_columns(&columns, &test.repo).unwrap();
+        let resolved = resolve_custom_columns(&columns, &BTreeMap::new(), &test.repo).unwrap();
         let names: Vec<&str> = resolved.iter().map(|c| c.name.as_str()).collect();
         assert_eq!(names, ["C", "B", "A"]);
         assert_eq!(resolved[0].priority, 3);
@@ -267,13 +412,81 @@ mod tests {
         assert_eq!(resolved[2].max_width, 10);
     }
 
+    #[test]
+    fn test_resolve_command_columns() {
+        let test = TestRepo::new();
+        let mut columns = BTreeMap::new();
+        columns.insert("Todo".to_string(), column_config("{{ branch }}"));
+        let command_columns = BTreeMap::from([
+            (
+                "Todo".to_string(),
+                ProjectListColumnConfig {
+                    command: "shadowed".to_string(),
+                    width: None,
+                    priority: None,
+                    timeout_ms: None,
+                },
+            ),
+            (
+                "Tests".to_string(),
+                ProjectListColumnConfig {
+                    command: "cargo nextest list | wc -l".to_string(),
+                    width: None,
+                    priority: Some(4),
+                    timeout_ms: Some(250),
+                },
+            ),
+        ]);
+
+        let resolved = resolve_custom_columns(&columns, &command_columns, &test.repo).unwrap();
+        assert_eq!(
+            resolved,
+            [
+                ResolvedCustomColumn {
+                    name: "Tests".to_string(),
+                    source: CustomColumnSource::Command {
+                        command: "cargo nextest list | wc -l".to_string(),
+                        timeout: Duration::from_millis(250),
+                    },
+                    max_width: DEFAULT_COMMAND_WIDTH,
+                    priority: 4,
+                },
+                // The template column wins the name collision
+                ResolvedCustomColumn {
+                    name: "Todo".to_string(),
+                    source: CustomColumnSource::Template("{{ branch }}".to_string()),
+                    max_width: DEFAULT_MAX_WIDTH,
+                    priority: DEFAULT_PRIORITY,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_column_command() {
+        let test = TestRepo::new();
+        let run = |command: &str| {
+            run_column_command(
+                "Col",
+                command,
+                DEFAULT_COMMAND_TIMEOUT,
+                test.path(),
+                &test.repo,
+            )
+        };
+        // First line only, sanitized
+        assert_eq!(run("printf 'a\tb\nsecond\n'"), "a b");
+        // Failures leave the cell empty
+        assert_eq!(run("echo partial; exit 3"), "");
+    }
+
     #[test]
     fn test_resolve_rejects_invalid_definitions() {
         let test = TestRepo::new();
 
         let mut blank_name = BTreeMap::new();
         blank_name.insert("  ".to_string(), column_config("x"));
-        let err = resolve_custom_columns(&blank_name, &test.repo).unwrap_err();
+        let err = resolve_custom_columns(&blank_name, &BTreeMap::new(), &test.repo).unwrap_err();
         assert!(err.to_string().contains("name"), "got: {err}");
 
         let mut zero_width = BTreeMap::new();
@@ -285,18 +498,18 @@ mod tests {
                 priority: None,
             },
         );
-        let err = resolve_custom_columns(&zero_width, &test.repo).unwrap_err();
+        let err = resolve_custom_columns(&zero_width, &BTreeMap::new(), &test.repo).unwrap_err();
         assert!(err.to_string().contains("width"), "got: {err}");
 
         let mut unknown_var = BTreeMap::new();
         unknown_var.insert("Ok".to_string(), column_config("{{ nope }}"));
-        let err = resolve_custom_columns(&unknown_var, &test.repo).unwrap_err();
+        let err = resolve_custom_columns(&unknown_var, &BTreeMap::new(), &test.repo).unwrap_err();
         assert!(err.to_string().contains("nope"), "got: {err}");
 
         let too_many: BTreeMap<String, ListColumnConfig> = (0..=256)
             .map(|i| (format!("C{i:03}"), column_config("x")))
             .collect();
-        let err = resolve_custom_columns(&too_many, &test.repo).unwrap_err();
+        let err = resolve_custom_columns(&too_many, &BTreeMap::new(), &test.repo).unwrap_err();
         assert!(err.to_string().contains("256"), "got: {err}");
     }
 
@@ -306,19 +519,19 @@ mod tests {
         let columns = vec![
             ResolvedCustomColumn {
                 name: "Ticket".to_string(),
-                template: "{{ vars.ticket }}".to_string(),
+                source: CustomColumnSource::Template("{{ vars.ticket }}".to_string()),
                 max_width: 40,
                 priority: 9,
             },
             ResolvedCustomColumn {
                 name: "Port".to_string(),
-                template: "{{ vars.config.port }}".to_string(),
+                source: CustomColumnSource::Template("{{ vars.config.port }}".to_string()),
                 max_width: 40,
                 priority: 9,
             },
             ResolvedCustomColumn {
                 name: "Tag".to_string(),
-                template: "{{ branch }}!".to_string(),
+                source: CustomColumnSource::Template("{{ branch }}!".to_string()),
                 max_width: 40,
                 priority: 9,
             },
@@ -341,11 +554,11 @@ mod tests {
         // Vars-backed cells are sanitized to one line; nested JSON access
         // works; identity vars expand
         assert_eq!(
-            items[0].custom_values,
-            ["JIRA-9 wrapped", "8080", "feature!"]
+            cells(&items[0]),
+            [Some("JIRA-9 wrapped"), Some("8080"), Some("feature!")]
         );
         // A branch without the vars keys renders empty cells, not errors
-        assert_eq!(items[1].custom_values, ["", "", "other!"]);
+        assert_eq!(cells(&items[1]), [Some(""), Some(""), Some("other!")]);
     }
 
     #[test]
@@ -354,7 +567,7 @@ mod tests {
         let columns = vec![
             ResolvedCustomColumn {
                 name: "Ticket".to_string(),
-                template: "{{ git.branch.jira }}".to_string(),
+                source: CustomColumnSource::Template("{{ git.branch.jira }}".to_string()),
                 max_width: 40,
                 priority: 9,
             },
@@ -362,7 +575,9 @@ mod tests {
             // just the summary line the use case wants.
             ResolvedCustomColumn {
                 name: "Summary".to_string(),
-                template: "{{ git.branch.description | lines | first }}".to_string(),
+                source: CustomColumnSource::Template(
+                    "{{ git.branch.description | lines | first }}".to_string(),
+                ),
                 max_width: 40,
                 priority: 9,
             },
@@ -393,8 +608,8 @@ mod tests {
 
         // The branch's own git config surfaces; the multi-line description is
         // reduced to its first line.
-        assert_eq!(items[0].custom_values, ["PROJ-1", "Add telemetry"]);
+        assert_eq!(cells(&items[0]), [Some("PROJ-1"), Some("Add telemetry")]);
         // A branch without the keys renders empty cells, not errors.
-        assert_eq!(items[1].custom_values, ["", ""]);
+        assert_eq!(cells(&items[1]), [Some(""), Some("")]);
     }
 }
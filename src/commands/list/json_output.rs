@@ -22,6 +22,8 @@ use schemars::JsonSchema;
 use serde::Serialize;
 use worktrunk::git::{GitRepoInfo, LineDiff, Repository};
 
+use crate::commands::hook_status::PipelineRecord;
+
 use super::ci_status::{CiSource, PrStatus, ReviewState};
 use super::custom_columns::ResolvedCustomColumn;
 use super::model::{ItemKind, ListItem, UpstreamStatus};
@@ -85,6 +87,21 @@ pub struct JsonItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ci: Option<JsonCi>,
 
+    /// Base the branch was cut from, when it has since been re-cut or deleted
+    /// (only with `--full`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<JsonBase>,
+
+    /// Last recorded run of each background hook pipeline (only with
+    /// `--full`, and only when the branch has one)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hooks: Vec<JsonHookRun>,
+
+    /// Number of stash entries made on the branch (only with `--full`, and
+    /// only when there are any)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stashes: Option<usize>,
+
     /// Repository web URL derived from the primary remote (absent when no parseable remote).
     /// This is the local checkout's repo; for the repo a PR/MR targets (e.g. the upstream of a
     /// fork), see `ci.repo_url`.
@@ -140,6 +157,10 @@ pub struct JsonCommit {
 
     /// Unix timestamp of commit
     pub timestamp: i64,
+
+    /// ISO 8601 commit time (only with `--absolute-times`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
 }
 
 /// Working tree state
@@ -160,6 +181,10 @@ pub struct JsonWorkingTree {
     /// Has deleted files (✘)
     pub deleted: bool,
 
+    /// Files with unresolved merge conflicts (absent when none)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflicted_files: Option<usize>,
+
     /// Lines added/deleted in working tree vs HEAD
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diff: Option<JsonDiff>,
@@ -193,6 +218,83 @@ pub struct JsonMain {
     /// Lines added/deleted vs default branch
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diff: Option<JsonDiff>,
+
+    /// Merge-base commit with the default branch (absent for orphan branches)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_base: Option<String>,
+}
+
+/// A branch base that moved since the branch was cut
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct JsonBase {
+    /// Ref the branch was created from (e.g. "origin/release/1.2")
+    #[serde(rename = "ref")]
+    pub base_ref: String,
+
+    /// Commit the branch started from
+    pub sha: String,
+
+    /// "rewritten" (force-pushed or re-cut) or "deleted"
+    pub state: &'static str,
+}
+
+/// The last run of one background hook pipeline
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct JsonHookRun {
+    /// Hook type (e.g. "post-start")
+    pub hook_type: String,
+
+    /// "user" or "project"
+    pub source: String,
+
+    /// Most severe command state: "failed", "running", "pending", "skipped",
+    /// or "succeeded"
+    pub state: &'static str,
+
+    /// Unix timestamp when the pipeline started
+    pub started_at: u64,
+
+    /// Each command, in pipeline order
+    pub commands: Vec<JsonHookCommand>,
+}
+
+/// One command of a background hook pipeline
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct JsonHookCommand {
+    /// Command name (`cmd-<n>` for unnamed commands)
+    pub name: String,
+
+    /// "succeeded", "failed", "running", "pending", or "skipped"
+    pub state: &'static str,
+
+    /// Run time in milliseconds (finished commands)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+
+    /// Exit code (finished commands not killed by a signal)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+}
+
+impl JsonHookRun {
+    pub(crate) fn from_record(record: &PipelineRecord) -> Self {
+        Self {
+            hook_type: record.hook_type.to_string(),
+            source: record.source.to_string(),
+            state: record.state().map_or("succeeded", Into::into),
+            started_at: record.started_at,
+            commands: record
+                .commands
+                .iter()
+                .map(|c| JsonHookCommand {
+                    name: c.name.clone(),
+                    state: c.state.into(),
+                    duration_ms: c.duration_ms,
+                    exit_code: c.exit_code,
+                })
+                .collect(),
+        }
+    }
 }
 
 /// Relationship to remote tracking branch
@@ -204,11 +306,20 @@ pub struct JsonRemote {
     /// Remote branch name (e.g., "feature-login")
     pub branch: String,
 
+    /// Upstream tracking ref (e.g., "origin/feature-login")
+    #[serde(rename = "ref")]
+    pub tracking_ref: String,
+
     /// Commits ahead of remote
     pub ahead: usize,
 
     /// Commits behind remote
     pub behind: usize,
+
+    /// True when the remote branch was deleted (git's `[gone]`); `ahead` and
+    /// `behind` are 0 then
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub gone: bool,
 }
 
 /// Worktree-specific state
@@ -224,6 +335,10 @@ pub struct JsonWorktree {
 
     /// HEAD is detached (not on a branch)
     pub detached: bool,
+
+    /// Bytes on disk for the checked-out files (only with `--size`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disk_usage: Option<u64>,
 }
 
 /// CI status from PR or branch workflow
@@ -301,6 +416,7 @@ impl JsonItem {
         } else {
             item.head.clone()
         };
+        let timestamp = item.commit.as_ref().map(|c| c.timestamp).unwrap_or(0);
         let commit = JsonCommit {
             sha,
             short_sha: item.short_sha.clone(),
@@ -309,7 +425,9 @@ impl JsonItem {
                 .as_ref()
                 .map(|c| c.commit_message.clone())
                 .unwrap_or_default(),
-            timestamp: item.commit.as_ref().map(|c| c.timestamp).unwrap_or(0),
+            timestamp,
+            date: crate::display::absolute_times()
+                .then(|| worktrunk::utils::format_timestamp_iso8601(timestamp.max(0) as u64)),
         };
 
         // Working tree: read directly from `WorktreeData`, not from
@@ -326,6 +444,7 @@ impl JsonItem {
                 untracked: wt.untracked,
                 renamed: wt.renamed,
                 deleted: wt.deleted,
+                conflicted_files: data.conflicted_files.filter(|&n| n > 0),
                 diff: data.working_tree_diff.map(JsonDiff::from),
             })
         });
@@ -354,6 +473,7 @@ impl JsonItem {
                 ahead: counts.ahead,
                 behind: counts.behind,
                 diff: item.branch_diff.map(|bd| JsonDiff::from(bd.diff)),
+                merge_base: item.merge_base.clone(),
             })
         };
 
@@ -370,6 +490,7 @@ impl JsonItem {
                 state,
                 reason,
                 detached: data.detached,
+                disk_usage: data.disk_usage,
             }
         });
 
@@ -383,6 +504,26 @@ impl JsonItem {
             .and_then(|opt| opt.as_ref())
             .map(|pr| JsonCi::from_pr_status(pr, ci_provider_override));
 
+        // Base drift: flatten Option<Option<_>> → Option<_>
+        let base = item
+            .base_drift
+            .as_ref()
+            .and_then(|opt| opt.as_ref())
+            .map(|(base, drift)| JsonBase {
+                base_ref: base.base_ref.clone(),
+                sha: base.sha.clone(),
+                state: drift.into(),
+            });
+
+        let hooks = item
+            .hook_status
+            .iter()
+            .flatten()
+            .map(JsonHookRun::from_record)
+            .collect();
+
+        let stashes = item.stash_count.filter(|&count| count > 0);
+
         // Statusline and symbols (raw, without ANSI codes)
         let statusline = item.statusline.clone();
         let symbols = Some(format_raw_symbols(&item.status_symbols)).filter(|s| !s.is_empty());
@@ -401,6 +542,7 @@ impl JsonItem {
         let columns = custom_columns
             .iter()
             .zip(&item.custom_values)
+            .filter_map(|(column, value)| Some((column, value.as_ref()?)))
             .filter(|(_, value)| !value.is_empty())
             .map(|(column, value)| (column.name.clone(), value.clone()))
             .collect();
@@ -421,6 +563,9 @@ impl JsonItem {
             is_current,
             is_previous,
             ci,
+            base,
+            hooks,
+            stashes,
             repo_url: repo.map(|repo| repo.url.clone()),
             repo: repo.cloned(),
             url: item.url.clone(),
@@ -436,14 +581,36 @@ impl JsonItem {
 
 /// Convert UpstreamStatus to JsonRemote
 fn upstream_to_json(upstream: &UpstreamStatus, branch: &Option<String>) -> Option<JsonRemote> {
+    if let Some(gone) = upstream.gone() {
+        let (name, remote_branch) = gone.split_once('/').unwrap_or((gone, ""));
+        return Some(JsonRemote {
+            name: name.to_string(),
+            branch: remote_branch.to_string(),
+            tracking_ref: gone.to_string(),
+            ahead: 0,
+            behind: 0,
+            gone: true,
+        });
+    }
     upstream.active().map(|active| {
-        // Use local branch name since UpstreamStatus only stores the remote name,
-        // not the full tracking refspec. In most cases these match (e.g., feature -> origin/feature).
+        // Without the tracking ref, assume the remote branch shares the local
+        // branch's name — true in most cases (e.g., feature -> origin/feature).
+        let branch = upstream
+            .tracking
+            .as_deref()
+            .and_then(|tracking| tracking.strip_prefix(active.remote)?.strip_prefix('/'))
+            .map(str::to_string)
+            .unwrap_or_else(|| branch.clone().unwrap_or_default());
         JsonRemote {
             name: active.remote.to_string(),
-            branch: branch.clone().unwrap_or_default(),
+            tracking_ref: upstream
+                .tracking
+                .clone()
+                .unwrap_or_else(|| format!("{}/{branch}", active.remote)),
+            branch,
             ahead: active.ahead,
             behind: active.behind,
+            gone: false,
         }
     })
 }
@@ -772,8 +939,10 @@ mod tests {
     fn test_upstream_to_json_with_remote() {
         let upstream = UpstreamStatus {
             remote: Some("origin".to_string()),
+            tracking: None,
             ahead: 3,
             behind: 2,
+            gone: None,
         };
         let branch = Some("feature".to_string());
         let json = upstream_to_json(&upstream, &branch);
@@ -781,16 +950,32 @@ mod tests {
         let json = json.unwrap();
         assert_eq!(json.name, "origin");
         assert_eq!(json.branch, "feature");
+        assert_eq!(json.tracking_ref, "origin/feature");
         assert_eq!(json.ahead, 3);
         assert_eq!(json.behind, 2);
     }
 
+    #[test]
+    fn test_upstream_to_json_renamed_upstream() {
+        let upstream = UpstreamStatus {
+            remote: Some("origin".to_string()),
+            tracking: Some("origin/user/feature".to_string()),
+            ..Default::default()
+        };
+        let json = upstream_to_json(&upstream, &Some("feature".to_string())).unwrap();
+        assert_eq!(json.name, "origin");
+        assert_eq!(json.branch, "user/feature");
+        assert_eq!(json.tracking_ref, "origin/user/feature");
+    }
+
     #[test]
     fn test_upstream_to_json_no_remote() {
         let upstream = UpstreamStatus {
             remote: None,
+            tracking: None,
             ahead: 0,
             behind: 0,
+            gone: None,
         };
         let branch = Some("feature".to_string());
         let json = upstream_to_json(&upstream, &branch);
@@ -801,8 +986,10 @@ mod tests {
     fn test_upstream_to_json_no_branch() {
         let upstream = UpstreamStatus {
             remote: Some("origin".to_string()),
+            tracking: None,
             ahead: 1,
             behind: 0,
+            gone: None,
         };
         let branch = None;
         let json = upstream_to_json(&upstream, &branch);
@@ -811,6 +998,20 @@ mod tests {
         assert_eq!(json.branch, ""); // Empty string when branch is None
     }
 
+    #[test]
+    fn test_upstream_to_json_gone() {
+        let upstream = UpstreamStatus {
+            gone: Some("origin/feature".to_string()),
+            ..Default::default()
+        };
+        let json = upstream_to_json(&upstream, &Some("feature".to_string())).unwrap();
+        assert_eq!(json.name, "origin");
+        assert_eq!(json.branch, "feature");
+        assert_eq!(json.tracking_ref, "origin/feature");
+        assert_eq!((json.ahead, json.behind), (0, 0));
+        assert!(json.gone);
+    }
+
     // ============================================================================
     // worktree_state_to_json Tests
     // ============================================================================
@@ -826,10 +1027,12 @@ mod tests {
             prunable: None,
             working_tree_diff: None,
             working_tree_status: None,
-            has_conflicts: None,
+            conflicted_files: None,
             has_working_tree_conflicts: None,
             git_operation: Some(ActiveGitOperation::None),
+            disk_usage: None,
             branch_worktree_mismatch: false,
+            tree_path: None,
         }
     }
 
@@ -990,6 +1193,7 @@ mod tests {
             short_sha: "abc123d".to_string(),
             message: "Fix bug".to_string(),
             timestamp: 1700000000,
+            date: None,
         })
         .unwrap();
         assert_snapshot!(commit, @r#"
@@ -1007,6 +1211,7 @@ mod tests {
             untracked: true,
             renamed: false,
             deleted: false,
+            conflicted_files: Some(2),
             diff: Some(JsonDiff {
                 added: 10,
                 deleted: 5,
@@ -1020,6 +1225,7 @@ mod tests {
           "untracked": true,
           "renamed": false,
           "deleted": false,
+          "conflicted_files": 2,
           "diff": {
             "added": 10,
             "deleted": 5
@@ -1034,6 +1240,7 @@ mod tests {
                 added: 50,
                 deleted: 20,
             }),
+            merge_base: Some("def456".to_string()),
         })
         .unwrap();
         assert_snapshot!(main, @r#"
@@ -1043,21 +1250,25 @@ mod tests {
           "diff": {
             "added": 50,
             "deleted": 20
-          }
+          },
+          "merge_base": "def456"
         }
         "#);
 
         let remote = serde_json::to_string_pretty(&JsonRemote {
             name: "origin".to_string(),
             branch: "feature".to_string(),
+            tracking_ref: "origin/feature".to_string(),
             ahead: 2,
             behind: 0,
+            gone: false,
         })
         .unwrap();
         assert_snapshot!(remote, @r#"
         {
           "name": "origin",
           "branch": "feature",
+          "ref": "origin/feature",
           "ahead": 2,
           "behind": 0
         }
@@ -1067,6 +1278,7 @@ mod tests {
             state: Some("locked"),
             reason: Some("manual".to_string()),
             detached: false,
+            disk_usage: None,
         })
         .unwrap();
         assert_snapshot!(worktree, @r#"
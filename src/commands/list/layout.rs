@@ -112,6 +112,11 @@
 //!      they're gated off, so `renders_given_run` filters the column out entirely
 //!      (bypasses the tier system). A `[list] columns` listing forces them on —
 //!      the planner then includes the task and the column renders
+//!    - **Base** rides the same `--full` gate: it is local git, but a re-cut
+//!      base is rare enough that the column is noise in the default table
+//!    - **Hooks** also rides `--full`, and additionally needs a recorded hook
+//!      run somewhere in the repo (a data-source gate, like Url's template)
+//!    - **Stash** rides `--full` too, gated on a non-empty stash list
 //!    - **BranchDiff** (`main…±`) is pure local git, so it is *not* gated — it
 //!      shows by default and follows the normal two-tier priority (6/16);
 //!      CiStatus is 5/15
@@ -194,9 +199,7 @@ use std::path::{Path, PathBuf};
 
 use anstyle::Style;
 use unicode_width::UnicodeWidthStr;
-use worktrunk::styling::{ADDITION, DELETION, Stream, supports_hyperlinks};
-
-use crate::display::shorten_path;
+use worktrunk::styling::{ADDITION, DELETION, Glyph, Stream, supports_hyperlinks};
 
 use super::collect::{TaskKind, parse_port_from_url};
 use super::columns::{COLUMN_SPECS, ColumnKind, ColumnSpec, column_display_index};
@@ -275,6 +278,10 @@ pub struct ColumnWidths {
     pub time: usize,
     pub url: usize,
     pub ci_status: usize,
+    pub base: usize,
+    pub hooks: usize,
+    pub stash: usize,
+    pub size: usize,
     pub ahead_behind: DiffWidths,
     pub working_diff: DiffWidths,
     pub branch_diff: DiffWidths,
@@ -295,6 +302,10 @@ pub struct ColumnDataFlags {
     pub upstream: bool,
     pub url: bool,
     pub ci_status: bool,
+    pub base: bool,
+    pub hooks: bool,
+    pub stash: bool,
+    pub size: bool,
     pub path: bool, // True if any worktree has branch_worktree_mismatch
 }
 
@@ -390,12 +401,12 @@ impl DiffVariant {
                 negative: "-",
             },
             DiffVariant::Arrows => DiffSymbols {
-                positive: "↑",
-                negative: "↓",
+                positive: Glyph::new("↑", ">").as_str(),
+                negative: Glyph::new("↓", "<").as_str(),
             },
             DiffVariant::UpstreamArrows => DiffSymbols {
-                positive: "⇡",
-                negative: "⇣",
+                positive: Glyph::new("⇡", "^").as_str(),
+                negative: Glyph::new("⇣", "v").as_str(),
             },
         }
     }
@@ -442,12 +453,17 @@ impl ColumnKind {
             ColumnKind::Url => flags.url,
             ColumnKind::Time => true,
             ColumnKind::CiStatus => flags.ci_status,
+            ColumnKind::Base => flags.base,
+            ColumnKind::Hooks => flags.hooks,
+            ColumnKind::Stash => flags.stash,
+            ColumnKind::Size => flags.size,
             ColumnKind::Commit => true,
             ColumnKind::Summary => true, // Placeholder shown until data arrives
             ColumnKind::Message => true,
-            // Custom values are final before layout (nothing arrives later),
-            // so all-empty columns are excluded from candidates instead of
-            // taking the EMPTY_PENALTY path built for still-loading data.
+            // Template values are final before layout (nothing arrives
+            // later), so all-empty columns are excluded from candidates
+            // instead of taking the EMPTY_PENALTY path built for still-loading
+            // data. Command columns always have a reserved width.
             ColumnKind::Custom(_) => true,
         }
     }
@@ -484,6 +500,10 @@ impl ColumnKind {
             ColumnKind::Time => text(widths.time),
             ColumnKind::Url => text(widths.url),
             ColumnKind::CiStatus => text(widths.ci_status),
+            ColumnKind::Base => text(widths.base),
+            ColumnKind::Hooks => text(widths.hooks),
+            ColumnKind::Stash => text(widths.stash),
+            ColumnKind::Size => text(widths.size),
             ColumnKind::Commit => text(commit_width),
             ColumnKind::Summary => None, // Flexible: handled specially in allocation loop
             ColumnKind::Message => None,
@@ -570,8 +590,8 @@ impl LayoutConfig {
     }
 }
 
-/// The user's column configuration for one render: which columns to show and
-/// the resolved custom columns available to render.
+/// The user's column configuration for one render: which columns to show,
+/// the resolved custom columns available to render, and width caps.
 ///
 /// `selected` is `None` for the default column set (every built-in, with custom
 /// columns appended in resolution order). `Some(order)` renders exactly those
@@ -579,10 +599,14 @@ impl LayoutConfig {
 /// (`ColumnKind::Custom(i)`, indexing `custom`), and anything not listed is
 /// hidden. `custom` always carries the resolved `[list.custom-columns]` so their
 /// widths and headers are available; `selected` decides which of them render.
-#[derive(Clone, Copy)]
+/// `branch_width` / `path_width` cap those columns (`[list] branch-width`);
+/// longer values are shortened in the middle when rendered.
+#[derive(Clone, Copy, Default)]
 pub struct ColumnSelection<'a> {
     pub selected: Option<&'a [ColumnKind]>,
     pub custom: &'a [ResolvedCustomColumn],
+    pub branch_width: Option<usize>,
+    pub path_width: Option<usize>,
 }
 
 #[derive(Clone, Copy)]
@@ -651,7 +675,16 @@ fn build_estimated_widths(
     let ahead_behind_fixed = fit_header(ColumnKind::AheadBehind.header(), 7); // "↑99 ↓99"
     let branch_diff_fixed = fit_header(ColumnKind::BranchDiff.header(), 9); // "+999 -999"
     let upstream_fixed = fit_header(ColumnKind::Upstream.header(), 7); // "↑99 ↓99"
-    let age_estimate = 4; // "11mo" (short format)
+    // "11mo" (short format), or "2025-01-01T00:00:00Z" under --absolute-times
+    let age_estimate = if crate::display::absolute_times() {
+        20
+    } else {
+        4
+    };
+    let size_fixed = fit_header(ColumnKind::Size.header(), 10); // "1023.9 MiB"
+    let base_fixed = fit_header(ColumnKind::Base.header(), 20); // "rewritten release/1.2"
+    let hooks_fixed = fit_header(ColumnKind::Hooks.header(), 12); // "✗ install +1"
+    let stash_fixed = fit_header(ColumnKind::Stash.header(), 2); // "12"
     // CI column: PR/MR reference ("#3035"), sized from the cached largest
     // number seen; "#9999" before the first fetch populates the cache. A
     // number that outgrows the estimate renders as the bare `#` indicator
@@ -668,7 +701,7 @@ fn build_estimated_widths(
     //
     // Exceptions that we can compute instantly from items:
     // - path: true only if any worktree has branch_worktree_mismatch
-    // - branch_diff/ci_status: false if their task isn't in the run plan
+    // - branch_diff/ci_status/base/hooks/stash/size: false if their task isn't in the run plan
     let data_flags = ColumnDataFlags {
         status: true,
         working_diff: true,
@@ -677,6 +710,10 @@ fn build_estimated_widths(
         upstream: true,
         url: tasks.contains(&TaskKind::UrlStatus),
         ci_status: tasks.contains(&TaskKind::CiStatus),
+        base: tasks.contains(&TaskKind::BaseDrift),
+        hooks: tasks.contains(&TaskKind::HookStatus),
+        stash: tasks.contains(&TaskKind::StashCount),
+        size: tasks.contains(&TaskKind::DiskUsage),
         path: has_branch_worktree_mismatch,
     };
 
@@ -694,6 +731,10 @@ fn build_estimated_widths(
         time: age_estimate,
         url: url_estimate,
         ci_status: ci_estimate,
+        base: base_fixed,
+        hooks: hooks_fixed,
+        stash: stash_fixed,
+        size: size_fixed,
         // Commit counts (Arrows): compact notation, 2 digits covers up to 99
         ahead_behind: DiffWidths {
             total: ahead_behind_fixed,
@@ -764,6 +805,7 @@ fn allocate_columns_with_priority(
     let ColumnSelection {
         custom: custom_columns,
         selected,
+        ..
     } = columns;
     let spacing = 2;
     let mut remaining = terminal_width;
@@ -1068,35 +1110,43 @@ pub fn calculate_layout_with_width(
         .max_by_key(|b| b.width());
 
     let max_branch = longest_branch.map(|b| b.width()).unwrap_or(0);
+    let max_branch = max_branch.min(columns.branch_width.unwrap_or(usize::MAX));
     let max_branch = fit_header(ColumnKind::Branch.header(), max_branch);
 
     let path_data_width = items
         .iter()
-        .filter_map(|item| item.worktree_path())
-        .map(|path| shorten_path(path.as_path(), main_worktree_path).width())
+        .filter_map(|item| item.worktree_data())
+        .map(|data| data.display_path(main_worktree_path).width())
         .max()
         .unwrap_or(0);
+    let path_data_width = path_data_width.min(columns.path_width.unwrap_or(usize::MAX));
     let max_path_width = fit_header(ColumnKind::Path.header(), path_data_width);
 
     // Check if any worktree has a branch-worktree mismatch.
-    // Path column is only useful when there's a mismatch; otherwise it's redundant with branch.
+    // Path column is only useful when there's a mismatch (or `--tree` asked
+    // for it); otherwise it's redundant with branch.
     let has_branch_worktree_mismatch = items
         .iter()
         .filter_map(|item| item.worktree_data())
-        .any(|data| data.branch_worktree_mismatch);
+        .any(|data| data.branch_worktree_mismatch || data.tree_path.is_some());
 
     // Estimate URL width from template (heuristic, no expansion needed)
     let url_width = estimate_url_width(url_template, supports_hyperlinks(Stream::Stdout));
 
-    // Custom column widths are measured, not estimated: values were expanded
-    // before layout. A column empty on every row stays 0 and is excluded.
+    // Template column widths are measured, not estimated: values were
+    // expanded before layout. A column empty on every row stays 0 and is
+    // excluded. Command columns reserve their configured width — their
+    // values arrive later.
     let custom_widths: Vec<usize> = custom_columns
         .iter()
         .enumerate()
         .map(|(i, column)| {
+            if column.is_command() {
+                return fit_header(&column.name, column.max_width);
+            }
             let widest_value = items
                 .iter()
-                .filter_map(|item| item.custom_values.get(i))
+                .filter_map(|item| item.custom_values.get(i)?.as_deref())
                 .map(|value| value.width())
                 .max()
                 .unwrap_or(0);
@@ -1134,6 +1184,7 @@ pub fn calculate_layout_with_width(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::commands::list::custom_columns::CustomColumnSource;
     use crate::display::terminal_width;
     use worktrunk::git::LineDiff;
 
@@ -1213,6 +1264,10 @@ mod tests {
             upstream: true,
             url: true,
             ci_status: true,
+            base: true,
+            hooks: true,
+            stash: true,
+            size: true,
             path: true,
         };
         let all_false = ColumnDataFlags {
@@ -1223,6 +1278,10 @@ mod tests {
             upstream: false,
             url: false,
             ci_status: false,
+            base: false,
+            hooks: false,
+            stash: false,
+            size: false,
             path: false,
         };
 
@@ -1248,6 +1307,14 @@ mod tests {
         assert!(!ColumnKind::Url.has_data(&all_false));
         assert!(ColumnKind::CiStatus.has_data(&all_true));
         assert!(!ColumnKind::CiStatus.has_data(&all_false));
+        assert!(ColumnKind::Base.has_data(&all_true));
+        assert!(!ColumnKind::Base.has_data(&all_false));
+        assert!(ColumnKind::Hooks.has_data(&all_true));
+        assert!(!ColumnKind::Hooks.has_data(&all_false));
+        assert!(ColumnKind::Stash.has_data(&all_true));
+        assert!(!ColumnKind::Stash.has_data(&all_false));
+        assert!(ColumnKind::Size.has_data(&all_true));
+        assert!(!ColumnKind::Size.has_data(&all_false));
         assert!(ColumnKind::Path.has_data(&all_true));
         assert!(!ColumnKind::Path.has_data(&all_false));
     }
@@ -1288,6 +1355,10 @@ mod tests {
             time: 4,
             url: 0,
             ci_status: 2,
+            base: 20,
+            hooks: 12,
+            stash: 5,
+            size: 10,
             ahead_behind: DiffWidths {
                 total: 7,
                 positive_digits: 2,
@@ -1356,6 +1427,10 @@ mod tests {
             time: 0,
             url: 0,
             ci_status: 0,
+            base: 0,
+            hooks: 0,
+            stash: 0,
+            size: 0,
             ahead_behind: DiffWidths {
                 total: 0,
                 positive_digits: 0,
@@ -1496,12 +1571,18 @@ mod tests {
             is_patch_id_match: None,
             is_ancestor: None,
             is_orphan: None,
+            merge_base: None,
             upstream: Some(UpstreamStatus {
                 remote: Some("origin".to_string()),
+                tracking: None,
                 ahead: 4,
                 behind: 2,
+                gone: None,
             }),
             pr_status: None,
+            base_drift: None,
+            hook_status: None,
+            stash_count: None,
             url: None,
             url_active: None,
             summary: None,
@@ -1517,13 +1598,15 @@ mod tests {
                 prunable: None,
                 working_tree_diff: Some(LineDiff::from((100, 50))),
                 working_tree_status: None,
-                has_conflicts: None,
+                conflicted_files: None,
                 has_working_tree_conflicts: None,
                 git_operation: Some(ActiveGitOperation::None),
+                disk_usage: None,
                 is_main: false,
                 is_current: false,
                 is_previous: false,
                 branch_worktree_mismatch: false,
+                tree_path: None,
             })),
         };
 
@@ -1540,6 +1623,7 @@ mod tests {
             ColumnSelection {
                 custom: &[],
                 selected: None,
+                ..Default::default()
             },
         );
 
@@ -1615,8 +1699,12 @@ mod tests {
             is_patch_id_match: None,
             is_ancestor: None,
             is_orphan: None,
+            merge_base: None,
             upstream: Some(UpstreamStatus::default()),
             pr_status: None,
+            base_drift: None,
+            hook_status: None,
+            stash_count: None,
             url: None,
             url_active: None,
             summary: None,
@@ -1632,13 +1720,15 @@ mod tests {
                 prunable: None,
                 working_tree_diff: Some(LineDiff::default()),
                 working_tree_status: None,
-                has_conflicts: None,
+                conflicted_files: None,
                 has_working_tree_conflicts: None,
                 git_operation: Some(ActiveGitOperation::None),
+                disk_usage: None,
                 is_main: true, // Primary worktree: no ahead/behind shown
                 is_current: false,
                 is_previous: false,
                 branch_worktree_mismatch: false,
+                tree_path: None,
             })),
         };
 
@@ -1655,6 +1745,7 @@ mod tests {
             ColumnSelection {
                 custom: &[],
                 selected: None,
+                ..Default::default()
             },
         );
 
@@ -1744,8 +1835,12 @@ mod tests {
             is_patch_id_match: None,
             is_ancestor: None,
             is_orphan: None,
+            merge_base: None,
             upstream: None,
             pr_status: None,
+            base_drift: None,
+            hook_status: None,
+            stash_count: None,
             url: None,
             url_active: None,
             summary: None,
@@ -1761,13 +1856,15 @@ mod tests {
                 prunable: None,
                 working_tree_diff: None,
                 working_tree_status: None,
-                has_conflicts: None,
+                conflicted_files: None,
                 has_working_tree_conflicts: None,
                 git_operation: Some(ActiveGitOperation::None),
+                disk_usage: None,
                 is_main: false,
                 is_current: false,
                 is_previous: false,
                 branch_worktree_mismatch: false,
+                tree_path: None,
             })),
         }
     }
@@ -1785,6 +1882,7 @@ mod tests {
             ColumnSelection {
                 custom: &[],
                 selected: None,
+                ..Default::default()
             },
         )
     }
@@ -1804,7 +1902,8 @@ mod tests {
 
     /// Full mode: every task runs.
     fn full_run_tasks() -> HashSet<TaskKind> {
-        run_except(&[])
+        // `--size` is opt-in on its own; `--full` doesn't imply it.
+        run_except(&[TaskKind::DiskUsage])
     }
 
     fn find_column(layout: &LayoutConfig, kind: ColumnKind) -> Option<&ColumnLayout> {
@@ -1828,6 +1927,7 @@ mod tests {
             ColumnSelection {
                 custom: &[],
                 selected: Some(&selected),
+                ..Default::default()
             },
         );
 
@@ -1855,18 +1955,18 @@ mod tests {
         // named custom renders interleaved at its configured position, while a
         // custom omitted from a non-empty selection is hidden.
         let mut item = make_test_item("feature-branch");
-        item.custom_values = vec!["TICKET-1".to_string(), "max".to_string()];
+        item.custom_values = vec![Some("TICKET-1".to_string()), Some("max".to_string())];
         let items = vec![item];
         let custom = [
             ResolvedCustomColumn {
                 name: "Ticket".to_string(),
-                template: "{{ vars.ticket }}".to_string(),
+                source: CustomColumnSource::Template("{{ vars.ticket }}".to_string()),
                 max_width: 40,
                 priority: 9,
             },
             ResolvedCustomColumn {
                 name: "Owner".to_string(),
-                template: "{{ vars.owner }}".to_string(),
+                source: CustomColumnSource::Template("{{ vars.owner }}".to_string()),
                 max_width: 40,
                 priority: 9,
             },
@@ -1887,6 +1987,7 @@ mod tests {
             ColumnSelection {
                 custom: &custom,
                 selected: Some(&selected),
+                ..Default::default()
             },
         );
 
@@ -1912,11 +2013,11 @@ mod tests {
         // Without a selection (the default set), every custom column appends in
         // resolution order — the selection filter only applies when present.
         let mut item = make_test_item("feature-branch");
-        item.custom_values = vec!["TICKET-1".to_string()];
+        item.custom_values = vec![Some("TICKET-1".to_string())];
         let items = vec![item];
         let custom = [ResolvedCustomColumn {
             name: "Ticket".to_string(),
-            template: "{{ vars.ticket }}".to_string(),
+            source: CustomColumnSource::Template("{{ vars.ticket }}".to_string()),
             max_width: 40,
             priority: 9,
         }];
@@ -1930,6 +2031,7 @@ mod tests {
             ColumnSelection {
                 custom: &custom,
                 selected: None,
+                ..Default::default()
             },
         );
         assert!(
@@ -1960,6 +2062,7 @@ mod tests {
             ColumnSelection {
                 custom: &[],
                 selected: Some(&selected),
+                ..Default::default()
             },
         );
         assert!(
@@ -1980,6 +2083,7 @@ mod tests {
             ColumnSelection {
                 custom: &[],
                 selected: Some(&selected),
+                ..Default::default()
             },
         );
         assert!(
@@ -2005,6 +2109,7 @@ mod tests {
             ColumnSelection {
                 custom: &[],
                 selected: Some(&selected),
+                ..Default::default()
             },
         );
         assert!(
@@ -2211,8 +2316,12 @@ mod tests {
             is_patch_id_match: None,
             is_ancestor: None,
             is_orphan: None,
+            merge_base: None,
             upstream: None,
             pr_status: None,
+            base_drift: None,
+            hook_status: None,
+            stash_count: None,
             url: None,
             url_active: None,
             summary: None,
@@ -2228,13 +2337,15 @@ mod tests {
                 prunable: None,
                 working_tree_diff: None,
                 working_tree_status: None,
-                has_conflicts: None,
+                conflicted_files: None,
                 has_working_tree_conflicts: None,
                 git_operation: Some(ActiveGitOperation::None),
+                disk_usage: None,
                 is_main: false,
                 is_current: false,
                 is_previous: false,
                 branch_worktree_mismatch: false,
+                tree_path: None,
             })),
         }
     }
@@ -2278,6 +2389,7 @@ mod tests {
             ColumnSelection {
                 custom: &[],
                 selected: None,
+                ..Default::default()
             },
         );
         assert!(
@@ -2302,6 +2414,7 @@ mod tests {
             ColumnSelection {
                 custom: &[],
                 selected: None,
+                ..Default::default()
             },
         );
         let summary_170 = find_column(&layout_170, ColumnKind::Summary)
@@ -2346,7 +2459,9 @@ mod tests {
             });
             let upstream_status = upstream.then(|| UpstreamStatus {
                 remote: Some("origin".to_string()),
+                tracking: None,
                 ahead: 0,
+                gone: None,
                 behind: 0,
             });
             super::super::model::ListItem {
@@ -2365,8 +2480,12 @@ mod tests {
                 is_patch_id_match: None,
                 is_ancestor: None,
                 is_orphan: None,
+                merge_base: None,
                 upstream: upstream_status,
                 pr_status: Some(None), // loaded, no CI
+                base_drift: Some(None),
+                hook_status: Some(Vec::new()),
+                stash_count: Some(0),
                 url: None,
                 url_active: None,
                 summary: Some(summary.map(|s| s.to_string())),
@@ -2382,13 +2501,15 @@ mod tests {
                     prunable: None,
                     working_tree_diff: Some(LineDiff::default()),
                     working_tree_status: None,
-                    has_conflicts: None,
+                    conflicted_files: None,
                     has_working_tree_conflicts: None,
                     git_operation: Some(ActiveGitOperation::None),
+                    disk_usage: None,
                     is_main,
                     is_current,
                     is_previous: false,
                     branch_worktree_mismatch: false,
+                    tree_path: None,
                 })),
             }
         };
@@ -2452,6 +2573,7 @@ mod tests {
             ColumnSelection {
                 custom: &[],
                 selected: None,
+                ..Default::default()
             },
         );
 
@@ -2460,7 +2582,7 @@ mod tests {
         for item in &items {
             lines.push(
                 layout
-                    .render_list_item_line(item, super::super::render::PLACEHOLDER)
+                    .render_list_item_line(item, super::super::render::PLACEHOLDER.as_str())
                     .plain_text(),
             );
         }
@@ -2490,6 +2612,7 @@ mod tests {
             ColumnSelection {
                 custom: &[],
                 selected: None,
+                ..Default::default()
             },
         );
         let branch = find_column(&layout, ColumnKind::Branch);
@@ -2514,6 +2637,7 @@ mod tests {
             ColumnSelection {
                 custom: &[],
                 selected: None,
+                ..Default::default()
             },
         );
         let branch = find_column(&layout, ColumnKind::Branch).unwrap();
@@ -2552,7 +2676,7 @@ mod tests {
     fn custom_column(name: &str, max_width: usize, priority: u8) -> ResolvedCustomColumn {
         ResolvedCustomColumn {
             name: name.to_string(),
-            template: String::new(),
+            source: CustomColumnSource::Template(String::new()),
             max_width,
             priority,
         }
@@ -2565,7 +2689,10 @@ mod tests {
             custom_column("WideHeaderName", 40, 9),
         ];
         let mut item = make_test_item("feature");
-        item.custom_values = vec!["JIRA-1234-overflows".to_string(), "x".to_string()];
+        item.custom_values = vec![
+            Some("JIRA-1234-overflows".to_string()),
+            Some("x".to_string()),
+        ];
         let items = vec![item];
 
         let layout = calculate_layout_with_width(
@@ -2578,6 +2705,7 @@ mod tests {
             ColumnSelection {
                 custom: &columns,
                 selected: None,
+                ..Default::default()
             },
         );
 
@@ -2601,7 +2729,7 @@ mod tests {
     fn test_custom_column_empty_everywhere_is_excluded() {
         let columns = [custom_column("Ticket", 40, 9)];
         let mut item = make_test_item("feature");
-        item.custom_values = vec![String::new()];
+        item.custom_values = vec![Some(String::new())];
         let items = vec![item];
 
         // Drop UrlStatus from the plan like collect does when no URL template
@@ -2620,6 +2748,7 @@ mod tests {
             ColumnSelection {
                 custom: &columns,
                 selected: None,
+                ..Default::default()
             },
         );
 
@@ -2633,7 +2762,7 @@ mod tests {
     fn test_custom_column_dropped_on_narrow_terminal() {
         let columns = [custom_column("Ticket", 40, 9)];
         let mut item = make_test_item("feature");
-        item.custom_values = vec!["JIRA-1234".to_string()];
+        item.custom_values = vec![Some("JIRA-1234".to_string())];
         let items = vec![item];
 
         let narrow = calculate_layout_with_width(
@@ -2646,6 +2775,7 @@ mod tests {
             ColumnSelection {
                 custom: &columns,
                 selected: None,
+                ..Default::default()
             },
         );
 
@@ -114,10 +114,12 @@
 //! 3. `git diff --shortstat` - Slow for large diffs or when pack files aren't cached
 //!
 //! Optimization tips:
-//! - Run `git commit-graph write --reachable --changed-paths` to speed up commit counting
+//! - Run `wt step optimize-repo` (commit-graph + multi-pack-index) to speed up commit counting
 //! - Run `git gc` periodically to consolidate objects into pack files
 //! - Minimize uncommitted changes across worktrees (each dirty worktree adds diff overhead)
 
+mod authorship;
+mod changes;
 pub mod ci_status;
 pub(crate) mod collect;
 pub(crate) mod columns;
@@ -125,31 +127,65 @@ pub(crate) mod custom_columns;
 pub mod json_output;
 pub(crate) mod layout;
 pub mod model;
+mod path_tree;
+mod porcelain;
 pub mod progressive;
 mod progressive_table;
 pub(crate) mod render;
+mod summary;
+
+use std::fs;
+use std::io::Write as _;
+use std::path::Path;
 
 // Layout is calculated in collect/mod.rs
 use anstyle::Style;
 use anyhow::Context;
+use color_print::cformat;
 use model::{ListData, ListItem};
 use progressive::RenderTarget;
-use worktrunk::git::Repository;
-use worktrunk::styling::INFO_SYMBOL;
+use worktrunk::git::{ListIndexEntry, ObjectStore, Repository};
+use worktrunk::progress::format_bytes;
+use worktrunk::styling::{INFO_SYMBOL, eprintln, info_message};
+
+use crate::commands::repo_group::GroupRepo;
 
 // Re-export for statusline and other consumers
 pub use collect::{CollectOptions, build_worktree_item, populate_item};
 pub use model::StatuslineSegment;
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_list(
     repo: Repository,
     format: crate::OutputFormat,
     cli_branches: bool,
     cli_remotes: bool,
     cli_full: bool,
+    cli_size: bool,
+    cli_tree: bool,
+    cli_only_mine: bool,
     progressive_flag: Option<bool>,
+    output: Option<&Path>,
+    atomic: bool,
+    changed: bool,
+    porcelain: bool,
+    summary: bool,
 ) -> anyhow::Result<()> {
-    let render_target = RenderTarget::detect(format, progressive_flag);
+    if output.is_some() && !matches!(format, crate::OutputFormat::Json) {
+        anyhow::bail!("--output requires --format=json");
+    }
+    // `--changed`, `--porcelain`, and `--summary` print their own output, so
+    // they collect without a table.
+    let render_target = if changed || porcelain || summary {
+        RenderTarget::Json
+    } else {
+        RenderTarget::detect(format, progressive_flag)
+    };
+    // Ask about project column commands before the table takes over the
+    // terminal; scripted output skips unapproved columns without asking.
+    if matches!(render_target, RenderTarget::Table { .. }) {
+        crate::commands::command_approval::approve_list_columns(&repo)?;
+    }
 
     let list_data = collect::collect(
         &repo,
@@ -157,6 +193,9 @@ pub fn handle_list(
             cli_branches,
             cli_remotes,
             cli_full,
+            cli_size,
+            cli_tree,
+            cli_only_mine,
         },
         render_target,
     )?;
@@ -169,14 +208,161 @@ pub fn handle_list(
         return Ok(());
     };
 
-    if matches!(render_target, RenderTarget::Json) {
+    if changed {
+        let json_items = json_output::to_json_items(&items, &custom_columns, &repo);
+        let previous = changes::load_snapshot(&repo);
+        changes::store_snapshot(&repo, &json_items);
+        let changes = changes::diff(&previous, &json_items);
+        if matches!(format, crate::OutputFormat::Json) {
+            let json =
+                serde_json::to_string_pretty(&changes).context("Failed to serialize to JSON")?;
+            match output {
+                Some(path) => write_output_file(path, &format!("{json}\n"), atomic)?,
+                None => println!("{}", json),
+            }
+        } else if changes.is_empty() {
+            eprintln!(
+                "{}",
+                info_message(cformat!(
+                    "No changes since the last <bright-black>wt list --changed</>"
+                ))
+            );
+        } else {
+            for change in &changes {
+                worktrunk::styling::println!("{}", changes::format_change(change));
+            }
+        }
+    } else if summary {
+        let json_items = json_output::to_json_items(&items, &custom_columns, &repo);
+        let default_branch = repo.default_branch().unwrap_or_else(|| "main".to_string());
+        println!("{}", summary::format_summary(&json_items, &default_branch));
+    } else if porcelain {
+        for item in json_output::to_json_items(&items, &custom_columns, &repo) {
+            println!("{}", porcelain::format_line(&item));
+        }
+    } else if matches!(render_target, RenderTarget::Json) {
         let json_items = json_output::to_json_items(&items, &custom_columns, &repo);
         let json =
             serde_json::to_string_pretty(&json_items).context("Failed to serialize to JSON")?;
+        match output {
+            Some(path) => write_output_file(path, &format!("{json}\n"), atomic)?,
+            None => println!("{}", json),
+        }
+    } else {
+        // Table modes already rendered inside `collect()`; record the row
+        // order so `wt switch %N` can refer back to it.
+        let rows: Vec<ListIndexEntry> = items
+            .iter()
+            .map(|item| ListIndexEntry {
+                branch: item.branch.clone(),
+                path: item.worktree_path().cloned(),
+            })
+            .collect();
+        repo.set_last_listing(&rows);
+    }
+
+    Ok(())
+}
+
+/// Handle `wt list --group <NAME>`: a table per repository of the group, each
+/// under a heading, or one JSON object per repository wrapping its rows.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_list_group(
+    group: &str,
+    format: crate::OutputFormat,
+    cli_branches: bool,
+    cli_remotes: bool,
+    cli_full: bool,
+    cli_size: bool,
+    cli_tree: bool,
+    cli_only_mine: bool,
+    progressive_flag: Option<bool>,
+) -> anyhow::Result<()> {
+    let config = worktrunk::config::UserConfig::load()?;
+    let repos = crate::commands::repo_group::open_repo_group(&config, group)?;
+
+    if matches!(format, crate::OutputFormat::Json) {
+        let mut groups = Vec::new();
+        for GroupRepo { name, repo } in repos {
+            let show = collect::ShowConfig::DeferredToParallel {
+                cli_branches,
+                cli_remotes,
+                cli_full,
+                cli_size,
+                cli_tree,
+                cli_only_mine,
+            };
+            let worktrees = match collect::collect(&repo, show, RenderTarget::Json)? {
+                Some(ListData {
+                    items,
+                    custom_columns,
+                }) => json_output::to_json_items(&items, &custom_columns, &repo),
+                None => Vec::new(),
+            };
+            groups.push(serde_json::json!({
+                "repo": name,
+                "path": repo.repo_path()?,
+                "worktrees": worktrees,
+            }));
+        }
+        let json = serde_json::to_string_pretty(&groups).context("Failed to serialize to JSON")?;
         println!("{}", json);
+        return Ok(());
     }
-    // Table modes already rendered inside `collect()`.
 
+    for (i, GroupRepo { name, repo }) in repos.into_iter().enumerate() {
+        if i > 0 {
+            worktrunk::styling::println!();
+        }
+        worktrunk::styling::println!(
+            "{}",
+            cformat!(
+                "<bold>{name}</> <bright-black>{}</>",
+                worktrunk::path::format_path_for_display(repo.repo_path()?)
+            )
+        );
+        handle_list(
+            repo,
+            format,
+            cli_branches,
+            cli_remotes,
+            cli_full,
+            cli_size,
+            cli_tree,
+            cli_only_mine,
+            progressive_flag,
+            None,
+            false,
+            false,
+            false,
+            false,
+        )?;
+    }
+    Ok(())
+}
+
+/// Write `--output`, optionally via a temp file renamed over `path` so a
+/// concurrent reader sees either the old snapshot or the new one.
+fn write_output_file(path: &Path, contents: &str, atomic: bool) -> anyhow::Result<()> {
+    let display = path.display();
+    if !atomic {
+        return fs::write(path, contents).with_context(|| format!("Failed to write {display}"));
+    }
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let mut temp = tempfile::Builder::new()
+        .prefix(".wt-list.")
+        .suffix(".tmp")
+        .tempfile_in(parent)
+        .with_context(|| format!("Failed to create temporary file for {display}"))?;
+    temp.write_all(contents.as_bytes())
+        .and_then(|()| temp.as_file().sync_all())
+        .with_context(|| format!("Failed to write temporary file for {display}"))?;
+    temp.persist(path)
+        .map_err(|e| e.error)
+        .with_context(|| format!("Failed to replace {display}"))?;
     Ok(())
 }
 
@@ -268,18 +454,33 @@ impl SummaryMetrics {
 }
 
 /// Format a summary message for the given items (used by both collect/mod.rs and mod.rs)
+///
+/// `object_store` is set with `--size`: every worktree shares it, so it's
+/// reported once here rather than per row.
 pub(crate) fn format_summary_message(
     items: &[ListItem],
     show_branches: bool,
     hidden_column_count: usize,
+    object_store: Option<&ObjectStore>,
     error_count: usize,
     timed_out_count: usize,
 ) -> String {
     let metrics = SummaryMetrics::from_items(items);
     let dim = Style::new().dimmed();
-    let summary = metrics
+    let mut summary = metrics
         .summary_parts(show_branches, hidden_column_count)
         .join(", ");
+    if let Some(store) = object_store {
+        summary.push_str(&format!(". Shared objects: {}", format_bytes(store.bytes)));
+        if !store.alternates.is_empty() {
+            let alternates: Vec<_> = store
+                .alternates
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect();
+            summary.push_str(&format!(", plus alternates at {}", alternates.join(", ")));
+        }
+    }
 
     if error_count > 0 {
         let failure_msg = if error_count == timed_out_count {
@@ -440,16 +641,34 @@ mod tests {
         use insta::assert_snapshot;
 
         // No errors
-        assert_snapshot!(format_summary_message(&[], false, 0, 0, 0), @"[2m○[22m [2mShowing 0 worktrees[0m");
+        assert_snapshot!(format_summary_message(&[], false, 0, None, 0, 0), @"[2m○[22m [2mShowing 0 worktrees[0m");
         // All timeouts
-        assert_snapshot!(format_summary_message(&[], false, 0, 3, 3), @"[2m○[22m [2mShowing 0 worktrees. 3 tasks timed out[0m");
+        assert_snapshot!(format_summary_message(&[], false, 0, None, 3, 3), @"[2m○[22m [2mShowing 0 worktrees. 3 tasks timed out[0m");
         // Mixed errors and timeouts
-        assert_snapshot!(format_summary_message(&[], false, 0, 5, 3), @"[2m○[22m [2mShowing 0 worktrees. 5 tasks failed (3 timed out)[0m");
+        assert_snapshot!(format_summary_message(&[], false, 0, None, 5, 3), @"[2m○[22m [2mShowing 0 worktrees. 5 tasks failed (3 timed out)[0m");
         // Only failures, no timeouts
-        assert_snapshot!(format_summary_message(&[], false, 0, 2, 0), @"[2m○[22m [2mShowing 0 worktrees. 2 tasks failed[0m");
+        assert_snapshot!(format_summary_message(&[], false, 0, None, 2, 0), @"[2m○[22m [2mShowing 0 worktrees. 2 tasks failed[0m");
         // Single error
-        assert_snapshot!(format_summary_message(&[], false, 0, 1, 0), @"[2m○[22m [2mShowing 0 worktrees. 1 task failed[0m");
+        assert_snapshot!(format_summary_message(&[], false, 0, None, 1, 0), @"[2m○[22m [2mShowing 0 worktrees. 1 task failed[0m");
         // Single timeout
-        assert_snapshot!(format_summary_message(&[], false, 0, 1, 1), @"[2m○[22m [2mShowing 0 worktrees. 1 task timed out[0m");
+        assert_snapshot!(format_summary_message(&[], false, 0, None, 1, 1), @"[2m○[22m [2mShowing 0 worktrees. 1 task timed out[0m");
+    }
+
+    #[test]
+    fn test_format_summary_message_object_store() {
+        use insta::assert_snapshot;
+        use std::path::PathBuf;
+
+        let store = ObjectStore {
+            bytes: 3 * 1024 * 1024,
+            alternates: Vec::new(),
+        };
+        assert_snapshot!(format_summary_message(&[], false, 0, Some(&store), 0, 0), @"[2m○[22m [2mShowing 0 worktrees. Shared objects: 3.0 MiB[0m");
+
+        let borrowed = ObjectStore {
+            bytes: 512 * 1024,
+            alternates: vec![PathBuf::from("/srv/mirror/objects")],
+        };
+        assert_snapshot!(format_summary_message(&[], false, 0, Some(&borrowed), 1, 0), @"[2m○[22m [2mShowing 0 worktrees. Shared objects: 512.0 KiB, plus alternates at /srv/mirror/objects. 1 task failed[0m");
     }
 }
@@ -3,13 +3,16 @@
 //! This module contains the main data structures used to represent
 //! worktrees and branches in `wt list` output.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use worktrunk::git::{IntegrationReason, IntegrationSignals, LineDiff, check_integration};
+use worktrunk::git::{
+    BaseDrift, BranchBase, IntegrationReason, IntegrationSignals, LineDiff, check_integration,
+};
 
 use super::state::{ActiveGitOperation, Divergence, MainState, OperationState, WorktreeState};
 use super::stats::{AheadBehind, BranchDiffTotals, CommitDetails, UpstreamStatus};
 use super::status_symbols::{StatusSymbols, WorkingTreeStatus};
+use crate::commands::hook_status::PipelineRecord;
 use crate::commands::list::ci_status::PrStatus;
 use crate::commands::list::columns::ColumnKind;
 
@@ -41,9 +44,10 @@ pub struct WorktreeData {
     /// Working-tree change flags (tracked/untracked/modified). `None` = not yet
     /// loaded; `Some` = loaded (possibly empty). Fed by the `WorkingTreeDiff` task.
     pub working_tree_status: Option<WorkingTreeStatus>,
-    /// Whether the working tree has merge conflicts in tracked files. `None` =
-    /// not yet loaded; `Some` = loaded. Fed by the `WorkingTreeDiff` task.
-    pub has_conflicts: Option<bool>,
+    /// Number of tracked files with unresolved merge conflicts. `None` = not
+    /// yet loaded; `Some(0)` = loaded, no conflicts. Fed by the
+    /// `WorkingTreeDiff` task.
+    pub conflicted_files: Option<usize>,
     /// Result of `WorkingTreeConflicts` task (`--full` mode only). Outer `None`
     /// = task hasn't run yet. Outer `Some(None)` = task ran but working tree
     /// was clean, so fall back to the committed-HEAD merge-tree check.
@@ -53,6 +57,9 @@ pub struct WorktreeData {
     /// `Some(ActiveGitOperation::None)` = loaded, no operation in progress.
     /// Fed by the `GitOperation` task.
     pub git_operation: Option<ActiveGitOperation>,
+    /// Bytes on disk for the checked-out files. `None` = not loaded (only
+    /// computed with `--size`). Fed by the `DiskUsage` task.
+    pub disk_usage: Option<u64>,
     pub is_main: bool,
     /// Whether this is the current worktree (matches repo discovery path: PWD or `-C`)
     pub is_current: bool,
@@ -61,6 +68,9 @@ pub struct WorktreeData {
     /// Whether the worktree is at an unexpected location (branch-worktree mismatch).
     /// Only true when: has branch name, not main worktree, and path differs from template.
     pub branch_worktree_mismatch: bool,
+    /// Path as rendered by `wt list --tree`, with the directory prefix it
+    /// shares with the row above blanked out. `None` outside tree mode.
+    pub tree_path: Option<String>,
 }
 
 impl WorktreeData {
@@ -69,6 +79,14 @@ impl WorktreeData {
         self.prunable.is_some()
     }
 
+    /// Path column text: the tree label under `--tree`, else the path
+    /// shortened relative to the main worktree.
+    pub fn display_path(&self, main_worktree_path: &Path) -> String {
+        self.tree_path
+            .clone()
+            .unwrap_or_else(|| crate::display::shorten_path(&self.path, main_worktree_path))
+    }
+
     /// This worktree's location on the gutter's presence axis (see
     /// [`WorktreePresence`]). `is_current` wins when a worktree is both
     /// current and primary — you're sitting in the main worktree.
@@ -248,12 +266,27 @@ pub struct ListItem {
     /// Whether this branch is an orphan (no common ancestor with default branch).
     /// Orphan branches have independent history and can't compute meaningful ahead/behind counts.
     pub is_orphan: Option<bool>,
+    /// Merge-base with the default branch, delivered with `counts`. `None`
+    /// until loaded, for orphans, and when no default branch resolves.
+    pub merge_base: Option<String>,
 
     pub upstream: Option<UpstreamStatus>,
 
     /// CI/PR status (inner Option: whether CI exists for this branch)
     pub pr_status: Option<Option<PrStatus>>,
 
+    /// Drift of the branch's recorded base (inner Option: whether the base
+    /// moved). Fed by the `BaseDrift` task (`--full`).
+    pub base_drift: Option<Option<(BranchBase, BaseDrift)>>,
+
+    /// Last recorded background hook runs (empty when none). Fed by the
+    /// `HookStatus` task (`--full`).
+    pub hook_status: Option<Vec<PipelineRecord>>,
+
+    /// Stash entries made on the branch. Fed by the `StashCount` task
+    /// (`--full`).
+    pub stash_count: Option<usize>,
+
     /// Dev server URL computed from project config template
     pub url: Option<String>,
     /// Whether the URL's port is actively listening
@@ -285,12 +318,12 @@ pub struct ListItem {
     /// `JsonItem` for the `statusline` field.
     pub statusline: Option<String>,
 
-    /// Rendered `[list.custom-columns]` values, indexed like the resolved column
-    /// list (`ColumnKind::Custom`). Unlike the `Option`-gated fields above,
-    /// these are final at construction time: templates expand from in-memory
-    /// data before layout, so there is no loading state. Empty when no custom
+    /// Rendered custom column values, indexed like the resolved column list
+    /// (`ColumnKind::Custom`). Template values are final at construction time
+    /// — they expand from in-memory data before layout. Command values are
+    /// `None` until the `CustomColumns` task reports. Empty when no custom
     /// columns are configured.
-    pub custom_values: Vec<String>,
+    pub custom_values: Vec<Option<String>>,
 
     // Type-specific data (worktree vs branch)
     pub kind: ItemKind,
@@ -299,8 +332,8 @@ pub struct ListItem {
 /// Container for list command results.
 pub struct ListData {
     pub items: Vec<ListItem>,
-    /// Resolved `[list.custom-columns]` definitions; each item's `custom_values`
-    /// uses the same indexing.
+    /// Resolved custom column definitions; each item's `custom_values` uses
+    /// the same indexing.
     pub custom_columns: Vec<crate::commands::list::custom_columns::ResolvedCustomColumn>,
 }
 
@@ -329,8 +362,12 @@ impl ListItem {
             is_patch_id_match: None,
             is_ancestor: None,
             is_orphan: None,
+            merge_base: None,
             upstream: None,
             pr_status: None,
+            base_drift: None,
+            hook_status: None,
+            stash_count: None,
             url: None,
             url_active: None,
             summary: None,
@@ -609,20 +646,21 @@ impl ListItem {
         }
     }
 
-    /// Gate 2: operation state. Resolves once both `has_conflicts` and
+    /// Gate 2: operation state. Resolves once both `conflicted_files` and
     /// `git_operation` have reported. Priority within the gate:
-    /// `has_conflicts` > rebase > merge > none.
+    /// conflicts > rebase > merge > none.
     fn try_gate_operation_state(&self) -> Option<OperationState> {
         match &self.kind {
             ItemKind::Worktree(data) => {
-                let has_conflicts = data.has_conflicts?;
-                if has_conflicts {
-                    return Some(OperationState::Conflicts);
+                let conflicted_files = data.conflicted_files?;
+                if conflicted_files > 0 {
+                    return Some(OperationState::Conflicts(conflicted_files));
                 }
                 let git_operation = data.git_operation.as_ref()?;
                 match git_operation {
                     ActiveGitOperation::Rebase => Some(OperationState::Rebase),
                     ActiveGitOperation::Merge => Some(OperationState::Merge),
+                    ActiveGitOperation::InterruptedMerge => Some(OperationState::InterruptedMerge),
                     ActiveGitOperation::None => Some(OperationState::None),
                 }
             }
@@ -740,6 +778,7 @@ impl ListItem {
         let upstream = self.upstream.as_ref()?;
         Some(match upstream.active() {
             Some(active) => Divergence::from_counts_with_remote(active.ahead, active.behind),
+            None if upstream.gone().is_some() => Divergence::Gone,
             None => Divergence::None,
         })
     }
@@ -1010,27 +1049,27 @@ mod tests {
 
     #[test]
     fn gate_operation_state_short_circuits_on_conflicts() {
-        // `has_conflicts = Some(true)` fires the gate immediately without
-        // waiting for `git_operation`.
+        // `conflicted_files = Some(n > 0)` fires the gate immediately
+        // without waiting for `git_operation`, carrying the count.
         let mut item = make_worktree_item();
         if let ItemKind::Worktree(ref mut data) = item.kind {
-            data.has_conflicts = Some(true);
+            data.conflicted_files = Some(3);
             // git_operation deliberately left None
         }
         item.refresh_status_symbols(None);
         assert_eq!(
             item.status_symbols.operation_state,
-            Some(OperationState::Conflicts)
+            Some(OperationState::Conflicts(3))
         );
     }
 
     #[test]
     fn gate_operation_state_waits_for_both_inputs() {
-        // `has_conflicts = Some(false)` but `git_operation = None` →
+        // `conflicted_files = Some(0)` but `git_operation = None` →
         // gate stays Loading (could still become Rebase/Merge).
         let mut item = make_worktree_item();
         if let ItemKind::Worktree(ref mut data) = item.kind {
-            data.has_conflicts = Some(false);
+            data.conflicted_files = Some(0);
             data.git_operation = None;
         }
         item.refresh_status_symbols(None);
@@ -4,6 +4,7 @@
 //! the default branch, upstream remote, or git operations in progress.
 
 use worktrunk::git::IntegrationReason;
+use worktrunk::styling::Glyph;
 
 /// Upstream divergence state relative to remote tracking branch.
 ///
@@ -17,6 +18,7 @@ use worktrunk::git::IntegrationReason;
 /// | Ahead     | `⇡`    - has unpushed commits   |
 /// | Behind    | `⇣`    - missing remote commits |
 /// | Diverged  | `⇅`    - both ahead and behind  |
+/// | Gone      | `⊘`    - upstream deleted from the remote |
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Divergence {
     /// No remote tracking branch configured
@@ -30,6 +32,8 @@ pub enum Divergence {
     Behind,
     /// Both ahead and behind the remote
     Diverged,
+    /// The tracked remote branch was deleted (git's `[gone]`)
+    Gone,
 }
 
 impl Divergence {
@@ -48,12 +52,18 @@ impl Divergence {
 
     /// Get the display symbol for this divergence state.
     pub fn symbol(self) -> &'static str {
+        self.glyph().unicode()
+    }
+
+    /// The symbol with its `output.ascii` stand-in.
+    fn glyph(self) -> Glyph {
         match self {
-            Self::None => "",
-            Self::InSync => "|",
-            Self::Ahead => "⇡",
-            Self::Behind => "⇣",
-            Self::Diverged => "⇅",
+            Self::None => Glyph::new("", ""),
+            Self::InSync => Glyph::new("|", "|"),
+            Self::Ahead => Glyph::new("⇡", "A"),
+            Self::Behind => Glyph::new("⇣", "B"),
+            Self::Diverged => Glyph::new("⇅", "D"),
+            Self::Gone => Glyph::new("⊘", "G"),
         }
     }
 
@@ -63,7 +73,7 @@ impl Divergence {
         if self == Self::None {
             None
         } else {
-            Some(cformat!("<dim>{}</>", self.symbol()))
+            Some(cformat!("<dim>{}</>", self.glyph()))
         }
     }
 }
@@ -91,18 +101,25 @@ pub enum WorktreeState {
     Branch,
 }
 
-impl std::fmt::Display for WorktreeState {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl WorktreeState {
+    /// The symbol with its `output.ascii` stand-in.
+    pub fn glyph(self) -> Glyph {
         match self {
-            Self::None => Ok(()),
-            Self::BranchWorktreeMismatch => write!(f, "⚑"),
-            Self::Prunable => write!(f, "⊟"),
-            Self::Locked => write!(f, "⊞"),
-            Self::Branch => write!(f, "/"),
+            Self::None => Glyph::new("", ""),
+            Self::BranchWorktreeMismatch => Glyph::new("⚑", "M"),
+            Self::Prunable => Glyph::new("⊟", "P"),
+            Self::Locked => Glyph::new("⊞", "L"),
+            Self::Branch => Glyph::new("/", "/"),
         }
     }
 }
 
+impl std::fmt::Display for WorktreeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.glyph().unicode())
+    }
+}
+
 /// Default branch relationship state
 ///
 /// Represents the combined relationship to the default branch in a single position.
@@ -160,24 +177,30 @@ pub enum MainState {
 }
 
 impl std::fmt::Display for MainState {
-    /// Single-stroke vertical arrows for Main column (vs double-stroke arrows for Remote column).
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Self::None => Ok(()),
-            Self::IsMain => write!(f, "^"),
-            Self::WouldConflict => write!(f, "✗"),
-            Self::Empty => write!(f, "_"),
-            Self::SameCommit => write!(f, "–"), // en-dash U+2013
-            Self::Integrated(_) => write!(f, "⊂"),
-            Self::Orphan => write!(f, "∅"), // U+2205 empty set
-            Self::Diverged => write!(f, "↕"),
-            Self::Ahead => write!(f, "↑"),
-            Self::Behind => write!(f, "↓"),
-        }
+        f.write_str(self.glyph().unicode())
     }
 }
 
 impl MainState {
+    /// The symbol with its `output.ascii` stand-in.
+    ///
+    /// Single-stroke vertical arrows for Main column (vs double-stroke arrows for Remote column).
+    pub fn glyph(&self) -> Glyph {
+        match self {
+            Self::None => Glyph::new("", ""),
+            Self::IsMain => Glyph::new("^", "^"),
+            Self::WouldConflict => Glyph::new("✗", "x"),
+            Self::Empty => Glyph::new("_", "_"),
+            Self::SameCommit => Glyph::new("–", "-"), // en-dash U+2013
+            Self::Integrated(_) => Glyph::new("⊂", "c"),
+            Self::Orphan => Glyph::new("∅", "0"), // U+2205 empty set
+            Self::Diverged => Glyph::new("↕", "~"),
+            Self::Ahead => Glyph::new("↑", ">"),
+            Self::Behind => Glyph::new("↓", "<"),
+        }
+    }
+
     /// Returns styled symbol with appropriate color, or None for None variant.
     ///
     /// Color semantics:
@@ -187,8 +210,8 @@ impl MainState {
         use color_print::cformat;
         match self {
             Self::None => None,
-            Self::WouldConflict => Some(cformat!("<yellow>{self}</>")),
-            _ => Some(cformat!("<dim>{self}</>")),
+            Self::WouldConflict => Some(cformat!("<yellow>{}</>", self.glyph())),
+            _ => Some(cformat!("<dim>{}</>", self.glyph())),
         }
     }
 
@@ -410,7 +433,7 @@ pub fn tier_counts(
 /// Represents blocking git operations in progress that require resolution.
 /// These take priority over all other states in the Worktree column.
 ///
-/// Priority: Conflicts (✘) > Rebase (⤴) > Merge (⤵)
+/// Priority: Conflicts (✘) > Rebase (⤴) > Merge (⤵) > InterruptedMerge (↯)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::IntoStaticStr)]
 #[strum(serialize_all = "snake_case")]
 pub enum OperationState {
@@ -418,37 +441,52 @@ pub enum OperationState {
     #[default]
     #[strum(serialize = "")]
     None,
-    /// Actual merge conflicts (unmerged paths in working tree)
-    Conflicts,
+    /// Actual merge conflicts: the number of unmerged paths in the working tree
+    Conflicts(usize),
     /// Rebase in progress
     Rebase,
     /// Merge in progress
     Merge,
+    /// `wt merge` failed partway (resumable with `wt merge --continue`)
+    InterruptedMerge,
 }
 
 impl std::fmt::Display for OperationState {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Self::None => Ok(()),
-            Self::Conflicts => write!(f, "✘"),
-            Self::Rebase => write!(f, "⤴"),
-            Self::Merge => write!(f, "⤵"),
+        f.write_str(self.glyph().unicode())?;
+        if let Self::Conflicts(count) = self {
+            write!(f, "{count}")?;
         }
+        Ok(())
     }
 }
 
 impl OperationState {
+    /// The symbol with its `output.ascii` stand-in. `Conflicts` is followed
+    /// by its count.
+    pub fn glyph(&self) -> Glyph {
+        match self {
+            Self::None => Glyph::new("", ""),
+            Self::Conflicts(_) => Glyph::new("✘", "X"),
+            Self::Rebase => Glyph::new("⤴", "r"),
+            Self::Merge => Glyph::new("⤵", "m"),
+            Self::InterruptedMerge => Glyph::new("↯", "&"),
+        }
+    }
+
     /// Returns styled symbol with appropriate color, or None for None variant.
     ///
     /// Color semantics:
     /// - ERROR (red): Conflicts - blocking problems
-    /// - WARNING (yellow): Rebase, Merge - active/stuck states
+    /// - WARNING (yellow): Rebase, Merge, InterruptedMerge - active/stuck states
     pub fn styled(&self) -> Option<String> {
         use color_print::cformat;
         match self {
             Self::None => None,
-            Self::Conflicts => Some(cformat!("<red>{self}</>")),
-            Self::Rebase | Self::Merge => Some(cformat!("<yellow>{self}</>")),
+            Self::Conflicts(count) => Some(cformat!("<red>{}{count}</>", self.glyph())),
+            Self::Rebase | Self::Merge | Self::InterruptedMerge => {
+                Some(cformat!("<yellow>{}</>", self.glyph()))
+            }
         }
     }
 
@@ -472,6 +510,8 @@ pub enum ActiveGitOperation {
     Rebase,
     /// Merge in progress (MERGE_HEAD exists)
     Merge,
+    /// A `wt merge` journal is pending for the branch
+    InterruptedMerge,
 }
 
 #[cfg(test)]
@@ -918,16 +958,17 @@ mod tests {
     #[test]
     fn test_operation_state_display() {
         assert_eq!(format!("{}", OperationState::None), "");
-        assert_eq!(format!("{}", OperationState::Conflicts), "✘");
+        assert_eq!(format!("{}", OperationState::Conflicts(2)), "✘2");
         assert_eq!(format!("{}", OperationState::Rebase), "⤴");
         assert_eq!(format!("{}", OperationState::Merge), "⤵");
+        assert_eq!(format!("{}", OperationState::InterruptedMerge), "↯");
     }
 
     #[test]
     fn test_operation_state_styled() {
         use insta::assert_snapshot;
         assert!(OperationState::None.styled().is_none());
-        assert_snapshot!(OperationState::Conflicts.styled().unwrap(), @"[31m✘[39m");
+        assert_snapshot!(OperationState::Conflicts(2).styled().unwrap(), @"[31m✘2[39m");
         assert_snapshot!(OperationState::Rebase.styled().unwrap(), @"[33m⤴[39m");
         assert_snapshot!(OperationState::Merge.styled().unwrap(), @"[33m⤵[39m");
     }
@@ -935,8 +976,64 @@ mod tests {
     #[test]
     fn test_operation_state_as_json_str() {
         assert_eq!(OperationState::None.as_json_str(), None);
-        assert_eq!(OperationState::Conflicts.as_json_str(), Some("conflicts"));
+        assert_eq!(
+            OperationState::Conflicts(2).as_json_str(),
+            Some("conflicts")
+        );
         assert_eq!(OperationState::Rebase.as_json_str(), Some("rebase"));
         assert_eq!(OperationState::Merge.as_json_str(), Some("merge"));
     }
+
+    #[test]
+    fn test_ascii_stand_ins() {
+        use unicode_width::UnicodeWidthStr;
+
+        // Worktree and operation states share position 3; main state is
+        // position 4 and divergence position 5. Each position's stand-ins
+        // must stay as distinct as its glyphs, and as wide.
+        let positions = [
+            vec![
+                WorktreeState::BranchWorktreeMismatch.glyph(),
+                WorktreeState::Prunable.glyph(),
+                WorktreeState::Locked.glyph(),
+                WorktreeState::Branch.glyph(),
+                OperationState::Conflicts(1).glyph(),
+                OperationState::Rebase.glyph(),
+                OperationState::Merge.glyph(),
+                OperationState::InterruptedMerge.glyph(),
+            ],
+            vec![
+                MainState::IsMain.glyph(),
+                MainState::WouldConflict.glyph(),
+                MainState::Empty.glyph(),
+                MainState::SameCommit.glyph(),
+                MainState::Integrated(IntegrationReason::Ancestor).glyph(),
+                MainState::Orphan.glyph(),
+                MainState::Diverged.glyph(),
+                MainState::Ahead.glyph(),
+                MainState::Behind.glyph(),
+            ],
+            vec![
+                Divergence::InSync.glyph(),
+                Divergence::Ahead.glyph(),
+                Divergence::Behind.glyph(),
+                Divergence::Diverged.glyph(),
+                Divergence::Gone.glyph(),
+            ],
+        ];
+        let mut all = std::collections::HashSet::new();
+        for glyphs in positions {
+            let mut seen = std::collections::HashSet::new();
+            for glyph in glyphs {
+                assert!(glyph.ascii().is_ascii(), "{glyph:?}");
+                assert_eq!(glyph.unicode().width(), glyph.ascii().width(), "{glyph:?}");
+                assert!(seen.insert(glyph.ascii()), "{glyph:?}");
+                // Across positions too, except where the glyph itself repeats.
+                assert!(
+                    all.insert(glyph.ascii()) || glyph.ascii() == glyph.unicode(),
+                    "{glyph:?}"
+                );
+            }
+        }
+    }
 }
@@ -34,8 +34,13 @@ pub struct BranchDiffTotals {
 #[derive(Default, Clone, Debug)]
 pub struct UpstreamStatus {
     pub(crate) remote: Option<String>,
+    /// The upstream ref (e.g., "origin/feature") while it exists.
+    pub(crate) tracking: Option<String>,
     pub(crate) ahead: usize,
     pub(crate) behind: usize,
+    /// The configured upstream (e.g., "origin/feature") when it's gone from
+    /// the remote. `remote` is `None` then — there's nothing to count against.
+    pub(crate) gone: Option<String>,
 }
 
 /// Active upstream tracking information (when a remote is configured).
@@ -54,6 +59,12 @@ impl UpstreamStatus {
             behind: self.behind,
         })
     }
+
+    /// The upstream the branch still tracks after it was deleted from the
+    /// remote, if so.
+    pub fn gone(&self) -> Option<&str> {
+        self.gone.as_deref()
+    }
 }
 
 #[cfg(test)]
@@ -64,8 +75,10 @@ mod tests {
     fn test_upstream_status_active_with_remote() {
         let status = UpstreamStatus {
             remote: Some("origin".to_string()),
+            tracking: None,
             ahead: 3,
             behind: 2,
+            gone: None,
         };
         let active = status.active().unwrap();
         assert_eq!(active.remote, "origin");
@@ -77,9 +90,21 @@ mod tests {
     fn test_upstream_status_active_no_remote() {
         let status = UpstreamStatus {
             remote: None,
+            tracking: None,
             ahead: 0,
             behind: 0,
+            gone: None,
+        };
+        assert!(status.active().is_none());
+    }
+
+    #[test]
+    fn test_upstream_status_gone() {
+        let status = UpstreamStatus {
+            gone: Some("origin/feature".to_string()),
+            ..Default::default()
         };
         assert!(status.active().is_none());
+        assert_eq!(status.gone(), Some("origin/feature"));
     }
 }
@@ -59,7 +59,7 @@
 //! `✘ > ⤴ > ⤵ > ⚑ > ⊟ > ⊞ > /`. The operation family (`✘⤴⤵`) comes from live
 //! task data; the attribute family (`⚑⊟⊞/`) is metadata, always known.
 //!
-//! **Inputs:** `data.has_conflicts`, `data.git_operation`, plus metadata
+//! **Inputs:** `data.conflicted_files`, `data.git_operation`, plus metadata
 //! (`locked`, `prunable`, `branch_worktree_mismatch`, `ItemKind::Branch`).
 //!
 //! **Rule — short-circuit on priority:** a higher-priority signal, once known
@@ -67,22 +67,28 @@
 //! lower-priority signals. Formally, render as soon as we can identify which
 //! row of the priority table is the answer:
 //!
-//! 1. `has_conflicts == Some(true)` → `✘`.
-//! 2. `has_conflicts == Some(false)` and `git_operation == Some(Rebase)` → `⤴`.
-//! 3. `has_conflicts == Some(false)` and `git_operation == Some(Merge)` → `⤵`.
-//! 4. `has_conflicts == Some(false)` and `git_operation == Some(None)` and
+//! 1. `conflicted_files == Some(n)`, `n > 0` → `✘n` (e.g. `✘3`).
+//! 2. `conflicted_files == Some(0)` and `git_operation == Some(Rebase)` → `⤴`.
+//! 3. `conflicted_files == Some(0)` and `git_operation == Some(Merge)` → `⤵`.
+//! 4. `conflicted_files == Some(0)` and `git_operation == Some(None)` and
 //!    metadata says mismatched → `⚑`.
 //! 5. …continuing down through `⊟`, `⊞`, `/`, nothing.
 //!
-//! Until both `has_conflicts` and `git_operation` are known, we cannot rule
+//! Until both `conflicted_files` and `git_operation` are known, we cannot rule
 //! out `✘/⤴/⤵`, so the position renders `·` even if metadata would otherwise
 //! produce `⊟` or `⊞`.
 //!
 //! **Exception — items with no working tree:** branches and prunable
-//! worktrees have seeded sentinels (`has_conflicts = Some(false)`,
+//! worktrees have seeded sentinels (`conflicted_files = Some(0)`,
 //! `git_operation = Some(None)`) at spawn time, so the operation family is
 //! ruled out immediately and metadata wins.
 //!
+//! **Conflict count:** `✘` carries the number of unmerged paths, so a
+//! conflicted row's Status cell is wider than its one-column allocation: the
+//! positions after it shift right and a user marker may be clipped.
+//! Conflicted rows are rare and the count is what needs attention; widening
+//! the slot for every row would cost more.
+//!
 //! # Gate 3: Main state (position 4)
 //!
 //! **Renders:** at most one of `^ _ ⊂ ✗ – ↕ ↑ ↓`. Priority:
@@ -561,13 +567,13 @@ impl StatusSymbols {
             Some(_) => match self.worktree_state {
                 None | Some(WorktreeState::None) => SlotState::Empty,
                 Some(WorktreeState::Branch) => {
-                    SlotState::Visible(cformat!("<dim>{}</>", WorktreeState::Branch))
+                    SlotState::Visible(cformat!("<dim>{}</>", WorktreeState::Branch.glyph()))
                 }
                 Some(WorktreeState::BranchWorktreeMismatch) => SlotState::Visible(cformat!(
                     "<red>{}</>",
-                    WorktreeState::BranchWorktreeMismatch
+                    WorktreeState::BranchWorktreeMismatch.glyph()
                 )),
-                Some(other) => SlotState::Visible(cformat!("<yellow>{}</>", other)),
+                Some(other) => SlotState::Visible(cformat!("<yellow>{}</>", other.glyph())),
             },
         };
 
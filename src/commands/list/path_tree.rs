@@ -0,0 +1,154 @@
+//! Tree rendering of the Path column for `wt list --tree`.
+//!
+//! Worktrees are regrouped so siblings in the same directory sit on adjacent
+//! rows, then each row's path drops the leading directories it shares with the
+//! row above — the shared prefix is blanked, so children appear indented under
+//! the first path that spells it out:
+//!
+//! ```text
+//! .
+//!   .worktrees/feature-a
+//!              feature-b
+//! ../scratch/hotfix
+//! ```
+//!
+//! Both steps are pure: grouping reorders `WorktreeInfo`s before items are
+//! built (so task indices stay aligned), and labels are computed from the
+//! already-shortened display paths.
+
+use std::path::Path;
+
+use unicode_width::UnicodeWidthStr;
+use worktrunk::git::WorktreeInfo;
+
+/// Stable-group worktrees by parent directory.
+///
+/// Groups appear in the order of their first member, and members keep their
+/// relative order, so the current/main-first sort survives: the first row
+/// never moves, and later rows only move up to join an earlier sibling.
+pub(super) fn group_by_parent(worktrees: Vec<WorktreeInfo>) -> Vec<WorktreeInfo> {
+    let mut groups: Vec<(Option<&Path>, Vec<usize>)> = Vec::new();
+    for (idx, wt) in worktrees.iter().enumerate() {
+        let parent = wt.path.parent();
+        match groups.iter_mut().find(|(p, _)| *p == parent) {
+            Some((_, members)) => members.push(idx),
+            None => groups.push((parent, vec![idx])),
+        }
+    }
+    let order: Vec<usize> = groups
+        .into_iter()
+        .flat_map(|(_, members)| members)
+        .collect();
+
+    let mut slots: Vec<Option<WorktreeInfo>> = worktrees.into_iter().map(Some).collect();
+    order
+        .into_iter()
+        .filter_map(|idx| slots[idx].take())
+        .collect()
+}
+
+/// Tree labels for a sequence of shortened display paths (`./x`, `../y`,
+/// `~/z`, …), one per row in display order.
+///
+/// Each label blanks the leading `/`-separated components it shares with the
+/// previous row's path, always keeping at least the final component. A shared
+/// prefix that is only the filesystem root or `~` isn't collapsed — that
+/// would turn unrelated paths into false siblings.
+pub(super) fn tree_labels(paths: &[String]) -> Vec<String> {
+    let mut labels = Vec::with_capacity(paths.len());
+    let mut previous: Option<Vec<&str>> = None;
+
+    for path in paths {
+        let components: Vec<&str> = path.split('/').collect();
+        let shared = previous.as_ref().map_or(0, |prev| {
+            prev.iter()
+                .zip(&components)
+                .take(components.len().saturating_sub(1))
+                .take_while(|(a, b)| a == b)
+                .count()
+        });
+        let meaningful = components[..shared]
+            .iter()
+            .any(|c| !c.is_empty() && *c != "~");
+
+        if meaningful {
+            let prefix = components[..shared].join("/") + "/";
+            let rest = components[shared..].join("/");
+            labels.push(format!("{}{rest}", " ".repeat(prefix.width())));
+        } else {
+            labels.push(path.clone());
+        }
+        previous = Some(components);
+    }
+
+    labels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn labels(paths: &[&str]) -> Vec<String> {
+        tree_labels(&paths.iter().map(|p| p.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn collapses_shared_directories() {
+        assert_eq!(
+            labels(&[
+                ".",
+                "./.worktrees/feature-a",
+                "./.worktrees/feature-b",
+                "../scratch/hotfix",
+                "../scratch/spike",
+            ]),
+            vec![
+                ".",
+                "  .worktrees/feature-a",
+                "             feature-b",
+                "../scratch/hotfix",
+                "           spike",
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_unrelated_roots_intact() {
+        assert_eq!(
+            labels(&["~/code/a", "~/work/b", "/tmp/c", "/var/d", "."]),
+            vec!["~/code/a", "~/work/b", "/tmp/c", "/var/d", "."]
+        );
+    }
+
+    #[test]
+    fn group_by_parent_is_stable() {
+        let wt = |path: &str| WorktreeInfo {
+            path: PathBuf::from(path),
+            head: String::new(),
+            branch: None,
+            bare: false,
+            detached: false,
+            locked: None,
+            prunable: None,
+        };
+        let grouped = group_by_parent(vec![
+            wt("/code/repo"),
+            wt("/code/repo.worktrees/b"),
+            wt("/tmp/x"),
+            wt("/code/repo.worktrees/a"),
+            wt("/code/other"),
+        ]);
+        let paths: Vec<_> = grouped.iter().map(|w| w.path.to_str().unwrap()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/code/repo",
+                "/code/other",
+                "/code/repo.worktrees/b",
+                "/code/repo.worktrees/a",
+                "/tmp/x",
+            ]
+        );
+    }
+}
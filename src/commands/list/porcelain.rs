@@ -0,0 +1,72 @@
+//! `wt list --porcelain` — one tab-separated line per row, for shell scripts.
+//!
+//! ```text
+//! branch<TAB>path<TAB>ahead<TAB>behind<TAB>flags
+//! ```
+//!
+//! The format is stable across versions: fields keep their order and meaning,
+//! and flag names don't change. New flags may be added, so scripts should
+//! match flags individually rather than compare the whole field. Absent
+//! values print as `-`, never as an empty field — `read` with a tab `IFS`
+//! collapses consecutive tabs. Git rejects `-` as a branch name, so it can't
+//! be confused with a real branch.
+
+use super::json_output::JsonItem;
+
+/// Placeholder for an absent value.
+const NONE: &str = "-";
+
+/// Render one row.
+pub(super) fn format_line(item: &JsonItem) -> String {
+    let branch = item.branch.as_deref().unwrap_or(NONE);
+    let path = item
+        .path
+        .as_ref()
+        .map_or_else(|| NONE.to_string(), |p| p.display().to_string());
+    let (ahead, behind) = match &item.main {
+        Some(main) => (main.ahead.to_string(), main.behind.to_string()),
+        None => (NONE.to_string(), NONE.to_string()),
+    };
+    let flags = flags(item);
+    let flags = if flags.is_empty() {
+        NONE.to_string()
+    } else {
+        flags.join(",")
+    };
+    format!("{branch}\t{path}\t{ahead}\t{behind}\t{flags}")
+}
+
+/// Flags in a fixed order. Each comes from a `--format=json` field, so the
+/// two outputs agree.
+fn flags(item: &JsonItem) -> Vec<&'static str> {
+    let mut flags = Vec::new();
+    if item.is_current {
+        flags.push("current");
+    }
+    if item.is_previous {
+        flags.push("previous");
+    }
+    if item.is_main {
+        flags.push("main");
+    }
+    if item.worktree.as_ref().is_some_and(|wt| wt.detached) {
+        flags.push("detached");
+    }
+    if item
+        .working_tree
+        .as_ref()
+        .is_some_and(|wt| wt.staged || wt.modified || wt.untracked || wt.renamed || wt.deleted)
+    {
+        flags.push("dirty");
+    }
+    if let Some(state) = item.operation_state {
+        flags.push(state);
+    }
+    if matches!(item.main_state, Some("integrated" | "empty")) {
+        flags.push("integrated");
+    }
+    if let Some(state @ ("locked" | "prunable")) = item.worktree.as_ref().and_then(|wt| wt.state) {
+        flags.push(state);
+    }
+    flags
+}
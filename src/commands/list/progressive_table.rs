@@ -7,6 +7,10 @@
 //! - Uses our own escape-aware width calculations (StyledLine, truncate_visible)
 //! - Supports OSC-8 hyperlinks correctly
 //! - Has predictable cursor behavior based on our rendering logic
+//!
+//! Row content goes through anstream's stdout so `--color=never` strips its
+//! styling; cursor movement goes to the raw stdout, where stripping would
+//! swallow it.
 
 use crossterm::{
     ExecutableCommand,
@@ -14,6 +18,7 @@ use crossterm::{
     terminal::{Clear, ClearType},
 };
 use std::io::{IsTerminal, Write, stdout};
+use worktrunk::styling::{stdout as styled_stdout, themed};
 
 use crate::display::truncate_visible;
 
@@ -123,9 +128,9 @@ impl ProgressiveTable {
 
     /// Print all lines to stdout.
     fn print_all(&self) -> std::io::Result<()> {
-        let mut stdout = stdout();
+        let mut stdout = styled_stdout();
         for line in &self.lines {
-            writeln!(stdout, "{}", line)?;
+            writeln!(stdout, "{}", themed(line))?;
         }
         stdout.flush()
     }
@@ -229,7 +234,10 @@ impl ProgressiveTable {
         stdout.execute(Clear(ClearType::CurrentLine))?;
 
         // Print the new content
-        write!(stdout, "{}", self.lines[line_idx])?;
+        stdout.flush()?;
+        let mut styled = styled_stdout();
+        write!(styled, "{}", themed(&self.lines[line_idx]))?;
+        styled.flush()?;
 
         // Move cursor back to the end (after footer)
         // We need to move down (lines_up) lines, but since we printed one line
@@ -262,17 +270,19 @@ impl ProgressiveTable {
             stdout.execute(MoveUp(self.lines.len() as u16))?;
             stdout.execute(MoveToColumn(0))?;
             stdout.execute(Clear(ClearType::FromCursorDown))?;
-            writeln!(stdout, "{}", self.lines[0])?; // header (unchanged)
+            stdout.flush()?;
+            let mut styled = styled_stdout();
+            writeln!(styled, "{}", themed(&self.lines[0]))?; // header (unchanged)
             for row in &final_rows {
-                writeln!(stdout, "{}", truncate_visible(row, self.max_width))?;
+                writeln!(styled, "{}", themed(&truncate_visible(row, self.max_width)))?;
             }
-            writeln!(stdout)?;
+            writeln!(styled)?;
             writeln!(
-                stdout,
+                styled,
                 "{}",
-                truncate_visible(&final_footer, self.max_width)
+                themed(&truncate_visible(&final_footer, self.max_width))
             )?;
-            stdout.flush()
+            styled.flush()
         } else {
             // Normal: update rows in-place + footer
             for (idx, row) in final_rows.into_iter().enumerate() {
@@ -1,8 +1,10 @@
-use crate::display::{format_relative_time_short, shorten_path, truncate_to_width};
-use anstyle::{Effects, Style};
+use crate::commands::hook_status::{self, HookRunState};
+use crate::display::{format_relative_time_short, truncate_middle, truncate_to_width};
+use anstyle::{AnsiColor, Effects, Style};
 use std::path::Path;
 use unicode_width::UnicodeWidthStr;
-use worktrunk::styling::{Stream, StyledLine, hyperlink_stdout, supports_hyperlinks};
+use worktrunk::progress::format_bytes;
+use worktrunk::styling::{Glyph, Stream, StyledLine, hyperlink_stdout, supports_hyperlinks};
 
 use super::collect::parse_port_from_url;
 use super::columns::{ColumnKind, DiffVariant};
@@ -19,7 +21,7 @@ use super::model::{ItemKind, ListItem, PositionMask};
 /// (e.g. `·` for one, `–` or braille dot for the other) once we can evaluate
 /// them side-by-side in real tables. Also update `src/cli/mod.rs`
 /// status-column help table when resplit.
-pub const PLACEHOLDER: &str = "·";
+pub const PLACEHOLDER: Glyph = Glyph::new("·", ".");
 
 /// Blank placeholder used by `wt list` during the first ~200ms of progressive
 /// rendering. The skeleton renders with blanks so fast commands (everything
@@ -71,7 +73,7 @@ impl DiffColumnConfig {
     fn format_overflow(value: usize, variant: DiffVariant) -> (String, bool) {
         if value >= 10_000 {
             // Use ∞ for extreme values to avoid false precision (9K could be 9K or 900K)
-            ("∞".to_string(), true)
+            (Glyph::new("∞", "*").to_string(), true)
         } else if value >= 1_000 {
             (format!("{}K", value / 1_000), true)
         } else if value >= 100 {
@@ -297,8 +299,8 @@ impl LayoutConfig {
     pub fn render_skeleton_row(&self, item: &ListItem, placeholder: &str) -> StyledLine {
         let branch = item.branch_name();
         let shortened_path = item
-            .worktree_path()
-            .map(|p| shorten_path(p, &self.main_worktree_path))
+            .worktree_data()
+            .map(|data| data.display_path(&self.main_worktree_path))
             .unwrap_or_default();
 
         let dim = Style::new().dimmed();
@@ -325,12 +327,12 @@ impl LayoutConfig {
                 }
                 ColumnKind::Branch => {
                     // Show actual branch name (no dim - start normal, gray out later if removable)
-                    cell.push_raw(branch.to_string());
+                    cell.push_raw(truncate_middle(branch, col.width));
                     cell.pad_to(col.width);
                 }
                 ColumnKind::Path => {
                     // Show actual path (no dim - start normal, gray out later if removable)
-                    cell.push_raw(&shortened_path);
+                    cell.push_raw(truncate_middle(&shortened_path, col.width));
                     cell.pad_to(col.width);
                 }
                 ColumnKind::Commit => {
@@ -342,14 +344,14 @@ impl LayoutConfig {
                     }
                 }
                 ColumnKind::Custom(i) => {
-                    // Custom values are expanded before the skeleton renders —
-                    // show them like Branch/Path rather than a placeholder.
-                    let text = item
-                        .custom_values
-                        .get(i as usize)
-                        .map(String::as_str)
-                        .unwrap_or("");
-                    return col.render_text_cell(text, None);
+                    // Template values are expanded before the skeleton renders —
+                    // show them like Branch/Path. Command values are still
+                    // pending and get the placeholder.
+                    return match item.custom_values.get(i as usize) {
+                        Some(None) => col.placeholder_cell(spinner),
+                        value => col
+                            .render_text_cell(value.and_then(Option::as_deref).unwrap_or(""), None),
+                    };
                 }
                 ColumnKind::CiStatus if item.pr_status.is_some() => {
                     // Set before the skeleton only by the picker's cache prime —
@@ -422,6 +424,12 @@ impl ColumnLayout {
         cell.truncate_to_width(self.width)
     }
 
+    /// Render a branch or path cell, shortened in the middle so the
+    /// distinguishing suffix survives.
+    fn render_middle_truncated_cell(&self, text: &str, style: Option<Style>) -> StyledLine {
+        self.render_text_cell(&truncate_middle(text, self.width), style)
+    }
+
     fn render_diff_cell(&self, positive: usize, negative: usize) -> StyledLine {
         let ColumnFormat::Diff(config) = self.format else {
             return StyledLine::new();
@@ -475,7 +483,7 @@ impl ColumnLayout {
             }
             ColumnKind::Branch => {
                 let text = item.branch.as_deref().unwrap_or("-");
-                self.render_text_cell(text, text_style)
+                self.render_middle_truncated_cell(text, text_style)
             }
             ColumnKind::Status => {
                 // `render_with_mask` emits the placeholder glyph per
@@ -525,14 +533,28 @@ impl ColumnLayout {
                 let Some(data) = worktree_data else {
                     return StyledLine::new();
                 };
-                let path_str = shorten_path(&data.path, main_worktree_path);
-                self.render_text_cell(&path_str, text_style)
+                let path_str = data.display_path(main_worktree_path);
+                self.render_middle_truncated_cell(&path_str, text_style)
+            }
+            ColumnKind::Size => {
+                let Some(data) = worktree_data else {
+                    return StyledLine::new(); // Branch item — nothing checked out
+                };
+                let Some(bytes) = data.disk_usage else {
+                    return self.placeholder_cell(placeholder); // Not loaded yet
+                };
+                self.render_text_cell(&format_bytes(bytes), text_style)
             }
             ColumnKind::Upstream => {
                 let Some(ref upstream) = item.upstream else {
                     return self.placeholder_cell(placeholder); // Not loaded yet
                 };
                 let Some(active) = upstream.active() else {
+                    if upstream.gone().is_some() {
+                        let mut cell = StyledLine::new();
+                        cell.push_styled("gone", Style::new().dimmed());
+                        return cell;
+                    }
                     return StyledLine::new(); // Loaded, no active upstream
                 };
                 // Show centered | when in sync instead of ⇡0  ⇣0
@@ -590,6 +612,44 @@ impl ColumnLayout {
                     }
                 }
             }
+            ColumnKind::Base => match &item.base_drift {
+                None => self.placeholder_cell(placeholder),
+                Some(None) => StyledLine::new(), // Base unknown or still holds
+                Some(Some((base, drift))) => {
+                    let label: &'static str = drift.into();
+                    let style = Style::new().fg_color(Some(AnsiColor::Yellow.into()));
+                    self.render_text_cell(&format!("{label} {}", base.base_ref), Some(style))
+                }
+            },
+            ColumnKind::Hooks => match &item.hook_status {
+                None => self.placeholder_cell(placeholder),
+                Some(records) => match hook_status::summary_cell(records) {
+                    None => StyledLine::new(),
+                    Some((state, text)) => {
+                        let style = match state {
+                            HookRunState::Failed => {
+                                Style::new().fg_color(Some(AnsiColor::Red.into()))
+                            }
+                            HookRunState::Running | HookRunState::Pending => {
+                                Style::new().fg_color(Some(AnsiColor::Yellow.into()))
+                            }
+                            HookRunState::Skipped => Style::new().dimmed(),
+                            HookRunState::Succeeded => {
+                                Style::new().fg_color(Some(AnsiColor::Green.into()))
+                            }
+                        };
+                        self.render_text_cell(&text, Some(style))
+                    }
+                },
+            },
+            ColumnKind::Stash => match item.stash_count {
+                None => self.placeholder_cell(placeholder),
+                Some(0) => StyledLine::new(),
+                Some(count) => self.render_text_cell(
+                    &count.to_string(),
+                    Some(Style::new().fg_color(Some(AnsiColor::Yellow.into()))),
+                ),
+            },
             ColumnKind::Commit => {
                 let head = item.head();
                 if head == worktrunk::git::NULL_OID {
@@ -618,16 +678,14 @@ impl ColumnLayout {
                 cell.push_styled(msg, Style::new().dimmed());
                 cell
             }
-            // Values are expanded before layout — no loading state, so an
-            // absent or empty value is an empty cell, never a placeholder.
-            ColumnKind::Custom(i) => {
-                let text = item
-                    .custom_values
-                    .get(i as usize)
-                    .map(String::as_str)
-                    .unwrap_or("");
-                self.render_text_cell(text, text_style)
-            }
+            // Template values are expanded before layout, so an absent or
+            // empty value is an empty cell. Only a command value still
+            // running shows the placeholder.
+            ColumnKind::Custom(i) => match item.custom_values.get(i as usize) {
+                Some(None) => self.placeholder_cell(placeholder),
+                value => self
+                    .render_text_cell(value.and_then(Option::as_deref).unwrap_or(""), text_style),
+            },
         }
     }
 }
@@ -1355,7 +1413,9 @@ mod tests {
 
         let item = ListItem::new_branch("abc123".into(), "feat".into());
 
-        let line = layout.render_list_item_line(&item, PLACEHOLDER).render();
+        let line = layout
+            .render_list_item_line(&item, PLACEHOLDER.as_str())
+            .render();
         assert!(line.contains('·'), "expected `·` in: {line}");
         assert!(!line.contains('⋯'), "unexpected `⋯` in: {line}");
     }
@@ -1380,17 +1440,17 @@ mod tests {
         // Case 1: summary = None (not loaded yet → placeholder)
         let mut item = ListItem::new_branch("abc123".into(), "feat".into());
         item.summary = None;
-        let cell = summary_col.render_cell(&item, &mask, &main_path, 50, 40, PLACEHOLDER);
+        let cell = summary_col.render_cell(&item, &mask, &main_path, 50, 40, PLACEHOLDER.as_str());
         insta::assert_snapshot!(cell.render(), @"[2m·[0m");
 
         // Case 2: summary = Some(None) (loaded, no summary → blank)
         item.summary = Some(None);
-        let cell = summary_col.render_cell(&item, &mask, &main_path, 50, 40, PLACEHOLDER);
+        let cell = summary_col.render_cell(&item, &mask, &main_path, 50, 40, PLACEHOLDER.as_str());
         assert!(cell.render().is_empty());
 
         // Case 3: summary = Some(Some(text)) (has summary)
         item.summary = Some(Some("Add user authentication".into()));
-        let cell = summary_col.render_cell(&item, &mask, &main_path, 50, 40, PLACEHOLDER);
+        let cell = summary_col.render_cell(&item, &mask, &main_path, 50, 40, PLACEHOLDER.as_str());
         insta::assert_snapshot!(cell.render(), @"Add user authentication");
     }
 
@@ -1433,7 +1493,9 @@ mod tests {
         // commit = None (first skeleton paint, before the batch) → placeholders.
         let mut item = ListItem::new_branch("abc123".into(), "feat".into());
         item.commit = None;
-        let line = layout.render_skeleton_row(&item, PLACEHOLDER).render();
+        let line = layout
+            .render_skeleton_row(&item, PLACEHOLDER.as_str())
+            .render();
         assert!(
             line.contains('·'),
             "Age/Message are placeholders before the commit batch: {line}"
@@ -1449,7 +1511,9 @@ mod tests {
             timestamp: 1_700_000_000,
             commit_message: "Fix the parser".into(),
         });
-        let line = layout.render_skeleton_row(&item, PLACEHOLDER).render();
+        let line = layout
+            .render_skeleton_row(&item, PLACEHOLDER.as_str())
+            .render();
         assert!(
             line.contains("Fix the parser"),
             "commit subject paints in the skeleton once the batch is in: {line}"
@@ -1489,13 +1553,20 @@ mod tests {
 
         // Branch item (no worktree data) → blank, not placeholder
         let branch_item = ListItem::new_branch("abc123".into(), "feat".into());
-        let cell = col.render_cell(&branch_item, &mask, &main_path, 50, 40, PLACEHOLDER);
+        let cell = col.render_cell(
+            &branch_item,
+            &mask,
+            &main_path,
+            50,
+            40,
+            PLACEHOLDER.as_str(),
+        );
         assert!(cell.render().is_empty(), "branch item should be blank");
 
         // Worktree item with working_tree_diff: None → placeholder
         let mut wt_item = ListItem::new_branch("abc123".into(), "feat".into());
         wt_item.kind = ItemKind::Worktree(Box::default());
-        let cell = col.render_cell(&wt_item, &mask, &main_path, 50, 40, PLACEHOLDER);
+        let cell = col.render_cell(&wt_item, &mask, &main_path, 50, 40, PLACEHOLDER.as_str());
         insta::assert_snapshot!(cell.render(), @"        [2m·[0m");
 
         // Stale placeholder
@@ -1533,13 +1604,13 @@ mod tests {
         // upstream: None (not loaded) → placeholder
         let item = ListItem::new_branch("abc123".into(), "feat".into());
         assert!(item.upstream.is_none());
-        let cell = col.render_cell(&item, &mask, &main_path, 50, 40, PLACEHOLDER);
+        let cell = col.render_cell(&item, &mask, &main_path, 50, 40, PLACEHOLDER.as_str());
         insta::assert_snapshot!(cell.render(), @"      [2m·[0m");
 
         // upstream: Some(default) (loaded, no active upstream) → blank
         let mut item = ListItem::new_branch("abc123".into(), "feat".into());
         item.upstream = Some(UpstreamStatus::default());
-        let cell = col.render_cell(&item, &mask, &main_path, 50, 40, PLACEHOLDER);
+        let cell = col.render_cell(&item, &mask, &main_path, 50, 40, PLACEHOLDER.as_str());
         assert!(
             cell.render().is_empty(),
             "no active upstream should be blank"
@@ -0,0 +1,70 @@
+//! `wt list --summary` — the whole listing as one line, for agent prompts
+//! and dashboards.
+//!
+//! ```text
+//! 5 worktrees: 2 dirty, 1 rebasing, 3 ahead of main, 1 with failing CI
+//! ```
+//!
+//! Counts come from the same rows as `--format=json`, and only worktree rows
+//! count — branch rows from `--branches`/`--remotes` have no working tree to
+//! be dirty. Categories at zero are left out. CI counts appear only when CI
+//! status was collected (`--full`), so the default stays cheap.
+
+use super::json_output::JsonItem;
+
+/// Render the summary line; `default_branch` names the branch "ahead of" counts
+/// are against.
+pub(super) fn format_summary(items: &[JsonItem], default_branch: &str) -> String {
+    let worktrees: Vec<&JsonItem> = items
+        .iter()
+        .filter(|item| item.worktree.is_some())
+        .collect();
+    let count = |pred: &dyn Fn(&JsonItem) -> bool| worktrees.iter().filter(|i| pred(i)).count();
+
+    let parts = [
+        (count(&is_dirty), "dirty".to_string()),
+        (
+            count(&|i| i.operation_state == Some("conflicts")),
+            "with conflicts".to_string(),
+        ),
+        (
+            count(&|i| i.operation_state == Some("rebase")),
+            "rebasing".to_string(),
+        ),
+        (
+            count(&|i| matches!(i.operation_state, Some("merge" | "interrupted_merge"))),
+            "merging".to_string(),
+        ),
+        (
+            count(&|i| !i.is_main && i.main.as_ref().is_some_and(|m| m.ahead > 0)),
+            format!("ahead of {default_branch}"),
+        ),
+        (
+            count(&|i| !i.is_main && i.main.as_ref().is_some_and(|m| m.behind > 0)),
+            format!("behind {default_branch}"),
+        ),
+        (
+            count(&|i| i.ci.as_ref().is_some_and(|ci| ci.status == "failed")),
+            "with failing CI".to_string(),
+        ),
+    ];
+    let details: Vec<String> = parts
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, label)| format!("{n} {label}"))
+        .collect();
+
+    let total = worktrees.len();
+    let noun = if total == 1 { "worktree" } else { "worktrees" };
+    if details.is_empty() {
+        format!("{total} {noun}")
+    } else {
+        format!("{total} {noun}: {}", details.join(", "))
+    }
+}
+
+fn is_dirty(item: &JsonItem) -> bool {
+    item.working_tree
+        .as_ref()
+        .is_some_and(|wt| wt.staged || wt.modified || wt.untracked || wt.renamed || wt.deleted)
+}
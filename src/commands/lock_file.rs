@@ -0,0 +1,329 @@
+//! `wt lock-file` — advisory locks shared by every worktree of a repository.
+//!
+//! Worktrees share resources outside of git — a local database schema, a port,
+//! generated fixtures. Two agents regenerating one at once clobber each other;
+//! a lock taken with `wt lock-file <path>` lets them take turns.
+//!
+//! # Storage
+//!
+//! One JSON file per lock in `.git/wt/locks/`, created with `create_new` so
+//! only one worktree can win a race. The file records the holder's branch,
+//! worktree, and PID — the process that ran `wt lock-file` (typically a shell
+//! or hook), since `wt` itself exits right away. A lock whose holder has
+//! exited is stale and taken over by the next acquirer.
+
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::Context;
+use color_print::cformat;
+use serde::{Deserialize, Serialize};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::{format_path_for_display, sanitize_for_filename};
+use worktrunk::styling::{
+    eprintln, info_message, println, progress_message, success_message, warning_message,
+};
+use worktrunk::utils::epoch_now;
+
+use crate::cli::SwitchFormat;
+
+/// How often `--wait` checks whether the lock came free.
+const WAIT_POLL: Duration = Duration::from_millis(200);
+
+/// A held lock, as recorded in its file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileLock {
+    /// The locked path, as given to `wt lock-file`
+    pub path: String,
+    /// Branch of the holder's worktree (null when detached)
+    pub branch: Option<String>,
+    /// The holder's worktree
+    pub worktree: PathBuf,
+    /// Process holding the lock
+    pub pid: u32,
+    /// Unix timestamp the lock was taken
+    pub acquired_at: u64,
+}
+
+impl FileLock {
+    fn holder(&self) -> String {
+        let branch = self.branch.as_deref().unwrap_or("(detached)");
+        cformat!("<bold>{branch}</> (PID {})", self.pid)
+    }
+}
+
+fn locks_dir(repo: &Repository) -> PathBuf {
+    repo.wt_dir().join("locks")
+}
+
+fn lock_file(repo: &Repository, path: &str) -> PathBuf {
+    locks_dir(repo).join(format!("{}.json", sanitize_for_filename(path)))
+}
+
+fn read_lock(file: &Path) -> Option<FileLock> {
+    let content = fs::read_to_string(file).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// The process a lock taken now is held by: whoever ran `wt`.
+fn holder_pid() -> u32 {
+    #[cfg(unix)]
+    {
+        std::os::unix::process::parent_id()
+    }
+    #[cfg(not(unix))]
+    {
+        std::process::id()
+    }
+}
+
+#[cfg(unix)]
+fn holder_alive(pid: u32) -> bool {
+    // Signal 0 checks existence without delivering anything; EPERM means the
+    // process exists under another user.
+    match nix::sys::signal::kill(nix::unistd::Pid::from_raw(pid as i32), None) {
+        Ok(()) => true,
+        Err(nix::errno::Errno::EPERM) => true,
+        Err(_) => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn holder_alive(_pid: u32) -> bool {
+    true
+}
+
+/// Every lock whose holder is still running.
+pub fn held_locks(repo: &Repository) -> Vec<FileLock> {
+    let Ok(entries) = fs::read_dir(locks_dir(repo)) else {
+        return Vec::new();
+    };
+    let mut locks: Vec<FileLock> = entries
+        .flatten()
+        .filter_map(|entry| read_lock(&entry.path()))
+        .filter(|lock| holder_alive(lock.pid))
+        .collect();
+    locks.sort_by(|a, b| a.path.cmp(&b.path));
+    locks
+}
+
+/// Remove the lock file at `file` if it still records `stale`, so a holder
+/// that took it over in the meantime keeps it.
+fn remove_stale(file: &Path, stale: &FileLock) -> anyhow::Result<()> {
+    if read_lock(file).as_ref() == Some(stale) {
+        match fs::remove_file(file) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                return Err(e).with_context(|| {
+                    format!(
+                        "Failed to remove stale lock {}",
+                        format_path_for_display(file)
+                    )
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Outcome of one attempt to take a lock.
+enum Attempt {
+    Acquired,
+    AlreadyHeld,
+    /// Held by someone else; `None` while the holder is still writing the file
+    HeldBy(Option<FileLock>),
+}
+
+fn try_acquire(repo: &Repository, path: &str, lock: &FileLock) -> anyhow::Result<Attempt> {
+    let file = lock_file(repo, path);
+    fs::create_dir_all(locks_dir(repo)).context("Failed to create locks directory")?;
+    loop {
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&file)
+        {
+            Ok(mut f) => {
+                f.write_all(serde_json::to_string_pretty(lock)?.as_bytes())
+                    .with_context(|| {
+                        format!("Failed to write {}", format_path_for_display(&file))
+                    })?;
+                return Ok(Attempt::Acquired);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!("Failed to create {}", format_path_for_display(&file))
+                });
+            }
+        }
+
+        let Some(existing) = read_lock(&file) else {
+            return Ok(Attempt::HeldBy(None));
+        };
+        if existing.pid == lock.pid {
+            return Ok(Attempt::AlreadyHeld);
+        }
+        if holder_alive(existing.pid) {
+            return Ok(Attempt::HeldBy(Some(existing)));
+        }
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Taking over <bold>{path}</> from {}, which exited without releasing it",
+                existing.holder()
+            ))
+        );
+        remove_stale(&file, &existing)?;
+    }
+}
+
+/// Handle `wt lock-file [PATH] [--release | --check | --wait]`.
+pub fn handle_lock_file(
+    path: Option<String>,
+    release: bool,
+    check: bool,
+    wait: bool,
+    format: SwitchFormat,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let Some(path) = path else {
+        return list_locks(&repo, format);
+    };
+
+    if release {
+        return release_lock(&repo, &path);
+    }
+
+    let worktree = repo.current_worktree();
+    let lock = FileLock {
+        path: path.clone(),
+        branch: worktree.branch()?,
+        worktree: worktree.root()?,
+        pid: holder_pid(),
+        acquired_at: epoch_now(),
+    };
+
+    if check {
+        return match read_lock(&lock_file(&repo, &path)) {
+            Some(existing) if existing.pid == lock.pid => {
+                eprintln!(
+                    "{}",
+                    info_message(cformat!("<bold>{path}</> is locked by this process"))
+                );
+                Ok(())
+            }
+            Some(existing) if holder_alive(existing.pid) => {
+                Err(locked_error(&path, Some(&existing)))
+            }
+            _ => {
+                eprintln!(
+                    "{}",
+                    info_message(cformat!("<bold>{path}</> is not locked"))
+                );
+                Ok(())
+            }
+        };
+    }
+
+    let mut announced = false;
+    loop {
+        match try_acquire(&repo, &path, &lock)? {
+            Attempt::Acquired => {
+                eprintln!("{}", success_message(cformat!("Locked <bold>{path}</>")));
+                return Ok(());
+            }
+            Attempt::AlreadyHeld => {
+                eprintln!(
+                    "{}",
+                    info_message(cformat!(
+                        "<bold>{path}</> is already locked by this process"
+                    ))
+                );
+                return Ok(());
+            }
+            Attempt::HeldBy(existing) if !wait => {
+                return Err(locked_error(&path, existing.as_ref()));
+            }
+            Attempt::HeldBy(existing) => {
+                if !announced && let Some(existing) = existing {
+                    eprintln!(
+                        "{}",
+                        progress_message(cformat!(
+                            "Waiting for <bold>{path}</>, locked by {}...",
+                            existing.holder()
+                        ))
+                    );
+                    announced = true;
+                }
+                std::thread::sleep(WAIT_POLL);
+            }
+        }
+    }
+}
+
+fn locked_error(path: &str, holder: Option<&FileLock>) -> anyhow::Error {
+    let message = match holder {
+        Some(holder) => cformat!(
+            "<bold>{path}</> is locked by {} in <bold>{}</>",
+            holder.holder(),
+            format_path_for_display(&holder.worktree)
+        ),
+        None => cformat!("<bold>{path}</> is being locked by another process"),
+    };
+    GitError::Other { message }.into()
+}
+
+/// Release `path`'s lock. Only the worktree holding it can release a live
+/// lock; a stale one is cleared from anywhere.
+fn release_lock(repo: &Repository, path: &str) -> anyhow::Result<()> {
+    let file = lock_file(repo, path);
+    let Some(existing) = read_lock(&file) else {
+        eprintln!(
+            "{}",
+            info_message(cformat!("<bold>{path}</> is not locked"))
+        );
+        return Ok(());
+    };
+    let root = repo.current_worktree().root()?;
+    if existing.worktree != root && holder_alive(existing.pid) {
+        return Err(locked_error(path, Some(&existing)));
+    }
+    if existing.worktree != root {
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Clearing <bold>{path}</>, left behind by {}",
+                existing.holder()
+            ))
+        );
+    }
+    remove_stale(&file, &existing)?;
+    eprintln!("{}", success_message(cformat!("Released <bold>{path}</>")));
+    Ok(())
+}
+
+/// Handle `wt lock-file` without a path.
+fn list_locks(repo: &Repository, format: SwitchFormat) -> anyhow::Result<()> {
+    let locks = held_locks(repo);
+    if format == SwitchFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&locks)?);
+        return Ok(());
+    }
+    if locks.is_empty() {
+        eprintln!("{}", info_message("No locks held"));
+        return Ok(());
+    }
+    let width = locks.iter().map(|l| l.path.len()).max().unwrap_or(0);
+    for lock in locks {
+        println!(
+            "{:<width$}  {}  {}",
+            lock.path,
+            lock.holder(),
+            cformat!("<dim>{}</>", format_path_for_display(&lock.worktree)),
+        );
+    }
+    Ok(())
+}
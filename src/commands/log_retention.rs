@@ -0,0 +1,247 @@
+//! Size limits for the logs under `wt_logs_dir()`.
+//!
+//! Hook and background-operation logs are rewritten on every run, so a single
+//! log only holds its latest run — but per-branch subtrees outlive their
+//! branches, and a chatty hook can write a large log. Three mechanisms keep
+//! the directory bounded, all configured by `[logs]` in user config:
+//!
+//! - **Rotation**: before a log is recreated, the previous run's log moves to
+//!   `<name>.log.1.gz` (or `<name>.log.1` with `compress = false`), keeping
+//!   only its last `max-file-size` bytes. One previous run is kept.
+//! - **Total cap**: after a log is created, the oldest files are deleted until
+//!   the directory fits in `max-total-size`.
+//! - **Pruning**: `wt config state logs prune` deletes logs older than a cutoff
+//!   and then applies the total cap.
+//!
+//! All three are best-effort: a failure is logged at debug level and never
+//! fails the command that triggered it.
+
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use worktrunk::config::LogsConfig;
+
+/// Suffix appended to a log's name when it's rotated uncompressed.
+const ROTATED_SUFFIX: &str = ".1";
+/// Suffix appended to a log's name when it's rotated and gzipped.
+const ROTATED_GZ_SUFFIX: &str = ".1.gz";
+
+/// Whether `name` is a rotated log (`<name>.log.1` or `<name>.log.1.gz`).
+pub(crate) fn is_rotated_log(name: &str) -> bool {
+    name.ends_with(".log.1") || name.ends_with(".log.1.gz")
+}
+
+/// Move `path` aside to its rotated name before it's recreated.
+///
+/// Keeps at most `max-file-size` bytes from the end of the log, gzipped
+/// unless `compress = false`. An empty or missing log is left alone.
+pub(crate) fn rotate(path: &Path, config: &LogsConfig) -> io::Result<()> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    let len = file.metadata()?.len();
+    if len == 0 {
+        return Ok(());
+    }
+    let max = config.max_file_size();
+    if len > max {
+        file.seek(SeekFrom::Start(len - max))?;
+    }
+
+    let (keep, stale) = if config.compress() {
+        (ROTATED_GZ_SUFFIX, ROTATED_SUFFIX)
+    } else {
+        (ROTATED_SUFFIX, ROTATED_GZ_SUFFIX)
+    };
+    let mut tail = file.take(max);
+    let rotated = File::create(with_suffix(path, keep))?;
+    if config.compress() {
+        let mut encoder = GzEncoder::new(rotated, Compression::default());
+        io::copy(&mut tail, &mut encoder)?;
+        encoder.finish()?.flush()?;
+    } else {
+        let mut rotated = rotated;
+        io::copy(&mut tail, &mut rotated)?;
+    }
+    match fs::remove_file(with_suffix(path, stale)) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    // Unlink rather than truncate, so a process still writing the previous
+    // run's log doesn't write into the new one.
+    fs::remove_file(path)
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// A file under the log directory, for age and size checks.
+struct LogFile {
+    path: PathBuf,
+    size: u64,
+    /// Modification time, unix seconds (0 when unavailable)
+    modified_at: u64,
+}
+
+fn collect_files(dir: &Path, out: &mut Vec<LogFile>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_files(&entry.path(), out)?;
+        } else if file_type.is_file() {
+            let metadata = entry.metadata()?;
+            let modified_at = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map_or(0, |d| d.as_secs());
+            out.push(LogFile {
+                path: entry.path(),
+                size: metadata.len(),
+                modified_at,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Remove directories under `dir` (but not `dir` itself) left empty.
+fn remove_empty_dirs(dir: &Path) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            let path = entry.path();
+            remove_empty_dirs(&path)?;
+            if fs::read_dir(&path)?.next().is_none() {
+                fs::remove_dir(&path)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// What a prune or cap removed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Removed {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+impl Removed {
+    fn add(&mut self, file: &LogFile) {
+        self.files += 1;
+        self.bytes += file.size;
+    }
+}
+
+/// Delete the oldest files under `log_dir` until it fits in `max_total_size`.
+pub(crate) fn enforce_total_size(log_dir: &Path, max_total_size: u64) -> io::Result<Removed> {
+    let mut removed = Removed::default();
+    if !log_dir.exists() {
+        return Ok(removed);
+    }
+    let mut files = Vec::new();
+    collect_files(log_dir, &mut files)?;
+    let mut total: u64 = files.iter().map(|f| f.size).sum();
+    if total <= max_total_size {
+        return Ok(removed);
+    }
+    files.sort_by(|a, b| {
+        a.modified_at
+            .cmp(&b.modified_at)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    for file in &files {
+        if total <= max_total_size {
+            break;
+        }
+        fs::remove_file(&file.path)?;
+        total -= file.size;
+        removed.add(file);
+    }
+    remove_empty_dirs(log_dir)?;
+    Ok(removed)
+}
+
+/// Delete files under `log_dir` last modified before `cutoff` (unix seconds).
+pub(crate) fn prune_older_than(log_dir: &Path, cutoff: u64) -> io::Result<Removed> {
+    let mut removed = Removed::default();
+    if !log_dir.exists() {
+        return Ok(removed);
+    }
+    let mut files = Vec::new();
+    collect_files(log_dir, &mut files)?;
+    for file in files.iter().filter(|f| f.modified_at < cutoff) {
+        fs::remove_file(&file.path)?;
+        removed.add(file);
+    }
+    remove_empty_dirs(log_dir)?;
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+
+    fn config(max_file_size: &str, compress: bool) -> LogsConfig {
+        LogsConfig {
+            max_file_size: Some(max_file_size.into()),
+            max_total_size: None,
+            compress: Some(compress),
+        }
+    }
+
+    #[test]
+    fn test_rotate_keeps_tail_compressed() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("server.log");
+        fs::write(&log, "0123456789").unwrap();
+        // An uncompressed rotation from an earlier config is replaced
+        fs::write(dir.path().join("server.log.1"), "stale").unwrap();
+
+        rotate(&log, &config("4", true)).unwrap();
+
+        assert!(!log.exists());
+        assert!(!dir.path().join("server.log.1").exists());
+        let mut content = String::new();
+        GzDecoder::new(File::open(dir.path().join("server.log.1.gz")).unwrap())
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "6789");
+    }
+
+    #[test]
+    fn test_rotate_uncompressed_and_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("server.log");
+        rotate(&log, &config("1M", false)).unwrap();
+        assert!(fs::read_dir(dir.path()).unwrap().next().is_none());
+
+        fs::write(&log, "output\n").unwrap();
+        rotate(&log, &config("1M", false)).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.path().join("server.log.1")).unwrap(),
+            "output\n"
+        );
+    }
+
+    #[test]
+    fn test_is_rotated_log() {
+        assert!(is_rotated_log("server.log.1"));
+        assert!(is_rotated_log("server.log.1.gz"));
+        assert!(!is_rotated_log("server.log"));
+        assert!(!is_rotated_log("commands.jsonl.old"));
+    }
+}
@@ -0,0 +1,151 @@
+//! `wt map` — the branch → worktree path mapping, for external tools — and
+//! `wt path`, the one-branch lookup.
+//!
+//! Paths come from `git worktree list`; a named branch without a worktree maps
+//! to [`compute_worktree_path`], so scripts get the same answer `wt switch`
+//! would act on. `wt path` instead fails for it: its output is for `cd`.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+
+use color_print::cformat;
+use serde::Serialize;
+use shell_escape::unix::escape;
+use worktrunk::config::UserConfig;
+use worktrunk::git::{GitError, Repository, ResolvedWorktree};
+use worktrunk::styling::{eprintln, print, println, warning_message};
+
+use super::worktree::compute_worktree_path;
+use crate::cli::MapFormat;
+
+#[derive(Debug, Serialize)]
+struct WorktreeMap {
+    /// Remote `host/owner/name`, or the repository path without a remote
+    repo: String,
+    primary: Option<PathBuf>,
+    /// Branch → worktree path, sorted by branch
+    worktrees: BTreeMap<String, PathBuf>,
+}
+
+/// Handle `wt map [BRANCHES]... [--format json|env]`.
+pub fn handle_map(branches: &[String], format: MapFormat) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let map = build_map(&repo, branches)?;
+    match format {
+        MapFormat::Json => println!("{}", serde_json::to_string_pretty(&map)?),
+        MapFormat::Env => print!("{}", to_env(&map)),
+    }
+    Ok(())
+}
+
+/// Handle `wt path [BRANCH]`.
+pub fn handle_path(branch: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    match repo.resolve_worktree(branch.unwrap_or("@"))? {
+        ResolvedWorktree::Worktree { path, .. } => {
+            println!("{}", path.display());
+            Ok(())
+        }
+        ResolvedWorktree::BranchOnly { branch } => {
+            Err(GitError::WorktreeNotFound { branch }.into())
+        }
+    }
+}
+
+fn build_map(repo: &Repository, branches: &[String]) -> anyhow::Result<WorktreeMap> {
+    let existing: BTreeMap<String, PathBuf> = repo
+        .list_worktrees()?
+        .iter()
+        .filter(|wt| !wt.is_prunable())
+        .filter_map(|wt| Some((wt.branch.clone()?, wt.path.clone())))
+        .collect();
+
+    let worktrees = if branches.is_empty() {
+        existing
+    } else {
+        let config = UserConfig::load()?;
+        branches
+            .iter()
+            .map(|branch| {
+                let path = match existing.get(branch) {
+                    Some(path) => path.clone(),
+                    None => compute_worktree_path(repo, branch, &config)?,
+                };
+                Ok((branch.clone(), path))
+            })
+            .collect::<anyhow::Result<_>>()?
+    };
+
+    Ok(WorktreeMap {
+        repo: repo.project_identifier()?,
+        primary: repo.primary_worktree()?,
+        worktrees,
+    })
+}
+
+/// `WT_WORKTREE_FEATURE_AUTH` for `feature/auth`.
+fn env_key(branch: &str) -> String {
+    let name: String = branch
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("WT_WORKTREE_{name}")
+}
+
+fn env_line(key: &str, value: &str) -> String {
+    format!("{key}={}\n", escape(value.into()))
+}
+
+fn to_env(map: &WorktreeMap) -> String {
+    let mut out = env_line("WT_REPO", &map.repo);
+    if let Some(primary) = &map.primary {
+        out.push_str(&env_line("WT_PRIMARY", &primary.to_string_lossy()));
+    }
+    let mut seen = HashSet::new();
+    for (branch, path) in &map.worktrees {
+        let key = env_key(branch);
+        if !seen.insert(key.clone()) {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Skipping <bold>{branch}</>: <bold>{key}</> is already set by another branch"
+                ))
+            );
+            continue;
+        }
+        out.push_str(&env_line(&key, &path.to_string_lossy()));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_output() {
+        let map = WorktreeMap {
+            repo: "github.com/acme/app".into(),
+            primary: Some("/code/app".into()),
+            worktrees: BTreeMap::from([
+                ("main".into(), "/code/app".into()),
+                ("feature/auth".into(), "/code/app.feature-auth".into()),
+                ("feature-auth".into(), "/code/other".into()),
+                ("spaced".into(), "/code/my app".into()),
+            ]),
+        };
+        insta::assert_snapshot!(to_env(&map), @"
+        WT_REPO=github.com/acme/app
+        WT_PRIMARY=/code/app
+        WT_WORKTREE_FEATURE_AUTH=/code/other
+        WT_WORKTREE_MAIN=/code/app
+        WT_WORKTREE_SPACED='/code/my app'
+        ");
+    }
+}
@@ -1,10 +1,16 @@
+use std::io::IsTerminal;
 use std::path::Path;
+use std::time::Instant;
 
 use anyhow::Context;
+use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::{MergeConfig, UserConfig};
-use worktrunk::git::Repository;
-use worktrunk::styling::{eprintln, info_message};
+use worktrunk::git::{ErrorExt, GitError, Repository, StackedBase, WorktrunkError};
+use worktrunk::stats::StatsEvent;
+use worktrunk::styling::{
+    eprintln, format_with_gutter, info_message, progress_message, success_message, warning_message,
+};
 
 use super::command_approval::approve_commit_template_append;
 use super::command_executor::FailureStrategy;
@@ -13,11 +19,16 @@ use super::context::CommandEnv;
 use super::flag_pair;
 use super::hook_plan::{ApprovedHookPlan, HookPlanBuilder, execute_planned_hook};
 use super::hooks::HookAnnouncer;
+use super::merge_journal::{JournalFlags, MergeJournal, MergeStep, journal_branch};
 use super::repository_ext::RepositoryCliExt;
+use super::stats::format_command_duration;
 use super::template_vars::TemplateVars;
+use crate::cli::StackedBaseMode;
+use crate::output::DirectivePassthrough;
+
 use super::worktree::{
-    FinishAfterMergeArgs, MergeOperations, PushKind, finish_after_merge, handle_no_ff_merge,
-    handle_push,
+    FinishAfterMergeArgs, MergeOperations, PrePushGate, PushKind, finish_after_merge,
+    handle_no_ff_merge, handle_push, sync_merged_submodules,
 };
 
 /// Tri-state CLI overrides for the six `wt merge` boolean flags. `None` =
@@ -29,6 +40,8 @@ pub struct MergeFlagOverrides {
     pub remove: Option<bool>,
     pub ff: Option<bool>,
     pub verify: Option<bool>,
+    /// `--verify` / `--no-verify`: run or skip the `[merge] verify` command.
+    pub verify_command: Option<bool>,
 }
 
 impl MergeFlagOverrides {
@@ -39,10 +52,17 @@ impl MergeFlagOverrides {
             rebase: flag_pair(args.rebase, args.no_rebase),
             remove: flag_pair(args.remove, args.no_remove),
             ff: flag_pair(args.ff, args.no_ff),
-            verify: flag_pair(args.verify, args.no_hooks || args.no_verify),
+            verify: flag_pair(args.verify, args.no_hooks),
+            verify_command: flag_pair(args.verify, args.no_verify),
         }
     }
 
+    /// `--no-verify` predates the verification command as an alias for
+    /// `--no-hooks`, and keeps that meaning when no command is configured.
+    pub fn legacy_no_verify(&self, config: &MergeConfig) -> bool {
+        self.verify_command == Some(false) && config.verify_command().is_none()
+    }
+
     /// Apply the override → effective-config → default-true chain.
     pub fn resolve(&self, config: &MergeConfig) -> ResolvedMergeFlags {
         ResolvedMergeFlags {
@@ -51,7 +71,30 @@ impl MergeFlagOverrides {
             rebase: self.rebase.unwrap_or(config.rebase()),
             remove: self.remove.unwrap_or(config.remove()),
             ff: self.ff.unwrap_or(config.ff()),
-            verify: self.verify.unwrap_or(config.verify()),
+            verify: self
+                .verify
+                .unwrap_or(!self.legacy_no_verify(config) && config.verify()),
+            verify_command: self
+                .verify_command
+                .unwrap_or(true)
+                .then(|| config.verify_command().map(str::to_string))
+                .flatten(),
+        }
+    }
+}
+
+impl From<JournalFlags> for MergeFlagOverrides {
+    /// Replay a journaled merge's flags as explicit overrides, so
+    /// `wt merge --continue` ignores config changes made since the failure.
+    fn from(flags: JournalFlags) -> Self {
+        Self {
+            squash: Some(flags.squash),
+            commit: Some(flags.commit),
+            rebase: Some(flags.rebase),
+            remove: Some(flags.remove),
+            ff: Some(flags.ff),
+            verify: Some(flags.verify),
+            verify_command: Some(flags.verify_command),
         }
     }
 }
@@ -63,6 +106,8 @@ pub struct ResolvedMergeFlags {
     pub remove: bool,
     pub ff: bool,
     pub verify: bool,
+    /// The `[merge] verify` command, unless `--no-verify` skipped it.
+    pub verify_command: Option<String>,
 }
 
 /// Options for the merge command. `flags` carries tri-state CLI overrides for
@@ -72,7 +117,13 @@ pub struct MergeOptions<'a> {
     pub flags: MergeFlagOverrides,
     pub yes: bool,
     pub stage: Option<super::commit::StageMode>,
+    /// How to handle a branch cut from another feature branch; `None` asks.
+    pub stacked_base: Option<StackedBaseMode>,
     pub format: crate::cli::SwitchFormat,
+    /// Journal of an interrupted merge being resumed by `--continue`.
+    pub resume: Option<MergeJournal>,
+    /// Wait in the target's merge queue before rebasing.
+    pub queue: bool,
 }
 
 /// Build the frozen [`ApprovedHookPlan`] for the merge's covered hooks, gating
@@ -83,8 +134,8 @@ pub struct MergeOptions<'a> {
 /// The *anchor* — the executor's plan lookup key — is the worktree each hook
 /// runs in:
 ///
-/// - `pre-commit` / `post-commit` / `pre-merge` / `pre-remove` / `post-remove`
-///   → the feature worktree.
+/// - `pre-commit` / `post-commit` / `pre-merge` / `pre-push` / `pre-remove` /
+///   `post-remove` → the feature worktree.
 /// - `post-merge` / `post-switch` → the merge destination.
 ///
 /// `pre-commit`/`post-commit` execute via the unchanged commit/squash path
@@ -128,6 +179,7 @@ fn approve_merge_plan(
         feature_hooks.push(HookType::PostCommit);
     }
     feature_hooks.push(HookType::PreMerge);
+    feature_hooks.push(HookType::PrePush);
     if will_remove {
         feature_hooks.push(HookType::PreRemove);
         feature_hooks.push(HookType::PostRemove);
@@ -147,6 +199,82 @@ fn approve_merge_plan(
     builder.finish().approve(pid, yes)
 }
 
+/// Pick how to merge a stacked branch: `mode` when given, else ask. Without a
+/// terminal to ask on, fail with the choices rather than guess.
+fn choose_stacked_base(
+    branch: &str,
+    target: &str,
+    stacked: &StackedBase,
+    mode: Option<StackedBaseMode>,
+) -> anyhow::Result<StackedBaseMode> {
+    if let Some(mode) = mode {
+        return Ok(mode);
+    }
+    let error = || GitError::StackedBranch {
+        branch: branch.to_string(),
+        parent: stacked.parent.clone(),
+        target_branch: target.to_string(),
+        commits: stacked.commits,
+    };
+    if !std::io::stdin().is_terminal() {
+        return Err(error().into());
+    }
+
+    let parent = &stacked.parent;
+    let commits = stacked.commits;
+    let noun = if commits == 1 { "commit" } else { "commits" };
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "<bold>{branch}</> was cut from <bold>{parent}</>, which has {commits} {noun} not on <bold>{target}</>"
+        ))
+    );
+    let options = cformat!(
+        "1. Rebase onto <bold>{target}</> without <bold>{parent}</>'s commits\n2. Merge into <bold>{parent}</> instead\n3. Merge everything into <bold>{target}</>"
+    );
+    eprintln!("{}", format_with_gutter(&options, None));
+    const MODES: [StackedBaseMode; 3] = [
+        StackedBaseMode::Rebase,
+        StackedBaseMode::Parent,
+        StackedBaseMode::Include,
+    ];
+    match crate::output::prompt::prompt_choice("Merge how? Enter cancels", MODES.len())? {
+        Some(choice) => Ok(MODES[choice]),
+        None => Err(error().into()),
+    }
+}
+
+/// Rebase the current branch onto `target`, dropping the commits it carries
+/// from the branch it was cut from (`git rebase --onto <target> <base>`).
+fn rebase_off_parent(repo: &Repository, target: &str, stacked: &StackedBase) -> anyhow::Result<()> {
+    eprintln!(
+        "{}",
+        progress_message(cformat!(
+            "Rebasing onto <bold>{target}</> without <bold>{}</>'s commits...",
+            stacked.parent
+        ))
+    );
+    let result = repo.run_command(&[
+        "rebase",
+        "--autostash",
+        "--onto",
+        target,
+        "--end-of-options",
+        &stacked.sha,
+    ]);
+    if let Err(e) = result {
+        if repo.current_worktree().is_rebasing()? {
+            return Err(GitError::RebaseConflict {
+                target_branch: target.to_string(),
+                git_output: e.display_message(),
+            }
+            .into());
+        }
+        return Err(e.context(format!("Failed to rebase onto {target}")));
+    }
+    Ok(())
+}
+
 pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     let json_mode = opts.format == crate::cli::SwitchFormat::Json;
     let MergeOptions {
@@ -154,6 +282,9 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         flags,
         yes,
         stage,
+        stacked_base,
+        resume,
+        queue,
         ..
     } = opts;
 
@@ -180,7 +311,11 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         remove,
         ff,
         verify,
+        verify_command,
     } = flags.resolve(&resolved.merge);
+    if resume.is_none() && flags.legacy_no_verify(&resolved.merge) {
+        crate::warn_no_verify_deprecated();
+    }
     let stage_mode = stage.unwrap_or(resolved.commit.stage());
 
     // Cache current worktree for multiple queries
@@ -204,7 +339,35 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     let squash_enabled = squash && commit;
 
     // Get and validate target branch (must be a branch since we're updating it)
-    let target_branch = repo.require_target_branch(target)?;
+    let mut target_branch = repo.require_target_branch(target)?;
+    // A branch cut from another feature branch carries that branch's commits;
+    // merging it as-is would land them too. A resumed merge already chose.
+    let mut drop_parent = None;
+    if resume.is_none() && current_branch != target_branch {
+        let head_sha = repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string();
+        if let Some(stacked) = repo.stacked_base(&current_branch, &head_sha, &target_branch)? {
+            match choose_stacked_base(&current_branch, &target_branch, &stacked, stacked_base)? {
+                StackedBaseMode::Rebase => drop_parent = Some(stacked),
+                StackedBaseMode::Parent => {
+                    eprintln!(
+                        "{}",
+                        info_message(cformat!(
+                            "Merging into <bold>{}</> instead of <bold>{target_branch}</>",
+                            stacked.parent
+                        ))
+                    );
+                    target_branch = repo.require_target_branch(Some(&stacked.parent))?;
+                }
+                StackedBaseMode::Include => {}
+            }
+        }
+    }
+    // A target that exists only on a remote gets a local tracking branch at
+    // the remote tip, without a checkout — the merge then updates the ref in
+    // place, as it does for any target without a worktree.
+    if !repo.branch(&target_branch).exists_locally()? {
+        create_tracking_target(repo, &target_branch)?;
+    }
     // Worktree for target is optional: if present we use it for safety checks and as destination.
     let target_worktree_path = repo.worktree_for_branch(&target_branch)?;
     // Where `post-merge` / `post-remove` / `post-switch` run: the target
@@ -224,8 +387,8 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     let remove_requested = remove && !on_target;
 
     // Build and approve the frozen hook plan once, at the gate. Every covered
-    // hook (`pre-merge` / `post-merge` / `pre-remove` / `post-remove` /
-    // `post-switch`) executes only from this immutable value — re-reading the
+    // hook (`pre-merge` / `pre-push` / `post-merge` / `pre-remove` /
+    // `post-remove` / `post-switch`) executes only from this immutable value — re-reading the
     // (by-then-rebased / merged) on-disk config is structurally impossible.
     let project_id = repo.project_identifier()?;
     // One anchor for every feature-worktree hook: the canonical root, the same
@@ -252,8 +415,8 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     let commit_hooks = HookGate::from_approval(verify, approved);
 
     // If commands were declined, skip hooks but continue with merge.
-    // Shadow verify to gate all subsequent hook execution (pre-merge, post-merge,
-    // pre-remove, post-switch) on approval.
+    // Shadow verify to gate all subsequent hook execution (pre-merge, pre-push,
+    // post-merge, pre-remove, post-switch) on approval.
     let verify = if approved {
         verify
     } else {
@@ -285,21 +448,58 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     };
     let guidance = super::step::PreApprovedGuidance::Resolved(project_append);
 
+    // Journal each step so a failure can be resumed (`--continue`) or rolled
+    // back (`--abort`). A pending journal from an earlier failed run blocks a
+    // fresh merge: starting over would overwrite the recorded rollback point.
+    let mut journal = match resume {
+        Some(journal) => journal,
+        None => {
+            // The resume/abort hint follows from `handle_merge_command`.
+            if let Some(pending) = MergeJournal::load(repo, &current_branch) {
+                anyhow::bail!(pending.describe());
+            }
+            MergeJournal::begin(
+                repo,
+                &current_branch,
+                &target_branch,
+                JournalFlags {
+                    squash,
+                    commit,
+                    rebase,
+                    remove,
+                    ff,
+                    verify,
+                    verify_command: verify_command.is_some(),
+                },
+            )?
+        }
+    };
+
+    // Before any squash, which would fold the parent's commits in with ours.
+    // Outside the journal's steps: `--abort` rolls it back like any rebase,
+    // and `--continue` after a conflict picks up at the first step.
+    if let Some(stacked) = &drop_parent {
+        rebase_off_parent(repo, &target_branch, stacked)?;
+    }
+
     // Handle uncommitted changes (skip if --no-commit) - track whether commit occurred
     let committed = if commit && current_wt.is_dirty()? {
         if squash_enabled {
             false // Squash path handles staging and committing
         } else {
-            let ctx = env.context(yes);
-            let mut options = CommitOptions::new(&ctx);
-            options.target_branch = Some(&target_branch);
-            options.hooks = commit_hooks;
-            options.stage_mode = stage_mode;
-            options.warn_about_untracked = stage_mode == super::commit::StageMode::All;
-            options.show_no_squash_note = true;
-            options.guidance = guidance.clone();
-
-            let _ = options.commit(&mut announcer)?;
+            journal.step(repo, MergeStep::Commit, || {
+                let ctx = env.context(yes);
+                let mut options = CommitOptions::new(&ctx);
+                options.target_branch = Some(&target_branch);
+                options.hooks = commit_hooks;
+                options.stage_mode = stage_mode;
+                options.warn_about_untracked = stage_mode == super::commit::StageMode::All;
+                options.show_no_squash_note = true;
+                options.guidance = guidance.clone();
+
+                let _ = options.commit(&mut announcer)?;
+                Ok(())
+            })?;
             true // Committed directly
         }
     } else {
@@ -310,69 +510,175 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     // Pass `commit_hooks` (not the shadowed `verify`) so handle_squash gets the
     // --no-hooks vs declined-approval distinction.
     let squashed = if squash_enabled {
-        matches!(
-            super::step::handle_squash(
-                Some(&target_branch),
-                yes,
-                commit_hooks,
-                Some(stage_mode),
-                &mut announcer,
-                guidance,
-            )?,
-            super::step::SquashResult::Squashed { .. }
-        )
+        journal.step(repo, MergeStep::Squash, || {
+            Ok(matches!(
+                super::step::handle_squash(
+                    Some(&target_branch),
+                    yes,
+                    commit_hooks,
+                    Some(stage_mode),
+                    &mut announcer,
+                    guidance,
+                )?,
+                super::step::SquashResult::Squashed { .. }
+            ))
+        })?
     } else {
         false
     };
 
-    // Rebase onto target - track whether rebasing occurred
-    let rebased = if rebase {
-        // Auto-rebase onto target
-        matches!(
-            super::step::handle_rebase(Some(&target_branch))?,
-            super::step::RebaseResult::Rebased { .. }
-        )
+    // Queued merges hold the target from rebase through merge, so each one
+    // rebases onto the tip the previous one left.
+    let queue_turn = if queue {
+        Some(super::merge_queue::wait_for_turn(
+            repo,
+            &current_branch,
+            &target_branch,
+        )?)
     } else {
-        // --no-rebase: verify already rebased, fail if not
-        if !repo.is_rebased_onto(&target_branch)? {
-            return Err(worktrunk::git::GitError::NotRebased { target_branch }.into());
-        }
-        false // Already rebased, no rebase occurred
+        None
     };
 
+    // Rebase onto target - track whether rebasing occurred
+    let rebased = journal.step(repo, MergeStep::Rebase, || {
+        if rebase {
+            // Auto-rebase onto target
+            Ok(matches!(
+                super::step::handle_rebase(Some(&target_branch), false)?,
+                super::step::RebaseResult::Rebased { .. }
+            ))
+        } else {
+            // --no-rebase: verify already rebased, fail if not
+            if !repo.is_rebased_onto(&target_branch)? {
+                return Err(worktrunk::git::GitError::NotRebased {
+                    target_branch: target_branch.clone(),
+                }
+                .into());
+            }
+            Ok(false) // Already rebased, no rebase occurred
+        }
+    })?;
+
+    // The verification command checks the rebased branch, ahead of the
+    // pre-merge hooks, so a failing test suite stops the merge before
+    // anything lands on the target.
+    if let Some(command) = &verify_command {
+        journal.step(repo, MergeStep::Verify, || {
+            run_verify_command(&feature_root, command)
+        })?;
+    }
+
     // Run pre-merge checks unless --no-hooks was specified
     // Do this after commit/squash/rebase to validate the final state that will be pushed
     if verify {
+        journal.step(repo, MergeStep::PreMerge, || {
+            let ctx = env.context(yes);
+            // What the merge brings onto the target: the branch's changes
+            // since it forked from it.
+            let range = format!("{target_branch}...HEAD");
+            let files = repo.worktree_at(&feature_root).changed_files(&[&range])?;
+            let mut vars = TemplateVars::new()
+                .with_target(&target_branch)
+                .with_changed_files(&files);
+            if let Some(p) = target_worktree_path.as_deref() {
+                vars = vars.with_target_worktree_path(p);
+            }
+            execute_planned_hook(
+                &plan,
+                &feature_root,
+                &ctx,
+                HookType::PreMerge,
+                &vars.as_extra_vars(),
+                FailureStrategy::FailFast,
+                crate::output::pre_hook_display_path(ctx.worktree_path),
+            )
+        })?;
+    }
+
+    // Merge to target branch
+    let operations = Some(MergeOperations {
+        committed,
+        squashed,
+        rebased: rebased || drop_parent.is_some(),
+    });
+    // pre-push runs inside the merge step, once the exact range that will land
+    // on the target is known.
+    let pre_push = |target_commit: &str, commit: &str| {
         let ctx = env.context(yes);
         let mut vars = TemplateVars::new().with_target(&target_branch);
         if let Some(p) = target_worktree_path.as_deref() {
             vars = vars.with_target_worktree_path(p);
         }
+        let vars = vars.with_push_range(target_commit, commit);
         execute_planned_hook(
             &plan,
             &feature_root,
             &ctx,
-            HookType::PreMerge,
+            HookType::PrePush,
             &vars.as_extra_vars(),
             FailureStrategy::FailFast,
             crate::output::pre_hook_display_path(ctx.worktree_path),
-        )?;
+        )
+    };
+    let pre_push: Option<PrePushGate<'_>> = verify.then_some(&pre_push);
+    // Record where the target stood so `wt step revert-merge` can undo the
+    // merge. A resumed merge keeps the tips from its first attempt.
+    let target_tip = || -> anyhow::Result<String> {
+        Ok(repo
+            .run_command(&[
+                "rev-parse",
+                "--verify",
+                &format!("refs/heads/{target_branch}"),
+            ])?
+            .trim()
+            .to_string())
+    };
+    if journal.target_before.is_none() {
+        journal.target_before = Some(target_tip()?);
     }
+    journal.step(repo, MergeStep::Merge, || {
+        if !ff {
+            // Create a merge commit on the target branch via commit-tree + update-ref
+            let _ =
+                handle_no_ff_merge(Some(&target_branch), operations, &current_branch, pre_push)?;
+        } else {
+            // Fast-forward push to target branch
+            let _ = handle_push(
+                Some(&target_branch),
+                PushKind::MergeFastForward,
+                operations,
+                pre_push,
+            )?;
+        }
+        // Inside the step so a resumed merge doesn't count twice
+        worktrunk::stats::record(StatsEvent::Merged {
+            branch: current_branch.clone(),
+            target: target_branch.clone(),
+        });
+        Ok(())
+    })?;
 
-    // Merge to target branch
-    let operations = Some(MergeOperations {
-        committed,
-        squashed,
-        rebased,
-    });
-    if !ff {
-        // Create a merge commit on the target branch via commit-tree + update-ref
-        let _ = handle_no_ff_merge(Some(&target_branch), operations, &current_branch)?;
-    } else {
-        // Fast-forward push to target branch
-        let _ = handle_push(Some(&target_branch), PushKind::MergeFastForward, operations)?;
+    if journal.target_after.is_none() {
+        journal.target_after = Some(target_tip()?);
+    }
+    drop(queue_turn);
+
+    if let Some(target) = target_worktree_path.as_deref()
+        && let Some(project) = repo.project_config()?
+        && !project.submodules.follow.is_empty()
+    {
+        sync_merged_submodules(
+            repo,
+            &feature_root,
+            target,
+            &current_branch,
+            &project.submodules.follow,
+        );
     }
 
+    // Removal moves the feature worktree away, so the journal's final write
+    // (its deletion) runs from the destination rather than `repo`'s cwd.
+    journal.start(repo, MergeStep::Remove)?;
     let removed = finish_after_merge(
         repo,
         config,
@@ -388,9 +694,19 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
             plan: &plan,
         },
     )?;
+    let destination_repo = Repository::at(&destination_path)?;
+    journal.complete(&destination_repo)?;
 
     announcer.flush()?;
 
+    super::rebase_others::rebase_others(
+        &destination_repo,
+        &target_branch,
+        &current_branch,
+        resolved.merge.rebase_others(),
+        yes,
+    )?;
+
     if json_mode {
         let output = serde_json::json!({
             "branch": current_branch,
@@ -405,3 +721,141 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Run the `[merge] verify` command in the feature worktree, reporting how
+/// long it took.
+fn run_verify_command(worktree: &Path, command: &str) -> anyhow::Result<()> {
+    eprintln!(
+        "{}",
+        progress_message(cformat!("Verifying with <bold>{command}</>..."))
+    );
+    let started = Instant::now();
+    let result = crate::output::execute_shell_command(
+        worktree,
+        command,
+        None,
+        Some("merge verify"),
+        DirectivePassthrough::default(),
+        true,
+    );
+    let elapsed = format_command_duration(started.elapsed().as_millis() as u64);
+    match result {
+        Ok(()) => {
+            eprintln!(
+                "{}",
+                success_message(cformat!("Verified with <bold>{command}</> in {elapsed}"))
+            );
+            Ok(())
+        }
+        Err(err) => match err.downcast_ref::<WorktrunkError>() {
+            Some(WorktrunkError::ChildProcessExited {
+                code, signal: None, ..
+            }) => Err(WorktrunkError::ChildProcessExited {
+                code: *code,
+                message: cformat!(
+                    "Verification failed after {elapsed}: <bold>{command}</> exited with code {code}"
+                ),
+                signal: None,
+            }
+            .into()),
+            _ => Err(err),
+        },
+    }
+}
+
+/// Create a local branch for a target that exists only on a remote, tracking
+/// the primary remote's copy when several remotes have it.
+fn create_tracking_target(repo: &Repository, branch: &str) -> anyhow::Result<()> {
+    let remotes = repo.branch(branch).remotes()?;
+    let primary = repo.primary_remote().ok();
+    let remote = primary
+        .filter(|primary| remotes.contains(primary))
+        .or_else(|| remotes.into_iter().next())
+        .context("Target branch has no remote to track")?;
+    let upstream = format!("{remote}/{branch}");
+    repo.run_command(&["branch", "--track", branch, &upstream])
+        .with_context(|| format!("Failed to create local branch {branch}"))?;
+    eprintln!(
+        "{}",
+        info_message(cformat!(
+            "Created local branch <bold>{branch}</> tracking <bold>{upstream}</>"
+        ))
+    );
+    Ok(())
+}
+
+/// The current worktree's pending journal, or an error naming the branch.
+fn load_pending_journal(repo: &Repository, action: &str) -> anyhow::Result<MergeJournal> {
+    let branch = journal_branch(repo)?.ok_or_else(|| worktrunk::git::GitError::DetachedHead {
+        action: Some(action.into()),
+    })?;
+    MergeJournal::load(repo, &branch)
+        .ok_or_else(|| anyhow::anyhow!(cformat!("No interrupted merge for <bold>{branch}</>")))
+}
+
+/// Handle `wt merge --continue`: resume the current branch's interrupted merge.
+///
+/// Replays the journaled target and flags. Steps that already completed and
+/// rewrite the branch (commit, squash) are switched off; the rest re-run and
+/// are no-ops when their work is already done (an up-to-date rebase, a target
+/// that already contains the branch).
+pub fn handle_merge_continue(
+    yes: bool,
+    format: crate::cli::SwitchFormat,
+    queue: bool,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let journal = load_pending_journal(&repo, "continue a merge")?;
+    let branch = journal.branch().to_string();
+    if repo.current_worktree().is_rebasing()? {
+        anyhow::bail!(cformat!(
+            "Rebase in progress; resolve conflicts and run <bright-black>git rebase --continue</> first"
+        ));
+    }
+
+    let mut flags = MergeFlagOverrides::from(journal.flags);
+    if journal.is_completed(MergeStep::Commit) || journal.is_completed(MergeStep::Squash) {
+        flags.commit = Some(false);
+        flags.squash = Some(false);
+    }
+    let target = journal.target.clone();
+    eprintln!(
+        "{}",
+        info_message(cformat!(
+            "Resuming merge of <bold>{branch}</> into <bold>{target}</>"
+        ))
+    );
+    handle_merge(MergeOptions {
+        target: Some(&target),
+        flags,
+        yes,
+        stage: None,
+        stacked_base: None,
+        format,
+        resume: Some(journal),
+        queue,
+    })
+}
+
+/// Handle `wt merge --abort`: roll the current branch back to where its
+/// interrupted merge started.
+pub fn handle_merge_abort() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let journal = load_pending_journal(&repo, "abort a merge")?;
+    let branch = journal.branch().to_string();
+    let orig_head = repo.short_sha(&journal.orig_head)?;
+    let restored_changes = journal.backup.is_some();
+    journal.abort(&repo)?;
+    let suffix = if restored_changes {
+        " and restored uncommitted changes"
+    } else {
+        ""
+    };
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Aborted merge; reset <bold>{branch}</> to <dim>{orig_head}</>{suffix}"
+        ))
+    );
+    Ok(())
+}
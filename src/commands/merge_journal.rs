@@ -0,0 +1,499 @@
+//! Failure-resume journal for `wt merge`.
+//!
+//! `wt merge` is a multi-step pipeline (commit → squash → rebase → verify →
+//! pre-merge → merge → remove). When a step fails — a rebase conflict, a failing
+//! pre-merge hook, a rejected fast-forward — the user is left with a branch
+//! in some intermediate state. The journal records which steps completed so
+//! `wt merge --continue` can pick up from the failed step and
+//! `wt merge --abort` can put the branch back where it started.
+//!
+//! # Storage
+//!
+//! One JSON value per branch in git config at `worktrunk.state.<branch>.merge`,
+//! alongside markers and vars. Git config (rather than a file under `.git/wt/`)
+//! keeps the journal visible to `wt list` through the bulk config cache, so the
+//! state column costs no extra subprocess per row.
+//!
+//! # Lifecycle
+//!
+//! - [`MergeJournal::begin`] writes the journal before the first step runs,
+//!   recording the branch tip and — when the worktree has uncommitted changes —
+//!   a snapshot commit of the whole working tree at `refs/wt-backup/<branch>`
+//!   (the same ref the squash step writes).
+//! - [`MergeJournal::step`] marks a step as in progress before it runs and
+//!   completed after. A step still marked in progress when the process exits
+//!   (error, Ctrl-C, crash) is the failed step.
+//! - [`MergeJournal::finish`] deletes the journal once the pipeline completes.
+//!   Removal moves the feature worktree away, so this last write runs from
+//!   the merge destination. A completed merge leaves a [`MergeRecord`] at
+//!   `worktrunk.state.<branch>.merged` — the target before and after, the
+//!   original tip, the backup — which `wt step revert-merge` reads to undo it.
+//!
+//! # Abort
+//!
+//! Rolling back is only offered while the target branch is untouched: once
+//! the `merge` step completes the target has moved, and resetting the feature
+//! branch would not undo that. Abort stops an in-progress rebase, then moves
+//! the branch back to the recorded tip without ever discarding working-tree
+//! content: a dirty tree gets `git reset --soft` (everything stays, now as
+//! uncommitted changes); a clean tree gets `git reset --keep` onto the snapshot
+//! followed by a mixed reset to the tip, so the pre-merge changes come back as
+//! uncommitted changes. Staging is not preserved.
+
+use anyhow::Context;
+use color_print::cformat;
+use serde::{Deserialize, Serialize};
+use worktrunk::git::Repository;
+use worktrunk::styling::progress::Phases;
+use worktrunk::utils::epoch_now;
+
+/// One step of the `wt merge` pipeline, in execution order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::IntoStaticStr)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum MergeStep {
+    Commit,
+    Squash,
+    Rebase,
+    Verify,
+    PreMerge,
+    Merge,
+    Remove,
+}
+
+impl MergeStep {
+    pub fn as_str(self) -> &'static str {
+        self.into()
+    }
+}
+
+/// `--progress-json` phases of a merge: the journal steps, announced as each
+/// starts. `pre-push` runs inside `merge`.
+const MERGE_PROGRESS: Phases = Phases::new(
+    "merge",
+    &[
+        ("commit", "Committing changes"),
+        ("squash", "Squashing commits"),
+        ("rebase", "Rebasing onto target"),
+        ("verify", "Running the verification command"),
+        ("pre-merge", "Running pre-merge hooks"),
+        ("merge", "Merging to target"),
+        ("remove", "Removing worktree"),
+    ],
+);
+
+/// The resolved boolean flags a merge ran with, replayed by `--continue`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct JournalFlags {
+    pub squash: bool,
+    pub commit: bool,
+    pub rebase: bool,
+    pub remove: bool,
+    pub ff: bool,
+    pub verify: bool,
+    /// Run the `[merge] verify` command. Absent from journals written before
+    /// it existed, which had no command to run.
+    #[serde(default)]
+    pub verify_command: bool,
+}
+
+/// Persisted progress of one `wt merge` invocation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeJournal {
+    #[serde(skip)]
+    branch: String,
+    pub target: String,
+    /// Branch tip before the first step ran.
+    pub orig_head: String,
+    /// Snapshot commit of the uncommitted changes, when there were any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup: Option<String>,
+    pub flags: JournalFlags,
+    #[serde(default)]
+    pub completed: Vec<MergeStep>,
+    /// Step that started but never finished — the failed step.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_progress: Option<MergeStep>,
+    /// Target tip before the `merge` step first ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_before: Option<String>,
+    /// Target tip once the `merge` step completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_after: Option<String>,
+    pub started_at: u64,
+}
+
+fn config_key(branch: &str) -> String {
+    format!("worktrunk.state.{branch}.merge")
+}
+
+fn record_key(branch: &str) -> String {
+    format!("worktrunk.state.{branch}.merged")
+}
+
+impl MergeJournal {
+    /// Read the journal for `branch`, if a merge of it was interrupted.
+    ///
+    /// Unparseable values read as absent: a corrupt journal must not wedge
+    /// `wt merge` (the user can still clear it with `--abort`'s fallback or
+    /// `wt config state clear`).
+    pub fn load(repo: &Repository, branch: &str) -> Option<Self> {
+        let raw = repo
+            .config_value(&config_key(branch))
+            .ok()
+            .flatten()
+            .filter(|s| !s.is_empty())?;
+        let mut journal: Self = serde_json::from_str(&raw).ok()?;
+        journal.branch = branch.to_string();
+        Some(journal)
+    }
+
+    /// Like [`load`](Self::load), but reads git config directly instead of
+    /// the bulk cache.
+    ///
+    /// `Repository::at` seeds its cache from the process-wide startup
+    /// preload, so a fresh `Repository` created after a failed merge would
+    /// not see the journal that merge wrote.
+    pub fn load_fresh(repo: &Repository, branch: &str) -> Option<Self> {
+        let raw = repo
+            .run_command(&["config", "--get", &config_key(branch)])
+            .ok()?;
+        let mut journal: Self = serde_json::from_str(raw.trim()).ok()?;
+        journal.branch = branch.to_string();
+        Some(journal)
+    }
+
+    /// Every pending journal in the repository, most recent first.
+    pub fn all(repo: &Repository) -> anyhow::Result<Vec<Self>> {
+        let output = repo.get_config_regexp(r"^worktrunk\.state\..+\.merge$")?;
+        let mut journals: Vec<Self> = output
+            .lines()
+            .filter_map(|line| {
+                let (key, value) = line.split_once(' ')?;
+                let branch = key
+                    .strip_prefix("worktrunk.state.")?
+                    .strip_suffix(".merge")?;
+                let mut journal: Self = serde_json::from_str(value).ok()?;
+                journal.branch = branch.to_string();
+                Some(journal)
+            })
+            .collect();
+        journals.sort_by(|a, b| {
+            b.started_at
+                .cmp(&a.started_at)
+                .then_with(|| a.branch.cmp(&b.branch))
+        });
+        Ok(journals)
+    }
+
+    /// Delete every pending journal; returns how many were removed.
+    pub fn clear_all(repo: &Repository) -> anyhow::Result<usize> {
+        let output = repo.get_config_regexp(r"^worktrunk\.state\..+\.merge$")?;
+        let mut cleared = 0;
+        for line in output.lines() {
+            if let Some(config_key) = line.split_whitespace().next() {
+                repo.unset_config(config_key)?;
+                cleared += 1;
+            }
+        }
+        Ok(cleared)
+    }
+
+    /// Record the start of a merge of `branch` into `target`.
+    pub fn begin(
+        repo: &Repository,
+        branch: &str,
+        target: &str,
+        flags: JournalFlags,
+    ) -> anyhow::Result<Self> {
+        let orig_head = repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string();
+        let backup = if repo.current_worktree().is_dirty()? {
+            let message = format!("{branch} → {target} (merge)");
+            Some(snapshot_backup(repo, branch, &orig_head, &message)?)
+        } else {
+            None
+        };
+        let journal = Self {
+            branch: branch.to_string(),
+            target: target.to_string(),
+            orig_head,
+            backup,
+            flags,
+            completed: Vec::new(),
+            in_progress: None,
+            target_before: None,
+            target_after: None,
+            started_at: epoch_now(),
+        };
+        journal.save(repo)?;
+        Ok(journal)
+    }
+
+    pub fn branch(&self) -> &str {
+        &self.branch
+    }
+
+    pub fn is_completed(&self, step: MergeStep) -> bool {
+        self.completed.contains(&step)
+    }
+
+    /// Run one pipeline step, recording it as in progress first and completed
+    /// after. An error leaves the step in progress — that's the failed step.
+    pub fn step<T>(
+        &mut self,
+        repo: &Repository,
+        step: MergeStep,
+        f: impl FnOnce() -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        self.start(repo, step)?;
+        let value = f()?;
+        self.in_progress = None;
+        if !self.completed.contains(&step) {
+            self.completed.push(step);
+        }
+        self.save(repo)?;
+        Ok(value)
+    }
+
+    /// Mark `step` as in progress without a completion write — for the final
+    /// step, whose completion is the journal's deletion.
+    pub fn start(&mut self, repo: &Repository, step: MergeStep) -> anyhow::Result<()> {
+        MERGE_PROGRESS.enter(step.as_str());
+        self.in_progress = Some(step);
+        self.save(repo)
+    }
+
+    /// Delete the journal after the pipeline completes (or is aborted).
+    pub fn finish(self, repo: &Repository) -> anyhow::Result<()> {
+        repo.unset_config(&config_key(&self.branch))?;
+        Ok(())
+    }
+
+    /// Delete the journal of a completed merge, leaving a [`MergeRecord`]
+    /// behind when the target moved.
+    pub fn complete(self, repo: &Repository) -> anyhow::Result<()> {
+        if let (Some(before), Some(after)) = (&self.target_before, &self.target_after)
+            && before != after
+        {
+            let record = MergeRecord {
+                branch: self.branch.clone(),
+                target: self.target.clone(),
+                orig_head: self.orig_head.clone(),
+                backup: self.backup.clone(),
+                target_before: before.clone(),
+                target_after: after.clone(),
+                merged_at: epoch_now(),
+            };
+            let json =
+                serde_json::to_string(&record).context("Failed to serialize merge record")?;
+            repo.set_config(&record_key(&self.branch), &json)?;
+        }
+        self.finish(repo)
+    }
+
+    /// Human-readable description of where the merge stopped.
+    pub fn describe(&self) -> String {
+        match self.in_progress {
+            Some(step) => cformat!(
+                "Merge of <bold>{}</> into <bold>{}</> stopped at <bold>{}</>",
+                self.branch,
+                self.target,
+                step.as_str()
+            ),
+            None => cformat!(
+                "Merge of <bold>{}</> into <bold>{}</> was interrupted",
+                self.branch,
+                self.target
+            ),
+        }
+    }
+
+    fn save(&self, repo: &Repository) -> anyhow::Result<()> {
+        let json = serde_json::to_string(self).context("Failed to serialize merge journal")?;
+        repo.set_config(&config_key(&self.branch), &json)
+    }
+
+    /// Roll the branch back to where the merge started.
+    ///
+    /// Refuses once the `merge` step completed: the target branch has already
+    /// moved and resetting the feature branch wouldn't undo that.
+    pub fn abort(self, repo: &Repository) -> anyhow::Result<()> {
+        if self.is_completed(MergeStep::Merge) {
+            anyhow::bail!(cformat!(
+                "Cannot abort: <bold>{}</> was already merged into <bold>{}</>; run <bright-black>wt merge --continue</> to finish cleanup",
+                self.branch,
+                self.target
+            ));
+        }
+
+        let wt = repo.current_worktree();
+        if wt.is_rebasing()? {
+            repo.run_command(&["rebase", "--abort"])
+                .context("Failed to abort rebase")?;
+        }
+
+        let head = repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string();
+        if head == self.orig_head {
+            // Nothing was committed: the worktree still holds whatever the
+            // merge left (or the user changed) — leave it alone.
+        } else if wt.is_dirty()? {
+            // A step failed with changes still uncommitted (e.g. the squash
+            // commit after its soft reset). A soft reset keeps every byte of
+            // the working tree and index, so nothing can be lost.
+            repo.run_command(&["reset", "--soft", &self.orig_head])
+                .context("Failed to reset branch to its pre-merge commit")?;
+        } else {
+            // Clean tree at a rewritten commit. Check out the snapshot (or the
+            // original tip) with `--keep`, then drop back to the original tip
+            // so the snapshot's changes — including files that were untracked
+            // before the merge auto-staged them — are uncommitted again.
+            let restore_to = self.backup.as_deref().unwrap_or(&self.orig_head);
+            repo.run_command(&["reset", "--keep", restore_to])
+                .context("Failed to reset branch to its pre-merge commit")?;
+            if self.backup.is_some() {
+                repo.run_command(&["reset", "--mixed", "-q", &self.orig_head])
+                    .context("Failed to restore uncommitted changes from backup")?;
+            }
+        }
+
+        self.finish(repo)
+    }
+}
+
+/// What a completed `wt merge` changed, kept so `wt step revert-merge` can
+/// back it out.
+///
+/// One per branch: merging the same branch again replaces the record, and a
+/// successful revert deletes it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeRecord {
+    #[serde(skip)]
+    branch: String,
+    pub target: String,
+    /// Branch tip before the merge's commit/squash/rebase steps.
+    pub orig_head: String,
+    /// Snapshot commit of the uncommitted changes the merge started with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup: Option<String>,
+    /// Target tip before the merge landed.
+    pub target_before: String,
+    /// Target tip right after the merge landed.
+    pub target_after: String,
+    pub merged_at: u64,
+}
+
+impl MergeRecord {
+    /// The record for `branch`, read directly from git config (a merge in
+    /// the same process writes it after the startup preload).
+    pub fn load(repo: &Repository, branch: &str) -> Option<Self> {
+        let raw = repo
+            .run_command(&["config", "--get", &record_key(branch)])
+            .ok()?;
+        let mut record: Self = serde_json::from_str(raw.trim()).ok()?;
+        record.branch = branch.to_string();
+        Some(record)
+    }
+
+    /// Every merge record in the repository, most recent first.
+    pub fn all(repo: &Repository) -> anyhow::Result<Vec<Self>> {
+        let output = repo.get_config_regexp(r"^worktrunk\.state\..+\.merged$")?;
+        let mut records: Vec<Self> = output
+            .lines()
+            .filter_map(|line| {
+                let (key, value) = line.split_once(' ')?;
+                let branch = key
+                    .strip_prefix("worktrunk.state.")?
+                    .strip_suffix(".merged")?;
+                let mut record: Self = serde_json::from_str(value).ok()?;
+                record.branch = branch.to_string();
+                Some(record)
+            })
+            .collect();
+        records.sort_by(|a, b| {
+            b.merged_at
+                .cmp(&a.merged_at)
+                .then_with(|| a.branch.cmp(&b.branch))
+        });
+        Ok(records)
+    }
+
+    /// Delete every merge record; returns how many were removed.
+    pub fn clear_all(repo: &Repository) -> anyhow::Result<usize> {
+        let output = repo.get_config_regexp(r"^worktrunk\.state\..+\.merged$")?;
+        let mut cleared = 0;
+        for line in output.lines() {
+            if let Some(config_key) = line.split_whitespace().next() {
+                repo.unset_config(config_key)?;
+                cleared += 1;
+            }
+        }
+        Ok(cleared)
+    }
+
+    pub fn branch(&self) -> &str {
+        &self.branch
+    }
+
+    /// Delete the record once the merge has been reverted.
+    pub fn delete(self, repo: &Repository) -> anyhow::Result<()> {
+        repo.unset_config(&record_key(&self.branch))?;
+        Ok(())
+    }
+}
+
+/// The branch a merge journal is keyed by for the current worktree.
+///
+/// A conflicted rebase detaches HEAD, so fall back to the branch git records
+/// for the rebase (surfaced by `list_worktrees`).
+pub fn journal_branch(repo: &Repository) -> anyhow::Result<Option<String>> {
+    let wt = repo.current_worktree();
+    if let Some(branch) = wt.branch()? {
+        return Ok(Some(branch));
+    }
+    let root = wt.root()?;
+    Ok(repo
+        .list_worktrees()?
+        .iter()
+        .find(|info| info.path == root)
+        .and_then(|info| info.branch.clone()))
+}
+
+/// Commit the full working tree — tracked changes and untracked files — on
+/// top of `head` and record it at `refs/wt-backup/<branch>`.
+///
+/// Unlike `git stash create`, the commit includes untracked files, which
+/// `wt merge` auto-stages; rolling back must bring them back too. Staging
+/// happens in a temporary index so the user's index is untouched.
+fn snapshot_backup(
+    repo: &Repository,
+    branch: &str,
+    head: &str,
+    message: &str,
+) -> anyhow::Result<String> {
+    let idx = repo.current_worktree().temp_index()?;
+    let run = |args: &[&str]| -> anyhow::Result<String> {
+        let output = idx.git(args.iter().copied()).run()?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    run(&["add", "-A"]).context("Failed to stage backup snapshot")?;
+    let tree = run(&["write-tree"]).context("Failed to write backup tree")?;
+    let sha = run(&["commit-tree", &tree, "-p", head, "-m", message])
+        .context("Failed to create backup commit")?;
+    repo.run_command(&[
+        "update-ref",
+        "--create-reflog",
+        "-m",
+        message,
+        &format!("refs/wt-backup/{branch}"),
+        &sha,
+    ])
+    .context("Failed to create backup ref")?;
+    Ok(sha)
+}
@@ -0,0 +1,204 @@
+//! `wt merge --queue` — one merge into a target branch at a time.
+//!
+//! Parallel merges into the same target race: each rebases onto the tip it
+//! saw, and whichever fast-forwards second is rejected. Queued merges instead
+//! wait their turn, then rebase onto the latest target, run pre-merge hooks,
+//! and update it while holding the queue.
+//!
+//! The queue is a directory per target under `.git/wt/merge-queue/`. Each
+//! waiting merge owns an entry — `<enqueued ns>-<pid>.json` describing it, and
+//! a `.lock` file beside it that it holds an exclusive lock on for as long as
+//! it waits or merges. Entries are served in name order. The lock is taken
+//! before the `.json` appears, so an entry whose lock can be acquired belongs
+//! to a process that exited without cleaning up (Ctrl-C, crash), and whoever
+//! notices removes it.
+
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use color_print::cformat;
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+use worktrunk::git::Repository;
+use worktrunk::path::sanitize_for_filename;
+use worktrunk::styling::{eprintln, info_message, progress_message};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Serialize, Deserialize)]
+struct QueueEntry {
+    branch: String,
+    pid: u32,
+}
+
+/// This process's place in the queue; leaving it (on drop) lets the next
+/// merge proceed.
+pub struct QueueTurn {
+    json: PathBuf,
+    lock_path: PathBuf,
+    _lock: File,
+}
+
+impl Drop for QueueTurn {
+    fn drop(&mut self) {
+        // Remove before the lock is released, so no one sees the entry as stale.
+        let _ = fs::remove_file(&self.json);
+        let _ = fs::remove_file(&self.lock_path);
+    }
+}
+
+fn queue_dir(repo: &Repository, target: &str) -> PathBuf {
+    repo.wt_state_dir()
+        .join("merge-queue")
+        .join(sanitize_for_filename(target))
+}
+
+/// Join the queue for `target` and block until every merge ahead has finished.
+pub fn wait_for_turn(repo: &Repository, branch: &str, target: &str) -> anyhow::Result<QueueTurn> {
+    let dir = queue_dir(repo, target);
+    let turn = enqueue(&dir, branch)?;
+    let mut announced: Option<(String, usize)> = None;
+    loop {
+        let ahead = live_entries_ahead(&dir, &turn.json);
+        let Some(first) = ahead.first() else {
+            if announced.is_some() {
+                eprintln!(
+                    "{}",
+                    info_message(cformat!(
+                        "Merge queue for <bold>{target}</> is clear; merging"
+                    ))
+                );
+            }
+            return Ok(turn);
+        };
+        let status = (first.branch.clone(), ahead.len());
+        if announced.as_ref() != Some(&status) {
+            let others = match ahead.len() {
+                1 => String::new(),
+                n => format!(" ({} more ahead)", n - 1),
+            };
+            eprintln!(
+                "{}",
+                progress_message(cformat!(
+                    "Waiting for <bold>{}</> to merge into <bold>{target}</>{others}",
+                    first.branch
+                ))
+            );
+            announced = Some(status);
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn enqueue(dir: &Path, branch: &str) -> anyhow::Result<QueueTurn> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create merge queue at {}", dir.display()))?;
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+    let pid = std::process::id();
+    let stem = format!("{nanos:020}-{pid}");
+    let lock_path = dir.join(format!("{stem}.lock"));
+    let json = dir.join(format!("{stem}.json"));
+
+    let lock = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .with_context(|| format!("Failed to create {}", lock_path.display()))?;
+    lock.lock_exclusive()
+        .with_context(|| format!("Failed to lock {}", lock_path.display()))?;
+    let turn = QueueTurn {
+        json,
+        lock_path,
+        _lock: lock,
+    };
+    let entry = QueueEntry {
+        branch: branch.to_string(),
+        pid,
+    };
+    fs::write(&turn.json, serde_json::to_string(&entry)?)
+        .with_context(|| format!("Failed to write {}", turn.json.display()))?;
+    Ok(turn)
+}
+
+/// Entries queued before `ours` whose owners are still running, oldest
+/// first. Entries of exited processes are removed along the way.
+fn live_entries_ahead(dir: &Path, ours: &Path) -> Vec<QueueEntry> {
+    let mut queued: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter(|path| path.file_name() < ours.file_name())
+        .collect();
+    queued.sort();
+
+    queued
+        .into_iter()
+        .filter_map(|json| {
+            let lock_path = json.with_extension("lock");
+            if let Ok(lock) = File::open(&lock_path)
+                && lock.try_lock_exclusive().is_ok()
+            {
+                tracing::debug!("Removing stale merge queue entry {}", json.display());
+                let _ = fs::remove_file(&json);
+                let _ = fs::remove_file(&lock_path);
+                return None;
+            }
+            // A missing lock file means the owner is mid-cleanup; a missing
+            // or unreadable entry means it just finished.
+            let raw = fs::read_to_string(&json).ok()?;
+            serde_json::from_str(&raw).ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(entries: &[QueueEntry]) -> Vec<&str> {
+        entries.iter().map(|e| e.branch.as_str()).collect()
+    }
+
+    #[test]
+    fn test_entries_ahead_in_order_and_released_on_drop() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = enqueue(dir.path(), "first").unwrap();
+        let second = enqueue(dir.path(), "second").unwrap();
+        let third = enqueue(dir.path(), "third").unwrap();
+
+        assert!(live_entries_ahead(dir.path(), &first.json).is_empty());
+        assert_eq!(
+            names(&live_entries_ahead(dir.path(), &third.json)),
+            ["first", "second"]
+        );
+
+        drop(first);
+        assert_eq!(
+            names(&live_entries_ahead(dir.path(), &third.json)),
+            ["second"]
+        );
+        drop(second);
+        assert!(live_entries_ahead(dir.path(), &third.json).is_empty());
+    }
+
+    #[test]
+    fn test_stale_entries_are_removed() {
+        let dir = tempfile::tempdir().unwrap();
+        // An entry whose lock nobody holds: its owner exited mid-merge.
+        let stale = dir.path().join("00000000000000000001-1.json");
+        fs::write(&stale, r#"{"branch":"gone","pid":1}"#).unwrap();
+        fs::write(stale.with_extension("lock"), "").unwrap();
+
+        let ours = enqueue(dir.path(), "ours").unwrap();
+        assert!(live_entries_ahead(dir.path(), &ours.json).is_empty());
+        assert!(!stale.exists());
+        assert!(!stale.with_extension("lock").exists());
+    }
+}
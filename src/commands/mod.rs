@@ -1,30 +1,51 @@
 mod alias;
 pub(crate) mod backup;
+pub(crate) mod branch_gc;
 pub(crate) mod command_approval;
 pub(crate) mod command_executor;
 pub(crate) mod commit;
 pub(crate) mod config;
 pub(crate) mod configure_shell;
 pub(crate) mod context;
+pub(crate) mod create_journal;
 pub(crate) mod custom;
+mod daemon;
+mod debug;
 mod eval;
 mod for_each;
+mod grep;
 mod hook_announcement;
 mod hook_commands;
 mod hook_filter;
 pub(crate) mod hook_plan;
+mod hook_sandbox;
+pub(crate) mod hook_status;
 pub(crate) mod hooks;
 pub(crate) mod init;
 pub(crate) mod list;
+pub(crate) mod lock_file;
+pub(crate) mod log_retention;
+mod map;
 pub(crate) mod merge;
+pub(crate) mod merge_journal;
+mod merge_queue;
+pub(crate) mod open;
 pub(crate) mod picker;
+pub(crate) mod pin;
 pub(crate) mod pipeline_spec;
 pub(crate) mod process;
 pub(crate) mod project_config;
+mod rebase_others;
 mod relocate;
 pub(crate) mod remove;
+mod repair;
+pub(crate) mod repo_group;
 pub(crate) mod repository_ext;
 mod run_pipeline;
+mod serve;
+mod setup;
+pub(crate) mod snapshot;
+mod stats;
 pub(crate) mod statusline;
 pub(crate) mod step;
 pub(crate) mod template_vars;
@@ -35,35 +56,53 @@ pub(crate) use alias::{
     try_alias,
 };
 pub(crate) use config::{
-    add_approvals, clear_approvals, handle_alias_dry_run, handle_alias_show, handle_cache_clear,
-    handle_cache_get, handle_claude_install, handle_claude_install_statusline,
+    add_approvals, clear_approvals, export_approvals, handle_alias_dry_run, handle_alias_show,
+    handle_cache_clear, handle_cache_get, handle_claude_install, handle_claude_install_statusline,
     handle_claude_uninstall, handle_codex_install, handle_codex_uninstall, handle_config_create,
-    handle_config_show, handle_config_update, handle_hints_clear, handle_hints_get,
-    handle_logs_list, handle_logs_profile, handle_opencode_install, handle_opencode_uninstall,
-    handle_state_clear, handle_state_clear_all, handle_state_get, handle_state_set,
-    handle_state_show, handle_vars_clear, handle_vars_get, handle_vars_list, handle_vars_set,
+    handle_config_show, handle_config_show_theme, handle_config_update, handle_hints_clear,
+    handle_hints_get, handle_hints_list, handle_logs_list, handle_logs_profile, handle_logs_prune,
+    handle_ns_clear, handle_ns_get, handle_ns_list, handle_ns_set, handle_opencode_install,
+    handle_opencode_uninstall, handle_state_clear, handle_state_clear_all, handle_state_gc,
+    handle_state_get, handle_state_set, handle_state_show, handle_vars_clear, handle_vars_get,
+    handle_vars_list, handle_vars_set, import_approvals,
 };
 pub(crate) use configure_shell::{
     handle_configure_shell, handle_show_theme, handle_unconfigure_shell,
 };
 pub(crate) use custom::handle_custom_command;
+pub(crate) use daemon::handle_daemon;
+pub(crate) use debug::handle_debug;
 pub(crate) use eval::step_eval;
 pub(crate) use for_each::step_for_each;
-pub(crate) use hook_commands::{HookCliArgs, handle_hook_show, run_hook};
-pub(crate) use init::{handle_completions, handle_init};
-pub(crate) use list::handle_list;
-pub(crate) use merge::{MergeFlagOverrides, MergeOptions, handle_merge};
+pub(crate) use grep::handle_grep;
+pub(crate) use hook_commands::{HookCliArgs, handle_hook_show, handle_hook_status, run_hook};
+pub(crate) use init::{handle_completions, handle_completions_install, handle_init};
+pub(crate) use list::{handle_list, handle_list_group};
+pub(crate) use lock_file::handle_lock_file;
+pub(crate) use map::{handle_map, handle_path};
+pub(crate) use merge::{
+    MergeFlagOverrides, MergeOptions, handle_merge, handle_merge_abort, handle_merge_continue,
+};
+pub(crate) use open::handle_open;
 pub(crate) use picker::handle_picker;
+pub(crate) use pin::{handle_pin, handle_unpin};
 pub(crate) use remove::handle_remove_command;
+pub(crate) use repair::{handle_repair, warn_if_repository_moved};
 pub(crate) use repository_ext::RemoveTarget;
 pub(crate) use run_pipeline::run_pipeline;
+pub(crate) use serve::handle_serve;
+pub(crate) use setup::handle_setup;
+pub(crate) use snapshot::{handle_snapshot_create, handle_snapshot_delete, handle_snapshot_list};
+pub(crate) use stats::handle_stats;
 pub(crate) use step::{
-    PreApprovedGuidance, PromoteResult, RebaseResult, SquashResult, handle_promote, handle_rebase,
-    handle_squash, step_commit, step_copy_ignored, step_diff, step_dry_run_squash, step_prune,
-    step_relocate, step_show_squash_prompt, step_tether,
+    PreApprovedGuidance, PromoteResult, RebaseResult, SquashResult,
+    check_step_not_on_default_branch, handle_promote, handle_rebase, handle_squash, step_clone,
+    step_commit, step_copy_ignored, step_diff, step_diff_branches, step_dry_run_squash,
+    step_optimize_repo, step_prune, step_push, step_relocate, step_revert_merge,
+    step_show_squash_prompt, step_tether,
 };
 pub(crate) use worktree::{
-    handle_switch_command, is_worktree_at_expected_path, resolve_worktree_arg,
+    DEFAULT_BATCH_JOBS, handle_switch_command, is_worktree_at_expected_path, resolve_worktree_arg,
     worktree_display_name,
 };
 
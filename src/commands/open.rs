@@ -0,0 +1,157 @@
+//! `wt open` — open a worktree in an editor.
+//!
+//! The worktree is resolved like `wt remove` resolves its argument; a branch
+//! without a worktree goes through [`SwitchPipeline`] first (hooks and all, no
+//! `cd`), so `wt open feature` behaves like `wt switch feature` followed by
+//! launching the editor.
+//!
+//! # Editor resolution
+//!
+//! `--editor` preset, then `[open] editor` in user config, then the first of
+//! `code` / `zed` / `idea` on `PATH`, then `$VISUAL` / `$EDITOR`. The command
+//! is a template expanded with the hook variables and run in the worktree.
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{UserConfig, expand_template};
+use worktrunk::git::{GitError, Repository, ResolvedWorktree};
+use worktrunk::path::format_path_for_display;
+use worktrunk::shell_exec::ShellEscapeMode;
+use worktrunk::styling::{eprintln, format_bash_with_gutter, progress_message};
+
+use super::command_executor::{CommandContext, build_hook_context};
+use super::worktree::SwitchPipeline;
+use crate::cli::{OpenEditor, SwitchFormat};
+use crate::output::{DirectivePassthrough, execute_shell_command};
+
+/// GUI editors `auto` looks for on `PATH`, in order.
+const AUTO_EDITORS: &[&str] = &["code", "zed", "idea"];
+
+/// Handle `wt open [BRANCH] [--editor EDITOR]`.
+pub fn handle_open(
+    branch: Option<String>,
+    editor: Option<OpenEditor>,
+    yes: bool,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let mut config = UserConfig::load().context("Failed to load config")?;
+
+    // Resolve the editor before creating anything, so a missing editor
+    // doesn't leave a fresh worktree behind
+    let template = editor_template(editor, &config)?;
+
+    let (path, branch) = match repo.resolve_worktree(branch.as_deref().unwrap_or("@"))? {
+        ResolvedWorktree::Worktree { path, branch } => (path, branch),
+        ResolvedWorktree::BranchOnly { branch } => {
+            SwitchPipeline {
+                repo: &repo,
+                config: &mut config,
+                identifier: &branch,
+                create: false,
+                base: None,
+                clobber: false,
+                verify: true,
+                yes,
+                change_dir: false,
+                tmux_window: false,
+                track: None,
+                format: SwitchFormat::Text,
+                is_recovered: false,
+                suggestion_ctx: None,
+                capture_source: false,
+                execute: None,
+                execute_args: &[],
+                shell_integration_binary: None,
+            }
+            .run()?;
+            // A fresh handle: the worktree list was cached before the switch
+            let path = Repository::current()?
+                .worktree_for_branch(&branch)?
+                .with_context(|| format!("No worktree for {branch} after switching"))?;
+            (path, Some(branch))
+        }
+    };
+
+    let ctx = CommandContext::new(&repo, &config, branch.as_deref(), &path, yes);
+    let context = build_hook_context(&ctx, &[], None)?;
+    let vars = context
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    let command = expand_template(
+        &template,
+        &vars,
+        ShellEscapeMode::Posix,
+        &repo,
+        "editor command",
+    )?;
+
+    let path_display = format_path_for_display(&path);
+    eprintln!(
+        "{}",
+        progress_message(cformat!("Opening <bold>{path_display}</>:"))
+    );
+    eprintln!("{}", format_bash_with_gutter(&command));
+    execute_shell_command(
+        &path,
+        &command,
+        None,
+        Some("open"),
+        DirectivePassthrough::default(),
+        false,
+    )
+}
+
+/// The editor command template for `choice`, falling back to user config and
+/// then to detection.
+fn editor_template(choice: Option<OpenEditor>, config: &UserConfig) -> anyhow::Result<String> {
+    let preset = |bin: &str| format!("{bin} {{{{ worktree_path }}}}");
+    let template = match choice {
+        Some(OpenEditor::Code) => Some(preset("code")),
+        Some(OpenEditor::Zed) => Some(preset("zed")),
+        Some(OpenEditor::Idea) => Some(preset("idea")),
+        Some(OpenEditor::Auto) => detect_editor(),
+        None => config.open.editor.clone().or_else(detect_editor),
+    };
+    template.ok_or_else(|| {
+        GitError::Other {
+            message: cformat!(
+                "No editor found; pass <bold>--editor</> or set <bold>[open] editor</> in user config"
+            ),
+        }
+        .into()
+    })
+}
+
+/// First GUI editor on `PATH`, else `$VISUAL` / `$EDITOR`.
+fn detect_editor() -> Option<String> {
+    let preset = |bin: &str| format!("{bin} {{{{ worktree_path }}}}");
+    if let Some(bin) = AUTO_EDITORS.iter().find(|bin| which::which(bin).is_ok()) {
+        return Some(preset(bin));
+    }
+    ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.trim().is_empty())
+        .map(|value| preset(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editor_template_precedence() {
+        let mut config = UserConfig::default();
+        config.open.editor = Some("subl {{ worktree_path }}".into());
+
+        assert_eq!(
+            editor_template(Some(OpenEditor::Zed), &config).unwrap(),
+            "zed {{ worktree_path }}"
+        );
+        assert_eq!(
+            editor_template(None, &config).unwrap(),
+            "subl {{ worktree_path }}"
+        );
+    }
+}
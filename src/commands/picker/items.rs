@@ -1941,8 +1941,10 @@ mod tests {
             let mut item = ListItem::new_branch("abc".into(), "feature".into());
             item.upstream = Some(UpstreamStatus {
                 remote: remote.map(String::from),
+                tracking: None,
                 ahead: a,
                 behind: b,
+                gone: None,
             });
             LocalContent::from_item(&item).upstream_diverged
         };
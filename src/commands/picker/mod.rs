@@ -856,7 +856,7 @@ fn build_morph_branch_row(
     branch_item.refresh_status_symbols(default_branch);
     let line = strip_osc8_hyperlinks(
         &layout
-            .render_list_item_line(&branch_item, PLACEHOLDER)
+            .render_list_item_line(&branch_item, PLACEHOLDER.as_str())
             .render(),
     );
     (line, LocalContent::from_item(&branch_item))
@@ -1611,6 +1611,7 @@ pub fn handle_picker(
     // Merge CLI flags with resolved config (project-specific config is now available)
     let config = repo.config();
     let change_dir = change_dir_flag.unwrap_or_else(|| config.switch.cd());
+    let tmux_window = config.switch.tmux_window();
     let show_branches = cli_branches || config.list.branches();
     let show_remotes = cli_remotes || config.list.remotes();
     // Flag-only: listing PRs always reaches the forge, so it stays opt-in
@@ -2176,6 +2177,8 @@ summary = true
             verify: true,
             yes: false,
             change_dir,
+            tmux_window,
+            track: None,
             format,
             is_recovered,
             suggestion_ctx: None,
@@ -3027,6 +3030,7 @@ pub mod tests {
                 crate::commands::list::layout::ColumnSelection {
                     custom: &[],
                     selected: None,
+                    ..Default::default()
                 },
             ));
         (row, token, rendered, morphed)
@@ -3982,6 +3986,7 @@ pub mod tests {
             crate::commands::list::layout::ColumnSelection {
                 custom: &[],
                 selected: None,
+                ..Default::default()
             },
         );
 
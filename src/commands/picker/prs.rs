@@ -613,6 +613,9 @@ pub(super) fn forge_noun(repo: &Repository) -> &'static str {
 
 /// Dispatch to the forge that hosts this repository's primary remote.
 fn fetch_open_prs(repo: &Repository) -> anyhow::Result<Vec<PrEntry>> {
+    if repo.user_config().offline() {
+        anyhow::bail!("--prs needs the network; it's unavailable offline");
+    }
     let repo_root = repo
         .current_worktree()
         .root()
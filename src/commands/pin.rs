@@ -0,0 +1,116 @@
+//! `wt pin` / `wt unpin` — keep a branch out of `wt step prune`.
+//!
+//! A pin is a lighter promise than `git worktree lock`: the worktree stays
+//! removable by hand, but `wt step prune` never selects it (nor its branch
+//! once the worktree is gone), and `wt list` marks it with 📌.
+//!
+//! # Storage
+//!
+//! One JSON value per branch in git config at `worktrunk.state.<branch>.pinned`,
+//! alongside markers and vars, so `wt config state clear` removes pins too.
+
+use std::collections::HashSet;
+
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{eprintln, info_message, success_message};
+use worktrunk::utils::epoch_now;
+
+/// Marker `wt list` shows in the Status column for pinned branches.
+pub const PIN_MARKER: &str = "📌";
+
+fn config_key(branch: &str) -> String {
+    format!("worktrunk.state.{branch}.pinned")
+}
+
+/// Whether `branch` is pinned.
+pub fn is_pinned(repo: &Repository, branch: &str) -> bool {
+    repo.config_value(&config_key(branch))
+        .ok()
+        .flatten()
+        .is_some_and(|s| !s.is_empty())
+}
+
+/// Every pinned branch.
+pub fn pinned_branches(repo: &Repository) -> anyhow::Result<HashSet<String>> {
+    let output = repo.get_config_regexp(r"^worktrunk\.state\..+\.pinned$")?;
+    Ok(output
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .filter_map(|key| {
+            key.strip_prefix("worktrunk.state.")
+                .and_then(|s| s.strip_suffix(".pinned"))
+        })
+        .map(str::to_string)
+        .collect())
+}
+
+/// Delete every pin; returns how many were removed.
+pub fn clear_all(repo: &Repository) -> anyhow::Result<usize> {
+    let output = repo.get_config_regexp(r"^worktrunk\.state\..+\.pinned$")?;
+    let mut cleared = 0;
+    for line in output.lines() {
+        if let Some(config_key) = line.split_whitespace().next() {
+            repo.unset_config(config_key)?;
+            cleared += 1;
+        }
+    }
+    Ok(cleared)
+}
+
+/// Handle `wt pin [BRANCH]`.
+pub fn handle_pin(branch: Option<String>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = match branch {
+        Some(branch) => {
+            if !repo.branch(&branch).exists_locally()? {
+                return Err(GitError::BranchNotFound {
+                    branch,
+                    show_create_hint: false,
+                    last_fetch_ago: None,
+                    pr_mr_platform: None,
+                }
+                .into());
+            }
+            branch
+        }
+        None => repo.require_current_branch("pin")?,
+    };
+
+    if is_pinned(&repo, &branch) {
+        eprintln!(
+            "{}",
+            info_message(cformat!("<bold>{branch}</> is already pinned"))
+        );
+        return Ok(());
+    }
+    let json = serde_json::json!({ "pinned_at": epoch_now() });
+    repo.set_config(&config_key(&branch), &json.to_string())?;
+    eprintln!("{}", success_message(cformat!("Pinned <bold>{branch}</>")));
+    Ok(())
+}
+
+/// Handle `wt unpin [BRANCH]`.
+///
+/// Doesn't require the branch to exist, so a pin left behind by a deleted
+/// branch can still be removed.
+pub fn handle_unpin(branch: Option<String>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = match branch {
+        Some(branch) => branch,
+        None => repo.require_current_branch("unpin")?,
+    };
+
+    if repo.unset_config(&config_key(&branch))? {
+        eprintln!(
+            "{}",
+            success_message(cformat!("Unpinned <bold>{branch}</>"))
+        );
+    } else {
+        eprintln!(
+            "{}",
+            info_message(cformat!("<bold>{branch}</> is not pinned"))
+        );
+    }
+    Ok(())
+}
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 
 use worktrunk::HookType;
+use worktrunk::config::SandboxConfig;
 
 use super::hook_filter::HookSource;
 
@@ -25,6 +26,9 @@ pub struct PipelineSpec {
     /// The pipeline runner creates one log file per command here,
     /// named via `HookLog::hook(source, hook_type, name)`.
     pub log_dir: PathBuf,
+    /// `[sandbox]` restrictions, for project pipelines when any are enabled.
+    #[serde(default)]
+    pub sandbox: Option<SandboxConfig>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -38,6 +42,9 @@ pub enum PipelineStepSpec {
         /// foreground's `HookCommandFailed { command_name }`.
         template_name: String,
         template: String,
+        /// Raw `cwd` template; see `PreparedCommand::cwd`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cwd: Option<String>,
     },
     Concurrent {
         commands: Vec<PipelineCommandSpec>,
@@ -50,6 +57,9 @@ pub struct PipelineCommandSpec {
     /// See `PipelineStepSpec::Single::template_name`.
     pub template_name: String,
     pub template: String,
+    /// See `PipelineStepSpec::Single::cwd`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
 }
 
 #[cfg(test)]
@@ -67,11 +77,13 @@ mod tests {
                 .into_iter()
                 .collect(),
             log_dir: "/tmp/test-worktree/.git/wt/logs".into(),
+            sandbox: None,
             steps: vec![
                 PipelineStepSpec::Single {
                     name: Some("install".into()),
                     template_name: "user:install".into(),
                     template: "npm install".into(),
+                    cwd: Some("{{ repo_path }}".into()),
                 },
                 PipelineStepSpec::Concurrent {
                     commands: vec![
@@ -79,11 +91,13 @@ mod tests {
                             name: Some("build".into()),
                             template_name: "user:build".into(),
                             template: "npm run build".into(),
+                            cwd: None,
                         },
                         PipelineCommandSpec {
                             name: None,
                             template_name: "user post-create hook".into(),
                             template: "echo {{ vars.tag }}".into(),
+                            cwd: None,
                         },
                     ],
                 },
@@ -106,10 +120,12 @@ mod tests {
                 name,
                 template_name,
                 template,
+                cwd,
             } => {
                 assert_eq!(name.as_deref(), Some("install"));
                 assert_eq!(template_name, "user:install");
                 assert_eq!(template, "npm install");
+                assert_eq!(cwd.as_deref(), Some("{{ repo_path }}"));
             }
             _ => panic!("expected Single step"),
         }
@@ -9,6 +9,7 @@ use worktrunk::path::{format_path_for_display, sanitize_for_filename};
 use worktrunk::utils::epoch_now;
 
 use crate::commands::hook_filter::HookSource;
+use crate::commands::log_retention;
 
 // ==================== Hook Log Specification ====================
 
@@ -168,6 +169,11 @@ fn create_detach_log(
         )
     })?;
 
+    let limits = &repo.user_config().logs;
+    if let Err(e) = log_retention::rotate(&log_path, limits) {
+        tracing::debug!(error = %e, "Failed to rotate {}: {e}", log_path.display());
+    }
+
     let log_file = fs::File::create(&log_path).with_context(|| {
         format!(
             "Failed to create log file {}",
@@ -175,6 +181,10 @@ fn create_detach_log(
         )
     })?;
 
+    if let Err(e) = log_retention::enforce_total_size(&log_dir, limits.max_total_size()) {
+        tracing::debug!(error = %e, "Failed to cap log directory size: {e}");
+    }
+
     Ok((log_path, log_file))
 }
 
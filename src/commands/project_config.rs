@@ -8,6 +8,8 @@ use worktrunk::git::HookType;
 pub enum Phase {
     Hook(HookType),
     Alias,
+    /// A `[list.columns]` command, run by `wt list` in each worktree.
+    ListColumn,
     /// Project-level commit-message append fragment — not a shell command.
     /// Approving records the raw fragment as "approved" so subsequent LLM
     /// calls include it without re-prompting.
@@ -19,6 +21,7 @@ impl fmt::Display for Phase {
         match self {
             Phase::Hook(hook_type) => write!(f, "{hook_type}"),
             Phase::Alias => write!(f, "alias"),
+            Phase::ListColumn => write!(f, "list-column"),
             Phase::CommitTemplateAppend => write!(f, "commit-template-append"),
         }
     }
@@ -81,6 +84,20 @@ pub fn collect_commands_for_aliases(project_config: &ProjectConfig) -> Vec<Appro
         .collect()
 }
 
+/// Collect the command of every `[list.columns]` entry, in `BTreeMap`
+/// (alphabetical) order, each named by its column header.
+pub fn collect_commands_for_list_columns(project_config: &ProjectConfig) -> Vec<ApprovableCommand> {
+    project_config
+        .list
+        .columns
+        .iter()
+        .map(|(name, column)| ApprovableCommand {
+            phase: Phase::ListColumn,
+            command: Command::new(Some(name.clone()), column.command.clone()),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,212 @@
+//! `merge.rebase-others`: carry sibling worktrees along after `wt merge`
+//! advances the target branch.
+//!
+//! Once the target moves, every other worktree branched from it falls behind.
+//! Clean ones are rebased onto the new tip in parallel; dirty ones, ones with
+//! a rebase or merge already underway, and ones whose rebase conflicts are
+//! left untouched and reported for manual attention. A conflicting rebase is
+//! aborted, so each worktree ends either rebased or exactly as it was.
+
+use std::io::{self, IsTerminal};
+
+use color_print::cformat;
+use rayon::prelude::*;
+use worktrunk::config::RebaseOthers;
+use worktrunk::git::{Repository, WorktreeInfo};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    eprintln, format_bash_with_gutter, format_with_gutter, hint_message, info_message,
+    progress_message, success_message, suggest_command_in_dir, warning_message,
+};
+
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
+
+/// A worktree behind the target that can't be rebased automatically.
+struct Attention<'a> {
+    wt: &'a WorktreeInfo,
+    reason: String,
+}
+
+/// Rebase the worktrees that fell behind `target` when `merged_branch` landed
+/// on it, as `mode` allows. `yes` turns `prompt` into `auto`.
+///
+/// `repo` must not be rooted in `merged_branch`'s worktree, which the merge
+/// may have removed.
+pub(crate) fn rebase_others(
+    repo: &Repository,
+    target: &str,
+    merged_branch: &str,
+    mode: RebaseOthers,
+    yes: bool,
+) -> anyhow::Result<()> {
+    if mode == RebaseOthers::Never {
+        return Ok(());
+    }
+
+    let target_sha = repo
+        .run_command(&["rev-parse", "--verify", "--end-of-options", target])?
+        .trim()
+        .to_string();
+    let mut candidates = Vec::new();
+    let mut attention = Vec::new();
+    for wt in repo.list_worktrees()? {
+        let Some(branch) = wt.branch.as_deref() else {
+            continue;
+        };
+        if wt.bare || wt.is_prunable() || branch == target || branch == merged_branch {
+            continue;
+        }
+        // Behind the target and with commits of its own. Branches without a
+        // common ancestor, already on the tip, or fully contained in the
+        // target are left alone.
+        let Some(merge_base) = repo.merge_base(&wt.head, &target_sha)? else {
+            continue;
+        };
+        if merge_base == target_sha || merge_base == wt.head {
+            continue;
+        }
+        let working_tree = repo.worktree_at(&wt.path);
+        if working_tree.is_rebasing()? || working_tree.is_merging()? {
+            attention.push(Attention {
+                wt,
+                reason: "rebase or merge in progress".into(),
+            });
+        } else if working_tree.is_dirty()? {
+            attention.push(Attention {
+                wt,
+                reason: "uncommitted changes".into(),
+            });
+        } else {
+            candidates.push(wt);
+        }
+    }
+
+    if candidates.is_empty() && attention.is_empty() {
+        return Ok(());
+    }
+
+    if !candidates.is_empty() && mode == RebaseOthers::Prompt && !yes {
+        let branches = candidates
+            .iter()
+            .map(|wt| cformat!("<bold>{}</>", wt.branch.as_deref().unwrap_or_default()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let noun = if candidates.len() == 1 {
+            "worktree"
+        } else {
+            "worktrees"
+        };
+        let confirmed = io::stdin().is_terminal()
+            && prompt_yes_no_preview(
+                &cformat!("Rebase {noun} {branches} onto <bold>{target}</>?"),
+                || {
+                    let paths: Vec<String> = candidates
+                        .iter()
+                        .map(|wt| format_path_for_display(&wt.path))
+                        .collect();
+                    eprintln!("{}", format_with_gutter(&paths.join("\n"), None));
+                },
+            )? == PromptResponse::Accepted;
+        if !confirmed {
+            eprintln!(
+                "{}",
+                info_message(cformat!(
+                    "Not rebasing {noun} behind <bold>{target}</>: {branches}"
+                ))
+            );
+            candidates.clear();
+        }
+    }
+
+    if !candidates.is_empty() {
+        eprintln!(
+            "{}",
+            progress_message(cformat!(
+                "Rebasing {} onto <bold>{target}</>...",
+                count_worktrees(candidates.len())
+            ))
+        );
+    }
+    let outcomes: Vec<(&WorktreeInfo, anyhow::Result<Option<String>>)> = candidates
+        .par_iter()
+        .map(|wt| (*wt, rebase_one(repo, wt, target)))
+        .collect();
+
+    let mut rebased = Vec::new();
+    for (wt, outcome) in outcomes {
+        match outcome? {
+            None => rebased.push(wt.branch.as_deref().unwrap_or_default()),
+            Some(reason) => attention.push(Attention { wt, reason }),
+        }
+    }
+
+    if !rebased.is_empty() {
+        let branches = rebased
+            .iter()
+            .map(|b| cformat!("<bold>{b}</>"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!(
+            "{}",
+            success_message(cformat!("Rebased {branches} onto <bold>{target}</>"))
+        );
+    }
+
+    if !attention.is_empty() {
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Not rebased onto <bold>{target}</>: {}",
+                count_worktrees(attention.len())
+            ))
+        );
+        let reasons: Vec<String> = attention
+            .iter()
+            .map(|a| {
+                format!(
+                    "{}: {}",
+                    a.wt.branch.as_deref().unwrap_or_default(),
+                    a.reason
+                )
+            })
+            .collect();
+        eprintln!("{}", format_with_gutter(&reasons.join("\n"), None));
+        let commands: Vec<String> = attention
+            .iter()
+            .map(|a| suggest_command_in_dir(&a.wt.path, "step", &["rebase", target], &[]))
+            .collect();
+        eprintln!("{}", hint_message("To rebase them by hand:"));
+        eprintln!("{}", format_bash_with_gutter(&commands.join("\n")));
+    }
+
+    Ok(())
+}
+
+/// Rebase one clean worktree onto `target`. `Ok(Some(reason))` when it needs
+/// manual attention; a conflicting rebase is aborted first.
+fn rebase_one(
+    repo: &Repository,
+    wt: &WorktreeInfo,
+    target: &str,
+) -> anyhow::Result<Option<String>> {
+    let working_tree = repo.worktree_at(&wt.path);
+    if working_tree
+        .run_command(&["rebase", "--end-of-options", target])
+        .is_ok()
+    {
+        return Ok(None);
+    }
+    if working_tree.is_rebasing()? {
+        working_tree.run_command(&["rebase", "--abort"])?;
+        return Ok(Some("conflicts".into()));
+    }
+    Ok(Some("rebase failed".into()))
+}
+
+fn count_worktrees(n: usize) -> String {
+    if n == 1 {
+        "1 worktree".into()
+    } else {
+        format!("{n} worktrees")
+    }
+}
@@ -2,15 +2,26 @@
 //! dispatch each removal to the output handler.
 
 use std::collections::HashSet;
-use std::path::Path;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
 
 use anyhow::Context;
+use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::UserConfig;
-use worktrunk::git::{BranchDeletionMode, ErrorExt, Repository, ResolvedWorktree};
-use worktrunk::styling::{eprintln, info_message};
+use worktrunk::git::{
+    BranchDeletionMode, ErrorExt, GitError, RefSnapshot, Repository, ResolvedWorktree, StashEntry,
+    WorkingTree, WorktreeInfo,
+};
+use worktrunk::path::{format_path_for_display, sanitize_for_filename};
+use worktrunk::state_store;
+use worktrunk::styling::{
+    eprintln, format_with_gutter, hint_message, info_message, success_message, suggest_command,
+    suggest_command_in_dir, warning_message,
+};
 
 use crate::cli::{RemoveArgs, SwitchFormat};
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
 use crate::output::{BackgroundFallbackMode, handle_remove_output};
 
 use super::hook_plan::{ApprovedHookPlan, HookPlanBuilder};
@@ -27,6 +38,8 @@ struct RemovePlans {
     others: Vec<RemoveResult>,
     branch_only: Vec<RemoveResult>,
     current: Option<RemoveResult>,
+    /// `--stash` / `--discard` work, run as each worktree is removed
+    cleanups: Vec<PendingCleanup>,
     errors: Vec<anyhow::Error>,
 }
 
@@ -48,11 +61,517 @@ impl RemovePlans {
     }
 }
 
+/// State namespace recording the stashes `wt remove --stash` makes, keyed by
+/// branch (or directory name, for a detached worktree).
+const REMOVED_STASH_NAMESPACE: &str = "removed-stash";
+
+/// What `--stash` / `--discard` do with uncommitted changes that would
+/// otherwise block removal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DirtyAction {
+    Stash,
+    Discard,
+}
+
+impl DirtyAction {
+    /// `None` without either flag: uncommitted changes refuse the removal.
+    fn from_args(args: &RemoveArgs) -> Option<Self> {
+        if args.stash {
+            Some(Self::Stash)
+        } else if args.discard {
+            Some(Self::Discard)
+        } else {
+            None
+        }
+    }
+}
+
+/// Uncommitted changes to stash or discard once the removal is approved.
+struct PendingCleanup {
+    action: DirtyAction,
+    worktree_path: PathBuf,
+    /// Branch, or directory name for a detached worktree
+    name: String,
+}
+
+impl PendingCleanup {
+    fn run(&self, repo: &Repository) -> anyhow::Result<()> {
+        let wt = repo.worktree_at(&self.worktree_path);
+        match self.action {
+            DirtyAction::Stash => stash_changes(repo, &wt, &self.name),
+            DirtyAction::Discard => wt.discard_changes(),
+        }
+    }
+}
+
+/// Run the cleanup planned for `result`'s worktree, if any.
+fn run_pending_cleanup(
+    repo: &Repository,
+    cleanups: &[PendingCleanup],
+    result: &RemoveResult,
+) -> anyhow::Result<()> {
+    let Some(path) = result.removed_worktree_path() else {
+        return Ok(());
+    };
+    match cleanups.iter().find(|c| c.worktree_path == path) {
+        Some(cleanup) => cleanup.run(repo),
+        None => Ok(()),
+    }
+}
+
+/// Prepare a removal like `prepare_worktree_removal`, but when uncommitted
+/// changes are its only objection — the clean check runs after every other
+/// one, running processes included — plan to stash or discard them per
+/// `dirty`. Nothing is changed here: the caller runs the returned
+/// [`PendingCleanup`] after hook approval, right before removing.
+///
+/// `worktree_path` is the target's worktree; `None` means the current one.
+/// Returns `None` when the user declines to discard.
+#[allow(clippy::too_many_arguments)]
+fn prepare_removal(
+    repo: &Repository,
+    target: RemoveTarget,
+    worktree_path: Option<&Path>,
+    deletion_mode: BranchDeletionMode,
+    force: bool,
+    dirty: Option<DirtyAction>,
+    yes: bool,
+    config: &UserConfig,
+    worktrees: Option<&[WorktreeInfo]>,
+    snapshot: Option<&RefSnapshot>,
+) -> anyhow::Result<Option<(RemoveResult, Option<PendingCleanup>)>> {
+    let prepare = |force| {
+        repo.prepare_worktree_removal(
+            target,
+            deletion_mode,
+            force,
+            config,
+            None,
+            worktrees,
+            snapshot,
+        )
+    };
+    let err = match prepare(force) {
+        Ok(result) => return Ok(Some((result, None))),
+        Err(err) => err,
+    };
+    let Some(GitError::UncommittedChanges { branch, .. }) = err.downcast_ref::<GitError>() else {
+        return Err(err);
+    };
+    let Some(action) = dirty else {
+        return Err(err);
+    };
+
+    let wt = match worktree_path {
+        Some(path) => repo.worktree_at(path),
+        None => repo.current_worktree(),
+    };
+    let name = match branch {
+        Some(branch) => branch.clone(),
+        None => wt
+            .root()?
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+    };
+    if action == DirtyAction::Discard && !confirm_discard(&wt, &name, yes)? {
+        return Ok(None);
+    }
+
+    // Every check before the clean one passed; skip it to build the plan, and
+    // keep the caller's `force` for the removal itself.
+    let mut result = prepare(true)?;
+    if let RemoveResult::RemovedWorktree { force_worktree, .. } = &mut result {
+        *force_worktree = force;
+    }
+    let cleanup = result.removed_worktree_path().map(|path| PendingCleanup {
+        action,
+        worktree_path: path.to_path_buf(),
+        name,
+    });
+    Ok(Some((result, cleanup)))
+}
+
+/// Stash `wt`'s changes as `wt remove: <name>` and record the stash in state.
+fn stash_changes(repo: &Repository, wt: &WorkingTree<'_>, name: &str) -> anyhow::Result<()> {
+    let Some(sha) = wt.stash_push(&format!("wt remove: {name}"))? else {
+        return Ok(());
+    };
+    state_store::set(
+        repo,
+        REMOVED_STASH_NAMESPACE,
+        &stash_state_key(name),
+        &sha,
+        None,
+    )?;
+    let short = repo.short_sha(&sha)?;
+    eprintln!(
+        "{}",
+        info_message(cformat!(
+            "Stashed uncommitted changes from <bold>{name}</> as <bold>{short}</>"
+        ))
+    );
+    eprintln!(
+        "{}",
+        hint_message(cformat!(
+            "To restore them, run <bright-black>git stash apply {short}</>"
+        ))
+    );
+    Ok(())
+}
+
+/// Show `wt`'s changes and ask (unless `--yes`) whether to discard them.
+fn confirm_discard(wt: &WorkingTree<'_>, name: &str, yes: bool) -> anyhow::Result<bool> {
+    let stat = wt.run_command(&["diff", "--stat", "HEAD"])?;
+    let untracked = wt.run_command(&["ls-files", "--others", "--exclude-standard"])?;
+    let summary: Vec<String> = stat
+        .lines()
+        .map(|line| line.trim_start().to_string())
+        .chain(untracked.lines().map(|path| format!("{path} (untracked)")))
+        .collect();
+    let show_summary = || {
+        eprintln!(
+            "{}",
+            info_message(cformat!("Uncommitted changes in <bold>{name}</>:"))
+        );
+        eprintln!("{}", format_with_gutter(&summary.join("\n"), None));
+    };
+    show_summary();
+
+    if !yes {
+        match prompt_yes_no_preview(
+            &cformat!("Discard these changes and remove <bold>{name}</>?"),
+            show_summary,
+        )? {
+            PromptResponse::Accepted => {}
+            PromptResponse::Declined => {
+                eprintln!(
+                    "{}",
+                    info_message(cformat!("Kept <bold>{name}</>; changes not discarded"))
+                );
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Stashes made on `branch` that nothing else keeps track of.
+///
+/// Those `wt remove --stash` made are left out: their SHAs are recorded under
+/// the `removed-stash` namespace, and the removal just printed how to restore
+/// them.
+pub(crate) fn untracked_branch_stashes<'a>(
+    repo: &'a Repository,
+    branch: &str,
+) -> Vec<&'a StashEntry> {
+    let recorded = format!(": wt remove: {branch}");
+    repo.branch_stashes(branch)
+        .into_iter()
+        .filter(|stash| !stash.subject.ends_with(&recorded))
+        .collect()
+}
+
+/// The branch a removal would delete that still has stashes made on it.
+///
+/// Stashes outlive their branch, but their subjects name it, so once it's gone
+/// nothing in `wt list` leads back to them. A safe deletion only happens when
+/// the branch is integrated, so that's checked here too — only for branches
+/// with stashes, keeping the common case free.
+fn stashed_branch_to_delete<'a>(repo: &Repository, result: &'a RemoveResult) -> Option<&'a str> {
+    let (branch, deletion_mode, target, integrated) = match result {
+        RemoveResult::RemovedWorktree {
+            branch_name: Some(branch),
+            deletion_mode,
+            target_branch,
+            ..
+        } => (
+            branch.as_str(),
+            deletion_mode,
+            target_branch.as_deref(),
+            None,
+        ),
+        RemoveResult::BranchOnly {
+            branch_name,
+            deletion_mode,
+            integration_reason,
+            ..
+        } => (
+            branch_name.as_str(),
+            deletion_mode,
+            None,
+            Some(integration_reason.is_some()),
+        ),
+        RemoveResult::RemovedWorktree {
+            branch_name: None, ..
+        } => return None,
+    };
+    if deletion_mode.should_keep() || untracked_branch_stashes(repo, branch).is_empty() {
+        return None;
+    }
+    if deletion_mode.is_force() {
+        return Some(branch);
+    }
+    let integrated = integrated.unwrap_or_else(|| {
+        repo.capture_refs()
+            .and_then(|snapshot| {
+                repo.integration_reason(&snapshot, branch, target.unwrap_or("HEAD"))
+            })
+            .is_ok_and(|(_, reason)| reason.is_some())
+    });
+    integrated.then_some(branch)
+}
+
+/// Warn that deleting `branch` strands its stashes and offer to export them
+/// first. `--yes` exports without asking; without a terminal, the warning
+/// stands alone.
+fn offer_stash_export(repo: &Repository, branch: &str, yes: bool) -> anyhow::Result<()> {
+    let stashes = untracked_branch_stashes(repo, branch);
+    let count = stashes.len();
+    let listing = stashes
+        .iter()
+        .map(|stash| format!("{}: {}", stash.selector, stash.subject))
+        .collect::<Vec<_>>()
+        .join("\n");
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "Branch <bold>{branch}</> has {count} stash{} that will outlive it",
+            if count == 1 { "" } else { "es" }
+        ))
+    );
+    eprintln!("{}", format_with_gutter(&listing, None));
+
+    if !yes {
+        if !std::io::stdin().is_terminal() {
+            let first = &stashes[0].selector;
+            eprintln!(
+                "{}",
+                hint_message(cformat!(
+                    "To save one as a patch, run <bright-black>git stash show -p --include-untracked {first}</>"
+                ))
+            );
+            return Ok(());
+        }
+        let prompt = cformat!("Export them as patches before deleting <bold>{branch}</>?");
+        if prompt_yes_no_preview(&prompt, || {
+            eprintln!("{}", format_with_gutter(&listing, None));
+        })? == PromptResponse::Declined
+        {
+            return Ok(());
+        }
+    }
+
+    let dir = export_branch_stashes(repo, branch)?;
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Exported {count} stash{} of <bold>{branch}</> to <bold>{}</>",
+            if count == 1 { "" } else { "es" },
+            format_path_for_display(&dir)
+        ))
+    );
+    Ok(())
+}
+
+/// Write each of [`untracked_branch_stashes`] as `<short-sha>.patch` (untracked
+/// files included) under `.git/wt/stashes/<branch>/`, returning that directory.
+pub(crate) fn export_branch_stashes(repo: &Repository, branch: &str) -> anyhow::Result<PathBuf> {
+    let dir = repo
+        .wt_dir()
+        .join("stashes")
+        .join(sanitize_for_filename(branch));
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create {}", format_path_for_display(&dir)))?;
+    for stash in untracked_branch_stashes(repo, branch) {
+        let patch =
+            repo.run_command(&["stash", "show", "-p", "--include-untracked", &stash.sha])?;
+        let path = dir.join(format!("{}.patch", repo.short_sha(&stash.sha)?));
+        std::fs::write(&path, patch)
+            .with_context(|| format!("Failed to write {}", format_path_for_display(&path)))?;
+    }
+    Ok(dir)
+}
+
+/// A branch a safe removal would keep because it isn't integrated.
+struct UnmergedBranch {
+    branch: String,
+    /// Integration target as checked — may be the default branch's upstream.
+    target: String,
+    /// Local default branch, to merge into.
+    merge_target: String,
+    /// Commits on the branch that `target` doesn't have.
+    ahead: usize,
+}
+
+/// The branch a worktree removal would leave behind as unmerged.
+///
+/// `wt remove` deletes a branch only once it's integrated; otherwise the
+/// worktree goes and the branch stays, easy to forget. Only default-mode
+/// removals of a worktree with commits of its own qualify.
+fn unmerged_branch_to_keep(repo: &Repository, result: &RemoveResult) -> Option<UnmergedBranch> {
+    let RemoveResult::RemovedWorktree {
+        branch_name: Some(branch),
+        deletion_mode: BranchDeletionMode::SafeDelete,
+        target_branch: Some(merge_target),
+        ..
+    } = result
+    else {
+        return None;
+    };
+    let snapshot = repo.capture_refs().ok()?;
+    let (target, reason) = repo
+        .integration_reason(&snapshot, branch, merge_target)
+        .ok()?;
+    if reason.is_some() {
+        return None;
+    }
+    let ahead = repo.count_commits(&target, branch).ok()?;
+    (ahead > 0).then(|| UnmergedBranch {
+        branch: branch.clone(),
+        target,
+        merge_target: merge_target.clone(),
+        ahead,
+    })
+}
+
+/// Warn that removing the worktree keeps `result`'s unmerged branch, and offer
+/// to merge it first, archive it as a bundle and delete it, or delete it
+/// anyway. Returns `false` when the removal should be skipped so the branch
+/// can be merged. `--yes` and non-interactive runs keep the branch.
+fn confirm_unmerged_removal(
+    repo: &Repository,
+    result: &mut RemoveResult,
+    yes: bool,
+) -> anyhow::Result<bool> {
+    let Some(unmerged) = unmerged_branch_to_keep(repo, result) else {
+        return Ok(true);
+    };
+    let UnmergedBranch {
+        branch,
+        target,
+        merge_target,
+        ahead,
+    } = &unmerged;
+    let commits = if *ahead == 1 { "commit" } else { "commits" };
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "Branch <bold>{branch}</> has {ahead} {commits} not in <bold>{target}</>"
+        ))
+    );
+    if yes || !std::io::stdin().is_terminal() {
+        return Ok(true);
+    }
+
+    const MERGE: usize = 0;
+    const ARCHIVE: usize = 1;
+    const DELETE: usize = 2;
+    let options = cformat!(
+        "1. Merge it into <bold>{merge_target}</> first\n2. Archive it as a bundle, then delete it\n3. Delete it anyway"
+    );
+    eprintln!("{}", format_with_gutter(&options, None));
+    match crate::output::prompt::prompt_choice("Before removing? Enter keeps the branch", 3)? {
+        Some(MERGE) => {
+            let cmd = match result.removed_worktree_path() {
+                Some(path) => suggest_command_in_dir(path, "merge", &[merge_target], &[]),
+                None => suggest_command("merge", &[merge_target], &[]),
+            };
+            eprintln!(
+                "{}",
+                info_message(cformat!("Skipped removing <bold>{branch}</>"))
+            );
+            eprintln!(
+                "{}",
+                hint_message(cformat!("To merge it, run <underline>{cmd}</>"))
+            );
+            return Ok(false);
+        }
+        Some(ARCHIVE) => {
+            let path = archive_branch(repo, branch, target)?;
+            let path_display = format_path_for_display(&path);
+            eprintln!(
+                "{}",
+                success_message(cformat!(
+                    "Archived {ahead} {commits} of <bold>{branch}</> to <bold>{path_display}</>"
+                ))
+            );
+            eprintln!(
+                "{}",
+                hint_message(cformat!(
+                    "To restore it, run <underline>git fetch {path_display} {branch}:{branch}</>"
+                ))
+            );
+        }
+        Some(DELETE) => {}
+        _ => return Ok(true),
+    }
+    if let RemoveResult::RemovedWorktree { deletion_mode, .. } = result {
+        *deletion_mode = BranchDeletionMode::ForceDelete;
+    }
+    Ok(true)
+}
+
+/// Write `branch`'s commits that `target` lacks to a bundle under
+/// `.git/wt/bundles/`, returning its path.
+///
+/// The file is named after the branch and its tip (`feature-1a2b3c4.bundle`),
+/// with a counter appended if that name is taken, so archiving a branch again
+/// (or one whose name sanitizes the same) never overwrites an earlier archive.
+fn archive_branch(repo: &Repository, branch: &str, target: &str) -> anyhow::Result<PathBuf> {
+    let dir = repo.wt_dir().join("bundles");
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create {}", format_path_for_display(&dir)))?;
+    let stem = format!(
+        "{}-{}",
+        sanitize_for_filename(branch),
+        repo.short_sha(&format!("refs/heads/{branch}"))?
+    );
+    let mut path = dir.join(format!("{stem}.bundle"));
+    for n in 2.. {
+        if !path.exists() {
+            break;
+        }
+        path = dir.join(format!("{stem}-{n}.bundle"));
+    }
+    repo.run_command(&[
+        "bundle",
+        "create",
+        "-q",
+        &path.to_string_lossy(),
+        &format!("refs/heads/{branch}"),
+        &format!("^{target}"),
+    ])
+    .with_context(|| cformat!("Failed to archive <bold>{branch}</>"))?;
+    Ok(path)
+}
+
+/// A [`state_store`] key for `name`: characters keys don't allow become `-`.
+fn stash_state_key(name: &str) -> String {
+    let key: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let key = key.trim_start_matches('.');
+    if key.is_empty() {
+        "worktree".to_string()
+    } else {
+        key.to_string()
+    }
+}
+
 /// Validate all removal targets, returning categorized plans.
 ///
 /// Resolves each branch name, determines whether it's the current worktree,
 /// another worktree, or branch-only, and prepares the removal plan.
 /// Errors are collected (not fatal) to support partial success.
+#[allow(clippy::too_many_arguments)]
 fn validate_remove_targets(
     repo: &Repository,
     branches: Vec<String>,
@@ -60,6 +579,8 @@ fn validate_remove_targets(
     keep_branch: bool,
     force_delete: bool,
     force: bool,
+    dirty: Option<DirtyAction>,
+    yes: bool,
 ) -> RemovePlans {
     let current_worktree = repo
         .current_worktree()
@@ -89,6 +610,7 @@ fn validate_remove_targets(
         others: Vec::new(),
         branch_only: Vec::new(),
         current: None,
+        cleanups: Vec::new(),
         errors: Vec::new(),
     };
 
@@ -108,16 +630,23 @@ fn validate_remove_targets(
                 let is_current = current_worktree.as_ref() == Some(&path_canonical);
 
                 if is_current {
-                    match repo.prepare_worktree_removal(
+                    match prepare_removal(
+                        repo,
                         RemoveTarget::Current,
+                        Some(&path_canonical),
                         deletion_mode,
                         force,
+                        dirty,
+                        yes,
                         config,
-                        None,
                         worktrees,
                         snapshot.as_ref(),
                     ) {
-                        Ok(result) => plans.current = Some(result),
+                        Ok(Some((result, cleanup))) => {
+                            plans.current = Some(result);
+                            plans.cleanups.extend(cleanup);
+                        }
+                        Ok(None) => {}
                         Err(e) => plans.record_error(e),
                     }
                     continue;
@@ -130,16 +659,23 @@ fn validate_remove_targets(
                 } else {
                     RemoveTarget::Path(&path_canonical)
                 };
-                match repo.prepare_worktree_removal(
+                match prepare_removal(
+                    repo,
                     target,
+                    Some(&path_canonical),
                     deletion_mode,
                     force,
+                    dirty,
+                    yes,
                     config,
-                    None,
                     worktrees,
                     snapshot.as_ref(),
                 ) {
-                    Ok(result) => plans.others.push(result),
+                    Ok(Some((result, cleanup))) => {
+                        plans.others.push(result);
+                        plans.cleanups.extend(cleanup);
+                    }
+                    Ok(None) => {}
                     Err(e) => plans.record_error(e),
                 }
             }
@@ -189,6 +725,7 @@ fn validate_remove_targets(
 pub fn handle_remove_command(args: RemoveArgs, yes: bool) -> anyhow::Result<()> {
     let json_mode = args.format == SwitchFormat::Json;
     let verify = args.hooks.resolve();
+    let dirty = DirtyAction::from_args(&args);
     UserConfig::load()
         .context("Failed to load config")
         .and_then(|config| {
@@ -257,23 +794,35 @@ pub fn handle_remove_command(args: RemoveArgs, yes: bool) -> anyhow::Result<()>
 
             if branches.is_empty() {
                 // Single worktree removal: validate FIRST, then approve, then execute
-                let result = repo
-                    .prepare_worktree_removal(
-                        RemoveTarget::Current,
-                        BranchDeletionMode::from_flags(!delete_branch, args.force_delete),
-                        args.force,
-                        &config,
-                        None,
-                        None,
-                        None,
-                    )
-                    .context("Failed to remove worktree")?;
+                let Some((mut result, cleanup)) = prepare_removal(
+                    &repo,
+                    RemoveTarget::Current,
+                    None,
+                    BranchDeletionMode::from_flags(!delete_branch, args.force_delete),
+                    args.force,
+                    dirty,
+                    yes,
+                    &config,
+                    None,
+                    None,
+                )
+                .context("Failed to remove worktree")?
+                else {
+                    return Ok(());
+                };
 
                 // Early exit for benchmarking time-to-first-output
                 if std::env::var_os("WORKTRUNK_FIRST_OUTPUT").is_some() {
                     return Ok(());
                 }
 
+                if !confirm_unmerged_removal(&repo, &mut result, yes)? {
+                    return Ok(());
+                }
+                if let Some(branch) = stashed_branch_to_delete(&repo, &result) {
+                    offer_stash_export(&repo, branch, yes)?;
+                }
+
                 // "Approve at the Gate": approval happens AFTER validation passes
                 let plan = approve_remove(
                     result.removed_worktree_path().as_slice(),
@@ -281,6 +830,9 @@ pub fn handle_remove_command(args: RemoveArgs, yes: bool) -> anyhow::Result<()>
                     yes,
                 )?;
 
+                if let Some(cleanup) = &cleanup {
+                    cleanup.run(&repo)?;
+                }
                 let mut announcer = HookAnnouncer::new(&repo, false);
                 handle_remove_output(
                     &result,
@@ -303,16 +855,22 @@ pub fn handle_remove_command(args: RemoveArgs, yes: bool) -> anyhow::Result<()>
                 Ok(())
             } else {
                 // Multi-worktree removal: validate ALL first, then approve, then execute
-                let plans = validate_remove_targets(
+                let mut plans = validate_remove_targets(
                     &repo,
                     branches,
                     &config,
                     !delete_branch,
                     args.force_delete,
                     args.force,
+                    dirty,
+                    yes,
                 );
 
                 if !plans.has_valid_plans() {
+                    // Nothing failed: every target was a declined `--discard`
+                    if plans.errors.is_empty() {
+                        return Ok(());
+                    }
                     anyhow::bail!("");
                 }
 
@@ -321,6 +879,36 @@ pub fn handle_remove_command(args: RemoveArgs, yes: bool) -> anyhow::Result<()>
                     return Ok(());
                 }
 
+                // Only worktree removals are asked about: a branch-only target
+                // removes nothing else, and its output already says the
+                // branch was retained.
+                let mut others = Vec::with_capacity(plans.others.len());
+                for mut result in std::mem::take(&mut plans.others) {
+                    if confirm_unmerged_removal(&repo, &mut result, yes)? {
+                        others.push(result);
+                    }
+                }
+                plans.others = others;
+                if let Some(mut result) = plans.current.take()
+                    && confirm_unmerged_removal(&repo, &mut result, yes)?
+                {
+                    plans.current = Some(result);
+                }
+                if !plans.has_valid_plans() {
+                    return Ok(());
+                }
+
+                for result in plans
+                    .others
+                    .iter()
+                    .chain(&plans.branch_only)
+                    .chain(plans.current.iter())
+                {
+                    if let Some(branch) = stashed_branch_to_delete(&repo, result) {
+                        offer_stash_export(&repo, branch, yes)?;
+                    }
+                }
+
                 // Approve hooks (only if we have valid plans). Each removed
                 // worktree's `pre-remove` / `post-remove` is approved against
                 // that worktree's config, and its `post-switch` against the
@@ -344,6 +932,7 @@ pub fn handle_remove_command(args: RemoveArgs, yes: bool) -> anyhow::Result<()>
                 let show_branch =
                     plans.others.len() + plans.branch_only.len() + plans.current.iter().len() > 1;
                 let run = |result: &RemoveResult| -> anyhow::Result<()> {
+                    run_pending_cleanup(&repo, &plans.cleanups, result)?;
                     let mut announcer = HookAnnouncer::new(&repo, show_branch);
                     handle_remove_output(
                         result,
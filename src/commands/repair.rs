@@ -0,0 +1,140 @@
+//! `wt repair` — relink worktrees after the repository moved.
+//!
+//! Each linked worktree's `.git` file records the absolute path of its
+//! administrative directory under the repository's `.git/worktrees/`. Moving
+//! or renaming the repository leaves every one of those pointing at the old
+//! location, so git fails in all worktrees at once. `wt repair`, run from the
+//! repository's new location, relinks them with a single
+//! `git worktree repair`.
+//!
+//! Every command also checks for this state — a few file reads — and points
+//! at `wt repair` when it finds it.
+
+use std::path::{Path, PathBuf};
+
+use color_print::cformat;
+use worktrunk::git::{Repository, WorktrunkError};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    eprintln, error_message, format_with_gutter, hint_message, info_message, success_message,
+    warning_message,
+};
+
+pub fn handle_repair() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let unlinked = repo.unlinked_worktrees();
+    if unlinked.is_empty() {
+        eprintln!(
+            "{}",
+            info_message("All worktrees are linked to this repository")
+        );
+        return Ok(());
+    }
+
+    repo.repair_worktrees(&unlinked)?;
+    let still_unlinked = repo.unlinked_worktrees();
+    let repaired: Vec<&PathBuf> = unlinked
+        .iter()
+        .filter(|path| !still_unlinked.contains(path))
+        .collect();
+    if !repaired.is_empty() {
+        let noun = if repaired.len() == 1 {
+            "worktree"
+        } else {
+            "worktrees"
+        };
+        eprintln!(
+            "{}",
+            success_message(cformat!("Relinked <bold>{}</> {noun}", repaired.len()))
+        );
+        eprintln!("{}", format_with_gutter(&path_list(&repaired), None));
+    }
+    if !still_unlinked.is_empty() {
+        eprintln!("{}", error_message("Could not relink"));
+        let paths: Vec<&PathBuf> = still_unlinked.iter().collect();
+        eprintln!("{}", format_with_gutter(&path_list(&paths), None));
+        return Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into());
+    }
+    Ok(())
+}
+
+/// Warn when the current repository's worktrees, or the worktree the command
+/// runs in, still point at a location the repository moved away from.
+pub fn warn_if_repository_moved() {
+    let Ok(repo) = Repository::current() else {
+        warn_if_worktree_disconnected();
+        return;
+    };
+    let unlinked = repo.unlinked_worktrees();
+    if unlinked.is_empty() {
+        return;
+    }
+    let (subject, verb) = if unlinked.len() == 1 {
+        ("1 worktree".to_string(), "points")
+    } else {
+        (format!("{} worktrees", unlinked.len()), "point")
+    };
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "{subject} {verb} at a previous location of this repository"
+        ))
+    );
+    let paths: Vec<&PathBuf> = unlinked.iter().collect();
+    eprintln!("{}", format_with_gutter(&path_list(&paths), None));
+    eprintln!(
+        "{}",
+        hint_message(cformat!("To relink them, run <underline>wt repair</>"))
+    );
+}
+
+/// Outside any repository git can open, look for a `.git` file above the
+/// working directory whose target is gone — a linked worktree of a moved
+/// repository.
+fn warn_if_worktree_disconnected() {
+    let Ok(cwd) = std::env::current_dir() else {
+        return;
+    };
+    let Some((dot_git, target)) = cwd
+        .ancestors()
+        .find(|dir| dir.join(".git").exists())
+        .and_then(stale_gitdir_file)
+    else {
+        return;
+    };
+    let worktree = dot_git.parent().unwrap_or(&dot_git);
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "Worktree <bold>{}</> points to missing <bold>{}</>",
+            format_path_for_display(worktree),
+            format_path_for_display(&target)
+        ))
+    );
+    eprintln!(
+        "{}",
+        hint_message(cformat!(
+            "If the repository moved, run <underline>wt repair</> from its new location"
+        ))
+    );
+}
+
+/// `dir/.git` and the git directory it names, when it's a file whose target
+/// doesn't exist.
+fn stale_gitdir_file(dir: &Path) -> Option<(PathBuf, PathBuf)> {
+    let dot_git = dir.join(".git");
+    if !dot_git.is_file() {
+        return None;
+    }
+    let content = std::fs::read_to_string(&dot_git).ok()?;
+    let target = dir.join(content.strip_prefix("gitdir:")?.trim());
+    (!target.exists()).then_some((dot_git, target))
+}
+
+fn path_list(paths: &[&PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| format_path_for_display(path))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,58 @@
+//! Repository groups — `[repo-groups]` in user config, selected with
+//! `--group` on `wt list` and `wt step for-each`.
+//!
+//! ```toml
+//! [repos]
+//! api = "~/code/api"
+//! workers = "~/code/workers"
+//!
+//! [repo-groups]
+//! backend = ["api", "workers"]
+//! ```
+//!
+//! Group entries name a `[repos]` entry or are paths themselves; see
+//! [`UserConfig::repo_group`].
+
+use color_print::cformat;
+use worktrunk::config::UserConfig;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+
+/// A repository of a group, with the entry that named it.
+pub struct GroupRepo {
+    pub name: String,
+    pub repo: Repository,
+}
+
+/// Open every repository in `group`, in the order listed. Fails when the
+/// group isn't defined or an entry isn't a git repository, before any
+/// command runs.
+pub fn open_repo_group(config: &UserConfig, group: &str) -> anyhow::Result<Vec<GroupRepo>> {
+    let Some(entries) = config.repo_group(group) else {
+        return Err(GitError::Other {
+            message: cformat!(
+                "No repository group <bold>{group}</>; define it under <bright-black>[repo-groups]</> in user config"
+            ),
+        }
+        .into());
+    };
+    entries
+        .into_iter()
+        .map(|(name, path)| {
+            let repo = Repository::at(&path).map_err(|_| {
+                // Show where a `[repos]` name points; a path entry speaks for itself.
+                let location = if std::path::Path::new(&name) == path {
+                    String::new()
+                } else {
+                    cformat!(" <bright-black>({})</>", format_path_for_display(&path))
+                };
+                GitError::Other {
+                    message: cformat!(
+                        "<bold>{name}</>{location} in group <bold>{group}</> isn't a git repository"
+                    ),
+                }
+            })?;
+            Ok(GroupRepo { name, repo })
+        })
+        .collect()
+}
@@ -14,7 +14,7 @@ use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{eprintln, format_with_gutter, progress_message, warning_message};
 
 /// Target for worktree removal.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum RemoveTarget<'a> {
     /// Remove worktree by branch name
     Branch(&'a str),
@@ -270,6 +270,29 @@ impl RepositoryCliExt for Repository {
         let target_wt = self.worktree_at(&worktree_path);
 
         if !force_worktree {
+            // Dev servers, agents, and shells sitting in the worktree would be
+            // left running in a deleted directory. Ones the branch's background
+            // hooks started are exempt: removal stops those itself.
+            let hooks = branch_name
+                .as_deref()
+                .map(|branch| super::hook_status::running_pipelines(self, branch))
+                .unwrap_or_default();
+            let processes: Vec<_> =
+                worktrunk::git::worktree_processes::processes_in(&worktree_path)
+                    .into_iter()
+                    .filter(|p| !super::hook_status::started_by(&hooks, p.pid))
+                    .collect();
+            if !processes.is_empty() {
+                return Err(GitError::WorktreeInUse {
+                    branch: branch_name.clone(),
+                    path: worktree_path.clone(),
+                    processes: processes.into_iter().map(|p| (p.pid, p.command)).collect(),
+                }
+                .into());
+            }
+
+            // Last, so that when `wt remove --stash` / `--discard` sees this
+            // error, every other check has already passed.
             target_wt.ensure_clean("remove worktree", branch_name.as_deref(), true)?;
         }
 
@@ -12,7 +12,9 @@
 //! 2. Open a [`Repository`] from the worktree path in the spec.
 //! 3. Walk steps in order. For each step, expand templates and spawn shell
 //!    children (see Execution model). Abort on the first serial step failure.
-//! 4. Exit. Log files in `.git/wt/logs/` are the only artifacts.
+//! 4. Exit. Artifacts are the log files in `.git/wt/logs/` and the run's
+//!    status record (see [`super::hook_status`]), updated as each command
+//!    starts and finishes.
 //!
 //! ## Execution model
 //!
@@ -65,7 +67,9 @@ use worktrunk::git::{Repository, WorktrunkError};
 use worktrunk::shell_exec::ShellConfig;
 use worktrunk::trace::CommandTrace;
 
-use super::command_executor::{expand_shell_template, wait_first_error};
+use super::command_executor::{expand_shell_template, resolve_working_dir, wait_first_error};
+use super::hook_sandbox::sandbox_command;
+use super::hook_status::PipelineRecorder;
 use super::pipeline_spec::{PipelineSpec, PipelineStepSpec};
 use super::process::HookLog;
 
@@ -93,6 +97,35 @@ pub fn run_pipeline() -> anyhow::Result<()> {
     fs::create_dir_all(&spec.log_dir)
         .with_context(|| format!("failed to create log directory: {}", spec.log_dir.display()))?;
 
+    let names = spec
+        .steps
+        .iter()
+        .flat_map(|step| match step {
+            PipelineStepSpec::Single { name, .. } => vec![name.as_deref()],
+            PipelineStepSpec::Concurrent { commands } => {
+                commands.iter().map(|c| c.name.as_deref()).collect()
+            }
+        })
+        .enumerate()
+        .map(|(index, name)| command_log_name(name, index))
+        .collect();
+    let mut recorder =
+        PipelineRecorder::start(&repo, &spec.branch, spec.source, spec.hook_type, names);
+
+    let result = run_steps(&spec, &repo, &mut recorder);
+    if result.is_err() {
+        recorder.abort();
+    }
+    result
+}
+
+/// Walk the spec's steps in order, recording each command's outcome.
+/// Returns the first failure; later steps don't run.
+fn run_steps(
+    spec: &PipelineSpec,
+    repo: &Repository,
+    recorder: &mut PipelineRecorder,
+) -> anyhow::Result<()> {
     let mut cmd_index = 0usize;
 
     for step in &spec.steps {
@@ -101,23 +134,33 @@ pub fn run_pipeline() -> anyhow::Result<()> {
                 template,
                 template_name,
                 name,
+                cwd,
             } => {
+                recorder.begin(cmd_index);
                 let log_name = command_log_name(name.as_deref(), cmd_index);
-                let log_file = create_command_log(&spec, &log_name)?;
+                let log_file = create_command_log(spec, &log_name)?;
                 let step_ctx = step_context(&spec.context, name.as_deref());
-                let expanded = expand_shell_template(template, &step_ctx, &repo, template_name)?;
+                let (dir, step_ctx) = resolve_working_dir(
+                    cwd.as_deref(),
+                    &step_ctx,
+                    repo,
+                    template_name,
+                    &spec.worktree_path,
+                )?;
+                let expanded = expand_shell_template(template, &step_ctx, repo, template_name)?;
                 let step_json = serde_json::to_string(&*step_ctx)
                     .context("failed to serialize step context")?;
                 let (mut child, mut trace) =
-                    spawn_shell_command(&expanded, &spec.worktree_path, &step_json, log_file)?;
+                    spawn_shell_command(&expanded, spec, &dir, &step_json, log_file)?;
                 let status = wait_resolving(&mut child, &mut trace, &expanded)?;
+                recorder.finish(cmd_index, status.success(), status.code());
                 if !status.success() {
                     return Err(failure_error(&status, name.as_deref().unwrap_or(&expanded)));
                 }
                 cmd_index += 1;
             }
             PipelineStepSpec::Concurrent { commands } => {
-                run_concurrent_group(commands, &spec, &repo, &mut cmd_index)?;
+                run_concurrent_group(commands, spec, repo, recorder, &mut cmd_index)?;
             }
         }
     }
@@ -148,14 +191,20 @@ fn step_context<'a>(
 /// Uses `ShellConfig` for portable shell detection (Git Bash on Windows,
 /// `sh` on Unix). stdout/stderr are redirected to `log_file` so each
 /// command gets its own log. Returns the `Child` so the caller controls
-/// when to wait.
+/// when to wait. Project pipelines run under the spec's `[sandbox]`
+/// restrictions.
 fn spawn_shell_command(
     expanded: &str,
-    worktree_path: &Path,
+    spec: &PipelineSpec,
+    dir: &Path,
     context_json: &str,
     log_file: fs::File,
 ) -> anyhow::Result<(Child, CommandTrace)> {
     let shell = ShellConfig::get()?;
+    let command = match &spec.sandbox {
+        Some(sandbox) => sandbox_command(sandbox, expanded, &spec.worktree_path)?,
+        None => expanded.to_string(),
+    };
     let log_err = log_file
         .try_clone()
         .context("failed to clone log file handle")?;
@@ -165,8 +214,8 @@ fn spawn_shell_command(
     // across worktrees isn't a duplicate (different per-worktree input).
     let mut trace = CommandTrace::new(None, expanded).reads_stdin(true);
     let mut child = match shell
-        .command(expanded)
-        .current_dir(worktree_path)
+        .command(&command)
+        .current_dir(dir)
         .stdin(Stdio::piped())
         .stdout(Stdio::from(log_file))
         .stderr(Stdio::from(log_err))
@@ -224,10 +273,11 @@ fn run_concurrent_group(
     commands: &[super::pipeline_spec::PipelineCommandSpec],
     spec: &PipelineSpec,
     repo: &Repository,
+    recorder: &mut PipelineRecorder,
     cmd_index: &mut usize,
 ) -> anyhow::Result<()> {
     let serial = super::force_serial_concurrent();
-    let mut children: Vec<(Option<String>, String, Child, CommandTrace)> =
+    let mut children: Vec<(usize, Option<String>, String, Child, CommandTrace)> =
         Vec::with_capacity(if serial { 0 } else { commands.len() });
 
     // Spawn (and, in serial mode, run) each command. Wrapped so that a mid-loop
@@ -236,19 +286,29 @@ fn run_concurrent_group(
     // unresolved trace guards (and as unreaped orphans).
     let spawn_result = (|| -> anyhow::Result<()> {
         for cmd in commands {
-            let log_name = command_log_name(cmd.name.as_deref(), *cmd_index);
+            let index = *cmd_index;
+            recorder.begin(index);
+            let log_name = command_log_name(cmd.name.as_deref(), index);
             let log_file = create_command_log(spec, &log_name)?;
             let cmd_ctx = step_context(&spec.context, cmd.name.as_deref());
+            let (dir, cmd_ctx) = resolve_working_dir(
+                cmd.cwd.as_deref(),
+                &cmd_ctx,
+                repo,
+                &cmd.template_name,
+                &spec.worktree_path,
+            )?;
             let expanded =
                 expand_shell_template(&cmd.template, &cmd_ctx, repo, &cmd.template_name)?;
             let cmd_json =
                 serde_json::to_string(&*cmd_ctx).context("failed to serialize step context")?;
             let (mut child, mut trace) =
-                spawn_shell_command(&expanded, &spec.worktree_path, &cmd_json, log_file)?;
+                spawn_shell_command(&expanded, spec, &dir, &cmd_json, log_file)?;
             *cmd_index += 1;
 
             if serial {
                 let status = wait_resolving(&mut child, &mut trace, &expanded)?;
+                recorder.finish(index, status.success(), status.code());
                 if !status.success() {
                     return Err(failure_error(
                         &status,
@@ -256,14 +316,14 @@ fn run_concurrent_group(
                     ));
                 }
             } else {
-                children.push((cmd.name.clone(), expanded, child, trace));
+                children.push((index, cmd.name.clone(), expanded, child, trace));
             }
         }
         Ok(())
     })();
 
     if let Err(e) = spawn_result {
-        for (_, _, mut child, mut trace) in children {
+        for (_, _, _, mut child, mut trace) in children {
             let _ = child.kill();
             let _ = child.wait();
             trace.complete(false);
@@ -272,8 +332,9 @@ fn run_concurrent_group(
     }
 
     wait_first_error(children.into_iter().map(
-        |(name, expanded, mut child, mut trace)| -> anyhow::Result<()> {
+        |(index, name, expanded, mut child, mut trace)| -> anyhow::Result<()> {
             let status = wait_resolving(&mut child, &mut trace, &expanded)?;
+            recorder.finish(index, status.success(), status.code());
             if !status.success() {
                 return Err(failure_error(&status, name.as_deref().unwrap_or(&expanded)));
             }
@@ -0,0 +1,450 @@
+//! `wt serve` — expose worktrunk operations to agents as MCP tools.
+//!
+//! Speaks JSON-RPC 2.0 over stdio, one message per line, implementing the
+//! part of the Model Context Protocol a tool server needs: `initialize`,
+//! `ping`, `tools/list`, and `tools/call`. Each tool runs `wt` itself with
+//! `--format=json` in a child process and returns the parsed output, so
+//! results match what the CLI prints and command output never mixes into the
+//! protocol stream on stdout.
+//!
+//! Only operations on the `--allow` list are advertised or callable.
+
+use std::io::{BufRead, Write};
+use std::process::Stdio;
+
+use ansi_str::AnsiStr;
+use anyhow::Context;
+use clap::ValueEnum;
+use serde_json::{Map, Value, json};
+use worktrunk::shell_exec::Cmd;
+
+use crate::cli::ServeOperation;
+
+/// MCP revision reported when the client doesn't name one.
+const PROTOCOL_VERSION: &str = "2025-06-18";
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+/// A JSON-RPC error object.
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn invalid_params(message: impl Into<String>) -> Self {
+        Self {
+            code: INVALID_PARAMS,
+            message: message.into(),
+        }
+    }
+}
+
+/// Handle `wt serve`: answer requests on stdin until it closes.
+pub fn handle_serve(allow: &[ServeOperation]) -> anyhow::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    for line in std::io::stdin().lock().lines() {
+        let line = line.context("Failed to read request")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_message(&line, allow) {
+            writeln!(stdout, "{response}")?;
+            stdout.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// The response to one message, or `None` for a notification.
+fn handle_message(line: &str, allow: &[ServeOperation]) -> Option<Value> {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(err) => {
+            return Some(response(
+                Value::Null,
+                Err(RpcError {
+                    code: PARSE_ERROR,
+                    message: format!("Parse error: {err}"),
+                }),
+            ));
+        }
+    };
+    let id = request.get("id")?.clone();
+    let params = request.get("params").unwrap_or(&Value::Null);
+    let result = match request.get("method").and_then(Value::as_str) {
+        Some("initialize") => Ok(initialize(params)),
+        Some("ping") => Ok(json!({})),
+        Some("tools/list") => Ok(json!({
+            "tools": allow.iter().map(|&op| tool(op)).collect::<Vec<_>>(),
+        })),
+        Some("tools/call") => call_tool(params, allow),
+        method => Err(RpcError {
+            code: METHOD_NOT_FOUND,
+            message: format!("Unknown method: {}", method.unwrap_or_default()),
+        }),
+    };
+    Some(response(id, result))
+}
+
+fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(err) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": err.code, "message": err.message },
+        }),
+    }
+}
+
+fn initialize(params: &Value) -> Value {
+    let version = params
+        .get("protocolVersion")
+        .and_then(Value::as_str)
+        .unwrap_or(PROTOCOL_VERSION);
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": {} },
+        "serverInfo": { "name": "worktrunk", "version": env!("CARGO_PKG_VERSION") },
+    })
+}
+
+/// The tool's name: the same spelling `--allow` takes.
+fn tool_name(op: ServeOperation) -> String {
+    op.to_possible_value()
+        .expect("no skipped variants")
+        .get_name()
+        .to_string()
+}
+
+/// The `tools/list` entry for an operation.
+fn tool(op: ServeOperation) -> Value {
+    let target = json!({ "type": "string", "description": "Target branch (defaults to the default branch)" });
+    let (description, mut properties, required): (&str, Value, &[&str]) = match op {
+        ServeOperation::List => (
+            "List worktrees with their status",
+            json!({
+                "branches": { "type": "boolean", "description": "Include branches without worktrees" },
+                "remotes": { "type": "boolean", "description": "Include remote branches" },
+                "full": { "type": "boolean", "description": "Include CI status and diff against the default branch" },
+            }),
+            &[],
+        ),
+        ServeOperation::Switch => (
+            "Switch to a branch's worktree, creating the worktree if needed",
+            json!({
+                "branch": { "type": "string", "description": "Branch name" },
+                "create": { "type": "boolean", "description": "Create a new branch" },
+                "base": { "type": "string", "description": "Base for a new branch" },
+            }),
+            &["branch"],
+        ),
+        ServeOperation::Remove => (
+            "Remove worktrees and their merged branches",
+            json!({
+                "branches": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Branches to remove (defaults to the current worktree)",
+                },
+                "force": { "type": "boolean", "description": "Remove even with uncommitted changes" },
+                "delete_branch": { "type": "boolean", "description": "Delete the branch when merged (default true)" },
+            }),
+            &[],
+        ),
+        ServeOperation::Merge => (
+            "Merge the current branch into the target: commit, squash, rebase, fast-forward, remove the worktree",
+            json!({
+                "target": target,
+                "squash": { "type": "boolean", "description": "Squash commits (default true)" },
+                "remove": { "type": "boolean", "description": "Remove the worktree after merging (default true)" },
+            }),
+            &[],
+        ),
+        ServeOperation::Rebase => (
+            "Rebase the current branch onto the target",
+            json!({ "target": target }),
+            &[],
+        ),
+        ServeOperation::Squash => (
+            "Squash the current branch's commits since the target into one",
+            json!({ "target": target }),
+            &[],
+        ),
+        ServeOperation::Push => (
+            "Fast-forward the target to the current branch",
+            json!({ "target": target }),
+            &[],
+        ),
+        ServeOperation::PruneCandidates => (
+            "List worktrees `wt step prune` would remove, without removing them",
+            json!({
+                "min_age": { "type": "string", "description": "Skip worktrees younger than this (default 1d)" },
+            }),
+            &[],
+        ),
+    };
+    properties["worktree"] = json!({
+        "type": "string",
+        "description": "Worktree path to run in (defaults to where the server started)",
+    });
+    json!({
+        "name": tool_name(op),
+        "description": description,
+        "inputSchema": { "type": "object", "properties": properties, "required": required },
+    })
+}
+
+fn call_tool(params: &Value, allow: &[ServeOperation]) -> Result<Value, RpcError> {
+    let name = params
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| RpcError::invalid_params("Missing tool name"))?;
+    let op = ServeOperation::from_str(name, false)
+        .map_err(|_| RpcError::invalid_params(format!("Unknown tool: {name}")))?;
+    if !allow.contains(&op) {
+        return Err(RpcError::invalid_params(format!(
+            "Tool not allowed: {name}; start the server with --allow {name}"
+        )));
+    }
+    let empty = Map::new();
+    let args = match params.get("arguments") {
+        None | Some(Value::Null) => &empty,
+        Some(Value::Object(args)) => args,
+        Some(_) => return Err(RpcError::invalid_params("Tool arguments must be an object")),
+    };
+    let argv = command_args(op, args).map_err(RpcError::invalid_params)?;
+    run_wt(&argv).map_err(|err| RpcError {
+        code: INTERNAL_ERROR,
+        message: format!("{err:#}"),
+    })
+}
+
+/// The `wt` arguments for a tool call.
+fn command_args(op: ServeOperation, args: &Map<String, Value>) -> Result<Vec<String>, String> {
+    let mut argv: Vec<String> = Vec::new();
+    if let Some(path) = string_arg(args, "worktree")? {
+        argv.extend(["-C".into(), path]);
+    }
+    let mut push = |items: &[&str]| argv.extend(items.iter().map(|s| s.to_string()));
+    match op {
+        ServeOperation::List => {
+            push(&["list", "--format=json"]);
+            for flag in ["branches", "remotes", "full"] {
+                if bool_arg(args, flag)? == Some(true) {
+                    push(&[&format!("--{flag}")]);
+                }
+            }
+        }
+        ServeOperation::Switch => {
+            let branch = string_arg(args, "branch")?.ok_or("Missing argument: branch")?;
+            push(&["switch", "--format=json", "--no-cd"]);
+            if bool_arg(args, "create")? == Some(true) {
+                push(&["--create"]);
+            }
+            if let Some(base) = string_arg(args, "base")? {
+                push(&["--base", &base]);
+            }
+            push(&[&branch]);
+        }
+        ServeOperation::Remove => {
+            push(&["remove", "--format=json", "--foreground"]);
+            if bool_arg(args, "force")? == Some(true) {
+                push(&["--force"]);
+            }
+            if bool_arg(args, "delete_branch")? == Some(false) {
+                push(&["--no-delete-branch"]);
+            }
+            for branch in string_list_arg(args, "branches")? {
+                push(&[&branch]);
+            }
+        }
+        ServeOperation::Merge => {
+            push(&["merge", "--format=json"]);
+            if bool_arg(args, "squash")? == Some(false) {
+                push(&["--no-squash"]);
+            }
+            if bool_arg(args, "remove")? == Some(false) {
+                push(&["--no-remove"]);
+            }
+            if let Some(target) = string_arg(args, "target")? {
+                push(&[&target]);
+            }
+        }
+        ServeOperation::Rebase | ServeOperation::Squash | ServeOperation::Push => {
+            push(&["step", &tool_name(op), "--format=json"]);
+            if let Some(target) = string_arg(args, "target")? {
+                push(&[&target]);
+            }
+        }
+        ServeOperation::PruneCandidates => {
+            push(&["step", "prune", "--dry-run", "--format=json"]);
+            if let Some(min_age) = string_arg(args, "min_age")? {
+                push(&["--min-age", &min_age]);
+            }
+        }
+    }
+    Ok(argv)
+}
+
+/// A string argument. Values starting with `-` are refused so a branch name
+/// can't be read as a flag.
+fn string_arg(args: &Map<String, Value>, key: &str) -> Result<Option<String>, String> {
+    match args.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) if s.starts_with('-') => {
+            Err(format!("Invalid argument {key}: {s:?} starts with '-'"))
+        }
+        Some(Value::String(s)) => Ok(Some(s.clone())),
+        Some(_) => Err(format!("Argument {key} must be a string")),
+    }
+}
+
+fn bool_arg(args: &Map<String, Value>, key: &str) -> Result<Option<bool>, String> {
+    match args.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Bool(b)) => Ok(Some(*b)),
+        Some(_) => Err(format!("Argument {key} must be a boolean")),
+    }
+}
+
+fn string_list_arg(args: &Map<String, Value>, key: &str) -> Result<Vec<String>, String> {
+    match args.get(key) {
+        None | Some(Value::Null) => Ok(Vec::new()),
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(|item| match item {
+                Value::String(s) if !s.starts_with('-') => Ok(s.clone()),
+                _ => Err(format!("Argument {key} must be a list of branch names")),
+            })
+            .collect(),
+        Some(_) => Err(format!("Argument {key} must be a list of strings")),
+    }
+}
+
+/// Run `wt` with `argv` and shape its output as a `tools/call` result.
+///
+/// A failed command is a tool result with `isError`, not a protocol error, so
+/// the agent sees the message and can react to it.
+fn run_wt(argv: &[String]) -> anyhow::Result<Value> {
+    let exe = std::env::current_exe().context("Failed to locate the wt executable")?;
+    let output = Cmd::new(exe.to_string_lossy())
+        .args(argv.iter().cloned())
+        .stdin(Stdio::null())
+        .run()
+        .context("Failed to run wt")?;
+    let success = output.status.success();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stdout = stdout.trim();
+    let messages = String::from_utf8_lossy(&output.stderr)
+        .ansi_strip()
+        .trim()
+        .to_string();
+    let data = if stdout.is_empty() {
+        Value::Null
+    } else {
+        serde_json::from_str(stdout).unwrap_or_else(|_| Value::String(stdout.to_string()))
+    };
+    let text = if success && !data.is_null() {
+        serde_json::to_string_pretty(&data)?
+    } else {
+        messages.clone()
+    };
+    Ok(json!({
+        "content": [{ "type": "text", "text": text }],
+        "structuredContent": { "success": success, "output": data, "messages": messages },
+        "isError": !success,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn test_command_args() {
+        assert_eq!(
+            command_args(
+                ServeOperation::Switch,
+                &args(json!({ "branch": "feature", "create": true, "worktree": "/repo" }))
+            )
+            .unwrap(),
+            [
+                "-C",
+                "/repo",
+                "switch",
+                "--format=json",
+                "--no-cd",
+                "--create",
+                "feature"
+            ]
+        );
+        assert_eq!(
+            command_args(ServeOperation::Rebase, &args(json!({ "target": "main" }))).unwrap(),
+            ["step", "rebase", "--format=json", "main"]
+        );
+        assert_eq!(
+            command_args(
+                ServeOperation::Remove,
+                &args(json!({ "branches": ["a", "b"], "delete_branch": false }))
+            )
+            .unwrap(),
+            [
+                "remove",
+                "--format=json",
+                "--foreground",
+                "--no-delete-branch",
+                "a",
+                "b"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_command_args_rejects_flags() {
+        assert!(command_args(ServeOperation::Switch, &args(json!({}))).is_err());
+        assert!(
+            command_args(
+                ServeOperation::Switch,
+                &args(json!({ "branch": "--execute=rm" }))
+            )
+            .is_err()
+        );
+        assert!(
+            command_args(ServeOperation::Remove, &args(json!({ "branches": ["-D"] }))).is_err()
+        );
+        assert!(command_args(ServeOperation::List, &args(json!({ "full": "yes" }))).is_err());
+    }
+
+    #[test]
+    fn test_handle_message_protocol() {
+        let allow = [ServeOperation::List];
+        assert!(
+            handle_message(
+                r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+                &allow
+            )
+            .is_none()
+        );
+        let parse = handle_message("{", &allow).unwrap();
+        assert_eq!(parse["error"]["code"], PARSE_ERROR);
+        let unknown =
+            handle_message(r#"{"jsonrpc":"2.0","id":1,"method":"nope"}"#, &allow).unwrap();
+        assert_eq!(unknown["error"]["code"], METHOD_NOT_FOUND);
+        let denied = handle_message(
+            r#"{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"remove"}}"#,
+            &allow,
+        )
+        .unwrap();
+        assert_eq!(denied["error"]["code"], INVALID_PARAMS);
+    }
+}
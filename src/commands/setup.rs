@@ -0,0 +1,278 @@
+//! `wt setup`: first-run onboarding.
+//!
+//! Walks through what a new user otherwise meets one prompt at a time — shell
+//! integration on the first `wt switch`, LLM commit messages on the first
+//! commit — plus the worktree location, which nothing prompts for, and the
+//! CI tools `wt list --full` relies on. Each step writes the user config as
+//! it goes, so quitting partway keeps the earlier choices.
+//!
+//! With `--yes`, every step takes its default without prompting: install
+//! shell integration, keep the current worktree-path, configure the detected
+//! LLM tool.
+
+use std::fmt::Write as _;
+use std::io::{self, IsTerminal};
+
+use color_print::cformat;
+use worktrunk::config::{UserConfig, config_path_for_display, require_config_path};
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    eprintln, format_heading, format_toml, format_with_gutter, hint_message, info_message,
+    success_message,
+};
+
+use crate::commands::config::{
+    render_ci_platform_status, render_ci_tool_status, render_commit_generation_status,
+};
+use crate::commands::configure_shell::handle_configure_shell;
+use crate::commands::list::ci_status::CiToolsStatus;
+use crate::commands::worktree::compute_worktree_path;
+use crate::output::commit_generation::{detect_llm_tool, format_command_for_display};
+use crate::output::prompt::{PromptResponse, prompt_choice, prompt_yes_no_preview};
+
+/// Worktree layouts offered by the setup wizard: a label and the
+/// `worktree-path` template. The first is the built-in default.
+const WORKTREE_PATH_PRESETS: &[(&str, &str)] = &[
+    (
+        "Sibling directory",
+        "{{ repo_path }}/../{{ repo }}.{{ branch | sanitize }}",
+    ),
+    (
+        "Inside the repository",
+        "{{ repo_path }}/.worktrees/{{ branch | sanitize }}",
+    ),
+    (
+        "Centralized directory",
+        "~/worktrees/{{ repo }}/{{ branch | sanitize }}",
+    ),
+    (
+        "Friendly names",
+        "{{ repo_path }}/../{{ repo }}.{{ branch | codename(2) }}",
+    ),
+];
+
+/// Branch each worktree-path preset is previewed with.
+const PREVIEW_BRANCH: &str = "feature/auth";
+
+/// Handle `wt setup`. `yes` accepts every step's default without prompting.
+pub(crate) fn handle_setup(yes: bool) -> anyhow::Result<()> {
+    let is_tty = io::stdin().is_terminal() && io::stderr().is_terminal();
+    if !yes && !is_tty {
+        anyhow::bail!("Setup requires an interactive terminal (use --yes to accept the defaults)");
+    }
+
+    // Outside a repository, previews fall back to the raw template and the CI
+    // check covers both gh and glab.
+    let repo = Repository::current()
+        .ok()
+        .filter(|repo| repo.repo_path().is_ok());
+    let mut config = UserConfig::load()?;
+    let config_path = require_config_path()?;
+
+    setup_shell_integration(yes)?;
+    setup_worktree_path(repo.as_ref(), &mut config, &config_path, yes)?;
+    setup_commit_generation(repo.as_ref(), &mut config, &config_path, yes)?;
+    check_ci_tools(repo.as_ref())?;
+
+    eprintln!();
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Setup complete; config @ <bold>{}</>",
+            config_path_for_display()
+        ))
+    );
+    eprintln!(
+        "{}",
+        hint_message(cformat!("View config: <underline>wt config show</>"))
+    );
+    Ok(())
+}
+
+fn setup_shell_integration(yes: bool) -> anyhow::Result<()> {
+    eprintln!("{}", format_heading("SHELL INTEGRATION", None));
+    match handle_configure_shell(None, yes, false, crate::binary_name()) {
+        Ok(scan_result) if scan_result.configured.is_empty() => {
+            crate::output::print_skipped_shells(&scan_result.skipped);
+            eprintln!(
+                "{}",
+                hint_message(cformat!(
+                    "No shell config files found; see <underline>wt config shell init --help</>"
+                ))
+            );
+        }
+        Ok(scan_result) => crate::output::print_shell_install_result(&scan_result),
+        Err(_) => eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Skipped; install later with <underline>wt config shell install</>"
+            ))
+        ),
+    }
+    Ok(())
+}
+
+fn setup_worktree_path(
+    repo: Option<&Repository>,
+    config: &mut UserConfig,
+    config_path: &std::path::Path,
+    yes: bool,
+) -> anyhow::Result<()> {
+    eprintln!();
+    eprintln!("{}", format_heading("WORKTREE PATHS", None));
+    let current = config.worktree_path();
+    let project = repo.and_then(|repo| repo.project_identifier().ok());
+
+    let mut listing = String::new();
+    for (i, (label, template)) in WORKTREE_PATH_PRESETS.iter().enumerate() {
+        let marker = if *template == current {
+            " (current)"
+        } else {
+            ""
+        };
+        writeln!(listing, "{}. {label}{marker}", i + 1)?;
+        writeln!(listing, "   {template}")?;
+        if let Some(preview) = repo.and_then(|repo| preview_path(repo, config, template)) {
+            writeln!(listing, "   {PREVIEW_BRANCH} → {preview}")?;
+        }
+    }
+    eprintln!("{}", format_with_gutter(listing.trim_end(), None));
+    if !WORKTREE_PATH_PRESETS.iter().any(|(_, t)| *t == current) {
+        eprintln!(
+            "{}",
+            info_message(cformat!("Current worktree-path: <bold>{current}</>"))
+        );
+    }
+    if let Some(project) = project.as_deref()
+        && config.has_project_worktree_path(project)
+    {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "This project sets its own worktree-path in <bold>[projects.\"{project}\"]</>, which takes precedence"
+            ))
+        );
+    }
+
+    let choice = if yes {
+        None
+    } else {
+        prompt_choice(
+            "Where should new worktrees go?",
+            WORKTREE_PATH_PRESETS.len(),
+        )?
+    };
+    match choice.map(|i| WORKTREE_PATH_PRESETS[i].1) {
+        Some(template) if template != current => {
+            config.set_worktree_path(template.to_string(), config_path)?;
+            eprintln!(
+                "{}",
+                success_message(cformat!("Set worktree-path to <bold>{template}</>"))
+            );
+        }
+        _ => eprintln!("{}", info_message("Keeping current worktree-path")),
+    }
+    Ok(())
+}
+
+/// Where a worktree for [`PREVIEW_BRANCH`] would go under `template`,
+/// ignoring any project override so each preset shows its own layout.
+fn preview_path(repo: &Repository, config: &UserConfig, template: &str) -> Option<String> {
+    let mut preview = config.clone();
+    preview.worktree_path = Some(template.to_string());
+    if let Ok(project) = repo.project_identifier() {
+        preview.projects.remove(&project);
+    }
+    compute_worktree_path(repo, PREVIEW_BRANCH, &preview)
+        .ok()
+        .map(|path| format_path_for_display(&path))
+}
+
+fn setup_commit_generation(
+    repo: Option<&Repository>,
+    config: &mut UserConfig,
+    config_path: &std::path::Path,
+    yes: bool,
+) -> anyhow::Result<()> {
+    eprintln!();
+    eprintln!("{}", format_heading("COMMIT MESSAGES", None));
+    let project = repo.and_then(|repo| repo.project_identifier().ok());
+
+    if !config.commit_generation(project.as_deref()).is_configured() {
+        let Some(tool) = detect_llm_tool() else {
+            eprintln!(
+                "{}",
+                hint_message(cformat!(
+                    "No LLM tool found (claude, codex, opencode); see <underline>https://worktrunk.dev/llm-commits/</>"
+                ))
+            );
+            return Ok(());
+        };
+
+        let command = tool.recommended_config();
+        let config_preview = format!(
+            "[commit.generation]\ncommand = {}",
+            format_command_for_display(command)
+        );
+        let accepted = yes
+            || prompt_yes_no_preview(
+                &cformat!("Configure <bold>{tool}</> for commit messages?"),
+                || {
+                    eprintln!(
+                        "{}",
+                        info_message(cformat!(
+                            "Would add to <bold>{}</>:",
+                            config_path_for_display()
+                        ))
+                    );
+                    eprintln!("{}", format_toml(&config_preview));
+                    eprintln!();
+                },
+            )? == PromptResponse::Accepted;
+        if !accepted {
+            eprintln!(
+                "{}",
+                info_message("Skipped; commits fall back to generic messages")
+            );
+            return Ok(());
+        }
+        config.set_commit_generation_command(command.to_string(), config_path)?;
+        eprintln!("{}", success_message("Added to user config:"));
+        eprintln!("{}", format_toml(&config_preview));
+    }
+
+    // Run the command once so a broken setup shows up now, not mid-merge.
+    let mut out = String::new();
+    render_commit_generation_status(&mut out, &config.commit_generation(project.as_deref()))?;
+    eprint!("{out}");
+    Ok(())
+}
+
+fn check_ci_tools(repo: Option<&Repository>) -> anyhow::Result<()> {
+    eprintln!();
+    eprintln!("{}", format_heading("CI STATUS", None));
+    let mut out = String::new();
+    match repo.and_then(|repo| repo.ci_platform(None)) {
+        Some(platform) => render_ci_platform_status(&mut out, Some(platform))?,
+        None => {
+            let ci_tools = CiToolsStatus::detect(None);
+            render_ci_tool_status(
+                &mut out,
+                "gh",
+                "GitHub",
+                ci_tools.gh_installed,
+                ci_tools.gh_authenticated,
+            )?;
+            render_ci_tool_status(
+                &mut out,
+                "glab",
+                "GitLab",
+                ci_tools.glab_installed,
+                ci_tools.glab_authenticated,
+            )?;
+        }
+    }
+    eprint!("{out}");
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! `wt snapshot` — named base commits for reproducible experiments.
+//!
+//! A snapshot pins the default branch's commit (or another ref's) so several
+//! worktrees can be created from exactly the same base with
+//! `wt switch --create <branch> --base snapshot:<name>`. Storage lives in
+//! [`worktrunk::git::BaseSnapshot`]'s module.
+
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{eprintln, info_message, println, success_message};
+
+use crate::cli::SwitchFormat;
+
+/// Prefix selecting a snapshot wherever `--base` is accepted.
+pub const SNAPSHOT_BASE_PREFIX: &str = "snapshot:";
+
+/// Handle `wt snapshot create <NAME> [--base REF]`.
+pub fn handle_snapshot_create(name: &str, base: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let reference = repo.resolve_worktree_name(base.unwrap_or("^"))?;
+    let sha = repo.create_base_snapshot(name, &reference)?;
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Created snapshot <bold>{name}</> of <bold>{reference}</> @ <dim>{}</>",
+            repo.short_sha(&sha)?
+        ))
+    );
+    Ok(())
+}
+
+/// Handle `wt snapshot [list]`.
+pub fn handle_snapshot_list(format: SwitchFormat) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let snapshots = repo.base_snapshots()?;
+
+    if format == SwitchFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&snapshots)?);
+        return Ok(());
+    }
+    if snapshots.is_empty() {
+        eprintln!("{}", info_message("No snapshots"));
+        return Ok(());
+    }
+    let width = snapshots.iter().map(|s| s.name.len()).max().unwrap_or(0);
+    for snapshot in snapshots {
+        println!(
+            "{:<width$}  {}  {}",
+            snapshot.name,
+            cformat!("<dim>{}</>", repo.short_sha(&snapshot.sha)?),
+            snapshot.subject,
+        );
+    }
+    Ok(())
+}
+
+/// Handle `wt snapshot delete <NAME>`.
+pub fn handle_snapshot_delete(name: &str) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    if repo.delete_base_snapshot(name)? {
+        eprintln!(
+            "{}",
+            success_message(cformat!("Deleted snapshot <bold>{name}</>"))
+        );
+    } else {
+        eprintln!(
+            "{}",
+            info_message(cformat!("No snapshot named <bold>{name}</>"))
+        );
+    }
+    Ok(())
+}
+
+/// Commit pinned by the snapshot `--base snapshot:<name>` names.
+pub fn resolve_snapshot_base(repo: &Repository, name: &str) -> anyhow::Result<String> {
+    repo.base_snapshot_sha(name)?.ok_or_else(|| {
+        GitError::Other {
+            message: cformat!(
+                "No snapshot named <bold>{name}</>. Run <underline>wt snapshot</> to list them."
+            ),
+        }
+        .into()
+    })
+}
@@ -0,0 +1,333 @@
+//! `wt stats` — local usage trends from the events `[stats] enabled` records.
+//!
+//! Events come from [`worktrunk::stats`]; this module buckets them into weeks
+//! (starting Monday, UTC) and aggregates command runs over the same window.
+//! Merge latency pairs each merge with the latest creation of the same
+//! branch's worktree before it.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+
+use color_print::cformat;
+use serde::Serialize;
+use worktrunk::git::Repository;
+use worktrunk::stats::{StatsEvent, StatsRecord, read_events};
+use worktrunk::styling::{
+    eprintln, format_heading, format_with_gutter, hint_message, info_message, println,
+};
+use worktrunk::utils::epoch_now;
+
+use crate::cli::OutputFormat;
+
+const DAY: u64 = 24 * 60 * 60;
+const WEEK: u64 = 7 * DAY;
+
+#[derive(Debug, Default, Serialize)]
+struct WeekStats {
+    /// Monday the week starts, as `YYYY-MM-DD`
+    week: String,
+    created: usize,
+    merged: usize,
+    removed: usize,
+    /// Average over merges whose worktree creation was recorded
+    avg_merge_latency_secs: Option<u64>,
+    #[serde(skip)]
+    latencies: Vec<u64>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct CommandStats {
+    command: String,
+    runs: usize,
+    failed: usize,
+    avg_duration_ms: u64,
+    #[serde(skip)]
+    total_duration_ms: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct Stats {
+    enabled: bool,
+    weeks: Vec<WeekStats>,
+    commands: Vec<CommandStats>,
+}
+
+/// Handle `wt stats [--weeks N] [--format json]`.
+pub fn handle_stats(weeks: u32, format: OutputFormat) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let enabled = repo.user_config().stats.enabled();
+    let records = read_events(&repo);
+    let stats = compute_stats(&records, weeks, epoch_now(), enabled);
+
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&stats)?),
+        OutputFormat::Table if records.is_empty() => {
+            eprintln!("{}", info_message("No usage stats recorded"));
+            if !enabled {
+                eprintln!("{}", hint_message(enable_hint()));
+            }
+        }
+        OutputFormat::Table => {
+            println!("{}", render_table(&stats)?.trim_end());
+            if !enabled {
+                eprintln!("{}", hint_message(enable_hint()));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn enable_hint() -> String {
+    cformat!(
+        "Recording is off; to turn it on, set <bright-black>enabled = true</> under <bright-black>[stats]</> in user config"
+    )
+}
+
+/// Aggregate `records` (oldest first) over the `weeks` weeks ending with the
+/// one containing `now`.
+fn compute_stats(records: &[StatsRecord], weeks: u32, now: u64, enabled: bool) -> Stats {
+    let current_week = week_start(now);
+    let window_start = current_week.saturating_sub(u64::from(weeks - 1) * WEEK);
+    let mut week_stats: Vec<WeekStats> = (0..u64::from(weeks))
+        .map(|i| WeekStats {
+            week: format_date(window_start + i * WEEK),
+            ..Default::default()
+        })
+        .collect();
+    let mut commands: BTreeMap<&str, CommandStats> = BTreeMap::new();
+    // Latest creation per branch, including before the window, so a branch
+    // created last month and merged this week still has a latency
+    let mut created_at: HashMap<&str, u64> = HashMap::new();
+
+    for record in records {
+        if let StatsEvent::WorktreeCreated { branch } = &record.event {
+            created_at.insert(branch, record.ts);
+        }
+        if record.ts < window_start || record.ts >= current_week + WEEK {
+            continue;
+        }
+        let week = &mut week_stats[((week_start(record.ts) - window_start) / WEEK) as usize];
+        match &record.event {
+            StatsEvent::Command {
+                command,
+                duration_ms,
+                success,
+            } => {
+                let entry = commands.entry(command).or_insert_with(|| CommandStats {
+                    command: command.clone(),
+                    ..Default::default()
+                });
+                entry.runs += 1;
+                entry.failed += usize::from(!success);
+                entry.total_duration_ms += duration_ms;
+            }
+            StatsEvent::WorktreeCreated { .. } => week.created += 1,
+            StatsEvent::WorktreeRemoved { .. } => week.removed += 1,
+            StatsEvent::Merged { branch, .. } => {
+                week.merged += 1;
+                if let Some(&created) = created_at.get(branch.as_str()) {
+                    week.latencies.push(record.ts - created);
+                }
+            }
+        }
+    }
+
+    for week in &mut week_stats {
+        if !week.latencies.is_empty() {
+            let total: u64 = week.latencies.iter().sum();
+            week.avg_merge_latency_secs = Some(total / week.latencies.len() as u64);
+        }
+    }
+    let mut commands: Vec<CommandStats> = commands
+        .into_values()
+        .map(|mut entry| {
+            entry.avg_duration_ms = entry.total_duration_ms / entry.runs as u64;
+            entry
+        })
+        .collect();
+    // Most-used first; the BTreeMap order breaks ties by name
+    commands.sort_by_key(|entry| std::cmp::Reverse(entry.runs));
+
+    Stats {
+        enabled,
+        weeks: week_stats,
+        commands,
+    }
+}
+
+fn render_table(stats: &Stats) -> Result<String, std::fmt::Error> {
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "{}",
+        format_heading("WORKTREES", Some("per week (UTC)"))
+    )?;
+    let rows: Vec<Vec<String>> = stats
+        .weeks
+        .iter()
+        .map(|week| {
+            vec![
+                week.week.clone(),
+                week.created.to_string(),
+                week.merged.to_string(),
+                week.removed.to_string(),
+                week.avg_merge_latency_secs
+                    .map_or_else(|| "-".to_string(), format_latency),
+            ]
+        })
+        .collect();
+    let rendered = crate::md_help::render_data_table(
+        &["Week", "Created", "Merged", "Removed", "Merge latency"],
+        &rows,
+    );
+    writeln!(out, "{}", rendered.trim_end())?;
+    writeln!(out)?;
+
+    writeln!(out, "{}", format_heading("COMMANDS", None))?;
+    if stats.commands.is_empty() {
+        writeln!(out, "{}", format_with_gutter("(none)", None))?;
+    } else {
+        let rows: Vec<Vec<String>> = stats
+            .commands
+            .iter()
+            .map(|entry| {
+                vec![
+                    entry.command.clone(),
+                    entry.runs.to_string(),
+                    format_command_duration(entry.avg_duration_ms),
+                    entry.failed.to_string(),
+                ]
+            })
+            .collect();
+        let rendered =
+            crate::md_help::render_data_table(&["Command", "Runs", "Avg time", "Failed"], &rows);
+        writeln!(out, "{}", rendered.trim_end())?;
+    }
+
+    Ok(out)
+}
+
+/// Start of the week (Monday 00:00 UTC) containing `ts`.
+fn week_start(ts: u64) -> u64 {
+    let day = ts / DAY;
+    // 1970-01-01 was a Thursday
+    (day - (day + 3) % 7) * DAY
+}
+
+fn format_date(ts: u64) -> String {
+    chrono::DateTime::from_timestamp(ts as i64, 0)
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
+
+/// Coarse duration for merge latency: `45m`, `5h`, `3d`.
+fn format_latency(secs: u64) -> String {
+    const HOUR: u64 = 60 * 60;
+    if secs < HOUR {
+        format!("{}m", secs / 60)
+    } else if secs < 2 * DAY {
+        format!("{}h", secs / HOUR)
+    } else {
+        format!("{}d", secs / DAY)
+    }
+}
+
+pub(super) fn format_command_duration(ms: u64) -> String {
+    if ms < 1000 {
+        format!("{ms}ms")
+    } else {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(ts: u64, event: StatsEvent) -> StatsRecord {
+        StatsRecord { ts, event }
+    }
+
+    #[test]
+    fn test_week_start() {
+        // 2025-01-02 (Thursday) → 2024-12-30 (Monday)
+        assert_eq!(format_date(week_start(1_735_776_000)), "2024-12-30");
+        // Monday midnight is its own week start
+        assert_eq!(week_start(1_735_516_800), 1_735_516_800);
+    }
+
+    #[test]
+    fn test_compute_stats() {
+        let now = 1_735_776_000; // Thursday 2025-01-02
+        let created = |ts, branch: &str| {
+            record(
+                ts,
+                StatsEvent::WorktreeCreated {
+                    branch: branch.into(),
+                },
+            )
+        };
+        let merged = |ts, branch: &str| {
+            record(
+                ts,
+                StatsEvent::Merged {
+                    branch: branch.into(),
+                    target: "main".into(),
+                },
+            )
+        };
+        let records = vec![
+            // Created before the window, merged inside it
+            created(now - 2 * WEEK - 3600, "old"),
+            created(now - WEEK, "a"),
+            merged(now - WEEK + 3600, "a"),
+            merged(now - WEEK + 3600, "old"),
+            created(now - 60, "b"),
+            merged(now, "untracked"),
+            record(
+                now,
+                StatsEvent::Command {
+                    command: "switch".into(),
+                    duration_ms: 300,
+                    success: true,
+                },
+            ),
+            record(
+                now,
+                StatsEvent::Command {
+                    command: "switch".into(),
+                    duration_ms: 100,
+                    success: false,
+                },
+            ),
+        ];
+        let stats = compute_stats(&records, 2, now, true);
+
+        assert_eq!(stats.weeks.len(), 2);
+        let last_week = &stats.weeks[0];
+        assert_eq!(last_week.week, "2024-12-23");
+        assert_eq!((last_week.created, last_week.merged), (1, 2));
+        assert_eq!(
+            last_week.avg_merge_latency_secs,
+            Some((3600 + WEEK + 7200) / 2)
+        );
+        let this_week = &stats.weeks[1];
+        assert_eq!((this_week.created, this_week.merged), (1, 1));
+        assert_eq!(this_week.avg_merge_latency_secs, None);
+
+        assert_eq!(stats.commands.len(), 1);
+        assert_eq!(stats.commands[0].runs, 2);
+        assert_eq!(stats.commands[0].failed, 1);
+        assert_eq!(stats.commands[0].avg_duration_ms, 200);
+    }
+
+    #[test]
+    fn test_format_durations() {
+        assert_eq!(format_latency(90), "1m");
+        assert_eq!(format_latency(5 * 3600 + 10), "5h");
+        assert_eq!(format_latency(3 * DAY), "3d");
+        assert_eq!(format_command_duration(250), "250ms");
+        assert_eq!(format_command_duration(1260), "1.3s");
+    }
+}
@@ -17,7 +17,8 @@ use ansi_str::AnsiStr;
 use anyhow::{Context, Result};
 use worktrunk::git::Repository;
 use worktrunk::styling::{
-    fix_dim_after_color_reset, terminal_width_for_statusline, truncate_visible,
+    ColorChoice, color_choice, fix_dim_after_color_reset, terminal_width_for_statusline,
+    truncate_visible,
 };
 
 use super::list::{self, CollectOptions, StatuslineSegment, json_output};
@@ -714,7 +715,8 @@ fn format_context_gauge(percentage: f64) -> String {
 /// Run the statusline command.
 ///
 /// Output uses `println!` for raw stdout (bypasses anstream color detection).
-/// Shell prompts (PS1) and Claude Code always expect ANSI codes.
+/// Shell prompts (PS1) and Claude Code always expect ANSI codes, so only an
+/// explicit `--color=never` strips them.
 pub fn run(format: StatuslineFormat) -> Result<()> {
     // Statusline runs on every prompt redraw — deprecation warnings on stderr
     // would appear above each prompt.
@@ -829,6 +831,11 @@ pub fn run(format: StatuslineFormat) -> Result<()> {
     let reset = anstyle::Reset;
     let output = fix_dim_after_color_reset(&output);
     let output = truncate_visible(&format!("{reset} {output}"), max_width);
+    let output = if color_choice() == ColorChoice::Never {
+        output.ansi_strip().into_owned()
+    } else {
+        output
+    };
 
     println!("{}", output);
 
@@ -886,8 +893,12 @@ fn run_json() -> Result<()> {
     let gates = list::columns::ColumnGates {
         show_full: true,
         summary_enabled: false,
+        show_size: false,
         has_llm_command: false,
         has_url_template: url_template.is_some(),
+        has_network: !repo.user_config().offline(),
+        has_hook_status: false,
+        has_stashes: false,
     };
     let options = CollectOptions {
         url_template,
@@ -1012,8 +1023,12 @@ fn git_status_segments(
     let gates = list::columns::ColumnGates {
         show_full: true,
         summary_enabled: false,
+        show_size: false,
         has_llm_command: false,
         has_url_template: url_template.is_some(),
+        has_network: !repo.user_config().offline(),
+        has_hook_status: false,
+        has_stashes: false,
     };
     let options = CollectOptions {
         url_template,
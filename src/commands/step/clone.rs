@@ -0,0 +1,110 @@
+//! `wt step clone` — clone a repository that shares or defers its objects.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{eprintln, format_with_gutter, info_message, success_message};
+
+/// Handle `wt step clone`
+///
+/// Runs `git clone` with `--reference` (objects/info/alternates) and/or
+/// `--filter` (partial clone), then reports what the new clone borrows.
+pub fn step_clone(
+    url: &str,
+    directory: Option<&Path>,
+    reference: Option<&Path>,
+    filter: Option<&str>,
+) -> anyhow::Result<()> {
+    // Name the directory ourselves so we can open the clone afterwards.
+    let directory = match directory {
+        Some(dir) => dir.to_path_buf(),
+        None => PathBuf::from(
+            directory_from_url(url)
+                .with_context(|| cformat!("Can't derive a directory from <bold>{url}</>"))?,
+        ),
+    };
+
+    let mut cmd = Cmd::new("git").arg("clone");
+    if let Some(reference) = reference {
+        cmd = cmd.arg("--reference").arg(reference.to_string_lossy());
+    }
+    if let Some(filter) = filter {
+        cmd = cmd.arg(format!("--filter={filter}"));
+    }
+    cmd.arg("--")
+        .arg(url)
+        .arg(directory.to_string_lossy())
+        .stdout(std::process::Stdio::from(std::io::stderr()))
+        .stream()?;
+
+    let repo = Repository::at(&directory)?;
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Cloned into <bold>{}</>",
+            format_path_for_display(&directory)
+        ))
+    );
+    let alternates = repo.object_store()?.alternates;
+    if !alternates.is_empty() {
+        let stores: Vec<String> = alternates
+            .iter()
+            .map(|path| format_path_for_display(path))
+            .collect();
+        eprintln!("{}", info_message("Borrowing objects from:"));
+        eprintln!("{}", format_with_gutter(&stores.join("\n"), None));
+    }
+    if let Some(filter) = filter {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Partial clone (<bold>{filter}</>); missing objects are fetched on demand"
+            ))
+        );
+    }
+    Ok(())
+}
+
+/// The directory `git clone` would pick for `url`: its last path component,
+/// without a trailing `/.git` or `.git`.
+fn directory_from_url(url: &str) -> Option<&str> {
+    let path = url.trim_end_matches('/');
+    let path = path.strip_suffix("/.git").unwrap_or(path);
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    // `host:org/repo` (scp-style) and `host:repo` both end after the last `/` or `:`.
+    let name = path.rsplit(['/', ':']).next()?;
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directory_from_url() {
+        assert_eq!(
+            directory_from_url("https://github.com/org/repo.git"),
+            Some("repo")
+        );
+        assert_eq!(
+            directory_from_url("https://github.com/org/repo/"),
+            Some("repo")
+        );
+        assert_eq!(
+            directory_from_url("git@github.com:org/repo.git"),
+            Some("repo")
+        );
+        assert_eq!(directory_from_url("host:repo"), Some("repo"));
+        assert_eq!(directory_from_url("/srv/git/repo/.git"), Some("repo"));
+        assert_eq!(directory_from_url("../repo"), Some("repo"));
+        assert_eq!(
+            directory_from_url("https://github.com/"),
+            Some("github.com")
+        );
+        assert_eq!(directory_from_url("/"), None);
+    }
+}
@@ -1,7 +1,10 @@
 //! `wt step diff` — show all changes since branching from the target.
+//!
+//! `wt step diff-branches` compares two worktrees' working states instead.
 
 use anyhow::Context;
-use worktrunk::git::Repository;
+use worktrunk::git::{Repository, ResolvedWorktree};
+use worktrunk::shell_exec::Cmd;
 
 /// Handle `wt step diff` command
 ///
@@ -50,3 +53,51 @@ pub fn step_diff(
 
     Ok(())
 }
+
+/// Handle `wt step diff-branches` command
+///
+/// Diffs two worktrees' current states, uncommitted and untracked files
+/// included. Each side with a worktree is snapshotted by staging everything
+/// into a temp index and writing a tree; a branch without a worktree
+/// contributes its commit. Worktrees share the object store, so the two trees
+/// diff directly.
+pub fn step_diff_branches(from: &str, to: &str, extra_args: &[String]) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let from_tree = snapshot_tree(&repo, from)?;
+    let to_tree = snapshot_tree(&repo, to)?;
+
+    let mut diff_args = vec!["diff".to_string(), from_tree, to_tree];
+    diff_args.extend_from_slice(extra_args);
+    Cmd::new("git")
+        .args(diff_args)
+        .current_dir(repo.repo_path()?)
+        .stream()?;
+
+    Ok(())
+}
+
+/// Tree of `name`'s working state: the worktree's files when it has one,
+/// otherwise the branch's commit.
+fn snapshot_tree(repo: &Repository, name: &str) -> anyhow::Result<String> {
+    let path = match repo.resolve_worktree(name)? {
+        ResolvedWorktree::Worktree { path, .. } => path,
+        ResolvedWorktree::BranchOnly { branch } => {
+            return Ok(format!("refs/heads/{branch}^{{tree}}"));
+        }
+    };
+
+    let idx = repo.worktree_at(path).temp_index()?;
+    let run = |args: &[&str]| -> anyhow::Result<String> {
+        let output = idx.git(args.iter().copied()).run()?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    run(&["add", "-A"]).with_context(|| format!("Failed to snapshot {name}"))?;
+    run(&["write-tree"]).with_context(|| format!("Failed to snapshot {name}"))
+}
@@ -5,34 +5,49 @@
 //! - `squash::handle_squash` - Squash commits into one
 //! - `squash::step_show_squash_prompt` - Show squash prompt without executing
 //! - `rebase::handle_rebase` - Rebase onto target branch
+//! - `push::step_push` - Fast-forward target to current branch
 //! - `diff::step_diff` - Show all changes since branching
 //!
 //! Standalone:
+//! - `diff::step_diff_branches` - Diff two worktrees, uncommitted changes included
+//! - `clone::step_clone` - Clone with `--reference` alternates or a partial-clone filter
 //! - `copy_ignored::step_copy_ignored` - Copy gitignored files matching .worktreeinclude
+//! - `optimize_repo::step_optimize_repo` - Write the commit-graph and multi-pack-index
 //! - `promote::handle_promote` - Swap a branch into the main worktree
 //! - `prune::step_prune` - Remove worktrees merged into the default branch
 //! - `relocate::step_relocate` - Move worktrees to expected paths
+//! - `revert_merge::step_revert_merge` - Back out a branch `wt merge` landed
 //! - `tether::step_tether` - Supervise a command and kill its whole process
 //!   group when the command exits or its worktree is removed
 
+pub(crate) mod clone;
 pub(crate) mod commit;
 pub(crate) mod copy_ignored;
 pub(crate) mod diff;
+pub(crate) mod optimize_repo;
 pub(crate) mod promote;
 pub(crate) mod prune;
+mod prune_report;
+pub(crate) mod push;
 pub(crate) mod rebase;
 pub(crate) mod relocate;
+pub(crate) mod revert_merge;
 mod shared;
 pub(crate) mod squash;
 pub(crate) mod tether;
 
+pub(crate) use clone::step_clone;
 pub(crate) use commit::step_commit;
 pub(crate) use copy_ignored::step_copy_ignored;
-pub(crate) use diff::step_diff;
+pub(crate) use diff::{step_diff, step_diff_branches};
+pub(crate) use optimize_repo::step_optimize_repo;
 pub(crate) use promote::{PromoteResult, handle_promote};
 pub(crate) use prune::step_prune;
+pub(crate) use push::step_push;
 pub(crate) use rebase::{RebaseResult, handle_rebase};
 pub(crate) use relocate::step_relocate;
+pub(crate) use revert_merge::step_revert_merge;
+pub(crate) use shared::check_step_not_on_default_branch;
 pub(crate) use squash::{
     PreApprovedGuidance, SquashResult, handle_squash, step_dry_run_squash, step_show_squash_prompt,
 };
@@ -0,0 +1,30 @@
+//! `wt step optimize-repo` — write the object-store indexes wt's history walks use.
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::styling::{eprintln, progress_message, success_message};
+
+/// Handle `wt step optimize-repo`
+///
+/// Writes the commit-graph and multi-pack-index for the shared object store,
+/// so every worktree benefits. Rewriting an existing commit-graph refreshes it
+/// with commits made since it was last written.
+pub fn step_optimize_repo() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    eprintln!("{}", progress_message("Writing repository indexes..."));
+    repo.write_object_indexes()?;
+
+    let indexes = repo.object_indexes();
+    let written = if indexes.multi_pack_index {
+        "commit-graph and multi-pack-index"
+    } else {
+        "commit-graph"
+    };
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Wrote <bold>{written}</>; fetches now keep the commit-graph current"
+        ))
+    );
+    Ok(())
+}
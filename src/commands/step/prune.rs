@@ -9,6 +9,7 @@ use std::time::Duration;
 use anyhow::Context;
 use color_print::cformat;
 use crossbeam_channel as chan;
+use globset::{Glob, GlobMatcher};
 use rayon::prelude::*;
 use worktrunk::HookType;
 use worktrunk::config::{Approvals, ProjectConfig, UserConfig};
@@ -16,13 +17,19 @@ use worktrunk::git::{
     BranchDeletionMode, IntegrationReason, RefSnapshot, Repository, WorktreeInfo,
 };
 use worktrunk::path::format_path_for_display;
+use worktrunk::styling::progress;
 use worktrunk::styling::{
     eprintln, format_with_gutter, hint_message, info_message, println, success_message,
+    suggest_command,
 };
 
+use super::super::branch_gc;
 use super::super::hook_plan::{ApprovedHookPlan, HookPlan, HookPlanBuilder};
 use super::super::hooks::HookAnnouncer;
+use super::super::pin;
+use super::super::remove::{export_branch_stashes, untracked_branch_stashes};
 use super::super::repository_ext::{RemoveTarget, RepositoryCliExt};
+use super::prune_report::{PruneReport, PrunedEntry, SkippedEntry};
 use crate::output::{BackgroundFallbackMode, handle_remove_output};
 
 /// A candidate worktree or branch selected for removal.
@@ -108,13 +115,121 @@ enum CheckSource {
 struct CheckItem {
     integration_ref: String,
     source: CheckSource,
+    /// Age guard for this item, from `--min-age` or a `[step.prune]` rule
+    min_age: MinAge,
 }
 
+/// An age threshold plus its spelling, for "younger than 2d" messages.
+#[derive(Clone)]
+struct MinAge {
+    duration: Duration,
+    label: String,
+}
+
+impl MinAge {
+    fn parse(s: &str) -> Result<Self, humantime::DurationError> {
+        Ok(Self {
+            duration: humantime::parse_duration(s)?,
+            label: s.to_string(),
+        })
+    }
+}
+
+/// One `[step.prune]` rule with its glob compiled.
+struct CompiledRule {
+    matcher: GlobMatcher,
+    keep: bool,
+    min_age: Option<MinAge>,
+}
+
+/// How prune treats each branch: pinned branches (`wt pin`) are kept, then
+/// the `[step.prune]` rules (user config first, then project config) apply,
+/// layered under the `--min-age` flag.
+struct PrunePolicy {
+    pinned: HashSet<String>,
+    rules: Vec<CompiledRule>,
+    /// Explicit `--min-age`, which wins over every rule's `min-age`
+    min_age_flag: Option<MinAge>,
+    default_min_age: MinAge,
+}
+
+impl PrunePolicy {
+    /// Compile the configured rules, failing on a bad glob or duration.
+    fn load(repo: &Repository, config: &UserConfig, min_age: Option<&str>) -> anyhow::Result<Self> {
+        let min_age_flag = min_age
+            .map(MinAge::parse)
+            .transpose()
+            .context("Invalid --min-age duration")?;
+        let project_id = repo.project_identifier().ok();
+        let user_rules = config.step(project_id.as_deref()).prune();
+        let project_config = repo
+            .project_config()
+            .context("Failed to load project config")?;
+        let project_rules = project_config
+            .and_then(ProjectConfig::prune)
+            .map_or(&[][..], |prune| &prune.rules);
+        let rules = user_rules
+            .rules
+            .iter()
+            .chain(project_rules)
+            .map(|rule| {
+                let matcher = Glob::new(&rule.branch)
+                    .with_context(|| {
+                        format!("Invalid [step.prune] branch pattern {}", rule.branch)
+                    })?
+                    .compile_matcher();
+                let min_age = rule
+                    .min_age
+                    .as_deref()
+                    .map(MinAge::parse)
+                    .transpose()
+                    .with_context(|| {
+                        format!("Invalid min-age in [step.prune] rule for {}", rule.branch)
+                    })?;
+                anyhow::Ok(CompiledRule {
+                    matcher,
+                    keep: rule.keep,
+                    min_age,
+                })
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self {
+            pinned: pin::pinned_branches(repo)?,
+            rules,
+            min_age_flag,
+            default_min_age: MinAge::parse(DEFAULT_MIN_AGE).expect("valid default min-age"),
+        })
+    }
+
+    /// The age guard for `branch`, or `None` when a pin or `keep` rule
+    /// protects it. Detached worktrees (no branch) match no rule.
+    fn min_age_for(&self, branch: Option<&str>) -> Option<MinAge> {
+        if branch.is_some_and(|b| self.pinned.contains(b)) {
+            return None;
+        }
+        let rule = branch.and_then(|b| self.rules.iter().find(|r| r.matcher.is_match(b)));
+        if rule.is_some_and(|r| r.keep) {
+            return None;
+        }
+        let min_age = self
+            .min_age_flag
+            .as_ref()
+            .or_else(|| rule.and_then(|r| r.min_age.as_ref()))
+            .unwrap_or(&self.default_min_age);
+        Some(min_age.clone())
+    }
+}
+
+/// Age guard when neither `--min-age` nor a `[step.prune]` rule sets one.
+const DEFAULT_MIN_AGE: &str = "1d";
+
 /// Per-candidate context displayed only in dry-run output.
 struct DryRunInfo {
+    reason: IntegrationReason,
     reason_desc: String,
     effective_target: String,
     suffix: &'static str,
+    upstream_gone: bool,
 }
 
 /// Build a human-readable count like "3 worktrees & branches".
@@ -259,9 +374,13 @@ struct CheckOutcome {
     /// Dirty, locked, and primary worktrees end up `false` and are filtered
     /// silently, never reported as "younger than" or processed downstream.
     removable: bool,
-    /// `Some(_)` if `min_age` is set and the age could be resolved; the
-    /// caller compares against `min_age_duration` to decide on the skip.
+    /// `Some(_)` if the item's min-age is set and the age could be resolved;
+    /// the caller compares against it to decide on the skip.
     age: Option<Duration>,
+    /// The branch's upstream was deleted from the remote. Integrated and gone
+    /// is the strongest signal a branch is done — it was pushed, merged, and
+    /// cleaned up — so the min-age guard doesn't apply.
+    upstream_gone: bool,
 }
 
 /// One check item's full parallel work: integration + removability + age.
@@ -275,7 +394,6 @@ fn check_one(
     integration_target: &str,
     config: &UserConfig,
     worktrees: &[WorktreeInfo],
-    min_age_duration: Duration,
     now_secs: u64,
 ) -> anyhow::Result<CheckOutcome> {
     let (effective_target, reason) =
@@ -286,8 +404,19 @@ fn check_one(
             reason,
             removable: false,
             age: None,
+            upstream_gone: false,
         });
     }
+    let branch = match &item.source {
+        CheckSource::Linked { wt_idx } | CheckSource::Prunable { wt_idx } => {
+            worktrees[*wt_idx].branch.as_deref()
+        }
+        CheckSource::Orphan => Some(item.integration_ref.as_str()),
+    };
+    let upstream_gone = match branch {
+        Some(branch) => repo.branch(branch).gone_upstream()?.is_some(),
+        None => false,
+    };
     let removable = match &item.source {
         CheckSource::Prunable { .. } | CheckSource::Orphan => true,
         CheckSource::Linked { wt_idx } => {
@@ -308,7 +437,7 @@ fn check_one(
             .is_ok()
         }
     };
-    let age = if min_age_duration > Duration::ZERO {
+    let age = if item.min_age.duration > Duration::ZERO && !upstream_gone {
         match &item.source {
             CheckSource::Linked { wt_idx } => worktree_age(repo, &worktrees[*wt_idx], now_secs)?,
             CheckSource::Orphan => orphan_branch_age(repo, &item.integration_ref, now_secs),
@@ -322,6 +451,7 @@ fn check_one(
         reason,
         removable,
         age,
+        upstream_gone,
     })
 }
 
@@ -392,11 +522,13 @@ fn candidate_fields(
 /// candidates whose integration status needs checking.
 ///
 /// Returns the items in a deterministic order: worktree entries first
-/// (preserving `worktrees` order), then orphan branches.
+/// (preserving `worktrees` order), then orphan branches. Pinned branches and
+/// those a `[step.prune]` rule keeps are left out.
 fn gather_check_items(
     repo: &Repository,
     worktrees: &[WorktreeInfo],
     default_branch: Option<&str>,
+    policy: &PrunePolicy,
 ) -> anyhow::Result<Vec<CheckItem>> {
     let mut check_items: Vec<CheckItem> = Vec::new();
     // Track branches seen via worktree entries so we don't double-count
@@ -428,10 +560,14 @@ fn gather_check_items(
         }
 
         if wt.is_prunable() {
+            let Some(min_age) = policy.min_age_for(wt.branch.as_deref()) else {
+                continue;
+            };
             let integration_ref = wt.branch.clone().unwrap_or_else(|| wt.head.clone());
             check_items.push(CheckItem {
                 integration_ref,
                 source: CheckSource::Prunable { wt_idx: idx },
+                min_age,
             });
             continue;
         }
@@ -446,14 +582,16 @@ fn gather_check_items(
             continue;
         }
 
-        let integration_ref = match &wt.branch {
-            Some(b) if !wt.detached => b.clone(),
-            _ => wt.head.clone(),
+        let branch = wt.branch.as_deref().filter(|_| !wt.detached);
+        let Some(min_age) = policy.min_age_for(branch) else {
+            continue;
         };
+        let integration_ref = branch.map_or_else(|| wt.head.clone(), str::to_string);
 
         check_items.push(CheckItem {
             integration_ref,
             source: CheckSource::Linked { wt_idx: idx },
+            min_age,
         });
     }
 
@@ -464,9 +602,13 @@ fn gather_check_items(
         if default_branch == Some(branch.as_str()) {
             continue;
         }
+        let Some(min_age) = policy.min_age_for(Some(&branch)) else {
+            continue;
+        };
         check_items.push(CheckItem {
             integration_ref: branch,
             source: CheckSource::Orphan,
+            min_age,
         });
     }
 
@@ -520,10 +662,11 @@ fn orphan_branch_age(repo: &Repository, branch: &str, now_secs: u64) -> Option<D
 
 /// Render dry-run output (text or JSON) and the `Skipped (younger than ...)`
 /// trailer. Returns once printing is complete; the caller exits early.
+///
+/// `skipped_young` pairs each skipped label with the min-age it fell under.
 fn render_dry_run(
     mut dry_run_info: Vec<(Candidate, DryRunInfo)>,
-    mut skipped_young: Vec<String>,
-    min_age: &str,
+    mut skipped_young: Vec<(String, String)>,
     format: crate::cli::SwitchFormat,
 ) -> anyhow::Result<()> {
     // Sort by original check order for deterministic output regardless of
@@ -540,6 +683,7 @@ fn render_dry_run(
                     "kind": c.kind.as_str(),
                     "reason": info.reason_desc,
                     "target": info.effective_target,
+                    "upstream_gone": info.upstream_gone,
                 })
             })
             .collect();
@@ -553,10 +697,15 @@ fn render_dry_run(
     // stay on stderr. See /writing-user-outputs.
     let mut dry_candidates = Vec::new();
     for (candidate, info) in dry_run_info {
+        let gone = if info.upstream_gone {
+            ", upstream gone"
+        } else {
+            ""
+        };
         println!(
             "{}",
             info_message(cformat!(
-                "<bold>{}</>{} — {} {}",
+                "<bold>{}</>{} — {} {}{gone}",
                 candidate.label,
                 info.suffix,
                 info.reason_desc,
@@ -566,15 +715,18 @@ fn render_dry_run(
         dry_candidates.push(candidate);
     }
 
-    // Report skipped worktrees (after candidates, before summary).
-    // Sort for deterministic output regardless of channel completion order.
-    skipped_young.sort();
-    if !skipped_young.is_empty() {
-        let names = skipped_young
+    // Report skipped worktrees (after candidates, before summary), one line
+    // per min-age. Sort for deterministic output regardless of channel
+    // completion order.
+    skipped_young
+        .sort_by(|(a_label, a_age), (b_label, b_age)| (a_age, a_label).cmp(&(b_age, b_label)));
+    for group in skipped_young.chunk_by(|(_, a), (_, b)| a == b) {
+        let names = group
             .iter()
-            .map(|n| cformat!("<bold>{n}</>"))
+            .map(|(n, _)| cformat!("<bold>{n}</>"))
             .collect::<Vec<_>>()
             .join(", ");
+        let min_age = &group[0].1;
         eprintln!(
             "{}",
             info_message(format!("Skipped {names} (younger than {min_age})"))
@@ -662,20 +814,24 @@ fn unapproved_for_hook(
 /// Handles four cases: live worktrees with branches (removed + branch deleted),
 /// detached HEAD worktrees (directory removed, no branch to delete), stale worktree
 /// entries (pruned + branch deleted), and orphan branches without worktrees (deleted).
-/// Skips the main/primary worktree, locked worktrees, and worktrees younger than
-/// `min_age`. Removes the current worktree last to trigger cd to primary.
+/// Skips the main/primary worktree, locked worktrees, branches kept by a
+/// `[step.prune]` rule, and worktrees younger than their min-age (`min_age`,
+/// else the matching rule's, else 1d). Removes the current worktree last to
+/// trigger cd to primary.
 pub fn step_prune(
     dry_run: bool,
     yes: bool,
-    min_age: &str,
+    min_age: Option<&str>,
     foreground: bool,
     format: crate::cli::SwitchFormat,
+    report: Option<&Path>,
 ) -> anyhow::Result<()> {
-    let min_age_duration =
-        humantime::parse_duration(min_age).context("Invalid --min-age duration")?;
-
+    if report == Some(Path::new("-")) && format == crate::cli::SwitchFormat::Json {
+        anyhow::bail!("--report=- and --format=json both write to stdout; pick one");
+    }
     let repo = Repository::current()?;
     let config = UserConfig::load()?;
+    let policy = PrunePolicy::load(&repo, &config, min_age)?;
 
     // Capture once at command entry. Reused for every per-branch
     // `integration_reason` probe later in this function.
@@ -702,9 +858,10 @@ pub fn step_prune(
     // Broad set of things that might be prunable. The parallel pass below
     // narrows this down via integration + removability + age, leaving the
     // exact worktrees prune will attempt to remove for the hook approval gate.
-    let check_items = gather_check_items(&repo, worktrees, default_branch.as_deref())?;
+    let check_items = gather_check_items(&repo, worktrees, default_branch.as_deref(), &policy)?;
 
-    let mut skipped_young: Vec<String> = Vec::new();
+    let mut skipped_young: Vec<(String, String)> = Vec::new();
+    let mut prune_report = report.map(|_| PruneReport::new(dry_run, &integration_target));
 
     // Streaming dry-run path: scans run in parallel, results are collected and
     // sorted for deterministic output. No removals, no approval — just print.
@@ -736,7 +893,6 @@ pub fn step_prune(
                                 integration_target_ref,
                                 config_ref,
                                 worktrees,
-                                min_age_duration,
                                 now_secs,
                             )
                         };
@@ -745,8 +901,17 @@ pub fn step_prune(
             });
 
             let mut info = Vec::new();
+            let mut checked = 0;
             for (idx, outcome) in &rx {
                 let outcome = outcome.context("checking branch integration")?;
+                checked += 1;
+                progress::items(
+                    "prune",
+                    "check",
+                    checked,
+                    check_items.len(),
+                    &check_items[idx].integration_ref,
+                );
                 let Some(reason) = outcome.reason else {
                     continue;
                 };
@@ -757,9 +922,9 @@ pub fn step_prune(
                 let (label, branch, path, kind, suffix) =
                     candidate_fields(item, &repo, worktrees, &current_root);
                 if let Some(age) = outcome.age
-                    && age < min_age_duration
+                    && age < item.min_age.duration
                 {
-                    skipped_young.push(label);
+                    skipped_young.push((label, item.min_age.label.clone()));
                     continue;
                 }
                 info.push((
@@ -771,16 +936,42 @@ pub fn step_prune(
                         kind,
                     },
                     DryRunInfo {
+                        reason,
                         reason_desc: reason.description().to_string(),
                         effective_target: outcome.effective_target,
                         suffix,
+                        upstream_gone: outcome.upstream_gone,
                     },
                 ));
             }
             anyhow::Ok(info)
         })?;
         dry_run_info.sort_by_key(|(c, _)| c.check_idx);
-        return render_dry_run(dry_run_info, skipped_young, min_age, format);
+        if let (Some(prune_report), Some(dest)) = (prune_report.as_mut(), report) {
+            for (candidate, info) in &dry_run_info {
+                prune_report.pruned.push(pruned_entry(
+                    &repo,
+                    &snapshot,
+                    &check_items[candidate.check_idx],
+                    candidate,
+                    info.reason,
+                    &info.effective_target,
+                    info.upstream_gone,
+                ));
+            }
+            prune_report
+                .skipped
+                .extend(skipped_young.iter().map(|(label, min_age)| SkippedEntry {
+                    label: label.clone(),
+                    reason: format!("younger than {min_age}"),
+                }));
+            write_report(prune_report, dest)?;
+            // The report takes stdout's place for the preview.
+            if dest == Path::new("-") {
+                return Ok(());
+            }
+        }
+        return render_dry_run(dry_run_info, skipped_young, format);
     }
 
     // Live path: prune NEVER prompts for hook approval inline. Streaming
@@ -863,6 +1054,8 @@ pub fn step_prune(
         .flatten()
         .and_then(|p| std::fs::read(p).ok());
     let mut skipped_approval: Vec<SkippedApproval> = Vec::new();
+    // Branches skipped because stashes were made on them
+    let mut skipped_stashed: Vec<String> = Vec::new();
 
     let check_lock = RwLock::new(());
     let removal_ctx = RemovalContext {
@@ -879,8 +1072,8 @@ pub fn step_prune(
     // each result as it arrives — print "Skipped (younger than X)" or call
     // `try_remove` immediately for positives. The current worktree is the one
     // exception: its removal cd's to the primary, so defer it until last.
-    let (removed, deferred_current) =
-        std::thread::scope(|s| -> anyhow::Result<(Vec<Candidate>, Option<Candidate>)> {
+    let (removed, deferred_current) = std::thread::scope(
+        |s| -> anyhow::Result<(Vec<Candidate>, Option<DeferredCurrent>)> {
             let (tx, rx) = chan::unbounded::<(usize, anyhow::Result<CheckOutcome>)>();
             // Pre-shadow with references so `move` on s.spawn moves only `tx`
             // (so it's dropped when the spawn ends and `rx` can terminate),
@@ -906,7 +1099,6 @@ pub fn step_prune(
                                 integration_target_ref,
                                 config_ref,
                                 worktrees,
-                                min_age_duration,
                                 now_secs,
                             )
                         };
@@ -915,10 +1107,19 @@ pub fn step_prune(
             });
 
             let mut removed: Vec<Candidate> = Vec::new();
-            let mut deferred_current: Option<Candidate> = None;
+            let mut deferred_current: Option<DeferredCurrent> = None;
+            let mut checked = 0;
             for (idx, outcome) in &rx {
                 let outcome = outcome.context("checking branch integration")?;
-                let Some(_reason) = outcome.reason else {
+                checked += 1;
+                progress::items(
+                    "prune",
+                    "check",
+                    checked,
+                    check_items.len(),
+                    &check_items[idx].integration_ref,
+                );
+                let Some(reason) = outcome.reason else {
                     continue;
                 };
                 if !outcome.removable {
@@ -928,15 +1129,22 @@ pub fn step_prune(
                 let (label, branch, path, kind, _suffix) =
                     candidate_fields(item, &repo, worktrees, &current_root);
                 if let Some(age) = outcome.age
-                    && age < min_age_duration
+                    && age < item.min_age.duration
                 {
+                    let min_age = &item.min_age.label;
                     eprintln!(
                         "{}",
                         info_message(cformat!(
                             "Skipped <bold>{label}</> (younger than {min_age})"
                         ))
                     );
-                    skipped_young.push(label);
+                    if let Some(prune_report) = prune_report.as_mut() {
+                        prune_report.skipped.push(SkippedEntry {
+                            label: label.clone(),
+                            reason: format!("younger than {min_age}"),
+                        });
+                    }
+                    skipped_young.push((label, min_age.clone()));
                     continue;
                 }
                 let needs_approval = match kind {
@@ -956,6 +1164,12 @@ pub fn step_prune(
                         "{}",
                         info_message(cformat!("Skipped <bold>{label}</> (approval required)"))
                     );
+                    if let Some(prune_report) = prune_report.as_mut() {
+                        prune_report.skipped.push(SkippedEntry {
+                            label: label.clone(),
+                            reason: "approval required".to_string(),
+                        });
+                    }
                     let differs = path.as_deref().is_some_and(|wt_path| {
                         let candidate_bytes =
                             std::fs::read(wt_path.join(".config").join("wt.toml")).ok();
@@ -964,6 +1178,34 @@ pub fn step_prune(
                     skipped_approval.push(SkippedApproval { path, differs });
                     continue;
                 }
+                // Deleting the branch would strand its stashes: skip it unless
+                // `--yes`, which exports them as patches first.
+                if let Some(branch) = branch.as_deref()
+                    && !untracked_branch_stashes(&repo, branch).is_empty()
+                {
+                    if !yes {
+                        eprintln!(
+                            "{}",
+                            info_message(cformat!("Skipped <bold>{label}</> (has stashes)"))
+                        );
+                        if let Some(prune_report) = prune_report.as_mut() {
+                            prune_report.skipped.push(SkippedEntry {
+                                label: label.clone(),
+                                reason: "has stashes".to_string(),
+                            });
+                        }
+                        skipped_stashed.push(branch.to_string());
+                        continue;
+                    }
+                    let dir = export_branch_stashes(&repo, branch)?;
+                    eprintln!(
+                        "{}",
+                        info_message(cformat!(
+                            "Exported stashes of <bold>{branch}</> to <bold>{}</>",
+                            format_path_for_display(&dir)
+                        ))
+                    );
+                }
                 let candidate = Candidate {
                     check_idx: idx,
                     label,
@@ -971,25 +1213,57 @@ pub fn step_prune(
                     path,
                     kind,
                 };
+                // Measured before removal: a background removal may still be
+                // deleting files when the report is written.
+                let entry = prune_report.is_some().then(|| {
+                    pruned_entry(
+                        &repo,
+                        &snapshot,
+                        item,
+                        &candidate,
+                        reason,
+                        &outcome.effective_target,
+                        outcome.upstream_gone,
+                    )
+                });
                 if matches!(candidate.kind, CandidateKind::Current) {
-                    deferred_current = Some(candidate);
+                    deferred_current = Some((candidate, entry));
                 } else if try_remove(&candidate, &removal_ctx)
                     .with_context(|| candidate.removal_context())?
                 {
+                    if let (Some(prune_report), Some(entry)) = (prune_report.as_mut(), entry) {
+                        prune_report.pruned.push(entry);
+                    }
                     removed.push(candidate);
                 }
             }
             Ok((removed, deferred_current))
-        })?;
+        },
+    )?;
 
     let mut removed = removed;
     // Remove deferred current worktree last (cd-to-primary happens here)
-    if let Some(current) = deferred_current
+    if let Some((current, entry)) = deferred_current
         && try_remove(&current, &removal_ctx).with_context(|| current.removal_context())?
     {
+        if let (Some(prune_report), Some(entry)) = (prune_report.as_mut(), entry) {
+            prune_report.pruned.push(entry);
+        }
         removed.push(current);
     }
 
+    // Branches deleted outside wt (or by a detached removal's shell tail)
+    // leave state and cache entries behind; sweep them while we're here.
+    // Logs wait for `wt config state gc` — background removals of this run
+    // are still writing theirs.
+    let swept = match branch_gc::sweep_deleted_branches(&repo, false) {
+        Ok(swept) => swept,
+        Err(e) => {
+            tracing::debug!("Failed to sweep data of deleted branches: {e:#}");
+            Default::default()
+        }
+    };
+
     if format == crate::cli::SwitchFormat::Json {
         let items: Vec<serde_json::Value> = removed
             .iter()
@@ -1003,7 +1277,7 @@ pub fn step_prune(
             .collect();
         println!("{}", serde_json::to_string_pretty(&items)?);
     } else if removed.is_empty() {
-        if skipped_young.is_empty() && skipped_approval.is_empty() {
+        if skipped_young.is_empty() && skipped_approval.is_empty() && skipped_stashed.is_empty() {
             eprintln!("{}", info_message("No merged worktrees to remove"));
         }
     } else {
@@ -1012,6 +1286,37 @@ pub fn step_prune(
             success_message(format!("Pruned {}", prune_summary(&removed)))
         );
     }
+    if format != crate::cli::SwitchFormat::Json && !swept.branches.is_empty() {
+        let count = swept.branches.len();
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Cleared stored data of <bold>{count}</> deleted branch{}",
+                if count == 1 { "" } else { "es" }
+            ))
+        );
+    }
+
+    if let (Some(prune_report), Some(dest)) = (prune_report.as_mut(), report) {
+        write_report(prune_report, dest)?;
+    }
+
+    if !skipped_stashed.is_empty() {
+        let remove_cmd = suggest_command(
+            "remove",
+            &skipped_stashed
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+            &[],
+        );
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To export their stashes and remove them, run <underline>{remove_cmd}</>, or prune with <underline>--yes</>"
+            ))
+        );
+    }
 
     if !skipped_approval.is_empty() {
         for block in approval_hint_blocks(
@@ -1028,6 +1333,59 @@ pub fn step_prune(
     Ok(())
 }
 
+/// The current worktree's candidate, held back to remove last, with its
+/// `--report` entry.
+type DeferredCurrent = (Candidate, Option<PrunedEntry>);
+
+/// Report entry for a candidate about to be removed. Size counts only the
+/// checked-out files of an existing worktree.
+fn pruned_entry(
+    repo: &Repository,
+    snapshot: &RefSnapshot,
+    item: &CheckItem,
+    candidate: &Candidate,
+    reason: IntegrationReason,
+    target: &str,
+    upstream_gone: bool,
+) -> PrunedEntry {
+    let commit = snapshot
+        .resolve(&item.integration_ref)
+        .unwrap_or(&item.integration_ref)
+        .to_string();
+    let bytes = match (candidate.kind, &candidate.path) {
+        (CandidateKind::Current | CandidateKind::Other, Some(path)) => {
+            repo.worktree_at(path).disk_usage().unwrap_or(0)
+        }
+        _ => 0,
+    };
+    PrunedEntry {
+        branch: candidate.branch.clone(),
+        path: candidate.path.clone(),
+        kind: candidate.kind.as_str(),
+        commit,
+        reason,
+        target: target.to_string(),
+        upstream_gone,
+        bytes,
+    }
+}
+
+/// Write the `--report` output to `dest`, or to stdout for `-`.
+fn write_report(report: &mut PruneReport, dest: &Path) -> anyhow::Result<()> {
+    let rendered = report.render(dest)?;
+    if dest == Path::new("-") {
+        println!("{}", rendered.trim_end());
+    } else {
+        fs::write(dest, rendered).with_context(|| {
+            format!(
+                "Failed to write report to {}",
+                format_path_for_display(dest)
+            )
+        })?;
+    }
+    Ok(())
+}
+
 /// One headline+gutter pair for the `(approval required)` end-of-run hint.
 struct ApprovalHintBlock {
     headline: String,
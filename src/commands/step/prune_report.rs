@@ -0,0 +1,194 @@
+//! `wt step prune --report` — a record of what prune removed (or would
+//! remove), for cleanup automation summaries and audits.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use worktrunk::git::IntegrationReason;
+use worktrunk::progress::format_bytes;
+
+/// Everything one prune run removed and skipped.
+#[derive(Serialize)]
+pub(super) struct PruneReport {
+    /// When the run started, ISO 8601 UTC
+    timestamp: String,
+    dry_run: bool,
+    /// Branch candidates were checked against
+    target: String,
+    pub(super) pruned: Vec<PrunedEntry>,
+    pub(super) skipped: Vec<SkippedEntry>,
+    /// Sum of `pruned[].bytes`
+    bytes: u64,
+}
+
+#[derive(Serialize)]
+pub(super) struct PrunedEntry {
+    pub(super) branch: Option<String>,
+    pub(super) path: Option<PathBuf>,
+    pub(super) kind: &'static str,
+    /// Commit the branch (or detached worktree) pointed at
+    pub(super) commit: String,
+    pub(super) reason: IntegrationReason,
+    /// Ref the integration check matched (`main` or its upstream)
+    pub(super) target: String,
+    /// The branch's upstream was deleted from the remote
+    pub(super) upstream_gone: bool,
+    /// Worktree files removed; 0 for branch-only and stale entries
+    pub(super) bytes: u64,
+}
+
+#[derive(Serialize)]
+pub(super) struct SkippedEntry {
+    /// Branch name, or `(detached <sha>)`
+    pub(super) label: String,
+    /// `younger than <age>` or `approval required`
+    pub(super) reason: String,
+}
+
+impl PruneReport {
+    pub(super) fn new(dry_run: bool, target: &str) -> Self {
+        Self {
+            timestamp: worktrunk::utils::now_iso8601(),
+            dry_run,
+            target: target.to_string(),
+            pruned: Vec::new(),
+            skipped: Vec::new(),
+            bytes: 0,
+        }
+    }
+
+    /// Render for `dest`: Markdown when it ends in `.md`, JSON otherwise
+    /// (including `-` for stdout). Entries are sorted by branch, since
+    /// removals finish in scan-completion order.
+    pub(super) fn render(&mut self, dest: &Path) -> anyhow::Result<String> {
+        self.pruned
+            .sort_by(|a, b| (&a.branch, &a.path).cmp(&(&b.branch, &b.path)));
+        self.skipped.sort_by(|a, b| a.label.cmp(&b.label));
+        self.bytes = self.pruned.iter().map(|e| e.bytes).sum();
+        if dest.extension().is_some_and(|ext| ext == "md") {
+            Ok(self.to_markdown())
+        } else {
+            Ok(serde_json::to_string_pretty(self)? + "\n")
+        }
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut out = String::from("# wt step prune\n\n");
+        let mode = if self.dry_run { " (dry run)" } else { "" };
+        out.push_str(&format!(
+            "{}{mode} · checked against `{}`\n\n",
+            self.timestamp, self.target
+        ));
+
+        if self.pruned.is_empty() {
+            out.push_str("Nothing pruned.\n");
+        } else {
+            out.push_str("| Branch | Kind | Commit | Reason | Size |\n");
+            out.push_str("|---|---|---|---|---|\n");
+            for entry in &self.pruned {
+                let branch = entry.branch.as_deref().unwrap_or("(detached)");
+                let commit: String = entry.commit.chars().take(7).collect();
+                let gone = if entry.upstream_gone {
+                    ", upstream gone"
+                } else {
+                    ""
+                };
+                out.push_str(&format!(
+                    "| `{branch}` | {} | `{commit}` | {} {}{gone} | {} |\n",
+                    entry.kind,
+                    entry.reason.description(),
+                    entry.target,
+                    format_bytes(entry.bytes)
+                ));
+            }
+            let verb = if self.dry_run {
+                "Would reclaim"
+            } else {
+                "Reclaimed"
+            };
+            out.push_str(&format!("\n{verb} {}.\n", format_bytes(self.bytes)));
+        }
+
+        if !self.skipped.is_empty() {
+            out.push_str("\n## Skipped\n\n");
+            for entry in &self.skipped {
+                out.push_str(&format!("- `{}` — {}\n", entry.label, entry.reason));
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_snapshot;
+
+    fn report(dry_run: bool) -> PruneReport {
+        PruneReport {
+            timestamp: "2025-01-01T00:00:00Z".into(),
+            dry_run,
+            target: "main".into(),
+            pruned: vec![
+                PrunedEntry {
+                    branch: Some("feature".into()),
+                    path: Some("/repo.feature".into()),
+                    kind: "worktree",
+                    commit: "0123456789abcdef".into(),
+                    reason: IntegrationReason::SameCommit,
+                    target: "main".into(),
+                    upstream_gone: false,
+                    bytes: 2048,
+                },
+                PrunedEntry {
+                    branch: Some("old".into()),
+                    path: None,
+                    kind: "branch_only",
+                    commit: "fedcba9876543210".into(),
+                    reason: IntegrationReason::Ancestor,
+                    target: "origin/main".into(),
+                    upstream_gone: true,
+                    bytes: 0,
+                },
+            ],
+            skipped: vec![SkippedEntry {
+                label: "fresh".into(),
+                reason: "younger than 1d".into(),
+            }],
+            bytes: 0,
+        }
+    }
+
+    #[test]
+    fn renders_markdown_for_md_files() {
+        let rendered = report(false).render(Path::new("prune.md")).unwrap();
+        assert_snapshot!(rendered, @"
+        # wt step prune
+
+        2025-01-01T00:00:00Z · checked against `main`
+
+        | Branch | Kind | Commit | Reason | Size |
+        |---|---|---|---|---|
+        | `feature` | worktree | `0123456` | same commit as main | 2.0 KiB |
+        | `old` | branch_only | `fedcba9` | ancestor of origin/main, upstream gone | 0 B |
+
+        Reclaimed 2.0 KiB.
+
+        ## Skipped
+
+        - `fresh` — younger than 1d
+        ");
+    }
+
+    #[test]
+    fn renders_json_otherwise() {
+        let rendered = report(true).render(Path::new("-")).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(json["dry_run"], true);
+        assert_eq!(json["bytes"], 2048);
+        assert_eq!(json["pruned"][0]["reason"], "same-commit");
+        assert_eq!(json["pruned"][1]["path"], serde_json::Value::Null);
+        assert_eq!(json["pruned"][1]["upstream_gone"], true);
+        assert_eq!(json["skipped"][0]["label"], "fresh");
+    }
+}
@@ -0,0 +1,55 @@
+//! `wt step push` — fast-forward the target branch to the current branch.
+
+use worktrunk::HookType;
+
+use super::super::command_approval::approve_or_skip;
+use super::super::command_executor::FailureStrategy;
+use super::super::context::CommandEnv;
+use super::super::hooks::execute_hook;
+use super::super::template_vars::TemplateVars;
+use super::super::worktree::{PrePushGate, PushKind, PushResult, handle_no_ff_merge, handle_push};
+
+/// Handle `wt step push` command
+///
+/// `pre-push` hooks are approved up front and run once the commit range is
+/// known, right before the target branch moves. `verify` is false for
+/// `--no-hooks`.
+pub fn step_push(
+    target: Option<&str>,
+    no_ff: bool,
+    yes: bool,
+    verify: bool,
+) -> anyhow::Result<PushResult> {
+    let env = CommandEnv::for_action_branchless()?;
+    let ctx = env.context(yes);
+
+    let approved = verify
+        && approve_or_skip(
+            &ctx,
+            &[HookType::PrePush],
+            "Commands declined, pushing without hooks",
+        )?;
+
+    let pre_push = |target_commit: &str, commit: &str| {
+        let target_branch = ctx.repo.require_target_branch(target)?;
+        let mut vars = TemplateVars::new().with_target(&target_branch);
+        if let Some(p) = ctx.repo.worktree_for_branch(&target_branch)? {
+            vars = vars.with_target_worktree_path(&p);
+        }
+        let vars = vars.with_push_range(target_commit, commit);
+        execute_hook(
+            &ctx,
+            HookType::PrePush,
+            &vars.as_extra_vars(),
+            FailureStrategy::FailFast,
+        )
+    };
+    let pre_push: Option<PrePushGate<'_>> = approved.then_some(&pre_push);
+
+    if no_ff {
+        let current_branch = env.require_branch("step push --no-ff")?;
+        handle_no_ff_merge(target, None, current_branch, pre_push)
+    } else {
+        handle_push(target, PushKind::Standalone, None, pre_push)
+    }
+}
@@ -1,22 +1,43 @@
 //! `wt step rebase` — rebase onto target branch (also used by `wt merge`).
+//!
+//! # Stacked branches
+//!
+//! Local branches whose tips sit between the merge base and `HEAD` are
+//! "stacked" under the current branch. A plain rebase rewrites their commits
+//! but leaves the branches pointing at the old ones, silently breaking the
+//! stack. `--update-refs` passes git's own `--update-refs` (git 2.38+) so the
+//! whole stack moves together; without it, a stack is reported as a hint.
 
 use anyhow::Context;
 use color_print::cformat;
 use worktrunk::git::{ErrorExt, Repository};
-use worktrunk::styling::{eprintln, progress_message, success_message};
+use worktrunk::styling::{
+    eprintln, hint_message, info_message, progress_message, success_message, warning_message,
+};
 
 use super::super::repository_ext::RepositoryCliExt;
 
 /// Result of a rebase operation
 pub enum RebaseResult {
     /// Rebase occurred. `fast_forward` distinguishes the two flavors.
-    Rebased { target: String, fast_forward: bool },
+    /// `updated_refs` lists stacked branches moved by `--update-refs`.
+    Rebased {
+        target: String,
+        fast_forward: bool,
+        updated_refs: Vec<String>,
+    },
     /// Already up-to-date with target branch
     UpToDate(String),
 }
 
+/// First git release with `git rebase --update-refs`.
+const UPDATE_REFS_MIN_GIT: (u32, u32) = (2, 38);
+
 /// Handle shared rebase workflow (used by `wt step rebase` and `wt merge`)
-pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
+///
+/// With `update_refs`, stacked branches are rebased along with the current
+/// branch.
+pub fn handle_rebase(target: Option<&str>, update_refs: bool) -> anyhow::Result<RebaseResult> {
     let repo = Repository::current()?;
 
     // Get and validate target ref (any commit-ish for rebase)
@@ -34,6 +55,16 @@ pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
     let head_sha = repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string();
     let is_fast_forward = merge_base == head_sha;
 
+    // Fast-forwards rewrite nothing, so there's no stack to move
+    let stacked = if is_fast_forward {
+        Vec::new()
+    } else {
+        stacked_branches(&repo, &merge_base)?
+    };
+    if update_refs && !stacked.is_empty() {
+        require_update_refs_support(&repo)?;
+    }
+
     // Only show progress for true rebases (fast-forwards are instant)
     if !is_fast_forward {
         eprintln!(
@@ -42,7 +73,12 @@ pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
         );
     }
 
-    let rebase_result = repo.run_command(&["rebase", "--end-of-options", &integration_target]);
+    let mut args = vec!["rebase"];
+    if update_refs && !stacked.is_empty() {
+        args.push("--update-refs");
+    }
+    args.extend(["--end-of-options", integration_target.as_str()]);
+    let rebase_result = repo.run_command(&args);
 
     // If rebase failed, check if it's due to conflicts
     if let Err(e) = rebase_result {
@@ -89,8 +125,131 @@ pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
     };
     eprintln!("{}", success_message(msg));
 
+    let updated_refs = if update_refs {
+        report_updated_refs(&repo, &stacked)?
+    } else {
+        if !stacked.is_empty() {
+            let list = format_branches(stacked.iter().map(|(branch, _)| branch.as_str()));
+            eprintln!(
+                "{}",
+                hint_message(cformat!(
+                    "Stacked branches {list} still point at the old commits; to move them too, run <underline>wt step rebase --update-refs</>"
+                ))
+            );
+        }
+        Vec::new()
+    };
+
     Ok(RebaseResult::Rebased {
         target: integration_target,
         fast_forward: is_fast_forward,
+        updated_refs,
     })
 }
+
+/// Local branches (other than the current one) whose tips are commits that a
+/// rebase onto `merge_base` would rewrite, with their current tips.
+fn stacked_branches(repo: &Repository, merge_base: &str) -> anyhow::Result<Vec<(String, String)>> {
+    let current = repo.current_worktree().branch()?;
+    let output = repo.run_command(&[
+        "for-each-ref",
+        "--format=%(refname:short) %(objectname)",
+        "--merged=HEAD",
+        &format!("--no-merged={merge_base}"),
+        "refs/heads/",
+    ])?;
+    Ok(output
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .filter(|(branch, _)| current.as_deref() != Some(*branch))
+        .map(|(branch, sha)| (branch.to_string(), sha.to_string()))
+        .collect())
+}
+
+/// Fail before rebasing when git is too old for `--update-refs`, rather than
+/// rebasing without it and leaving the stack behind.
+fn require_update_refs_support(repo: &Repository) -> anyhow::Result<()> {
+    let version = repo.run_command(&["--version"])?;
+    match parse_git_version(&version) {
+        Some(found) if found < UPDATE_REFS_MIN_GIT => {
+            let (major, minor) = UPDATE_REFS_MIN_GIT;
+            Err(worktrunk::git::GitError::Other {
+                message: cformat!(
+                    "<bold>--update-refs</> requires git {major}.{minor} or later (found {})",
+                    version.trim().trim_start_matches("git version ")
+                ),
+            }
+            .into())
+        }
+        // Unparseable versions (vendor builds) are left for git to reject
+        _ => Ok(()),
+    }
+}
+
+/// Parse `git version 2.39.5 (Apple Git-154)` into `(2, 39)`.
+fn parse_git_version(output: &str) -> Option<(u32, u32)> {
+    let version = output.trim().strip_prefix("git version ")?;
+    let mut parts = version.split(|c: char| !c.is_ascii_digit());
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Report which stacked branches moved. Git skips branches checked out in
+/// another worktree, so those are warned about instead.
+fn report_updated_refs(
+    repo: &Repository,
+    stacked: &[(String, String)],
+) -> anyhow::Result<Vec<String>> {
+    let mut updated = Vec::new();
+    for (branch, old_sha) in stacked {
+        let new_sha = repo
+            .run_command(&["rev-parse", "--verify", &format!("refs/heads/{branch}")])?
+            .trim()
+            .to_string();
+        if new_sha == *old_sha {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Stacked branch <bold>{branch}</> was not updated (checked out in another worktree?)"
+                ))
+            );
+        } else {
+            updated.push(branch.clone());
+        }
+    }
+    if !updated.is_empty() {
+        let list = format_branches(updated.iter().map(String::as_str));
+        eprintln!(
+            "{}",
+            info_message(format!("Updated stacked branches {list}"))
+        );
+    }
+    Ok(updated)
+}
+
+fn format_branches<'a>(branches: impl Iterator<Item = &'a str>) -> String {
+    branches
+        .map(|branch| cformat!("<bold>{branch}</>"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_git_version() {
+        assert_eq!(parse_git_version("git version 2.39.5\n"), Some((2, 39)));
+        assert_eq!(
+            parse_git_version("git version 2.37.1 (Apple Git-137.1)"),
+            Some((2, 37))
+        );
+        assert_eq!(
+            parse_git_version("git version 2.45.windows.1"),
+            Some((2, 45))
+        );
+        assert_eq!(parse_git_version("not git"), None);
+    }
+}
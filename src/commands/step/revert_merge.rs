@@ -0,0 +1,183 @@
+//! `wt step revert-merge` — back out a branch that `wt merge` landed.
+//!
+//! Reads the [`MergeRecord`] a completed merge leaves behind and reverts the
+//! target's `target_before..target_after` range in the target's worktree.
+//! The revert is a new commit; the target is never reset, so it's safe after
+//! the target has been pushed or built on.
+//!
+//! With `--restore`, the branch is re-created at its pre-merge tip, its
+//! worktree comes back through `wt switch`, and the uncommitted changes from
+//! the merge's backup snapshot are restored as uncommitted changes (the same
+//! `reset --keep` + `reset --mixed` pair `wt merge --abort` uses).
+
+use std::fs;
+
+use anyhow::Context;
+use clap::Parser;
+use color_print::cformat;
+use worktrunk::config::UserConfig;
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{eprintln, info_message, success_message};
+
+use crate::cli::{Cli, Commands};
+use crate::commands::handle_switch_command;
+use crate::commands::merge_journal::MergeRecord;
+use crate::commands::worktree::compute_worktree_path;
+
+/// How the merged range is reverted, by its shape.
+#[derive(Debug, PartialEq, Eq)]
+enum RevertPlan {
+    /// One non-merge commit (a squash merge, or a single fast-forwarded commit).
+    Commit,
+    /// A `--no-ff` merge commit, reverted against its first parent.
+    MergeCommit,
+    /// Several fast-forwarded commits, reverted together as one commit.
+    Range,
+}
+
+/// Handle `wt step revert-merge`.
+pub fn step_revert_merge(branch: Option<&str>, restore: bool, yes: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let record = match branch {
+        Some(branch) => MergeRecord::load(&repo, branch).ok_or_else(|| {
+            anyhow::anyhow!(cformat!("No recorded merge of <bold>{branch}</> to revert"))
+        })?,
+        None => MergeRecord::all(&repo)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("No recorded merges to revert"))?,
+    };
+    let branch = record.branch().to_string();
+    let target = record.target.clone();
+
+    // A rewritten target (reset, force-push) no longer contains the merge.
+    let tip = repo
+        .run_command(&["rev-parse", "--verify", &format!("refs/heads/{target}")])
+        .with_context(|| cformat!("Target branch <bold>{target}</> no longer exists"))?;
+    if !repo.is_ancestor_by_sha(&record.target_after, tip.trim())? {
+        anyhow::bail!(cformat!(
+            "Cannot revert: <bold>{target}</> no longer contains the merge of <bold>{branch}</>"
+        ));
+    }
+
+    let target_path = repo.worktree_for_branch(&target)?.ok_or_else(|| {
+        anyhow::anyhow!(cformat!(
+            "Cannot revert: <bold>{target}</> has no worktree; create one with <bright-black>wt switch {target}</>"
+        ))
+    })?;
+    let target_wt = repo.worktree_at(&target_path);
+    target_wt.ensure_clean("revert merge", Some(&target), false)?;
+
+    let range = format!("{}..{}", record.target_before, record.target_after);
+    let plan = revert_plan(&repo, &record)?;
+    let reverted = match plan {
+        RevertPlan::Commit => target_wt.run_command(&["revert", "--no-edit", &record.target_after]),
+        RevertPlan::MergeCommit => {
+            target_wt.run_command(&["revert", "--no-edit", "-m", "1", &record.target_after])
+        }
+        RevertPlan::Range => target_wt
+            .run_command(&["revert", "--no-commit", &range])
+            .and_then(|_| {
+                target_wt.run_command(&[
+                    "commit",
+                    "-m",
+                    &format!("Revert merge of {branch} into {target}"),
+                ])
+            }),
+    };
+    if let Err(err) = reverted {
+        return Err(err.context(cformat!(
+            "Reverting <bold>{branch}</> stopped in {}; resolve and run <bright-black>git revert --continue</>, or <bright-black>git revert --abort</>",
+            format_path_for_display(&target_path)
+        )));
+    }
+
+    let head = target_wt.run_command(&["rev-parse", "HEAD"])?;
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Reverted merge of <bold>{branch}</> from <bold>{target}</> @ <dim>{}</>",
+            repo.short_sha(head.trim())?
+        ))
+    );
+    let backup = record.backup.clone();
+    let orig_head = record.orig_head.clone();
+    record.delete(&repo)?;
+
+    if restore {
+        restore_branch(&repo, &branch, &orig_head, backup.as_deref(), yes)?;
+    }
+    Ok(())
+}
+
+/// Classify the merged range: one commit, a merge commit on top of the old
+/// target, or several fast-forwarded commits.
+fn revert_plan(repo: &Repository, record: &MergeRecord) -> anyhow::Result<RevertPlan> {
+    let parents = repo.run_command(&["rev-list", "--parents", "-n1", &record.target_after])?;
+    let parents: Vec<&str> = parents.split_whitespace().skip(1).collect();
+    Ok(match parents.as_slice() {
+        [first, _, ..] if *first == record.target_before => RevertPlan::MergeCommit,
+        [only] if *only == record.target_before => RevertPlan::Commit,
+        _ => RevertPlan::Range,
+    })
+}
+
+/// Re-create `branch` at its pre-merge tip, bring back its worktree, and
+/// restore the uncommitted changes from the merge's backup snapshot.
+fn restore_branch(
+    repo: &Repository,
+    branch: &str,
+    orig_head: &str,
+    backup: Option<&str>,
+    yes: bool,
+) -> anyhow::Result<()> {
+    if repo.branch(branch).exists_locally()? {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Branch <bold>{branch}</> still exists; switch to it with <bright-black>wt switch {branch}</>"
+            ))
+        );
+        return Ok(());
+    }
+    repo.run_command(&["branch", branch, orig_head])
+        .context("Failed to re-create branch")?;
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Restored branch <bold>{branch}</> @ <dim>{}</>",
+            repo.short_sha(orig_head)?
+        ))
+    );
+
+    // Removing the merged worktree can leave an empty placeholder at its path
+    // (kept so a shell sitting in it has a valid cwd); clear it so the switch
+    // can create the worktree there again.
+    let path = compute_worktree_path(repo, branch, &UserConfig::load()?)?;
+    if fs::read_dir(&path).is_ok_and(|mut entries| entries.next().is_none()) {
+        let _ = fs::remove_dir(&path);
+    }
+
+    let cli = Cli::try_parse_from(["wt", "switch", branch])
+        .context("failed to build switch invocation")?;
+    let Some(Commands::Switch(args)) = cli.command else {
+        unreachable!("parsed a switch invocation");
+    };
+    handle_switch_command(args, yes)?;
+
+    if let Some(backup) = backup {
+        let wt = repo.worktree_at(&path);
+        wt.run_command(&["reset", "--keep", backup])
+            .context("Failed to restore uncommitted changes from backup")?;
+        wt.run_command(&["reset", "--mixed", "-q", orig_head])
+            .context("Failed to restore uncommitted changes from backup")?;
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Restored uncommitted changes from <bold>refs/wt-backup/{branch}</>"
+            ))
+        );
+    }
+    Ok(())
+}
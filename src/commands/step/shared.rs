@@ -1,6 +1,8 @@
 //! Helpers shared between multiple step subcommands.
 //!
 //! - `print_dry_run` — used by `commit` and `squash` for `--dry-run` output.
+//! - `check_step_not_on_default_branch` — the `--allow-primary` guard for
+//!   `squash` and `rebase`.
 //! - Copy-ignored discovery (`list_and_filter_ignored_entries` and friends) —
 //!   used by `copy_ignored` and `promote`.
 
@@ -9,12 +11,39 @@ use std::path::{Path, PathBuf};
 use anyhow::Context;
 use ignore::gitignore::GitignoreBuilder;
 use worktrunk::config::CopyIgnoredConfig;
-use worktrunk::git::Repository;
+use worktrunk::git::{GitError, Repository};
 use worktrunk::shell_exec::Cmd;
 use worktrunk::styling::{format_bash_with_gutter, format_heading, format_with_gutter};
 
 use super::super::commit::CommitGenerator;
 
+/// Refuse a history-rewriting step (`squash`, `rebase`) on the default branch
+/// unless `--allow-primary` was passed. A soft reset or rebase there rewrites
+/// commits other branches and the remote already share.
+///
+/// Only the standalone step commands check this; `wt merge` runs the same
+/// steps from the feature branch.
+pub(crate) fn check_step_not_on_default_branch(
+    repo: &Repository,
+    step: &str,
+    allow_primary: bool,
+) -> anyhow::Result<()> {
+    if allow_primary {
+        return Ok(());
+    }
+    let Some(branch) = repo.current_worktree().branch()? else {
+        return Ok(());
+    };
+    if repo.default_branch().as_deref() == Some(branch.as_str()) {
+        return Err(GitError::StepOnDefaultBranch {
+            step: step.to_string(),
+            branch,
+        }
+        .into());
+    }
+    Ok(())
+}
+
 /// Print the three dry-run sections: rendered prompt, LLM command, generated message.
 ///
 /// The COMMAND and MESSAGE sections use the same gutter treatment as the regular commit
@@ -157,21 +157,28 @@ pub fn handle_squash(
         }
     }
 
-    // Run pre-commit hooks (user first, then project).
+    // Get merge base with target branch (required for squash)
+    let merge_base = repo
+        .merge_base("HEAD", &integration_target)?
+        .context("Cannot squash: no common ancestor with target branch")?;
+
+    // Run pre-commit hooks (user first, then project). The squash commit
+    // records everything staged since the merge base.
     if hooks.run() {
+        let files = repo
+            .current_worktree()
+            .changed_files(&["--cached", &merge_base])?;
+        let pre_commit_vars = TemplateVars::new()
+            .with_target(&integration_target)
+            .with_changed_files(&files);
         execute_hook(
             &ctx,
             HookType::PreCommit,
-            &template_vars.as_extra_vars(),
+            &pre_commit_vars.as_extra_vars(),
             FailureStrategy::FailFast,
         )?;
     }
 
-    // Get merge base with target branch (required for squash)
-    let merge_base = repo
-        .merge_base("HEAD", &integration_target)?
-        .context("Cannot squash: no common ancestor with target branch")?;
-
     // Count commits since merge base
     let commit_count = repo.count_commits(&merge_base, "HEAD")?;
 
@@ -283,6 +290,11 @@ pub fn handle_squash(
         &resolved.commit_generation,
         project_append.as_deref(),
     )?;
+    let commit_message = append_squashed_trailers(
+        &commit_message,
+        &repo.commit_trailers(&range)?,
+        &resolved.commit.squash_trailers(),
+    );
 
     // Display the generated commit message
     let formatted_message = generator.format_message_for_display(&commit_message);
@@ -361,6 +373,7 @@ fn preview_squash(target: Option<&str>, dry_run: bool, yes: bool) -> anyhow::Res
     let config = UserConfig::load().context("Failed to load config")?;
     let project_id = repo.project_identifier().ok();
     let commit_config = config.commit_generation(project_id.as_deref());
+    let trailer_keys = config.commit(project_id.as_deref()).squash_trailers();
 
     let integration_target = repo.require_target_ref(target)?;
 
@@ -406,5 +419,101 @@ fn preview_squash(target: Option<&str>, dry_run: bool, yes: bool) -> anyhow::Res
         &commit_config,
         project_append.as_deref(),
     )?;
+    let message = append_squashed_trailers(&message, &repo.commit_trailers(&range)?, &trailer_keys);
     print_dry_run(&prompt, &commit_config, &message)
 }
+
+/// Append the squashed commits' trailers whose key is in `keys` (the
+/// `[commit] squash-trailers` allowlist) to `message`, so `Co-authored-by:`
+/// attribution survives the squash.
+///
+/// Keys match case-insensitively, as git's do. A trailer is added once, and
+/// not at all when the message already carries it — an LLM that copied it
+/// from the commit bodies wins.
+fn append_squashed_trailers(message: &str, trailers: &[String], keys: &[String]) -> String {
+    let mut seen: Vec<(String, String)> = message.lines().filter_map(parse_trailer).collect();
+    let mut kept = Vec::new();
+    for trailer in trailers {
+        let Some(parsed) = parse_trailer(trailer) else {
+            continue;
+        };
+        if !keys.iter().any(|key| key.eq_ignore_ascii_case(&parsed.0)) || seen.contains(&parsed) {
+            continue;
+        }
+        seen.push(parsed);
+        kept.push(trailer.trim());
+    }
+    if kept.is_empty() {
+        return message.to_string();
+    }
+    let message = message.trim_end();
+
+    // Join an existing trailer block rather than starting a second one.
+    let ends_in_trailers = message
+        .rsplit_once("\n\n")
+        .is_some_and(|(_, last)| last.lines().all(|line| parse_trailer(line).is_some()));
+    let separator = if ends_in_trailers { "\n" } else { "\n\n" };
+    format!("{message}{separator}{}", kept.join("\n"))
+}
+
+/// `Key: value` → (lowercased key, trimmed value); `None` for lines that
+/// aren't trailers.
+fn parse_trailer(line: &str) -> Option<(String, String)> {
+    let (key, value) = line.split_once(':')?;
+    let key = key.trim();
+    if key.is_empty() || key.contains(char::is_whitespace) || value.trim().is_empty() {
+        return None;
+    }
+    Some((key.to_ascii_lowercase(), value.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::append_squashed_trailers;
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_append_squashed_trailers_dedupes_and_filters() {
+        let trailers = strings(&[
+            "Co-authored-by: Ada <ada@example.com>",
+            "Signed-off-by: Bob <bob@example.com>",
+            "co-authored-by: Ada <ada@example.com>",
+            "Co-authored-by: Eve <eve@example.com>",
+        ]);
+        let message =
+            append_squashed_trailers("feat: thing\n", &trailers, &strings(&["Co-authored-by"]));
+        assert_eq!(
+            message,
+            "feat: thing\n\nCo-authored-by: Ada <ada@example.com>\nCo-authored-by: Eve <eve@example.com>"
+        );
+    }
+
+    #[test]
+    fn test_append_squashed_trailers_joins_existing_block() {
+        let trailers = strings(&[
+            "Co-authored-by: Ada <ada@example.com>",
+            "Co-authored-by: Eve <eve@example.com>",
+        ]);
+        let message = append_squashed_trailers(
+            "feat: thing\n\nBody.\n\nCo-authored-by: Ada <ada@example.com>",
+            &trailers,
+            &strings(&["Co-authored-by"]),
+        );
+        assert_eq!(
+            message,
+            "feat: thing\n\nBody.\n\nCo-authored-by: Ada <ada@example.com>\nCo-authored-by: Eve <eve@example.com>"
+        );
+    }
+
+    #[test]
+    fn test_append_squashed_trailers_empty_allowlist() {
+        let trailers = strings(&["Co-authored-by: Ada <ada@example.com>"]);
+        assert_eq!(
+            append_squashed_trailers("feat: thing", &trailers, &[]),
+            "feat: thing"
+        );
+    }
+}
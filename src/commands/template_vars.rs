@@ -7,7 +7,8 @@
 //! don't repeat that aliasing.
 //!
 //! The struct carries operation-context vars (`base` / `target` directional
-//! pairs and `pr_*`) plus optional Active overrides (`worktree_path`,
+//! pairs, the `pre-push` commit range, the pre-commit/pre-merge file list,
+//! and `pr_*`) plus optional Active overrides (`worktree_path`,
 //! `worktree_name`, `commit`, `short_commit`) for sites whose hooks should
 //! reference an Active identity that differs from the execution worktree —
 //! e.g., post-merge running in the destination but referencing the feature
@@ -15,6 +16,7 @@
 
 use std::path::Path;
 
+use worktrunk::config::CHANGED_FILES_KEY;
 use worktrunk::path::to_posix_path;
 
 use super::worktree::{SwitchBranchInfo, SwitchResult};
@@ -25,6 +27,10 @@ pub(crate) struct TemplateVars {
     base_worktree_path: Option<String>,
     target: Option<String>,
     target_worktree_path: Option<String>,
+    target_commit: Option<String>,
+    commit_range: Option<String>,
+    /// JSON-encoded list behind `changed_files` and `changed_files_json`.
+    changed_files: Option<String>,
     /// Override the bare `worktree_path` (and the deprecated `worktree` alias).
     active_worktree_path: Option<String>,
     /// Override the bare `worktree_name`.
@@ -70,6 +76,24 @@ impl TemplateVars {
         self
     }
 
+    /// Set `target_commit` (the target tip before a push) and `commit_range`
+    /// (`target_commit..commit`, the commits the push adds).
+    pub fn with_push_range(mut self, target_commit: &str, commit: &str) -> Self {
+        self.commit_range = Some(format!("{target_commit}..{commit}"));
+        self.target_commit = Some(target_commit.to_string());
+        self
+    }
+
+    /// Set `changed_files` (see [`CHANGED_FILES_KEY`]) and
+    /// `changed_files_json` to the files a pre-commit or pre-merge hook
+    /// checks.
+    pub fn with_changed_files(mut self, files: &[String]) -> Self {
+        self.changed_files = Some(
+            serde_json::to_string(files).expect("Vec<String> serialization should never fail"),
+        );
+        self
+    }
+
     /// Override the Active worktree identity. Sets `worktree_path` (and the
     /// deprecated `worktree` alias) plus `worktree_name`. Falls back to
     /// `"unknown"` for `worktree_name` when the path has no file name or the
@@ -122,6 +146,16 @@ impl TemplateVars {
         if let Some(v) = &self.target_worktree_path {
             out.push(("target_worktree_path", v));
         }
+        if let Some(v) = &self.target_commit {
+            out.push(("target_commit", v));
+        }
+        if let Some(v) = &self.commit_range {
+            out.push(("commit_range", v));
+        }
+        if let Some(v) = &self.changed_files {
+            out.push((CHANGED_FILES_KEY, v));
+            out.push(("changed_files_json", v));
+        }
         if let Some(v) = &self.active_worktree_path {
             out.push(("worktree_path", v));
             out.push(("worktree", v));
@@ -231,6 +265,23 @@ mod tests {
         assert!(pairs.contains(&("short_commit", "0123456")));
     }
 
+    #[test]
+    fn push_range_spans_target_to_commit() {
+        let vars = TemplateVars::new().with_push_range("aaa111", "bbb222");
+        let pairs = vars.as_extra_vars();
+        assert!(pairs.contains(&("target_commit", "aaa111")));
+        assert!(pairs.contains(&("commit_range", "aaa111..bbb222")));
+    }
+
+    #[test]
+    fn changed_files_encoded_as_json() {
+        let files = vec!["src/a.rs".to_string(), "my file.txt".to_string()];
+        let vars = TemplateVars::new().with_changed_files(&files);
+        let pairs = vars.as_extra_vars();
+        assert!(pairs.contains(&("changed_files", r#"["src/a.rs","my file.txt"]"#)));
+        assert!(pairs.contains(&("changed_files_json", r#"["src/a.rs","my file.txt"]"#)));
+    }
+
     #[test]
     fn pr_pair_independent() {
         let vars = TemplateVars::new().with_pr(Some(42), Some("https://example.test/pr/42"));
@@ -255,6 +306,7 @@ mod tests {
             base_branch: Some("main".to_string()),
             base_worktree_path: Some("/repo".to_string()),
             from_remote: None,
+            upstream: None,
             pr_number: Some(42),
             pr_url: Some("https://example.test/pr/42".to_string()),
         };
@@ -0,0 +1,444 @@
+//! `wt switch --batch`: create several worktrees from one manifest.
+//!
+//! The manifest lists worktrees to create — a branch name (a template over
+//! `{{ n }}` when an entry has a `count`), and an optional base:
+//!
+//! ```toml
+//! [[worktree]]
+//! branch = "attempt-{{ n }}"
+//! count = 3
+//! base = "main"
+//! ```
+//!
+//! `--batch -` reads the same schema as JSON from stdin.
+//!
+//! Creation is all or nothing. Every entry is planned before anything is
+//! created, so a taken branch or path fails the batch without side effects,
+//! and a `git worktree add` that fails midway removes the worktrees and
+//! branches the batch already created. Worktrees are created one at a time —
+//! git serializes on its config and ref locks anyway — and the pre-start hooks,
+//! which dominate the wall time, then run in parallel, at most `--jobs` at
+//! once. A hook failure doesn't undo the batch: the worktree exists, and its
+//! row in the result table says the hooks failed.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Read as _;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::{Context, bail};
+use color_print::cformat;
+use serde::{Deserialize, Serialize};
+use worktrunk::config::{UserConfig, expand_template};
+use worktrunk::git::{Repository, WorktrunkError};
+use worktrunk::shell_exec::ShellEscapeMode;
+use worktrunk::styling::{
+    eprintln, error_message, format_with_gutter, info_message, println, success_message,
+};
+
+use super::switch::{
+    capture_switch_source, execute_switch, plan_switch, spawn_switch_background_hooks,
+    switch_post_hook_types,
+};
+use super::types::{SwitchBranchInfo, SwitchResult};
+use crate::cli::SwitchFormat;
+use crate::commands::command_executor::CommandContext;
+use crate::commands::hook_plan::{ApprovedHookPlan, HookPlanBuilder};
+use crate::commands::template_vars::TemplateVars;
+
+/// Pre-start hooks running at once when `--jobs` isn't given.
+pub const DEFAULT_BATCH_JOBS: usize = 4;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    #[serde(default, rename = "worktree")]
+    worktrees: Vec<ManifestEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ManifestEntry {
+    /// Branch to create; a template over `{{ n }}` (1-based)
+    branch: String,
+    base: Option<String>,
+    /// Create this many worktrees from the entry, `n` = 1..=count
+    count: Option<usize>,
+}
+
+/// Read the manifest at `source`, or JSON from stdin for `-`.
+fn read_manifest(source: &Path) -> anyhow::Result<Manifest> {
+    if source == Path::new("-") {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .context("Failed to read batch manifest from stdin")?;
+        return serde_json::from_str(&content).context("Failed to parse batch manifest JSON");
+    }
+    let display = worktrunk::path::format_path_for_display(source);
+    let content = std::fs::read_to_string(source)
+        .with_context(|| format!("Failed to read batch manifest {display}"))?;
+    if source.extension().is_some_and(|ext| ext == "json") {
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse batch manifest {display}"))
+    } else {
+        toml::from_str(&content)
+            .with_context(|| format!("Failed to parse batch manifest {display}"))
+    }
+}
+
+/// Expand the manifest into `(branch, base)` pairs, in manifest order.
+fn expand_entries(
+    repo: &Repository,
+    manifest: Manifest,
+) -> anyhow::Result<Vec<(String, Option<String>)>> {
+    let mut expanded = Vec::new();
+    let mut seen = HashSet::new();
+    for entry in manifest.worktrees {
+        let count = entry.count.unwrap_or(1);
+        if count == 0 {
+            bail!(cformat!(
+                "Batch entry <bold>{}</> has count = 0",
+                entry.branch
+            ));
+        }
+        for n in 1..=count {
+            let n = n.to_string();
+            let vars = HashMap::from([("n", n.as_str())]);
+            let branch = expand_template(
+                &entry.branch,
+                &vars,
+                ShellEscapeMode::Literal,
+                repo,
+                "batch branch",
+            )?;
+            if !seen.insert(branch.clone()) {
+                let hint = if entry.count.is_some() {
+                    " (use {{ n }} in a counted branch name)"
+                } else {
+                    ""
+                };
+                bail!(cformat!(
+                    "Batch manifest lists branch <bold>{branch}</> more than once{hint}"
+                ));
+            }
+            expanded.push((branch, entry.base.clone()));
+        }
+    }
+    if expanded.is_empty() {
+        bail!("Batch manifest has no [[worktree]] entries");
+    }
+    Ok(expanded)
+}
+
+/// Outcome of a batch entry's pre-start hooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum HookOutcome {
+    Ok,
+    Failed,
+    Skipped,
+}
+
+impl HookOutcome {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+        }
+    }
+}
+
+/// One row of the batch result, also its `--format=json` object.
+#[derive(Serialize)]
+struct BatchRow {
+    branch: String,
+    path: PathBuf,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base_branch: Option<String>,
+    hooks: HookOutcome,
+}
+
+/// A worktree the batch created, with what's needed to run its hooks or
+/// roll it back.
+struct Created {
+    branch: String,
+    result: SwitchResult,
+}
+
+/// Options for `wt switch --batch`.
+pub struct BatchOptions<'a> {
+    pub manifest: &'a Path,
+    pub jobs: usize,
+    pub verify: bool,
+    pub yes: bool,
+    pub format: SwitchFormat,
+}
+
+/// Entry point for `wt switch --batch`.
+pub fn handle_switch_batch(opts: BatchOptions<'_>) -> anyhow::Result<()> {
+    let BatchOptions {
+        manifest,
+        jobs,
+        verify,
+        yes,
+        format,
+    } = opts;
+    let config = UserConfig::load().context("Failed to load config")?;
+    let repo = Repository::current()?;
+    let entries = expand_entries(&repo, read_manifest(manifest)?)?;
+
+    // Plan everything before creating anything: a taken branch or path fails
+    // the whole batch here, with nothing to undo.
+    let mut plans = Vec::with_capacity(entries.len());
+    let mut paths = HashSet::new();
+    for (branch, base) in &entries {
+        let plan = plan_switch(&repo, branch, true, base.as_deref(), false, &config)?;
+        if !paths.insert(plan.worktree_path().to_path_buf()) {
+            let path = worktrunk::path::format_path_for_display(plan.worktree_path());
+            bail!(cformat!(
+                "Two batch entries would create a worktree at <bold>{path}</>"
+            ));
+        }
+        plans.push(plan);
+    }
+
+    // One approval for every worktree's hooks.
+    let (hooks_approved, hook_plan) = if verify {
+        let project_id = repo.project_identifier().ok();
+        let pid = project_id.as_deref();
+        let project_config = repo.load_project_config()?;
+        let mut builder = HookPlanBuilder::new(project_config.as_ref(), &config, pid);
+        for plan in &plans {
+            builder.add(plan.worktree_path(), switch_post_hook_types(true));
+        }
+        match builder.finish().approve(pid, yes)? {
+            Some(approved) => (true, approved),
+            None => {
+                eprintln!(
+                    "{}",
+                    info_message("Commands declined, continuing worktree creation without hooks")
+                );
+                (false, ApprovedHookPlan::empty())
+            }
+        }
+    } else {
+        (false, ApprovedHookPlan::empty())
+    };
+
+    let (source_branch, source_path) = capture_switch_source(&repo, false);
+    let track = repo.config().switch.track;
+
+    // Create sequentially; hooks run afterwards, in parallel.
+    let mut created: Vec<Created> = Vec::with_capacity(plans.len());
+    for plan in plans {
+        match execute_switch(&repo, plan, &config, track, yes, false, &hook_plan) {
+            Ok((result, branch_info)) => created.push(Created {
+                branch: branch_info.branch.unwrap_or_default(),
+                result,
+            }),
+            Err(err) if created.is_empty() => return Err(err),
+            Err(err) => {
+                roll_back(&repo, &created);
+                return Err(err.context(format!(
+                    "Batch stopped; removed the {} worktree{} it had created",
+                    created.len(),
+                    if created.len() == 1 { "" } else { "s" }
+                )));
+            }
+        }
+    }
+
+    let outcomes = if hooks_approved {
+        run_pre_start_hooks(&config, &created, &hook_plan, yes, jobs)
+    } else {
+        vec![HookOutcome::Skipped; created.len()]
+    };
+
+    // Background hooks go last, so they never compete with pre-start hooks
+    // for the `--jobs` budget.
+    if hooks_approved {
+        for (entry, outcome) in created.iter().zip(&outcomes) {
+            if *outcome == HookOutcome::Failed {
+                continue;
+            }
+            let branch_info = SwitchBranchInfo {
+                branch: Some(entry.branch.clone()),
+                expected_path: None,
+            };
+            let template_vars = TemplateVars::for_post_switch(
+                &entry.result,
+                &branch_info,
+                &source_branch,
+                &source_path,
+            );
+            spawn_switch_background_hooks(
+                &config,
+                &entry.result,
+                Some(&entry.branch),
+                yes,
+                &template_vars.as_extra_vars(),
+                Some(entry.result.path()),
+                &hook_plan,
+            )?;
+        }
+    }
+
+    let rows: Vec<BatchRow> = created
+        .into_iter()
+        .zip(outcomes)
+        .map(|(entry, hooks)| {
+            let (path, base_branch) = match entry.result {
+                SwitchResult::Created {
+                    path, base_branch, ..
+                } => (path, base_branch),
+                SwitchResult::Existing { path } | SwitchResult::AlreadyAt(path) => (path, None),
+            };
+            BatchRow {
+                branch: entry.branch,
+                path,
+                base_branch,
+                hooks,
+            }
+        })
+        .collect();
+    let failed = rows
+        .iter()
+        .filter(|row| row.hooks == HookOutcome::Failed)
+        .count();
+
+    if format == SwitchFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+    } else {
+        let table: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                vec![
+                    row.branch.clone(),
+                    row.base_branch.clone().unwrap_or_else(|| "-".to_string()),
+                    worktrunk::path::format_path_for_display(&row.path),
+                    row.hooks.label().to_string(),
+                ]
+            })
+            .collect();
+        let rendered =
+            crate::md_help::render_data_table(&["Branch", "Base", "Path", "Hooks"], &table);
+        println!("{}", rendered.trim_end());
+    }
+
+    let total = rows.len();
+    let plural = if total == 1 { "" } else { "s" };
+    if failed == 0 {
+        eprintln!(
+            "{}",
+            success_message(format!("Created {total} worktree{plural}"))
+        );
+        Ok(())
+    } else {
+        eprintln!(
+            "{}",
+            error_message(format!(
+                "Created {total} worktree{plural}; pre-start hooks failed in {failed}"
+            ))
+        );
+        Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into())
+    }
+}
+
+/// Run each created worktree's pre-start hooks, at most `jobs` at once.
+///
+/// Hook output streams as it would for a single `wt switch --create`; each
+/// announcement names its worktree. Returns outcomes in `created` order.
+fn run_pre_start_hooks(
+    config: &UserConfig,
+    created: &[Created],
+    hook_plan: &ApprovedHookPlan,
+    yes: bool,
+    jobs: usize,
+) -> Vec<HookOutcome> {
+    let next = AtomicUsize::new(0);
+    let outcomes = Mutex::new(vec![HookOutcome::Ok; created.len()]);
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, created.len().max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(entry) = created.get(index) else {
+                        break;
+                    };
+                    if let Err(err) = run_entry_pre_start(config, entry, hook_plan, yes) {
+                        eprintln!(
+                            "{}",
+                            error_message(cformat!(
+                                "Pre-start hooks failed for <bold>{}</>",
+                                entry.branch
+                            ))
+                        );
+                        eprintln!("{}", format_with_gutter(&format!("{err:#}"), None));
+                        outcomes.lock().unwrap()[index] = HookOutcome::Failed;
+                    }
+                }
+            });
+        }
+    });
+    outcomes.into_inner().unwrap()
+}
+
+fn run_entry_pre_start(
+    config: &UserConfig,
+    entry: &Created,
+    hook_plan: &ApprovedHookPlan,
+    yes: bool,
+) -> anyhow::Result<()> {
+    let SwitchResult::Created {
+        path,
+        base_branch,
+        base_worktree_path,
+        ..
+    } = &entry.result
+    else {
+        return Ok(());
+    };
+    let hook_repo = Repository::at(path)?;
+    let ctx = CommandContext::new(&hook_repo, config, Some(&entry.branch), path, yes);
+    let vars = TemplateVars::new()
+        .with_target(&entry.branch)
+        .with_target_worktree_path(path)
+        .with_base_strs(base_branch.as_deref(), base_worktree_path.as_deref());
+    ctx.execute_pre_create_commands(&vars.as_extra_vars(), hook_plan, path)
+}
+
+/// Undo a partial batch: remove each created worktree, and the branch when
+/// the batch created it. Best-effort — a leftover is reported, not fatal.
+fn roll_back(repo: &Repository, created: &[Created]) {
+    for entry in created.iter().rev() {
+        let path = entry.result.path();
+        let path_str = path.to_string_lossy();
+        if let Err(err) = repo.run_command(&["worktree", "remove", "--force", "--", &path_str]) {
+            let display = worktrunk::path::format_path_for_display(path);
+            eprintln!(
+                "{}",
+                error_message(cformat!("Could not remove <bold>{display}</>: {err:#}"))
+            );
+            continue;
+        }
+        if matches!(
+            entry.result,
+            SwitchResult::Created {
+                created_branch: true,
+                ..
+            }
+        ) && let Err(err) = repo.run_command(&["branch", "-D", "--", &entry.branch])
+        {
+            eprintln!(
+                "{}",
+                error_message(cformat!(
+                    "Could not delete branch <bold>{}</>: {err:#}",
+                    entry.branch
+                ))
+            );
+        }
+    }
+}
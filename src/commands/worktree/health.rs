@@ -0,0 +1,161 @@
+//! Repairs for common worktree breakage, applied when switching.
+//!
+//! A worktree can be left unusable by things outside wt's control: its
+//! directory deleted by hand, the repository moved (leaving the `.git` file
+//! pointing at the old location), or a crashed git command leaving
+//! `index.lock` behind. Each surfaces as a raw git error on the next command,
+//! so `wt switch` repairs what it safely can and says what it did.
+//!
+//! Checks on an existing worktree only touch the filesystem; git runs only
+//! when something needs fixing, keeping the switch fast path cheap.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{eprintln, hint_message, info_message, warning_message};
+
+/// An `index.lock` untouched for this long is left over from a crashed git
+/// command, not held by a running one.
+const STALE_LOCK_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Whether the worktree at `path` is left over from a removal: its directory
+/// is gone, or — when `git worktree remove` was interrupted partway — its
+/// `.git` file is.
+pub(super) fn is_stale_worktree(path: &Path) -> bool {
+    !path.join(".git").exists()
+}
+
+/// Prune the entry of a stale worktree (see [`is_stale_worktree`]), so the
+/// worktree can be created again.
+///
+/// Returns `false` when the worktree is locked — git keeps locked entries, and
+/// the lock says the missing directory is expected (e.g. on removable media).
+pub(super) fn prune_stale_worktree(repo: &Repository, path: &Path) -> anyhow::Result<bool> {
+    let locked = repo
+        .list_worktrees()?
+        .iter()
+        .any(|wt| wt.path == path && wt.locked.is_some());
+    if locked {
+        return Ok(false);
+    }
+
+    repo.prune_worktree_entry(path)?;
+    let path_display = format_path_for_display(path);
+    let message = if path.exists() {
+        cformat!("Worktree <bold>{path_display}</> was partly removed; pruned its stale entry")
+    } else {
+        cformat!("Worktree directory <bold>{path_display}</> was missing; pruned its stale entry")
+    };
+    eprintln!("{}", info_message(message));
+    Ok(true)
+}
+
+/// Fix what's broken in the existing worktree at `path`, warning about what
+/// can't be fixed. Never fails: the switch itself still works.
+pub(super) fn repair_worktree(repo: &Repository, path: &Path) {
+    let dot_git = path.join(".git");
+    let git_dir = if dot_git.is_file() {
+        let Some(target) = read_gitdir_file(&dot_git, path) else {
+            return;
+        };
+        if target.exists() {
+            target
+        } else {
+            match relink_worktree(repo, path, &target) {
+                Some(target) => target,
+                None => return,
+            }
+        }
+    } else {
+        dot_git
+    };
+
+    clear_stale_index_lock(&git_dir.join("index.lock"));
+}
+
+/// The git directory named by a worktree's `.git` file, resolved against the
+/// worktree (git writes relative paths under `worktree.useRelativePaths`).
+fn read_gitdir_file(dot_git: &Path, worktree: &Path) -> Option<PathBuf> {
+    let content = std::fs::read_to_string(dot_git).ok()?;
+    let target = content.strip_prefix("gitdir:")?.trim();
+    Some(worktree.join(target))
+}
+
+/// Run `git worktree repair` for a worktree whose `.git` file points at a
+/// directory that no longer exists. Returns the repaired git directory.
+fn relink_worktree(repo: &Repository, path: &Path, stale: &Path) -> Option<PathBuf> {
+    let path_display = format_path_for_display(path);
+    let stale_display = format_path_for_display(stale);
+    let repaired = repo
+        .run_command(&["worktree", "repair", &path.to_string_lossy()])
+        .ok()
+        .and_then(|_| read_gitdir_file(&path.join(".git"), path))
+        .filter(|target| target.exists());
+
+    match &repaired {
+        Some(_) => eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Repaired <bold>{path_display}</>: its .git file pointed to missing <bold>{stale_display}</>"
+            ))
+        ),
+        None => {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Worktree <bold>{path_display}</> is disconnected: its .git file points to missing <bold>{stale_display}</>"
+                ))
+            );
+            eprintln!(
+                "{}",
+                hint_message(cformat!(
+                    "If the repository moved, run <underline>wt repair</> from its new location"
+                ))
+            );
+        }
+    }
+    repaired
+}
+
+/// Remove an `index.lock` old enough to be a leftover; warn about a fresh one,
+/// which a running git command may still hold.
+fn clear_stale_index_lock(lock: &Path) {
+    let Ok(modified) = std::fs::metadata(lock).and_then(|m| m.modified()) else {
+        return;
+    };
+    let lock_display = format_path_for_display(lock);
+
+    if modified.elapsed().unwrap_or_default() < STALE_LOCK_AGE {
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "<bold>{lock_display}</> exists; git commands in this worktree fail until it's removed"
+            ))
+        );
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "If no git command is running, remove it: <underline>rm {lock_display}</>"
+            ))
+        );
+        return;
+    }
+
+    match std::fs::remove_file(lock) {
+        Ok(()) => eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Removed stale <bold>{lock_display}</> (untouched for over an hour)"
+            ))
+        ),
+        Err(e) => eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Failed to remove stale <bold>{lock_display}</>: {e}"
+            ))
+        ),
+    }
+}
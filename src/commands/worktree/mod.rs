@@ -81,20 +81,28 @@
 //!
 //! The shell wrapper is generated by `wt config shell init <shell>` from templates in `templates/`.
 
+mod batch;
 mod finish;
+mod health;
 pub(crate) mod hooks;
+mod multiplexer;
 mod push;
 mod resolve;
+mod scaffold;
+mod submodules;
 mod switch;
 mod types;
+mod wip;
 
 // Re-export public types and functions
+pub use batch::DEFAULT_BATCH_JOBS;
 pub use finish::{FinishAfterMergeArgs, finish_after_merge};
-pub use push::{PushKind, PushOutcome, PushResult, handle_no_ff_merge, handle_push};
+pub use push::{PrePushGate, PushKind, PushOutcome, PushResult, handle_no_ff_merge, handle_push};
 pub use resolve::{
     compute_worktree_path, is_worktree_at_expected_path, path_mismatch, resolve_worktree_arg,
     worktree_display_name,
 };
+pub(crate) use submodules::sync_merged_submodules;
 pub(crate) use switch::SwitchPipeline;
 pub use switch::handle_switch_command;
 pub use types::{MergeOperations, RemoveResult, SwitchBranchInfo, SwitchResult};
@@ -0,0 +1,111 @@
+//! Open a worktree in a dedicated tmux window or Zellij tab.
+//!
+//! `wt switch --tmux-window` names the window (tab, in Zellij) after the
+//! branch: switching to a branch that already has one focuses it, otherwise a
+//! new one opens rooted at the worktree. The pane `wt` ran in stays where it
+//! is, so nothing is written to the cd directive.
+
+use std::path::Path;
+
+use anyhow::{Context, bail};
+use worktrunk::shell_exec::Cmd;
+
+/// A terminal multiplexer `wt` is running inside.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Multiplexer {
+    Tmux,
+    Zellij,
+}
+
+/// What [`Multiplexer::open`] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WindowAction {
+    Opened,
+    Focused,
+}
+
+impl Multiplexer {
+    /// The multiplexer of the current session, from `$TMUX` / `$ZELLIJ`.
+    pub(crate) fn detect() -> Option<Self> {
+        if std::env::var_os("TMUX").is_some_and(|v| !v.is_empty()) {
+            Some(Self::Tmux)
+        } else if std::env::var_os("ZELLIJ").is_some() {
+            Some(Self::Zellij)
+        } else {
+            None
+        }
+    }
+
+    /// What the multiplexer calls a window, for messages.
+    pub(crate) fn window_label(self) -> &'static str {
+        match self {
+            Self::Tmux => "tmux window",
+            Self::Zellij => "Zellij tab",
+        }
+    }
+
+    /// Focus the window named `name`, or open one rooted at `path`.
+    pub(crate) fn open(self, name: &str, path: &Path) -> anyhow::Result<WindowAction> {
+        let path = path.to_string_lossy();
+        match self {
+            Self::Tmux => {
+                let windows = run(
+                    "tmux",
+                    &["list-windows", "-F", "#{window_id}\t#{window_name}"],
+                )?;
+                // Target by id: a name containing `:` or `.` would be parsed
+                // as a session or pane target.
+                if let Some(id) = find_tmux_window(&windows, name) {
+                    run("tmux", &["select-window", "-t", id])?;
+                    return Ok(WindowAction::Focused);
+                }
+                run("tmux", &["new-window", "-n", name, "-c", &path])?;
+            }
+            Self::Zellij => {
+                let tabs = run("zellij", &["action", "query-tab-names"])?;
+                if tabs.lines().any(|tab| tab == name) {
+                    run("zellij", &["action", "go-to-tab-name", name])?;
+                    return Ok(WindowAction::Focused);
+                }
+                run(
+                    "zellij",
+                    &["action", "new-tab", "--name", name, "--cwd", &path],
+                )?;
+            }
+        }
+        Ok(WindowAction::Opened)
+    }
+}
+
+/// The id of the window named `name` in `list-windows -F '#{window_id}\t#{window_name}'` output.
+fn find_tmux_window<'a>(windows: &'a str, name: &str) -> Option<&'a str> {
+    windows
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .find_map(|(id, window)| (window == name).then_some(id))
+}
+
+fn run(program: &str, args: &[&str]) -> anyhow::Result<String> {
+    let output = Cmd::new(program)
+        .args(args.iter().copied())
+        .run()
+        .with_context(|| format!("Failed to run {program}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("{program} {} failed: {}", args.join(" "), stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_tmux_window() {
+        let windows = "@1\tzsh\n@4\tfeature/auth\n@7\tfeature\n";
+        assert_eq!(find_tmux_window(windows, "feature"), Some("@7"));
+        assert_eq!(find_tmux_window(windows, "feature/auth"), Some("@4"));
+        assert_eq!(find_tmux_window(windows, "main"), None);
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! Push changes to target branch with safety checks. Both fast-forward push and
 //! `--no-ff` merge share common scaffolding (target resolution, fast-forward check,
-//! stash guard, progress/success output) extracted into [`MergeContext`].
+//! `pre-push` gate, stash guard, progress/success output) extracted into
+//! [`MergeContext`].
 
 use std::path::PathBuf;
 
@@ -60,6 +61,11 @@ pub enum PushOutcome {
     MergeCommit { merge_sha: String },
 }
 
+/// Callback run right before the target branch moves, given the target tip and
+/// the commit it will move to. Callers use it to run `pre-push` hooks; an error
+/// aborts the push with nothing changed.
+pub type PrePushGate<'a> = &'a dyn Fn(&str, &str) -> anyhow::Result<()>;
+
 // ---------------------------------------------------------------------------
 // Shared scaffolding
 // ---------------------------------------------------------------------------
@@ -67,8 +73,9 @@ pub enum PushOutcome {
 /// Pre-computed state shared by both fast-forward push and `--no-ff` merge.
 ///
 /// Created by [`MergeContext::prepare`], which resolves the target branch,
-/// verifies fast-forward, sets up the stash guard, counts commits, and captures
-/// diff statistics — all steps that are identical between the two strategies.
+/// verifies fast-forward, counts commits, runs the `pre-push` gate, sets up the
+/// stash guard, and captures diff statistics — all steps that are identical
+/// between the two strategies.
 struct MergeContext {
     repo: Repository,
     target_branch: String,
@@ -81,13 +88,31 @@ struct MergeContext {
 }
 
 impl MergeContext {
-    /// Resolve target, verify fast-forward, stash guard, count commits, capture stats.
-    fn prepare(target: Option<&str>, operations: Option<MergeOperations>) -> anyhow::Result<Self> {
+    /// Resolve target, verify fast-forward, count commits, run the `pre-push`
+    /// gate, stash guard, capture stats.
+    fn prepare(
+        target: Option<&str>,
+        operations: Option<MergeOperations>,
+        pre_push: Option<PrePushGate<'_>>,
+    ) -> anyhow::Result<Self> {
         let repo = Repository::current()?;
 
         let target_branch = repo.require_target_branch(target)?;
         let target_worktree_path = repo.worktree_for_branch(&target_branch)?;
 
+        // A target worktree mid-rebase/merge can't take the push: the
+        // `updateInstead` checkout would rewrite files under the operation.
+        if let Some(wt_path) = target_worktree_path.as_ref().filter(|p| p.exists())
+            && let Some(state) = repo.worktree_at(wt_path).operation_state()?
+        {
+            return Err(GitError::TargetOperationInProgress {
+                target_branch,
+                worktree_path: wt_path.clone(),
+                state,
+            }
+            .into());
+        }
+
         // Snapshot target SHA early for TOCTOU safety (used by both strategies
         // for the fast-forward check; --no-ff also uses it for update-ref).
         let target_ref = format!("refs/heads/{}", target_branch);
@@ -120,12 +145,20 @@ impl MergeContext {
             .into());
         }
 
+        let commit_count = repo.count_commits(&target_branch, "HEAD")?;
+
+        // Gate before the stash guard so a rejected push leaves the target
+        // worktree untouched. Nothing to validate when the target is current.
+        if commit_count > 0
+            && let Some(gate) = pre_push
+        {
+            gate(&target_tip, &head_sha)?;
+        }
+
         // Auto-stash non-overlapping changes in target worktree
         let stash_guard =
             repo.prepare_target_worktree(target_worktree_path.as_ref(), &target_branch)?;
 
-        let commit_count = repo.count_commits(&target_branch, "HEAD")?;
-
         let stats_summary = if commit_count > 0 {
             repo.diff_stats_summary(&[
                 "diff",
@@ -295,6 +328,7 @@ fn format_up_to_date_context(operations: Option<MergeOperations>) -> String {
 ///
 /// The `operations` parameter indicates which merge operations occurred (commit, squash, rebase).
 /// Pass `None` for standalone push operations where these concepts don't apply.
+/// `pre_push` runs after the fast-forward check, before anything is modified.
 ///
 /// During the push stage we temporarily `git stash` non-overlapping changes in the
 /// target worktree (if present) so that concurrent edits there do not block the
@@ -304,8 +338,9 @@ pub fn handle_push(
     target: Option<&str>,
     kind: PushKind,
     operations: Option<MergeOperations>,
+    pre_push: Option<PrePushGate<'_>>,
 ) -> anyhow::Result<PushResult> {
-    let mut ctx = MergeContext::prepare(target, operations)?;
+    let mut ctx = MergeContext::prepare(target, operations, pre_push)?;
 
     ctx.show_progress(kind.verb_progressive(), "", operations)?;
 
@@ -366,8 +401,9 @@ pub fn handle_no_ff_merge(
     target: Option<&str>,
     operations: Option<MergeOperations>,
     feature_branch: &str,
+    pre_push: Option<PrePushGate<'_>>,
 ) -> anyhow::Result<PushResult> {
-    let mut ctx = MergeContext::prepare(target, operations)?;
+    let mut ctx = MergeContext::prepare(target, operations, pre_push)?;
 
     ctx.show_progress("Merging", " (--no-ff)", operations)?;
 
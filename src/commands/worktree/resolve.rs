@@ -2,6 +2,7 @@
 //!
 //! Functions for resolving worktree arguments and computing expected paths.
 
+use std::collections::BTreeSet;
 use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 
@@ -9,12 +10,14 @@ use anyhow::Context;
 use color_print::cformat;
 use normalize_path::NormalizePath;
 use worktrunk::config::UserConfig;
-use worktrunk::git::{Repository, ResolvedWorktree};
+use worktrunk::git::{GitError, Repository, ResolvedWorktree, path_dir_name};
 use worktrunk::path::{format_path_for_display, paths_match};
 use worktrunk::styling::{
-    eprintln, format_toml, hint_message, info_message, success_message, warning_message,
+    eprintln, format_toml, format_with_gutter, hint_message, info_message, success_message,
+    warning_message,
 };
 
+use crate::output::notice;
 use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
 
 /// Resolve a worktree argument using branch-first lookup.
@@ -70,6 +73,86 @@ pub fn resolve_worktree_arg(repo: &Repository, name: &str) -> anyhow::Result<Res
     Ok(ResolvedWorktree::BranchOnly { branch })
 }
 
+/// Resolve a partial branch name to the one branch it abbreviates.
+///
+/// Called for a `wt switch` argument that names no branch. The query matches,
+/// case-insensitively, local branches containing it and branches whose
+/// worktree directory name contains it; a worktree directory named exactly
+/// the query beats substring matches. Returns `None` when nothing matches.
+///
+/// Several matches prompt with a numbered list when stdin and stderr are
+/// terminals, and are an [`GitError::AmbiguousBranch`] error otherwise (or
+/// when the prompt is skipped).
+///
+/// [`GitError::AmbiguousBranch`]: worktrunk::git::GitError::AmbiguousBranch
+pub fn resolve_partial_branch(repo: &Repository, query: &str) -> anyhow::Result<Option<String>> {
+    let needle = query.to_lowercase();
+    let mut exact_dirs = BTreeSet::new();
+    let mut matches = BTreeSet::new();
+    for wt in repo.list_worktrees()? {
+        let Some(branch) = &wt.branch else {
+            continue;
+        };
+        let dir = path_dir_name(&wt.path).to_lowercase();
+        if dir == needle {
+            exact_dirs.insert(branch.clone());
+        } else if dir.contains(&needle) {
+            matches.insert(branch.clone());
+        }
+    }
+    for branch in repo.local_branches()? {
+        if branch.name.to_lowercase().contains(&needle) {
+            matches.insert(branch.name.clone());
+        }
+    }
+    let matches: Vec<String> = if exact_dirs.is_empty() {
+        matches.into_iter().collect()
+    } else {
+        exact_dirs.into_iter().collect()
+    };
+
+    match matches.as_slice() {
+        [] => Ok(None),
+        [only] => {
+            eprintln!(
+                "{}",
+                info_message(cformat!("<bold>{query}</> matches <bold>{only}</>"))
+            );
+            Ok(Some(only.clone()))
+        }
+        _ => {
+            let ambiguous = || GitError::AmbiguousBranch {
+                query: query.to_string(),
+                matches: matches.clone(),
+            };
+            if !(std::io::stdin().is_terminal() && std::io::stderr().is_terminal()) {
+                return Err(ambiguous().into());
+            }
+            eprintln!(
+                "{}",
+                info_message(cformat!(
+                    "<bold>{query}</> matches {} branches",
+                    matches.len()
+                ))
+            );
+            let options = matches
+                .iter()
+                .enumerate()
+                .map(|(i, branch)| cformat!("{}. <bold>{branch}</>", i + 1))
+                .collect::<Vec<_>>()
+                .join("\n");
+            eprintln!("{}", format_with_gutter(&options, None));
+            match crate::output::prompt::prompt_choice(
+                "Switch to which? Enter cancels",
+                matches.len(),
+            )? {
+                Some(choice) => Ok(Some(matches[choice].clone())),
+                None => Err(ambiguous().into()),
+            }
+        }
+    }
+}
+
 /// Compute the expected worktree path for a branch name.
 ///
 /// For the default branch, returns the repo root (main worktree location).
@@ -218,7 +301,7 @@ pub fn offer_bare_repo_worktree_path_fix(
         return Ok(false);
     }
 
-    if repo.has_shown_hint("skip-bare-repo-prompt") {
+    if !notice::SKIP_BARE_REPO_PROMPT.is_due(repo) {
         return Ok(false);
     }
 
@@ -302,9 +385,9 @@ pub fn offer_bare_repo_worktree_path_fix(
             Ok(true)
         }
         PromptResponse::Declined => {
-            // Best-effort, like every other hint write: a failed persist just
+            // Best-effort, like every other notice: a failed persist just
             // means the prompt may reappear on the next switch.
-            let _ = repo.mark_hint_shown("skip-bare-repo-prompt");
+            notice::SKIP_BARE_REPO_PROMPT.record(repo);
             Ok(false)
         }
     }
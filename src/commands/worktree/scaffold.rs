@@ -0,0 +1,150 @@
+//! `[scaffold]` — instantiate template files into a new worktree.
+//!
+//! Runs right after `git worktree add`, before the pre-start hooks, so hooks
+//! can rely on the files existing. Each entry maps a destination in the new
+//! worktree to a template file; the template's contents are expanded with the
+//! hook template variables (`{{ branch }}`, `{{ worktree_path }}`, …).
+//!
+//! Scaffolding is best-effort: a template that can't be read or expanded is
+//! reported as a warning and the worktree is still created. Destinations that
+//! already exist (e.g. a tracked file) are left alone.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::expand_template;
+use worktrunk::shell_exec::ShellEscapeMode;
+use worktrunk::styling::{eprintln, info_message, warning_message};
+
+use crate::commands::command_executor::{CommandContext, build_hook_context};
+
+/// Instantiate every `[scaffold]` entry from `scaffold` into `ctx.worktree_path`.
+///
+/// `extra_vars` are the operation's bindings (base branch, PR number), as
+/// passed to the pre-start hooks.
+pub(crate) fn apply_scaffold(
+    ctx: &CommandContext<'_>,
+    scaffold: &BTreeMap<String, String>,
+    extra_vars: &[(&str, &str)],
+) {
+    let context = match build_hook_context(ctx, extra_vars, None) {
+        Ok(context) => context,
+        Err(e) => {
+            eprintln!(
+                "{}",
+                warning_message(cformat!("Could not scaffold files: {e:#}"))
+            );
+            return;
+        }
+    };
+    let vars: HashMap<&str, &str> = context
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+
+    let mut created = Vec::new();
+    for (dest, template) in scaffold {
+        match scaffold_file(ctx, &vars, dest, template) {
+            Ok(true) => created.push(dest.as_str()),
+            Ok(false) => {}
+            Err(e) => eprintln!(
+                "{}",
+                warning_message(cformat!("Could not scaffold <bold>{dest}</>: {e:#}"))
+            ),
+        }
+    }
+    if !created.is_empty() {
+        let list = created
+            .iter()
+            .map(|dest| cformat!("<bold>{dest}</>"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!("{}", info_message(format!("Scaffolded {list}")));
+    }
+}
+
+/// Write one scaffolded file. Returns `false` when the destination exists.
+fn scaffold_file(
+    ctx: &CommandContext<'_>,
+    vars: &HashMap<&str, &str>,
+    dest: &str,
+    template: &str,
+) -> anyhow::Result<bool> {
+    let dest_path = destination(ctx.worktree_path, dest)?;
+    if dest_path.symlink_metadata().is_ok() {
+        return Ok(false);
+    }
+
+    let source = expand_template(
+        template,
+        vars,
+        ShellEscapeMode::Literal,
+        ctx.repo,
+        "scaffold source",
+    )?;
+    let source_path = ctx.worktree_path.join(&source);
+    let bytes = fs::read(&source_path)
+        .with_context(|| format!("Failed to read {}", source_path.display()))?;
+    // Binary templates are copied verbatim
+    let contents = match String::from_utf8(bytes) {
+        Ok(text) => {
+            let mut expanded = expand_template(
+                &text,
+                vars,
+                ShellEscapeMode::Literal,
+                ctx.repo,
+                &format!("scaffold {dest}"),
+            )?;
+            // Templating drops the final newline; files should keep it
+            if text.ends_with('\n') && !expanded.ends_with('\n') {
+                expanded.push('\n');
+            }
+            expanded.into_bytes()
+        }
+        Err(e) => e.into_bytes(),
+    };
+
+    if let Some(parent) = dest_path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(&dest_path, contents)
+        .with_context(|| format!("Failed to write {}", dest_path.display()))?;
+    // Keep executable bits, so scaffolded scripts stay runnable
+    if let Ok(metadata) = fs::metadata(&source_path) {
+        let _ = fs::set_permissions(&dest_path, metadata.permissions());
+    }
+    Ok(true)
+}
+
+/// Resolve `dest` inside the worktree, refusing paths that would escape it.
+fn destination(worktree_path: &Path, dest: &str) -> anyhow::Result<PathBuf> {
+    let relative = Path::new(dest);
+    let inside = relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+    if !inside || dest.is_empty() {
+        anyhow::bail!("destination must be a relative path inside the worktree");
+    }
+    Ok(worktree_path.join(relative))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_destination_stays_inside_worktree() {
+        let root = Path::new("/repo.feature");
+        assert_eq!(
+            destination(root, ".vscode/settings.json").unwrap(),
+            root.join(".vscode/settings.json")
+        );
+        assert!(destination(root, "../escape").is_err());
+        assert!(destination(root, "/etc/passwd").is_err());
+        assert!(destination(root, "").is_err());
+    }
+}
@@ -0,0 +1,159 @@
+//! `[submodules] follow` — keep chosen submodules on the worktree's branch.
+//!
+//! Each followed submodule is checked out on a branch named after the parent
+//! worktree's branch: created at the commit the parent records, or the
+//! existing branch when the submodule already has one. `wt switch` applies
+//! this to new worktrees and re-applies it to existing ones; `wt merge` then
+//! brings the target worktree's copy up to the commit the merge recorded.
+//!
+//! Every worktree has its own clone of each submodule (under its private git
+//! dir), so branches created here go away with `wt remove`.
+//!
+//! All of this is best-effort: failures are reported as warnings and never
+//! block the switch or merge.
+
+use std::path::Path;
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::styling::{eprintln, hint_message, info_message, warning_message};
+
+/// Initialize each followed submodule in `worktree_path` and check it out on
+/// `branch`. A submodule with uncommitted changes on another branch is left
+/// alone.
+pub(crate) fn follow_submodules(
+    repo: &Repository,
+    worktree_path: &Path,
+    branch: &str,
+    follow: &[String],
+) {
+    let mut moved = Vec::new();
+    for path in follow {
+        match follow_one(repo, worktree_path, path, branch) {
+            Ok(true) => moved.push(path.as_str()),
+            Ok(false) => {}
+            Err(e) => eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Could not follow submodule <bold>{path}</>: {e:#}"
+                ))
+            ),
+        }
+    }
+    if !moved.is_empty() {
+        let list = moved
+            .iter()
+            .map(|path| cformat!("<bold>{path}</>"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Checked out <bold>{branch}</> in submodules {list}"
+            ))
+        );
+    }
+}
+
+/// Returns whether the submodule's checkout changed.
+fn follow_one(
+    repo: &Repository,
+    worktree_path: &Path,
+    path: &str,
+    branch: &str,
+) -> anyhow::Result<bool> {
+    let dir = worktree_path.join(path);
+    if !dir.join(".git").exists() {
+        repo.worktree_at(worktree_path).run_command(&[
+            "submodule",
+            "update",
+            "--init",
+            "--",
+            path,
+        ])?;
+    }
+
+    let sub = repo.worktree_at(&dir);
+    let head = sub.run_command_output(&["symbolic-ref", "--quiet", "--short", "HEAD"])?;
+    if String::from_utf8_lossy(&head.stdout).trim() == branch {
+        return Ok(false);
+    }
+    if !sub.run_command(&["status", "--porcelain"])?.is_empty() {
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Submodule <bold>{path}</> has uncommitted changes; not checking out <bold>{branch}</>"
+            ))
+        );
+        return Ok(false);
+    }
+
+    // `checkout <branch>` also picks up a same-named remote branch; only a
+    // branch that exists nowhere is created at the recorded commit.
+    let checked_out = sub
+        .run_command_output(&["checkout", "--quiet", branch])?
+        .status
+        .success();
+    if !checked_out {
+        sub.run_command(&["checkout", "--quiet", "-b", branch])?;
+    }
+    Ok(true)
+}
+
+/// After `branch` merged into the worktree at `target_path`, fast-forward each
+/// followed submodule there to the commit the target now records, fetching
+/// it from the submodule in `source_path`.
+pub(crate) fn sync_merged_submodules(
+    repo: &Repository,
+    source_path: &Path,
+    target_path: &Path,
+    branch: &str,
+    follow: &[String],
+) {
+    for path in follow {
+        if let Err(e) = sync_one(repo, source_path, target_path, path, branch) {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Could not update submodule <bold>{path}</> in the target worktree: {e:#}"
+                ))
+            );
+            let update = format!("git submodule update -- {path}");
+            eprintln!(
+                "{}",
+                hint_message(cformat!(
+                    "To check out the recorded commit, run <underline>{update}</> in the target worktree"
+                ))
+            );
+        }
+    }
+}
+
+fn sync_one(
+    repo: &Repository,
+    source_path: &Path,
+    target_path: &Path,
+    path: &str,
+    branch: &str,
+) -> anyhow::Result<()> {
+    let dir = target_path.join(path);
+    if !dir.join(".git").exists() {
+        // Not checked out in the target: nothing to keep in sync
+        return Ok(());
+    }
+    let recorded = repo
+        .worktree_at(target_path)
+        .run_command(&["rev-parse", &format!("HEAD:{path}")])?;
+    let recorded = recorded.trim();
+    let sub = repo.worktree_at(&dir);
+    if sub.run_command(&["rev-parse", "HEAD"])?.trim() == recorded {
+        return Ok(());
+    }
+
+    let source = source_path.join(path);
+    if source.join(".git").exists() {
+        sub.run_command(&["fetch", "--quiet", &source.to_string_lossy(), branch])?;
+    }
+    sub.run_command(&["merge", "--ff-only", "--quiet", recorded])?;
+    Ok(())
+}
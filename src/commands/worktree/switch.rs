@@ -5,6 +5,7 @@
 //! shared by the `wt switch` argument path and the interactive picker.
 
 use std::collections::HashMap;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 
 use crate::display::format_relative_time_short;
@@ -14,7 +15,8 @@ use dunce::canonicalize;
 use serde::Serialize;
 use worktrunk::HookType;
 use worktrunk::config::{
-    UserConfig, ValidationScope, expand_template, template_references_var, validate_template,
+    TrackMode, UserConfig, ValidationScope, expand_template, template_references_var,
+    validate_template,
 };
 use worktrunk::git::remote_ref::{
     self, AzureDevOpsProvider, GitHubProvider, GitLabProvider, GiteaProvider, RemoteRefInfo,
@@ -24,26 +26,39 @@ use worktrunk::git::{
     GitError, GitRemoteUrl, RefContext, RefType, Repository, SwitchSuggestionCtx,
     current_or_recover,
 };
+use worktrunk::jj::JjRevision;
 use worktrunk::shell_exec::{ShellEscapeMode, directive_shell_escape_mode, shell_escape_for};
+use worktrunk::stats::StatsEvent;
+use worktrunk::styling::progress::Phases;
 use worktrunk::styling::{
-    eprintln, format_with_gutter, hint_message, info_message, progress_message, suggest_command,
-    warning_message,
+    eprintln, format_with_gutter, hint_message, info_message, progress_message, success_message,
+    suggest_command, suggest_command_in_dir, warning_message,
 };
 
-use super::resolve::{compute_worktree_path, offer_bare_repo_worktree_path_fix, path_mismatch};
+use super::health::{is_stale_worktree, prune_stale_worktree, repair_worktree};
+use super::multiplexer::{Multiplexer, WindowAction};
+use super::resolve::{
+    compute_worktree_path, offer_bare_repo_worktree_path_fix, path_mismatch, resolve_partial_branch,
+};
+use super::scaffold::apply_scaffold;
+use super::submodules::follow_submodules;
 use super::types::{CreationMethod, SwitchBranchInfo, SwitchPlan, SwitchResult};
+use super::wip::{restore_wip, save_wip};
 use crate::cli::{SwitchArgs, SwitchFormat};
 use crate::commands::backup::back_up_clobbered_path_now;
 use crate::commands::command_approval::approve_hooks;
 use crate::commands::command_executor::FailureStrategy;
 use crate::commands::command_executor::{CommandContext, build_hook_context};
+use crate::commands::create_journal::CreateJournal;
 use crate::commands::flag_pair;
 use crate::commands::hook_plan::{ApprovedHookPlan, HookPlanBuilder, register_planned};
 use crate::commands::hooks::{HookAnnouncer, execute_hook};
+use crate::commands::snapshot::{SNAPSHOT_BASE_PREFIX, resolve_snapshot_base};
 use crate::commands::template_vars::TemplateVars;
+use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
 use crate::output::{
     execute_user_command, handle_switch_output, is_shell_integration_active,
-    prompt_shell_integration,
+    prompt_shell_integration, terminal_title,
 };
 
 /// Result of resolving the switch target.
@@ -209,6 +224,16 @@ fn fetch_ref_info(
     number: u32,
     repo: &Repository,
 ) -> anyhow::Result<RemoteRefInfo> {
+    if repo.user_config().offline() {
+        let ref_type = provider.ref_type();
+        let (name, symbol) = (ref_type.name(), ref_type.symbol());
+        return Err(GitError::Other {
+            message: cformat!(
+                "Cannot look up {name} <bold>{symbol}{number}</> offline; drop <bold>--offline</> or set <bold>network = true</>"
+            ),
+        }
+        .into());
+    }
     let _watchdog = worktrunk::progress::Watchdog::start(
         &format!("the {} info", provider.ref_type().name()),
         None,
@@ -475,8 +500,9 @@ fn parse_ref_shortcut(input: &str) -> Option<(RefType, u32)> {
     None
 }
 
-/// Resolve a `--base` value, expanding `pr:`/`mr:` shortcuts. Non-shortcut
-/// inputs go through [`Repository::resolve_worktree_name`] (handles `@`/`-`/`^`).
+/// Resolve a `--base` value, expanding `pr:`/`mr:` and `snapshot:` shortcuts.
+/// Non-shortcut inputs go through [`Repository::resolve_worktree_name`]
+/// (handles `@`/`-`/`^`).
 ///
 /// Returns the resolved ref plus, when the user picked a `pr:`/`mr:` shortcut
 /// against a same-repo PR/MR, the `(remote, branch)` pair the new branch
@@ -499,19 +525,180 @@ fn resolve_base_ref(
         }
         None => {}
     }
+    if let Some(name) = base.strip_prefix(SNAPSHOT_BASE_PREFIX) {
+        return Ok((resolve_snapshot_base(repo, name)?, None));
+    }
 
     let resolved = repo.resolve_worktree_name(base)?;
+    fetch_stale_remote_base(repo, &resolved)?;
 
     if !repo.ref_exists(&resolved)? {
         let remotes = repo.branch(&resolved).remotes()?;
         if remotes.len() == 1 {
             return Ok((format!("{}/{}", remotes[0], resolved), None));
         }
+        // A jj change ID or revset, in a colocated jj repo
+        if let Some(revision) = JjRevision::resolve(repo.repo_path()?, base)? {
+            return Ok((revision.commit_id, None));
+        }
     }
 
     Ok((resolved, None))
 }
 
+/// Refresh a remote-tracking `--base` (`origin/main`) before branching from
+/// it, so the new worktree starts from the upstream tip rather than whatever
+/// the last fetch saw. Fetches only when `FETCH_HEAD` is older than
+/// `[switch] fetch-base`; a failed fetch warns and falls back to the local
+/// remote-tracking ref. Offline, the ref is used as it is.
+fn fetch_stale_remote_base(repo: &Repository, base: &str) -> anyhow::Result<()> {
+    let setting = repo.config().switch.fetch_base().to_string();
+    if setting == "off" || repo.user_config().offline() || repo.branch(base).exists_locally()? {
+        return Ok(());
+    }
+    let Some((remote, branch)) = split_remote_ref(repo, base) else {
+        return Ok(());
+    };
+    let max_age = humantime::parse_duration(&setting)
+        .with_context(|| cformat!("Invalid <bold>fetch-base</> in [switch]: {setting}"))?;
+    let fresh = repo.last_fetch_epoch().is_some_and(|fetched| {
+        let age = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |now| now.as_secs().saturating_sub(fetched));
+        age < max_age.as_secs()
+    });
+    if fresh {
+        return Ok(());
+    }
+
+    eprintln!(
+        "{}",
+        progress_message(cformat!("Fetching <bold>{base}</>..."))
+    );
+    let refspec = format!("+refs/heads/{branch}:refs/remotes/{remote}/{branch}");
+    if let Err(err) = repo.run_command(&["fetch", "--", &remote, &refspec]) {
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Failed to fetch <bold>{base}</>; using the local ref: {err:#}"
+            ))
+        );
+    }
+    Ok(())
+}
+
+/// `[switch] max-base-behind`: before branching from a local `base` that
+/// trails its upstream by more than the limit, offer to fast-forward it, so
+/// the new branch doesn't start from a week-old trunk. The upstream is fetched
+/// first when the last fetch is older than `fetch-base` — unless offline, where
+/// the upstream is compared as last fetched. Interactive switches prompt;
+/// `--yes` fast-forwards; anything else warns and branches from the base as it
+/// is. A base with commits of its own only gets the warning.
+fn offer_base_fast_forward(repo: &Repository, base: &str, yes: bool) -> anyhow::Result<()> {
+    let project_limit = repo
+        .project_config()?
+        .and_then(|config| config.switch.max_base_behind);
+    let Some(limit) = repo.config().switch.max_base_behind.or(project_limit) else {
+        return Ok(());
+    };
+    let Some(upstream) = repo.branch(base).upstream()? else {
+        return Ok(());
+    };
+    fetch_stale_remote_base(repo, &upstream)?;
+
+    // Pin both ends, so the ref moves to the commits counted and previewed,
+    // and only if nothing else moved it in the meantime.
+    let base_ref = format!("refs/heads/{base}");
+    let base_sha = repo.run_command(&["rev-parse", "--verify", &base_ref])?;
+    let base_sha = base_sha.trim();
+    let upstream_sha = repo.run_command(&["rev-parse", "--verify", &upstream])?;
+    let upstream_sha = upstream_sha.trim();
+
+    let range = format!("{base_sha}..{upstream_sha}");
+    let behind: u32 = repo
+        .run_command(&["rev-list", "--count", &range])?
+        .trim()
+        .parse()
+        .context("Failed to count commits behind the upstream")?;
+    if behind <= limit {
+        return Ok(());
+    }
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "Base <bold>{base}</> is {behind} commit{} behind <bold>{upstream}</>",
+            if behind == 1 { "" } else { "s" }
+        ))
+    );
+
+    if !repo.run_command_check(&["merge-base", "--is-ancestor", base_sha, upstream_sha])? {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "<bold>{base}</> has commits of its own, so it can't be fast-forwarded; to branch from the upstream, use <underline>--base {upstream}</>"
+            ))
+        );
+        return Ok(());
+    }
+    if !yes {
+        if !std::io::stdin().is_terminal() {
+            eprintln!(
+                "{}",
+                hint_message(cformat!(
+                    "To fast-forward it first, run with <underline>--yes</>; to branch from the upstream, use <underline>--base {upstream}</>"
+                ))
+            );
+            return Ok(());
+        }
+        let prompt = cformat!("Fast-forward <bold>{base}</> before branching from it?");
+        let preview = || {
+            if let Ok(log) = repo.run_command(&["log", "--oneline", "--max-count=20", &range]) {
+                eprintln!("{}", format_with_gutter(log.trim_end(), None));
+            }
+        };
+        if prompt_yes_no_preview(&prompt, preview)? == PromptResponse::Declined {
+            return Ok(());
+        }
+    }
+
+    // A base checked out somewhere moves with its worktree, which refuses
+    // rather than overwrite local changes; otherwise only the ref moves.
+    let result = match repo.worktree_for_branch(base)? {
+        Some(path) => {
+            repo.worktree_at(path)
+                .run_command(&["merge", "--ff-only", "--quiet", upstream_sha])
+        }
+        None => repo.run_command(&["update-ref", &base_ref, upstream_sha, base_sha]),
+    };
+    match result {
+        Ok(_) => eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Fast-forwarded <bold>{base}</> to <bold>{upstream}</>"
+            ))
+        ),
+        Err(err) => eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Failed to fast-forward <bold>{base}</>; branching from it as it is: {err:#}"
+            ))
+        ),
+    }
+    Ok(())
+}
+
+/// Split `origin/feature/x` into a configured remote and its branch, preferring
+/// the longest matching remote name. `None` when no remote matches.
+fn split_remote_ref(repo: &Repository, reference: &str) -> Option<(String, String)> {
+    repo.all_remote_urls()
+        .into_iter()
+        .filter_map(|(remote, _)| {
+            let branch = reference.strip_prefix(&format!("{remote}/"))?;
+            (!branch.is_empty()).then(|| (remote.clone(), branch.to_string()))
+        })
+        .max_by_key(|(remote, _)| remote.len())
+}
+
 /// Resolve `pr:{N}` / `mr:{N}` for `--base`. Same-repo returns the source
 /// branch name plus the (remote, branch) the new branch should track; fork
 /// returns the PR head SHA so we don't create a tracking branch for a ref
@@ -592,6 +779,16 @@ fn resolve_switch_target(
         resolved_branch = local_name;
     }
 
+    // A name that matches no branch may abbreviate one (`wt switch login` for
+    // `feature/login-page`).
+    if !create
+        && repo.worktree_for_branch(&resolved_branch)?.is_none()
+        && !repo.branch(&resolved_branch).exists()?
+        && let Some(matched) = resolve_partial_branch(repo, &resolved_branch)?
+    {
+        resolved_branch = matched;
+    }
+
     // Resolve and validate base (only when --create is set)
     let (resolved_base, base_pr_upstream) = if let Some(base_str) = base {
         if !create {
@@ -614,6 +811,16 @@ fn resolve_switch_target(
         (None, None)
     };
 
+    // In a colocated jj repo, a name that matches no branch may be a change
+    // ID or revset (`wt switch 'description(glob:"fix*")'`).
+    if !create
+        && repo.worktree_for_branch(&resolved_branch)?.is_none()
+        && !repo.branch(&resolved_branch).exists()?
+        && let Some(revision) = JjRevision::resolve(repo.repo_path()?, branch)?
+    {
+        return jj_revision_target(repo, revision);
+    }
+
     // Validate --create constraints
     if create {
         let branch_handle = repo.branch(&resolved_branch);
@@ -674,7 +881,7 @@ fn resolve_switch_target(
 /// Validate that we can create a worktree at the given path.
 ///
 /// Checks:
-/// - Path not occupied by another worktree
+/// - Path not occupied by another worktree (a stale occupant is pruned)
 /// - For regular switches (not --create), branch must exist
 /// - Handles --clobber for stale directories
 ///
@@ -686,6 +893,7 @@ fn validate_worktree_creation(
     path: &Path,
     clobber: bool,
     method: &CreationMethod,
+    pruned: Option<&Path>,
 ) -> anyhow::Result<bool> {
     // For regular switches without --create, validate branch exists
     if let CreationMethod::Regular {
@@ -703,21 +911,25 @@ fn validate_worktree_creation(
         .into());
     }
 
-    // Check if path is occupied by another worktree
+    // Check if path is occupied by another worktree. A stale occupant is
+    // pruned so the path can be reused; the worktree list is cached, so an
+    // entry already pruned by `plan_switch` still shows up here.
     if let Some((existing_path, occupant)) = repo.worktree_at_path(path)? {
-        if !existing_path.exists() {
+        if !is_stale_worktree(&existing_path) {
+            return Err(GitError::WorktreePathOccupied {
+                branch: branch.to_string(),
+                path: path.to_path_buf(),
+                occupant,
+            }
+            .into());
+        }
+        if pruned != Some(existing_path.as_path()) && !prune_stale_worktree(repo, &existing_path)? {
             let occupant_branch = occupant.unwrap_or_else(|| branch.to_string());
             return Err(GitError::WorktreeMissing {
                 branch: occupant_branch,
             }
             .into());
         }
-        return Err(GitError::WorktreePathOccupied {
-            branch: branch.to_string(),
-            path: path.to_path_buf(),
-            occupant,
-        }
-        .into());
     }
 
     // Handle clobber for stale directories. Returns whether `execute_switch`
@@ -810,6 +1022,28 @@ fn setup_fork_branch(
     Ok(())
 }
 
+/// Target for a jj revision: its bookmark when it has one, otherwise a new
+/// branch named after the short change ID, cut at the revision's commit. In a
+/// colocated repo jj imports that branch as a bookmark.
+fn jj_revision_target(repo: &Repository, revision: JjRevision) -> anyhow::Result<ResolvedTarget> {
+    let (branch, create_branch) = match revision.bookmarks.into_iter().next() {
+        Some(bookmark) => (bookmark, false),
+        None => {
+            // An earlier switch to this change already named a branch after it.
+            let exists = repo.branch(&revision.change_id).exists_locally()?;
+            (revision.change_id, !exists)
+        }
+    };
+    Ok(ResolvedTarget {
+        branch,
+        method: CreationMethod::Regular {
+            create_branch,
+            base_branch: create_branch.then_some(revision.commit_id),
+            base_pr_upstream: None,
+        },
+    })
+}
+
 /// Validate and plan a switch operation.
 ///
 /// This performs all validation upfront, returning a `SwitchPlan` that can be
@@ -818,7 +1052,7 @@ fn setup_fork_branch(
 ///
 /// Warnings (remote branch shadow, --base without --create, invalid default branch)
 /// are printed during planning since they're informational, not blocking.
-fn plan_switch(
+pub(super) fn plan_switch(
     repo: &Repository,
     branch: &str,
     create: bool,
@@ -834,19 +1068,25 @@ fn plan_switch(
 
     // Phase 2: Check if worktree already exists for this branch (fast path)
     // This avoids computing the worktree path template (~7 git commands) for existing switches.
+    let mut pruned = None;
     match repo.worktree_for_branch(&target.branch)? {
-        Some(existing_path) if existing_path.exists() => {
+        Some(existing_path) if !is_stale_worktree(&existing_path) => {
             return Ok(SwitchPlan::Existing {
                 path: canonicalize(&existing_path).unwrap_or(existing_path),
                 branch: Some(target.branch),
                 new_previous,
             });
         }
-        Some(_) => {
-            return Err(GitError::WorktreeMissing {
-                branch: target.branch,
+        // Directory deleted out from under git, or an interrupted removal:
+        // prune the entry and recreate below
+        Some(missing_path) => {
+            if !prune_stale_worktree(repo, &missing_path)? {
+                return Err(GitError::WorktreeMissing {
+                    branch: target.branch,
+                }
+                .into());
             }
-            .into());
+            pruned = Some(missing_path);
         }
         None => {}
     }
@@ -886,6 +1126,7 @@ fn plan_switch(
         &expected_path,
         clobber,
         &target.method,
+        pruned.as_deref(),
     )?;
 
     // Phase 5: Return the plan
@@ -898,6 +1139,16 @@ fn plan_switch(
     })
 }
 
+/// `[submodules] follow` from the project config. An unreadable config is
+/// reported by whatever loads it for hooks; here it just follows nothing.
+fn followed_submodules(repo: &Repository) -> &[String] {
+    repo.project_config()
+        .ok()
+        .flatten()
+        .map(|project| project.submodules.follow.as_slice())
+        .unwrap_or_default()
+}
+
 /// Execute a validated switch plan.
 ///
 /// Takes a `SwitchPlan` from `plan_switch()` and executes it.
@@ -905,10 +1156,11 @@ fn plan_switch(
 /// `SwitchBranchInfo` has `expected_path: None` — callers fill it in after
 /// first output to avoid computing path mismatch on the hot path.
 /// For `SwitchPlan::Create`, creates the worktree and runs hooks.
-fn execute_switch(
+pub(super) fn execute_switch(
     repo: &Repository,
     plan: SwitchPlan,
     config: &UserConfig,
+    track: Option<TrackMode>,
     force: bool,
     run_hooks: bool,
     hook_plan: &ApprovedHookPlan,
@@ -919,6 +1171,11 @@ fn execute_switch(
             branch,
             new_previous,
         } => {
+            repair_worktree(repo, &path);
+            if let Some(branch) = &branch {
+                follow_submodules(repo, &path, branch, followed_submodules(repo));
+            }
+
             let current_dir = std::env::current_dir()
                 .ok()
                 .and_then(|p| canonicalize(&p).ok());
@@ -926,11 +1183,19 @@ fn execute_switch(
                 .as_ref()
                 .map(|cur| cur == &path)
                 .unwrap_or(false);
+            // From a subdirectory (or the git dir) of the target worktree the
+            // switch still moves to its root, but it's the same worktree.
+            let same_worktree = already_at_worktree
+                || repo
+                    .current_worktree()
+                    .root()
+                    .is_ok_and(|root| root == path);
 
             // Only update switch history when actually switching worktrees.
-            // Updating on AlreadyAt would corrupt `wt switch -` by recording
-            // the current branch as "previous" even though no switch occurred.
-            if !already_at_worktree {
+            // Updating within the same worktree would corrupt `wt switch -` by
+            // recording the current branch as "previous" even though no
+            // switch occurred.
+            if !same_worktree {
                 let _ = repo.set_switch_previous(new_previous.as_deref());
             }
 
@@ -976,8 +1241,26 @@ fn execute_switch(
                 );
             }
 
+            // Mark the creation as in progress before anything exists on disk,
+            // so an interruption from here until the pre-start hooks finish
+            // leaves the marker behind. A failed `git worktree add` clears
+            // it: whatever is at the path now isn't ours. See
+            // `create_journal`.
+            let mut journal = CreateJournal::begin(
+                repo,
+                &branch,
+                &worktree_path,
+                matches!(
+                    method,
+                    CreationMethod::Regular {
+                        create_branch: true,
+                        ..
+                    } | CreationMethod::ForkRef { .. }
+                ),
+            )?;
+
             // Execute based on creation method
-            let (created_branch, base_branch, from_remote) = match &method {
+            let (created_branch, base_branch, from_remote, upstream) = match &method {
                 CreationMethod::Regular {
                     create_branch,
                     base_branch,
@@ -1005,7 +1288,9 @@ fn execute_switch(
                     let tracking_ref;
 
                     let trailing_ref: Option<&str> = if *create_branch {
-                        args.push("-b");
+                        // The upstream is set afterwards, from `track`; git's
+                        // own default would track a remote-tracking base.
+                        args.extend(["--no-track", "-b"]);
                         args.push(&branch);
                         base_branch.as_deref()
                     } else if !local_branch_existed {
@@ -1041,6 +1326,7 @@ fn execute_switch(
                         Repository::SLOW_OPERATION_DELAY_MS,
                         progress_msg,
                     ) {
+                        journal.finish(repo)?;
                         return Err(worktree_creation_error(
                             &e,
                             branch.clone(),
@@ -1049,32 +1335,26 @@ fn execute_switch(
                         .into());
                     }
 
-                    // Safety: unset unsafe upstream when creating a new branch from a remote
-                    // tracking branch. When `git worktree add -b feature origin/main` runs,
-                    // git sets feature to track origin/main. This is dangerous because
-                    // `git push` would push to main instead of the feature branch.
-                    // See: https://github.com/max-sixty/worktrunk/issues/713
-                    if *create_branch
-                        && let Some(base) = base_branch
-                        && repo.is_remote_tracking_branch(base)
-                    {
-                        // Unset the upstream to prevent accidental pushes
-                        branch_handle.unset_upstream()?;
-                    }
+                    let upstream = if *create_branch {
+                        set_new_branch_upstream(
+                            repo,
+                            &branch,
+                            base_branch.as_deref(),
+                            base_pr_upstream.as_ref(),
+                            track,
+                        )?
+                    } else {
+                        None
+                    };
 
-                    // `--base pr:N` / `--base mr:N` against a same-repo PR/MR: the
-                    // user asked for a custom local name pointing at an existing
-                    // remote branch — wire up tracking so `git push` from the new
-                    // worktree pushes back to the PR/MR's source branch instead
-                    // of failing with "no upstream branch". See issue #2497.
+                    // Remember where the branch was cut, so `wt list --full` can
+                    // flag it once that base is re-cut or deleted. Git's reflog
+                    // records the same, but expires.
                     if *create_branch
-                        && let Some((upstream_remote, upstream_branch)) = base_pr_upstream
+                        && let Some(base) = base_branch
+                        && let Err(e) = repo.record_branch_base(&branch, base)
                     {
-                        repo.set_config(&format!("branch.{branch}.remote"), upstream_remote)?;
-                        repo.set_config(
-                            &format!("branch.{branch}.merge"),
-                            &format!("refs/heads/{upstream_branch}"),
-                        )?;
+                        tracing::debug!("Could not record base of {branch}: {e:#}");
                     }
 
                     // Report tracking info when the branch was auto-created from a remote
@@ -1084,7 +1364,7 @@ fn execute_switch(
                         None
                     };
 
-                    (*create_branch, base_branch.clone(), from_remote)
+                    (*create_branch, base_branch.clone(), from_remote, upstream)
                 }
 
                 CreationMethod::ForkRef {
@@ -1099,8 +1379,11 @@ fn execute_switch(
 
                     // Fetch the ref (remote was resolved during planning)
                     // Use -- to prevent refs starting with - from being interpreted as flags
-                    repo.run_command(&["fetch", "--", remote, ref_path])
-                        .with_context(|| format!("Failed to fetch {} from {}", label, remote))?;
+                    if let Err(e) = repo.run_command(&["fetch", "--", remote, ref_path]) {
+                        journal.finish(repo)?;
+                        return Err(e)
+                            .with_context(|| format!("Failed to fetch {} from {}", label, remote));
+                    }
 
                     // Execute branch creation and configuration with cleanup on failure.
                     let setup_result = setup_fork_branch(
@@ -1116,6 +1399,7 @@ fn execute_switch(
                     if let Err(e) = setup_result {
                         // Cleanup: try to delete the branch if it was created
                         let _ = repo.run_command(&["branch", "-D", "--", &branch]);
+                        journal.finish(repo)?;
                         return Err(e);
                     }
 
@@ -1141,10 +1425,32 @@ fn execute_switch(
                         );
                     }
 
-                    (false, None, Some(label))
+                    (false, None, Some(label), None)
                 }
             };
 
+            if let Some(head) = repo.worktree_at(&worktree_path).head_sha()? {
+                journal.record_head(repo, head)?;
+            }
+
+            // Apply `[worktree-git-config]` before any hook runs, so a
+            // pre-start hook that commits already uses it.
+            let git_config = config
+                .worktree_git_config(repo.project_identifier().ok().as_deref())
+                .entries();
+            if let Err(e) = repo.set_worktree_config(&worktree_path, &git_config) {
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!(
+                        "Could not apply <bold>worktree-git-config</>: {e:#}"
+                    ))
+                );
+            }
+
+            // Check out followed submodules before scaffolding and hooks, which
+            // may read from them.
+            follow_submodules(repo, &worktree_path, &branch, followed_submodules(repo));
+
             // Compute base worktree path for hooks and result.
             //
             // `git worktree add` already mutated the worktree list, but `repo`
@@ -1173,11 +1479,21 @@ fn execute_switch(
                 CreationMethod::Regular { .. } => (None, None),
             };
 
-            // Execute pre-start commands. `hook_repo` roots the render context
-            // in the new worktree (created just above); the commands come from
-            // the frozen `hook_plan`, selected at the gate from the invoking
-            // worktree's config.
+            let scaffold = repo
+                .project_config()?
+                .map(|project| &project.scaffold)
+                .filter(|scaffold| !scaffold.is_empty());
+
             if run_hooks {
+                SWITCH_PROGRESS.enter("pre-start");
+            }
+
+            // Instantiate `[scaffold]` files, then execute pre-start commands.
+            // `hook_repo` roots the render context in the new worktree
+            // (created just above); the commands come from the frozen
+            // `hook_plan`, selected at the gate from the invoking worktree's
+            // config, as is the scaffold list.
+            if run_hooks || scaffold.is_some() {
                 let hook_repo = Repository::at(&worktree_path)?;
                 let ctx =
                     CommandContext::new(&hook_repo, config, Some(&branch), &worktree_path, force);
@@ -1195,8 +1511,21 @@ fn execute_switch(
                         vars = vars.with_pr(Some(*number), Some(ref_url));
                     }
                 }
-                ctx.execute_pre_create_commands(&vars.as_extra_vars(), hook_plan, &worktree_path)?;
+                if let Some(scaffold) = scaffold {
+                    apply_scaffold(&ctx, scaffold, &vars.as_extra_vars());
+                }
+                if run_hooks {
+                    ctx.execute_pre_create_commands(
+                        &vars.as_extra_vars(),
+                        hook_plan,
+                        &worktree_path,
+                    )?;
+                }
             }
+            journal.finish(repo)?;
+            worktrunk::stats::record(StatsEvent::WorktreeCreated {
+                branch: branch.clone(),
+            });
 
             // Record successful switch in history
             let _ = repo.set_switch_previous(new_previous.as_deref());
@@ -1208,6 +1537,7 @@ fn execute_switch(
                     base_branch,
                     base_worktree_path,
                     from_remote,
+                    upstream,
                     pr_number,
                     pr_url,
                 },
@@ -1222,6 +1552,66 @@ fn execute_switch(
 
 /// Resolve the deferred path mismatch for existing worktree switches.
 ///
+/// Set the upstream of a branch just created with `--create`, and return it
+/// for display (e.g. `origin/feature`).
+///
+/// `track` unset keeps the long-standing default: a `--base pr:N` branch
+/// tracks the PR's source branch, so `git push` updates the PR (issue #2497),
+/// and any other branch tracks nothing — in particular not a remote-tracking
+/// base like `origin/main`, which would send `git push` to main (issue #713).
+fn set_new_branch_upstream(
+    repo: &Repository,
+    branch: &str,
+    base: Option<&str>,
+    pr_upstream: Option<&(String, String)>,
+    track: Option<TrackMode>,
+) -> anyhow::Result<Option<String>> {
+    let (remote, remote_branch) = match (track, pr_upstream) {
+        (Some(TrackMode::None), _) | (None, None) => return Ok(None),
+        (None | Some(TrackMode::Remote), Some((remote, remote_branch))) => {
+            (remote.clone(), remote_branch.clone())
+        }
+        (Some(TrackMode::Remote), None) => match repo.primary_remote() {
+            Ok(remote) => (remote, branch.to_string()),
+            Err(_) => {
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!(
+                        "No remote to track; <bold>{branch}</> has no upstream"
+                    ))
+                );
+                return Ok(None);
+            }
+        },
+        (Some(TrackMode::Base), _) => {
+            let Some(base) = base.filter(|base| {
+                repo.is_remote_tracking_branch(base)
+                    || repo.branch(base).exists_locally().unwrap_or(false)
+            }) else {
+                let base = base.unwrap_or("HEAD");
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!(
+                        "<bold>{base}</> isn't a branch, so <bold>{branch}</> can't track it"
+                    ))
+                );
+                return Ok(None);
+            };
+            repo.run_command(&["branch", &format!("--set-upstream-to={base}"), "--", branch])?;
+            return Ok(Some(base.to_string()));
+        }
+    };
+
+    // Config rather than `--set-upstream-to`, which refuses a remote branch
+    // that hasn't been pushed yet.
+    repo.set_config(&format!("branch.{branch}.remote"), &remote)?;
+    repo.set_config(
+        &format!("branch.{branch}.merge"),
+        &format!("refs/heads/{remote_branch}"),
+    )?;
+    Ok(Some(format!("{remote}/{remote_branch}")))
+}
+
 fn worktree_creation_error(
     err: &anyhow::Error,
     branch: String,
@@ -1268,18 +1658,22 @@ struct SwitchJsonOutput {
     /// Remote tracking branch if auto-created
     #[serde(skip_serializing_if = "Option::is_none")]
     from_remote: Option<String>,
+    /// Upstream set on a branch created with --create
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upstream: Option<String>,
 }
 
 impl SwitchJsonOutput {
     fn from_result(result: &SwitchResult, branch_info: &SwitchBranchInfo) -> Self {
-        let (action, path, created_branch, base_branch, from_remote) = match result {
-            SwitchResult::AlreadyAt(path) => ("already_at", path, None, None, None),
-            SwitchResult::Existing { path } => ("existing", path, None, None, None),
+        let (action, path, created_branch, base_branch, from_remote, upstream) = match result {
+            SwitchResult::AlreadyAt(path) => ("already_at", path, None, None, None, None),
+            SwitchResult::Existing { path } => ("existing", path, None, None, None, None),
             SwitchResult::Created {
                 path,
                 created_branch,
                 base_branch,
                 from_remote,
+                upstream,
                 ..
             } => (
                 "created",
@@ -1287,6 +1681,7 @@ impl SwitchJsonOutput {
                 Some(*created_branch),
                 base_branch.clone(),
                 from_remote.clone(),
+                upstream.clone(),
             ),
         };
         Self {
@@ -1296,6 +1691,7 @@ impl SwitchJsonOutput {
             created_branch,
             base_branch,
             from_remote,
+            upstream,
         }
     }
 }
@@ -1328,10 +1724,167 @@ struct SwitchOptions<'a> {
     clobber: bool,
     /// Resolved from --cd/--no-cd flags: Some(true) = cd, Some(false) = no cd, None = use config
     change_dir: Option<bool>,
+    /// Resolved from --tmux-window/--no-tmux-window flags, None = use config
+    tmux_window: Option<bool>,
+    /// Resolved from --track/--no-track flags, None = use config
+    track: Option<TrackMode>,
     verify: bool,
     format: crate::cli::SwitchFormat,
 }
 
+/// How a switch to a branch with an interrupted `--create` should proceed.
+enum InterruptedCreate {
+    /// No marker: switch as requested.
+    None,
+    /// The worktree stays (hooks re-run, or kept as is): switch to it.
+    Kept,
+    /// The partial worktree was removed: nothing left to switch to.
+    Removed,
+}
+
+/// Offer to finish or discard a worktree whose creation was interrupted.
+///
+/// Reads the marker [`CreateJournal`] leaves when `wt switch --create` dies
+/// between `git worktree add` and the end of its pre-start hooks. Interactive
+/// switches choose between re-running the pre-start hooks and removing the
+/// worktree (Enter keeps it as is); `--yes` re-runs the hooks; anything else
+/// warns and switches as requested. `--no-hooks` never runs them.
+///
+/// Runs before anything reads the worktree list, so removing the worktree
+/// here can't leave `repo`'s cached inventory stale for the rest of the
+/// switch — a removal ends the switch instead.
+fn resolve_interrupted_create(
+    repo: &Repository,
+    config: &UserConfig,
+    identifier: &str,
+    yes: bool,
+    verify: bool,
+) -> anyhow::Result<InterruptedCreate> {
+    let Some(journal) = repo
+        .resolve_worktree_name(identifier)
+        .ok()
+        .and_then(|branch| CreateJournal::load(repo, &branch))
+    else {
+        return Ok(InterruptedCreate::None);
+    };
+    let branch = journal.branch().to_string();
+    let path = journal.path.clone();
+
+    // Creation never got as far as the directory, or the worktree has since
+    // been removed by hand: nothing is half-initialized any more.
+    if !path.exists() {
+        journal.finish(repo)?;
+        return Ok(InterruptedCreate::None);
+    }
+
+    let path_display = worktrunk::path::format_path_for_display(&path);
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "Creating the worktree for <bold>{branch}</> @ <bold>{path_display}</> was interrupted before its pre-start hooks finished"
+        ))
+    );
+
+    // Force only past what creation itself left: anything since — edits, or
+    // commits on the branch — makes git's own checks refuse instead.
+    let untouched = partial_worktree_untouched(repo, &journal);
+
+    if !yes && !std::io::stdin().is_terminal() {
+        let hook_cmd = suggest_command_in_dir(&path, "hook", &["pre-start"], &[]);
+        let mut remove_flags = vec!["--foreground"];
+        if untouched {
+            remove_flags.push("--force");
+            if journal.created_branch {
+                remove_flags.push("-D");
+            }
+        }
+        let remove_cmd = suggest_command("remove", &[&branch], &remove_flags);
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To finish it, run <underline>{hook_cmd}</>; to discard it, run <underline>{remove_cmd}</>"
+            ))
+        );
+        return Ok(InterruptedCreate::None);
+    }
+
+    const RESUME: usize = 0;
+    const REMOVE: usize = 1;
+    let choice = if yes {
+        Some(RESUME)
+    } else {
+        let remove_label = if journal.created_branch {
+            cformat!("Remove the worktree and branch <bold>{branch}</>")
+        } else {
+            "Remove the worktree".to_string()
+        };
+        let options = format!("1. Run the pre-start hooks again\n2. {remove_label}");
+        eprintln!("{}", format_with_gutter(&options, None));
+        crate::output::prompt::prompt_choice("Finish or discard it? Enter keeps it as is", 2)?
+    };
+
+    match choice {
+        Some(REMOVE) => {
+            let path_str = path.to_string_lossy();
+            let mut remove_args = vec!["worktree", "remove"];
+            if untouched {
+                remove_args.push("--force");
+            }
+            remove_args.push(&path_str);
+            repo.run_command(&remove_args)
+                .context("Failed to remove the partial worktree")?;
+            if journal.created_branch {
+                let delete = if untouched { "-D" } else { "-d" };
+                repo.run_command(&["branch", delete, "--", &branch])
+                    .context("Failed to delete the partial worktree's branch")?;
+                worktrunk::git::forget_deleted_branch(repo, &branch);
+            }
+            journal.finish(repo)?;
+            eprintln!(
+                "{}",
+                success_message(cformat!(
+                    "Removed the partial worktree for <bold>{branch}</> @ <bold>{path_display}</>"
+                ))
+            );
+            return Ok(InterruptedCreate::Removed);
+        }
+        Some(_) if verify => {
+            // Select the hooks from the invoking worktree's config, as the
+            // interrupted `--create` did (see `approve_switch_hooks`).
+            let project_id = repo.project_identifier().ok();
+            let pid = project_id.as_deref();
+            let project_config = repo.load_project_config()?;
+            let mut builder = HookPlanBuilder::new(project_config.as_ref(), config, pid);
+            builder.add(&path, &[HookType::PreCreate]);
+            if let Some(hook_plan) = builder.finish().approve(pid, yes)? {
+                let hook_repo = Repository::at(&path)?;
+                let ctx = CommandContext::new(&hook_repo, config, Some(&branch), &path, yes);
+                let vars = TemplateVars::new()
+                    .with_target(&branch)
+                    .with_target_worktree_path(&path);
+                ctx.execute_pre_create_commands(&vars.as_extra_vars(), &hook_plan, &path)?;
+            }
+        }
+        _ => {}
+    }
+    journal.finish(repo)?;
+    Ok(InterruptedCreate::Kept)
+}
+
+/// Whether a partial worktree holds only what `git worktree add` put there: a
+/// clean tree, on a branch still at the commit the marker recorded.
+fn partial_worktree_untouched(repo: &Repository, journal: &CreateJournal) -> bool {
+    let Some(head) = journal.head.as_deref() else {
+        return false;
+    };
+    if repo.worktree_at(&journal.path).is_dirty().unwrap_or(true) {
+        return false;
+    }
+    let branch_ref = format!("refs/heads/{}", journal.branch());
+    repo.run_command(&["rev-parse", "--verify", "--quiet", &branch_ref])
+        .is_ok_and(|tip| tip.trim() == head)
+}
+
 /// Run pre-switch hooks before branch resolution or worktree creation.
 ///
 /// Symbolic arguments (`-`, `@`, `^`) are resolved to concrete branch names
@@ -1387,7 +1940,7 @@ fn run_pre_switch_hooks(
 ///
 /// Creates trigger pre-start + post-start + post-switch hooks;
 /// existing worktrees trigger only post-switch.
-fn switch_post_hook_types(is_create: bool) -> &'static [HookType] {
+pub(super) fn switch_post_hook_types(is_create: bool) -> &'static [HookType] {
     if is_create {
         &[
             HookType::PreCreate,
@@ -1446,7 +1999,7 @@ fn approve_switch_hooks(
 }
 
 /// Spawn post-switch (and post-start for creates) background hooks.
-fn spawn_switch_background_hooks(
+pub(super) fn spawn_switch_background_hooks(
     config: &UserConfig,
     result: &SwitchResult,
     branch: Option<&str>,
@@ -1492,7 +2045,7 @@ fn spawn_switch_background_hooks(
 /// `current_worktree()` would resolve to the recovered ancestor (typically
 /// the main worktree), which would misleadingly report main's branch/path
 /// as the user's "base".
-fn capture_switch_source(repo: &Repository, is_recovered: bool) -> (String, String) {
+pub(super) fn capture_switch_source(repo: &Repository, is_recovered: bool) -> (String, String) {
     if is_recovered {
         return (String::new(), String::new());
     }
@@ -1511,6 +2064,20 @@ fn capture_switch_source(repo: &Repository, is_recovered: bool) -> (String, Stri
     (source_branch, source_path)
 }
 
+/// `--progress-json` phases of a switch, in order. `pre-start` only runs on
+/// `--create`; skipped phases never announce.
+const SWITCH_PROGRESS: Phases = Phases::new(
+    "switch",
+    &[
+        ("pre-switch", "Running pre-switch hooks"),
+        ("resolve", "Resolving branch"),
+        ("switch", "Switching worktree"),
+        ("pre-start", "Running pre-start hooks"),
+        ("hooks", "Starting background hooks"),
+        ("execute", "Running --execute command"),
+    ],
+);
+
 /// The full switch sequence shared by the argument path ([`run_switch`]) and
 /// the interactive picker.
 ///
@@ -1545,6 +2112,12 @@ pub(crate) struct SwitchPipeline<'a> {
     /// `--yes`: skip approval prompts and force past clobber checks.
     pub yes: bool,
     pub change_dir: bool,
+    /// Open the worktree in a tmux window / Zellij tab named after the branch
+    /// instead of changing directory. No effect outside a multiplexer.
+    pub tmux_window: bool,
+    /// Upstream for a branch created with `create`. `None` falls back to
+    /// `[switch] track`.
+    pub track: Option<TrackMode>,
     pub format: SwitchFormat,
     /// True when `current_or_recover` recovered from a deleted CWD. Suppresses
     /// pre-switch hooks (no source worktree to run them against) and source
@@ -1581,6 +2154,8 @@ impl SwitchPipeline<'_> {
             verify,
             yes,
             change_dir,
+            tmux_window,
+            track,
             format,
             is_recovered,
             suggestion_ctx,
@@ -1594,12 +2169,22 @@ impl SwitchPipeline<'_> {
         // (.git, .bare) before anything reads worktree-path config.
         offer_bare_repo_worktree_path_fix(repo, config, identifier)?;
 
+        // A previous `--create` of this branch was interrupted: finish or
+        // discard the partial worktree first. Once it's kept, `--create`
+        // would only fail on the existing branch, so switch to it instead.
+        let create = match resolve_interrupted_create(repo, config, identifier, yes, verify)? {
+            InterruptedCreate::None => create,
+            InterruptedCreate::Kept => false,
+            InterruptedCreate::Removed => return Ok(()),
+        };
+
         // Run pre-switch hooks before branch resolution or worktree creation.
         // {{ branch }} receives the raw user input (before resolution). Skip
         // when recovered — the source worktree is gone, nothing to run hooks
         // against. `yes` is the single switch-wide flag, so the picker (no
         // `--yes`) and the argument path gate `pre-switch` hooks identically.
         if verify && !is_recovered {
+            SWITCH_PROGRESS.enter("pre-switch");
             run_pre_switch_hooks(repo, config, identifier, yes)?;
         }
 
@@ -1615,6 +2200,7 @@ impl SwitchPipeline<'_> {
         };
 
         // Validate and resolve the target branch.
+        SWITCH_PROGRESS.enter("resolve");
         let plan = plan_switch(repo, identifier, create, base, clobber, config).map_err(|err| {
             match suggestion_ctx {
                 Some(ref ctx) => match err.downcast::<GitError>() {
@@ -1629,6 +2215,21 @@ impl SwitchPipeline<'_> {
             }
         })?;
 
+        // A stale local base is brought up to date (if the user agrees) before
+        // anything is approved or created from it.
+        if let SwitchPlan::Create {
+            method:
+                CreationMethod::Regular {
+                    create_branch: true,
+                    base_branch: Some(base),
+                    ..
+                },
+            ..
+        } = &plan
+        {
+            offer_base_fast_forward(repo, base, yes)?;
+        }
+
         // "Approve at the Gate": collect and approve hooks upfront. Approval
         // happens once at the command entry point. If the user declines, skip
         // hooks but continue with the worktree operation. Switch hooks resolve
@@ -1642,8 +2243,23 @@ impl SwitchPipeline<'_> {
         validate_switch_templates(repo, config, &plan, execute, execute_args, hooks_approved)?;
 
         // Execute the validated plan.
+        SWITCH_PROGRESS.enter("switch");
+        let track = track.or(repo.config().switch.track);
         let (result, branch_info) =
-            execute_switch(repo, plan, config, yes, hooks_approved, &hook_plan)?;
+            execute_switch(repo, plan, config, track, yes, hooks_approved, &hook_plan)?;
+
+        // `[switch] auto-wip`: park the changes of the worktree being left and
+        // bring back any parked on the target. Leaving is skipped when
+        // recovered — the source worktree is gone.
+        if repo.config().switch.auto_wip() && !matches!(result, SwitchResult::AlreadyAt(_)) {
+            let source = repo.current_worktree();
+            if !is_recovered && source.root().is_ok_and(|root| root != *result.path()) {
+                save_wip(repo, &source);
+            }
+            if let Some(branch) = &branch_info.branch {
+                restore_wip(repo, result.path(), branch);
+            }
+        }
 
         // --format=json: write structured result to stdout. All behavior
         // (hooks, --execute, shell integration) proceeds normally — format only
@@ -1685,8 +2301,18 @@ impl SwitchPipeline<'_> {
         let fallback_path = repo.repo_path()?.to_path_buf();
         let cwd = std::env::current_dir().unwrap_or(fallback_path.clone());
         let source_root = repo.current_worktree().root().unwrap_or(fallback_path);
+        // In a multiplexer window the worktree gets its own shell, so the
+        // current pane stays where it is.
+        let multiplexer = tmux_window.then(Multiplexer::detect).flatten();
+        let change_dir = change_dir && multiplexer.is_none();
         let hooks_display_path =
             handle_switch_output(&result, &branch_info, change_dir, Some(&source_root), &cwd)?;
+        if let Some(multiplexer) = multiplexer {
+            open_multiplexer_window(multiplexer, &result, &branch_info);
+        }
+        if change_dir && repo.config().switch.terminal_title() {
+            terminal_title::set(&window_name(&result, &branch_info));
+        }
 
         // Offer shell integration if not already installed/active (only shows
         // the prompt/hint when shell integration isn't working). With
@@ -1716,6 +2342,7 @@ impl SwitchPipeline<'_> {
         //   won't be there)
         // - post-start: runs only when creating a NEW worktree
         if hooks_approved {
+            SWITCH_PROGRESS.enter("hooks");
             spawn_switch_background_hooks(
                 config,
                 &result,
@@ -1730,6 +2357,7 @@ impl SwitchPipeline<'_> {
         // Execute the user command after post-start hooks have been spawned.
         // Note: execute_args requires execute via clap's `requires` attribute.
         if let Some(cmd) = execute {
+            SWITCH_PROGRESS.enter("execute");
             // Build template context for expansion (includes base vars when
             // creating).
             let ctx = CommandContext::new(
@@ -1787,6 +2415,46 @@ impl SwitchPipeline<'_> {
     }
 }
 
+/// Name for the worktree's window, tab, or terminal title: the branch, or
+/// the directory name for a detached worktree.
+fn window_name(result: &SwitchResult, branch_info: &SwitchBranchInfo) -> String {
+    branch_info.branch.clone().unwrap_or_else(|| {
+        result
+            .path()
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    })
+}
+
+/// Focus or open the multiplexer window for a completed switch.
+///
+/// The switch itself has already succeeded, so a multiplexer failure is a
+/// warning rather than an error.
+fn open_multiplexer_window(
+    multiplexer: Multiplexer,
+    result: &SwitchResult,
+    branch_info: &SwitchBranchInfo,
+) {
+    let path = result.path();
+    let name = window_name(result, branch_info);
+    let label = multiplexer.window_label();
+    match multiplexer.open(&name, path) {
+        Ok(WindowAction::Opened) => eprintln!(
+            "{}",
+            success_message(cformat!("Opened {label} <bold>{name}</>"))
+        ),
+        Ok(WindowAction::Focused) => eprintln!(
+            "{}",
+            info_message(cformat!("Switched to {label} <bold>{name}</>"))
+        ),
+        Err(err) => eprintln!(
+            "{}",
+            warning_message(cformat!("Could not open {label} <bold>{name}</>: {err:#}"))
+        ),
+    }
+}
+
 /// Handle the switch command.
 fn run_switch(
     opts: SwitchOptions<'_>,
@@ -1802,6 +2470,8 @@ fn run_switch(
         yes,
         clobber,
         change_dir: change_dir_flag,
+        tmux_window: tmux_window_flag,
+        track,
         verify,
         format,
     } = opts;
@@ -1810,10 +2480,15 @@ fn run_switch(
 
     // Resolve change_dir: explicit CLI flags > project config > global config > default (true)
     // Now that we have the repo, we can resolve project-specific config.
-    let change_dir = change_dir_flag.unwrap_or_else(|| {
+    let switch_config = || {
         let project_id = repo.project_identifier().ok();
-        config.resolved(project_id.as_deref()).switch.cd()
-    });
+        config.resolved(project_id.as_deref()).switch
+    };
+    let change_dir = change_dir_flag.unwrap_or_else(|| switch_config().cd());
+    // Same precedence for tmux_window. `--execute` takes over the current
+    // pane, so the config default doesn't apply to it (the flag conflicts).
+    let tmux_window =
+        execute.is_none() && tmux_window_flag.unwrap_or_else(|| switch_config().tmux_window());
 
     // Build switch suggestion context for enriching error hints with --execute/trailing args.
     // Without this, errors like "branch already exists" would suggest `wt switch <branch>`
@@ -1836,6 +2511,8 @@ fn run_switch(
         verify,
         yes,
         change_dir,
+        tmux_window,
+        track,
         format,
         is_recovered,
         suggestion_ctx,
@@ -1851,6 +2528,16 @@ fn run_switch(
 pub fn handle_switch_command(args: SwitchArgs, yes: bool) -> anyhow::Result<()> {
     let verify = args.hooks.resolve();
 
+    if let Some(manifest) = &args.batch {
+        return super::batch::handle_switch_batch(super::batch::BatchOptions {
+            manifest,
+            jobs: args.jobs,
+            verify,
+            yes,
+            format: args.format,
+        });
+    }
+
     // With no branch argument, `wt switch` opens a TUI picker — config
     // deprecation warnings would render above the picker and push it down.
     // They're still shown by other commands (`wt list`, `wt merge`, …).
@@ -1863,6 +2550,7 @@ pub fn handle_switch_command(args: SwitchArgs, yes: bool) -> anyhow::Result<()>
         .and_then(|mut config| {
             // No branch argument: open interactive picker
             let change_dir_flag = flag_pair(args.cd, args.no_cd);
+            let tmux_window_flag = flag_pair(args.tmux_window, args.no_tmux_window);
 
             let Some(branch) = args.branch else {
                 // No branch argument: open the interactive picker.
@@ -1885,6 +2573,12 @@ pub fn handle_switch_command(args: SwitchArgs, yes: bool) -> anyhow::Result<()>
                     yes,
                     clobber: args.clobber,
                     change_dir: change_dir_flag,
+                    tmux_window: tmux_window_flag,
+                    track: if args.no_track {
+                        Some(TrackMode::None)
+                    } else {
+                        args.track
+                    },
                     verify,
                     format: args.format,
                 },
@@ -31,6 +31,9 @@ pub enum SwitchResult {
         base_worktree_path: Option<String>,
         /// Remote tracking branch if auto-created from remote (e.g., "origin/feature")
         from_remote: Option<String>,
+        /// Upstream set on a branch created with `--create` (e.g.,
+        /// "origin/feature"), per `--track` / `[switch] track`
+        upstream: Option<String>,
         /// PR/MR number when created via `pr:N` / `mr:N` (carried into post-* hook
         /// templates as `pr_number`).
         pr_number: Option<u32>,
@@ -271,6 +274,7 @@ mod tests {
             base_branch: Some("main".to_string()),
             base_worktree_path: Some("/test/main".to_string()),
             from_remote: None,
+            upstream: None,
             pr_number: None,
             pr_url: None,
         };
@@ -286,6 +290,7 @@ mod tests {
             base_branch: None,
             base_worktree_path: None,
             from_remote: Some("origin/feature".to_string()),
+            upstream: None,
             pr_number: None,
             pr_url: None,
         };
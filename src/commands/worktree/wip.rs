@@ -0,0 +1,94 @@
+//! `[switch] auto-wip` — park uncommitted changes as a commit across switches.
+//!
+//! Switching away from a dirty worktree commits everything, untracked files
+//! included, as a `wip:` commit on its branch, and records that commit as
+//! `worktrunk.state.<branch>.wip`. Switching back soft-resets it, so the
+//! changes return staged. The commit is only undone while it's still the
+//! branch's `HEAD`; after the branch moves on, the record is dropped and the
+//! commit stays like any other.
+//!
+//! Both halves are best-effort: failures are warnings and never block the
+//! switch.
+
+use std::path::Path;
+
+use color_print::cformat;
+use worktrunk::git::{Repository, WorkingTree};
+use worktrunk::styling::{eprintln, info_message, warning_message};
+
+/// Subject of the commit holding the parked changes.
+const WIP_MESSAGE: &str = "wip: saved by wt switch";
+
+/// Commit the uncommitted changes of the worktree the user is leaving.
+pub(crate) fn save_wip(repo: &Repository, source: &WorkingTree<'_>) {
+    let Ok(Some(branch)) = source.branch() else {
+        return;
+    };
+    match commit_wip(repo, source, &branch) {
+        Ok(Some(sha)) => {
+            let short = repo.short_sha(&sha).unwrap_or(sha);
+            eprintln!(
+                "{}",
+                info_message(cformat!(
+                    "Saved uncommitted changes on <bold>{branch}</> as WIP commit <bold>{short}</>"
+                ))
+            );
+        }
+        Ok(None) => {}
+        Err(e) => eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Could not save uncommitted changes on <bold>{branch}</>: {e:#}"
+            ))
+        ),
+    }
+}
+
+fn commit_wip(
+    repo: &Repository,
+    source: &WorkingTree<'_>,
+    branch: &str,
+) -> anyhow::Result<Option<String>> {
+    if source.operation_state()?.is_some() {
+        return Ok(None);
+    }
+    let Some(sha) = source.commit_wip(WIP_MESSAGE)? else {
+        return Ok(None);
+    };
+    repo.set_wip_commit(branch, &sha)?;
+    Ok(Some(sha))
+}
+
+/// Undo the WIP commit on `branch` in the worktree at `path`, if `wt switch`
+/// made one and it's still `HEAD`.
+pub(crate) fn restore_wip(repo: &Repository, path: &Path, branch: &str) {
+    let Some(sha) = repo.wip_commit(branch) else {
+        return;
+    };
+    match undo_wip(repo, path, branch, &sha) {
+        Ok(true) => eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Restored uncommitted changes on <bold>{branch}</> from WIP commit"
+            ))
+        ),
+        Ok(false) => {}
+        Err(e) => eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Could not restore WIP commit on <bold>{branch}</>: {e:#}"
+            ))
+        ),
+    }
+}
+
+/// Returns whether the WIP commit was undone; the record is dropped either way.
+fn undo_wip(repo: &Repository, path: &Path, branch: &str, sha: &str) -> anyhow::Result<bool> {
+    let wt = repo.worktree_at(path);
+    let at_wip = wt.head_sha()?.as_deref() == Some(sha);
+    if at_wip {
+        wt.undo_wip()?;
+    }
+    repo.clear_wip_commit(branch)?;
+    Ok(at_wip)
+}
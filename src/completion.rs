@@ -493,6 +493,12 @@ fn build_hook_completion_command(name: &'static str) -> Command {
                 .action(clap::ArgAction::SetTrue)
                 .help("Run in foreground (block until complete)"),
         )
+        .arg(
+            clap::Arg::new("all")
+                .long("all")
+                .action(clap::ArgAction::SetTrue)
+                .help("Run in every worktree"),
+        )
         .arg(
             clap::Arg::new("yes")
                 .short('y')
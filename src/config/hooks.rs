@@ -70,6 +70,10 @@ pub struct HooksConfig {
     )]
     pub post_merge: Option<CommandConfig>,
 
+    /// Commands to execute right before integrating into the target branch (blocking, fail-fast)
+    #[serde(default, rename = "pre-push", skip_serializing_if = "Option::is_none")]
+    pub pre_push: Option<CommandConfig>,
+
     /// Commands to execute before worktree removal (blocking, fail-fast)
     #[serde(
         default,
@@ -98,6 +102,7 @@ impl HooksConfig {
             HookType::PostCommit => self.post_commit.as_ref(),
             HookType::PreMerge => self.pre_merge.as_ref(),
             HookType::PostMerge => self.post_merge.as_ref(),
+            HookType::PrePush => self.pre_push.as_ref(),
             HookType::PreRemove => self.pre_remove.as_ref(),
             HookType::PostRemove => self.post_remove.as_ref(),
         }
@@ -133,6 +138,7 @@ impl Merge for HooksConfig {
             post_commit: merge_append_hooks(&self.post_commit, &other.post_commit),
             pre_merge: merge_append_hooks(&self.pre_merge, &other.pre_merge),
             post_merge: merge_append_hooks(&self.post_merge, &other.post_merge),
+            pre_push: merge_append_hooks(&self.pre_push, &other.pre_push),
             pre_remove: merge_append_hooks(&self.pre_remove, &other.pre_remove),
             post_remove: merge_append_hooks(&self.post_remove, &other.post_remove),
         }
@@ -0,0 +1,254 @@
+//! `include` in project config — shared team config pulled into `.config/wt.toml`.
+//!
+//! ```toml
+//! include = ["../shared/wt-common.toml", "https://example.com/wt-team.toml"]
+//! ```
+//!
+//! Included files load first, in order, and the project's own file goes on
+//! top: tables merge key by key, and any other value in a later file replaces
+//! the earlier one. Included files can't include others.
+//!
+//! Local paths resolve against the including file's directory. Remote
+//! includes are never fetched while loading: `wt config approvals add`
+//! fetches each one, shows it, and on approval stores the content under
+//! `.git/wt/includes/<hash>.toml` with the URL's hash in `approvals.toml`.
+//! Loading reads that stored copy, so an upstream change takes effect only
+//! once it's approved in turn. An unapproved remote include is skipped with a
+//! warning.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+use color_print::cformat;
+
+use super::ConfigError;
+use super::approvals::{Approvals, command_hash};
+use crate::git::Repository;
+use crate::path::format_path_for_display;
+use crate::styling::{eprintln, hint_message, warning_message};
+
+/// Remote includes already warned about this process.
+static WARNED_UNAPPROVED: LazyLock<Mutex<HashSet<String>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Whether an include entry names a URL rather than a file.
+pub fn is_remote_include(spec: &str) -> bool {
+    spec.starts_with("https://") || spec.starts_with("http://")
+}
+
+/// Hash identifying an include's content in `approvals.toml`.
+///
+/// The same hash as [`command_hash`], so `--approve <hash>` covers includes
+/// too.
+pub fn include_hash(content: &str) -> String {
+    command_hash(content)
+}
+
+/// Where the approved copy of an include with `hash` is stored.
+pub fn stored_include_path(repo: &Repository, hash: &str) -> PathBuf {
+    repo.wt_state_dir()
+        .join("includes")
+        .join(format!("{hash}.toml"))
+}
+
+/// Store `content` as an approved include, returning its hash.
+pub fn store_include(repo: &Repository, content: &str) -> std::io::Result<String> {
+    let hash = include_hash(content);
+    let path = stored_include_path(repo, &hash);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, content)?;
+    Ok(hash)
+}
+
+/// The remote entries of the project config's `include`, in order.
+pub fn remote_includes(repo: &Repository) -> Result<Vec<String>, ConfigError> {
+    let Some(config_path) = repo
+        .project_config_path()
+        .map_err(|e| ConfigError(format!("Failed to get config path: {}", e)))?
+        .filter(|path| path.exists())
+    else {
+        return Ok(Vec::new());
+    };
+    let contents = std::fs::read_to_string(&config_path)
+        .map_err(|e| ConfigError(format!("Failed to read config file: {}", e)))?;
+    let Ok(table) = contents.parse::<toml::Table>() else {
+        // Loading the config reports the parse error
+        return Ok(Vec::new());
+    };
+    Ok(include_specs(&table)
+        .into_iter()
+        .filter(|spec| is_remote_include(spec))
+        .collect())
+}
+
+/// The `include` entries of a parsed config. Anything other than an array of
+/// strings is left for deserialization to reject.
+fn include_specs(table: &toml::Table) -> Vec<String> {
+    table
+        .get("include")
+        .and_then(toml::Value::as_array)
+        .map(|specs| {
+            specs
+                .iter()
+                .filter_map(|spec| spec.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Layer `config` (the project file at `config_path`) over its includes.
+///
+/// Returns `None` when the file has no `include`, so the caller can keep
+/// parsing the original text (and its error positions).
+pub(super) fn apply_includes(
+    repo: &Repository,
+    config_path: &Path,
+    config: &str,
+) -> Result<Option<toml::Table>, ConfigError> {
+    let Ok(table) = config.parse::<toml::Table>() else {
+        return Ok(None);
+    };
+    let specs = include_specs(&table);
+    if specs.is_empty() {
+        return Ok(None);
+    }
+
+    let base_dir = config_path.parent().unwrap_or(Path::new("."));
+    let mut merged = toml::Table::new();
+    for spec in &specs {
+        let Some(content) = read_include(repo, base_dir, spec)? else {
+            continue;
+        };
+        let included: toml::Table = content.parse().map_err(|e| {
+            ConfigError(format!(
+                "Project config include {spec} failed to parse:\n{e}"
+            ))
+        })?;
+        if included.contains_key("include") {
+            return Err(ConfigError(format!(
+                "Project config include {spec} has its own include; included files can't include others"
+            )));
+        }
+        merge_table(&mut merged, included);
+    }
+    merge_table(&mut merged, table);
+    Ok(Some(merged))
+}
+
+/// The content of one include, or `None` for a remote include that isn't
+/// approved (after warning about it).
+fn read_include(
+    repo: &Repository,
+    base_dir: &Path,
+    spec: &str,
+) -> Result<Option<String>, ConfigError> {
+    if !is_remote_include(spec) {
+        let path = base_dir.join(spec);
+        return std::fs::read_to_string(&path).map(Some).map_err(|e| {
+            ConfigError(format!(
+                "Failed to read project config include {}: {e}",
+                format_path_for_display(&path)
+            ))
+        });
+    }
+
+    let approved = repo.project_identifier().ok().and_then(|project_id| {
+        let approvals = Approvals::load().ok()?;
+        let hash = approvals.approved_include_hash(&project_id, spec)?;
+        let content = std::fs::read_to_string(stored_include_path(repo, hash)).ok()?;
+        // A stored copy edited since approval no longer counts
+        (include_hash(&content) == hash).then_some(content)
+    });
+    if approved.is_none() {
+        warn_unapproved(spec);
+    }
+    Ok(approved)
+}
+
+fn warn_unapproved(url: &str) {
+    if super::deprecation::warnings_suppressed()
+        || !WARNED_UNAPPROVED.lock().unwrap().insert(url.to_string())
+    {
+        return;
+    }
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "Project config include <bold>{url}</> isn't approved (will be ignored)"
+        ))
+    );
+    eprintln!(
+        "{}",
+        hint_message(cformat!(
+            "To review it, run <bright-black>wt config approvals add</>"
+        ))
+    );
+}
+
+/// Merge `overlay` into `base`: tables merge recursively, other values replace.
+fn merge_table(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(table)) => {
+                merge_table(existing, table);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_table() {
+        let mut base: toml::Table = toml::from_str(
+            r#"
+pre-start = "npm ci"
+[post-start]
+server = "npm run dev"
+docs = "mkdocs serve"
+[list]
+url = "http://localhost:3000"
+"#,
+        )
+        .unwrap();
+        let overlay: toml::Table = toml::from_str(
+            r#"
+pre-start = "pnpm install"
+[post-start]
+docs = "make docs"
+[aliases]
+deploy = "make deploy"
+"#,
+        )
+        .unwrap();
+        merge_table(&mut base, overlay);
+        assert_eq!(base["pre-start"].as_str(), Some("pnpm install"));
+        assert_eq!(base["post-start"]["server"].as_str(), Some("npm run dev"));
+        assert_eq!(base["post-start"]["docs"].as_str(), Some("make docs"));
+        assert_eq!(base["list"]["url"].as_str(), Some("http://localhost:3000"));
+        assert_eq!(base["aliases"]["deploy"].as_str(), Some("make deploy"));
+    }
+
+    #[test]
+    fn test_include_specs() {
+        let table: toml::Table =
+            toml::from_str(r#"include = ["a.toml", "https://example.com/b.toml"]"#).unwrap();
+        assert_eq!(
+            include_specs(&table),
+            ["a.toml", "https://example.com/b.toml"]
+        );
+        assert!(!is_remote_include("a.toml"));
+        assert!(is_remote_include("https://example.com/b.toml"));
+
+        let table: toml::Table = toml::from_str(r#"include = "a.toml""#).unwrap();
+        assert!(include_specs(&table).is_empty());
+    }
+}
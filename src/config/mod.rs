@@ -18,6 +18,7 @@ mod commands;
 pub(crate) mod deprecation;
 mod expansion;
 mod hooks;
+mod include;
 mod project;
 #[cfg(test)]
 mod test;
@@ -124,7 +125,10 @@ pub(crate) fn schema_top_level_keys<T: schemars::JsonSchema>() -> Vec<String> {
 }
 
 // Re-export public types
-pub use approvals::{Approvals, approvals_path, require_approvals_path};
+pub use approvals::{
+    Approvals, ApprovalsSnippet, SnippetCommand, approvals_path, command_hash,
+    require_approvals_path,
+};
 pub use commands::{Command, CommandConfig, HookStep, append_aliases};
 pub use deprecation::CheckAndMigrateResult;
 pub use deprecation::DeprecationInfo;
@@ -144,17 +148,19 @@ pub use deprecation::{
 };
 pub use deprecation::{DeprecationKind, Deprecations};
 pub use expansion::{
-    ACTIVE_VARS, ALIAS_ARGS_KEY, DEPRECATED_TEMPLATE_VARS, EXEC_BASE_VARS, REPO_VARS,
-    TemplateExpandError, ValidationScope, alias_context_filter, base_vars, expand_template,
-    format_alias_variables, format_hook_variables, redact_credentials, referenced_vars_for_config,
-    sanitize_branch_name, sanitize_db, short_hash, template_environment, template_references_var,
-    validate_list_column_template, validate_template, validate_template_syntax, vars_available_in,
-    vars_map_to_value,
+    ACTIVE_VARS, ALIAS_ARGS_KEY, CHANGED_FILES_KEY, DEPRECATED_TEMPLATE_VARS, EXEC_BASE_VARS,
+    JJ_VARS, REPO_VARS, TemplateExpandError, ValidationScope, alias_context_filter, base_vars,
+    expand_template, format_alias_variables, format_hook_variables, redact_credentials,
+    referenced_vars_for_config, sanitize_branch_name, sanitize_db, short_hash,
+    template_environment, template_references_var, validate_list_column_template,
+    validate_template, validate_template_syntax, vars_available_in, vars_map_to_value,
 };
 pub use hooks::HooksConfig;
+pub use include::{is_remote_include, remote_includes, store_include, stored_include_path};
 pub use project::{
-    ProjectCiConfig, ProjectCommitConfig, ProjectCommitGenerationConfig, ProjectConfig,
-    ProjectListConfig, valid_project_config_keys,
+    CommitScopeConfig, ProjectCiConfig, ProjectCommitConfig, ProjectCommitGenerationConfig,
+    ProjectConfig, ProjectListColumnConfig, ProjectListConfig, ProjectSubmodulesConfig,
+    ProjectSwitchConfig, valid_project_config_keys,
 };
 pub use unknown_tree::{
     UnknownAnalysis, UnknownTree, UnknownWarning, collect_unknown_warnings, compute_unknown_tree,
@@ -162,8 +168,10 @@ pub use unknown_tree::{
 pub(crate) use user::LoadError;
 pub use user::{
     CommitConfig, CommitGenerationConfig, CopyIgnoredConfig, ListColumnConfig, ListConfig,
-    MergeConfig, RemoveConfig, ResolvedConfig, StageMode, StepConfig, SwitchConfig,
-    SwitchPickerConfig, UserConfig, UserProjectOverrides, config_path, config_path_for_display,
+    LogsConfig, MergeConfig, MergeVerify, OpenConfig, OutputConfig, PruneConfig, PruneRule,
+    RebaseOthers, RemoteConfig, RemoveConfig, ResolvedConfig, SandboxConfig, StageMode,
+    StatsConfig, StepConfig, SwitchConfig, SwitchPickerConfig, ThemeConfig, TrackMode, UserConfig,
+    UserProjectOverrides, WorktreeGitConfig, config_path, config_path_for_display,
     default_config_path, default_system_config_path, require_config_path, set_config_overrides,
     set_config_path, system_config_path, valid_user_config_keys,
 };
@@ -560,6 +568,9 @@ task2 = "echo 'Task 2 running' > task2.txt"
             squash_template: None,
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
 
         assert_snapshot!(toml::to_string(&config).unwrap(), @r#"
@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 use super::ConfigError;
 use super::commands::CommandConfig;
 use super::is_default;
-use super::{CopyIgnoredConfig, HooksConfig, StepConfig};
+use super::{CopyIgnoredConfig, HooksConfig, PruneConfig, StepConfig};
 
 /// Project-level configuration for `wt list` output.
 ///
@@ -22,6 +22,9 @@ use super::{CopyIgnoredConfig, HooksConfig, StepConfig};
 /// ```toml
 /// [list]
 /// url = "http://localhost:{{ branch | hash_port }}"
+///
+/// [list.columns.TODOs]
+/// command = "git grep -c TODO | wc -l"
 /// ```
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
 pub struct ProjectListConfig {
@@ -35,6 +38,43 @@ pub struct ProjectListConfig {
     /// listening, normal if it is.
     #[serde(default)]
     pub url: Option<String>,
+
+    /// Columns computed by running a command in each worktree, keyed by
+    /// header text. See [`ProjectListColumnConfig`].
+    ///
+    /// *(Experimental — fields may change in future releases.)*
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub columns: BTreeMap<String, ProjectListColumnConfig>,
+}
+
+/// A `wt list` column filled by a shell command.
+///
+/// The command runs in each worktree's directory after the table appears;
+/// the first line of its output is the cell text. Like hooks, the command
+/// needs approval before it runs.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+pub struct ProjectListColumnConfig {
+    /// Shell command whose first output line is the cell text
+    pub command: String,
+
+    /// Column width; longer values truncate (default: 10). Values below the
+    /// header's width are raised to it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<usize>,
+
+    /// Drop order when the terminal narrows; lower = kept longer (default: 9,
+    /// alongside the URL column; built-in columns range 0-15)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+
+    /// Milliseconds before the command is killed and its cell left empty
+    /// (default: 5000)
+    #[serde(
+        default,
+        rename = "timeout-ms",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub timeout_ms: Option<u64>,
 }
 
 /// Project-level CI configuration.
@@ -71,6 +111,90 @@ pub struct ProjectCommitConfig {
     /// Commit message generation settings shared across the team.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub generation: Option<ProjectCommitGenerationConfig>,
+
+    /// Conventional-commit scope inference for `wt step commit`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<CommitScopeConfig>,
+}
+
+/// Conventional-commit scope inferred from where a commit's changes are.
+///
+/// The scope is that of the directory holding most of the changed lines:
+/// a directory listed in `map` takes its mapped scope (the longest listed
+/// directory wins), any other path its top-level directory's name. Changes
+/// to files at the repository root compete as "no scope".
+///
+/// ```toml
+/// [commit.scope]
+/// fallback-type = "chore"
+///
+/// [commit.scope.map]
+/// "apps/web" = "web"
+/// "crates/worktrunk-cli" = "cli"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
+pub struct CommitScopeConfig {
+    /// Directory → scope, for directories not named after their scope
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub map: BTreeMap<String, String>,
+
+    /// Commit type prefixed to the message written without an LLM
+    /// (default: `chore`)
+    #[serde(
+        default,
+        rename = "fallback-type",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub fallback_type: Option<String>,
+}
+
+impl CommitScopeConfig {
+    /// Commit type for the fallback message.
+    pub fn fallback_type(&self) -> &str {
+        self.fallback_type.as_deref().unwrap_or("chore")
+    }
+
+    /// The scope of the directory with the most changed lines, given each
+    /// changed path and its changed-line count. Ties go to the scope that
+    /// sorts first. Scopes are kept to letters, digits, `-`, `_`, `.`, and
+    /// `/` — they reach the LLM prompt, so anything else is passed over.
+    pub fn infer<'a>(&self, changes: impl IntoIterator<Item = (&'a str, usize)>) -> Option<String> {
+        let mut weights: BTreeMap<Option<&str>, usize> = BTreeMap::new();
+        for (path, lines) in changes {
+            *weights.entry(self.scope_of(path)).or_default() += lines.max(1);
+        }
+        let mut best: Option<(Option<&str>, usize)> = None;
+        for (scope, weight) in weights {
+            if best.is_none_or(|(_, most)| weight > most) {
+                best = Some((scope, weight));
+            }
+        }
+        best.and_then(|(scope, _)| scope).map(str::to_string)
+    }
+
+    /// The scope for one path: the longest mapped directory containing it,
+    /// else its top-level directory. `None` for a root-level file.
+    fn scope_of<'a>(&'a self, path: &'a str) -> Option<&'a str> {
+        let mapped = self
+            .map
+            .iter()
+            .filter(|(dir, _)| {
+                let dir = dir.trim_end_matches('/');
+                path.strip_prefix(dir)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+            .max_by_key(|(dir, _)| dir.trim_end_matches('/').len())
+            .map(|(_, scope)| scope.as_str());
+        let scope = match mapped {
+            Some(scope) => scope,
+            None => path.split_once('/')?.0,
+        };
+        let valid = !scope.is_empty()
+            && scope
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'));
+        valid.then_some(scope)
+    }
 }
 
 /// Project-level commit message generation settings.
@@ -125,10 +249,46 @@ pub struct ProjectForgeConfig {
     pub hostname: Option<String>,
 }
 
+/// Project-level submodule configuration.
+///
+/// Followed submodules are initialized in each new worktree and checked out
+/// on a branch named after the worktree's branch, so a feature spans the
+/// parent and its submodules without per-worktree juggling.
+///
+/// # Example
+///
+/// ```toml
+/// [submodules]
+/// follow = ["libs/core", "libs/ui"]
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
+pub struct ProjectSubmodulesConfig {
+    /// Submodule paths, relative to the repository root.
+    #[serde(default)]
+    pub follow: Vec<String>,
+}
+
+/// Project-level `wt switch` configuration.
+///
+/// # Example
+///
+/// ```toml
+/// [switch]
+/// max-base-behind = 20
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
+pub struct ProjectSwitchConfig {
+    /// Offer to fast-forward a local base before `wt switch --create` when it
+    /// is more than this many commits behind its upstream. A user config
+    /// `[switch] max-base-behind` takes precedence.
+    #[serde(default, rename = "max-base-behind")]
+    pub max_base_behind: Option<u32>,
+}
+
 impl ProjectListConfig {
     /// Returns true if any list configuration is set.
     pub fn is_configured(&self) -> bool {
-        self.url.is_some()
+        self.url.is_some() || !self.columns.is_empty()
     }
 }
 
@@ -158,6 +318,16 @@ impl ProjectConfig {
         self.step.copy_ignored.as_ref()
     }
 
+    /// Get `wt step prune` rules if configured.
+    pub fn prune(&self) -> Option<&PruneConfig> {
+        self.step.prune.as_ref()
+    }
+
+    /// Conventional-commit scope inference, if `[commit.scope]` is set.
+    pub fn commit_scope(&self) -> Option<&CommitScopeConfig> {
+        self.commit.scope.as_ref()
+    }
+
     /// Project-level commit-message append fragment (trimmed, empty
     /// treated as unset).
     ///
@@ -196,15 +366,32 @@ impl ProjectConfig {
 /// - `{{ remote }}` - Primary remote name (e.g., "origin")
 /// - `{{ upstream }}` - Upstream tracking branch (e.g., "origin/feature"), if configured
 ///
-/// Merge-related hooks (`pre-commit`, `pre-merge`, `post-merge`) also support:
+/// Merge-related hooks (`pre-commit`, `pre-merge`, `pre-push`, `post-merge`) also support:
 /// - `{{ target }}` - Target branch for the merge (e.g., "main")
 ///
+/// `pre-push` additionally supports:
+/// - `{{ target_commit }}` - Target branch SHA before the push
+/// - `{{ commit_range }}` - Commits being pushed (`target_commit..commit`)
+///
 /// # Filters
 ///
 /// - `{{ branch | sanitize }}` - Replace `/` and `\` with `-` (e.g., "feature-auth")
 /// - `{{ branch | hash_port }}` - Hash string to deterministic port (10000-19999)
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
 pub struct ProjectConfig {
+    /// Shared config files layered under this one: paths relative to this
+    /// file, or `https://` URLs.
+    ///
+    /// Included files load in order, then this file on top; tables merge key
+    /// by key. Remote includes take effect once approved with
+    /// `wt config approvals add`.
+    ///
+    /// ```toml
+    /// include = ["../shared/wt-common.toml", "https://example.com/wt-team.toml"]
+    /// ```
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+
     /// Project hooks (same keys as user hooks, flattened at top level)
     #[serde(flatten, default)]
     pub hooks: HooksConfig,
@@ -244,6 +431,30 @@ pub struct ProjectConfig {
     /// ```
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub aliases: BTreeMap<String, CommandConfig>,
+
+    /// Files to instantiate into each new worktree.
+    ///
+    /// Maps a destination (relative to the new worktree) to a template file
+    /// (relative to the new worktree, or absolute). Template paths and
+    /// contents support the hook template variables. Files are written after
+    /// the worktree is created and before pre-start hooks run; existing
+    /// destinations are left alone.
+    ///
+    /// ```toml
+    /// [scaffold]
+    /// ".env" = ".env.example"
+    /// ".vscode/settings.json" = "{{ primary_worktree_path }}/.vscode/settings.json"
+    /// ```
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub scaffold: BTreeMap<String, String>,
+
+    /// Submodules that follow the worktree's branch
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub submodules: ProjectSubmodulesConfig,
+
+    /// Configuration for `wt switch`
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub switch: ProjectSwitchConfig,
 }
 
 impl ProjectConfig {
@@ -296,12 +507,17 @@ impl ProjectConfig {
 
         // Deserialize the structurally migrated content so deprecated keys
         // (e.g. `pre-start`/`post-start`) still load into their canonical fields.
-        let config: ProjectConfig = toml::from_str(&migrated).map_err(|e| {
+        let parse_error = |e: toml::de::Error| {
             ConfigError(format!(
                 "Project config at {} failed to parse:\n{e}",
                 crate::path::format_path_for_display(&config_path),
             ))
-        })?;
+        };
+        let config: ProjectConfig =
+            match super::include::apply_includes(repo, &config_path, &migrated)? {
+                Some(merged) => merged.try_into().map_err(parse_error)?,
+                None => toml::from_str(&migrated).map_err(parse_error)?,
+            };
 
         Ok(Some(config))
     }
@@ -346,6 +562,13 @@ post-remove = "echo removed"
         assert!(config.hooks.post_remove.is_some());
     }
 
+    #[test]
+    fn test_deserialize_switch_max_base_behind() {
+        let config: ProjectConfig = toml::from_str("[switch]\nmax-base-behind = 20\n").unwrap();
+        assert_eq!(config.switch.max_base_behind, Some(20));
+        assert_eq!(ProjectConfig::default().switch.max_base_behind, None);
+    }
+
     // ============================================================================
     // ListConfig Tests
     // ============================================================================
@@ -364,6 +587,28 @@ url = "http://localhost:{{ branch | hash_port }}"
         assert!(config.list.is_configured());
     }
 
+    #[test]
+    fn test_deserialize_list_columns() {
+        let contents = r#"
+[list.columns.TODOs]
+command = "git grep -c TODO | wc -l"
+timeout-ms = 1000
+
+[list.columns.Tests]
+command = "ls tests | wc -l"
+width = 6
+priority = 3
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert!(config.list.is_configured());
+        let todos = &config.list.columns["TODOs"];
+        assert_eq!(todos.command, "git grep -c TODO | wc -l");
+        assert_eq!(todos.timeout_ms, Some(1000));
+        assert_eq!(todos.width, None);
+        let tests = &config.list.columns["Tests"];
+        assert_eq!((tests.width, tests.priority), (Some(6), Some(3)));
+    }
+
     #[test]
     fn test_deserialize_list_empty() {
         let contents = r#"
@@ -387,6 +632,27 @@ exclude = [".conductor/", ".entire/"]
         );
     }
 
+    #[test]
+    fn test_deserialize_step_prune_rules() {
+        let contents = r#"
+[[step.prune.rules]]
+branch = "release/*"
+keep = true
+
+[[step.prune.rules]]
+branch = "dependabot/*"
+min-age = "0s"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        let rules = &config.prune().unwrap().rules;
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].branch, "release/*");
+        assert!(rules[0].keep);
+        assert_eq!(rules[0].min_age, None);
+        assert!(!rules[1].keep);
+        assert_eq!(rules[1].min_age.as_deref(), Some("0s"));
+    }
+
     // ============================================================================
     // CiConfig Tests
     // ============================================================================
@@ -564,4 +830,46 @@ template-append = "  - a\n  - b  "
         let config: ProjectConfig = toml::from_str(toml).unwrap();
         assert_eq!(config.commit_template_append(), None);
     }
+
+    #[test]
+    fn test_commit_scope_infer() {
+        let toml = r#"
+[commit.scope.map]
+"apps/web" = "web"
+"apps/web/legacy" = "legacy"
+"#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        let scope = config.commit_scope().unwrap();
+        assert_eq!(scope.fallback_type(), "chore");
+
+        // Mapped directories, longest first; others by top-level directory
+        assert_eq!(
+            scope.infer([("apps/web/src/app.tsx", 10)]).as_deref(),
+            Some("web")
+        );
+        assert_eq!(
+            scope.infer([("apps/web/legacy/old.js", 10)]).as_deref(),
+            Some("legacy")
+        );
+        assert_eq!(
+            scope.infer([("apps/webhooks/a.rs", 1)]).as_deref(),
+            Some("apps")
+        );
+        // Most changed lines wins, not most files
+        assert_eq!(
+            scope
+                .infer([("docs/a.md", 1), ("docs/b.md", 1), ("apps/web/x.ts", 5)])
+                .as_deref(),
+            Some("web")
+        );
+        // Root-level files compete as "no scope"; ties go to the first scope
+        assert_eq!(scope.infer([("README.md", 20), ("src/lib.rs", 3)]), None);
+        assert_eq!(
+            scope.infer([("src/a.rs", 2), ("docs/a.md", 2)]).as_deref(),
+            Some("docs")
+        );
+        assert_eq!(scope.infer([]), None);
+        // Scopes that aren't plain names never reach the prompt
+        assert_eq!(scope.infer([("my dir/a.rs", 1)]), None);
+    }
 }
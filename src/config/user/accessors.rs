@@ -13,8 +13,8 @@ use crate::shell_exec::ShellEscapeMode;
 use super::UserConfig;
 use super::merge::Merge;
 use super::sections::{
-    CommitConfig, CommitGenerationConfig, CopyIgnoredConfig, ListConfig, MergeConfig, RemoveConfig,
-    StepConfig, SwitchConfig, SwitchPickerConfig,
+    CommitConfig, CommitGenerationConfig, CopyIgnoredConfig, ListConfig, MergeConfig, RemoteConfig,
+    RemoveConfig, StepConfig, SwitchConfig, SwitchPickerConfig, WorktreeGitConfig,
 };
 
 /// Default worktree path template
@@ -42,6 +42,11 @@ impl UserConfig {
         }
     }
 
+    /// Whether network features are off (`network = false` or `--offline`).
+    pub fn offline(&self) -> bool {
+        self.network == Some(false)
+    }
+
     /// Returns the worktree path template, falling back to the default if not set.
     pub fn worktree_path(&self) -> String {
         self.worktree_path
@@ -49,6 +54,36 @@ impl UserConfig {
             .unwrap_or_else(default_worktree_path)
     }
 
+    /// Returns the configured `state-dir`, with `~` expanded.
+    ///
+    /// `None` keeps logs and caches in the git directory.
+    pub fn state_dir(&self) -> Option<std::path::PathBuf> {
+        self.state_dir
+            .as_deref()
+            .map(|dir| std::path::PathBuf::from(shellexpand::tilde(dir).as_ref()))
+    }
+
+    /// The repositories in `[repo-groups] <group>`, as (entry, path) pairs in
+    /// the order listed; `None` when the group isn't defined.
+    ///
+    /// An entry names a `[repos]` entry or is itself a path; `~` is expanded
+    /// either way.
+    pub fn repo_group(&self, group: &str) -> Option<Vec<(String, std::path::PathBuf)>> {
+        let entries = self.repo_groups.get(group)?;
+        Some(
+            entries
+                .iter()
+                .map(|entry| {
+                    let path = self.repos.get(entry).unwrap_or(entry);
+                    (
+                        entry.clone(),
+                        std::path::PathBuf::from(shellexpand::tilde(path).as_ref()),
+                    )
+                })
+                .collect(),
+        )
+    }
+
     /// Returns true if the user has explicitly set a custom global worktree-path.
     pub fn has_custom_worktree_path(&self) -> bool {
         self.worktree_path.is_some()
@@ -79,15 +114,22 @@ impl UserConfig {
     /// settings take precedence for fields that are set. Deprecated
     /// `[commit-generation]` sections are normalized into `[commit.generation]`
     /// during config loading.
+    ///
+    /// Offline, the command is dropped, so every caller falls back to its
+    /// deterministic message.
     pub fn commit_generation(&self, project: Option<&str>) -> CommitGenerationConfig {
         let global = self.commit.generation.clone().unwrap_or_default();
-        match self
+        let mut config = match self
             .project_overrides(project)
             .and_then(|config| config.commit.generation.as_ref())
         {
             Some(proj) => global.merge_with(proj),
             None => global,
+        };
+        if self.offline() {
+            config.command = None;
         }
+        config
     }
 
     /// Returns the list config for a specific project.
@@ -135,6 +177,18 @@ impl UserConfig {
         self.merged_project_config(project, &self.step, |config| &config.step)
     }
 
+    /// Returns the `[remote]` config for a specific project.
+    pub fn remote(&self, project: Option<&str>) -> RemoteConfig {
+        self.merged_project_config(project, &self.remote, |config| &config.remote)
+    }
+
+    /// Returns the `[worktree-git-config]` settings for a specific project.
+    pub fn worktree_git_config(&self, project: Option<&str>) -> WorktreeGitConfig {
+        self.merged_project_config(project, &self.worktree_git_config, |config| {
+            &config.worktree_git_config
+        })
+    }
+
     /// Returns the `wt step copy-ignored` config for a specific project.
     pub fn copy_ignored(&self, project: Option<&str>) -> CopyIgnoredConfig {
         self.step(project).copy_ignored.unwrap_or_default()
@@ -224,6 +278,11 @@ impl UserConfig {
         if let Some(ref owner) = owner {
             vars.insert("owner", owner.as_str());
         }
+        // The new worktree doesn't exist yet, so there's no jj workspace to
+        // describe; defined empty so templates shared with hooks render
+        for var in crate::config::JJ_VARS {
+            vars.insert(var, "");
+        }
         Ok(expand_template(
             &template,
             &vars,
@@ -51,8 +51,10 @@ pub use resolved::ResolvedConfig;
 pub use schema::valid_user_config_keys;
 pub use sections::{
     CommitConfig, CommitGenerationConfig, CopyIgnoredConfig, ListColumnConfig, ListConfig,
-    MergeConfig, RemoveConfig, StageMode, StepConfig, SwitchConfig, SwitchPickerConfig,
-    UserProjectOverrides,
+    LogsConfig, MergeConfig, MergeVerify, OpenConfig, OutputConfig, PruneConfig, PruneRule,
+    RebaseOthers, RemoteConfig, RemoveConfig, SandboxConfig, StageMode, StatsConfig, StepConfig,
+    SwitchConfig, SwitchPickerConfig, ThemeConfig, TrackMode, UserProjectOverrides,
+    WorktreeGitConfig,
 };
 
 /// Describes a problem encountered during config loading. Each variant
@@ -129,12 +131,13 @@ struct EnvVar {
 /// - `WORKTRUNK_COMMIT__GENERATION__COMMAND=cmd` → `[commit.generation]\ncommand = "cmd"`
 ///
 /// Infrastructure vars (`_CONFIG_PATH`, `_SYSTEM_CONFIG_PATH`,
-/// `_APPROVALS_PATH`) and test vars (`_TEST_*`) are excluded.
+/// `_APPROVALS_PATH`, `_GIT_CALLS`) and test vars (`_TEST_*`) are excluded.
 fn parse_worktrunk_env_vars() -> Vec<EnvVar> {
     const INFRA_VARS: &[&str] = &[
         "WORKTRUNK_CONFIG_PATH",
         "WORKTRUNK_SYSTEM_CONFIG_PATH",
         "WORKTRUNK_APPROVALS_PATH",
+        crate::trace::audit::AUDIT_ENV,
     ];
 
     let mut env_vars: Vec<_> = std::env::vars()
@@ -360,6 +363,39 @@ pub struct UserConfig {
     )]
     pub worktree_path: Option<String>,
 
+    /// Directory for logs and caches, outside the git directory
+    #[serde(rename = "state-dir", default, skip_serializing_if = "Option::is_none")]
+    pub state_dir: Option<String>,
+
+    /// Allow network features: forge queries, version checks, LLM commands
+    /// (default: true). `--offline` sets it to false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<bool>,
+
+    /// Size limits for background and hook logs
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub logs: sections::LogsConfig,
+
+    /// Colors for messages and `wt list`
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub theme: sections::ThemeConfig,
+
+    /// Glyphs for messages and `wt list`
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub output: sections::OutputConfig,
+
+    /// Editor command for `wt open`
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub open: sections::OpenConfig,
+
+    /// Local usage stats for `wt stats`
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub stats: sections::StatsConfig,
+
+    /// Restrictions on project hooks
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub sandbox: sections::SandboxConfig,
+
     /// Configuration for the `wt list` command
     #[serde(default, skip_serializing_if = "super::is_default")]
     pub list: sections::ListConfig,
@@ -384,6 +420,30 @@ pub struct UserConfig {
     #[serde(default, skip_serializing_if = "super::is_default")]
     pub step: sections::StepConfig,
 
+    /// Remotes to compare against (fork-based workflows)
+    #[serde(default, skip_serializing_if = "super::is_default")]
+    pub remote: sections::RemoteConfig,
+
+    /// Git config set on each new worktree
+    #[serde(
+        rename = "worktree-git-config",
+        default,
+        skip_serializing_if = "super::is_default"
+    )]
+    pub worktree_git_config: sections::WorktreeGitConfig,
+
+    /// Repository paths by name, for `[repo-groups]`
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub repos: std::collections::BTreeMap<String, String>,
+
+    /// Named sets of repositories for `--group`
+    #[serde(
+        rename = "repo-groups",
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty"
+    )]
+    pub repo_groups: std::collections::BTreeMap<String, Vec<String>>,
+
     /// Command aliases for `wt <name>`
     #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
     pub aliases: std::collections::BTreeMap<String, crate::config::commands::CommandConfig>,
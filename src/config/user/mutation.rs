@@ -128,6 +128,21 @@ impl UserConfig {
         })
     }
 
+    /// Set the global worktree-path and save.
+    pub fn set_worktree_path(
+        &mut self,
+        worktree_path: String,
+        config_path: &std::path::Path,
+    ) -> Result<(), ConfigError> {
+        self.with_locked_mutation(config_path, |config| {
+            if config.worktree_path.as_ref() == Some(&worktree_path) {
+                return false;
+            }
+            config.worktree_path = Some(worktree_path);
+            true
+        })
+    }
+
     /// Set worktree-path for a specific project and save.
     ///
     /// Creates the project entry if it doesn't exist.
@@ -210,6 +210,46 @@ impl UserConfig {
             return Err(ConfigError("worktree-path cannot be empty".into()));
         }
 
+        if let Some(ref dir) = self.state_dir
+            && !std::path::Path::new(shellexpand::tilde(dir).as_ref()).is_absolute()
+        {
+            return Err(ConfigError(format!(
+                "state-dir must be an absolute path (or start with ~), got `{dir}`"
+            )));
+        }
+
+        for (key, value) in [
+            ("logs.max-file-size", &self.logs.max_file_size),
+            ("logs.max-total-size", &self.logs.max_total_size),
+        ] {
+            if let Some(value) = value
+                && super::sections::parse_byte_size(value).is_none()
+            {
+                return Err(ConfigError(format!(
+                    "{key} must be a size like `10M` or `512K`, got `{value}`"
+                )));
+            }
+        }
+
+        if let Some(ref preset) = self.theme.preset
+            && crate::styling::Theme::preset(preset).is_none()
+        {
+            return Err(ConfigError(format!(
+                "theme.preset must be one of {}, got `{preset}`",
+                crate::styling::THEME_PRESETS.join(", ")
+            )));
+        }
+        for (slot, value) in self.theme.slot_values() {
+            if let Some(value) = value
+                && crate::styling::ThemeColor::parse(value).is_none()
+            {
+                let key: &str = slot.into();
+                return Err(ConfigError(format!(
+                    "theme.{key} must be a color like `blue`, `bright-red`, `208`, `#2aa198`, or `none`, got `{value}`"
+                )));
+            }
+        }
+
         // Validate per-project configs
         for (project, project_config) in &self.projects {
             // Validate worktree path
@@ -12,6 +12,7 @@ use super::merge::{Merge, merge_optional};
 use crate::config::HooksConfig;
 use crate::config::commands::CommandConfig;
 use crate::config::is_default;
+use crate::styling::{Theme, ThemeColor, ThemeSlot};
 
 /// What to stage before committing
 #[derive(
@@ -29,6 +30,46 @@ pub enum StageMode {
     None,
 }
 
+/// What a branch created by `wt switch --create` tracks as its upstream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "kebab-case")]
+pub enum TrackMode {
+    /// Track the branch it was created from (e.g. `main`)
+    Base,
+    /// Track the same-named branch on the primary remote, so `git push`
+    /// publishes it there
+    Remote,
+    /// Set no upstream
+    None,
+}
+
+/// `[merge] verify`: a bool switches project hooks on or off; a string also
+/// names a verification command
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum MergeVerify {
+    /// Run project hooks (`true`) or skip them (`false`)
+    Hooks(bool),
+    /// Run project hooks, and this command once the branch is rebased
+    Command(String),
+}
+
+/// Whether `wt merge` rebases other worktrees onto the advanced target
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum RebaseOthers {
+    /// List the worktrees that fell behind and ask before rebasing them
+    Prompt,
+    /// Rebase them without asking
+    Auto,
+    /// Leave them alone
+    #[default]
+    Never,
+}
+
 /// Configuration for commit message generation
 ///
 /// The command is a shell string executed via `sh -c`. Environment variables
@@ -78,8 +119,27 @@ pub struct CommitGenerationConfig {
     /// *(Experimental — may change in future releases.)*
     #[serde(default, rename = "template-append")]
     pub template_append: Option<String>,
+
+    /// Per-attempt timeout in milliseconds. A command still running when it
+    /// expires is killed. Set to 0 to disable. Default: 300000 (5 minutes).
+    #[serde(rename = "timeout-ms", skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+
+    /// Extra attempts after a failed or timed-out command, with exponential
+    /// backoff between them (1s, 2s, 4s, …). Default: 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u32>,
+
+    /// Show the generated message as it arrives, instead of a waiting status.
+    /// Default: false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
 }
 
+/// Default per-attempt LLM timeout: long enough for slow models, short enough
+/// that a hung command doesn't block `wt merge` indefinitely.
+const DEFAULT_LLM_TIMEOUT_MS: u64 = 300_000;
+
 impl CommitGenerationConfig {
     /// Returns true if an LLM command is configured
     pub fn is_configured(&self) -> bool {
@@ -88,6 +148,24 @@ impl CommitGenerationConfig {
             .map(|s| !s.trim().is_empty())
             .unwrap_or(false)
     }
+
+    /// Per-attempt timeout (default: 5 minutes). Returns `None` when disabled
+    /// (timeout_ms = 0).
+    pub fn timeout(&self) -> Option<std::time::Duration> {
+        Some(self.timeout_ms.unwrap_or(DEFAULT_LLM_TIMEOUT_MS))
+            .filter(|&ms| ms > 0)
+            .map(std::time::Duration::from_millis)
+    }
+
+    /// Extra attempts after a failure (default: 0)
+    pub fn retries(&self) -> u32 {
+        self.retries.unwrap_or(0)
+    }
+
+    /// Stream the generated message as it arrives (default: false)
+    pub fn stream(&self) -> bool {
+        self.stream.unwrap_or(false)
+    }
 }
 
 impl Merge for CommitGenerationConfig {
@@ -123,6 +201,9 @@ impl Merge for CommitGenerationConfig {
                 .template_append
                 .clone()
                 .or_else(|| self.template_append.clone()),
+            timeout_ms: other.timeout_ms.or(self.timeout_ms),
+            retries: other.retries.or(self.retries),
+            stream: other.stream.or(self.stream),
         }
     }
 }
@@ -192,6 +273,17 @@ pub struct ListConfig {
     #[serde(rename = "timeout-ms", skip_serializing_if = "Option::is_none")]
     pub timeout_ms: Option<u64>,
 
+    /// Maximum width of the Branch column. Longer names are shortened in the
+    /// middle, keeping the prefix and the distinguishing suffix. Default: the
+    /// longest branch name.
+    #[serde(rename = "branch-width", skip_serializing_if = "Option::is_none")]
+    pub branch_width: Option<usize>,
+
+    /// Maximum width of the Path column, shortened like `branch-width`.
+    /// Default: the longest path.
+    #[serde(rename = "path-width", skip_serializing_if = "Option::is_none")]
+    pub path_width: Option<usize>,
+
     /// Columns to render, in order. When non-empty this is exhaustive — only
     /// these columns appear (a subset and/or reorder); empty means the default
     /// set. Built-ins are kebab identifiers (`branch`, `status`, `working-diff`,
@@ -287,6 +379,8 @@ impl Merge for ListConfig {
             summary: other.summary.or(self.summary),
             task_timeout_ms: other.task_timeout_ms.or(self.task_timeout_ms),
             timeout_ms: other.timeout_ms.or(self.timeout_ms),
+            branch_width: other.branch_width.or(self.branch_width),
+            path_width: other.path_width.or(self.path_width),
             columns,
             custom_columns,
         }
@@ -308,13 +402,31 @@ pub struct CommitConfig {
     /// Nested under `[commit.generation]` in TOML.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub generation: Option<CommitGenerationConfig>,
+
+    /// Trailer keys carried from squashed commits into the squash commit
+    /// (default: `["Co-authored-by"]`; empty list to drop them all)
+    #[serde(rename = "squash-trailers", skip_serializing_if = "Option::is_none")]
+    pub squash_trailers: Option<Vec<String>>,
 }
 
+/// Trailer keys `wt step squash` preserves when `squash-trailers` is unset.
+const DEFAULT_SQUASH_TRAILERS: &[&str] = &["Co-authored-by"];
+
 impl CommitConfig {
     /// What to stage before committing (default: All)
     pub fn stage(&self) -> StageMode {
         self.stage.unwrap_or_default()
     }
+
+    /// Trailer keys preserved when squashing (default: [`DEFAULT_SQUASH_TRAILERS`])
+    pub fn squash_trailers(&self) -> Vec<String> {
+        self.squash_trailers.clone().unwrap_or_else(|| {
+            DEFAULT_SQUASH_TRAILERS
+                .iter()
+                .map(|key| key.to_string())
+                .collect()
+        })
+    }
 }
 
 impl Merge for CommitConfig {
@@ -322,6 +434,10 @@ impl Merge for CommitConfig {
         Self {
             stage: other.stage.or(self.stage),
             generation: merge_optional(self.generation.as_ref(), other.generation.as_ref()),
+            squash_trailers: other
+                .squash_trailers
+                .clone()
+                .or_else(|| self.squash_trailers.clone()),
         }
     }
 }
@@ -349,13 +465,19 @@ pub struct MergeConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remove: Option<bool>,
 
-    /// Run project hooks (default: true)
+    /// Run project hooks (default: true); a command string also runs that
+    /// command after rebasing, before the target is updated
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub verify: Option<bool>,
+    pub verify: Option<MergeVerify>,
 
     /// Fast-forward merge instead of creating a merge commit (default: true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ff: Option<bool>,
+
+    /// Rebase other worktrees onto the target after merging (default: never)
+    /// Values: "prompt", "auto", "never"
+    #[serde(rename = "rebase-others", skip_serializing_if = "Option::is_none")]
+    pub rebase_others: Option<RebaseOthers>,
 }
 
 impl MergeConfig {
@@ -381,13 +503,26 @@ impl MergeConfig {
 
     /// Run project hooks (default: true)
     pub fn verify(&self) -> bool {
-        self.verify.unwrap_or(true)
+        !matches!(self.verify, Some(MergeVerify::Hooks(false)))
+    }
+
+    /// Verification command to run after rebasing (default: none)
+    pub fn verify_command(&self) -> Option<&str> {
+        match &self.verify {
+            Some(MergeVerify::Command(command)) => Some(command),
+            _ => None,
+        }
     }
 
     /// Fast-forward merge instead of creating a merge commit (default: true)
     pub fn ff(&self) -> bool {
         self.ff.unwrap_or(true)
     }
+
+    /// Rebase other worktrees onto the target after merging (default: Never)
+    pub fn rebase_others(&self) -> RebaseOthers {
+        self.rebase_others.unwrap_or_default()
+    }
 }
 
 impl Merge for MergeConfig {
@@ -397,8 +532,9 @@ impl Merge for MergeConfig {
             commit: other.commit.or(self.commit),
             rebase: other.rebase.or(self.rebase),
             remove: other.remove.or(self.remove),
-            verify: other.verify.or(self.verify),
+            verify: other.verify.clone().or_else(|| self.verify.clone()),
             ff: other.ff.or(self.ff),
+            rebase_others: other.rebase_others.or(self.rebase_others),
         }
     }
 }
@@ -463,9 +599,41 @@ pub struct SwitchConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cd: Option<bool>,
 
+    /// Open the worktree in a tmux window or Zellij tab named after the
+    /// branch, instead of changing directory (default: false)
+    #[serde(rename = "tmux-window", skip_serializing_if = "Option::is_none")]
+    pub tmux_window: Option<bool>,
+
+    /// Set the terminal's tab and window title to the branch after
+    /// switching, and clear it when the worktree is removed (default: false)
+    #[serde(rename = "terminal-title", skip_serializing_if = "Option::is_none")]
+    pub terminal_title: Option<bool>,
+
     /// Picker settings for the interactive selector
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub picker: Option<SwitchPickerConfig>,
+
+    /// Fetch a remote-tracking `--base` (e.g. `origin/main`) before creating
+    /// when the last fetch is older than this duration; `"off"` never fetches
+    /// (default: "5m")
+    #[serde(rename = "fetch-base", skip_serializing_if = "Option::is_none")]
+    pub fetch_base: Option<String>,
+
+    /// Commit uncommitted changes as a WIP commit when switching away from a
+    /// worktree, and undo it when switching back (default: false)
+    #[serde(rename = "auto-wip", skip_serializing_if = "Option::is_none")]
+    pub auto_wip: Option<bool>,
+
+    /// Upstream for branches created with `--create`: `base`, `remote`, or
+    /// `none`. Unset, only a `--base pr:N` branch gets one (the PR's branch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track: Option<TrackMode>,
+
+    /// Offer to fast-forward a local base before `--create` when it is more
+    /// than this many commits behind its upstream. Overrides the project's
+    /// `[switch] max-base-behind`; unset there too, no check runs.
+    #[serde(rename = "max-base-behind", skip_serializing_if = "Option::is_none")]
+    pub max_base_behind: Option<u32>,
 }
 
 impl SwitchConfig {
@@ -473,13 +641,40 @@ impl SwitchConfig {
     pub fn cd(&self) -> bool {
         self.cd.unwrap_or(true)
     }
+
+    /// Open the worktree in a multiplexer window (default: false)
+    pub fn tmux_window(&self) -> bool {
+        self.tmux_window.unwrap_or(false)
+    }
+
+    /// Title the terminal after the branch (default: false)
+    pub fn terminal_title(&self) -> bool {
+        self.terminal_title.unwrap_or(false)
+    }
+
+    /// Maximum age of the last fetch before a remote `--base` is fetched
+    /// again (default: "5m"; `"off"` never fetches)
+    pub fn fetch_base(&self) -> &str {
+        self.fetch_base.as_deref().unwrap_or("5m")
+    }
+
+    /// Save and restore uncommitted changes as WIP commits (default: false)
+    pub fn auto_wip(&self) -> bool {
+        self.auto_wip.unwrap_or(false)
+    }
 }
 
 impl Merge for SwitchConfig {
     fn merge_with(&self, other: &Self) -> Self {
         Self {
             cd: other.cd.or(self.cd),
+            tmux_window: other.tmux_window.or(self.tmux_window),
+            terminal_title: other.terminal_title.or(self.terminal_title),
             picker: merge_optional(self.picker.as_ref(), other.picker.as_ref()),
+            fetch_base: other.fetch_base.clone().or_else(|| self.fetch_base.clone()),
+            auto_wip: other.auto_wip.or(self.auto_wip),
+            track: other.track.or(self.track),
+            max_base_behind: other.max_base_behind.or(self.max_base_behind),
         }
     }
 }
@@ -520,6 +715,10 @@ pub struct StepConfig {
         skip_serializing_if = "Option::is_none"
     )]
     pub copy_ignored: Option<CopyIgnoredConfig>,
+
+    /// Configuration for `wt step prune`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prune: Option<PruneConfig>,
 }
 
 impl StepConfig {
@@ -527,16 +726,429 @@ impl StepConfig {
     pub fn copy_ignored(&self) -> CopyIgnoredConfig {
         self.copy_ignored.clone().unwrap_or_default()
     }
+
+    /// Returns the resolved prune config, defaulting to no rules if unset.
+    pub fn prune(&self) -> PruneConfig {
+        self.prune.clone().unwrap_or_default()
+    }
 }
 
 impl Merge for StepConfig {
     fn merge_with(&self, other: &Self) -> Self {
         Self {
             copy_ignored: merge_optional(self.copy_ignored.as_ref(), other.copy_ignored.as_ref()),
+            prune: merge_optional(self.prune.as_ref(), other.prune.as_ref()),
+        }
+    }
+}
+
+/// Configuration for `wt step prune`: per-branch cleanup rules.
+///
+/// Each candidate's branch is matched against the rules in order and the
+/// first match applies. Branches matching no rule use the `--min-age` flag.
+///
+/// ```toml
+/// [[step.prune.rules]]
+/// branch = "release/*"
+/// keep = true
+///
+/// [[step.prune.rules]]
+/// branch = "dependabot/*"
+/// min-age = "0s"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct PruneConfig {
+    /// Rules checked in order; the first whose `branch` glob matches applies.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<PruneRule>,
+}
+
+/// One `[[step.prune.rules]]` entry.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct PruneRule {
+    /// Glob matched against the whole branch name (`*` also matches `/`)
+    pub branch: String,
+
+    /// Never prune matching branches, even when integrated
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub keep: bool,
+
+    /// Skip matching worktrees younger than this (e.g. `"0s"`, `"7d"`),
+    /// in place of the `--min-age` default
+    #[serde(default, rename = "min-age", skip_serializing_if = "Option::is_none")]
+    pub min_age: Option<String>,
+}
+
+impl Merge for PruneConfig {
+    /// The more specific layer's rules are checked first.
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            rules: other.rules.iter().chain(&self.rules).cloned().collect(),
+        }
+    }
+}
+
+/// Configuration for which remotes worktrunk compares against.
+///
+/// In a fork-based ("triangular") workflow, `origin` is the personal fork and
+/// another remote holds the canonical repository. Setting `upstream` makes
+/// the mainline comparison (the `wt list` `main↕`/`main…±` columns,
+/// integration status, and `wt remove`/`wt merge` integration checks) use
+/// `<upstream>/<branch>` instead of the default branch's own `@{upstream}`,
+/// which usually points at the fork's stale copy.
+///
+/// ```toml
+/// [remote]
+/// upstream = "upstream"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct RemoteConfig {
+    /// Remote holding the canonical repository (e.g. `"upstream"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upstream: Option<String>,
+}
+
+impl RemoteConfig {
+    /// The canonical remote name, if configured and non-empty.
+    pub fn upstream(&self) -> Option<&str> {
+        self.upstream
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+    }
+}
+
+impl Merge for RemoteConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            upstream: other.upstream.clone().or_else(|| self.upstream.clone()),
+        }
+    }
+}
+
+/// Git config set on each new worktree, scoped to that worktree.
+///
+/// Keys are git config names; dotted keys nest as TOML tables, so both
+/// spellings below set `user.email`. Values may be strings, booleans, or
+/// integers.
+///
+/// ```toml
+/// [worktree-git-config]
+/// user.email = "bot@corp.com"
+/// "commit.gpgsign" = false
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+#[serde(transparent)]
+pub struct WorktreeGitConfig(BTreeMap<String, GitConfigValue>);
+
+/// One `[worktree-git-config]` value: a setting, or a table of nested keys.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum GitConfigValue {
+    Bool(bool),
+    Integer(i64),
+    String(String),
+    Table(BTreeMap<String, GitConfigValue>),
+}
+
+impl WorktreeGitConfig {
+    /// The settings as `(key, value)` pairs, nested tables joined with `.`,
+    /// sorted by key.
+    pub fn entries(&self) -> Vec<(String, String)> {
+        fn walk(
+            prefix: &str,
+            table: &BTreeMap<String, GitConfigValue>,
+            out: &mut Vec<(String, String)>,
+        ) {
+            for (name, value) in table {
+                let key = if prefix.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{prefix}.{name}")
+                };
+                match value {
+                    GitConfigValue::Bool(b) => out.push((key, b.to_string())),
+                    GitConfigValue::Integer(n) => out.push((key, n.to_string())),
+                    GitConfigValue::String(s) => out.push((key, s.clone())),
+                    GitConfigValue::Table(nested) => walk(&key, nested, out),
+                }
+            }
+        }
+        let mut entries = Vec::new();
+        walk("", &self.0, &mut entries);
+        entries.sort();
+        entries
+    }
+}
+
+impl Merge for WorktreeGitConfig {
+    /// Per key: the project's value wins, nested tables merge.
+    fn merge_with(&self, other: &Self) -> Self {
+        fn merge(
+            base: &BTreeMap<String, GitConfigValue>,
+            over: &BTreeMap<String, GitConfigValue>,
+        ) -> BTreeMap<String, GitConfigValue> {
+            let mut merged = base.clone();
+            for (name, value) in over {
+                let value = match (merged.get(name), value) {
+                    (Some(GitConfigValue::Table(a)), GitConfigValue::Table(b)) => {
+                        GitConfigValue::Table(merge(a, b))
+                    }
+                    _ => value.clone(),
+                };
+                merged.insert(name.clone(), value);
+            }
+            merged
         }
+        Self(merge(&self.0, &other.0))
     }
 }
 
+/// Size limits for the background and hook logs under `wt-logs`.
+///
+/// Sizes take a `K`, `M`, or `G` suffix (powers of 1024; a trailing `B` is
+/// optional), or a plain byte count.
+///
+/// ```toml
+/// [logs]
+/// max-file-size = "10M"
+/// max-total-size = "100M"
+/// compress = true
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct LogsConfig {
+    /// Largest log kept when it's rotated; longer logs keep their end
+    #[serde(
+        default,
+        rename = "max-file-size",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_file_size: Option<String>,
+
+    /// Total size of a repository's logs before the oldest are deleted
+    #[serde(
+        default,
+        rename = "max-total-size",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_total_size: Option<String>,
+
+    /// Gzip rotated logs (default: true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compress: Option<bool>,
+}
+
+impl LogsConfig {
+    const DEFAULT_MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+    const DEFAULT_MAX_TOTAL_SIZE: u64 = 100 * 1024 * 1024;
+
+    /// Per-file limit in bytes (default 10M).
+    pub fn max_file_size(&self) -> u64 {
+        self.max_file_size
+            .as_deref()
+            .and_then(parse_byte_size)
+            .unwrap_or(Self::DEFAULT_MAX_FILE_SIZE)
+    }
+
+    /// Per-repository limit in bytes (default 100M).
+    pub fn max_total_size(&self) -> u64 {
+        self.max_total_size
+            .as_deref()
+            .and_then(parse_byte_size)
+            .unwrap_or(Self::DEFAULT_MAX_TOTAL_SIZE)
+    }
+
+    /// Whether rotated logs are gzipped.
+    pub fn compress(&self) -> bool {
+        self.compress.unwrap_or(true)
+    }
+}
+
+/// Recolors for the colors messages and `wt list` are styled with.
+///
+/// Colors are ANSI names (`blue`, `bright-red`), 256-color indexes (`208`),
+/// `#rrggbb`, or `none` for the terminal's default color.
+///
+/// ```toml
+/// [theme]
+/// preset = "solarized"
+/// cyan = "blue"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct ThemeConfig {
+    /// Named palette to start from: `solarized`, `high-contrast`, or `monochrome`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+
+    /// Progress, prompts, and working-tree changes in `wt list`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cyan: Option<String>,
+
+    /// Review requests in the `wt list` CI column
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub magenta: Option<String>,
+
+    /// Success messages, additions, and commits ahead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub green: Option<String>,
+
+    /// Errors, deletions, commits behind, and conflicts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub red: Option<String>,
+
+    /// Warnings and states needing attention
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yellow: Option<String>,
+}
+
+impl ThemeConfig {
+    /// The configured color for each slot, as written.
+    pub fn slot_values(&self) -> [(ThemeSlot, Option<&str>); 5] {
+        [
+            (ThemeSlot::Cyan, self.cyan.as_deref()),
+            (ThemeSlot::Magenta, self.magenta.as_deref()),
+            (ThemeSlot::Green, self.green.as_deref()),
+            (ThemeSlot::Red, self.red.as_deref()),
+            (ThemeSlot::Yellow, self.yellow.as_deref()),
+        ]
+    }
+
+    /// The palette: the preset, then each configured color over it. Values
+    /// that don't parse are skipped — loading already warned about them.
+    pub fn theme(&self) -> Theme {
+        let mut theme = self
+            .preset
+            .as_deref()
+            .and_then(Theme::preset)
+            .unwrap_or_default();
+        for (slot, value) in self.slot_values() {
+            if let Some(color) = value.and_then(ThemeColor::parse) {
+                theme.set(slot, color);
+            }
+        }
+        theme
+    }
+}
+
+/// How output is drawn.
+///
+/// ```toml
+/// [output]
+/// ascii = true
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct OutputConfig {
+    /// ASCII stand-ins for the Unicode glyphs in message prefixes and
+    /// `wt list` (default: false)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ascii: Option<bool>,
+}
+
+impl OutputConfig {
+    pub fn ascii(&self) -> bool {
+        self.ascii.unwrap_or(false)
+    }
+}
+
+/// Configuration for `wt open`.
+///
+/// ```toml
+/// [open]
+/// editor = "code --new-window {{ worktree_path }}"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct OpenConfig {
+    /// Command template that opens a worktree in an editor
+    ///
+    /// Supports hook template variables; runs in the worktree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor: Option<String>,
+}
+
+/// Configuration for the local usage stats read by `wt stats`.
+///
+/// ```toml
+/// [stats]
+/// enabled = true
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct StatsConfig {
+    /// Record command runs and worktree activity for `wt stats` (default: false)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+impl StatsConfig {
+    /// Whether to record usage stats (default: false)
+    pub fn enabled(&self) -> bool {
+        self.enabled.unwrap_or(false)
+    }
+}
+
+/// Restrictions applied to project hooks (commands from `.config/wt.toml`).
+///
+/// User hooks and aliases run unrestricted.
+///
+/// ```toml
+/// [sandbox]
+/// nice = true
+/// clear-env = true
+/// keep-env = ["NPM_TOKEN"]
+/// isolate = true
+/// writable = ["~/.npm"]
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct SandboxConfig {
+    /// Run at low CPU priority (`nice`), and idle I/O priority (`ionice`) on Linux
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub nice: bool,
+
+    /// Pass only a minimal environment (`PATH`, `HOME`, locale, terminal)
+    #[serde(
+        default,
+        rename = "clear-env",
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub clear_env: bool,
+
+    /// Extra variables kept with `clear-env`
+    #[serde(default, rename = "keep-env", skip_serializing_if = "Vec::is_empty")]
+    pub keep_env: Vec<String>,
+
+    /// Limit writes to the worktree and temp directory, with `bwrap` (Linux)
+    /// or `sandbox-exec` (macOS); hooks fail when neither is installed
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub isolate: bool,
+
+    /// Extra directories writable under `isolate`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub writable: Vec<String>,
+}
+
+impl SandboxConfig {
+    /// Whether any restriction is turned on.
+    pub fn is_enabled(&self) -> bool {
+        self.nice || self.clear_env || self.isolate
+    }
+}
+
+/// Parse a size like `10M`, `512KB`, or `2048` into bytes.
+pub(super) fn parse_byte_size(s: &str) -> Option<u64> {
+    let s = s.trim();
+    let digits = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(digits);
+    let number: u64 = number.parse().ok()?;
+    let unit = unit.trim().to_ascii_uppercase();
+    let multiplier: u64 = match unit.strip_suffix('B').unwrap_or(&unit) {
+        "" => 1,
+        "K" => 1024,
+        "M" => 1024 * 1024,
+        "G" => 1024 * 1024 * 1024,
+        _ => return None,
+    };
+    number.checked_mul(multiplier)
+}
+
 /// Per-project overrides in the user's config file
 ///
 /// Stored under `[projects."project-id"]` in the user's config.
@@ -597,6 +1209,16 @@ pub struct UserProjectOverrides {
     #[serde(default, skip_serializing_if = "is_default")]
     pub step: StepConfig,
 
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub remote: RemoteConfig,
+
+    #[serde(
+        default,
+        rename = "worktree-git-config",
+        skip_serializing_if = "is_default"
+    )]
+    pub worktree_git_config: WorktreeGitConfig,
+
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub aliases: BTreeMap<String, CommandConfig>,
 }
@@ -279,6 +279,8 @@ fn test_list_config_serde() {
         task_timeout_ms: Some(500),
         timeout_ms: None,
         columns: vec!["branch".into(), "ci".into(), "path".into()],
+        branch_width: None,
+        path_width: None,
         custom_columns: Default::default(),
     };
     let json = serde_json::to_string(&config).unwrap();
@@ -484,8 +486,9 @@ fn test_merge_config_serde() {
         commit: Some(true),
         rebase: Some(false),
         remove: Some(true),
-        verify: Some(true),
+        verify: Some(MergeVerify::Hooks(true)),
         ff: None,
+        rebase_others: None,
     };
     let json = serde_json::to_string(&config).unwrap();
     let parsed: MergeConfig = serde_json::from_str(&json).unwrap();
@@ -631,6 +634,8 @@ fn test_merge_list_config() {
         task_timeout_ms: Some(1000),
         timeout_ms: Some(2000),
         columns: vec!["branch".into(), "ci".into()],
+        branch_width: None,
+        path_width: None,
         custom_columns: Default::default(),
     };
     let override_config = ListConfig {
@@ -641,6 +646,8 @@ fn test_merge_list_config() {
         task_timeout_ms: None, // Should fall back to base
         timeout_ms: None,      // Should fall back to base
         columns: Vec::new(),   // Empty → fall back to base
+        branch_width: None,
+        path_width: None,
         custom_columns: Default::default(),
     };
 
@@ -706,10 +713,12 @@ fn test_merge_commit_config() {
     let base = CommitConfig {
         stage: Some(StageMode::All),
         generation: None,
+        squash_trailers: None,
     };
     let override_config = CommitConfig {
         stage: Some(StageMode::Tracked),
         generation: None,
+        squash_trailers: None,
     };
 
     let merged = base.merge_with(&override_config);
@@ -725,10 +734,12 @@ fn test_merge_commit_config_generation_base_only() {
             command: Some("base-llm".to_string()),
             ..Default::default()
         }),
+        squash_trailers: None,
     };
     let override_config = CommitConfig {
         stage: None,
         generation: None,
+        squash_trailers: None,
     };
 
     let merged = base.merge_with(&override_config);
@@ -744,6 +755,7 @@ fn test_merge_commit_config_generation_override_only() {
     let base = CommitConfig {
         stage: None,
         generation: None,
+        squash_trailers: None,
     };
     let override_config = CommitConfig {
         stage: None,
@@ -751,6 +763,7 @@ fn test_merge_commit_config_generation_override_only() {
             command: Some("override-llm".to_string()),
             ..Default::default()
         }),
+        squash_trailers: None,
     };
 
     let merged = base.merge_with(&override_config);
@@ -770,6 +783,7 @@ fn test_merge_commit_config_generation_both() {
             template: Some("base-template".to_string()),
             ..Default::default()
         }),
+        squash_trailers: None,
     };
     let override_config = CommitConfig {
         stage: None, // Will use base's stage
@@ -778,6 +792,7 @@ fn test_merge_commit_config_generation_both() {
             template: None,                            // Use base's template
             ..Default::default()
         }),
+        squash_trailers: None,
     };
 
     let merged = base.merge_with(&override_config);
@@ -794,16 +809,18 @@ fn test_merge_merge_config() {
         commit: Some(true),
         rebase: Some(true),
         remove: Some(true),
-        verify: Some(true),
+        verify: Some(MergeVerify::Hooks(true)),
         ff: Some(true),
+        rebase_others: None,
     };
     let override_config = MergeConfig {
-        squash: Some(false), // Override
-        commit: None,        // Fall back to base
-        rebase: None,        // Fall back to base
-        remove: Some(false), // Override
-        verify: None,        // Fall back to base
-        ff: Some(false),     // Override
+        squash: Some(false),                     // Override
+        commit: None,                            // Fall back to base
+        rebase: None,                            // Fall back to base
+        remove: Some(false),                     // Override
+        verify: None,                            // Fall back to base
+        ff: Some(false),                         // Override
+        rebase_others: Some(RebaseOthers::Auto), // Override
     };
 
     let merged = base.merge_with(&override_config);
@@ -811,8 +828,9 @@ fn test_merge_merge_config() {
     assert_eq!(merged.commit, Some(true));
     assert_eq!(merged.rebase, Some(true));
     assert_eq!(merged.remove, Some(false));
-    assert_eq!(merged.verify, Some(true));
+    assert_eq!(merged.verify, Some(MergeVerify::Hooks(true)));
     assert_eq!(merged.ff, Some(false));
+    assert_eq!(merged.rebase_others, Some(RebaseOthers::Auto));
 }
 
 #[test]
@@ -824,6 +842,9 @@ fn test_merge_commit_generation_config() {
         squash_template: None,
         squash_template_file: None,
         template_append: None,
+        timeout_ms: Some(60_000),
+        retries: None,
+        stream: Some(true),
     };
     let override_config = CommitGenerationConfig {
         command: Some("claude -p --model=haiku".to_string()), // Override
@@ -832,6 +853,9 @@ fn test_merge_commit_generation_config() {
         squash_template: None,
         squash_template_file: None,
         template_append: None,
+        timeout_ms: None,    // Fall back to base
+        retries: Some(2),    // Override (was None)
+        stream: Some(false), // Override
     };
 
     let merged = base.merge_with(&override_config);
@@ -839,6 +863,20 @@ fn test_merge_commit_generation_config() {
     assert_eq!(merged.template, Some("custom".to_string()));
     // When project sets template, template_file is cleared to maintain mutual exclusivity
     assert_eq!(merged.template_file, None);
+    assert_eq!(merged.timeout(), Some(std::time::Duration::from_secs(60)));
+    assert_eq!(merged.retries(), 2);
+    assert!(!merged.stream());
+}
+
+#[test]
+fn test_commit_generation_timeout_default() {
+    let config = CommitGenerationConfig::default();
+    assert_eq!(config.timeout(), Some(std::time::Duration::from_secs(300)));
+    let disabled = CommitGenerationConfig {
+        timeout_ms: Some(0),
+        ..Default::default()
+    };
+    assert_eq!(disabled.timeout(), None);
 }
 
 #[test]
@@ -954,6 +992,7 @@ fn test_effective_commit_generation_no_project() {
                 command: Some("global-llm".to_string()),
                 ..Default::default()
             }),
+            squash_trailers: None,
         },
         ..Default::default()
     };
@@ -971,6 +1010,7 @@ fn test_effective_commit_generation_with_project_override() {
                 command: Some("global-llm".to_string()),
                 ..Default::default()
             }),
+            squash_trailers: None,
         },
         ..Default::default()
     };
@@ -984,6 +1024,7 @@ fn test_effective_commit_generation_with_project_override() {
                     command: Some("project-llm".to_string()),
                     ..Default::default()
                 }),
+                squash_trailers: None,
             },
             ..Default::default()
         },
@@ -1009,8 +1050,9 @@ fn test_effective_merge_with_partial_override() {
             commit: Some(true),
             rebase: Some(true),
             remove: Some(true),
-            verify: Some(true),
+            verify: Some(MergeVerify::Hooks(true)),
             ff: Some(true),
+            rebase_others: None,
         },
         ..Default::default()
     };
@@ -1025,6 +1067,7 @@ fn test_effective_merge_with_partial_override() {
                 remove: None,
                 verify: None,
                 ff: None,
+                rebase_others: None,
             },
             ..Default::default()
         },
@@ -1071,6 +1114,7 @@ fn test_effective_commit_global_only() {
         commit: CommitConfig {
             stage: Some(StageMode::Tracked),
             generation: None,
+            squash_trailers: None,
         },
         ..Default::default()
     };
@@ -1103,6 +1147,8 @@ fn test_list_config_accessor_methods_with_values() {
         task_timeout_ms: Some(5000),
         timeout_ms: Some(3000),
         columns: Vec::new(),
+        branch_width: None,
+        path_width: None,
         custom_columns: Default::default(),
     };
     assert!(config.full());
@@ -1138,8 +1184,9 @@ fn test_merge_config_accessor_methods_with_values() {
         commit: Some(false),
         rebase: Some(false),
         remove: Some(false),
-        verify: Some(false),
+        verify: Some(MergeVerify::Hooks(false)),
         ff: Some(false),
+        rebase_others: None,
     };
     assert!(!config.squash());
     assert!(!config.commit());
@@ -1170,6 +1217,7 @@ fn test_commit_config_accessor_methods() {
     let config = CommitConfig {
         stage: Some(StageMode::Tracked),
         generation: None,
+        squash_trailers: None,
     };
     assert_eq!(config.stage(), StageMode::Tracked);
 }
@@ -1314,6 +1362,76 @@ cd = false
     assert!(!switch.cd());
 }
 
+#[test]
+fn test_switch_config_tmux_window() {
+    use crate::config::user::{Merge, SwitchConfig};
+
+    assert!(!SwitchConfig::default().tmux_window());
+
+    let toml = r#"
+[switch]
+tmux-window = true
+"#;
+    let config = UserConfig::load_from_str(toml).unwrap();
+    assert!(config.switch(None).tmux_window());
+
+    // A project override turns it back off
+    let off = SwitchConfig {
+        tmux_window: Some(false),
+        ..Default::default()
+    };
+    assert!(!config.switch(None).merge_with(&off).tmux_window());
+}
+
+#[test]
+fn test_switch_config_track() {
+    use crate::config::user::{Merge, SwitchConfig, TrackMode};
+
+    assert_eq!(SwitchConfig::default().track, None);
+
+    let toml = r#"
+[switch]
+track = "remote"
+"#;
+    let config = UserConfig::load_from_str(toml).unwrap();
+    assert_eq!(config.switch(None).track, Some(TrackMode::Remote));
+
+    let base = SwitchConfig {
+        track: Some(TrackMode::Base),
+        ..Default::default()
+    };
+    assert_eq!(
+        config.switch(None).merge_with(&base).track,
+        Some(TrackMode::Base)
+    );
+}
+
+#[test]
+fn test_switch_config_terminal_title() {
+    use crate::config::user::SwitchConfig;
+
+    assert!(!SwitchConfig::default().terminal_title());
+
+    let toml = r#"
+[switch]
+terminal-title = true
+"#;
+    let config = UserConfig::load_from_str(toml).unwrap();
+    assert!(config.switch(None).terminal_title());
+}
+
+#[test]
+fn test_stats_config_enabled() {
+    assert!(!UserConfig::default().stats.enabled());
+
+    let toml = r#"
+[stats]
+enabled = true
+"#;
+    let config = UserConfig::load_from_str(toml).unwrap();
+    assert!(config.stats.enabled());
+}
+
 #[test]
 fn test_switch_config_cd_resolved() {
     let toml = r#"
@@ -1496,6 +1614,7 @@ fn test_user_project_config_with_nested_configs_serde() {
                 command: Some("llm -m gpt-4".to_string()),
                 ..Default::default()
             }),
+            squash_trailers: None,
         },
         merge: MergeConfig {
             squash: Some(false),
@@ -1615,6 +1734,73 @@ exclude = [".repo-local/", ".entire/"]
     );
 }
 
+#[test]
+fn test_remote_config_project_override() {
+    let project_id = "github.com/user/repo";
+    let config = UserConfig::load_from_str(
+        r#"
+[remote]
+upstream = "canonical"
+
+[projects."github.com/user/repo".remote]
+upstream = "upstream"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.remote(None).upstream(), Some("canonical"));
+    assert_eq!(config.remote(Some(project_id)).upstream(), Some("upstream"));
+    assert_eq!(
+        config
+            .resolved(Some("github.com/other/repo"))
+            .remote
+            .upstream(),
+        Some("canonical")
+    );
+
+    let blank = UserConfig::load_from_str("[remote]\nupstream = \" \"").unwrap();
+    assert_eq!(blank.remote(None).upstream(), None);
+}
+
+#[test]
+fn test_worktree_git_config_entries_and_project_override() {
+    let config = UserConfig::load_from_str(
+        r#"
+[worktree-git-config]
+user.email = "bot@corp.com"
+user.name = "Bot"
+"commit.gpgsign" = false
+
+[projects."github.com/user/repo".worktree-git-config]
+user.email = "client@example.com"
+gc.auto = 0
+"#,
+    )
+    .unwrap();
+
+    let entry = |k: &str, v: &str| (k.to_string(), v.to_string());
+    assert_eq!(
+        config.worktree_git_config(None).entries(),
+        [
+            entry("commit.gpgsign", "false"),
+            entry("user.email", "bot@corp.com"),
+            entry("user.name", "Bot"),
+        ]
+    );
+    // Nested tables merge: the project's user.email wins, user.name stays
+    assert_eq!(
+        config
+            .worktree_git_config(Some("github.com/user/repo"))
+            .entries(),
+        [
+            entry("commit.gpgsign", "false"),
+            entry("gc.auto", "0"),
+            entry("user.email", "client@example.com"),
+            entry("user.name", "Bot"),
+        ]
+    );
+}
+
 #[test]
 fn test_deprecated_commit_generation_migrated_on_load() {
     // [commit-generation] is migrated to [commit.generation] at the TOML level
@@ -1702,6 +1888,102 @@ fn test_validation_absolute_worktree_path_allowed() {
     );
 }
 
+#[test]
+fn test_validation_state_dir() {
+    let err = UserConfig::load_from_str(r#"state-dir = "state""#)
+        .unwrap_err()
+        .to_string();
+    insta::assert_snapshot!(err, @"state-dir must be an absolute path (or start with ~), got `state`");
+
+    let config = UserConfig::load_from_str(r#"state-dir = "~/.local/state/worktrunk""#).unwrap();
+    let dir = config.state_dir().unwrap();
+    assert!(dir.is_absolute(), "{dir:?}");
+    assert!(dir.ends_with(".local/state/worktrunk"), "{dir:?}");
+}
+
+#[test]
+fn test_network_false_drops_commit_generation_command() {
+    let content = r#"
+[commit.generation]
+command = "llm -m haiku"
+"#;
+    let config = UserConfig::load_from_str(content).unwrap();
+    assert!(!config.offline());
+    assert!(config.commit_generation(None).is_configured());
+
+    let config = UserConfig::load_from_str(&format!("network = false\n{content}")).unwrap();
+    assert!(config.offline());
+    assert!(!config.commit_generation(None).is_configured());
+}
+
+#[test]
+fn test_logs_config_sizes() {
+    let config = UserConfig::load_from_str("").unwrap();
+    assert_eq!(config.logs.max_file_size(), 10 * 1024 * 1024);
+    assert_eq!(config.logs.max_total_size(), 100 * 1024 * 1024);
+    assert!(config.logs.compress());
+
+    let config = UserConfig::load_from_str(
+        r#"
+[logs]
+max-file-size = "512K"
+max-total-size = "1gb"
+compress = false
+"#,
+    )
+    .unwrap();
+    assert_eq!(config.logs.max_file_size(), 512 * 1024);
+    assert_eq!(config.logs.max_total_size(), 1024 * 1024 * 1024);
+    assert!(!config.logs.compress());
+
+    let err = UserConfig::load_from_str("[logs]\nmax-total-size = \"lots\"")
+        .unwrap_err()
+        .to_string();
+    insta::assert_snapshot!(err, @"logs.max-total-size must be a size like `10M` or `512K`, got `lots`");
+}
+
+#[test]
+fn test_theme_config() {
+    use crate::styling::{ThemeColor, ThemeSlot};
+
+    let config = UserConfig::load_from_str("").unwrap();
+    assert!(config.theme.theme().is_default());
+
+    let config = UserConfig::load_from_str(
+        r##"
+[theme]
+preset = "high-contrast"
+green = "#859900"
+"##,
+    )
+    .unwrap();
+    let theme = config.theme.theme();
+    assert_eq!(
+        theme.get(ThemeSlot::Green),
+        Some(ThemeColor::Rgb(0x85, 0x99, 0x00))
+    );
+    assert_eq!(
+        theme.get(ThemeSlot::Red),
+        Some(ThemeColor::Ansi(anstyle::AnsiColor::BrightRed))
+    );
+
+    let err = UserConfig::load_from_str("[theme]\npreset = \"dracula\"")
+        .unwrap_err()
+        .to_string();
+    insta::assert_snapshot!(err, @"theme.preset must be one of solarized, high-contrast, monochrome, got `dracula`");
+    let err = UserConfig::load_from_str("[theme]\nred = \"crimson\"")
+        .unwrap_err()
+        .to_string();
+    insta::assert_snapshot!(err, @"theme.red must be a color like `blue`, `bright-red`, `208`, `#2aa198`, or `none`, got `crimson`");
+}
+
+#[test]
+fn test_output_config() {
+    assert!(!UserConfig::default().output.ascii());
+    let config = UserConfig::load_from_str("[output]\nascii = true").unwrap();
+    assert!(config.output.ascii());
+}
+
 #[test]
 fn test_validation_project_empty_worktree_path() {
     let content = r#"
@@ -1774,6 +2056,7 @@ fn test_save_to_new_file_with_commit_generation() {
                 command: Some("llm -m haiku".to_string()),
                 ..Default::default()
             }),
+            squash_trailers: None,
         },
         ..Default::default()
     };
@@ -1809,6 +2092,7 @@ fn test_save_to_new_file_commit_with_stage_and_generation() {
                 command: Some("llm -m haiku".to_string()),
                 ..Default::default()
             }),
+            squash_trailers: None,
         },
         ..Default::default()
     };
@@ -2102,14 +2386,34 @@ fn test_valid_user_config_keys_all_deserialize() {
             // Silent aliases for canonical `pre-start`/`post-start`; including
             // both would produce a duplicate-field error.
             "pre-create" | "post-create" => continue,
-            "skip-shell-integration-prompt" | "skip-commit-generation-prompt" => {
+            "skip-shell-integration-prompt" | "skip-commit-generation-prompt" | "network" => {
                 scalar_lines.push(format!("{key} = true"));
             }
             "worktree-path" => {
                 scalar_lines.push(format!("{key} = \"test-value\""));
             }
-            "list" | "commit" | "merge" | "remove" | "switch" | "step" | "select"
-            | "commit-generation" | "aliases" => {
+            "state-dir" => {
+                scalar_lines.push(format!("{key} = \"~/state\""));
+            }
+            "list"
+            | "commit"
+            | "merge"
+            | "remove"
+            | "switch"
+            | "step"
+            | "remote"
+            | "select"
+            | "commit-generation"
+            | "aliases"
+            | "logs"
+            | "open"
+            | "sandbox"
+            | "stats"
+            | "repos"
+            | "repo-groups"
+            | "theme"
+            | "output"
+            | "worktree-git-config" => {
                 // Table sections with minimal content
                 table_lines.push(format!("[{key}]"));
             }
@@ -2720,6 +3024,7 @@ fn test_save_to_existing_file_writes_project_sections() {
             commit: CommitConfig {
                 stage: Some(StageMode::Tracked),
                 generation: None,
+                squash_trailers: None,
             },
             merge: MergeConfig {
                 squash: Some(false),
@@ -2727,7 +3032,13 @@ fn test_save_to_existing_file_writes_project_sections() {
             },
             switch: SwitchConfig {
                 cd: Some(false),
+                tmux_window: None,
+                terminal_title: None,
                 picker: None,
+                fetch_base: None,
+                auto_wip: None,
+                track: None,
+                max_base_behind: None,
             },
             ..Default::default()
         },
@@ -2854,6 +3165,7 @@ template = "stays: {{ diff }}"
                 template: Some("stays: {{ diff }}".to_string()),
                 ..Default::default()
             }),
+            squash_trailers: None,
         },
         ..Default::default()
     };
@@ -2901,6 +3213,7 @@ stage = "all"
                 command: Some("llm".to_string()),
                 ..Default::default()
             }),
+            squash_trailers: None,
         },
         ..Default::default()
     };
@@ -2980,6 +3293,7 @@ fn test_save_to_existing_file_where_commit_is_scalar() {
                 command: Some("llm".to_string()),
                 ..Default::default()
             }),
+            squash_trailers: None,
         },
         ..Default::default()
     };
@@ -3018,6 +3332,7 @@ fn test_save_to_existing_file_where_commit_generation_is_scalar() {
                 command: Some("llm".to_string()),
                 ..Default::default()
             }),
+            squash_trailers: None,
         },
         ..Default::default()
     };
@@ -3187,7 +3502,13 @@ fn test_save_to_new_file_expands_nested_project_inline_tables() {
             },
             switch: SwitchConfig {
                 cd: Some(false),
+                tmux_window: None,
+                terminal_title: None,
                 picker: None,
+                fetch_base: None,
+                auto_wip: None,
+                track: None,
+                max_base_behind: None,
             },
             ..Default::default()
         },
@@ -3426,6 +3747,7 @@ future-knob = "from-newer-wt"
                 command: Some("new-llm".to_string()),
                 ..Default::default()
             }),
+            squash_trailers: None,
         },
         ..Default::default()
     };
@@ -3581,6 +3903,32 @@ fn test_set_commit_generation_command_noop_when_unchanged() {
     );
 }
 
+#[test]
+fn test_set_worktree_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("config.toml");
+    std::fs::write(&config_path, "# keep\n").unwrap();
+
+    let mut config = UserConfig::default();
+    config
+        .set_worktree_path(".worktrees/{{ branch }}".to_string(), &config_path)
+        .unwrap();
+    assert_eq!(config.worktree_path(), ".worktrees/{{ branch }}");
+
+    let after_first = std::fs::read_to_string(&config_path).unwrap();
+    assert!(
+        after_first.contains("worktree-path = \".worktrees/{{ branch }}\""),
+        "{after_first}"
+    );
+
+    // An unchanged value skips the save.
+    let mut config2 = UserConfig::default();
+    config2
+        .set_worktree_path(".worktrees/{{ branch }}".to_string(), &config_path)
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(&config_path).unwrap(), after_first);
+}
+
 #[test]
 fn test_set_skip_shell_integration_prompt_noop_on_second_call() {
     // Covers the `return false` early-exit in set_skip_shell_integration_prompt's
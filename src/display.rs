@@ -7,15 +7,33 @@
 //! - Terminal width detection
 
 use std::path::{Component, Path};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use path_slash::PathExt as _;
 use unicode_width::UnicodeWidthChar;
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::visual_width;
-use worktrunk::utils::epoch_now;
+use worktrunk::utils::{epoch_now, format_timestamp_iso8601};
 
-/// Format timestamp as abbreviated relative time (e.g., "2h")
+/// `--absolute-times`: print timestamps instead of relative ages.
+static ABSOLUTE_TIMES: AtomicBool = AtomicBool::new(false);
+
+/// Set by `--absolute-times` before any command runs.
+pub(crate) fn set_absolute_times(absolute: bool) {
+    ABSOLUTE_TIMES.store(absolute, Ordering::Relaxed);
+}
+
+/// Whether times print as ISO 8601 timestamps rather than relative ages.
+pub(crate) fn absolute_times() -> bool {
+    ABSOLUTE_TIMES.load(Ordering::Relaxed)
+}
+
+/// Format timestamp as abbreviated relative time (e.g., "2h"), or as an ISO
+/// 8601 timestamp under `--absolute-times`
 pub(crate) fn format_relative_time_short(timestamp: i64) -> String {
+    if absolute_times() {
+        return format_timestamp_iso8601(timestamp.max(0) as u64);
+    }
     // Cast to i64 for signed arithmetic (handles future timestamps)
     format_relative_time_impl(timestamp, epoch_now() as i64)
 }
@@ -115,6 +133,51 @@ pub(crate) fn truncate_to_width(text: &str, max_width: usize) -> String {
     format!("{}…", truncated)
 }
 
+/// Truncate text to `max_width` with the ellipsis in the middle.
+///
+/// Keeps both ends: branch names and paths tend to share a prefix
+/// (`feature/`, `../repo.`) and differ at the end (`PROJ-1234`), so cutting
+/// the tail loses the part that tells rows apart. The prefix gets the extra
+/// column when the budget is odd.
+pub(crate) fn truncate_middle(text: &str, max_width: usize) -> String {
+    if visual_width(text) <= max_width {
+        return text.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+
+    let budget = max_width - 1;
+    let suffix_budget = budget / 2;
+    let prefix_budget = budget - suffix_budget;
+
+    let mut prefix_width = 0;
+    let mut prefix_end = 0;
+    for (idx, ch) in text.char_indices() {
+        let char_width = ch.width().unwrap_or(0);
+        if prefix_width + char_width > prefix_budget {
+            break;
+        }
+        prefix_width += char_width;
+        prefix_end = idx + ch.len_utf8();
+    }
+
+    // Give the suffix whatever the prefix left unused (wide chars at the cut).
+    let suffix_budget = budget - prefix_width;
+    let mut suffix_width = 0;
+    let mut suffix_start = text.len();
+    for (idx, ch) in text.char_indices().rev() {
+        let char_width = ch.width().unwrap_or(0);
+        if idx < prefix_end || suffix_width + char_width > suffix_budget {
+            break;
+        }
+        suffix_width += char_width;
+        suffix_start = idx;
+    }
+
+    format!("{}…{}", &text[..prefix_end], &text[suffix_start..])
+}
+
 // Re-export from styling for convenience
 pub(crate) use worktrunk::styling::{terminal_dimensions, terminal_width, truncate_visible};
 
@@ -321,4 +384,27 @@ mod tests {
         let result = truncate_to_width("123456", 5);
         assert!(result.ends_with('…'));
     }
+
+    #[test]
+    fn test_truncate_middle() {
+        let branch = "feature/very-long-description-PROJ-1234";
+        assert_eq!(truncate_middle(branch, 20), "feature/ve…PROJ-1234");
+        assert_eq!(visual_width(&truncate_middle(branch, 20)), 20);
+        assert_eq!(truncate_middle(branch, 21), "feature/ve…-PROJ-1234");
+
+        // Fits: unchanged
+        assert_eq!(truncate_middle("main", 4), "main");
+        assert_eq!(truncate_middle("main", 10), "main");
+
+        // Degenerate widths
+        assert_eq!(truncate_middle("abcdef", 1), "…");
+        assert_eq!(truncate_middle("abcdef", 2), "a…");
+        assert_eq!(truncate_middle("abcdef", 0), "");
+
+        // Wide characters never overflow the width
+        let wide = "機能-ブランチ-名前";
+        for width in 1..visual_width(wide) {
+            assert!(visual_width(&truncate_middle(wide, width)) <= width);
+        }
+    }
 }
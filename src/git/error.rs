@@ -153,6 +153,15 @@ pub trait ErrorExt {
     /// [`WorktrunkError`] variants that carry an exit code.
     fn exit_code(&self) -> Option<i32>;
 
+    /// Process exit code for this error under the documented contract.
+    ///
+    /// A propagated child exit code ([`Self::exit_code`]) wins, except for
+    /// hook failures, which report [`exit_codes::HOOK_FAILED`] unless the
+    /// hook was killed by a signal (`128 + sig`). Otherwise the
+    /// first [`GitError`] in the chain picks the category, falling back to
+    /// [`exit_codes::ERROR`].
+    fn process_exit_code(&self) -> i32;
+
     /// If the error is signal-derived, return the equivalent shell exit
     /// code (`128 + signal`).
     ///
@@ -165,8 +174,18 @@ pub trait ErrorExt {
     /// See the "Signal Handling" section of the project `CLAUDE.md` for
     /// the rationale and the full list of loops that apply this policy.
     fn interrupt_exit_code(&self) -> Option<i32>;
+
+    /// Stable machine-readable code for this error, for `--format=json`.
+    ///
+    /// The first typed error in the chain picks the code: a [`GitError`] or
+    /// [`WorktrunkError`] variant, or `command-failed` for a [`CommandError`].
+    /// Untyped (`anyhow!`) errors report `error`.
+    fn error_code(&self) -> &'static str;
 }
 
+/// Code reported for errors without a more specific one.
+const ERROR_CODE: &str = "error";
+
 /// Information about a failed command, for display in error messages.
 ///
 /// Separates the command string from exit information so Display impls
@@ -378,7 +397,8 @@ impl SwitchSuggestionCtx {
 ///     println!("branch {branch} already exists");
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, strum::IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
 pub enum GitError {
     // Git state errors
     DetachedHead {
@@ -413,10 +433,20 @@ pub enum GitError {
         /// suggesting both `pr:N` and `mr:N`.
         pr_mr_platform: Option<RefType>,
     },
+    /// A partial branch name matched several branches or worktrees, with no
+    /// terminal to ask which one was meant.
+    AmbiguousBranch {
+        query: String,
+        matches: Vec<String>,
+    },
     /// Reference (branch, tag, commit) not found - used when any commit-ish is accepted
     ReferenceNotFound {
         reference: String,
     },
+    /// A target that must be a branch resolved to a tag or commit instead
+    NotABranch {
+        reference: String,
+    },
     /// Persisted `worktrunk.default-branch` points at a branch that no longer
     /// resolves locally. Surfaced when a command would use the default branch
     /// (no explicit `--target`) and the cached value is stale, so the user
@@ -473,11 +503,23 @@ pub enum GitError {
     CannotRemoveDefaultBranch {
         branch: String,
     },
+    /// A history-rewriting `wt step` ran on the default branch.
+    StepOnDefaultBranch {
+        step: String,
+        branch: String,
+    },
     WorktreeLocked {
         branch: String,
         path: PathBuf,
         reason: Option<String>,
     },
+    /// Processes have their working directory inside the worktree.
+    WorktreeInUse {
+        branch: Option<String>,
+        path: PathBuf,
+        /// `(pid, command)` per process, sorted by PID
+        processes: Vec<(u32, String)>,
+    },
 
     // Merge/push errors
     ConflictingChanges {
@@ -490,6 +532,15 @@ pub enum GitError {
         commits_formatted: String,
         in_merge_context: bool,
     },
+    /// The target branch's worktree is mid-rebase/merge/etc.; pushing into
+    /// it would rewrite files under the operation.
+    TargetOperationInProgress {
+        target_branch: String,
+        worktree_path: PathBuf,
+        /// [`WorkingTree::operation_state`](super::WorkingTree::operation_state)
+        /// value, e.g. `REBASING 2/5`
+        state: String,
+    },
     RebaseConflict {
         target_branch: String,
         git_output: String,
@@ -497,13 +548,24 @@ pub enum GitError {
     NotRebased {
         target_branch: String,
     },
+    /// The branch was cut from `parent`, whose commits `target_branch` lacks.
+    StackedBranch {
+        branch: String,
+        parent: String,
+        target_branch: String,
+        /// Parent commits the merge would bring along
+        commits: usize,
+    },
     PushFailed {
         target_branch: String,
         error: String,
     },
 
     // Validation/other errors
-    NotInteractive,
+    NotInteractive {
+        /// `command_hash` of each command that needed approval
+        hashes: Vec<String>,
+    },
     HookCommandNotFound {
         name: String,
         available: Vec<String>,
@@ -574,7 +636,91 @@ pub enum GitError {
 
 impl std::error::Error for GitError {}
 
+/// Process exit codes `wt` reports, documented under `wt --help` so scripts
+/// can branch on the outcome.
+///
+/// Exit codes from child processes (aliases, `wt step for-each`) and signals
+/// (`128 + sig`) pass through unchanged. Successful no-ops exit
+/// [`SUCCESS`](exit_codes::SUCCESS).
+pub mod exit_codes {
+    /// Success, including commands with nothing to do.
+    pub const SUCCESS: i32 = 0;
+    /// Any failure without a more specific code.
+    pub const ERROR: i32 = 1;
+    /// Invalid command-line usage (reported by the argument parser).
+    pub const USAGE: i32 = 2;
+    /// The repository isn't in a state the command accepts — uncommitted
+    /// changes, detached HEAD, missing or existing branch/worktree, etc.
+    pub const PRECONDITION: i32 = 3;
+    /// Merge/rebase conflicts or a target that can't be fast-forwarded.
+    pub const CONFLICT: i32 = 4;
+    /// A hook command failed.
+    pub const HOOK_FAILED: i32 = 5;
+}
+
 impl GitError {
+    /// Stable machine-readable code for this error, e.g. `branch-not-found`.
+    ///
+    /// Reported as `error.code` in `--format=json` output. The code is the
+    /// variant name in kebab-case, so renaming a variant changes the contract.
+    pub fn code(&self) -> &'static str {
+        match self {
+            GitError::WithSwitchSuggestion { source, .. } => source.code(),
+            other => other.into(),
+        }
+    }
+
+    /// Category exit code for this error (see [`exit_codes`]).
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            GitError::WithSwitchSuggestion { source, .. } => source.exit_code(),
+
+            GitError::DetachedHead { .. }
+            | GitError::UncommittedChanges { .. }
+            | GitError::BranchAlreadyExists { .. }
+            | GitError::BranchNotFound { .. }
+            | GitError::AmbiguousBranch { .. }
+            | GitError::ReferenceNotFound { .. }
+            | GitError::StaleDefaultBranch { .. }
+            | GitError::UnbornDefaultBranch { .. }
+            | GitError::NotInWorktree { .. }
+            | GitError::WorktreeMissing { .. }
+            | GitError::RemoteOnlyBranch { .. }
+            | GitError::NotABranch { .. }
+            | GitError::WorktreePathOccupied { .. }
+            | GitError::WorktreePathExists { .. }
+            | GitError::CannotRemoveMainWorktree
+            | GitError::CannotRemoveDefaultBranch { .. }
+            | GitError::StepOnDefaultBranch { .. }
+            | GitError::WorktreeLocked { .. }
+            | GitError::WorktreeInUse { .. }
+            | GitError::NotRebased { .. }
+            | GitError::StackedBranch { .. }
+            | GitError::TargetOperationInProgress { .. }
+            | GitError::NotInteractive { .. }
+            | GitError::HookCommandNotFound { .. }
+            | GitError::ProjectConfigNotFound { .. }
+            | GitError::WorktreeNotFound { .. }
+            | GitError::RefCreateConflict { .. }
+            | GitError::RefBaseConflict { .. }
+            | GitError::BranchTracksDifferentRef { .. } => exit_codes::PRECONDITION,
+
+            GitError::ConflictingChanges { .. }
+            | GitError::NotFastForward { .. }
+            | GitError::RebaseConflict { .. } => exit_codes::CONFLICT,
+
+            GitError::WorktreeCreationFailed { .. }
+            | GitError::WorktreeRemovalFailed { .. }
+            | GitError::PushFailed { .. }
+            | GitError::ParseError { .. }
+            | GitError::WorktreeIncludeParseError { .. }
+            | GitError::LlmCommandFailed { .. }
+            | GitError::NoRemoteForRepo { .. }
+            | GitError::CliApiError { .. }
+            | GitError::Other { .. } => exit_codes::ERROR,
+        }
+    }
+
     /// Styled title for this variant (first line, with inline `<bold>`
     /// highlights on entity names like branch and path).
     ///
@@ -610,10 +756,18 @@ impl GitError {
                 cformat!("No branch named <bold>{branch}</>")
             }
 
+            GitError::AmbiguousBranch { query, matches } => {
+                cformat!("<bold>{query}</> matches {} branches", matches.len())
+            }
+
             GitError::ReferenceNotFound { reference } => {
                 cformat!("No branch, tag, or commit named <bold>{reference}</>")
             }
 
+            GitError::NotABranch { reference } => {
+                cformat!("Cannot update <bold>{reference}</> — it's not a branch")
+            }
+
             GitError::StaleDefaultBranch { branch } => {
                 cformat!("Default branch <bold>{branch}</> does not exist locally")
             }
@@ -680,6 +834,10 @@ impl GitError {
                 cformat!("Cannot remove the default branch <bold>{branch}</>")
             }
 
+            GitError::StepOnDefaultBranch { step, branch } => {
+                cformat!("Refusing to {step} the default branch <bold>{branch}</>")
+            }
+
             GitError::WorktreeLocked { branch, reason, .. } => {
                 let reason_text = match reason {
                     Some(r) if !r.is_empty() => format!(" ({r})"),
@@ -688,6 +846,24 @@ impl GitError {
                 cformat!("Cannot remove <bold>{branch}</>, worktree is locked{reason_text}")
             }
 
+            GitError::WorktreeInUse {
+                branch,
+                path,
+                processes,
+            } => {
+                let target = match branch {
+                    Some(b) => b.clone(),
+                    None => format_path_for_display(path),
+                };
+                let count = processes.len();
+                let noun = if count == 1 {
+                    "process is"
+                } else {
+                    "processes are"
+                };
+                cformat!("Cannot remove <bold>{target}</>, {count} {noun} running in the worktree")
+            }
+
             GitError::ConflictingChanges { target_branch, .. } => cformat!(
                 "Can't push to local <bold>{target_branch}</> branch: conflicting uncommitted changes"
             ),
@@ -696,6 +872,17 @@ impl GitError {
                 "Can't push to local <bold>{target_branch}</> branch: it has newer commits"
             ),
 
+            GitError::TargetOperationInProgress {
+                target_branch,
+                state,
+                ..
+            } => {
+                let operation = operation_name(state);
+                cformat!(
+                    "Can't push to local <bold>{target_branch}</> branch: its worktree is in the middle of a {operation}"
+                )
+            }
+
             GitError::RebaseConflict { target_branch, .. } => {
                 cformat!("Rebase onto <bold>{target_branch}</> incomplete")
             }
@@ -704,11 +891,23 @@ impl GitError {
                 cformat!("Branch not rebased onto <bold>{target_branch}</>")
             }
 
+            GitError::StackedBranch {
+                branch,
+                parent,
+                target_branch,
+                commits,
+            } => {
+                let noun = if *commits == 1 { "commit" } else { "commits" };
+                cformat!(
+                    "<bold>{branch}</> was cut from <bold>{parent}</>; merging into <bold>{target_branch}</> would also merge {commits} {noun} from <bold>{parent}</>"
+                )
+            }
+
             GitError::PushFailed { target_branch, .. } => {
                 cformat!("Can't push to local <bold>{target_branch}</> branch")
             }
 
-            GitError::NotInteractive => {
+            GitError::NotInteractive { .. } => {
                 "Cannot prompt for approval in non-interactive environment".to_string()
             }
 
@@ -816,11 +1015,12 @@ impl GitError {
             } => {
                 let title = self.title();
                 let hint = if *force_hint {
-                    // Construct full command: "wt remove [branch] --force"
+                    // Construct full commands: "wt remove [branch] --stash" / "--force"
                     let args: Vec<&str> = branch.as_deref().into_iter().collect();
-                    let cmd = suggest_command("remove", &args, &["--force"]);
+                    let stash_cmd = suggest_command("remove", &args, &["--stash"]);
+                    let force_cmd = suggest_command("remove", &args, &["--force"]);
                     cformat!(
-                        "Commit or stash changes first, or to lose uncommitted changes, run <underline>{cmd}</>"
+                        "Commit changes first, or to stash them, run <underline>{stash_cmd}</>; to lose them, run <underline>{force_cmd}</>"
                     )
                 } else {
                     "Commit or stash changes first".to_string()
@@ -885,11 +1085,46 @@ impl GitError {
                 write!(f, "{}\n{}", error_message(&title), hint_message(hint))
             }
 
+            GitError::AmbiguousBranch { matches, .. } => {
+                let title = self.title();
+                let listed = matches
+                    .iter()
+                    .map(|m| cformat!("<bold>{m}</>"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let mut switch_cmd = suggest_command("switch", &[&matches[0]], &[]);
+                if let Some(ctx) = ctx {
+                    switch_cmd = ctx.apply(switch_cmd);
+                }
+                write!(
+                    f,
+                    "{}\n{}\n{}",
+                    error_message(&title),
+                    format_with_gutter(&listed, None),
+                    hint_message(cformat!(
+                        "To switch to one, name it in full, e.g. <underline>{switch_cmd}</>"
+                    ))
+                )
+            }
+
             GitError::ReferenceNotFound { .. } => {
                 let title = self.title();
                 write!(f, "{}", error_message(&title))
             }
 
+            GitError::NotABranch { reference } => {
+                let title = self.title();
+                let escaped = escape(Cow::Borrowed(reference.as_str()));
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(&title),
+                    hint_message(cformat!(
+                        "To merge into it, create a branch there with <underline>git branch BRANCH {escaped}</> and target that branch"
+                    ))
+                )
+            }
+
             GitError::StaleDefaultBranch { .. } => {
                 let title = self.title();
                 write!(
@@ -1057,6 +1292,18 @@ impl GitError {
                 )
             }
 
+            GitError::StepOnDefaultBranch { step, branch } => {
+                let title = self.title();
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(&title),
+                    hint_message(cformat!(
+                        "<underline>wt step {step}</> rewrites the current branch's commits, which on <bold>{branch}</> would rewrite shared history; run it from a feature worktree, or add <underline>--allow-primary</> to proceed"
+                    ))
+                )
+            }
+
             GitError::WorktreeLocked { path, .. } => {
                 let title = self.title();
                 let path_display = format_path_for_display(path);
@@ -1070,6 +1317,28 @@ impl GitError {
                 )
             }
 
+            GitError::WorktreeInUse {
+                branch, processes, ..
+            } => {
+                let title = self.title();
+                let listing = processes
+                    .iter()
+                    .map(|(pid, command)| format!("{pid} {command}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let args: Vec<&str> = branch.as_deref().into_iter().collect();
+                let cmd = suggest_command("remove", &args, &["--force"]);
+                write!(
+                    f,
+                    "{}\n{}\n{}",
+                    error_message(&title),
+                    format_with_gutter(&listing, None),
+                    hint_message(cformat!(
+                        "Stop these processes first, or to remove anyway, run <underline>{cmd}</>"
+                    ))
+                )
+            }
+
             GitError::ConflictingChanges {
                 files,
                 worktree_path,
@@ -1123,6 +1392,24 @@ impl GitError {
                 }
             }
 
+            GitError::TargetOperationInProgress {
+                worktree_path,
+                state,
+                ..
+            } => {
+                let title = self.title();
+                let operation = operation_name(state);
+                let path_display = format_path_for_display(worktree_path);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(&title),
+                    hint_message(cformat!(
+                        "Finish or abort the {operation} in <underline>{path_display}</> first"
+                    ))
+                )
+            }
+
             GitError::RebaseConflict { git_output, .. } => {
                 let title = self.title();
                 write!(f, "{}", error_message(&title))?;
@@ -1153,24 +1440,43 @@ impl GitError {
                 )
             }
 
-            GitError::PushFailed { error, .. } => {
-                let title = self.title();
-                write!(f, "{}", format_error_block(error_message(&title), error))
-            }
-
-            GitError::NotInteractive => {
+            GitError::StackedBranch { branch, parent, .. } => {
                 let title = self.title();
-                let approvals_cmd = suggest_command("config", &["approvals", "add"], &[]);
                 write!(
                     f,
                     "{}\n{}",
                     error_message(&title),
                     hint_message(cformat!(
-                        "To skip prompts in CI/CD, add <underline>--yes</>; to pre-approve commands, run <underline>{approvals_cmd}</>"
+                        "To merge only <bold>{branch}</>'s commits, add <underline>--stacked-base=rebase</>; to merge into <bold>{parent}</> instead, add <underline>--stacked-base=parent</>; to merge everything, add <underline>--stacked-base=include</>"
                     ))
                 )
             }
 
+            GitError::PushFailed { error, .. } => {
+                let title = self.title();
+                write!(f, "{}", format_error_block(error_message(&title), error))
+            }
+
+            GitError::NotInteractive { hashes } => {
+                let title = self.title();
+                let approvals_cmd = suggest_command("config", &["approvals", "add"], &[]);
+                let hint = if hashes.is_empty() {
+                    cformat!(
+                        "To skip prompts in CI/CD, add <underline>--yes</>; to pre-approve commands, run <underline>{approvals_cmd}</>"
+                    )
+                } else {
+                    let approve = hashes
+                        .iter()
+                        .map(|hash| format!("--approve {hash}"))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    cformat!(
+                        "To run just these commands, add <underline>{approve}</>; to skip prompts in CI/CD, add <underline>--yes</>; to pre-approve commands, run <underline>{approvals_cmd}</>"
+                    )
+                };
+                write!(f, "{}\n{}", error_message(&title), hint_message(hint))
+            }
+
             GitError::HookCommandNotFound { .. } => {
                 let title = self.title();
                 write!(f, "{}", error_message(&title))
@@ -1381,6 +1687,16 @@ impl WorktrunkError {
             WorktrunkError::CommandNotApproved => None,
         }
     }
+
+    /// Stable machine-readable code for this error (see [`GitError::code`]).
+    pub fn code(&self) -> &'static str {
+        match self {
+            WorktrunkError::ChildProcessExited { .. } => "child-process-exited",
+            WorktrunkError::HookCommandFailed { .. } => "hook-command-failed",
+            WorktrunkError::CommandNotApproved => "command-not-approved",
+            WorktrunkError::AlreadyDisplayed { .. } => ERROR_CODE,
+        }
+    }
 }
 
 impl Diagnostic for WorktrunkError {
@@ -1444,6 +1760,47 @@ impl ErrorExt for anyhow::Error {
             .and_then(WorktrunkError::exit_code)
     }
 
+    fn process_exit_code(&self) -> i32 {
+        for e in self.chain() {
+            if let Some(err) = e.downcast_ref::<WorktrunkError>() {
+                match err {
+                    // A hook killed by a signal keeps `128 + sig` so
+                    // interrupts read the same as everywhere else.
+                    WorktrunkError::HookCommandFailed {
+                        exit_code: Some(code),
+                        ..
+                    } if *code > 128 => return *code,
+                    WorktrunkError::HookCommandFailed { .. } => return exit_codes::HOOK_FAILED,
+                    other => {
+                        if let Some(code) = other.exit_code() {
+                            return code;
+                        }
+                    }
+                }
+            }
+            if let Some(err) = e.downcast_ref::<GitError>() {
+                return err.exit_code();
+            }
+        }
+        exit_codes::ERROR
+    }
+
+    fn error_code(&self) -> &'static str {
+        self.chain()
+            .find_map(|e| {
+                if let Some(err) = e.downcast_ref::<GitError>() {
+                    Some(err.code())
+                } else if let Some(err) = e.downcast_ref::<WorktrunkError>() {
+                    Some(err.code())
+                } else if e.is::<CommandError>() {
+                    Some("command-failed")
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(ERROR_CODE)
+    }
+
     fn interrupt_exit_code(&self) -> Option<i32> {
         if let Some(WorktrunkError::ChildProcessExited {
             signal: Some(sig), ..
@@ -1572,6 +1929,19 @@ fn pr_mr_switch_hint(
     }
 }
 
+/// The operation an `operation_state` value names, as prose: `REBASING 2/5`
+/// → `rebase`.
+fn operation_name(state: &str) -> &'static str {
+    match state.split_whitespace().next().unwrap_or_default() {
+        "MERGING" => "merge",
+        "REBASING" => "rebase",
+        "CHERRY-PICKING" => "cherry-pick",
+        "REVERTING" => "revert",
+        "BISECTING" => "bisect",
+        _ => "git operation",
+    }
+}
+
 /// Format an error with header and gutter content
 fn format_error_block(header: impl Into<String>, error: &str) -> String {
     let header = header.into();
@@ -1711,6 +2081,107 @@ mod tests {
         assert_eq!(add_hook_skip_hint(inner).exit_code(), Some(7));
     }
 
+    #[test]
+    fn test_process_exit_code() {
+        // Hook failures report HOOK_FAILED, through the --no-hooks hint wrapper
+        let hook: anyhow::Error = WorktrunkError::HookCommandFailed {
+            hook_type: HookType::PreMerge,
+            command_name: Some("test".into()),
+            error: "failed".into(),
+            exit_code: Some(7),
+        }
+        .into();
+        assert_eq!(
+            add_hook_skip_hint(hook).process_exit_code(),
+            exit_codes::HOOK_FAILED
+        );
+
+        // Child exit codes pass through
+        let child: anyhow::Error = WorktrunkError::ChildProcessExited {
+            code: 42,
+            message: "exit status: 42".into(),
+            signal: None,
+        }
+        .into();
+        assert_eq!(child.process_exit_code(), 42);
+
+        // GitError categories, including behind context
+        let dirty = anyhow::Error::from(GitError::UncommittedChanges {
+            action: Some("merge".into()),
+            branch: None,
+            force_hint: false,
+            dirty_files: vec![],
+        })
+        .context("merging");
+        assert_eq!(dirty.process_exit_code(), exit_codes::PRECONDITION);
+        let exists = anyhow::Error::from(GitError::BranchAlreadyExists {
+            branch: "feature".into(),
+        });
+        assert_eq!(exists.process_exit_code(), exit_codes::PRECONDITION);
+        let conflict = anyhow::Error::from(GitError::RebaseConflict {
+            target_branch: "main".into(),
+            git_output: String::new(),
+        });
+        assert_eq!(conflict.process_exit_code(), exit_codes::CONFLICT);
+
+        // Everything else is a generic error
+        assert_eq!(
+            anyhow::anyhow!("some unrelated failure").process_exit_code(),
+            exit_codes::ERROR
+        );
+        assert_eq!(
+            anyhow::Error::from(WorktrunkError::CommandNotApproved).process_exit_code(),
+            exit_codes::ERROR
+        );
+    }
+
+    #[test]
+    fn test_error_code() {
+        // GitError variants report their kebab-case name, through context
+        // and switch-suggestion wrappers
+        let missing = anyhow::Error::from(GitError::WithSwitchSuggestion {
+            source: Box::new(GitError::BranchNotFound {
+                branch: "feature".into(),
+                show_create_hint: true,
+                last_fetch_ago: None,
+                pr_mr_platform: None,
+            }),
+            ctx: SwitchSuggestionCtx {
+                extra_flags: vec![],
+                trailing_args: vec![],
+            },
+        })
+        .context("switching");
+        assert_eq!(missing.error_code(), "branch-not-found");
+        let conflict = anyhow::Error::from(GitError::RebaseConflict {
+            target_branch: "main".into(),
+            git_output: String::new(),
+        });
+        assert_eq!(conflict.error_code(), "rebase-conflict");
+
+        let hook = add_hook_skip_hint(
+            WorktrunkError::HookCommandFailed {
+                hook_type: HookType::PreMerge,
+                command_name: None,
+                error: "failed".into(),
+                exit_code: Some(1),
+            }
+            .into(),
+        );
+        assert_eq!(hook.error_code(), "hook-command-failed");
+
+        let command = anyhow::Error::from(CommandError {
+            program: "git".into(),
+            args: vec!["fetch".into()],
+            stderr: "fatal: no remote".into(),
+            stdout: String::new(),
+            exit_code: Some(128),
+        });
+        assert_eq!(command.error_code(), "command-failed");
+
+        assert_eq!(anyhow::anyhow!("untyped").error_code(), "error");
+    }
+
     #[test]
     fn test_interrupt_exit_code() {
         // Signal-derived child exit → 128 + sig
@@ -2053,6 +2524,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn snapshot_worktree_in_use() {
+        let err = GitError::WorktreeInUse {
+            branch: Some("feature".into()),
+            path: PathBuf::from("/tmp/repo.feature"),
+            processes: vec![(4242, "npm run dev".into()), (4300, "claude".into())],
+        };
+        assert_snapshot!(err.render(), @"
+        [31m✗[39m [31mCannot remove [1mfeature[22m, 2 processes are running in the worktree[39m
+        [107m [0m 4242 npm run dev
+        [107m [0m 4300 claude
+        [2m↳[22m [2mStop these processes first, or to remove anyway, run [4mwt remove --force feature[24m[22m
+        ");
+    }
+
     #[test]
     fn snapshot_not_rebased() {
         let err = GitError::NotRebased {
@@ -2064,6 +2550,34 @@ mod tests {
         ");
     }
 
+    #[test]
+    fn snapshot_stacked_branch() {
+        let err = GitError::StackedBranch {
+            branch: "feature-b".into(),
+            parent: "feature-a".into(),
+            target_branch: "main".into(),
+            commits: 2,
+        };
+        assert_snapshot!(err.render(), @"
+        [31m✗[39m [31m[1mfeature-b[22m was cut from [1mfeature-a[22m; merging into [1mmain[22m would also merge 2 commits from [1mfeature-a[22m[39m
+        [2m↳[22m [2mTo merge only [1mfeature-b[22m's commits, add [4m--stacked-base=rebase[24m; to merge into [1mfeature-a[22m instead, add [4m--stacked-base=parent[24m; to merge everything, add [4m--stacked-base=include[24m[22m
+        ");
+    }
+
+    #[test]
+    fn snapshot_ambiguous_branch() {
+        let err = GitError::AmbiguousBranch {
+            query: "login".into(),
+            matches: vec!["feature/login-form".into(), "fix/login-redirect".into()],
+        };
+        assert_snapshot!(err.render(), @"
+        [31m✗[39m [31m[1mlogin[22m matches 2 branches[39m
+        [107m [0m [1mfeature/login-form[22m
+        [107m [0m [1mfix/login-redirect[22m
+        [2m↳[22m [2mTo switch to one, name it in full, e.g. [4mwt switch feature/login-form[24m[22m
+        ");
+    }
+
     #[test]
     fn snapshot_hook_command_not_found() {
         let err = GitError::HookCommandNotFound {
@@ -2155,7 +2669,7 @@ mod tests {
         };
         assert_snapshot!(err.render(), @"
         [31m✗[39m [31mCannot remove worktree: [1mfeature[22m has uncommitted changes[39m
-        [2m↳[22m [2mCommit or stash changes first, or to lose uncommitted changes, run [4mwt remove --force feature[24m[22m
+        [2m↳[22m [2mCommit changes first, or to stash them, run [4mwt remove --stash feature[24m; to lose them, run [4mwt remove --force feature[24m[22m
         ");
 
         // With dirty_files populated — surfaces *which* files block the action.
@@ -13,6 +13,7 @@ pub mod remote_ref;
 pub mod remove;
 mod repository;
 mod url;
+pub mod worktree_processes;
 
 #[cfg(test)]
 mod test;
@@ -47,8 +48,9 @@ pub use error::{
     // distinct from their short single-line `Display`
     Diagnostic,
     // Extension methods on `anyhow::Error` (render_diagnostic,
-    // display_message, exit_code, interrupt_exit_code). Bring into scope
-    // to call them via method syntax.
+    // display_message, exit_code, process_exit_code, interrupt_exit_code,
+    // error_code).
+    // Bring into scope to call them via method syntax.
     ErrorExt,
     // Structured command failure info
     FailedCommand,
@@ -64,6 +66,8 @@ pub use error::{
     WorktrunkError,
     // Wrap a HookCommandFailed-bearing error with a --no-hooks hint
     add_hook_skip_hint,
+    // Documented process exit codes
+    exit_codes,
     // Render a single error via Diagnostic if it implements one
     try_render_diagnostic,
 };
@@ -71,13 +75,15 @@ pub use parse::{parse_porcelain_z, parse_untracked_files};
 pub use recover::{current_or_recover, cwd_removed_hint};
 pub use remove::{
     BranchDeletionMode, BranchDeletionOutcome, BranchDeletionResult, RemovalOutput, RemoveOptions,
-    delete_branch_if_safe, remove_worktree_with_cleanup, stage_worktree_removal,
-    stop_fsmonitor_daemon,
+    delete_branch_if_safe, forget_deleted_branch, remove_worktree_with_cleanup,
+    stage_worktree_removal, stop_fsmonitor_daemon,
 };
 pub use repository::sha_cache;
 pub use repository::{
-    Branch, BranchDiffSpec, CommitMessageDetail, IntegrationTargets, RefSnapshot, Repository,
-    ResolvedWorktree, TempIndex, WorkingTree, select_comparison_base, set_base_path,
+    BaseDrift, BaseSnapshot, Branch, BranchBase, BranchDiffSpec, CommitMessageDetail, ConfigOrigin,
+    IntegrationTargets, ListIndexEntry, ObjectIndexes, ObjectStore, RefSnapshot, Repository,
+    ResolvedWorktree, StackedBase, StashEntry, TempIndex, WorkingTree, select_comparison_base,
+    set_base_path,
 };
 pub use url::parse_owner_repo;
 pub use url::{GitRemoteUrl, GitRepoInfo, GitRepoProvider};
@@ -315,7 +321,7 @@ fn resolve_via_snapshot(
 }
 
 /// Category of branch for completion display
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum BranchCategory {
     /// Branch has an active worktree
     Worktree,
@@ -326,7 +332,7 @@ pub enum BranchCategory {
 }
 
 /// Branch information for shell completions
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CompletionBranch {
     /// Branch name (local name for remotes, e.g., "fix" not "origin/fix")
     pub name: String,
@@ -352,6 +358,10 @@ pub struct LocalBranch {
     /// `None` when no upstream is set, or when the configured upstream is gone
     /// (git reports `[gone]` via `%(upstream:track)`).
     pub upstream_short: Option<String>,
+    /// The configured upstream (e.g., "origin/feature") when it's gone from
+    /// the remote — typically deleted after its PR merged, then pruned by
+    /// `git fetch --prune`. `None` when the upstream exists or none is set.
+    pub gone_upstream: Option<String>,
 }
 
 /// A single remote-tracking branch entry from the branch inventory.
@@ -486,6 +496,7 @@ pub enum HookType {
     PostCommit,
     PreMerge,
     PostMerge,
+    PrePush,
     PreRemove,
     PostRemove,
 }
@@ -503,6 +514,7 @@ impl HookType {
             | HookType::PreCreate
             | HookType::PreCommit
             | HookType::PreMerge
+            | HookType::PrePush
             | HookType::PreRemove => true,
             HookType::PostSwitch
             | HookType::PostCreate
@@ -31,6 +31,9 @@
 //!    - [`ForceDelete`](BranchDeletionMode::ForceDelete): run `branch -D`
 //!      without the integration check.
 //!
+//!    A deleted branch's stored state (`worktrunk.state.<branch>.*`) goes
+//!    with it; see [`forget_deleted_branch`].
+//!
 //! # Example
 //!
 //! ```no_run
@@ -408,6 +411,7 @@ pub fn delete_branch_if_safe(
     // behavior for the Worktree path). The user explicitly chose -D.
     if force_delete {
         repo.run_command(&["branch", "-D", "--", branch_name])?;
+        forget_deleted_branch(repo, branch_name);
         return Ok(BranchDeletionResult {
             outcome: BranchDeletionOutcome::ForceDeleted,
             integration_target: target.to_string(),
@@ -443,6 +447,9 @@ pub fn delete_branch_if_safe(
         }
         None => BranchDeletionOutcome::NotDeleted,
     };
+    if matches!(outcome, BranchDeletionOutcome::Integrated(_)) {
+        forget_deleted_branch(repo, branch_name);
+    }
 
     Ok(BranchDeletionResult {
         outcome,
@@ -450,6 +457,15 @@ pub fn delete_branch_if_safe(
     })
 }
 
+/// Drop the stored state of a just-deleted branch (see
+/// [`Repository::clear_branch_state`]). Best-effort: the branch is already
+/// gone, and `wt config state gc` sweeps anything left behind.
+pub fn forget_deleted_branch(repo: &Repository, branch: &str) {
+    if let Err(e) = repo.clear_branch_state(branch) {
+        tracing::debug!(branch = %branch, error = %e, "Failed to clear state of deleted branch {branch}: {e}");
+    }
+}
+
 /// Atomically delete `refs/heads/<branch>` iff it currently points at
 /// `expected_sha`, and translate the result into a [`BranchDeletionOutcome`].
 ///
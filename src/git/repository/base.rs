@@ -0,0 +1,240 @@
+//! Where a branch was cut from, and whether that base still holds.
+//!
+//! A branch's base is the ref it was created from plus the commit that ref
+//! pointed at then. `wt switch --create` records both in git config as
+//! `worktrunk.state.<branch>.base`; for branches created any other way, the
+//! oldest reflog entry (`branch: Created from <ref>`) carries the same pair
+//! until reflog expiry drops it.
+//!
+//! Release-train repos re-cut release branches: `release/1.2` is force-pushed
+//! to a new history, or deleted outright. Branches cut from the old history
+//! keep its commits and need rebasing onto the new one; [`BaseDrift`] names
+//! that state.
+//!
+//! Stacked branches are cut from another feature branch. Merging one into
+//! trunk also merges the parent's commits, unless the parent landed first;
+//! [`StackedBase`] names that state.
+
+use super::Repository;
+
+/// The ref a branch was created from, and the commit it pointed at then.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BranchBase {
+    /// Ref name as given at creation (e.g. `origin/release/1.2`).
+    #[serde(rename = "ref")]
+    pub base_ref: String,
+    /// Commit the branch started from.
+    pub sha: String,
+}
+
+/// How a branch's recorded base has moved since the branch was cut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, strum::IntoStaticStr)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum BaseDrift {
+    /// The base ref no longer contains the commit the branch started from —
+    /// it was force-pushed or re-cut — while the branch still does.
+    Rewritten,
+    /// The base ref no longer exists.
+    Deleted,
+}
+
+/// A branch cut from another branch that still carries commits its merge
+/// target doesn't have.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackedBase {
+    /// The branch it was cut from.
+    pub parent: String,
+    /// Commit the branch started from — the tip of the parent's work it carries.
+    pub sha: String,
+    /// Commits up to `sha` that aren't on the target.
+    pub commits: usize,
+}
+
+impl Repository {
+    /// Record `base_ref` as the base of the newly created `branch`, at the
+    /// commit `branch` points to now.
+    pub fn record_branch_base(&self, branch: &str, base_ref: &str) -> anyhow::Result<()> {
+        let sha = self
+            .run_command(&[
+                "rev-parse",
+                "--verify",
+                "--end-of-options",
+                &format!("refs/heads/{branch}^{{commit}}"),
+            ])?
+            .trim()
+            .to_string();
+        let base = BranchBase {
+            base_ref: base_ref.to_string(),
+            sha,
+        };
+        self.set_config(
+            &format!("worktrunk.state.{branch}.base"),
+            &serde_json::to_string(&base)?,
+        )
+    }
+
+    /// The base `branch` was created from: recorded creation metadata first,
+    /// else the branch's reflog. `None` when neither knows, or the branch was
+    /// created from a bare `HEAD`.
+    pub fn branch_base(&self, branch: &str) -> Option<BranchBase> {
+        let recorded = self
+            .config_last(&format!("worktrunk.state.{branch}.base"))
+            .ok()
+            .flatten()
+            .and_then(|raw| serde_json::from_str(&raw).ok());
+        recorded.or_else(|| self.branch_base_from_reflog(branch))
+    }
+
+    fn branch_base_from_reflog(&self, branch: &str) -> Option<BranchBase> {
+        let output = self
+            .run_command(&[
+                "reflog",
+                "show",
+                "--format=%H%x00%gs",
+                &format!("refs/heads/{branch}"),
+                "--",
+            ])
+            .ok()?;
+        // Newest first; the creation entry is the last line.
+        parse_creation_entry(output.lines().last()?)
+    }
+
+    /// How `branch` (at `head_sha`) has drifted from its base, if at all.
+    ///
+    /// A base that is the branch's own upstream is skipped: a branch checked
+    /// out from `origin/feature` tracks it, and the upstream column already
+    /// reports when that side moves.
+    pub fn base_drift(
+        &self,
+        branch: &str,
+        head_sha: &str,
+    ) -> anyhow::Result<Option<(BranchBase, BaseDrift)>> {
+        let Some(base) = self.branch_base(branch) else {
+            return Ok(None);
+        };
+        if base.base_ref == branch
+            || self.branch(branch).upstream()?.as_deref() == Some(base.base_ref.as_str())
+        {
+            return Ok(None);
+        }
+
+        let Some(base_tip) = self.resolve_commit(&base.base_ref)? else {
+            return Ok(Some((base, BaseDrift::Deleted)));
+        };
+        // A local base is only as fresh as its last pull; check the upstream
+        // it tracks too, so a force-push shows up after a plain fetch.
+        let mut tips = vec![base_tip];
+        if let Some(upstream) = self.branch(&base.base_ref).upstream()?
+            && let Some(upstream_tip) = self.resolve_commit(&upstream)?
+        {
+            tips.push(upstream_tip);
+        }
+
+        for tip in &tips {
+            if !self.is_ancestor_by_sha(&base.sha, tip)? {
+                // Once the branch is rebased onto the new history it no
+                // longer carries the old start commit.
+                let drift = self
+                    .is_ancestor_by_sha(&base.sha, head_sha)?
+                    .then_some(BaseDrift::Rewritten);
+                return Ok(drift.map(|drift| (base, drift)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Whether merging `branch` (at `head_sha`) into `target` would also merge
+    /// commits from the branch it was cut from.
+    ///
+    /// Skipped when the base is the target itself (or a remote copy of it), and
+    /// once the branch has been rebased off the parent's commits. A parent that
+    /// was squash-merged still counts: its original commits aren't on the
+    /// target, so merging would bring them back.
+    pub fn stacked_base(
+        &self,
+        branch: &str,
+        head_sha: &str,
+        target: &str,
+    ) -> anyhow::Result<Option<StackedBase>> {
+        let Some(base) = self.branch_base(branch) else {
+            return Ok(None);
+        };
+        if base.base_ref == branch
+            || base.base_ref == target
+            || self.strip_remote_prefix(&base.base_ref).as_deref() == Some(target)
+            || self.branch(target).upstream()?.as_deref() == Some(base.base_ref.as_str())
+        {
+            return Ok(None);
+        }
+        if !self.is_ancestor_by_sha(&base.sha, head_sha)? {
+            return Ok(None);
+        }
+
+        let commits = self.count_commits(target, &base.sha)?;
+        Ok((commits > 0).then_some(StackedBase {
+            parent: base.base_ref,
+            sha: base.sha,
+            commits,
+        }))
+    }
+
+    /// Resolve `reference` to a commit SHA, `None` when it doesn't exist.
+    fn resolve_commit(&self, reference: &str) -> anyhow::Result<Option<String>> {
+        let output = self.run_command_output(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            "--end-of-options",
+            &format!("{reference}^{{commit}}"),
+        ])?;
+        Ok(output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string()))
+    }
+}
+
+/// Parse a `%H%x00%gs` reflog line into a base, if it is a branch-creation
+/// entry naming a ref other than `HEAD`.
+fn parse_creation_entry(line: &str) -> Option<BranchBase> {
+    let (sha, subject) = line.split_once('\0')?;
+    let base_ref = subject.strip_prefix("branch: Created from ")?.trim();
+    if base_ref.is_empty() || base_ref == "HEAD" {
+        return None;
+    }
+    Some(BranchBase {
+        base_ref: base_ref
+            .strip_prefix("refs/heads/")
+            .unwrap_or(base_ref)
+            .to_string(),
+        sha: sha.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_creation_entry() {
+        assert_eq!(
+            parse_creation_entry("abc123\0branch: Created from origin/release/1.2"),
+            Some(BranchBase {
+                base_ref: "origin/release/1.2".into(),
+                sha: "abc123".into(),
+            })
+        );
+        assert_eq!(
+            parse_creation_entry("abc123\0branch: Created from refs/heads/main")
+                .map(|b| b.base_ref),
+            Some("main".into())
+        );
+        assert_eq!(
+            parse_creation_entry("abc123\0branch: Created from HEAD"),
+            None
+        );
+        assert_eq!(parse_creation_entry("abc123\0commit: fix things"), None);
+        assert_eq!(parse_creation_entry("abc123"), None);
+    }
+}
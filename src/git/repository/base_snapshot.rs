@@ -0,0 +1,117 @@
+//! Named base snapshots: a commit pinned under a name so several worktrees
+//! can be created from exactly the same base.
+//!
+//! `wt snapshot create` records the default branch's commit (or another
+//! ref's) as `refs/worktrunk/snapshots/<name>`, and `wt switch --create
+//! --base snapshot:<name>` branches from it however far trunk has moved since.
+//!
+//! # Storage
+//!
+//! A ref rather than a git config value: the ref keeps the commit reachable,
+//! so a force-pushed or rebased trunk can't leave the snapshot pointing at a
+//! commit `git gc` has pruned.
+
+use color_print::cformat;
+
+use super::{GitError, Repository};
+
+/// Ref namespace holding the snapshots.
+const SNAPSHOT_REF_PREFIX: &str = "refs/worktrunk/snapshots/";
+
+/// A named snapshot and the commit it pins.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct BaseSnapshot {
+    pub name: String,
+    pub sha: String,
+    /// Subject of the pinned commit.
+    pub subject: String,
+}
+
+fn snapshot_ref(name: &str) -> String {
+    format!("{SNAPSHOT_REF_PREFIX}{name}")
+}
+
+impl Repository {
+    /// Commit pinned by snapshot `name`, `None` when there's no such snapshot.
+    pub fn base_snapshot_sha(&self, name: &str) -> anyhow::Result<Option<String>> {
+        self.peel_to_commit(&snapshot_ref(name))
+    }
+
+    /// Pin the commit `reference` points at as snapshot `name`, returning
+    /// that commit. Fails when the name isn't a valid ref component, the
+    /// snapshot already exists, or `reference` doesn't resolve to a commit.
+    pub fn create_base_snapshot(&self, name: &str, reference: &str) -> anyhow::Result<String> {
+        let snapshot = snapshot_ref(name);
+        let valid = self
+            .run_command_output(&["check-ref-format", &snapshot])?
+            .status
+            .success();
+        if name.is_empty() || !valid {
+            return Err(GitError::Other {
+                message: cformat!("Invalid snapshot name <bold>{name}</>"),
+            }
+            .into());
+        }
+        if self.base_snapshot_sha(name)?.is_some() {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "Snapshot <bold>{name}</> already exists; delete it first with <underline>wt snapshot delete {name}</>"
+                ),
+            }
+            .into());
+        }
+        let sha = self
+            .peel_to_commit(reference)?
+            .ok_or_else(|| GitError::ReferenceNotFound {
+                reference: reference.to_string(),
+            })?;
+        // An empty old value makes git refuse to overwrite a ref created
+        // since the check above.
+        self.run_command(&["update-ref", &snapshot, &sha, ""])?;
+        Ok(sha)
+    }
+
+    /// Commit `reference` peels to, `None` when it doesn't resolve.
+    fn peel_to_commit(&self, reference: &str) -> anyhow::Result<Option<String>> {
+        let output = self.run_command_output(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{reference}^{{commit}}"),
+        ])?;
+        Ok(output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string()))
+    }
+
+    /// Delete snapshot `name`; returns whether it existed.
+    pub fn delete_base_snapshot(&self, name: &str) -> anyhow::Result<bool> {
+        if self.base_snapshot_sha(name)?.is_none() {
+            return Ok(false);
+        }
+        self.run_command(&["update-ref", "-d", &snapshot_ref(name)])?;
+        Ok(true)
+    }
+
+    /// Every snapshot, sorted by name.
+    pub fn base_snapshots(&self) -> anyhow::Result<Vec<BaseSnapshot>> {
+        let output = self.run_command(&[
+            "for-each-ref",
+            "--format=%(refname)%00%(objectname)%00%(subject)",
+            SNAPSHOT_REF_PREFIX,
+        ])?;
+        Ok(output
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\0');
+                let name = fields.next()?.strip_prefix(SNAPSHOT_REF_PREFIX)?;
+                Some(BaseSnapshot {
+                    name: name.to_string(),
+                    sha: fields.next()?.to_string(),
+                    subject: fields.next().unwrap_or_default().to_string(),
+                })
+            })
+            .collect())
+    }
+}
@@ -98,6 +98,17 @@ impl<'a> Branch<'a> {
             .and_then(|b| b.upstream_short.clone()))
     }
 
+    /// The configured upstream of this branch when it's gone from its remote
+    /// (git's `[gone]` track state, as `git branch -vv` shows it) — the
+    /// remote branch was deleted and a `git fetch --prune` removed its
+    /// tracking ref. Reads the same inventory as [`Self::upstream`].
+    pub fn gone_upstream(&self) -> anyhow::Result<Option<String>> {
+        Ok(self
+            .repo
+            .local_branch(&self.name)?
+            .and_then(|b| b.gone_upstream.clone()))
+    }
+
     /// Unset the upstream tracking branch for this branch.
     ///
     /// This removes the tracking relationship, preventing accidental pushes
@@ -249,7 +249,18 @@ impl Repository {
     ///
     /// For remote branches, returns the local name (e.g., "fix" not "origin/fix")
     /// since `git worktree add path fix` auto-creates a tracking branch.
+    ///
+    /// Served from the `wt daemon` cache when its fingerprint is current (see
+    /// `ref_watch`).
     pub fn branches_for_completion(&self) -> anyhow::Result<Vec<CompletionBranch>> {
+        if let Some(branches) = self.cached_completion_branches() {
+            return Ok(branches);
+        }
+        self.scan_branches_for_completion()
+    }
+
+    /// [`Self::branches_for_completion`], always from the `git` scans.
+    pub(super) fn scan_branches_for_completion(&self) -> anyhow::Result<Vec<CompletionBranch>> {
         let worktrees = self.list_worktrees()?;
         let worktree_branches: HashSet<String> = worktrees
             .iter()
@@ -352,16 +363,19 @@ fn parse_local_branch_line(line: &str) -> Option<LocalBranch> {
     let committer_ts: i64 = parts.next()?.parse().ok()?;
     let upstream_short_raw = parts.next()?;
     let upstream_track = parts.next()?;
-    let upstream_short = if upstream_short_raw.is_empty() || upstream_track == "[gone]" {
-        None
+    let gone = upstream_track == "[gone]";
+    let upstream = (!upstream_short_raw.is_empty()).then(|| upstream_short_raw.to_string());
+    let (upstream_short, gone_upstream) = if gone {
+        (None, upstream)
     } else {
-        Some(upstream_short_raw.to_string())
+        (upstream, None)
     };
     Some(LocalBranch {
         name,
         commit_sha,
         committer_ts,
         upstream_short,
+        gone_upstream,
     })
 }
 
@@ -407,6 +421,24 @@ mod tests {
         assert_eq!(repo.default_branch_sha(), Some(expected));
     }
 
+    #[test]
+    fn parse_local_branch_line_separates_gone_upstream() {
+        let line = |upstream: &str, track: &str| {
+            ["feature", "abc123", "1700000000", upstream, track].join("\0")
+        };
+
+        let live = parse_local_branch_line(&line("origin/feature", "[ahead 1]")).unwrap();
+        assert_eq!(live.upstream_short.as_deref(), Some("origin/feature"));
+        assert_eq!(live.gone_upstream, None);
+
+        let gone = parse_local_branch_line(&line("origin/feature", "[gone]")).unwrap();
+        assert_eq!(gone.upstream_short, None);
+        assert_eq!(gone.gone_upstream.as_deref(), Some("origin/feature"));
+
+        let none = parse_local_branch_line(&line("", "")).unwrap();
+        assert_eq!((none.upstream_short, none.gone_upstream), (None, None));
+    }
+
     #[test]
     fn default_branch_sha_none_when_branch_missing_from_inventory() {
         // Stale `worktrunk.default-branch` config points at a branch that
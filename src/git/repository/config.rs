@@ -1,6 +1,6 @@
 //! Git config, hints, marker, and default branch operations for Repository.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 use color_print::cformat;
@@ -9,7 +9,29 @@ use crate::config::ProjectConfig;
 
 use super::{DefaultBranchName, GitError, Repository};
 
+/// A git config value and where git read it from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigOrigin {
+    pub key: String,
+    pub value: String,
+    /// As `--show-origin` prints it: `file:<path>`, `command line:`, …
+    pub origin: String,
+}
+
 impl Repository {
+    /// Every git config value the worktree at `path` sees, with its origin
+    /// (`git config --list --show-origin`).
+    ///
+    /// Not cached, and read per worktree: `includeIf` conditions and
+    /// `config.worktree` files can resolve a key differently in one worktree
+    /// than another. `wt config show --full` compares them.
+    pub fn worktree_config_origins(&self, path: &Path) -> anyhow::Result<Vec<ConfigOrigin>> {
+        let output =
+            self.worktree_at(path)
+                .run_command(&["config", "--list", "-z", "--show-origin"])?;
+        Ok(parse_config_origins_z(&output))
+    }
+
     /// Get a git config value. Returns None if the key doesn't exist.
     ///
     /// Reads from the bulk config map populated by the private
@@ -106,6 +128,60 @@ impl Repository {
         }
     }
 
+    /// Set `settings` in the git config of the worktree at `path` only
+    /// (`git config --worktree`), leaving every other worktree untouched.
+    ///
+    /// Per-worktree config needs `extensions.worktreeConfig`, which this
+    /// enables on first use. Once it's on, git reads `core.bare` and
+    /// `core.worktree` from the shared config in every worktree, so — as
+    /// git-worktree(1) prescribes — those move to the main worktree's own
+    /// `config.worktree` first; otherwise a bare repository's `core.bare = true`
+    /// would break all its linked worktrees.
+    pub fn set_worktree_config(
+        &self,
+        path: &Path,
+        settings: &[(String, String)],
+    ) -> anyhow::Result<()> {
+        if settings.is_empty() {
+            return Ok(());
+        }
+
+        let enabled = self
+            .run_command(&[
+                "config",
+                "--type=bool",
+                "--get",
+                "extensions.worktreeConfig",
+            ])
+            .is_ok_and(|value| value.trim() == "true");
+        if !enabled {
+            let common = self.git_common_dir().join("config");
+            let common = common.to_string_lossy();
+            let main = self.git_common_dir().join("config.worktree");
+            let main = main.to_string_lossy();
+            for key in ["core.bare", "core.worktree"] {
+                let Ok(value) = self.run_command(&["config", "--file", &common, "--get", key])
+                else {
+                    continue;
+                };
+                let value = value.trim();
+                if key == "core.bare" && value != "true" {
+                    continue;
+                }
+                self.run_command(&["config", "--file", &main, key, value])?;
+                self.run_command(&["config", "--file", &common, "--unset", key])?;
+            }
+            self.set_config("extensions.worktreeConfig", "true")?;
+        }
+
+        let wt = self.worktree_at(path);
+        for (key, value) in settings {
+            wt.run_command(&["config", "--worktree", key, value])
+                .with_context(|| format!("Failed to set {key} for the worktree"))?;
+        }
+        Ok(())
+    }
+
     /// Read a user-defined marker from `worktrunk.state.<branch>.marker` in git config.
     ///
     /// Markers are stored as JSON: `{"marker": "text", "set_at": unix_timestamp}`.
@@ -124,6 +200,25 @@ impl Repository {
         parsed.marker
     }
 
+    /// The WIP commit `wt switch` made on `branch` when leaving its worktree
+    /// dirty, from `worktrunk.state.<branch>.wip`.
+    pub fn wip_commit(&self, branch: &str) -> Option<String> {
+        self.config_last(&format!("worktrunk.state.{branch}.wip"))
+            .ok()
+            .flatten()
+            .filter(|s| !s.is_empty())
+    }
+
+    /// Record `sha` as the WIP commit `wt switch` made on `branch`.
+    pub fn set_wip_commit(&self, branch: &str, sha: &str) -> anyhow::Result<()> {
+        self.set_config(&format!("worktrunk.state.{branch}.wip"), sha)
+    }
+
+    /// Forget the WIP commit recorded for `branch`.
+    pub fn clear_wip_commit(&self, branch: &str) -> anyhow::Result<bool> {
+        self.unset_config(&format!("worktrunk.state.{branch}.wip"))
+    }
+
     /// Read user-defined branch-keyed marker.
     pub fn user_marker(&self, branch: Option<&str>) -> Option<String> {
         branch.and_then(|branch| self.branch_marker(branch))
@@ -246,6 +341,39 @@ impl Repository {
         Ok(result)
     }
 
+    /// Every per-branch `worktrunk.state.<branch>.*` key, as `(branch, key)`.
+    ///
+    /// Merge records (`.merged`) are left out: `wt step revert-merge
+    /// --restore` reads them to bring a deleted branch back.
+    pub fn branch_state_keys(&self) -> anyhow::Result<Vec<(String, String)>> {
+        let output = self.get_config_regexp(r"^worktrunk\.state\.")?;
+        let mut keys: Vec<(String, String)> = output
+            .lines()
+            .filter_map(|line| {
+                let config_key = line.split_once(' ').map_or(line, |(key, _)| key);
+                let (branch, field) = parse_state_config_key(config_key)?;
+                (field != "merged").then(|| (branch.to_string(), config_key.to_string()))
+            })
+            .collect();
+        keys.dedup();
+        Ok(keys)
+    }
+
+    /// Delete the stored state of `branch` once it's gone: marker, pin, vars,
+    /// recorded base and any journals. Returns how many keys were removed.
+    pub fn clear_branch_state(&self, branch: &str) -> anyhow::Result<usize> {
+        let mut cleared = 0;
+        for (_, key) in self
+            .branch_state_keys()?
+            .into_iter()
+            .filter(|(b, _)| b == branch)
+        {
+            self.unset_config(&key)?;
+            cleared += 1;
+        }
+        Ok(cleared)
+    }
+
     /// Set the previous branch in worktrunk.history for `wt switch -` support.
     ///
     /// Stores the branch we're switching FROM, so `wt switch -` can return to it.
@@ -297,10 +425,25 @@ impl Repository {
         self.config_last(&format!("worktrunk.hints.{name}"))
             .ok()
             .flatten()
-            .and_then(|s| s.parse::<u32>().ok())
+            .and_then(|s| {
+                let count = s.split_once('@').map_or(s.as_str(), |(count, _)| count);
+                count.parse::<u32>().ok()
+            })
             .unwrap_or(0)
     }
 
+    /// Return the stamp recorded with a hint's last display — the part after
+    /// `@` in `<count>@<stamp>`, written by [`mark_hint_shown_at`].
+    ///
+    /// [`mark_hint_shown_at`]: Self::mark_hint_shown_at
+    pub fn hint_stamp(&self, name: &str) -> Option<String> {
+        let value = self
+            .config_last(&format!("worktrunk.hints.{name}"))
+            .ok()
+            .flatten()?;
+        value.split_once('@').map(|(_, stamp)| stamp.to_string())
+    }
+
     /// Mark a hint as shown in this repo, incrementing its display counter.
     ///
     /// The first call writes `1`; subsequent calls increment. Legacy `"true"`
@@ -314,6 +457,16 @@ impl Repository {
         self.set_config_value(&format!("worktrunk.hints.{name}"), &next.to_string())
     }
 
+    /// Mark a hint as shown, recording `stamp` (a time or version) beside the
+    /// incremented counter as `<count>@<stamp>`.
+    pub fn mark_hint_shown_at(&self, name: &str, stamp: &str) -> anyhow::Result<()> {
+        let next = self.hint_count(name).saturating_add(1);
+        self.set_config_value(
+            &format!("worktrunk.hints.{name}"),
+            &format!("{next}@{stamp}"),
+        )
+    }
+
     /// Clear a hint so it will show again.
     ///
     /// Returns `true` if the hint was cleared, `false` if it didn't exist.
@@ -417,8 +570,14 @@ impl Repository {
     }
 
     /// Try to detect default branch from remote.
+    ///
+    /// Prefers the canonical remote (`[remote] upstream`) so a fork whose
+    /// default branch differs from the canonical repository's still detects
+    /// the mainline.
     fn detect_from_remote(&self) -> Option<String> {
-        let remote = self.primary_remote().ok()?;
+        let remote = self
+            .canonical_remote()
+            .or_else(|| self.primary_remote().ok())?;
 
         // Try git's local cache for this remote (e.g., origin/HEAD)
         if let Ok(branch) = self.local_default_branch(&remote) {
@@ -460,7 +619,9 @@ impl Repository {
     /// and the resolved branch doesn't exist, surfaces
     /// [`GitError::StaleDefaultBranch`] with cache-reset hints rather than
     /// the generic "branch not found" — the user didn't type that name,
-    /// the persisted cache did.
+    /// the persisted cache did. An explicit target that names a tag or
+    /// commit surfaces [`GitError::NotABranch`], since there's no ref to
+    /// update.
     pub fn require_target_branch(&self, target: Option<&str>) -> anyhow::Result<String> {
         let branch = self.resolve_target_branch(target)?;
         if !self.branch(&branch).exists()? {
@@ -470,6 +631,9 @@ impl Repository {
                 }
                 return Err(GitError::StaleDefaultBranch { branch }.into());
             }
+            if self.ref_exists(&branch)? {
+                return Err(GitError::NotABranch { reference: branch }.into());
+            }
             return Err(GitError::BranchNotFound {
                 branch,
                 show_create_hint: true,
@@ -720,6 +884,33 @@ fn parse_vars_config_key(config_key: &str) -> Option<(&str, &str)> {
         .rsplit_once(".vars.")
 }
 
+/// Split a `worktrunk.state.<branch>.<field>` config key into `(branch, field)`,
+/// reporting every `vars.<key>` entry as field `vars`.
+fn parse_state_config_key(config_key: &str) -> Option<(&str, &str)> {
+    if let Some((branch, _)) = parse_vars_config_key(config_key) {
+        return Some((branch, "vars"));
+    }
+    config_key
+        .strip_prefix("worktrunk.state.")?
+        .rsplit_once('.')
+}
+
+/// Parse `git config --list -z --show-origin`: each entry is `origin\0`
+/// followed by `key\nvalue\0`.
+fn parse_config_origins_z(stdout: &str) -> Vec<ConfigOrigin> {
+    let mut fields = stdout.split('\0');
+    let mut entries = Vec::new();
+    while let (Some(origin), Some(entry)) = (fields.next(), fields.next()) {
+        let (key, value) = entry.split_once('\n').unwrap_or((entry, ""));
+        entries.push(ConfigOrigin {
+            key: key.to_string(),
+            value: value.to_string(),
+            origin: origin.to_string(),
+        });
+    }
+    entries
+}
+
 /// Split a `branch.<name>.<key>` config key into `(branch, key)`.
 ///
 /// Uses `rsplit_once`: git config variable names cannot contain dots, so the
@@ -751,6 +942,41 @@ mod tests {
         assert_eq!(output, "");
     }
 
+    #[test]
+    fn test_clear_branch_state() {
+        let test = TestRepo::with_initial_commit();
+        let repo = Repository::at(test.root_path()).unwrap();
+
+        for key in [
+            "worktrunk.state.feature.marker",
+            "worktrunk.state.feature.pinned",
+            "worktrunk.state.feature.vars.ticket",
+            "worktrunk.state.feature.merged",
+            "worktrunk.state.feature.x.marker",
+            "worktrunk.state.other.marker",
+        ] {
+            repo.set_config(key, "{}").unwrap();
+        }
+
+        assert_eq!(repo.clear_branch_state("feature").unwrap(), 3);
+        let remaining: Vec<String> = repo
+            .get_config_regexp(r"^worktrunk\.state\.")
+            .unwrap()
+            .lines()
+            .map(|line| line.split_once(' ').unwrap().0.to_string())
+            .collect();
+        // The merge record stays for `revert-merge --restore`; a branch whose
+        // name extends `feature` is untouched
+        assert_eq!(
+            remaining,
+            [
+                "worktrunk.state.feature.merged",
+                "worktrunk.state.feature.x.marker",
+                "worktrunk.state.other.marker",
+            ]
+        );
+    }
+
     #[test]
     fn test_get_config_regexp_returns_matches() {
         let test = TestRepo::with_initial_commit();
@@ -821,6 +1047,25 @@ mod tests {
         assert_eq!(repo.hint_count("legacy"), 2);
     }
 
+    #[test]
+    fn test_hint_stamp_round_trip() {
+        let test = TestRepo::with_initial_commit();
+        let repo = Repository::at(test.root_path()).unwrap();
+
+        repo.mark_hint_shown("plain").unwrap();
+        assert_eq!(repo.hint_stamp("plain"), None);
+
+        repo.mark_hint_shown_at("daily", "1700000000").unwrap();
+        repo.mark_hint_shown_at("daily", "1700090000").unwrap();
+        assert_eq!(repo.hint_count("daily"), 2);
+        assert_eq!(repo.hint_stamp("daily").as_deref(), Some("1700090000"));
+
+        // A plain mark keeps counting but drops the stamp.
+        repo.mark_hint_shown("daily").unwrap();
+        assert_eq!(repo.hint_count("daily"), 3);
+        assert_eq!(repo.hint_stamp("daily"), None);
+    }
+
     /// The snapshot read must parse the same entries as the subprocess read,
     /// including branch names that themselves contain `.vars.`.
     #[test]
@@ -871,4 +1116,56 @@ mod tests {
         // `branch.sort` is git's own key, not a per-branch entry.
         assert!(!snapshot.contains_key("sort"));
     }
+
+    #[test]
+    fn test_parse_config_origins_z() {
+        let stdout = "file:.git/config\0core.bare\nfalse\0\
+file:/home/u/.gitconfig\0user.name\nA Person\0\
+command line:\0core.flag\0";
+        let entries = parse_config_origins_z(stdout);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[1].key, "user.name");
+        assert_eq!(entries[1].value, "A Person");
+        assert_eq!(entries[1].origin, "file:/home/u/.gitconfig");
+        // A bare boolean key has no value line.
+        assert_eq!(entries[2].key, "core.flag");
+        assert_eq!(entries[2].value, "");
+    }
+
+    /// An `includeIf "onbranch:…"` section applies only in the worktree with
+    /// that branch checked out, so config is read per worktree rather than
+    /// once for the whole repository.
+    #[test]
+    fn test_config_resolved_per_worktree_with_include_if() {
+        let mut test = TestRepo::with_initial_commit();
+        let feature = test.add_worktree("feature");
+        let include = test.root_path().join("feature.gitconfig");
+        std::fs::write(&include, "[worktrunk]\n\tdefault-branch = develop\n").unwrap();
+        test.run_git(&[
+            "config",
+            "includeIf.onbranch:feature.path",
+            include.to_str().unwrap(),
+        ]);
+
+        let main = Repository::at(test.root_path()).unwrap();
+        let other = Repository::at(&feature).unwrap();
+        assert_eq!(main.config_last("worktrunk.default-branch").unwrap(), None);
+        assert_eq!(
+            other.config_last("worktrunk.default-branch").unwrap(),
+            Some("develop".to_string())
+        );
+
+        let origin = main
+            .worktree_config_origins(&feature)
+            .unwrap()
+            .into_iter()
+            .find(|entry| entry.key == "worktrunk.default-branch")
+            .unwrap();
+        assert_eq!(origin.value, "develop");
+        assert!(
+            origin.origin.ends_with("feature.gitconfig"),
+            "{}",
+            origin.origin
+        );
+    }
 }
@@ -165,6 +165,42 @@ impl Repository {
         Ok(result)
     }
 
+    /// Author email of each of `commits`, keyed by full SHA. One batched
+    /// `git log --no-walk`, like [`Self::commit_details_many`].
+    pub fn commit_author_emails_many(
+        &self,
+        commits: &[&str],
+    ) -> anyhow::Result<HashMap<String, String>> {
+        if commits.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let mut args = vec![
+            "log",
+            "--no-walk",
+            "--no-show-signature",
+            "--format=%H%x00%ae",
+        ];
+        args.extend(commits);
+        let stdout = self.run_command(&args)?;
+        Ok(stdout
+            .lines()
+            .filter_map(|line| line.split_once('\0'))
+            .map(|(sha, email)| (sha.to_string(), email.to_string()))
+            .collect())
+    }
+
+    /// Author emails of the commits in `range`, newest first.
+    pub fn author_emails(&self, range: &str) -> anyhow::Result<Vec<String>> {
+        let output = self.run_command(&[
+            "log",
+            "--no-show-signature",
+            "--format=%ae",
+            "--end-of-options",
+            range,
+        ])?;
+        Ok(output.lines().map(str::to_string).collect())
+    }
+
     /// Get commit subjects and bodies from a range.
     pub fn commit_message_details(&self, range: &str) -> anyhow::Result<Vec<CommitMessageDetail>> {
         // Git pretty-format placeholders:
@@ -182,6 +218,25 @@ impl Repository {
         parse_commit_message_details_output(&output)
     }
 
+    /// Trailers (`Key: value` lines) of the commits in `range`, oldest commit
+    /// first, with folded continuation lines unfolded.
+    pub fn commit_trailers(&self, range: &str) -> anyhow::Result<Vec<String>> {
+        let output = self.run_command(&[
+            "log",
+            "--reverse",
+            "--no-show-signature",
+            "--format=%(trailers:only,unfold)",
+            "--end-of-options",
+            range,
+        ])?;
+        Ok(output
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
     /// Get recent commit subjects for style reference.
     ///
     /// Returns up to `count` commit subjects (first line of message), excluding merges.
@@ -269,6 +324,12 @@ impl Repository {
                     return Ok(e.insert(cached).clone());
                 }
 
+                #[cfg(feature = "gix")]
+                if let Some(result) = self.gix_merge_base(sha1, sha2) {
+                    super::sha_cache::put_merge_base(self, sha1, sha2, &result);
+                    return Ok(e.insert(result).clone());
+                }
+
                 // Exit codes: 0 = found, 1 = no common ancestor, 128+ = invalid ref
                 let output = self.run_command_output(&["merge-base", sha1, sha2])?;
 
@@ -325,7 +386,12 @@ impl Repository {
         // Both inputs are SHAs (the only caller is `ahead_behind_by_sha`), and
         // `merge_base_by_sha` returns a SHA, so the equality check is sound on
         // both sides.
-        let count = |range: String| -> anyhow::Result<usize> {
+        let count = |from: &str, to: &str| -> anyhow::Result<usize> {
+            #[cfg(feature = "gix")]
+            if let Some(count) = self.gix_count_range(from, to) {
+                return Ok(count);
+            }
+            let range = format!("{from}..{to}");
             let output = self.run_command(&["rev-list", "--count", &range])?;
             output
                 .trim()
@@ -335,12 +401,12 @@ impl Repository {
         let ahead = if merge_base == head {
             0
         } else {
-            count(format!("{merge_base}..{head}"))?
+            count(&merge_base, head)?
         };
         let behind = if merge_base == base {
             0
         } else {
-            count(format!("{merge_base}..{base}"))?
+            count(&merge_base, base)?
         };
 
         Ok((ahead, behind))
@@ -0,0 +1,140 @@
+//! In-process history queries through gitoxide, behind the `gix` feature.
+//!
+//! `wt list` spends most of its git time on per-row merge-base and
+//! `rev-list --count` forks. With the feature on, [`Repository`] answers
+//! those from a shared in-process handle instead; mutations, and every query
+//! gix can't answer (a missing object, an unsupported repository format),
+//! still go through the `git` subprocess. Results are identical either way —
+//! callers treat `None` as "ask git" — so the feature only changes speed.
+
+use std::fmt;
+
+use once_cell::sync::OnceCell;
+
+use super::Repository;
+
+/// Object cache per thread-local handle. gix's default of none makes repeated
+/// commit lookups across a walk re-decode the same objects.
+const OBJECT_CACHE_BYTES: usize = 8 * 1024 * 1024;
+
+/// The repository opened once per [`Repository`], shared across threads.
+#[derive(Default)]
+pub(crate) struct GixHandle(OnceCell<Option<gix::ThreadSafeRepository>>);
+
+impl fmt::Debug for GixHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("GixHandle")
+            .field(&self.0.get().map(|repo| repo.is_some()))
+            .finish()
+    }
+}
+
+impl Repository {
+    /// A thread-local gix handle, or `None` when gix can't open the
+    /// repository (callers then fall back to git).
+    ///
+    /// Opened at `git_common_dir`, where the object database and refs every
+    /// worktree shares live. Isolated from user and system config: only
+    /// history is read, and that doesn't depend on config.
+    fn gix(&self) -> Option<gix::Repository> {
+        let shared = self.cache.gix.0.get_or_init(|| {
+            let opts = gix::open::Options::isolated();
+            match gix::open_opts(&self.git_common_dir, opts) {
+                Ok(repo) => Some(repo.into_sync()),
+                Err(e) => {
+                    tracing::debug!("gix: falling back to git, open failed: {e}");
+                    None
+                }
+            }
+        });
+        let mut repo = shared.as_ref()?.to_thread_local();
+        repo.object_cache_size_if_unset(OBJECT_CACHE_BYTES);
+        Some(repo)
+    }
+
+    /// Merge-base of two commit SHAs: `Some(Some(sha))` when found,
+    /// `Some(None)` when the histories are unrelated, `None` to ask git.
+    pub(super) fn gix_merge_base(&self, sha1: &str, sha2: &str) -> Option<Option<String>> {
+        let repo = self.gix()?;
+        let one = gix::ObjectId::from_hex(sha1.as_bytes()).ok()?;
+        let two = gix::ObjectId::from_hex(sha2.as_bytes()).ok()?;
+        match repo.merge_base(one, two) {
+            Ok(base) => Some(Some(base.to_string())),
+            // gix reports a commit it can't find the same way as unrelated
+            // histories; only trust "unrelated" when both exist, so a bad SHA
+            // still surfaces git's error.
+            Err(gix::repository::merge_base::Error::NotFound { .. }) => {
+                let exists = |id| repo.find_commit(id).is_ok();
+                (exists(one) && exists(two)).then_some(None)
+            }
+            Err(e) => {
+                tracing::debug!("gix: merge-base {sha1} {sha2} failed: {e}");
+                None
+            }
+        }
+    }
+
+    /// Commits reachable from `to` but not from `from` — `git rev-list
+    /// --count from..to` — or `None` to ask git.
+    pub(super) fn gix_count_range(&self, from: &str, to: &str) -> Option<usize> {
+        let repo = self.gix()?;
+        let from = gix::ObjectId::from_hex(from.as_bytes()).ok()?;
+        let to = gix::ObjectId::from_hex(to.as_bytes()).ok()?;
+        let walk = match repo.rev_walk([to]).with_hidden([from]).all() {
+            Ok(walk) => walk,
+            Err(e) => {
+                tracing::debug!("gix: rev-walk {from}..{to} failed: {e}");
+                return None;
+            }
+        };
+        let mut count = 0;
+        for info in walk {
+            if let Err(e) = info {
+                tracing::debug!("gix: rev-walk {from}..{to} failed: {e}");
+                return None;
+            }
+            count += 1;
+        }
+        Some(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestRepo;
+
+    /// gix answers match git's for diverged, linear, and unrelated histories.
+    #[test]
+    fn test_gix_queries_match_git() {
+        let test = TestRepo::with_initial_commit();
+        let repo = Repository::at(test.root_path()).unwrap();
+        let base = repo.run_command(&["rev-parse", "HEAD"]).unwrap();
+        let base = base.trim();
+
+        test.run_git(&["checkout", "-q", "-b", "feature"]);
+        test.commit("feature one");
+        test.commit("feature two");
+        test.run_git(&["checkout", "-q", "main"]);
+        test.commit("main one");
+        test.run_git(&["checkout", "-q", "--orphan", "orphan"]);
+        test.commit("orphan root");
+
+        let sha = |rev: &str| {
+            repo.run_command(&["rev-parse", rev])
+                .unwrap()
+                .trim()
+                .to_string()
+        };
+        let (main, feature, orphan) = (sha("main"), sha("feature"), sha("orphan"));
+
+        assert_eq!(
+            repo.gix_merge_base(&main, &feature),
+            Some(Some(base.to_string()))
+        );
+        assert_eq!(repo.gix_merge_base(&main, &orphan), Some(None));
+        assert_eq!(repo.gix_count_range(base, &feature), Some(2));
+        assert_eq!(repo.gix_count_range(base, &main), Some(1));
+        assert_eq!(repo.gix_count_range(&feature, base), Some(0));
+    }
+}
@@ -550,7 +550,7 @@ impl Repository {
     /// Returns `None` when the default branch cannot be determined.
     pub fn integration_targets(&self, snapshot: &RefSnapshot) -> Option<IntegrationTargets> {
         let target = self.default_branch()?;
-        let upstream = snapshot.upstream_of(&target).map(str::to_string);
+        let upstream = self.target_upstream(snapshot, &target);
 
         let target_sha = snapshot_resolve(self, snapshot, &target).ok()?;
 
@@ -579,6 +579,22 @@ impl Repository {
         Some(IntegrationTargets { primary, secondary })
     }
 
+    /// The upstream ref a target branch is compared against.
+    ///
+    /// When a canonical remote is configured (`[remote] upstream`) and has the
+    /// target branch, that's `<remote>/<target>` — in a fork workflow the
+    /// target's own `@{upstream}` usually tracks the fork, not the mainline.
+    /// Otherwise the target's configured `@{upstream}` from the snapshot.
+    pub fn target_upstream(&self, snapshot: &RefSnapshot, target: &str) -> Option<String> {
+        if let Some(remote) = self.canonical_remote() {
+            let canonical = format!("{remote}/{target}");
+            if snapshot.resolve(&canonical).is_some() {
+                return Some(canonical);
+            }
+        }
+        snapshot.upstream_of(target).map(str::to_string)
+    }
+
     /// The upstream-aware comparison base for the diff/summary preview panes,
     /// resolved once and memoized (the base is repo-wide, like the default
     /// branch). Returns `None` when no default branch can be determined.
@@ -786,9 +802,8 @@ impl Repository {
         target: &str,
     ) -> anyhow::Result<(String, Option<IntegrationReason>)> {
         // Resolve upstream once. Errors and "no upstream" both collapse to None.
-        let upstream = snapshot
-            .upstream_of(target)
-            .map(str::to_string)
+        let upstream = self
+            .target_upstream(snapshot, target)
             .or_else(|| self.branch(target).upstream().ok().flatten());
 
         // Decide whether to check local, upstream, or both. All ancestor
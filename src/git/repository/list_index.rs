@@ -0,0 +1,113 @@
+//! Row shortcuts (`%N`) from the last `wt list` in this shell.
+//!
+//! Each table render of `wt list` records its row order; `%2` then resolves to
+//! the second row wherever a branch name is accepted (`wt switch %2`,
+//! `wt remove %2`, …). Listings are kept per shell session so two terminals
+//! listing different repos — or the same repo with different flags — don't
+//! renumber each other's rows.
+//!
+//! # Session key
+//!
+//! The session is the parent process of `wt`: the interactive shell, for both
+//! direct invocations and the shell-integration wrapper (which runs the binary
+//! as a direct child). Windows has no portable parent-PID query, so all
+//! sessions there share one listing.
+//!
+//! Listings are stored as cache entries under `<wt-state-dir>/cache/list-index/`
+//! — losing one only means running `wt list` again.
+
+use std::path::PathBuf;
+
+use color_print::cformat;
+use serde::{Deserialize, Serialize};
+
+use super::Repository;
+use crate::cache;
+use crate::git::GitError;
+
+const KIND: &str = "list-index";
+
+/// Listings kept across shell sessions before the oldest are swept.
+const MAX_SESSIONS: usize = 32;
+
+/// One row of a recorded listing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListIndexEntry {
+    /// Branch name; `None` for detached worktrees.
+    pub branch: Option<String>,
+    /// Worktree path; `None` for branch-only rows.
+    pub path: Option<PathBuf>,
+}
+
+/// Parse a `%N` row shortcut into its 1-based row number.
+pub(super) fn parse_list_index(name: &str) -> Option<usize> {
+    let digits = name.strip_prefix('%')?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn session_key() -> String {
+    #[cfg(unix)]
+    {
+        format!("{}.json", std::os::unix::process::parent_id())
+    }
+    #[cfg(not(unix))]
+    {
+        "default.json".to_string()
+    }
+}
+
+impl Repository {
+    /// Record the rows of a rendered `wt list`, in display order, for `%N`
+    /// shortcuts in this shell session.
+    pub fn set_last_listing(&self, entries: &[ListIndexEntry]) {
+        cache::write_with_lru(self, KIND, &session_key(), &entries, MAX_SESSIONS);
+    }
+
+    /// The rows recorded by the last `wt list` in this shell session.
+    pub fn last_listing(&self) -> Option<Vec<ListIndexEntry>> {
+        cache::read(self, KIND, &session_key())
+    }
+
+    /// Look up row `row` (1-based) of the last listing.
+    ///
+    /// Errors when nothing was listed in this shell or the row is out of range.
+    pub fn list_index_entry(&self, row: usize) -> anyhow::Result<ListIndexEntry> {
+        let Some(entries) = self.last_listing() else {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "No listing to resolve <bold>%{row}</> against. Run <underline>wt list</> first."
+                ),
+            }
+            .into());
+        };
+        let count = entries.len();
+        row.checked_sub(1)
+            .and_then(|idx| entries.into_iter().nth(idx))
+            .ok_or_else(|| {
+                GitError::Other {
+                    message: cformat!(
+                        "No row <bold>%{row}</> in the last listing ({count} rows). Run <underline>wt list</> to refresh it."
+                    ),
+                }
+                .into()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_row_shortcuts() {
+        assert_eq!(parse_list_index("%2"), Some(2));
+        assert_eq!(parse_list_index("%10"), Some(10));
+        assert_eq!(parse_list_index("%"), None);
+        assert_eq!(parse_list_index("2"), None);
+        assert_eq!(parse_list_index("%2a"), None);
+        assert_eq!(parse_list_index("%-1"), None);
+    }
+}
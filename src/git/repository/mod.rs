@@ -133,22 +133,36 @@ pub(super) use super::{
 };
 
 // Submodules with impl blocks
+mod base;
+mod base_snapshot;
 mod branch;
 mod branches;
 mod config;
 mod diff;
+#[cfg(feature = "gix")]
+mod gix_backend;
 mod integration;
+mod list_index;
+mod objects;
 mod ref_snapshot;
+mod ref_watch;
 mod remotes;
 pub mod sha_cache;
+mod stash;
 mod working_tree;
 mod worktrees;
 
 // Re-export WorkingTree, Branch, IntegrationTargets, and RefSnapshot
+pub use base::{BaseDrift, BranchBase, StackedBase};
+pub use base_snapshot::BaseSnapshot;
 pub use branch::Branch;
+pub use config::ConfigOrigin;
 pub use diff::CommitMessageDetail;
 pub use integration::{BranchDiffSpec, IntegrationTargets, select_comparison_base};
+pub use list_index::ListIndexEntry;
+pub use objects::{ObjectIndexes, ObjectStore};
 pub use ref_snapshot::RefSnapshot;
+pub use stash::StashEntry;
 pub(super) use working_tree::path_to_logging_context;
 pub use working_tree::{TempIndex, WorkingTree};
 
@@ -230,6 +244,8 @@ pub(super) struct RepoCache {
     pub(super) comparison_base: OnceCell<Option<integration::ComparisonBase>>,
     /// Project identifier derived from remote URL
     pub(super) project_identifier: OnceCell<String>,
+    /// Directory holding logs and caches (see [`Repository::wt_state_dir`])
+    pub(super) state_dir: OnceCell<PathBuf>,
     /// Project config (loaded from .config/wt.toml in main worktree)
     pub(super) project_config: OnceCell<Option<ProjectConfig>>,
     /// CI platform from project config (`forge.platform` / `ci.platform`).
@@ -247,6 +263,8 @@ pub(super) struct RepoCache {
     pub(super) resolved_config: OnceCell<ResolvedConfig>,
     /// Sparse checkout paths (empty if not a sparse checkout)
     pub(super) sparse_checkout_paths: OnceCell<Vec<String>>,
+    /// Stash list, newest first (see [`Repository::stashes`])
+    pub(super) stashes: OnceCell<Vec<stash::StashEntry>>,
     /// Merge-base cache: (sha1, sha2) -> merge_base_sha (None = no common ancestor).
     /// Keys are commit SHAs by contract — callers must resolve refs through
     /// a [`RefSnapshot`] before consulting. The key order is normalized
@@ -313,6 +331,10 @@ pub(super) struct RepoCache {
     /// Sits in front of the persistent `sha_cache` to prevent parallel tasks
     /// from racing through the file-based cache for the same SHA pair.
     pub(super) diff_stats: DashMap<(String, String), LineDiff>,
+    /// In-process gitoxide handle for merge-base and commit counts, opened on
+    /// first use.
+    #[cfg(feature = "gix")]
+    pub(super) gix: gix_backend::GixHandle,
 
     // ========== Per-worktree values (keyed by path) ==========
     //
@@ -541,11 +563,19 @@ impl Repository {
             let _ = cache.user_config.set(preloaded.clone());
         }
 
-        Ok(Self {
+        let mut repo = Self {
             discovery_path,
             git_common_dir,
             cache: Arc::new(cache),
-        })
+        };
+        // From inside the git directory (`.git/`, `.git/refs/`,
+        // `.git/worktrees/<name>/`), act as if run from the worktree that
+        // directory belongs to, so every command sees the same current
+        // worktree whatever the CWD depth.
+        if let Some(root) = repo.worktree_owning_discovery_path() {
+            repo.discovery_path = root;
+        }
+        Ok(repo)
     }
 
     /// Eagerly populate the process-wide git-discovery caches
@@ -754,13 +784,11 @@ impl Repository {
     /// from `discovery_path` and stashes the parsed map in
     /// [`GIT_CONFIG_PRELOAD`] for [`Repository::at`] to consume.
     ///
-    /// Runs from `discovery_path` rather than `git_common_dir` (which we
-    /// don't know yet — the rev-parse thread is racing in parallel). Git's
-    /// `config --list` normally emits the same merged system + global + local
-    /// config from any path inside the repo, including linked worktrees of
-    /// bare repos: linked worktrees and the common dir share one config file,
-    /// so the merged output is identical to the existing `git_common_dir`
-    /// invocation in [`Repository::all_config`].
+    /// Runs from `discovery_path`, like the on-demand read in
+    /// [`Repository::all_config`], so the map is this worktree's view:
+    /// conditional includes evaluate against the worktree, and linked
+    /// worktrees of bare repos read the shared config file they have in
+    /// common with the bare repo.
     ///
     /// **`extensions.worktreeConfig` exception** ([#2779]): when the
     /// extension is enabled, each worktree gets its own `config.worktree`
@@ -773,9 +801,9 @@ impl Repository {
     /// incomplete map would cause `is_bare()` to read `false`, and the
     /// `repo_path()` fallback would walk one level too high. So when the
     /// parsed map contains `extensions.worktreeconfig=true`, we skip the
-    /// preload entirely — [`Repository::all_config`] re-forks from
-    /// `git_common_dir`, which sees the full merged set (one extra
-    /// subprocess in this layout; the prewarm benefit is preserved for all
+    /// preload entirely — [`Repository::all_config`] re-forks and fills the
+    /// missing keys from `git_common_dir`, which sees them (two extra
+    /// subprocesses in this layout; the prewarm benefit is preserved for all
     /// other repos).
     ///
     /// Failures (non-repo directory, corrupted config) are swallowed; the
@@ -824,6 +852,11 @@ impl Repository {
     /// a test that already populated the lock) we drop the second value.
     fn prewarm_user_config() {
         let (config, warnings) = UserConfig::load_with_warnings();
+        // `[theme]` recolors, and `output.ascii` picks glyphs for, everything
+        // printed from here on — these warnings included; nothing
+        // command-specific has printed yet.
+        crate::styling::set_theme(config.theme.theme());
+        crate::styling::set_ascii(config.output.ascii());
         emit_user_config_warnings(&warnings);
         let _ = WORKTRUNK_USER_CONFIG_PRELOAD.set(config);
     }
@@ -917,6 +950,33 @@ impl Repository {
         Ok(resolved)
     }
 
+    /// The worktree whose git directory contains the discovery path, when
+    /// that path is inside the git directory rather than a working tree.
+    ///
+    /// `.git/worktrees/<name>/...` maps to the linked worktree recorded in
+    /// that directory's `gitdir` file; anywhere else under the common dir
+    /// maps to the main worktree. `None` outside the git directory, and for
+    /// bare repositories, whose common dir has no worktree of its own.
+    fn worktree_owning_discovery_path(&self) -> Option<PathBuf> {
+        let path = canonicalize(&self.discovery_path).ok()?;
+        let relative = path.strip_prefix(&self.git_common_dir).ok()?;
+        let mut components = relative.components();
+        if components
+            .next()
+            .is_some_and(|c| c.as_os_str() == "worktrees")
+            && let Some(name) = components.next()
+        {
+            let admin_dir = self.git_common_dir.join("worktrees").join(name);
+            let gitdir = std::fs::read_to_string(admin_dir.join("gitdir")).ok()?;
+            let root = Path::new(gitdir.trim()).parent()?;
+            return canonicalize(root).ok();
+        }
+        if self.is_bare().unwrap_or(true) {
+            return None;
+        }
+        self.repo_path().ok().map(Path::to_path_buf)
+    }
+
     /// Get the path this repository was discovered from.
     ///
     /// This is primarily for internal use. For worktree operations,
@@ -1084,11 +1144,45 @@ impl Repository {
         self.git_common_dir().join("wt")
     }
 
+    /// Get the directory holding worktrunk logs, caches, and other
+    /// file-backed state.
+    ///
+    /// Defaults to [`wt_dir`](Self::wt_dir). When the user config sets
+    /// `state-dir`, returns `<state-dir>/<project-id>/` instead, so this state
+    /// survives a re-clone and stays out of backups of the repository. On
+    /// first use, the existing directories ([`STATE_SUBDIRS`]) are moved there
+    /// from the git directory.
+    ///
+    /// Trash and staging directories stay under [`wt_dir`](Self::wt_dir)
+    /// regardless: they rely on same-filesystem renames from the worktrees.
+    /// State kept in git config (`worktrunk.*` keys) stays with the
+    /// repository too.
+    pub fn wt_state_dir(&self) -> PathBuf {
+        self.cache
+            .state_dir
+            .get_or_init(|| {
+                let Some(base) = self.user_config().state_dir() else {
+                    return self.wt_dir();
+                };
+                let project = match self.project_identifier() {
+                    Ok(project) => project,
+                    Err(e) => {
+                        tracing::debug!(error = %e, "state-dir: no project identifier, using git dir: {e}");
+                        return self.wt_dir();
+                    }
+                };
+                let dir = base.join(crate::path::sanitize_for_filename(&project));
+                migrate_state(&self.wt_dir(), &dir);
+                dir
+            })
+            .clone()
+    }
+
     /// Get the directory where worktrunk background logs are stored.
     ///
-    /// Returns `<git-common-dir>/wt/logs/` (typically `.git/wt/logs/`).
+    /// Returns `<wt-state-dir>/logs/` (typically `.git/wt/logs/`).
     pub fn wt_logs_dir(&self) -> PathBuf {
-        self.wt_dir().join("logs")
+        self.wt_state_dir().join("logs")
     }
 
     /// Get the directory where worktrees are staged for background deletion.
@@ -1183,23 +1277,32 @@ impl Repository {
     /// lazily on first access; every config-reading accessor consults this
     /// map rather than spawning its own subprocess.
     ///
-    /// Run from `git_common_dir` so linked worktrees of bare repos correctly
-    /// read the bare repo's config.
+    /// Run from `discovery_path`, the same as [`Self::prewarm_git_config`],
+    /// so the map is what git itself sees in this worktree: conditional
+    /// includes (`includeIf "gitdir:…"`, `"onbranch:…"`) evaluate against it
+    /// and its own `config.worktree` applies. Under `extensions.worktreeConfig`
+    /// a linked worktree's view misses the main worktree's `config.worktree`
+    /// — including a bare layout's `core.bare = true` ([#2779]) — so keys
+    /// only `git_common_dir` sees are filled in from there, and `core.bare`,
+    /// which describes the repository rather than a worktree, is taken from
+    /// there outright.
+    ///
+    /// [#2779]: https://github.com/max-sixty/worktrunk/issues/2779
     pub(super) fn all_config(
         &self,
     ) -> anyhow::Result<&std::sync::RwLock<indexmap::IndexMap<String, Vec<String>>>> {
         self.cache.all_config.get_or_try_init(|| {
-            let output = Cmd::new("git")
-                .args(["config", "--list", "-z"])
-                .current_dir(&self.git_common_dir)
-                .context(path_to_logging_context(&self.git_common_dir))
-                .run()
-                .context("failed to read git config")?;
-            if !output.status.success() {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                bail!("git config --list failed: {}", stderr.trim());
+            let mut map = read_config_list(&self.discovery_path)?;
+            if worktree_config_enabled(&map) && self.discovery_path != self.git_common_dir {
+                for (key, values) in read_config_list(&self.git_common_dir)? {
+                    if key == "core.bare" {
+                        map.insert(key, values);
+                    } else {
+                        map.entry(key).or_insert(values);
+                    }
+                }
             }
-            Ok(std::sync::RwLock::new(parse_config_list_z(&output.stdout)))
+            Ok(std::sync::RwLock::new(map))
         })
     }
 
@@ -1329,51 +1432,10 @@ impl Repository {
     }
 
     /// Get merge/rebase status for the worktree at this repository's discovery path.
+    ///
+    /// See [`WorkingTree::operation_state`].
     pub fn worktree_state(&self) -> anyhow::Result<Option<String>> {
-        let git_dir = self.worktree_at(self.discovery_path()).git_dir()?;
-
-        // Check for merge
-        if git_dir.join("MERGE_HEAD").exists() {
-            return Ok(Some("MERGING".to_string()));
-        }
-
-        // Check for rebase. `rebase-merge` (interactive/merge backend) and
-        // `rebase-apply` (am backend) are mutually exclusive; probe each once.
-        let rebase_merge = git_dir.join("rebase-merge");
-        let rebase_apply = git_dir.join("rebase-apply");
-        if let Some(rebase_dir) = rebase_merge
-            .exists()
-            .then_some(rebase_merge)
-            .or_else(|| rebase_apply.exists().then_some(rebase_apply))
-        {
-            if let (Ok(msgnum), Ok(end)) = (
-                std::fs::read_to_string(rebase_dir.join("msgnum")),
-                std::fs::read_to_string(rebase_dir.join("end")),
-            ) {
-                let current = msgnum.trim();
-                let total = end.trim();
-                return Ok(Some(format!("REBASING {}/{}", current, total)));
-            }
-
-            return Ok(Some("REBASING".to_string()));
-        }
-
-        // Check for cherry-pick
-        if git_dir.join("CHERRY_PICK_HEAD").exists() {
-            return Ok(Some("CHERRY-PICKING".to_string()));
-        }
-
-        // Check for revert
-        if git_dir.join("REVERT_HEAD").exists() {
-            return Ok(Some("REVERTING".to_string()));
-        }
-
-        // Check for bisect
-        if git_dir.join("BISECT_LOG").exists() {
-            return Ok(Some("BISECTING".to_string()));
-        }
-
-        Ok(None)
+        self.worktree_at(self.discovery_path()).operation_state()
     }
 
     // =========================================================================
@@ -1594,6 +1656,21 @@ fn worktree_config_enabled(parsed: &indexmap::IndexMap<String, Vec<String>>) ->
         .unwrap_or(false)
 }
 
+/// Run `git config --list -z` in `dir` and parse it.
+fn read_config_list(dir: &Path) -> anyhow::Result<indexmap::IndexMap<String, Vec<String>>> {
+    let output = Cmd::new("git")
+        .args(["config", "--list", "-z"])
+        .current_dir(dir)
+        .context(path_to_logging_context(dir))
+        .run()
+        .context("failed to read git config")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git config --list failed: {}", stderr.trim());
+    }
+    Ok(parse_config_list_z(&output.stdout))
+}
+
 /// Parse the output of `git config --list -z`.
 ///
 /// Format: each entry is `key\nvalue\0`. Values may be empty (no `\n`) for
@@ -1622,6 +1699,51 @@ fn parse_config_list_z(stdout: &[u8]) -> indexmap::IndexMap<String, Vec<String>>
     map
 }
 
+/// Subdirectories of [`Repository::wt_state_dir`] that `state-dir` relocates.
+///
+/// Transient entries (the merge queue's lock files) aren't moved: a merge
+/// waiting in the old queue finishes there.
+const STATE_SUBDIRS: &[&str] = &["logs", "cache", "state", "hook-status", "stats", "includes"];
+
+/// Move the [`STATE_SUBDIRS`] from the in-repo `wt/` directory into a
+/// relocated state directory (`state-dir`).
+///
+/// Best-effort: an entry that already exists at the new location is left
+/// alone, and a failed move leaves the old data in place — the new location
+/// then starts empty, as after `wt config state clear`.
+fn migrate_state(old_root: &Path, new_root: &Path) {
+    for name in STATE_SUBDIRS {
+        let old = old_root.join(name);
+        let new = new_root.join(name);
+        if !old.is_dir() || new.exists() {
+            continue;
+        }
+        if let Err(e) = move_dir(&old, &new) {
+            tracing::debug!(from = %old.display(), to = %new.display(), error = %e, "state-dir: failed to migrate {}: {e:#}", old.display());
+        }
+    }
+}
+
+/// Rename `from` to `to`, falling back to copy-then-delete across filesystems.
+fn move_dir(from: &Path, to: &Path) -> anyhow::Result<()> {
+    if let Some(parent) = to.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("creating {}", parent.display()))?;
+    }
+    if std::fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    crate::copy::copy_dir_recursive(
+        from,
+        to,
+        None,
+        false,
+        &crate::progress::Progress::disabled(),
+    )?;
+    std::fs::remove_dir_all(from).with_context(|| format!("removing {}", from.display()))?;
+    Ok(())
+}
+
 /// Emit `UserConfig::load_with_warnings` warnings to stderr.
 ///
 /// Shared by [`Repository::prewarm_user_config`] (preload thread on `main`)
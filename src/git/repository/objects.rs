@@ -0,0 +1,177 @@
+//! Object store size and sharing for Repository.
+//!
+//! Every worktree of a repository reads the same `objects/` directory under the
+//! common git dir, so history is stored once no matter how many worktrees are
+//! checked out. A repository can additionally borrow objects from another one
+//! through `objects/info/alternates` (set up by `git clone --reference` or
+//! `--shared`); those objects count against the other repository's disk, not
+//! this one's.
+//!
+//! Two optional indexes over the object store speed up the history walks wt
+//! runs for every worktree: the commit-graph (`rev-list --count`, merge-base,
+//! ancestry checks) and the multi-pack-index (object lookups across packs).
+//! Git only writes them during `gc`/`maintenance`, so clones that never ran
+//! either have neither.
+
+use std::path::PathBuf;
+
+use super::Repository;
+
+/// Size and sharing of a repository's object store, from `git count-objects -v`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ObjectStore {
+    /// Bytes on disk for loose objects, packs, and pack garbage.
+    pub bytes: u64,
+    /// Object stores this repository borrows from via alternates.
+    pub alternates: Vec<PathBuf>,
+}
+
+impl ObjectStore {
+    /// Parse `git count-objects -v` output.
+    ///
+    /// Sizes are reported in KiB. `alternate:` lines (one per borrowed store)
+    /// are only present when the repository has alternates.
+    fn parse(output: &str) -> Self {
+        let mut store = Self::default();
+        for line in output.lines() {
+            let Some((key, value)) = line.split_once(": ") else {
+                continue;
+            };
+            match key {
+                "size" | "size-pack" | "size-garbage" => {
+                    store.bytes += value.trim().parse::<u64>().unwrap_or(0) * 1024;
+                }
+                "alternate" => {
+                    // Paths with special characters are quoted C-style; strip
+                    // the surrounding quotes for display.
+                    let path = value.trim().trim_matches('"');
+                    store.alternates.push(PathBuf::from(path));
+                }
+                _ => {}
+            }
+        }
+        store
+    }
+}
+
+/// Which object-store indexes exist on disk.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ObjectIndexes {
+    /// `objects/info/commit-graph`, or a split `commit-graphs/` chain.
+    pub commit_graph: bool,
+    /// `objects/pack/multi-pack-index`.
+    pub multi_pack_index: bool,
+    /// Number of packfiles in `objects/pack`.
+    pub packs: usize,
+}
+
+impl ObjectIndexes {
+    /// Names of the indexes worth writing, for display.
+    ///
+    /// A multi-pack-index only pays off once objects are spread across
+    /// several packs, and can't be written with none.
+    pub fn missing(&self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if !self.commit_graph {
+            missing.push("commit-graph");
+        }
+        if !self.multi_pack_index && self.packs > 1 {
+            missing.push("multi-pack-index");
+        }
+        missing
+    }
+}
+
+impl Repository {
+    /// Size of the shared object store and the stores it borrows from.
+    pub fn object_store(&self) -> anyhow::Result<ObjectStore> {
+        let output = self.run_command(&["count-objects", "-v"])?;
+        Ok(ObjectStore::parse(&output))
+    }
+
+    /// Which commit-graph and multi-pack-index files the shared object store has.
+    pub fn object_indexes(&self) -> ObjectIndexes {
+        let objects = self.git_common_dir().join("objects");
+        let packs = std::fs::read_dir(objects.join("pack"))
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "pack"))
+                    .count()
+            })
+            .unwrap_or(0);
+        ObjectIndexes {
+            commit_graph: objects.join("info/commit-graph").is_file()
+                || objects
+                    .join("info/commit-graphs/commit-graph-chain")
+                    .is_file(),
+            multi_pack_index: objects.join("pack/multi-pack-index").is_file(),
+            packs,
+        }
+    }
+
+    /// Write the commit-graph (and the multi-pack-index, when there are packs
+    /// to index), and keep the commit-graph current on later fetches via
+    /// `fetch.writeCommitGraph`.
+    pub fn write_object_indexes(&self) -> anyhow::Result<()> {
+        self.run_command(&["commit-graph", "write", "--reachable", "--changed-paths"])?;
+        if self.object_indexes().packs > 0 {
+            self.run_command(&["multi-pack-index", "write"])?;
+        }
+        self.set_config("fetch.writeCommitGraph", "true")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_count_objects() {
+        let output = "count: 12\nsize: 48\nin-pack: 300\npacks: 1\nsize-pack: 1024\nprune-packable: 0\ngarbage: 0\nsize-garbage: 0\n";
+        assert_eq!(
+            ObjectStore::parse(output),
+            ObjectStore {
+                bytes: (48 + 1024) * 1024,
+                alternates: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_object_indexes_missing() {
+        let none = ObjectIndexes::default();
+        assert_eq!(none.missing(), vec!["commit-graph"]);
+
+        let many_packs = ObjectIndexes {
+            packs: 3,
+            ..ObjectIndexes::default()
+        };
+        assert_eq!(
+            many_packs.missing(),
+            vec!["commit-graph", "multi-pack-index"]
+        );
+
+        let indexed = ObjectIndexes {
+            commit_graph: true,
+            multi_pack_index: true,
+            packs: 3,
+        };
+        assert!(indexed.missing().is_empty());
+    }
+
+    #[test]
+    fn test_parse_count_objects_with_alternates() {
+        let output = "count: 0\nsize: 0\nin-pack: 0\npacks: 0\nsize-pack: 0\nprune-packable: 0\ngarbage: 0\nsize-garbage: 0\nalternate: /srv/mirror/objects\nalternate: \"/srv/with space/objects\"\n";
+        let store = ObjectStore::parse(output);
+        assert_eq!(store.bytes, 0);
+        assert_eq!(
+            store.alternates,
+            vec![
+                PathBuf::from("/srv/mirror/objects"),
+                PathBuf::from("/srv/with space/objects"),
+            ]
+        );
+    }
+}
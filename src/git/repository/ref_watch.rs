@@ -0,0 +1,172 @@
+//! Stat-based change detection for refs, HEADs, and indexes, plus the
+//! completion cache it validates.
+//!
+//! Git updates every file `wt` derives branch and worktree inventories from —
+//! loose refs, `packed-refs`, each worktree's `HEAD` and `index` — by writing a
+//! `.lock` file and renaming it into place. The rename gives the file a new
+//! mtime and bumps its directory's, so hashing the stat data of those files
+//! and of the `refs/` directory tree catches every such change without
+//! reading a single ref. False positives only cost a recompute.
+//!
+//! The git dir's own mtime is left out: creating `.git/wt/` (which the cache
+//! itself does) would otherwise invalidate the entry it just wrote.
+//!
+//! # Completion cache
+//!
+//! `wt daemon` calls [`Repository::warm_completion_cache`] whenever the
+//! fingerprint changes; [`Repository::branches_for_completion`] serves the
+//! cached candidates while their fingerprint still matches and falls back to
+//! the `git` scans otherwise. Only the daemon writes the cache, so a repo it
+//! doesn't watch never grows one.
+
+use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{CompletionBranch, Repository};
+use crate::cache;
+
+const KIND: &str = "completion";
+const KEY: &str = "branches.json";
+
+#[derive(Serialize, Deserialize)]
+struct CachedCompletion {
+    fingerprint: String,
+    branches: Vec<CompletionBranch>,
+}
+
+impl Repository {
+    /// Hash of the stat data (mtime, size) of everything branch and worktree
+    /// inventories depend on. Changes whenever a ref, a worktree's `HEAD` or
+    /// `index`, or the set of worktrees changes.
+    pub fn ref_fingerprint(&self) -> String {
+        let common = self.git_common_dir();
+        let mut hasher = Sha256::new();
+
+        for file in ["HEAD", "index", "packed-refs"] {
+            hash_stat(&mut hasher, &common.join(file));
+        }
+        hash_dir_tree(&mut hasher, &common.join("refs"));
+        // reftable-format repos keep refs here instead.
+        hash_stat(&mut hasher, &common.join("reftable"));
+
+        let worktrees = common.join("worktrees");
+        hash_stat(&mut hasher, &worktrees);
+        let mut linked: Vec<_> = fs::read_dir(&worktrees)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .collect();
+        linked.sort();
+        for dir in linked {
+            for file in ["HEAD", "index", "gitdir", "locked"] {
+                hash_stat(&mut hasher, &dir.join(file));
+            }
+        }
+
+        hasher
+            .finalize()
+            .iter()
+            .take(16)
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    /// Completion candidates cached by [`Self::warm_completion_cache`], if
+    /// they were computed at the current [`Self::ref_fingerprint`].
+    pub(super) fn cached_completion_branches(&self) -> Option<Vec<CompletionBranch>> {
+        let cached: CachedCompletion = cache::read(self, KIND, KEY)?;
+        (cached.fingerprint == self.ref_fingerprint()).then_some(cached.branches)
+    }
+
+    /// Recompute the completion candidates and cache them under the current
+    /// fingerprint.
+    ///
+    /// The fingerprint is taken before the scans: a ref that moves mid-scan
+    /// leaves a cache that no longer matches, rather than one that matches
+    /// stale candidates.
+    pub fn warm_completion_cache(&self) -> anyhow::Result<()> {
+        let fingerprint = self.ref_fingerprint();
+        let branches = self.scan_branches_for_completion()?;
+        cache::write_json(
+            &cache::cache_dir(self, KIND).join(KEY),
+            &CachedCompletion {
+                fingerprint,
+                branches,
+            },
+        );
+        Ok(())
+    }
+}
+
+fn hash_stat(hasher: &mut Sha256, path: &Path) {
+    hasher.update(path.as_os_str().as_encoded_bytes());
+    match fs::metadata(path) {
+        Ok(meta) => {
+            let mtime = meta
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map_or(0, |d| d.as_nanos());
+            hasher.update(mtime.to_le_bytes());
+            hasher.update(meta.len().to_le_bytes());
+        }
+        Err(_) => hasher.update(b"-"),
+    }
+}
+
+/// Hash `dir` and every directory below it. Files are covered by their
+/// directory's mtime, since git replaces them by rename.
+fn hash_dir_tree(hasher: &mut Sha256, dir: &Path) {
+    hash_stat(hasher, dir);
+    let mut subdirs: Vec<_> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .map(|entry| entry.path())
+        .collect();
+    subdirs.sort();
+    for subdir in subdirs {
+        hash_dir_tree(hasher, &subdir);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::TestRepo;
+
+    use super::*;
+
+    #[test]
+    fn fingerprint_tracks_ref_changes() {
+        let test = TestRepo::with_initial_commit();
+        let repo = Repository::at(test.root_path()).unwrap();
+
+        let before = repo.ref_fingerprint();
+        assert_eq!(repo.ref_fingerprint(), before);
+
+        test.run_git(&["branch", "feature"]);
+        assert_ne!(repo.ref_fingerprint(), before);
+    }
+
+    #[test]
+    fn completion_cache_invalidates_on_ref_change() {
+        let test = TestRepo::with_initial_commit();
+        let repo = Repository::at(test.root_path()).unwrap();
+
+        assert!(repo.cached_completion_branches().is_none());
+        repo.warm_completion_cache().unwrap();
+        let cached = repo.cached_completion_branches().unwrap();
+        assert_eq!(cached.len(), 1);
+
+        test.run_git(&["branch", "feature"]);
+        assert!(repo.cached_completion_branches().is_none());
+        let fresh = Repository::at(test.root_path()).unwrap();
+        assert_eq!(fresh.branches_for_completion().unwrap().len(), 2);
+    }
+}
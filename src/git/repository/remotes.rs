@@ -132,6 +132,20 @@ impl Repository {
         first_remote.ok_or_else(|| anyhow::anyhow!("No remotes configured"))
     }
 
+    /// The canonical remote for fork-based workflows, if configured.
+    ///
+    /// Reads `[remote] upstream` from the resolved user config. Returns `None`
+    /// when unset or when the named remote has no URL, so a config written
+    /// for one clone doesn't break a clone that lacks that remote.
+    pub fn canonical_remote(&self) -> Option<String> {
+        let remote = self.config().remote.upstream()?;
+        if self.remote_url(remote).is_none() {
+            tracing::debug!(remote, "Configured remote.upstream has no URL; ignoring");
+            return None;
+        }
+        Some(remote.to_string())
+    }
+
     /// Get the URL for a remote, if configured.
     ///
     /// Returns the raw value from `.git/config` without applying `url.insteadOf`
@@ -0,0 +1,98 @@
+//! Stash list operations for Repository.
+//!
+//! The stash is repo-wide (`refs/stash` and its reflog live in the common
+//! directory), but every entry's subject records the branch it was made on:
+//! `WIP on <branch>: …` for a bare `git stash`, `On <branch>: <message>` for
+//! `git stash push -m`. That name is the only link between a stash and its
+//! branch, so `wt list` counts stashes per branch from it, and `wt remove`
+//! warns before deleting a branch that stashes still name.
+
+use super::Repository;
+
+/// One entry of the stash list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StashEntry {
+    /// Reflog selector, e.g. `stash@{0}` — shifts as entries are pushed or dropped
+    pub selector: String,
+    /// Stash commit SHA — stable, and valid for `git stash apply`
+    pub sha: String,
+    /// Branch the stash was made on; `None` for a detached HEAD
+    pub branch: Option<String>,
+    /// Full subject, e.g. `On feature: wt remove: feature`
+    pub subject: String,
+}
+
+impl StashEntry {
+    /// Parse one `%H%x00%gd%x00%gs` line of `git stash list`.
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(3, '\0');
+        let sha = fields.next()?.to_string();
+        let selector = fields.next()?.to_string();
+        let subject = fields.next()?.to_string();
+        // Branch names can't contain `:`, so the first `: ` ends the name.
+        let branch = subject
+            .strip_prefix("WIP on ")
+            .or_else(|| subject.strip_prefix("On "))
+            .and_then(|rest| rest.split_once(": "))
+            .map(|(branch, _)| branch)
+            .filter(|branch| *branch != "(no branch)")
+            .map(String::from);
+        Some(Self {
+            selector,
+            sha,
+            branch,
+            subject,
+        })
+    }
+}
+
+impl Repository {
+    /// Every stash entry, newest first.
+    ///
+    /// Read once per Repository instance: `wt list` counts per branch from
+    /// the same list on every row. A repository without a stash yields an
+    /// empty list.
+    pub fn stashes(&self) -> &[StashEntry] {
+        self.cache.stashes.get_or_init(|| {
+            match self.run_command(&["stash", "list", "--format=%H%x00%gd%x00%gs"]) {
+                Ok(output) => output.lines().filter_map(StashEntry::parse).collect(),
+                Err(e) => {
+                    tracing::debug!(error = %e, "Failed to list stashes: {e}");
+                    Vec::new()
+                }
+            }
+        })
+    }
+
+    /// Stash entries made on `branch`, newest first.
+    pub fn branch_stashes(&self, branch: &str) -> Vec<&StashEntry> {
+        self.stashes()
+            .iter()
+            .filter(|stash| stash.branch.as_deref() == Some(branch))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stash_subjects() {
+        let entry =
+            StashEntry::parse("abc\0stash@{0}\0WIP on feature/x: 1234567 Add thing").unwrap();
+        assert_eq!(entry.branch.as_deref(), Some("feature/x"));
+        assert_eq!(entry.selector, "stash@{0}");
+        assert_eq!(entry.sha, "abc");
+
+        let entry = StashEntry::parse("def\0stash@{1}\0On feature: wt remove: feature").unwrap();
+        assert_eq!(entry.branch.as_deref(), Some("feature"));
+        assert_eq!(entry.subject, "On feature: wt remove: feature");
+
+        let entry =
+            StashEntry::parse("123\0stash@{2}\0WIP on (no branch): 1234567 Detached").unwrap();
+        assert_eq!(entry.branch, None);
+
+        assert!(StashEntry::parse("garbage").is_none());
+    }
+}
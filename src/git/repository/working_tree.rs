@@ -69,6 +69,15 @@ pub fn path_to_logging_context(path: &Path) -> String {
     }
 }
 
+/// Split NUL-terminated `-z` output into paths.
+fn split_nul(stdout: &str) -> Vec<String> {
+    stdout
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
 /// A borrowed handle for running git commands in a specific worktree.
 ///
 /// This type borrows a [`Repository`] and holds a path to a specific worktree.
@@ -345,6 +354,22 @@ impl<'a> WorkingTree<'a> {
         Ok(stdout.lines().map(str::to_owned).collect())
     }
 
+    /// Paths `git diff --name-only <diff_args>` reports, relative to the
+    /// worktree root and in git's order. Deleted files are left out: callers
+    /// hand the list to linters and formatters, which can't open them.
+    pub fn changed_files(&self, diff_args: &[&str]) -> anyhow::Result<Vec<String>> {
+        let mut args = vec!["diff", "--name-only", "-z", "--diff-filter=d"];
+        args.extend_from_slice(diff_args);
+        let stdout = self.run_command(&args)?;
+        Ok(split_nul(&stdout))
+    }
+
+    /// Untracked files that aren't ignored, relative to the worktree root.
+    pub fn untracked_files(&self) -> anyhow::Result<Vec<String>> {
+        let stdout = self.run_command(&["ls-files", "--others", "--exclude-standard", "-z"])?;
+        Ok(split_nul(&stdout))
+    }
+
     /// Get the root directory of this worktree (top-level of the working tree).
     ///
     /// Returns the canonicalized absolute path to the top-level directory.
@@ -398,6 +423,56 @@ impl<'a> WorkingTree<'a> {
         }
     }
 
+    /// The git operation in progress in this worktree, if any: `MERGING`,
+    /// `REBASING` (with `n/m` progress when git records it), `CHERRY-PICKING`,
+    /// `REVERTING`, or `BISECTING`.
+    pub fn operation_state(&self) -> anyhow::Result<Option<String>> {
+        let git_dir = self.git_dir()?;
+
+        // Check for merge
+        if git_dir.join("MERGE_HEAD").exists() {
+            return Ok(Some("MERGING".to_string()));
+        }
+
+        // Check for rebase. `rebase-merge` (interactive/merge backend) and
+        // `rebase-apply` (am backend) are mutually exclusive; probe each once.
+        let rebase_merge = git_dir.join("rebase-merge");
+        let rebase_apply = git_dir.join("rebase-apply");
+        if let Some(rebase_dir) = rebase_merge
+            .exists()
+            .then_some(rebase_merge)
+            .or_else(|| rebase_apply.exists().then_some(rebase_apply))
+        {
+            if let (Ok(msgnum), Ok(end)) = (
+                std::fs::read_to_string(rebase_dir.join("msgnum")),
+                std::fs::read_to_string(rebase_dir.join("end")),
+            ) {
+                let current = msgnum.trim();
+                let total = end.trim();
+                return Ok(Some(format!("REBASING {}/{}", current, total)));
+            }
+
+            return Ok(Some("REBASING".to_string()));
+        }
+
+        // Check for cherry-pick
+        if git_dir.join("CHERRY_PICK_HEAD").exists() {
+            return Ok(Some("CHERRY-PICKING".to_string()));
+        }
+
+        // Check for revert
+        if git_dir.join("REVERT_HEAD").exists() {
+            return Ok(Some("REVERTING".to_string()));
+        }
+
+        // Check for bisect
+        if git_dir.join("BISECT_LOG").exists() {
+            return Ok(Some("BISECTING".to_string()));
+        }
+
+        Ok(None)
+    }
+
     /// Check if a rebase is in progress.
     pub fn is_rebasing(&self) -> anyhow::Result<bool> {
         let git_dir = self.git_dir()?;
@@ -426,6 +501,41 @@ impl<'a> WorkingTree<'a> {
         Ok(git_dir != common_dir)
     }
 
+    /// Bytes on disk for this worktree's checked-out files.
+    ///
+    /// Excludes git's own data: the `.git` entry at the root (for the main
+    /// worktree, the shared object store — see [`Repository::object_store`])
+    /// and any nested directory holding a `.git` entry, which is a separate
+    /// checkout (submodule or worktree placed inside this one). Symlinks count
+    /// as their own size; unreadable entries are skipped.
+    pub fn disk_usage(&self) -> anyhow::Result<u64> {
+        let root = self.root()?;
+        let mut total = 0;
+        let mut stack = vec![root.clone()];
+        while let Some(dir) = stack.pop() {
+            if dir != root && dir.join(".git").symlink_metadata().is_ok() {
+                continue;
+            }
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                if entry.file_name() == ".git" {
+                    continue;
+                }
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                if metadata.is_dir() {
+                    stack.push(entry.path());
+                } else {
+                    total += metadata.len();
+                }
+            }
+        }
+        Ok(total)
+    }
+
     /// Ensure this worktree is clean (no uncommitted changes).
     ///
     /// Returns an error if there are uncommitted changes.
@@ -655,6 +765,70 @@ impl<'a> WorkingTree<'a> {
 
         self.repo().short_sha(&backup_sha)
     }
+
+    /// Stash all uncommitted changes, including untracked files, as a stash
+    /// entry named `message`, leaving the working tree clean.
+    ///
+    /// Returns the stash commit's SHA, or `None` when there was nothing to
+    /// stash. The SHA stays valid for `git stash apply <sha>` even after the
+    /// entry is dropped from the stash list (until garbage collection).
+    pub fn stash_push(&self, message: &str) -> anyhow::Result<Option<String>> {
+        let stash_tip = || {
+            self.run_command(&["rev-parse", "--quiet", "--verify", "refs/stash"])
+                .ok()
+                .map(|s| s.trim().to_string())
+        };
+        let before = stash_tip();
+        // `git stash push` exits 0 whether or not anything was stashed, so
+        // compare the stash tip instead.
+        self.run_command(&["stash", "push", "--include-untracked", "-m", message])?;
+        let after = stash_tip();
+        Ok(after.filter(|sha| Some(sha) != before.as_ref()))
+    }
+
+    /// Commit all uncommitted changes, including untracked files, as
+    /// `message`, skipping commit hooks.
+    ///
+    /// Returns the new commit's SHA, or `None` when the tree was clean.
+    pub fn commit_wip(&self, message: &str) -> anyhow::Result<Option<String>> {
+        if !self.is_dirty()? {
+            return Ok(None);
+        }
+        // Stage and commit from a copy of the index, so a commit that fails
+        // (a signing error, say) leaves the user's staging as it was.
+        let idx = self.temp_index()?;
+        let run = |args: &[&str]| -> anyhow::Result<()> {
+            let output = idx.git(args.iter().copied()).run()?;
+            if !output.status.success() {
+                anyhow::bail!(
+                    "git {} failed: {}",
+                    args.join(" "),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+            }
+            Ok(())
+        };
+        run(&["add", "--all"])?;
+        run(&["commit", "--no-verify", "--quiet", "-m", message])?;
+        // The commit holds everything; line the real index up with it.
+        self.run_command(&["reset", "--quiet"])?;
+        self.head_sha()
+    }
+
+    /// Undo a WIP commit made by [`Self::commit_wip`]: move `HEAD` back to
+    /// its parent, keeping the changes staged.
+    pub fn undo_wip(&self) -> anyhow::Result<()> {
+        self.run_command(&["reset", "--soft", "--quiet", "HEAD~1"])?;
+        Ok(())
+    }
+
+    /// Discard all uncommitted changes: reset tracked files to `HEAD` and
+    /// delete untracked files and directories. Ignored files are kept.
+    pub fn discard_changes(&self) -> anyhow::Result<()> {
+        self.run_command(&["reset", "--hard", "--quiet", "HEAD"])?;
+        self.run_command(&["clean", "-d", "--force", "--quiet"])?;
+        Ok(())
+    }
 }
 
 /// A temporary copy of a worktree's index, plus the bits needed to run
@@ -114,6 +114,77 @@ impl Repository {
         Ok(())
     }
 
+    /// Prune the entry of the worktree at `path` alone.
+    ///
+    /// Removes its administrative directory (`worktrees/<name>` in the common
+    /// git directory) — what `git worktree prune` does, but without touching
+    /// any other stale entry. Does nothing when no entry names `path`.
+    pub fn prune_worktree_entry(&self, path: &Path) -> anyhow::Result<()> {
+        let Ok(entries) = std::fs::read_dir(self.git_common_dir().join("worktrees")) else {
+            return Ok(());
+        };
+        let admin_dir = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .find(|admin_dir| {
+                std::fs::read_to_string(admin_dir.join("gitdir")).is_ok_and(|gitdir| {
+                    admin_dir
+                        .join(gitdir.trim())
+                        .parent()
+                        .is_some_and(|root| paths_match(root, path))
+                })
+            });
+        if let Some(admin_dir) = admin_dir {
+            std::fs::remove_dir_all(&admin_dir).map_err(|e| {
+                anyhow::anyhow!(cformat!(
+                    "Failed to prune <bold>{}</>: {e}",
+                    format_path_for_display(&admin_dir)
+                ))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Linked worktrees whose `.git` file no longer points back into this
+    /// repository — what moving or renaming the repository directory leaves
+    /// behind. Returns the worktree roots.
+    ///
+    /// Only reads files: each `worktrees/<name>/gitdir` names a worktree's
+    /// `.git` file, which must in turn name `worktrees/<name>`. Worktrees
+    /// whose directory is gone are skipped; `git worktree prune` handles
+    /// those.
+    pub fn unlinked_worktrees(&self) -> Vec<PathBuf> {
+        let Ok(entries) = std::fs::read_dir(self.git_common_dir().join("worktrees")) else {
+            return Vec::new();
+        };
+        let mut unlinked: Vec<PathBuf> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let admin_dir = entry.path();
+                let gitdir = std::fs::read_to_string(admin_dir.join("gitdir")).ok()?;
+                let dot_git = admin_dir.join(gitdir.trim());
+                let root = dot_git.parent()?.to_path_buf();
+                let content = std::fs::read_to_string(&dot_git).ok()?;
+                let target = root.join(content.strip_prefix("gitdir:")?.trim());
+                let linked = canonicalize(&target)
+                    .is_ok_and(|target| canonicalize(&admin_dir).is_ok_and(|a| a == target));
+                (!linked).then_some(root)
+            })
+            .collect();
+        unlinked.sort();
+        unlinked
+    }
+
+    /// Point the `.git` files of the worktrees at `paths` back at this
+    /// repository with `git worktree repair`.
+    pub fn repair_worktrees(&self, paths: &[PathBuf]) -> anyhow::Result<()> {
+        let mut args = vec!["worktree".to_string(), "repair".to_string()];
+        args.extend(paths.iter().map(|p| p.to_string_lossy().into_owned()));
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        self.run_command(&args)?;
+        Ok(())
+    }
+
     /// Remove a worktree at the specified path.
     ///
     /// When `force` is true, passes `--force` to `git worktree remove`,
@@ -180,13 +251,15 @@ impl Repository {
         Ok(())
     }
 
-    /// Resolve a worktree name, expanding "@" to current, "-" to previous, and "^" to main.
+    /// Resolve a worktree name, expanding "@" to current, "-" to previous, "^" to main,
+    /// and "%N" to row N of the last `wt list`.
     ///
     /// # Arguments
     /// * `name` - The worktree name to resolve:
     ///   - "@" for current HEAD
     ///   - "-" for previous branch (via worktrunk.history)
     ///   - "^" for default branch
+    ///   - "%N" for the branch in row N of this shell's last `wt list`
     ///   - any other string is returned as-is
     ///
     /// # Returns
@@ -194,9 +267,27 @@ impl Repository {
     /// - `Ok(current_branch)` if "@" and on a branch
     /// - `Ok(previous_branch)` if "-" and worktrunk.history has a previous branch
     /// - `Ok(default_branch)` if "^"
+    /// - `Ok(row_branch)` if "%N" and row N of the last listing has a branch
     /// - `Err(DetachedHead)` if "@" and in detached HEAD state
     /// - `Err` if "-" but no previous branch in history
+    /// - `Err` if "%N" but there is no such row, or the row is detached
     pub fn resolve_worktree_name(&self, name: &str) -> anyhow::Result<String> {
+        if let Some(row) = super::list_index::parse_list_index(name) {
+            let entry = self.list_index_entry(row)?;
+            return entry.branch.ok_or_else(|| {
+                let path = entry
+                    .path
+                    .as_deref()
+                    .map(format_path_for_display)
+                    .unwrap_or_default();
+                GitError::Other {
+                    message: cformat!(
+                        "Row <bold>%{row}</> is a detached worktree at <bold>{path}</> and has no branch"
+                    ),
+                }
+                .into()
+            });
+        }
         match name {
             "@" => self.current_worktree().branch()?.ok_or_else(|| {
                 GitError::DetachedHead {
@@ -238,6 +329,7 @@ impl Repository {
     ///   - "@" for current worktree (works even in detached HEAD)
     ///   - "-" for previous branch's worktree
     ///   - "^" for main worktree
+    ///   - "%N" for row N of the last `wt list` (works for detached rows)
     ///   - any other string is treated as a branch name
     ///
     /// # Returns
@@ -264,6 +356,13 @@ impl Repository {
                     .and_then(|wt| wt.branch.clone());
                 Ok(ResolvedWorktree::Worktree { path, branch })
             }
+            _ if let Some(row) = super::list_index::parse_list_index(name)
+                && let entry = self.list_index_entry(row)?
+                && entry.branch.is_none()
+                && let Some(path) = entry.path =>
+            {
+                Ok(ResolvedWorktree::Worktree { path, branch: None })
+            }
             _ => {
                 // Resolve to branch name first, then find its worktree
                 let branch = self.resolve_worktree_name(name)?;
@@ -0,0 +1,337 @@
+//! Find running processes whose working directory is inside a worktree.
+//!
+//! Removing a worktree out from under a dev server, an agent, or a shell leaves
+//! that process running in a deleted directory — builds fail with confusing
+//! `ENOENT`s and agents lose their place. `wt remove` checks for such processes
+//! up front and refuses (unless `--force`), listing them so the user can stop
+//! them first.
+//!
+//! # What counts
+//!
+//! A process counts when its current working directory is the worktree root or
+//! anything beneath it. Open file handles are not considered: a cwd is what
+//! makes a process "live in" a worktree, and scanning every fd would be slow.
+//!
+//! Excluded:
+//! - `wt` itself and its ancestors — the shell that invoked `wt remove` from
+//!   inside the worktree is about to be moved out by the shell integration.
+//! - `wt`'s own descendants — the `git` subprocesses it spawns in the worktree
+//!   while gathering state for the removal.
+//! - `git fsmonitor--daemon` — removal stops it itself (see
+//!   [`crate::git::remove`]).
+//!
+//! # Platform
+//!
+//! Linux reads `/proc/<pid>/cwd`; other Unix platforms ask `lsof` for every
+//! process's `cwd` descriptor. Windows has no cheap cwd query for foreign
+//! processes, so detection is a no-op there and removal proceeds as before.
+//! Every failure (unreadable `/proc` entry, missing `lsof`, timeout) is treated
+//! as "no process" — the check never blocks a removal it can't substantiate.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// A process whose working directory is inside a worktree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeProcess {
+    pub pid: u32,
+    /// Command line (Linux) or command name (`lsof`), for display.
+    pub command: String,
+}
+
+/// One process as seen by the platform scan. `cwd` is [`None`] when it
+/// couldn't be read (another user's process, or a process that exited
+/// mid-scan); such entries still contribute their parent link.
+#[derive(Debug, Clone)]
+struct ProcessEntry {
+    pid: u32,
+    ppid: Option<u32>,
+    cwd: Option<PathBuf>,
+    command: String,
+}
+
+/// Processes whose working directory is `worktree` or beneath it, sorted by
+/// PID. Empty when none are found or the platform can't tell.
+pub fn processes_in(worktree: &Path) -> Vec<WorktreeProcess> {
+    let Ok(root) = dunce::canonicalize(worktree) else {
+        return Vec::new();
+    };
+    let processes = holders(&root, &enumerate(), std::process::id());
+    if !processes.is_empty() {
+        tracing::debug!(
+            "{} process(es) running in {}: {:?}",
+            processes.len(),
+            root.display(),
+            processes
+        );
+    }
+    processes
+}
+
+/// Filter a process scan down to the processes living in `root`, excluding
+/// `self_pid`, its ancestors and descendants, and fsmonitor daemons.
+fn holders(root: &Path, entries: &[ProcessEntry], self_pid: u32) -> Vec<WorktreeProcess> {
+    let parents: HashMap<u32, u32> = entries
+        .iter()
+        .filter_map(|e| e.ppid.map(|ppid| (e.pid, ppid)))
+        .collect();
+    let ancestry = ancestors(self_pid, &parents);
+
+    let mut found: Vec<WorktreeProcess> = entries
+        .iter()
+        .filter(|e| !ancestry.contains(&e.pid))
+        .filter(|e| !ancestors(e.pid, &parents).contains(&self_pid))
+        .filter(|e| !e.command.contains("fsmonitor--daemon"))
+        .filter(|e| e.cwd.as_deref().is_some_and(|cwd| cwd.starts_with(root)))
+        .map(|e| WorktreeProcess {
+            pid: e.pid,
+            command: e.command.clone(),
+        })
+        .collect();
+    found.sort_by_key(|p| p.pid);
+    found
+}
+
+/// `pid` followed by its parent chain, as far as the scan knows it.
+fn ancestors(pid: u32, parents: &HashMap<u32, u32>) -> Vec<u32> {
+    let mut chain = vec![pid];
+    let mut pid = pid;
+    // Bounded walk: a corrupt or racing scan can't produce a cycle that spins.
+    while let Some(&ppid) = parents.get(&pid) {
+        if ppid == 0 || chain.contains(&ppid) || chain.len() > 64 {
+            break;
+        }
+        chain.push(ppid);
+        pid = ppid;
+    }
+    chain
+}
+
+#[cfg(target_os = "linux")]
+fn enumerate() -> Vec<ProcessEntry> {
+    let Ok(dir) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    dir.filter_map(Result::ok)
+        .filter_map(|entry| {
+            let pid: u32 = entry.file_name().to_str()?.parse().ok()?;
+            let base = entry.path();
+            let stat = std::fs::read_to_string(base.join("stat")).ok()?;
+            let (comm, ppid) = parse_proc_stat(&stat)?;
+            let cmdline = std::fs::read(base.join("cmdline")).unwrap_or_default();
+            let command = parse_proc_cmdline(&cmdline).unwrap_or(comm);
+            Some(ProcessEntry {
+                pid,
+                ppid: Some(ppid),
+                cwd: std::fs::read_link(base.join("cwd")).ok(),
+                command,
+            })
+        })
+        .collect()
+}
+
+/// Split `/proc/<pid>/stat` into `comm` and the fields after it. `comm` is
+/// parenthesized and may itself contain spaces or parentheses, so split at the
+/// last `)`.
+#[cfg(any(target_os = "linux", test))]
+fn split_proc_stat(stat: &str) -> Option<(&str, std::str::SplitWhitespace<'_>)> {
+    let open = stat.find('(')?;
+    let close = stat.rfind(')')?;
+    let comm = stat.get(open + 1..close)?;
+    Some((comm, stat.get(close + 1..)?.split_whitespace()))
+}
+
+/// Extract `(comm, ppid)` from `/proc/<pid>/stat`.
+#[cfg(any(target_os = "linux", test))]
+fn parse_proc_stat(stat: &str) -> Option<(String, u32)> {
+    let (comm, mut fields) = split_proc_stat(stat)?;
+    // After `comm`: state, then ppid.
+    let ppid = fields.nth(1)?.parse().ok()?;
+    Some((comm.to_string(), ppid))
+}
+
+/// Extract `starttime` from `/proc/<pid>/stat` — the 20th field after `comm`.
+#[cfg(any(target_os = "linux", test))]
+fn parse_proc_start(stat: &str) -> Option<String> {
+    let (_, mut fields) = split_proc_stat(stat)?;
+    fields.nth(19).map(str::to_string)
+}
+
+/// When process `pid` started, as an opaque token that differs between two
+/// processes that held the same pid: the `starttime` field of
+/// `/proc/<pid>/stat` on Linux, `ps -o lstart=` elsewhere. [`None`] when the
+/// process is gone or the platform can't tell.
+#[cfg(target_os = "linux")]
+pub fn process_start(pid: u32) -> Option<String> {
+    parse_proc_start(&std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?)
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+pub fn process_start(pid: u32) -> Option<String> {
+    let output = crate::shell_exec::Cmd::new("ps")
+        .args(["-o", "lstart=", "-p", &pid.to_string()])
+        .run()
+        .ok()?;
+    let start = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !start.is_empty()).then_some(start)
+}
+
+#[cfg(not(unix))]
+pub fn process_start(_pid: u32) -> Option<String> {
+    None
+}
+
+/// Join a NUL-separated `/proc/<pid>/cmdline` into a display string. Kernel
+/// threads have an empty cmdline, which yields [`None`].
+#[cfg(any(target_os = "linux", test))]
+fn parse_proc_cmdline(cmdline: &[u8]) -> Option<String> {
+    let joined = String::from_utf8_lossy(cmdline)
+        .split('\0')
+        .filter(|arg| !arg.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!joined.is_empty()).then_some(joined)
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+fn enumerate() -> Vec<ProcessEntry> {
+    use crate::shell_exec::Cmd;
+
+    // `-d cwd` restricts output to each process's cwd descriptor; `-F pRcn`
+    // prints pid, ppid, command name, and path as field-prefixed lines.
+    let Ok(output) = Cmd::new("lsof")
+        .args(["-n", "-d", "cwd", "-F", "pRcn"])
+        .timeout(std::time::Duration::from_secs(5))
+        .run()
+    else {
+        return Vec::new();
+    };
+    // lsof exits 1 when some processes couldn't be inspected (permissions)
+    // while still printing the rest, so parse stdout regardless of status.
+    parse_lsof_cwds(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse `lsof -d cwd -F pRcn` output. Each process record starts with a
+/// `p<pid>` line followed by `R<ppid>`, `c<command>`, then an `f`/`n` pair for
+/// its cwd descriptor.
+#[cfg(any(all(unix, not(target_os = "linux")), test))]
+fn parse_lsof_cwds(stdout: &str) -> Vec<ProcessEntry> {
+    let mut entries: Vec<ProcessEntry> = Vec::new();
+    for line in stdout.lines() {
+        let Some((field, value)) = line.split_at_checked(1) else {
+            continue;
+        };
+        if field == "p" {
+            if let Ok(pid) = value.parse() {
+                entries.push(ProcessEntry {
+                    pid,
+                    ppid: None,
+                    cwd: None,
+                    command: String::new(),
+                });
+            }
+            continue;
+        }
+        let Some(current) = entries.last_mut() else {
+            continue;
+        };
+        match field {
+            "R" => current.ppid = value.parse().ok(),
+            "c" => current.command = value.to_string(),
+            "n" => current.cwd = Some(PathBuf::from(value)),
+            _ => {}
+        }
+    }
+    entries
+}
+
+#[cfg(not(unix))]
+fn enumerate() -> Vec<ProcessEntry> {
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(pid: u32, ppid: u32, cwd: &str, command: &str) -> ProcessEntry {
+        ProcessEntry {
+            pid,
+            ppid: Some(ppid),
+            cwd: Some(PathBuf::from(cwd)),
+            command: command.to_string(),
+        }
+    }
+
+    #[test]
+    fn holders_excludes_self_family_and_fsmonitor() {
+        let root = Path::new("/code/repo.feature");
+        let entries = vec![
+            entry(1, 0, "/", "init"),
+            entry(10, 1, "/code/repo.feature", "zsh"),
+            entry(20, 10, "/code/repo.feature/src", "wt remove"),
+            entry(21, 20, "/code/repo.feature", "git status"),
+            entry(22, 21, "/code/repo.feature", "git-fsmonitor"),
+            entry(30, 1, "/code/repo.feature/web", "npm run dev"),
+            entry(31, 1, "/code/repo.feature", "git fsmonitor--daemon run"),
+            entry(40, 1, "/code/repo.feature-2", "vim"),
+            entry(41, 1, "/code/repo", "cargo watch"),
+            entry(5, 1, "/code/repo.feature", "claude"),
+        ];
+        assert_eq!(
+            holders(root, &entries, 20),
+            vec![
+                WorktreeProcess {
+                    pid: 5,
+                    command: "claude".into()
+                },
+                WorktreeProcess {
+                    pid: 30,
+                    command: "npm run dev".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_proc_stat_with_awkward_comm() {
+        let stat = "4242 (tmux: server) (x) S 17 4242 4242 0 -1 4194560";
+        assert_eq!(
+            parse_proc_stat(stat),
+            Some(("tmux: server) (x".to_string(), 17))
+        );
+        assert_eq!(parse_proc_stat("garbage"), None);
+    }
+
+    #[test]
+    fn parses_proc_start_with_awkward_comm() {
+        let stat = "4242 (a) b) S 17 4242 4242 0 -1 4194560 0 0 0 0 0 0 0 0 20 0 1 0 98765 0";
+        assert_eq!(parse_proc_start(stat), Some("98765".to_string()));
+        assert_eq!(parse_proc_start("4242 (x) S 17"), None);
+    }
+
+    #[test]
+    fn parses_proc_cmdline() {
+        assert_eq!(
+            parse_proc_cmdline(b"npm\0run\0dev\0"),
+            Some("npm run dev".to_string())
+        );
+        assert_eq!(parse_proc_cmdline(b""), None);
+    }
+
+    #[test]
+    fn parses_lsof_cwd_records() {
+        let stdout =
+            "p501\nR1\ncnode\nfcwd\nn/code/repo.feature\np502\nR501\ncsleep\nfcwd\nn/tmp\n";
+        let entries = parse_lsof_cwds(stdout);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].pid, 501);
+        assert_eq!(entries[0].ppid, Some(1));
+        assert_eq!(entries[0].command, "node");
+        assert_eq!(
+            entries[0].cwd.as_deref(),
+            Some(Path::new("/code/repo.feature"))
+        );
+        assert_eq!(entries[1].ppid, Some(501));
+    }
+}
@@ -21,10 +21,11 @@
 //! Without Git Bash, we only use a pager if the configured command works under
 //! the PowerShell fallback; otherwise we print directly.
 
+use ansi_str::AnsiStr;
 use std::io::{IsTerminal, Write};
 use std::process::Stdio;
 use worktrunk::shell_exec::ShellConfig;
-use worktrunk::styling::print;
+use worktrunk::styling::{Stream, colors_enabled, print, themed};
 
 use crate::pager::{git_config_pager, parse_pager_value};
 
@@ -103,8 +104,17 @@ pub(crate) fn show_help_in_pager(help_text: &str, use_pager: bool) {
         return;
     }
 
+    // The pager writes to the terminal itself, bypassing anstream, so strip
+    // styling here when color is off (`--color=never`, `NO_COLOR`) and apply
+    // `[theme]` when it's on.
+    let paged_text = if colors_enabled(Stream::Stdout) {
+        themed(help_text)
+    } else {
+        help_text.ansi_strip()
+    };
+
     tracing::debug!(pager_cmd = %pager_cmd, "Invoking pager: {}", pager_cmd);
-    if let Err(e) = pipe_through_pager(&pager_cmd, help_text) {
+    if let Err(e) = pipe_through_pager(&pager_cmd, &paged_text) {
         tracing::debug!(error = %e, "Pager failed, falling back to stdout: {}", e);
         print!("{}", help_text);
     }
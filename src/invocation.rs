@@ -3,6 +3,7 @@
 //! These functions examine `argv[0]` and environment variables to determine:
 //! - What name the binary was invoked as (`binary_name`)
 //! - Whether we're running as a git subcommand (`is_git_subcommand`)
+//! - Whether the shell wrapper ran us inside `$( )` (`is_command_substitution`)
 //! - Whether shell integration can work (`was_invoked_with_explicit_path`)
 
 /// Get the binary name from `argv[0]`, falling back to "wt".
@@ -34,6 +35,16 @@ pub fn is_git_subcommand() -> bool {
     std::env::var_os("GIT_EXEC_PATH").is_some()
 }
 
+/// Check if the shell wrapper ran us inside a command substitution or pipeline.
+///
+/// In `$(wt ...)` or `wt ... | jq` the wrapper runs in a subshell, where a `cd`
+/// can't reach the calling shell. The wrapper then skips directive handling,
+/// runs the binary directly, and sets `WORKTRUNK_COMMAND_SUBSTITUTION` so the
+/// missing cd isn't reported as broken shell integration.
+pub fn is_command_substitution() -> bool {
+    std::env::var_os("WORKTRUNK_COMMAND_SUBSTITUTION").is_some()
+}
+
 /// Get the `argv[0]` value (how we were invoked), with forward-slash separators.
 ///
 /// Used in error messages to show what command was actually run.
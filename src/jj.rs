@@ -0,0 +1,214 @@
+//! jj (Jujutsu) metadata for template variables.
+//!
+//! worktrunk drives git, but a worktree can also be a colocated jj workspace
+//! (a `.jj/` directory beside the checkout). There, `{{ change_id }}`,
+//! `{{ bookmark }}`, and `{{ workspace }}` resolve through the `jj` CLI; in a
+//! plain git worktree they're empty, so one config serves both setups.
+//!
+//! `wt switch` also accepts a jj revset or change ID in a colocated repo:
+//! [`JjRevision::resolve`] maps it to the commit git checks out.
+
+use std::path::Path;
+
+use color_print::cformat;
+
+use crate::shell_exec::Cmd;
+
+/// jj identity of a workspace. Fields are empty when unknown.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct JjMetadata {
+    /// Change ID of the working-copy commit (`@`)
+    pub change_id: String,
+    /// Nearest local bookmark on `@` or its ancestors
+    pub bookmark: String,
+    /// Workspace name, e.g. `default`
+    pub workspace: String,
+}
+
+impl JjMetadata {
+    /// Metadata for the worktree at `path`. Empty unless it's a jj workspace
+    /// and `jj` runs successfully.
+    pub fn for_path(path: &Path) -> Self {
+        if !path.join(".jj").is_dir() {
+            return Self::default();
+        }
+        query(path).unwrap_or_else(|e| {
+            tracing::debug!("Failed to read jj metadata: {e}");
+            Self::default()
+        })
+    }
+}
+
+/// The single revision a jj revset resolved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JjRevision {
+    /// Git commit ID of the revision
+    pub commit_id: String,
+    /// Change ID in jj's short form (12 characters)
+    pub change_id: String,
+    /// Local bookmarks pointing at the revision
+    pub bookmarks: Vec<String>,
+}
+
+impl JjRevision {
+    /// Resolve `revset` in the colocated jj repository at `path`.
+    ///
+    /// `Ok(None)` when `path` isn't a jj workspace, or when jj rejects the
+    /// revset or finds nothing — the caller treats the argument as a plain
+    /// branch name then. A revset matching several revisions is an error.
+    pub fn resolve(path: &Path, revset: &str) -> anyhow::Result<Option<Self>> {
+        if !path.join(".jj").is_dir() {
+            return Ok(None);
+        }
+        let output = match jj_log_limited(
+            path,
+            revset,
+            r#"commit_id ++ " " ++ change_id.short() ++ " " ++ local_bookmarks.map(|b| b.name()).join(" ") ++ "\n""#,
+            2,
+        ) {
+            Ok(output) => output,
+            Err(e) => {
+                tracing::debug!("jj couldn't resolve {revset}: {e}");
+                return Ok(None);
+            }
+        };
+        let mut revisions = output.lines().filter_map(Self::parse);
+        let Some(revision) = revisions.next() else {
+            return Ok(None);
+        };
+        if revisions.next().is_some() {
+            anyhow::bail!(cformat!(
+                "Revset <bold>{revset}</> matches more than one revision"
+            ));
+        }
+        Ok(Some(revision))
+    }
+
+    /// Parse one `commit_id change_id bookmark...` line.
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split_whitespace();
+        let commit_id = fields.next()?.to_string();
+        let change_id = fields.next()?.to_string();
+        Some(Self {
+            commit_id,
+            change_id,
+            bookmarks: fields.map(str::to_string).collect(),
+        })
+    }
+}
+
+fn query(path: &Path) -> anyhow::Result<JjMetadata> {
+    let at = jj_log(path, "@", r#"change_id ++ "\n" ++ working_copies"#)?;
+    let bookmarks = jj_log(
+        path,
+        "latest(heads(::@ & bookmarks()))",
+        r#"local_bookmarks.map(|b| b.name()).join(" ")"#,
+    )?;
+    Ok(parse(&at, &bookmarks))
+}
+
+/// Run `jj log` for a single revision without snapshotting the working copy —
+/// the change ID survives snapshots, and a read shouldn't rewrite the repo.
+fn jj_log(path: &Path, revision: &str, template: &str) -> anyhow::Result<String> {
+    jj_log_limited(path, revision, template, 1)
+}
+
+/// [`jj_log`] showing at most `limit` revisions.
+fn jj_log_limited(
+    path: &Path,
+    revision: &str,
+    template: &str,
+    limit: usize,
+) -> anyhow::Result<String> {
+    let output = Cmd::new("jj")
+        .args([
+            "log",
+            "-r",
+            revision,
+            "-T",
+            template,
+            "--no-graph",
+            "--ignore-working-copy",
+            "--color=never",
+            "--limit",
+            &limit.to_string(),
+        ])
+        .current_dir(path)
+        .run()?;
+    if !output.status.success() {
+        anyhow::bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parse the two `jj log` outputs: `change_id` then `working_copies` (as
+/// `name@`, space-separated) for `@`, and the bookmark names on the nearest
+/// bookmarked ancestor.
+fn parse(at: &str, bookmarks: &str) -> JjMetadata {
+    let mut lines = at.lines();
+    let change_id = lines.next().unwrap_or_default().trim().to_string();
+    let workspace = lines
+        .next()
+        .and_then(|line| line.split_whitespace().next())
+        .map(|name| name.trim_end_matches('@').to_string())
+        .unwrap_or_default();
+    let bookmark = bookmarks
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    JjMetadata {
+        change_id,
+        bookmark,
+        workspace,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let meta = parse(
+            "kntqzsqtsvwwrmypsopwvoyzxwxkxorx\ndefault@\n",
+            "feature main\n",
+        );
+        assert_eq!(
+            meta,
+            JjMetadata {
+                change_id: "kntqzsqtsvwwrmypsopwvoyzxwxkxorx".into(),
+                bookmark: "feature".into(),
+                workspace: "default".into(),
+            }
+        );
+        assert_eq!(parse("", ""), JjMetadata::default());
+    }
+
+    #[test]
+    fn test_parse_revision() {
+        assert_eq!(
+            JjRevision::parse("0123abcd kntqzsqtsvww feature main"),
+            Some(JjRevision {
+                commit_id: "0123abcd".into(),
+                change_id: "kntqzsqtsvww".into(),
+                bookmarks: vec!["feature".into(), "main".into()],
+            })
+        );
+        assert_eq!(
+            JjRevision::parse("0123abcd kntqzsqtsvww "),
+            Some(JjRevision {
+                commit_id: "0123abcd".into(),
+                change_id: "kntqzsqtsvww".into(),
+                bookmarks: Vec::new(),
+            })
+        );
+        assert_eq!(JjRevision::parse(""), None);
+    }
+
+    #[test]
+    fn test_git_worktree_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(JjMetadata::for_path(dir.path()), JjMetadata::default());
+    }
+}
@@ -13,6 +13,7 @@ pub mod config;
 pub mod copy;
 pub mod docs;
 pub mod git;
+pub mod jj;
 pub mod path;
 pub mod priority;
 pub mod progress;
@@ -21,12 +22,14 @@ pub mod shell;
 pub mod shell_exec;
 #[cfg(unix)]
 pub mod signal_forwarder;
+pub mod state_store;
+pub mod stats;
 pub mod styling;
 pub mod sync;
 pub mod trace;
 pub mod utils;
 
-#[doc(hidden)]
+#[cfg_attr(not(feature = "test-util"), doc(hidden))]
 pub mod testing;
 
 // Re-export HookType for convenience
@@ -6,11 +6,12 @@ use std::fmt;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 use worktrunk::config::CommitGenerationConfig;
 use worktrunk::git::{CommitMessageDetail, Repository};
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell_exec::{Cmd, ShellConfig};
-use worktrunk::styling::{eprintln, warning_message};
+use worktrunk::styling::{eprintln, format_with_gutter, warning_message};
 
 use minijinja::Environment;
 use minijinja::value::{Enumerator, Object, Value};
@@ -65,7 +66,7 @@ const SHELL_METACHARACTERS: &[char] = &[
 
 /// Render the shell invocation worktrunk would use to run `command`.
 ///
-/// Mirrors the wrapping done by [`execute_llm_command`]: every LLM command is passed as
+/// Mirrors the wrapping done by [`LlmClient`]: every LLM command is passed as
 /// a single argument to the platform shell, so the displayed form is always
 /// `<shell> <shell-args> <quoted-command>` (e.g. `sh -c 'claude -p'`).
 ///
@@ -90,7 +91,7 @@ pub(crate) fn render_llm_invocation(command: &str) -> anyhow::Result<String> {
 
 /// Start a "still waiting" watchdog for a *foreground* LLM shell-out.
 ///
-/// [`execute_llm_command`] captures stdout, so a slow or hung command is
+/// [`LlmClient`] captures stdout, so a slow or hung command is
 /// otherwise silent. The watchdog shows a dim status line that escalates to
 /// reveal the exact invocation (via [`render_llm_invocation`]) in a gutter. The
 /// caller holds the returned guard until the command returns; on drop it clears
@@ -319,6 +320,9 @@ struct TemplateContext<'a> {
     commit_details: &'a [CommitMessageDetail],
     /// Target branch for merge (squash only)
     target_branch: Option<&'a str>,
+    /// Conventional-commit scope inferred from the changed paths (commit
+    /// only, with `[commit.scope]` in the project config)
+    scope: Option<&'a str>,
     /// Approved project-level append fragment. `None` when no project
     /// `template-append` is set or the user declined approval. The
     /// user-level append fragment is read from the [`CommitGenerationConfig`]
@@ -365,7 +369,8 @@ const DEFAULT_TEMPLATE: &str = r#"<task>Write a commit message for the staged ch
 
 <context>
 Branch: {{ branch }}
-{% if recent_commits %}<recent_commits>
+{% if scope %}Conventional-commit scope: {{ scope }}
+{% endif %}{% if recent_commits %}<recent_commits>
 {% for commit in recent_commits %}- {{ commit }}
 {% endfor %}</recent_commits>{% endif %}
 </context>
@@ -409,60 +414,204 @@ const DEFAULT_SQUASH_TEMPLATE: &str = r#"<task>Write a commit message for the co
 </diff>
 "#;
 
-/// Execute an LLM command with the given prompt via stdin.
+/// Runs an LLM command: the prompt goes to stdin, the message comes back on
+/// stdout.
 ///
 /// The command is a shell string executed via the platform shell (sh on Unix,
 /// Git Bash on Windows), allowing environment variables to be set inline
 /// (e.g., `MAX_THINKING_TOKENS=0 claude -p ...`).
 ///
+/// Each attempt is bounded by a timeout; failed attempts retry with
+/// exponential backoff. A failure's stderr (or stdout, or exit code) becomes
+/// the error message, and the full streams land in the `-vv` subprocess log.
+///
 /// This is the canonical way to execute LLM commands in this codebase.
-/// All LLM execution should go through this function to maintain consistency.
-pub(crate) fn execute_llm_command(command: &str, prompt: &str) -> anyhow::Result<String> {
-    // TODO(diff-pipe): Consider splitting the prompt template around
-    // `{{ git_diff }}` and piping `git diff` directly into the LLM via
-    // `Cmd::pipe_into` (preamble + epilogue through env vars). Avoids buffering
-    // MB-scale diffs in our process memory and removes them from our logs
-    // entirely. See conversation around PR #2136 for sketch.
+/// All LLM execution should go through this type to maintain consistency.
+pub(crate) struct LlmClient<'a> {
+    command: &'a str,
+    timeout: Option<Duration>,
+    retries: u32,
+    stream: bool,
+    /// What a foreground caller is waiting for; `None` for background calls.
+    waiting_for: Option<&'a str>,
+}
 
-    let shell = ShellConfig::get()?;
-    let output = Cmd::new(shell.executable.to_string_lossy())
-        .args(&shell.args)
-        .arg(command)
-        .external("commit.generation")
-        .stdin_bytes(prompt)
-        .run()
-        .context("Failed to spawn LLM command")?;
+impl<'a> LlmClient<'a> {
+    /// A client with the default timeout, no retries, and no streaming.
+    pub(crate) fn new(command: &'a str) -> Self {
+        Self::from_config(command, &CommitGenerationConfig::default())
+    }
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        let stderr = stderr.trim();
-        if stderr.is_empty() {
-            // Fall back to stdout or exit code when stderr is empty
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            let stdout = stdout.trim();
-            if stdout.is_empty() {
-                anyhow::bail!(
-                    "LLM command failed with exit code {}",
-                    output.status.code().unwrap_or(-1)
-                );
-            } else {
-                anyhow::bail!("{}", stdout);
+    /// A client honouring `[commit.generation]` `timeout-ms`, `retries`, and
+    /// `stream`.
+    pub(crate) fn from_config(command: &'a str, config: &CommitGenerationConfig) -> Self {
+        Self {
+            command,
+            timeout: config.timeout(),
+            retries: config.retries(),
+            stream: config.stream(),
+            waiting_for: None,
+        }
+    }
+
+    /// Mark this as a single foreground call awaiting `waiting_for` (e.g.
+    /// `"the commit message"`).
+    ///
+    /// Stdout is captured, so a slow or hung command is otherwise silent: each
+    /// attempt runs under a "still waiting" watchdog (see
+    /// [`watch_llm_command`]), or — with `stream` — shows the output in a
+    /// gutter as it arrives. Never for the concurrent summary path, where
+    /// per-call output would interleave.
+    pub(crate) fn foreground(mut self, waiting_for: &'a str) -> Self {
+        self.waiting_for = Some(waiting_for);
+        self
+    }
+
+    /// Run the command, retrying failed attempts.
+    pub(crate) fn execute(&self, prompt: &str) -> anyhow::Result<String> {
+        let mut attempt = 0;
+        loop {
+            let result = {
+                let _watchdog = self
+                    .waiting_for
+                    .filter(|_| !self.stream)
+                    .map(|waiting_for| watch_llm_command(self.command, waiting_for));
+                self.attempt(prompt)
+            };
+            match result {
+                Ok(message) => return Ok(message),
+                Err(failure) if failure.retryable && attempt < self.retries => {
+                    let delay = Duration::from_secs(1 << attempt.min(6));
+                    attempt += 1;
+                    if self.waiting_for.is_some() {
+                        eprintln!(
+                            "{}",
+                            warning_message(cformat!(
+                                "LLM command failed, retrying in {}s ({attempt}/{}): {}",
+                                delay.as_secs(),
+                                self.retries,
+                                first_line(&failure.error.to_string())
+                            ))
+                        );
+                    }
+                    std::thread::sleep(delay);
+                }
+                Err(failure) => return Err(failure.error),
             }
-        } else {
-            anyhow::bail!("{}", stderr);
         }
     }
 
-    let message = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+    fn attempt(&self, prompt: &str) -> Result<String, AttemptFailure> {
+        // TODO(diff-pipe): Consider splitting the prompt template around
+        // `{{ git_diff }}` and piping `git diff` directly into the LLM via
+        // `Cmd::pipe_into` (preamble + epilogue through env vars). Avoids buffering
+        // MB-scale diffs in our process memory and removes them from our logs
+        // entirely. See conversation around PR #2136 for sketch.
+
+        let shell = ShellConfig::get().map_err(AttemptFailure::fatal)?;
+        let mut cmd = Cmd::new(shell.executable.to_string_lossy())
+            .args(&shell.args)
+            .arg(self.command)
+            .external("commit.generation")
+            .stdin_bytes(prompt);
+        if let Some(timeout) = self.timeout {
+            cmd = cmd.timeout(timeout);
+        }
+        if self.stream && self.waiting_for.is_some() {
+            cmd = cmd.on_stdout_line(|line| {
+                eprintln!("{}", format_with_gutter(line, None));
+            });
+        }
 
-    if message.is_empty() {
-        return Err(worktrunk::git::GitError::Other {
-            message: "LLM returned empty message".into(),
+        let output = match cmd.run() {
+            Ok(output) => output,
+            Err(e) if e.kind() == std::io::ErrorKind::TimedOut => {
+                let timeout = self.timeout.unwrap_or_default();
+                return Err(AttemptFailure::retryable(anyhow::anyhow!(
+                    "LLM command timed out after {}",
+                    format_timeout(timeout)
+                )));
+            }
+            Err(e) => {
+                return Err(AttemptFailure::fatal(
+                    anyhow::Error::new(e).context("Failed to spawn LLM command"),
+                ));
+            }
+        };
+
+        if !output.status.success() {
+            // 126/127: the shell couldn't find or run the command — retrying
+            // won't help
+            let retryable = !matches!(output.status.code(), Some(126 | 127));
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stderr = stderr.trim();
+            let error = if stderr.is_empty() {
+                // Fall back to stdout or exit code when stderr is empty
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let stdout = stdout.trim();
+                if stdout.is_empty() {
+                    anyhow::anyhow!(
+                        "LLM command failed with exit code {}",
+                        output.status.code().unwrap_or(-1)
+                    )
+                } else {
+                    anyhow::anyhow!("{}", stdout)
+                }
+            } else {
+                anyhow::anyhow!("{}", stderr)
+            };
+            return Err(AttemptFailure { error, retryable });
+        }
+
+        let message = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+
+        if message.is_empty() {
+            return Err(AttemptFailure::retryable(
+                worktrunk::git::GitError::Other {
+                    message: "LLM returned empty message".into(),
+                }
+                .into(),
+            ));
+        }
+
+        Ok(message)
+    }
+}
+
+/// Why an [`LlmClient`] attempt failed, and whether another attempt could
+/// succeed.
+struct AttemptFailure {
+    error: anyhow::Error,
+    retryable: bool,
+}
+
+impl AttemptFailure {
+    fn retryable(error: anyhow::Error) -> Self {
+        Self {
+            error,
+            retryable: true,
         }
-        .into());
     }
 
-    Ok(message)
+    fn fatal(error: anyhow::Error) -> Self {
+        Self {
+            error,
+            retryable: false,
+        }
+    }
+}
+
+/// `300s` for whole seconds, `1500ms` otherwise.
+fn format_timeout(timeout: Duration) -> String {
+    if timeout.subsec_millis() == 0 {
+        format!("{}s", timeout.as_secs())
+    } else {
+        format!("{}ms", timeout.as_millis())
+    }
+}
+
+fn first_line(s: &str) -> &str {
+    s.lines().next().unwrap_or_default()
 }
 
 /// Template type for selecting the appropriate template source
@@ -616,6 +765,7 @@ fn build_prompt(
             commits => &commits_chronological,
             commit_details => &commit_details_chronological,
             target_branch => context.target_branch.unwrap_or(""),
+            scope => context.scope.unwrap_or(""),
         })?)
     };
     let user_guidance = match config
@@ -641,6 +791,7 @@ fn build_prompt(
         commits => commits_chronological,
         commit_details => commit_details_chronological,
         target_branch => context.target_branch.unwrap_or(""),
+        scope => context.scope.unwrap_or(""),
         user_guidance => user_guidance,
         project_guidance => project_guidance,
     })?;
@@ -664,10 +815,6 @@ pub(crate) fn generate_commit_message(
     // Check if commit generation is configured (non-empty command)
     if commit_generation_config.is_configured() {
         let command = commit_generation_config.command.as_ref().unwrap();
-        // A slow or hung command is otherwise silent (stdout is captured); the
-        // watchdog surfaces a "still waiting" status. Held until this function
-        // returns, clearing the block before the caller prints the message.
-        let _watchdog = watch_llm_command(command, "the commit message");
         // Commit generation is explicitly configured - fail if it doesn't work
         return try_generate_commit_message(
             command,
@@ -720,7 +867,38 @@ pub(crate) fn generate_commit_message(
         n => format!("Changes to {} files", n),
     };
 
-    Ok(message)
+    Ok(match staged_scope(&repo, index_override)? {
+        Some((kind, scope)) => format!("{kind}({scope}): {message}"),
+        None => message,
+    })
+}
+
+/// The commit type and inferred scope for the staged changes, when the
+/// project config sets `[commit.scope]`.
+fn staged_scope(
+    repo: &Repository,
+    index_override: Option<&Path>,
+) -> anyhow::Result<Option<(String, String)>> {
+    let Some(config) = repo.project_config()?.and_then(|c| c.commit_scope()) else {
+        return Ok(None);
+    };
+    let mut numstat = Cmd::new("git")
+        .args(["diff", "--staged", "--numstat", "--no-renames", "-z"])
+        .current_dir(repo.discovery_path());
+    if let Some(path) = index_override {
+        numstat = numstat.env("GIT_INDEX_FILE", path);
+    }
+    let output = run_git_capture(numstat, "diff --staged --numstat")?;
+    let changes = output.split('\0').filter_map(|record| {
+        let mut fields = record.splitn(3, '\t');
+        // Binary files count `-` lines; `infer` weighs them as one
+        let added = fields.next()?.parse::<usize>().unwrap_or(0);
+        let deleted = fields.next()?.parse::<usize>().unwrap_or(0);
+        Some((fields.next()?, added + deleted))
+    });
+    Ok(config
+        .infer(changes)
+        .map(|scope| (config.fallback_type().to_string(), scope)))
 }
 
 fn try_generate_commit_message(
@@ -730,7 +908,9 @@ fn try_generate_commit_message(
     project_append: Option<&str>,
 ) -> anyhow::Result<String> {
     let prompt = build_commit_prompt(config, index_override, project_append)?;
-    execute_llm_command(command, &prompt)
+    LlmClient::from_config(command, config)
+        .foreground("the commit message")
+        .execute(&prompt)
 }
 
 /// Run a git `Cmd` and bail on non-zero exit, mirroring [`Repository::run_command`].
@@ -802,6 +982,7 @@ pub(crate) fn build_commit_prompt(
         .unwrap_or("repo");
 
     let recent_commits = repo.recent_commit_subjects(None, 5);
+    let scope = staged_scope(&repo, index_override)?;
 
     let context = TemplateContext {
         git_diff: &prepared.diff,
@@ -811,6 +992,7 @@ pub(crate) fn build_commit_prompt(
         repo_name,
         commit_details: &[],
         target_branch: None,
+        scope: scope.as_ref().map(|(_, scope)| scope.as_str()),
         project_append,
     };
     build_prompt(config, TemplateType::Commit, &context)
@@ -839,20 +1021,20 @@ pub(crate) fn generate_squash_message(
             project_append,
         )?;
 
-        // See `generate_commit_message` — keep a slow squash-message generation
-        // from being silent.
-        let _watchdog = watch_llm_command(command, "the squash commit message");
-        return execute_llm_command(command, &prompt).map_err(|e| {
-            worktrunk::git::GitError::LlmCommandFailed {
-                command: command.clone(),
-                error: e.to_string(),
-                reproduction_command: Some(format_reproduction_command(
-                    "wt step squash --show-prompt",
-                    command,
-                )),
-            }
-            .into()
-        });
+        return LlmClient::from_config(command, commit_generation_config)
+            .foreground("the squash commit message")
+            .execute(&prompt)
+            .map_err(|e| {
+                worktrunk::git::GitError::LlmCommandFailed {
+                    command: command.clone(),
+                    error: e.to_string(),
+                    reproduction_command: Some(format_reproduction_command(
+                        "wt step squash --show-prompt",
+                        command,
+                    )),
+                }
+                .into()
+            });
     }
 
     // Fallback: deterministic commit message (only when not configured)
@@ -906,6 +1088,7 @@ pub(crate) fn build_squash_prompt(
         repo_name,
         commit_details,
         target_branch: Some(target_branch),
+        scope: None,
         project_append,
     };
     build_prompt(config, TemplateType::Squash, &context)
@@ -958,6 +1141,7 @@ pub(crate) fn test_commit_generation(
         repo_name: "test-repo",
         commit_details: &[],
         target_branch: None,
+        scope: None,
         // The connectivity test sends a synthetic prompt — keep it independent
         // of any project guidance so it doesn't surface team-policy text in
         // `wt config show`.
@@ -965,17 +1149,19 @@ pub(crate) fn test_commit_generation(
     };
     let prompt = build_prompt(commit_generation_config, TemplateType::Commit, &context)?;
 
-    // The connectivity test shells out the same way real generation does, so a
-    // slow command would be just as silent — surface the same waiting status.
-    let _watchdog = watch_llm_command(command, "the test commit message");
-    execute_llm_command(command, &prompt).map_err(|e| {
-        worktrunk::git::GitError::LlmCommandFailed {
-            command: command.clone(),
-            error: e.to_string(),
-            reproduction_command: None, // Already a test command
-        }
-        .into()
-    })
+    // The connectivity test shells out the same way real generation does,
+    // with the same timeout, retries, and waiting status.
+    LlmClient::from_config(command, commit_generation_config)
+        .foreground("the test commit message")
+        .execute(&prompt)
+        .map_err(|e| {
+            worktrunk::git::GitError::LlmCommandFailed {
+                command: command.clone(),
+                error: e.to_string(),
+                reproduction_command: None, // Already a test command
+            }
+            .into()
+        })
 }
 
 #[cfg(test)]
@@ -1084,6 +1270,7 @@ mod tests {
             repo_name,
             commit_details: &[],
             target_branch: None,
+            scope: None,
             project_append: None,
         }
     }
@@ -1105,6 +1292,7 @@ mod tests {
             repo_name,
             commit_details,
             target_branch: Some(target_branch),
+            scope: None,
             project_append: None,
         }
     }
@@ -1224,6 +1412,9 @@ mod tests {
             squash_template: None,
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let context = commit_context("my diff", "feature", None, "repo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -1240,6 +1431,9 @@ mod tests {
             squash_template: None,
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let context = commit_context("diff", "main", None, "repo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -1255,6 +1449,9 @@ mod tests {
             squash_template: None,
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let context = commit_context("diff", "main", None, "repo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -1273,6 +1470,9 @@ mod tests {
             squash_template: None,
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let commits = vec!["commit1".to_string(), "commit2".to_string()];
         let context = commit_context("my diff", "feature", Some(&commits), "myrepo");
@@ -1554,6 +1754,9 @@ mod tests {
             ),
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let commit_details = vec![
             CommitMessageDetail {
@@ -1586,6 +1789,9 @@ mod tests {
             ),
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let commit_details = vec![
             CommitMessageDetail {
@@ -1625,6 +1831,9 @@ mod tests {
             squash_template: Some("{% for x in commits %}{{ x }".to_string()),
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let commit_details = vec![];
         let context = squash_context("diff", "feature", None, "repo", &commit_details, "main");
@@ -1641,6 +1850,9 @@ mod tests {
             squash_template: Some("  \n  ".to_string()),
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let commit_details = vec![];
         let context = squash_context("diff", "feature", None, "repo", &commit_details, "main");
@@ -1661,6 +1873,9 @@ mod tests {
             ),
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let commit_details = vec![
             CommitMessageDetail {
@@ -1715,6 +1930,9 @@ Diff follows:
             squash_template: None,
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
 
         // With commits — exercises if-branch, filters, loop.index, whitespace control
@@ -1771,6 +1989,9 @@ Single commit: {{ commits[0] }}
             ),
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
 
         // Multiple commits — reversed for chronological order (C, B, A)
@@ -1828,6 +2049,9 @@ Single commit: {{ commits[0] }}
             squash_template: None,
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let context = commit_context("my diff", "feature", None, "myrepo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -1850,6 +2074,9 @@ Single commit: {{ commits[0] }}
             squash_template: None,
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let context = commit_context("diff", "main", None, "repo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -1876,6 +2103,9 @@ Single commit: {{ commits[0] }}
             squash_template: None,
             squash_template_file: Some(template_path.to_string_lossy().to_string()),
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let commit_details = vec![
             CommitMessageDetail {
@@ -1908,6 +2138,9 @@ Single commit: {{ commits[0] }}
             squash_template: None,
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let context = commit_context("diff", "main", None, "repo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
@@ -1932,6 +2165,9 @@ Single commit: {{ commits[0] }}
             squash_template: None,
             squash_template_file: None,
             template_append: None,
+            timeout_ms: None,
+            retries: None,
+            stream: None,
         };
         let context = commit_context("diff", "feature", None, "repo");
         let result = build_prompt(&config, TemplateType::Commit, &context);
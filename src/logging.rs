@@ -6,7 +6,7 @@
 //!
 //! | layer            | filter                                              | format            |
 //! | ---------------- | --------------------------------------------------- | ----------------- |
-//! | stderr           | `$RUST_LOG` or flag baseline (`Off`/`Info`/`Info`)  | human, ANSI-styled |
+//! | stderr           | `$RUST_LOG` or flag baseline (`Off`/`Info`/`Info`/`Debug`) | human, ANSI-styled |
 //! | `trace.log`      | `-vv` only, excludes `SUBPROCESS_FULL_TARGET`       | human, plain text |
 //! | `trace.jsonl`    | `-vv` only, excludes both subprocess targets        | one JSON object per event (machine) |
 //! | `subprocess.log` | `-vv` only, includes only `SUBPROCESS_FULL_TARGET`  | raw bodies + `$ cmd … seq=N` headers |
@@ -19,7 +19,12 @@
 //!
 //! At `-vv` the stderr layer keeps its Info baseline — `-vv` is a strict
 //! superset of `-v`, with Debug-level records (the noisy ones, including
-//! the bounded subprocess preview) routed to the file layers only.
+//! the bounded subprocess preview) routed to the file layers only. `-vvv`
+//! lifts stderr to Debug and the files to Trace.
+//!
+//! `$WORKTRUNK_LOG` adds per-module directives (`git=debug,hooks=trace`) on
+//! top of every layer's baseline, so one subsystem can be turned up without
+//! the rest (see [`layer_filter`]).
 //!
 //! Each layer's target filter carries an explicit TRACE `max_level_hint`
 //! (see [`target_filter`]) so the `EnvFilter`'s level bound survives the
@@ -508,12 +513,16 @@ pub(crate) fn init(verbose_level: u8) {
     //
     // Mirror the env-wins-when-set semantics the layer filters use (PR #2901):
     // if `RUST_LOG` is set, its level wins; otherwise the verbosity flag
-    // baseline applies. Without an explicit cap, the default
+    // baseline applies. `WORKTRUNK_LOG` module directives only raise it. Without an explicit cap, the default
     // `LevelFilter::max()` would always pass the static check, forcing
     // every dependency `log::debug!(…)` to format its args even when no
     // sink is active.
     let _ = tracing_log::LogTracer::builder()
-        .with_max_level(effective_log_max_level(verbose_level, rust_log_level()))
+        .with_max_level(effective_log_max_level(
+            verbose_level,
+            env_log_level("RUST_LOG"),
+            env_log_level(LOG_ENV),
+        ))
         .init();
 
     if verbose_level >= 2 {
@@ -522,30 +531,37 @@ pub(crate) fn init(verbose_level: u8) {
 }
 
 /// Effective ceiling for `log::max_level` given the verbosity flag and the
-/// parsed `RUST_LOG` value. Env wins when set; otherwise the verbosity
-/// baseline (`0` → Warn, `1` → Info, `2+` → Debug) applies. Factored out
-/// so the merge logic can be tested without driving the process env.
+/// parsed `RUST_LOG` and `WORKTRUNK_LOG` values. `RUST_LOG` wins when set;
+/// otherwise the verbosity baseline (`0` → Warn, `1` → Info, `2` → Debug,
+/// `3+` → Trace) applies. `WORKTRUNK_LOG` layers on top, so it can only
+/// raise the ceiling. Factored out so the merge logic can be tested without
+/// driving the process env.
 fn effective_log_max_level(
     verbose_level: u8,
     from_env: Option<log::LevelFilter>,
+    from_modules: Option<log::LevelFilter>,
 ) -> log::LevelFilter {
     let baseline = match verbose_level {
         0 => log::LevelFilter::Warn,
         1 => log::LevelFilter::Info,
-        _ => log::LevelFilter::Debug,
+        2 => log::LevelFilter::Debug,
+        _ => log::LevelFilter::Trace,
     };
-    from_env.unwrap_or(baseline)
+    from_env
+        .unwrap_or(baseline)
+        .max(from_modules.unwrap_or(log::LevelFilter::Off))
 }
 
-/// Highest level mentioned in `$RUST_LOG`, or `None` if unset / unparsable.
+/// Highest level mentioned in the directives of env var `name`, or `None` if
+/// unset / unparsable.
 ///
 /// `RUST_LOG=info,worktrunk=debug` returns `Some(Debug)` (the most permissive
 /// directive wins). The `EnvFilter` on the stderr / trace layers still does
 /// the per-target matching; this helper just lifts `log::max_level` high
 /// enough that `log::*` macros don't short-circuit before reaching the
 /// dispatcher.
-fn rust_log_level() -> Option<log::LevelFilter> {
-    let raw = std::env::var("RUST_LOG").ok()?;
+fn env_log_level(name: &str) -> Option<log::LevelFilter> {
+    let raw = std::env::var(name).ok()?;
     raw.split(',')
         .filter_map(|directive| {
             // Each directive is either `level` or `target=level` (the level
@@ -557,6 +573,14 @@ fn rust_log_level() -> Option<log::LevelFilter> {
         .max()
 }
 
+/// Environment variable with per-module log directives, e.g.
+/// `WORKTRUNK_LOG=git=debug,hooks=trace`. A bare module name is a module of
+/// worktrunk (see [`expand_module_directive`]); a bare level replaces the
+/// verbosity baseline. Unlike `RUST_LOG`, which replaces the baseline outright,
+/// these directives apply on top of it, so one subsystem can be turned up
+/// without dumping everything else.
+pub(crate) const LOG_ENV: &str = "WORKTRUNK_LOG";
+
 /// Environment variable mirroring the `-v`/`-vv` flags as a level
 /// (`0`/`1`/`2`) — the env-var equivalent of the flag. Unlike the flag it is
 /// read everywhere, including shell completion, which exits before `main`
@@ -599,11 +623,75 @@ where
     filter_fn(predicate).with_max_level_hint(LevelFilter::TRACE)
 }
 
-/// Stderr layer: the flag sets a baseline (`Off` / `Info` / `Info`) and
-/// `RUST_LOG`, when set, overrides via the standard directive grammar —
-/// matching the env-wins-when-set convention (see PR #2901). At `-vv`
-/// stderr keeps the Info baseline so `-vv` is a strict superset of `-v`;
-/// Debug-level records (the noisy ones) route to the file layers only.
+/// Layer filter at `baseline`: `RUST_LOG` replaces the baseline when set
+/// (the env-wins-when-set convention, see PR #2901), and [`LOG_ENV`]
+/// directives apply on top of whichever is in effect.
+fn layer_filter(baseline: LevelFilter) -> EnvFilter {
+    let rust_log = std::env::var("RUST_LOG").ok();
+    let module_log = std::env::var(LOG_ENV).ok();
+    EnvFilter::builder()
+        .with_default_directive(baseline.into())
+        .parse_lossy(layer_directives(
+            baseline,
+            rust_log.as_deref(),
+            module_log.as_deref(),
+        ))
+}
+
+/// Pure merge of the baseline, `RUST_LOG`, and [`LOG_ENV`] into one
+/// directive string for [`layer_filter`].
+///
+/// `EnvFilter` only falls back to its default directive when given no
+/// directives at all, so the baseline is spelled out as a bare level here —
+/// otherwise `WORKTRUNK_LOG=git=debug` would silence every other module.
+fn layer_directives(
+    baseline: LevelFilter,
+    rust_log: Option<&str>,
+    module_log: Option<&str>,
+) -> String {
+    let modules: Vec<&str> = module_log
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .collect();
+    let (levels, targeted): (Vec<&str>, Vec<&str>) =
+        modules.into_iter().partition(|d| !d.contains('='));
+    let base = match rust_log.filter(|raw| !raw.trim().is_empty()) {
+        Some(raw) => raw.to_string(),
+        None => levels
+            .last()
+            .map_or_else(|| baseline.to_string(), |level| level.to_string()),
+    };
+    std::iter::once(base)
+        .chain(targeted.into_iter().flat_map(expand_module_directive))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Expand a `module=level` directive from [`LOG_ENV`] to the targets that
+/// module logs under: `git=debug` covers `worktrunk::git::*` in the library
+/// and `wt::git`/`wt::commands::git` in the binary. A module written as a
+/// full path (`worktrunk::git::repository=trace`) passes through as is.
+fn expand_module_directive(directive: &str) -> Vec<String> {
+    match directive.split_once('=') {
+        Some((module, level)) if !module.contains("::") => {
+            let bin = module_path!().split("::").next().unwrap_or("wt");
+            vec![
+                format!("worktrunk::{module}={level}"),
+                format!("{bin}::{module}={level}"),
+                format!("{bin}::commands::{module}={level}"),
+            ]
+        }
+        _ => vec![directive.to_string()],
+    }
+}
+
+/// Stderr layer: the flag sets a baseline (`Off` / `Info` / `Info` /
+/// `Debug`) that `RUST_LOG` and `WORKTRUNK_LOG` adjust (see
+/// [`layer_filter`]). At `-vv` stderr keeps the Info baseline so `-vv` is a
+/// strict superset of `-v`; Debug-level records (the noisy ones) route to
+/// the file layers only, until `-vvv` brings them to stderr too.
 /// Excludes `SUBPROCESS_FULL_TARGET` at all levels — raw bodies must
 /// never reach the terminal.
 fn build_stderr_layer<S>(verbose_level: u8) -> Option<impl Layer<S>>
@@ -612,11 +700,10 @@ where
 {
     let baseline = match verbose_level {
         0 => LevelFilter::OFF,
-        _ => LevelFilter::INFO,
+        1 | 2 => LevelFilter::INFO,
+        _ => LevelFilter::DEBUG,
     };
-    let env_filter = EnvFilter::builder()
-        .with_default_directive(baseline.into())
-        .from_env_lossy();
+    let env_filter = layer_filter(baseline);
     let exclude_full = target_filter(|meta| meta.target() != SUBPROCESS_FULL_TARGET);
     let layer = tracing_subscriber::fmt::layer()
         .with_writer(std::io::stderr)
@@ -626,8 +713,17 @@ where
     Some(layer)
 }
 
+/// Baseline of the file layers: Debug at `-vv`, Trace at `-vvv`.
+fn file_baseline(verbose_level: u8) -> LevelFilter {
+    if verbose_level >= 3 {
+        LevelFilter::TRACE
+    } else {
+        LevelFilter::DEBUG
+    }
+}
+
 /// `trace.log` layer: only when `-vv` opened the file. Captures everything
-/// at the Debug baseline (`RUST_LOG`, when set, overrides — e.g.
+/// at the file baseline (`RUST_LOG`, when set, overrides — e.g.
 /// `RUST_LOG=trace wt -vv` lifts the file to Trace) except
 /// `SUBPROCESS_FULL_TARGET` (raw bodies go to `subprocess.log`).
 fn build_trace_layer<S>(verbose_level: u8) -> Option<impl Layer<S>>
@@ -637,9 +733,7 @@ where
     if verbose_level < 2 || !log_files::TRACE.is_active() {
         return None;
     }
-    let env_filter = EnvFilter::builder()
-        .with_default_directive(LevelFilter::DEBUG.into())
-        .from_env_lossy();
+    let env_filter = layer_filter(file_baseline(verbose_level));
     let exclude_full = target_filter(|meta| meta.target() != SUBPROCESS_FULL_TARGET);
     let layer = tracing_subscriber::fmt::layer()
         .with_writer(TraceMakeWriter)
@@ -650,7 +744,7 @@ where
 }
 
 /// `trace.jsonl` layer: every event *except* the two subprocess-output targets,
-/// rendered one JSON object per line at the Debug baseline (`RUST_LOG`
+/// rendered one JSON object per line at the file baseline (`RUST_LOG`
 /// overrides, matching `trace.log`). Active only when `-vv` opened the file.
 ///
 /// The exclusions are the raw bodies (`SUBPROCESS_FULL_TARGET`, which belong in
@@ -666,9 +760,7 @@ where
     if verbose_level < 2 || !log_files::TRACE_JSONL.is_active() {
         return None;
     }
-    let env_filter = EnvFilter::builder()
-        .with_default_directive(LevelFilter::DEBUG.into())
-        .from_env_lossy();
+    let env_filter = layer_filter(file_baseline(verbose_level));
     let exclude_output = target_filter(|meta| {
         meta.target() != SUBPROCESS_FULL_TARGET && meta.target() != SUBPROCESS_BOUNDED_TARGET
     });
@@ -731,7 +823,7 @@ mod tests {
 
     use super::{
         WT_TRACE_TARGET, WtTraceFields, effective_log_max_level, event_json, format_wt_trace,
-        label_for_thread_index, parse_verbose_level, style_stderr_line,
+        label_for_thread_index, layer_directives, parse_verbose_level, style_stderr_line,
     };
 
     /// The level-hint footgun this module's `target_filter` exists to dodge:
@@ -1049,14 +1141,49 @@ mod tests {
     #[test]
     fn effective_log_max_level_env_wins_when_set() {
         use log::LevelFilter::*;
-        assert_eq!(effective_log_max_level(0, None), Warn);
-        assert_eq!(effective_log_max_level(1, None), Info);
-        assert_eq!(effective_log_max_level(2, None), Debug);
+        assert_eq!(effective_log_max_level(0, None, None), Warn);
+        assert_eq!(effective_log_max_level(1, None, None), Info);
+        assert_eq!(effective_log_max_level(2, None, None), Debug);
+        assert_eq!(effective_log_max_level(3, None, None), Trace);
         // Env raises:
-        assert_eq!(effective_log_max_level(0, Some(Debug)), Debug);
+        assert_eq!(effective_log_max_level(0, Some(Debug), None), Debug);
         // Env lowers (the env-wins-when-set contract — env can also
         // suppress, not just raise):
-        assert_eq!(effective_log_max_level(2, Some(Warn)), Warn);
+        assert_eq!(effective_log_max_level(2, Some(Warn), None), Warn);
+        // Module directives only raise:
+        assert_eq!(effective_log_max_level(0, None, Some(Trace)), Trace);
+        assert_eq!(effective_log_max_level(2, Some(Warn), Some(Info)), Info);
+        assert_eq!(effective_log_max_level(2, None, Some(Info)), Debug);
+    }
+
+    /// `WORKTRUNK_LOG` keeps the baseline for every module it doesn't name,
+    /// expands bare module names to worktrunk's targets, and lets a bare
+    /// level stand in for the baseline — but not for a set `RUST_LOG`.
+    #[test]
+    fn layer_directives_apply_modules_on_top() {
+        use tracing_subscriber::filter::LevelFilter;
+
+        assert_eq!(layer_directives(LevelFilter::OFF, None, None), "off");
+        assert_eq!(
+            layer_directives(LevelFilter::INFO, Some("warn"), None),
+            "warn"
+        );
+        assert_eq!(
+            layer_directives(LevelFilter::INFO, None, Some("git=debug")),
+            "info,worktrunk::git=debug,wt::git=debug,wt::commands::git=debug"
+        );
+        assert_eq!(
+            layer_directives(
+                LevelFilter::OFF,
+                None,
+                Some("trace, worktrunk::wt_trace=off")
+            ),
+            "trace,worktrunk::wt_trace=off"
+        );
+        assert_eq!(
+            layer_directives(LevelFilter::OFF, Some("info"), Some("debug")),
+            "info"
+        );
     }
 
     /// `WORKTRUNK_VERBOSE` parses like the `-v`/`-vv` count. Anything that
@@ -1071,8 +1198,8 @@ mod tests {
         assert_eq!(parse_verbose_level(Some("1")), 1);
         assert_eq!(parse_verbose_level(Some("2")), 2);
         assert_eq!(parse_verbose_level(Some(" 2 ")), 2);
-        // Higher counts pass through (treated like `-vvv`, which the layer
-        // builders already collapse to the `>= 2` behavior).
+        // Higher counts pass through (`3` is `-vvv`; the layer builders
+        // treat anything above as `-vvv`).
         assert_eq!(parse_verbose_level(Some("3")), 3);
         // Garbage and out-of-range values are dropped, not errored.
         assert_eq!(parse_verbose_level(Some("abc")), 0);
@@ -1,6 +1,6 @@
 use clap::FromArgMatches;
 use clap::error::ErrorKind as ClapErrorKind;
-use color_print::{ceprintln, cformat};
+use color_print::cformat;
 use std::process;
 use worktrunk::config::{set_config_overrides, set_config_path};
 use worktrunk::git::{
@@ -30,36 +30,45 @@ mod summary;
 
 // Re-export invocation utilities at crate level for use by other modules
 pub(crate) use invocation::{
-    binary_name, invocation_path, is_git_subcommand, was_invoked_with_explicit_path,
+    binary_name, invocation_path, is_command_substitution, is_git_subcommand,
+    was_invoked_with_explicit_path,
 };
 
 pub(crate) use crate::cli::{OutputFormat, StatuslineFormat};
 
 use commands::commit::HookGate;
 use commands::handle_picker;
-use commands::worktree::{PushKind, PushOutcome, PushResult, handle_no_ff_merge, handle_push};
+use commands::worktree::{PushOutcome, PushResult};
 use commands::{
     HookCliArgs, MergeFlagOverrides, MergeOptions, RebaseResult, SquashResult, add_approvals,
-    clear_approvals, flag_pair, handle_alias_dry_run, handle_alias_show, handle_cache_clear,
-    handle_cache_get, handle_claude_install, handle_claude_install_statusline,
+    clear_approvals, export_approvals, flag_pair, handle_alias_dry_run, handle_alias_show,
+    handle_cache_clear, handle_cache_get, handle_claude_install, handle_claude_install_statusline,
     handle_claude_uninstall, handle_codex_install, handle_codex_uninstall, handle_completions,
-    handle_config_create, handle_config_show, handle_config_update, handle_configure_shell,
-    handle_custom_command, handle_hints_clear, handle_hints_get, handle_hook_show, handle_init,
-    handle_list, handle_logs_list, handle_logs_profile, handle_merge, handle_opencode_install,
-    handle_opencode_uninstall, handle_promote, handle_rebase, handle_remove_command,
-    handle_show_theme, handle_squash, handle_state_clear, handle_state_clear_all, handle_state_get,
-    handle_state_set, handle_state_show, handle_switch_command, handle_unconfigure_shell,
-    handle_vars_clear, handle_vars_get, handle_vars_list, handle_vars_set, run_hook, step_commit,
-    step_copy_ignored, step_diff, step_eval, step_for_each, step_prune, step_relocate, step_tether,
+    handle_completions_install, handle_config_create, handle_config_show, handle_config_show_theme,
+    handle_config_update, handle_configure_shell, handle_custom_command, handle_daemon,
+    handle_debug, handle_grep, handle_hints_clear, handle_hints_get, handle_hints_list,
+    handle_hook_show, handle_hook_status, handle_init, handle_list, handle_list_group,
+    handle_lock_file, handle_logs_list, handle_logs_profile, handle_logs_prune, handle_map,
+    handle_merge, handle_merge_abort, handle_merge_continue, handle_ns_clear, handle_ns_get,
+    handle_ns_list, handle_ns_set, handle_open, handle_opencode_install, handle_opencode_uninstall,
+    handle_path, handle_pin, handle_promote, handle_rebase, handle_remove_command, handle_repair,
+    handle_serve, handle_setup, handle_show_theme, handle_snapshot_create, handle_snapshot_delete,
+    handle_snapshot_list, handle_squash, handle_state_clear, handle_state_clear_all,
+    handle_state_gc, handle_state_get, handle_state_set, handle_state_show, handle_stats,
+    handle_switch_command, handle_unconfigure_shell, handle_unpin, handle_vars_clear,
+    handle_vars_get, handle_vars_list, handle_vars_set, import_approvals, run_hook, step_clone,
+    step_commit, step_copy_ignored, step_diff, step_diff_branches, step_eval, step_for_each,
+    step_optimize_repo, step_prune, step_relocate, step_revert_merge, step_tether,
+    warn_if_repository_moved,
 };
 
 use cli::{
-    ApprovalsCommand, CacheAction, CiStatusAction, Cli, Commands, ConfigAliasCommand,
-    ConfigCommand, ConfigPluginsClaudeCommand, ConfigPluginsCodexCommand, ConfigPluginsCommand,
-    ConfigPluginsOpencodeCommand, ConfigShellCommand, DefaultBranchAction, GlobalFormatFlag,
-    HintsAction, HookCommand, HookOptions, ListArgs, ListSubcommand, LogsAction, MarkerAction,
-    MergeArgs, PreviousBranchAction, StateCommand, StateWrite, StepCommand, SwitchFormat,
-    VarsAction,
+    ApprovalsCommand, CacheAction, CiStatusAction, Cli, ColorWhen, Commands, CompletionsCommand,
+    ConfigAliasCommand, ConfigCommand, ConfigPluginsClaudeCommand, ConfigPluginsCodexCommand,
+    ConfigPluginsCommand, ConfigPluginsOpencodeCommand, ConfigShellCommand, ConfigShowTopic,
+    DefaultBranchAction, GlobalFormatFlag, HintsAction, HintsCommand, HookCommand, HookOptions,
+    ListArgs, ListSubcommand, LogsAction, MarkerAction, MergeArgs, PreviousBranchAction,
+    SnapshotCommand, StateCommand, StateWrite, StepCommand, SwitchFormat, VarsAction,
 };
 
 /// Render a clap error to stderr, appending a wt-specific nested-subcommand
@@ -73,10 +82,13 @@ fn print_enhanced_clap_error(err: &clap::Error) {
     {
         let cmd = cli::build_command();
         if let Some(suggestion) = cli::suggest_nested_subcommand(&cmd, &unknown.to_string()) {
-            ceprintln!(
-                "{}
+            eprintln!(
+                "{}",
+                cformat!(
+                    "{}
   <yellow>tip:</>  perhaps <cyan,bold>{suggestion}</cyan,bold>?",
-                err.render().ansi()
+                    err.render().ansi()
+                )
             );
             return;
         }
@@ -118,11 +130,11 @@ fn warn_select_deprecated() {
 /// moved under `wt config state cache` (ci-status, hints, previous-branch).
 /// These still work — the warning nudges callers toward `cache`.
 fn warn_state_subcommand_deprecated(name: &str) {
-    eprintln!(
-        "{}",
+    output::notice::STATE_SUBCOMMAND_DEPRECATED.emit(
+        Repository::current().ok().as_ref(),
         warning_message(cformat!(
             "wt config state {name} is deprecated; use <bold>wt config state cache</> instead"
-        ))
+        )),
     );
 }
 
@@ -132,11 +144,11 @@ fn warn_state_subcommand_deprecated(name: &str) {
 /// step commit, step squash) and `handle_merge_command` both call here, so the
 /// message stays identical across every command that accepts `--no-verify`.
 pub(crate) fn warn_no_verify_deprecated() {
-    eprintln!(
-        "{}",
+    output::notice::NO_VERIFY_DEPRECATED.emit(
+        Repository::current().ok().as_ref(),
         warning_message(cformat!(
             "--no-verify is deprecated; use <bold>--no-hooks</> instead"
-        ))
+        )),
     );
 }
 
@@ -147,6 +159,7 @@ fn handle_hook_command(action: HookCommand, yes: bool) -> anyhow::Result<()> {
             expanded,
             format,
         } => handle_hook_show(hook_type.as_deref(), expanded, format),
+        HookCommand::Status { branch, format } => handle_hook_status(branch, format),
         HookCommand::RunPipeline => commands::run_pipeline(),
         HookCommand::Approvals { action } => {
             eprintln!(
@@ -158,6 +171,8 @@ fn handle_hook_command(action: HookCommand, yes: bool) -> anyhow::Result<()> {
             match action {
                 ApprovalsCommand::Add { all } => add_approvals(all),
                 ApprovalsCommand::Clear { global } => clear_approvals(global),
+                ApprovalsCommand::Export => export_approvals(),
+                ApprovalsCommand::Import { file } => import_approvals(&file),
             }
         }
         HookCommand::Run(args) => {
@@ -171,6 +186,7 @@ fn handle_hook_command(action: HookCommand, yes: bool) -> anyhow::Result<()> {
                 yes || opts.yes,
                 opts.foreground,
                 opts.dry_run,
+                opts.all,
                 HookCliArgs {
                     name_filters: &opts.name_filters,
                     explicit_vars: &opts.explicit_vars,
@@ -232,6 +248,7 @@ fn handle_step_command(
             } else {
                 // Approval is handled inside handle_squash (like step_commit).
                 let repo = Repository::current()?;
+                commands::check_step_not_on_default_branch(&repo, "squash", args.allow_primary)?;
                 let hooks = if verify {
                     HookGate::Run
                 } else {
@@ -297,16 +314,11 @@ fn handle_step_command(
         StepCommand::Push {
             target,
             no_ff,
+            hooks,
             format,
             ..
         } => {
-            let result = if no_ff {
-                let repo = Repository::current()?;
-                let current_branch = repo.require_current_branch("step push --no-ff")?;
-                handle_no_ff_merge(target.as_deref(), None, &current_branch)?
-            } else {
-                handle_push(target.as_deref(), PushKind::Standalone, None)?
-            };
+            let result = commands::step_push(target.as_deref(), no_ff, yes, hooks.resolve())?;
             if format == SwitchFormat::Json {
                 let PushResult {
                     target,
@@ -329,16 +341,28 @@ fn handle_step_command(
             }
             Ok(())
         }
-        StepCommand::Rebase { target, format } => {
-            let result = handle_rebase(target.as_deref())?;
+        StepCommand::Rebase {
+            target,
+            update_refs,
+            allow_primary,
+            format,
+        } => {
+            commands::check_step_not_on_default_branch(
+                &Repository::current()?,
+                "rebase",
+                allow_primary,
+            )?;
+            let result = handle_rebase(target.as_deref(), update_refs)?;
             if format == SwitchFormat::Json {
                 let output = match &result {
                     RebaseResult::Rebased {
                         target,
                         fast_forward,
+                        updated_refs,
                     } => serde_json::json!({
                         "target": target,
                         "outcome": if *fast_forward { "fast_forwarded" } else { "rebased" },
+                        "updated_refs": updated_refs,
                     }),
                     RebaseResult::UpToDate(target) => serde_json::json!({
                         "target": target,
@@ -359,6 +383,11 @@ fn handle_step_command(
             branch,
             extra_args,
         } => step_diff(branch.as_deref(), target.as_deref(), &extra_args),
+        StepCommand::DiffBranches {
+            from,
+            to,
+            extra_args,
+        } => step_diff_branches(&from, &to, &extra_args),
         StepCommand::CopyIgnored {
             from,
             to,
@@ -374,8 +403,23 @@ fn handle_step_command(
             require_include,
             format,
         ),
+        StepCommand::Clone {
+            url,
+            directory,
+            reference,
+            filter,
+        } => step_clone(
+            &url,
+            directory.as_deref(),
+            reference.as_deref(),
+            filter.as_deref(),
+        ),
         StepCommand::Eval { template, format } => step_eval(&template, format),
-        StepCommand::ForEach { format, args } => step_for_each(args, format),
+        StepCommand::ForEach {
+            format,
+            group,
+            args,
+        } => step_for_each(args, format, group.as_deref()),
         StepCommand::Promote { branch } => {
             handle_promote(branch.as_deref()).map(|result| match result {
                 commands::PromoteResult::Promoted => (),
@@ -389,12 +433,21 @@ fn handle_step_command(
                 }
             })
         }
+        StepCommand::OptimizeRepo => step_optimize_repo(),
         StepCommand::Prune {
             dry_run,
             min_age,
             foreground,
             format,
-        } => step_prune(dry_run, yes, &min_age, foreground, format),
+            report,
+        } => step_prune(
+            dry_run,
+            yes,
+            min_age.as_deref(),
+            foreground,
+            format,
+            report.as_deref(),
+        ),
         StepCommand::Relocate {
             branches,
             dry_run,
@@ -402,6 +455,9 @@ fn handle_step_command(
             clobber,
             format,
         } => step_relocate(branches, dry_run, commit, clobber, format),
+        StepCommand::RevertMerge { branch, restore } => {
+            step_revert_merge(branch.as_deref(), restore, yes)
+        }
         StepCommand::Tether { command } => step_tether(&command, working_dir.as_deref()),
         StepCommand::External(args) => commands::step_alias(args, yes),
     }
@@ -520,7 +576,10 @@ fn handle_state_command(action: StateCommand, yes: bool) -> anyhow::Result<()> {
             }
             match action {
                 Some(LogsAction::Get) | None => handle_logs_list(format),
-                Some(LogsAction::Profile { file }) => handle_logs_profile(file, format),
+                Some(LogsAction::Profile { file, flame }) => {
+                    handle_logs_profile(file, flame, format)
+                }
+                Some(LogsAction::Prune { older_than }) => handle_logs_prune(&older_than),
                 Some(LogsAction::Clear) => handle_state_clear("logs", None, false),
             }
         }
@@ -548,8 +607,22 @@ fn handle_state_command(action: StateCommand, yes: bool) -> anyhow::Result<()> {
                 handle_vars_clear(key.as_deref(), all, branch)
             }
         },
-        StateCommand::Get { format } => handle_state_show(format),
-        StateCommand::Clear => handle_state_clear_all(yes),
+        StateCommand::Get { ns, key, format } => match (ns, key) {
+            (Some(ns), Some(key)) => handle_ns_get(&ns, &key),
+            _ => handle_state_show(format),
+        },
+        StateCommand::List { ns, format } => handle_ns_list(ns.as_deref(), format),
+        StateCommand::Set {
+            ns,
+            key,
+            value,
+            ttl,
+        } => handle_ns_set(&ns, &key, &value, ttl.as_deref()),
+        StateCommand::Clear { ns, key } => match ns {
+            Some(ns) => handle_ns_clear(&ns, key.as_deref()),
+            None => handle_state_clear_all(yes),
+        },
+        StateCommand::Gc => handle_state_gc(),
     }
 }
 
@@ -609,11 +682,22 @@ fn handle_config_command(action: ConfigCommand, yes: bool) -> anyhow::Result<()>
     match action {
         ConfigCommand::Shell { action } => handle_config_shell_command(action, yes),
         ConfigCommand::Create { project } => handle_config_create(project),
-        ConfigCommand::Show { full, format } => handle_config_show(full, format),
+        ConfigCommand::Show {
+            topic: Some(ConfigShowTopic::Theme),
+            format,
+            ..
+        } => handle_config_show_theme(format),
+        ConfigCommand::Show {
+            topic: None,
+            full,
+            format,
+        } => handle_config_show(full, format),
         ConfigCommand::Update { print } => handle_config_update(yes, print),
         ConfigCommand::Approvals { action } => match action {
             ApprovalsCommand::Add { all } => add_approvals(all),
             ApprovalsCommand::Clear { global } => clear_approvals(global),
+            ApprovalsCommand::Export => export_approvals(),
+            ApprovalsCommand::Import { file } => import_approvals(&file),
         },
         ConfigCommand::Alias { action } => match action {
             ConfigAliasCommand::Show { name } => handle_alias_show(name),
@@ -665,17 +749,38 @@ fn handle_list_command(args: ListArgs) -> anyhow::Result<()> {
             };
             commands::statusline::run(effective_format)
         }
-        None => {
-            let (repo, _recovered) = current_or_recover()?;
-            handle_list(
-                repo,
+        None => match args.group {
+            Some(group) => handle_list_group(
+                &group,
                 args.format,
                 args.branches,
                 args.remotes,
                 args.full,
+                args.size,
+                args.tree,
+                args.only_mine,
                 flag_pair(args.progressive, args.no_progressive),
-            )
-        }
+            ),
+            None => {
+                let (repo, _recovered) = current_or_recover()?;
+                handle_list(
+                    repo,
+                    args.format,
+                    args.branches,
+                    args.remotes,
+                    args.full,
+                    args.size,
+                    args.tree,
+                    args.only_mine,
+                    flag_pair(args.progressive, args.no_progressive),
+                    args.output.as_deref(),
+                    args.atomic,
+                    args.changed,
+                    args.porcelain,
+                    args.summary,
+                )
+            }
+        },
     }
 }
 
@@ -718,7 +823,7 @@ fn init_rayon_thread_pool() {
         .build_global();
 }
 
-fn parse_cli() -> Option<Cli> {
+fn parse_cli() -> Option<(Cli, String)> {
     if completion::maybe_handle_env_completion() {
         return None;
     }
@@ -729,8 +834,8 @@ fn parse_cli() -> Option<Cli> {
     // The same early parse also tells us whether this is help for the top
     // level or `wt step`, so the splice path in `augment_help` has no
     // separate arg scanner.
-    let (directory, config, config_overrides, alias_help_context) = parse_early_globals();
-    apply_global_options(directory, config, config_overrides);
+    let (directory, config, config_overrides, color, alias_help_context) = parse_early_globals();
+    apply_global_options(directory, config, config_overrides, color);
 
     // Handle --help with pager before clap processes it.
     // Exits the process on a help/version/doc request; otherwise returns.
@@ -747,14 +852,45 @@ fn parse_cli() -> Option<Cli> {
         .unwrap_or_else(|e| {
             enhance_and_exit_error(e);
         });
-    Some(Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit()))
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    Some((cli, command_path(&matches)))
+}
+
+/// The matched subcommand path as `wt stats` groups it, e.g. `step commit`.
+///
+/// Two levels at most: deeper paths (`config state logs get`) fold into
+/// their parent group.
+fn command_path(matches: &clap::ArgMatches) -> String {
+    let mut path = Vec::new();
+    let mut current = matches;
+    while path.len() < 2
+        && let Some((name, sub)) = current.subcommand()
+    {
+        path.push(name);
+        current = sub;
+    }
+    path.join(" ")
+}
+
+fn init_command_log(command_line: &str) {
+    // Initialize command log for always-on logging of hooks and LLM commands.
+    // Directory and file are created lazily on first log_command() call.
+    if let Ok(repo) = worktrunk::git::Repository::current() {
+        worktrunk::command_log::init(&repo.wt_logs_dir(), command_line);
+        worktrunk::stats::init(&repo);
+    }
 }
 
 fn apply_global_options(
     directory: Option<std::path::PathBuf>,
     config: Option<std::path::PathBuf>,
     config_overrides: Vec<String>,
+    color: ColorWhen,
 ) {
+    // Before anything prints — help and clap errors included.
+    worktrunk::styling::set_color_choice(color.into());
+    crossterm::style::force_color_output(color != ColorWhen::Never);
+
     // Initialize base path from -C flag if provided
     if let Some(path) = directory {
         set_base_path(path);
@@ -771,7 +907,7 @@ fn apply_global_options(
     }
 }
 
-/// Parse global options (`-C`, `--config`, `--config-set`) and detect whether this
+/// Parse global options (`-C`, `--config`, `--config-set`, `--color`) and detect whether this
 /// invocation renders help that should include the configured aliases — in a
 /// single pass against the real `Cli` definition.
 ///
@@ -787,20 +923,30 @@ fn parse_early_globals() -> (
     Option<std::path::PathBuf>,
     Option<std::path::PathBuf>,
     Vec<String>,
+    ColorWhen,
     Option<commands::HelpContext>,
 ) {
     let cmd = cli::build_command()
         .ignore_errors(true)
         .disable_help_flag(true);
     let Ok(matches) = cmd.try_get_matches_from(std::env::args_os()) else {
-        return (None, None, Vec::new(), None);
+        return (None, None, Vec::new(), ColorWhen::Auto, None);
     };
+    let color = matches
+        .get_one::<ColorWhen>("color")
+        .copied()
+        .unwrap_or(ColorWhen::Auto);
     let directory = matches.get_one::<std::path::PathBuf>("directory").cloned();
     let config = matches.get_one::<std::path::PathBuf>("config").cloned();
-    let config_overrides = matches
+    let mut config_overrides: Vec<String> = matches
         .get_many::<String>("config_override")
         .map(|values| values.cloned().collect())
         .unwrap_or_default();
+    // `--offline` is shorthand for `--config-set network=false`, so every
+    // config load sees it.
+    if matches.get_flag("offline") {
+        config_overrides.push("network = false".to_string());
+    }
     // Top-level help: `wt --help` (or `-h`, or bare `wt` via `arg_required_else_help`)
     // lands here with no subcommand matched. Step help: `wt step --help` (or
     // `-h`, or bare `wt step`) matches `step` with nothing past it. Other
@@ -810,28 +956,62 @@ fn parse_early_globals() -> (
         Some(("step", sub)) if sub.subcommand_name().is_none() => Some(commands::HelpContext::Step),
         _ => None,
     };
-    (directory, config, config_overrides, alias_help_context)
+    (
+        directory,
+        config,
+        config_overrides,
+        color,
+        alias_help_context,
+    )
 }
 
-fn init_command_log(command_line: &str) {
-    // Initialize command log for always-on logging of hooks and LLM commands.
-    // Directory and file are created lazily on first log_command() call.
-    if let Ok(repo) = worktrunk::git::Repository::current() {
-        worktrunk::command_log::init(&repo.wt_logs_dir(), command_line);
+fn handle_merge_command(args: MergeArgs, yes: bool) -> anyhow::Result<()> {
+    if args.abort {
+        return handle_merge_abort();
     }
+    let result = if args.resume {
+        handle_merge_continue(yes, args.format, args.queue)
+    } else {
+        handle_merge(MergeOptions {
+            target: args.target.as_deref(),
+            flags: MergeFlagOverrides::from_cli(&args),
+            yes,
+            stage: args.stage,
+            stacked_base: args.stacked_base,
+            format: args.format,
+            resume: None,
+            queue: args.queue,
+        })
+    };
+    result.map_err(merge_resume_hint)
 }
 
-fn handle_merge_command(args: MergeArgs, yes: bool) -> anyhow::Result<()> {
-    if args.no_verify {
-        warn_no_verify_deprecated();
+/// Follow a failed `wt merge` with how to resume or roll it back.
+///
+/// Only when the failure left a journal behind (see `merge_journal`) and the
+/// user didn't interrupt — Ctrl-C propagates untouched.
+fn merge_resume_hint(err: anyhow::Error) -> anyhow::Error {
+    if err.interrupt_exit_code().is_some() {
+        return err;
     }
-    handle_merge(MergeOptions {
-        target: args.target.as_deref(),
-        flags: MergeFlagOverrides::from_cli(&args),
-        yes,
-        stage: args.stage,
-        format: args.format,
-    })
+    let pending = Repository::current().ok().and_then(|repo| {
+        let branch = commands::merge_journal::journal_branch(&repo)
+            .ok()
+            .flatten()?;
+        commands::merge_journal::MergeJournal::load_fresh(&repo, &branch)
+    });
+    if pending.is_none() {
+        return err;
+    }
+    let exit_code = err.process_exit_code();
+    print_command_error(&err);
+    eprintln!(
+        "{}",
+        hint_message(cformat!(
+            "To resume after fixing, run <bright-black>wt merge --continue</>; to roll back, <bright-black>wt merge --abort</>"
+        ))
+    );
+    WorktrunkError::AlreadyDisplayed { exit_code }.into()
 }
 
 /// True when the parsed command should silence prewarm-time deprecation
@@ -851,7 +1031,7 @@ fn handle_merge_command(args: MergeArgs, yes: bool) -> anyhow::Result<()> {
 fn command_suppresses_warnings(command: Option<&Commands>) -> bool {
     match command {
         Some(Commands::Select { .. }) => true,
-        Some(Commands::Switch(args)) => args.branch.is_none(),
+        Some(Commands::Switch(args)) => args.branch.is_none() && args.batch.is_none(),
         Some(Commands::List(args)) => {
             matches!(args.subcommand, Some(ListSubcommand::Statusline { .. }))
         }
@@ -869,6 +1049,45 @@ fn dispatch_command(
 ) -> anyhow::Result<()> {
     match command {
         Commands::Config { action } => handle_config_command(action, yes),
+        Commands::Setup => handle_setup(yes),
+        Commands::Grep {
+            pattern,
+            dirty_only,
+            args,
+        } => handle_grep(&pattern, dirty_only, &args),
+        Commands::Open { branch, editor } => handle_open(branch, editor, yes),
+        Commands::Pin { branch } => handle_pin(branch),
+        Commands::Unpin { branch } => handle_unpin(branch),
+        Commands::LockFile {
+            path,
+            release,
+            check,
+            wait,
+            format,
+        } => handle_lock_file(path, release, check, wait, format.format),
+        Commands::Snapshot { action, format } => match action {
+            Some(SnapshotCommand::Create { name, base }) => {
+                handle_snapshot_create(&name, base.as_deref())
+            }
+            Some(SnapshotCommand::List) | None => handle_snapshot_list(format.format),
+            Some(SnapshotCommand::Delete { name }) => handle_snapshot_delete(&name),
+        },
+        Commands::Repair => handle_repair(),
+        Commands::Path { branch } => handle_path(branch.as_deref()),
+        Commands::Map { branches, format } => handle_map(&branches, format),
+        Commands::Hints { action, format } => match action {
+            Some(HintsCommand::List) | None => handle_hints_list(format.format),
+            Some(HintsCommand::Reset { name }) => handle_hints_clear(name),
+        },
+        Commands::Completions { action } => match action {
+            CompletionsCommand::Install { shell, dry_run } => {
+                handle_completions_install(shell, dry_run)
+            }
+        },
+        Commands::Daemon { action } => handle_daemon(action),
+        Commands::Debug { action } => handle_debug(action, working_dir.as_deref(), yes),
+        Commands::Serve { allow } => handle_serve(&allow),
+        Commands::Stats { weeks, format } => handle_stats(weeks, format),
         Commands::Step { action } => handle_step_command(action, working_dir, yes),
         Commands::Hook { action } => handle_hook_command(action, yes),
         Commands::Select { branches, remotes } => handle_select_command(branches, remotes),
@@ -1015,18 +1234,60 @@ fn finish_command(verbose_level: u8, command_line: &str, error: Option<&anyhow::
     let error_text = error.map(|err| err.to_string());
     diagnostic::write_if_verbose(verbose_level, command_line, error_text.as_deref());
     let _ = output::terminate_output();
+    worktrunk::styling::progress::done(error.map_or(0, |err| err.process_exit_code()));
 }
 
 fn handle_command_failure(error: anyhow::Error, verbose_level: u8, command_line: &str) -> ! {
     print_command_error(&error);
     print_cwd_removed_hint_if_needed();
 
-    // Preserve exit code from child processes (especially for signals like SIGINT)
-    let code = error.exit_code().unwrap_or(1);
+    // Child exit codes (especially signals like SIGINT) pass through;
+    // otherwise the documented category code.
+    let code = error.process_exit_code();
+    if json_format_requested(std::env::args().skip(1)) {
+        print_json_error(&error, code);
+    }
     finish_command(verbose_level, command_line, Some(&error));
     process::exit(code);
 }
 
+/// Whether the command line asks for `--format=json` (before any `--`).
+///
+/// Scanned from argv rather than the parsed command since every JSON-capable
+/// subcommand declares its own `--format`.
+fn json_format_requested(args: impl IntoIterator<Item = String>) -> bool {
+    let mut args = args.into_iter().take_while(|arg| arg != "--");
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format=json" => return true,
+            "--format" if args.next().as_deref() == Some("json") => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Print a failed command's error as JSON on stdout, for `--format=json`
+/// callers that parse stdout rather than stderr.
+///
+/// Errors that were already displayed (or declined prompts) carry no message
+/// and print nothing.
+fn print_json_error(error: &anyhow::Error, exit_code: i32) {
+    use ansi_str::AnsiStr;
+    let message = format!("{error:#}").ansi_strip().into_owned();
+    if message.is_empty() {
+        return;
+    }
+    let payload = serde_json::json!({
+        "error": {
+            "code": error.error_code(),
+            "message": message,
+            "exit_code": exit_code,
+        }
+    });
+    println!("{payload}");
+}
+
 fn print_help_to_stderr() {
     // No subcommand provided - print help to stderr (stdout is eval'd by shell wrapper)
     let mut cmd = cli::build_command();
@@ -1043,17 +1304,14 @@ fn main() {
     //
     // `[wt-trace]` spans before the logger is registered would silently
     // no-op, so the prelude up to `init_logging` — `init_startup_cwd`,
-    // `init_rayon_thread_pool`, `force_color_output`, `parse_cli` — isn't
+    // `init_rayon_thread_pool`, `parse_cli` — isn't
     // attributed. If startup itself becomes the suspect, capture it as
     // wall-clock minus the sum of post-init spans.
     worktrunk::shell_exec::init_startup_cwd();
 
     init_rayon_thread_pool();
 
-    // Tell crossterm to always emit ANSI sequences
-    crossterm::style::force_color_output(true);
-
-    let Some(cli) = parse_cli() else {
+    let Some((cli, command_path)) = parse_cli() else {
         return;
     };
 
@@ -1062,9 +1320,30 @@ fn main() {
         config,
         config_override,
         verbose,
+        quiet,
+        progress_json,
         yes,
+        approve,
+        // Folded into the config overrides by `parse_early_globals`
+        offline: _,
+        color,
+        absolute_times,
         command,
     } = cli;
+    worktrunk::styling::set_quiet(quiet);
+    display::set_absolute_times(absolute_times);
+    commands::command_approval::init_approve_policy(&approve);
+    if let Some(fd) = progress_json
+        && let Err(err) = worktrunk::styling::progress::enable(fd)
+    {
+        eprintln!(
+            "{}",
+            error_message(cformat!(
+                "Cannot write progress events to file descriptor <bold>{fd}</>: {err}"
+            ))
+        );
+        process::exit(2);
+    }
     // `WORKTRUNK_VERBOSE` provides a baseline verbosity the `-v`/`-vv` flags
     // raise but never lower (`max`). It also drives shell completion, which
     // exits in `parse_cli` before reaching here — see
@@ -1073,7 +1352,7 @@ fn main() {
     // Globals were already applied in `parse_cli` before help rendering;
     // OnceLock makes this call a no-op, but keeping it avoids touching the
     // existing destructure pattern.
-    apply_global_options(directory.clone(), config, config_override);
+    apply_global_options(directory.clone(), config, config_override, color);
 
     // Latch warning suppression for commands whose UX is broken by stderr
     // noise — TUI pickers (`switch` without a branch, `select`) and
@@ -1083,7 +1362,8 @@ fn main() {
     // command handler's own `suppress_warnings()` call could latch.
     // Handlers keep their `suppress_warnings()` calls — `OnceLock` is
     // idempotent and the local call documents the intent at the use site.
-    if command_suppresses_warnings(command.as_ref()) {
+    let suppress_warnings = command_suppresses_warnings(command.as_ref());
+    if suppress_warnings {
         worktrunk::config::suppress_warnings();
     }
 
@@ -1107,6 +1387,12 @@ fn main() {
     // on-demand callers unchanged.
     Repository::prewarm();
 
+    // A moved repository breaks git in every worktree; say how to fix it
+    // before the command trips over it. `wt repair` reports for itself.
+    if !suppress_warnings && !matches!(command, None | Some(Commands::Repair)) {
+        warn_if_repository_moved();
+    }
+
     let command_line = std::env::args_os()
         .map(|arg| arg.to_string_lossy().into_owned())
         .collect::<Vec<_>>()
@@ -1121,7 +1407,14 @@ fn main() {
         return;
     };
 
+    // The statusline renders on every prompt; counting it would drown out
+    // everything else in `wt stats`, as would `wt stats` counting itself.
+    let record_stats = !matches!(command_path.as_str(), "list statusline" | "stats");
+    let started = std::time::Instant::now();
     let result = dispatch_command(command, directory, yes);
+    if record_stats {
+        worktrunk::stats::record_command(&command_path, started.elapsed(), result.is_ok());
+    }
 
     match result {
         Ok(()) => finish_command(verbose, &command_line, None),
@@ -1147,6 +1440,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn detects_json_format_request() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+        assert!(json_format_requested(args("switch feature --format=json")));
+        assert!(json_format_requested(args("list --format json")));
+        assert!(!json_format_requested(args("list --format table")));
+        assert!(!json_format_requested(args(
+            "step for-each -- jq --format=json"
+        )));
+        assert!(!json_format_requested(args("list --format")));
+    }
+
     /// Regression for #2564: a buffered `git` failure surfaces as a typed
     /// `CommandError`. The single-line summary becomes the header and the
     /// multi-line stderr lands in the gutter — no `debug_assert!` panic.
@@ -119,7 +119,7 @@ fn command_exists_in(cmd: &str, paths: Option<&OsStr>) -> bool {
 ///
 /// Uses the toml crate for proper escaping. The result may wrap in terminal
 /// but is always valid TOML.
-fn format_command_for_display(command: &str) -> String {
+pub(crate) fn format_command_for_display(command: &str) -> String {
     toml::Value::String(command.to_string()).to_string()
 }
 
@@ -167,8 +167,9 @@ pub fn prompt_commit_generation(config: &mut UserConfig) -> anyhow::Result<bool>
         return Ok(false);
     }
 
-    // Skip if prompt was previously declined or dismissed
-    if config.skip_commit_generation_prompt {
+    // Skip if prompt was previously declined or dismissed, or offline (the
+    // command is dropped, not missing)
+    if config.skip_commit_generation_prompt || config.offline() {
         return Ok(false);
     }
 
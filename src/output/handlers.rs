@@ -27,17 +27,19 @@ use worktrunk::git::Repository;
 use worktrunk::git::path_dir_name;
 use worktrunk::git::{
     BranchDeletionMode, BranchDeletionOutcome, BranchDeletionResult, RemoveOptions,
-    delete_branch_if_safe, remove_worktree_with_cleanup, stage_worktree_removal,
-    stop_fsmonitor_daemon,
+    delete_branch_if_safe, forget_deleted_branch, remove_worktree_with_cleanup,
+    stage_worktree_removal, stop_fsmonitor_daemon,
 };
 use worktrunk::path::format_path_for_display;
 use worktrunk::progress::{Progress, format_stats_paren};
 use worktrunk::remove_dir::remove_dir_with_progress;
+use worktrunk::stats::StatsEvent;
 use worktrunk::styling::{
     FormattedMessage, eprintln, error_message, format_with_gutter, hint_message, info_message,
     progress_message, success_message, suggest_command, verbosity, warning_message,
 };
 
+use super::notice;
 use super::shell_integration::{
     compute_shell_warning_reason, explicit_path_hint, git_subcommand_warning,
     print_shell_integration_hint, should_show_explicit_path_hint,
@@ -419,7 +421,8 @@ fn list_remaining_entries(path: &Path) -> Option<Vec<String>> {
 /// Format a switch message based on what was created
 ///
 /// # Message formats
-/// - Branch + worktree created (`--create`): "Created branch X from Y and worktree @ path"
+/// - Branch + worktree created (`--create`): "Created branch X from Y and worktree @ path",
+///   with "(tracking Z)" after Y when an upstream was set
 /// - Branch from remote + worktree (DWIM): "Created branch X (tracking remote) and worktree @ path"
 /// - Worktree only created: "Created worktree for X @ path"
 /// - Switched to existing: "Switched to worktree for X @ path"
@@ -429,21 +432,22 @@ fn format_switch_message(
     worktree_created: bool,
     created_branch: bool,
     base_branch: Option<&str>,
-    from_remote: Option<&str>,
+    upstream: Option<&str>,
 ) -> String {
     let path_display = format_path_for_display(path);
 
     if created_branch {
         // --create flag: created branch and worktree
-        match base_branch {
-            Some(base) => cformat!(
-                "Created branch <bold>{branch}</> from <bold>{base}</> and worktree @ <bold>{path_display}</>"
-            ),
-            None => {
-                cformat!("Created branch <bold>{branch}</> and worktree @ <bold>{path_display}</>")
-            }
-        }
-    } else if let Some(remote) = from_remote {
+        let from = base_branch
+            .map(|base| cformat!(" from <bold>{base}</>"))
+            .unwrap_or_default();
+        let tracking = upstream
+            .map(|upstream| cformat!(" (tracking <bold>{upstream}</>)"))
+            .unwrap_or_default();
+        cformat!(
+            "Created branch <bold>{branch}</>{from}{tracking} and worktree @ <bold>{path_display}</>"
+        )
+    } else if let Some(remote) = upstream {
         // DWIM from remote: created local tracking branch and worktree
         cformat!(
             "Created branch <bold>{branch}</> (tracking <bold>{remote}</>) and worktree @ <bold>{path_display}</>"
@@ -497,14 +501,16 @@ fn build_switch_output_context(
 
     let is_git_subcommand = crate::is_git_subcommand();
     let is_shell_integration_active = super::is_shell_integration_active();
-    let shell_warning_reason = if !change_dir || is_shell_integration_active {
+    // Inside `$(wt switch ...)` the calling shell staying put is expected.
+    let in_substitution = crate::is_command_substitution();
+    let shell_warning_reason = if !change_dir || is_shell_integration_active || in_substitution {
         None
     } else if is_git_subcommand {
         Some("ran git wt; running through git prevents cd".to_string())
     } else {
         Some(compute_shell_warning_reason())
     };
-    let user_wont_be_in_worktree = !change_dir || shell_warning_reason.is_some();
+    let user_wont_be_in_worktree = !change_dir || in_substitution || shell_warning_reason.is_some();
     let branch_worktree_mismatch_warning = branch_info
         .expected_path
         .as_ref()
@@ -524,6 +530,13 @@ fn build_switch_output_context(
 fn print_switch_path_mismatch_warning(ctx: &SwitchOutputContext) {
     if let Some(warning) = &ctx.branch_worktree_mismatch_warning {
         eprintln!("{}", warning);
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To move it, run <underline>wt step relocate {}</>",
+                ctx.branch
+            ))
+        );
     }
 }
 
@@ -593,12 +606,13 @@ fn maybe_print_worktree_path_hint(created_branch: bool) {
                         .is_some_and(|p| c.has_project_worktree_path(&p))
             })
             .unwrap_or(false);
-        if !has_custom_config && !repo.has_shown_hint("worktree-path") {
-            let hint = hint_message(cformat!(
-                "To customize worktree locations, run <underline>wt config create</>"
-            ));
-            eprintln!("{}", hint);
-            let _ = repo.mark_hint_shown("worktree-path");
+        if !has_custom_config {
+            notice::WORKTREE_PATH.emit(
+                Some(&repo),
+                hint_message(cformat!(
+                    "To customize worktree locations, run <underline>wt config create</>"
+                )),
+            );
         }
     }
 }
@@ -607,7 +621,7 @@ fn handle_switch_created_output(
     ctx: &SwitchOutputContext,
     created_branch: bool,
     base_branch: Option<&str>,
-    from_remote: Option<&str>,
+    upstream: Option<&str>,
 ) -> Option<PathBuf> {
     eprintln!(
         "{}",
@@ -617,7 +631,7 @@ fn handle_switch_created_output(
             true, // worktree_created
             created_branch,
             base_branch,
-            from_remote,
+            upstream,
         ))
     );
 
@@ -842,7 +856,7 @@ fn print_switch_message_if_changed(
             )
         );
         eprintln!("{}", hint_message(git_subcommand_warning()));
-    } else {
+    } else if !crate::is_command_substitution() {
         // Shell integration not active - compute specific reason
         let reason = compute_shell_warning_reason();
         eprintln!(
@@ -942,12 +956,13 @@ pub fn handle_switch_output(
             created_branch,
             base_branch,
             from_remote,
+            upstream,
             ..
         } => handle_switch_created_output(
             &ctx,
             *created_branch,
             base_branch.as_deref(),
-            from_remote.as_deref(),
+            from_remote.as_deref().or(upstream.as_deref()),
         ),
     };
 
@@ -1127,6 +1142,9 @@ fn handle_branch_only_output(
     } else if deletion_mode.is_force() {
         let repo = worktrunk::git::Repository::current()?;
         let result = repo.run_command(&["branch", "-D", "--", branch_name]);
+        if result.is_ok() {
+            forget_deleted_branch(&repo, branch_name);
+        }
         handle_branch_deletion_result(
             result.map(|_| BranchDeletionResult {
                 outcome: BranchDeletionOutcome::ForceDeleted,
@@ -1831,7 +1849,13 @@ fn handle_removed_worktree_output(
     let repo = worktrunk::git::Repository::at(ctx.main_path)?;
 
     execute_pre_remove_hooks_if_needed(&repo, &ctx)?;
+    if let Some(branch_name) = ctx.branch_name {
+        stop_background_hooks(&repo, branch_name, ctx.silent);
+    }
     let safety = refresh_removal_safety_after_pre_remove(&repo, &ctx)?;
+    worktrunk::stats::record(StatsEvent::WorktreeRemoved {
+        branch: ctx.branch_name.map(str::to_string),
+    });
 
     // TUI (picker) path: the removal runs in a background thread while skim
     // owns the terminal, so no messages, no spinner, no `cd` directive (the
@@ -1842,6 +1866,9 @@ fn handle_removed_worktree_output(
     }
 
     prepare_remove_directory_change(ctx.main_path, ctx.worktree_path, ctx.changed_directory)?;
+    if ctx.changed_directory && repo.config().switch.terminal_title() {
+        super::terminal_title::clear();
+    }
 
     // Handle detached HEAD case (no branch known)
     let Some(branch_name) = ctx.branch_name else {
@@ -1855,6 +1882,27 @@ fn handle_removed_worktree_output(
     }
 }
 
+/// Stop processes `branch_name`'s background hooks left running (a `post-start`
+/// dev server, say) before its worktree goes away. Runs after `pre-remove`, so
+/// those hooks can still talk to the servers they're about to lose.
+fn stop_background_hooks(repo: &Repository, branch_name: &str, silent: bool) {
+    let stopped = crate::commands::hook_status::stop_branch_processes(repo, branch_name);
+    if stopped.is_empty() || silent {
+        return;
+    }
+    let pipelines = stopped
+        .iter()
+        .map(|record| cformat!("<bold>{}</> ({})", record.hook_type, record.source))
+        .collect::<Vec<_>>()
+        .join(", ");
+    eprintln!(
+        "{}",
+        info_message(cformat!(
+            "Stopped processes still running from {pipelines} hooks"
+        ))
+    );
+}
+
 /// Remove a `RemovedWorktree` worktree with no terminal output — the TUI
 /// (`wt switch` picker) path of [`handle_remove_output`].
 ///
@@ -2140,6 +2188,17 @@ mod tests {
         let msg = format_switch_message("feature", &path, true, true, Some("main"), None);
         assert_snapshot!(msg, @"Created branch [1mfeature[22m from [1mmain[22m and worktree @ [1m/tmp/test[22m");
 
+        // Created branch with an upstream (--track)
+        let msg = format_switch_message(
+            "feature",
+            &path,
+            true,
+            true,
+            Some("main"),
+            Some("origin/feature"),
+        );
+        assert_snapshot!(msg, @"Created branch [1mfeature[22m from [1mmain[22m (tracking [1morigin/feature[22m) and worktree @ [1m/tmp/test[22m");
+
         // Created worktree from remote (DWIM) - also creates local tracking branch
         let msg =
             format_switch_message("feature", &path, true, false, None, Some("origin/feature"));
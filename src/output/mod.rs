@@ -36,8 +36,10 @@ pub(crate) mod commit_generation;
 pub(crate) mod concurrent;
 mod global;
 pub(crate) mod handlers;
+pub(crate) mod notice;
 pub(crate) mod prompt;
 pub(crate) mod shell_integration;
+pub(crate) mod terminal_title;
 
 // Re-export the public API
 pub(crate) use global::{
@@ -0,0 +1,181 @@
+//! Notices — hints and warnings that shouldn't repeat on every invocation.
+//!
+//! Each [`Notice`] has a name and a [`Cadence`]. Displays are recorded per
+//! repository in git config as `worktrunk.hints.<name> = <count>[@<stamp>]`,
+//! where the stamp is the Unix time (daily notices) or worktrunk version
+//! (per-version notices) of the last display. `wt hints` lists them and
+//! `wt hints reset` clears the record so they show again.
+//!
+//! Outside a repository there is nowhere to record a display, so due-ness
+//! can't be tracked and every notice shows.
+
+use std::fmt::Display;
+
+use worktrunk::git::Repository;
+use worktrunk::styling::eprintln;
+use worktrunk::utils::epoch_now;
+
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+/// How often a notice may show in one repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Cadence {
+    /// Once, until `wt hints reset`.
+    Once,
+    /// At most once every 24 hours.
+    Daily,
+    /// Once per worktrunk release.
+    PerVersion,
+    /// Every time; the count only escalates the message.
+    Always,
+}
+
+impl Cadence {
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Cadence::Once => "once",
+            Cadence::Daily => "daily",
+            Cadence::PerVersion => "per-version",
+            Cadence::Always => "always",
+        }
+    }
+}
+
+/// A named hint or warning with a display cadence.
+pub(crate) struct Notice {
+    pub name: &'static str,
+    pub cadence: Cadence,
+    /// What triggers it, for `wt hints`.
+    pub trigger: &'static str,
+}
+
+/// Customize worktree locations, after the first `wt switch --create`.
+pub(crate) const WORKTREE_PATH: Notice = Notice {
+    name: "worktree-path",
+    cadence: Cadence::Once,
+    trigger: "First wt switch --create without a custom worktree-path",
+};
+
+/// Records declining the bare-repo worktree-path prompt; never printed.
+pub(crate) const SKIP_BARE_REPO_PROMPT: Notice = Notice {
+    name: "skip-bare-repo-prompt",
+    cadence: Cadence::Once,
+    trigger: "Declining the bare-repo worktree-path prompt",
+};
+
+/// Install shell integration, whenever a switch can't change directory.
+pub(crate) const SHELL_INTEGRATION: Notice = Notice {
+    name: "shell-integration",
+    cadence: Cadence::Always,
+    trigger: "Switching without shell integration (escalates after 5 shows)",
+};
+
+/// `--no-verify` was renamed to `--no-hooks`.
+pub(crate) const NO_VERIFY_DEPRECATED: Notice = Notice {
+    name: "no-verify-deprecated",
+    cadence: Cadence::Daily,
+    trigger: "Passing --no-verify",
+};
+
+/// `wt config state {ci-status,hints,previous-branch}` moved under `cache`.
+pub(crate) const STATE_SUBCOMMAND_DEPRECATED: Notice = Notice {
+    name: "state-subcommand-deprecated",
+    cadence: Cadence::PerVersion,
+    trigger: "Running a wt config state subcommand that moved under cache",
+};
+
+/// Every notice, in the order `wt hints` lists them.
+pub(crate) const NOTICES: &[&Notice] = &[
+    &WORKTREE_PATH,
+    &SKIP_BARE_REPO_PROMPT,
+    &SHELL_INTEGRATION,
+    &NO_VERIFY_DEPRECATED,
+    &STATE_SUBCOMMAND_DEPRECATED,
+];
+
+impl Notice {
+    /// Whether the notice should show now in `repo`.
+    pub(crate) fn is_due(&self, repo: &Repository) -> bool {
+        if !repo.has_shown_hint(self.name) {
+            return true;
+        }
+        match self.cadence {
+            Cadence::Once => false,
+            Cadence::Always => true,
+            Cadence::Daily => repo
+                .hint_stamp(self.name)
+                .and_then(|stamp| stamp.parse::<u64>().ok())
+                .is_none_or(|shown| epoch_now().saturating_sub(shown) >= DAY_SECS),
+            Cadence::PerVersion => {
+                repo.hint_stamp(self.name).as_deref() != Some(env!("CARGO_PKG_VERSION"))
+            }
+        }
+    }
+
+    /// Record a display. Best-effort: a failed write only means the notice
+    /// may show again sooner.
+    pub(crate) fn record(&self, repo: &Repository) {
+        let _ = match self.cadence {
+            Cadence::Once | Cadence::Always => repo.mark_hint_shown(self.name),
+            Cadence::Daily => repo.mark_hint_shown_at(self.name, &epoch_now().to_string()),
+            Cadence::PerVersion => repo.mark_hint_shown_at(self.name, env!("CARGO_PKG_VERSION")),
+        };
+    }
+
+    /// Print `message` to stderr if the notice is due, and record it.
+    /// Returns whether it printed.
+    pub(crate) fn emit(&self, repo: Option<&Repository>, message: impl Display) -> bool {
+        if repo.is_some_and(|repo| !self.is_due(repo)) {
+            return false;
+        }
+        eprintln!("{message}");
+        if let Some(repo) = repo {
+            self.record(repo);
+        }
+        true
+    }
+}
+
+/// Look up a notice by name.
+pub(crate) fn find(name: &str) -> Option<&'static Notice> {
+    NOTICES.iter().copied().find(|notice| notice.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use worktrunk::testing::TestRepo;
+
+    #[test]
+    fn test_cadences() {
+        let test = TestRepo::with_initial_commit();
+        let repo = Repository::at(test.root_path()).unwrap();
+
+        for notice in NOTICES {
+            assert!(notice.is_due(&repo), "{} starts due", notice.name);
+            notice.record(&repo);
+        }
+        assert!(!WORKTREE_PATH.is_due(&repo));
+        assert!(SHELL_INTEGRATION.is_due(&repo));
+        assert!(!NO_VERIFY_DEPRECATED.is_due(&repo));
+        assert!(!STATE_SUBCOMMAND_DEPRECATED.is_due(&repo));
+
+        // A day-old daily record, or one from another release, is due again.
+        let old = epoch_now() - DAY_SECS;
+        repo.mark_hint_shown_at(NO_VERIFY_DEPRECATED.name, &old.to_string())
+            .unwrap();
+        assert!(NO_VERIFY_DEPRECATED.is_due(&repo));
+        repo.mark_hint_shown_at(STATE_SUBCOMMAND_DEPRECATED.name, "0.0.1")
+            .unwrap();
+        assert!(STATE_SUBCOMMAND_DEPRECATED.is_due(&repo));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(
+            find("worktree-path").map(|n| n.cadence),
+            Some(Cadence::Once)
+        );
+        assert!(find("nonexistent").is_none());
+    }
+}
@@ -69,3 +69,26 @@ pub fn prompt_yes_no_preview(
         }
     }
 }
+
+/// Prompt for a numbered choice among `count` options listed by the caller.
+///
+/// # Returns
+/// * `Ok(Some(i))` (zero-based) if user enters a number from 1 to `count`
+/// * `Ok(None)` if user enters anything else (including empty)
+pub fn prompt_choice(prompt_text: &str, count: usize) -> io::Result<Option<usize>> {
+    eprint!(
+        "{}",
+        cformat!("{PROMPT_SYMBOL} {prompt_text} <bold>[1-{count}, Enter to skip]</> ")
+    );
+    io::stderr().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(input
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|n| (1..=count).contains(n))
+        .map(|n| n - 1))
+}
@@ -74,11 +74,6 @@ use crate::commands::configure_shell::{
     scan_shell_configs,
 };
 
-/// Git config key tracking how many times the shell-integration install hint
-/// has been shown to this user. Stored as an integer count via the generic
-/// hints API; reaching 5 escalates the hint with a `wt config show` pointer.
-pub(crate) const SHELL_INTEGRATION_HINT: &str = "shell-integration";
-
 /// Shell integration install hint message.
 ///
 /// `count` is how many times the hint has previously been shown (i.e., the
@@ -104,9 +99,9 @@ pub(crate) fn shell_integration_hint(count: u32) -> String {
 /// `git config` fails, only the escalation is delayed, never the hint
 /// itself, so we deliberately ignore the error.
 pub(crate) fn print_shell_integration_hint(repo: &Repository) {
-    let count = repo.hint_count(SHELL_INTEGRATION_HINT);
-    eprintln!("{}", hint_message(shell_integration_hint(count)));
-    let _ = repo.mark_hint_shown(SHELL_INTEGRATION_HINT);
+    let notice = &super::notice::SHELL_INTEGRATION;
+    let count = repo.hint_count(notice.name);
+    notice.emit(Some(repo), hint_message(shell_integration_hint(count)));
 }
 
 /// Hint when shell integration is installed but shell needs restart.
@@ -403,6 +398,11 @@ pub fn prompt_shell_integration(
         return Ok(false);
     }
 
+    // Likewise inside `$(wt ...)`: the wrapper is active, it just can't cd
+    if crate::is_command_substitution() {
+        return Ok(false);
+    }
+
     let is_tty = std::io::stdin().is_terminal() && std::io::stderr().is_terminal();
 
     // Check the current shell (via $SHELL or PSModulePath fallback)
@@ -0,0 +1,69 @@
+//! `[switch] terminal-title` — name the terminal tab after the branch.
+//!
+//! After a switch that changes directory, the tab and window title become the
+//! branch (OSC 0, understood by iTerm2, WezTerm, Kitty, and most others), and
+//! iTerm2 also shows it as a badge. Removing the worktree the shell is in
+//! clears both, so the terminal falls back to its own title.
+//!
+//! Sequences go to stderr, and only when it's a terminal — piped output never
+//! carries them.
+
+use std::io::{IsTerminal, Write};
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+
+/// Set the title (and iTerm2 badge) to `title`.
+pub(crate) fn set(title: &str) {
+    emit(&sequences(title, is_iterm()));
+}
+
+/// Reset the title and badge.
+pub(crate) fn clear() {
+    emit(&sequences("", is_iterm()));
+}
+
+fn emit(sequences: &str) {
+    let mut stderr = std::io::stderr();
+    if stderr.is_terminal() {
+        let _ = stderr.write_all(sequences.as_bytes());
+        let _ = stderr.flush();
+    }
+}
+
+/// iTerm2 sets `LC_TERMINAL` too, which survives ssh.
+fn is_iterm() -> bool {
+    std::env::var("TERM_PROGRAM").is_ok_and(|v| v == "iTerm.app")
+        || std::env::var("LC_TERMINAL").is_ok_and(|v| v == "iTerm2")
+}
+
+/// The escape sequences that set `title`; an empty `title` resets it.
+fn sequences(title: &str, badge: bool) -> String {
+    // A control character would end the sequence early
+    let title: String = title.chars().filter(|c| !c.is_control()).collect();
+    let mut out = format!("\x1b]0;{title}\x07");
+    if badge {
+        let encoded = STANDARD.encode(&title);
+        out.push_str(&format!("\x1b]1337;SetBadgeFormat={encoded}\x07"));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequences() {
+        assert_eq!(sequences("feature/auth", false), "\x1b]0;feature/auth\x07");
+        assert_eq!(
+            sequences("feature", true),
+            "\x1b]0;feature\x07\x1b]1337;SetBadgeFormat=ZmVhdHVyZQ==\x07"
+        );
+        assert_eq!(
+            sequences("", true),
+            "\x1b]0;\x07\x1b]1337;SetBadgeFormat=\x07"
+        );
+        assert_eq!(sequences("a\x07b\x1bc", false), "\x1b]0;abc\x07");
+    }
+}
@@ -54,9 +54,11 @@ mod imp {
     };
 
     use super::{format_bytes, format_count};
-    use crate::styling::HINT_SYMBOL;
+    use crate::styling::{HINT_SYMBOL, ascii_active};
 
     const SPINNER_FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+    /// `output.ascii` spins a bar instead.
+    const ASCII_SPINNER_FRAMES: &[char] = &['|', '/', '-', '\\'];
     const TICK_INTERVAL: Duration = Duration::from_millis(100);
     /// Delay before the first frame renders, so sub-second operations stay silent.
     const STARTUP_DELAY: Duration = Duration::from_millis(300);
@@ -231,11 +233,15 @@ mod imp {
         verb: &str,
         elapsed: Duration,
     ) -> std::io::Result<()> {
-        let frame_idx =
-            (elapsed.as_millis() / TICK_INTERVAL.as_millis()) as usize % SPINNER_FRAMES.len();
+        let frames = if ascii_active() {
+            ASCII_SPINNER_FRAMES
+        } else {
+            SPINNER_FRAMES
+        };
+        let frame_idx = (elapsed.as_millis() / TICK_INTERVAL.as_millis()) as usize % frames.len();
         let files = shared.files.load(Ordering::Relaxed);
         let bytes = shared.bytes.load(Ordering::Relaxed);
-        let line = format_line(verb, files, bytes, SPINNER_FRAMES[frame_idx]);
+        let line = format_line(verb, files, bytes, frames[frame_idx]);
         render_line(w, &line)
     }
 
@@ -695,6 +695,93 @@ fn run_with_timeout_impl(
     })
 }
 
+/// Callback receiving each stdout line of a [`Cmd::run`] as it arrives.
+type StdoutObserver = Box<dyn FnMut(&str) + Send>;
+
+/// Capture-mode execution with piped stdin and/or a stdout line observer.
+///
+/// Each pipe gets its own thread (writing stdin while the child fills stdout
+/// can't deadlock), and the wait honours `timeout`. The threads are detached
+/// rather than scoped: a shell command's grandchild can inherit the pipes and
+/// outlive the killed child, and joining would then block past the timeout.
+fn run_piped_impl(
+    cmd: &mut Command,
+    stdin: Option<Vec<u8>>,
+    timeout: Option<std::time::Duration>,
+    mut on_stdout_line: Option<StdoutObserver>,
+) -> std::io::Result<std::process::Output> {
+    let mut child = cmd
+        .stdin(if stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let child_stdin = child.stdin.take();
+    let child_stdout = child.stdout.take();
+    let child_stderr = child.stderr.take();
+
+    // Ignore BrokenPipe — some commands exit without reading all of stdin.
+    // Dropping the handle at the end of the thread closes the pipe, so a child
+    // that reads stdin to EOF (e.g. `git … --stdin`) doesn't block forever.
+    let stdin_thread = std::thread::spawn(move || match (child_stdin, stdin) {
+        (Some(mut pipe), Some(data)) => match pipe.write_all(&data) {
+            Err(e) if e.kind() != ErrorKind::BrokenPipe => Err(e),
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    });
+    let stdout_thread = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let Some(pipe) = child_stdout else {
+            return Ok(buf);
+        };
+        let Some(observer) = on_stdout_line.as_mut() else {
+            BufReader::new(pipe).read_to_end(&mut buf)?;
+            return Ok(buf);
+        };
+        let mut reader = BufReader::new(pipe);
+        let mut line = Vec::new();
+        while reader.read_until(b'\n', &mut line)? > 0 {
+            observer(String::from_utf8_lossy(&line).trim_end_matches(['\r', '\n']));
+            buf.append(&mut line);
+        }
+        Ok::<_, std::io::Error>(buf)
+    });
+    let stderr_thread = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = child_stderr {
+            pipe.read_to_end(&mut buf)?;
+        }
+        Ok::<_, std::io::Error>(buf)
+    });
+
+    let status = match timeout {
+        Some(timeout) => match child.wait_timeout(timeout)? {
+            Some(status) => status,
+            None => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(std::io::Error::new(
+                    ErrorKind::TimedOut,
+                    "command timed out",
+                ));
+            }
+        },
+        None => child.wait()?,
+    };
+
+    stdin_thread.join().unwrap()?;
+    Ok(std::process::Output {
+        status,
+        stdout: stdout_thread.join().unwrap()?,
+        stderr: stderr_thread.join().unwrap()?,
+    })
+}
+
 // ============================================================================
 // Builder-style command execution
 // ============================================================================
@@ -742,6 +829,8 @@ pub struct Cmd {
     context: Option<String>,
     stdin_data: Option<Vec<u8>>,
     timeout: Option<std::time::Duration>,
+    /// Called with each stdout line as it arrives (for `.run()`)
+    on_stdout_line: Option<StdoutObserver>,
     envs: Vec<(OsString, OsString)>,
     env_removes: Vec<OsString>,
     /// If true, wrap command through ShellConfig (for stream())
@@ -815,7 +904,7 @@ fn record_captured(
     result: &std::io::Result<std::process::Output>,
 ) {
     match result {
-        Ok(output) => trace.complete(output.status.success()),
+        Ok(output) => trace.complete_status(output.status),
         Err(e) => trace.fail(e),
     }
     // stdin is logged either way; stdout/stderr only when the command produced
@@ -906,6 +995,7 @@ impl Cmd {
             context: None,
             stdin_data: None,
             timeout: None,
+            on_stdout_line: None,
             envs: Vec::new(),
             env_removes: Vec::new(),
             shell_wrap,
@@ -1065,6 +1155,13 @@ impl Cmd {
         self
     }
 
+    /// Observe stdout line by line while `.run()` still captures it — for
+    /// showing a slow command's output as it's produced.
+    pub fn on_stdout_line(mut self, observer: impl FnMut(&str) + Send + 'static) -> Self {
+        self.on_stdout_line = Some(Box::new(observer));
+        self
+    }
+
     /// Set an environment variable.
     ///
     /// Accepts the same types as [`Command::env`]: string literals, `String`,
@@ -1204,7 +1301,7 @@ impl Cmd {
     ///
     /// Panics if called on a shell-wrapped command (created via `Cmd::shell()`).
     /// Shell commands must use `.stream()` because they need TTY preservation.
-    pub fn run(self) -> std::io::Result<std::process::Output> {
+    pub fn run(mut self) -> std::io::Result<std::process::Output> {
         assert!(
             !self.shell_wrap,
             "Cmd::shell() commands must use .stream(), not .run()"
@@ -1224,7 +1321,8 @@ impl Cmd {
         let _guard = semaphore().acquire();
 
         let mut trace = CommandTrace::new(self.context.as_deref(), &cmd_str)
-            .reads_stdin(self.stdin_data.is_some());
+            .reads_stdin(self.stdin_data.is_some())
+            .cwd(self.current_dir.as_deref());
 
         if let Err(e) = self.check_spawn_preconditions() {
             trace.fail(&e);
@@ -1242,30 +1340,14 @@ impl Cmd {
         // `Result<Output>` so spawn/write failures resolve the trace through
         // `record_captured` rather than `?`-ing past it (which would leave the
         // command unattributed and trip CommandTrace's drop assertion).
-        let result = if let Some(stdin_data) = self.stdin_data.as_deref() {
-            // Stdin piping requires spawn/write/wait
-            // Note: stdin path doesn't support timeout (would need async I/O)
-            cmd.stdin(Stdio::piped())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped());
-
-            match cmd.spawn() {
-                Ok(mut child) => {
-                    // Write stdin data in an inner scope so the handle DROPS
-                    // (closing the pipe) before `wait_with_output` — otherwise a
-                    // child that reads stdin to EOF (e.g. `git … --stdin`) blocks
-                    // forever. (ignore BrokenPipe - some commands exit early)
-                    let write_result = {
-                        let mut stdin = child.stdin.take().expect("stdin was configured as piped");
-                        stdin.write_all(stdin_data)
-                    };
-                    match write_result {
-                        Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => Err(e),
-                        _ => child.wait_with_output(),
-                    }
-                }
-                Err(e) => Err(e),
-            }
+        let result = if self.stdin_data.is_some() || self.on_stdout_line.is_some() {
+            // Stdin piping and line observation need a thread per pipe
+            run_piped_impl(
+                &mut cmd,
+                self.stdin_data.clone(),
+                effective_timeout,
+                self.on_stdout_line.take(),
+            )
         } else if let Some(timeout_duration) = effective_timeout {
             // Timeout handling uses the existing impl
             run_with_timeout_impl(&mut cmd, timeout_duration)
@@ -1380,7 +1462,8 @@ impl Cmd {
         // brackets the real spawn → wait span. The source reads stdin only when
         // fed a buffer; the sink always reads it (the source's piped stdout).
         let mut first_trace = CommandTrace::new(self.context.as_deref(), &first_cmd_str)
-            .reads_stdin(source_stdin.is_some());
+            .reads_stdin(source_stdin.is_some())
+            .cwd(self.current_dir.as_deref());
         let mut first_child = match first.spawn() {
             Ok(child) => child,
             Err(e) => {
@@ -1412,8 +1495,9 @@ impl Cmd {
         // Spawn `next` before waiting on either child so `self`'s stdout keeps
         // flowing through the pipe (otherwise a full pipe buffer would wedge
         // `self`). If the spawn itself fails, clean up `self` before returning.
-        let mut second_trace =
-            CommandTrace::new(next.context.as_deref(), &second_cmd_str).reads_stdin(true);
+        let mut second_trace = CommandTrace::new(next.context.as_deref(), &second_cmd_str)
+            .reads_stdin(true)
+            .cwd(next.current_dir.as_deref());
         let second_child = match second.spawn() {
             Ok(child) => child,
             Err(e) => {
@@ -1541,6 +1625,15 @@ impl Cmd {
             cmd.env(DIRECTIVE_FILE_ENV_VAR, path);
         }
 
+        // The child writes to our terminal, so an explicit `--color` applies
+        // to it as well.
+        for (key, val) in crate::styling::child_color_env() {
+            match val {
+                Some(val) => cmd.env(key, val),
+                None => cmd.env_remove(key),
+            };
+        }
+
         if let Err(e) = self.check_spawn_preconditions() {
             // Nothing spawned yet — emit a one-shot failed record (the trace
             // guard is constructed just before spawn, below).
@@ -1602,7 +1695,8 @@ impl Cmd {
         // signal-handler install — so a pre-spawn early return can't drop the
         // guard unresolved, and the duration brackets the child.
         let mut trace = CommandTrace::new(self.context.as_deref(), &cmd_str)
-            .reads_stdin(self.stdin_data.is_some());
+            .reads_stdin(self.stdin_data.is_some())
+            .cwd(self.current_dir.as_deref());
         let mut child = match cmd.spawn() {
             Ok(child) => child,
             Err(e) => {
@@ -1760,7 +1854,8 @@ impl Cmd {
         let cmd_str = self.command_string();
         self.log_delayed_stream_start(&cmd_str, delay_ms);
 
-        let mut trace = CommandTrace::new(self.context.as_deref(), &cmd_str);
+        let mut trace =
+            CommandTrace::new(self.context.as_deref(), &cmd_str).cwd(self.current_dir.as_deref());
 
         let mut cmd = self.direct_command();
         self.apply_common_settings(&mut cmd);
@@ -1800,7 +1895,7 @@ impl Cmd {
                     Ok(Some(status)) => {
                         let _ = stdout_handle.join();
                         let _ = stderr_handle.join();
-                        trace.complete(status.success());
+                        trace.complete_status(status);
                         return stream_exit_result(status, &buffer, &cmd_str);
                     }
                     // Threshold exceeded — fall through to streaming.
@@ -1836,7 +1931,7 @@ impl Cmd {
                 return Err(e).context("Failed to wait for command");
             }
         };
-        trace.complete(status.success());
+        trace.complete_status(status);
         stream_exit_result(status, &buffer, &cmd_str)
     }
 }
@@ -2210,6 +2305,34 @@ mod tests {
         assert!(String::from_utf8_lossy(&output.stdout).contains("hello from stdin"));
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn test_cmd_timeout_with_stdin_and_grandchild() {
+        // The shell's `sleep` inherits the pipes and outlives the killed shell;
+        // the timeout must still return promptly.
+        let started = Instant::now();
+        let result = Cmd::new("sh")
+            .args(["-c", "cat >/dev/null; sleep 10; true"])
+            .stdin_bytes("prompt")
+            .timeout(Duration::from_millis(100))
+            .run();
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::TimedOut);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_cmd_on_stdout_line_observes_and_captures() {
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&lines);
+        let output = Cmd::new("cat")
+            .stdin_bytes("first\nsecond\n")
+            .on_stdout_line(move |line| seen.lock().unwrap().push(line.to_string()))
+            .run()
+            .unwrap();
+        assert_eq!(*lines.lock().unwrap(), ["first", "second"]);
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "first\nsecond\n");
+    }
+
     #[test]
     fn test_thread_local_timeout_setting() {
         // Initially no timeout (or whatever was set by previous test)
@@ -0,0 +1,237 @@
+//! Namespaced key-value state with optional expiry, under
+//! `<wt-state-dir>/state/<namespace>/<key>.json`.
+//!
+//! Backs `wt config state set/list --ns`. Each subsystem (or script) picks its
+//! own namespace, so a PR cache, a last-visited record, and user data can use
+//! the same key names without colliding, and `clear --ns` drops one of them
+//! without touching the rest.
+//!
+//! # Expiry
+//!
+//! An entry written with a TTL records `expires_at`. Expiration is lazy: reads
+//! treat an expired entry as missing and delete its file, so stale data
+//! cleans itself up on next access with no background sweeper.
+//!
+//! # Error policy
+//!
+//! Unlike the caches in [`crate::cache`], this state is authoritative: writes
+//! and clears propagate I/O errors. Reads still treat a corrupt entry as
+//! missing, since there is nothing better to return.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cache;
+use crate::git::Repository;
+use crate::utils::epoch_now;
+
+/// One stored value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateEntry {
+    pub value: String,
+    /// Unix seconds when the entry was written.
+    pub set_at: u64,
+    /// Unix seconds after which the entry reads as missing. `None` never expires.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+}
+
+impl StateEntry {
+    fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|at| at <= now)
+    }
+}
+
+fn state_dir(repo: &Repository) -> PathBuf {
+    repo.wt_state_dir().join("state")
+}
+
+fn entry_path(repo: &Repository, namespace: &str, key: &str) -> PathBuf {
+    state_dir(repo).join(namespace).join(format!("{key}.json"))
+}
+
+/// Check that `name` is usable as a namespace or key: letters, digits, `-`,
+/// `_`, and `.`, not starting with `.`. Both become path components.
+pub fn validate_name(kind: &str, name: &str) -> anyhow::Result<()> {
+    if name.is_empty() {
+        anyhow::bail!("{kind} cannot be empty");
+    }
+    if name.starts_with('.')
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        anyhow::bail!(
+            "Invalid {} {name:?}: use only letters, digits, '-', '_', and '.', not starting with '.'",
+            kind.to_lowercase()
+        );
+    }
+    Ok(())
+}
+
+/// Store `value` under `namespace`/`key`, replacing any previous entry. With
+/// `ttl`, the entry expires that long from now.
+pub fn set(
+    repo: &Repository,
+    namespace: &str,
+    key: &str,
+    value: &str,
+    ttl: Option<Duration>,
+) -> anyhow::Result<()> {
+    validate_name("Namespace", namespace)?;
+    validate_name("Key", key)?;
+    let now = epoch_now();
+    let entry = StateEntry {
+        value: value.to_string(),
+        set_at: now,
+        expires_at: ttl.map(|ttl| now.saturating_add(ttl.as_secs())),
+    };
+    let path = entry_path(repo, namespace, key);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            anyhow::Error::new(e).context(format!("failed to create {}", parent.display()))
+        })?;
+    }
+    fs::write(&path, serde_json::to_string(&entry)?)
+        .map_err(|e| anyhow::Error::new(e).context(format!("failed to write {}", path.display())))
+}
+
+/// The live entry at `namespace`/`key`, if any. An expired entry is deleted.
+pub fn get(repo: &Repository, namespace: &str, key: &str) -> Option<StateEntry> {
+    let path = entry_path(repo, namespace, key);
+    let entry: StateEntry = cache::read_json(&path)?;
+    if entry.is_expired(epoch_now()) {
+        let _ = fs::remove_file(&path);
+        return None;
+    }
+    Some(entry)
+}
+
+/// Live entries in `namespace`, sorted by key. Expired entries are deleted.
+pub fn list(repo: &Repository, namespace: &str) -> Vec<(String, StateEntry)> {
+    let Ok(dir) = fs::read_dir(state_dir(repo).join(namespace)) else {
+        return Vec::new();
+    };
+    let now = epoch_now();
+    let mut entries: Vec<(String, StateEntry)> = dir
+        .flatten()
+        .filter_map(|e| {
+            let path = e.path();
+            let key = path
+                .file_name()?
+                .to_str()?
+                .strip_suffix(".json")?
+                .to_string();
+            let entry: StateEntry = cache::read_json(&path)?;
+            if entry.is_expired(now) {
+                let _ = fs::remove_file(&path);
+                return None;
+            }
+            Some((key, entry))
+        })
+        .collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries
+}
+
+/// Namespaces that hold at least one file, sorted. Expired entries may still
+/// count until [`list`] sweeps them.
+pub fn namespaces(repo: &Repository) -> Vec<String> {
+    let Ok(dir) = fs::read_dir(state_dir(repo)) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = dir
+        .flatten()
+        .filter(|e| e.path().is_dir() && cache::count_json_files(&e.path()) > 0)
+        .filter_map(|e| e.file_name().into_string().ok())
+        .collect();
+    names.sort();
+    names
+}
+
+/// Remove `namespace`/`key`, or the whole namespace when `key` is `None`.
+/// Returns how many entries were removed, expired ones included.
+pub fn clear(repo: &Repository, namespace: &str, key: Option<&str>) -> anyhow::Result<usize> {
+    validate_name("Namespace", namespace)?;
+    match key {
+        Some(key) => {
+            validate_name("Key", key)?;
+            Ok(usize::from(cache::clear_one(&entry_path(
+                repo, namespace, key,
+            ))?))
+        }
+        None => {
+            let dir = state_dir(repo).join(namespace);
+            let cleared = cache::clear_json_files(&dir)?;
+            let _ = fs::remove_dir(&dir);
+            Ok(cleared)
+        }
+    }
+}
+
+/// Remove every namespace. Returns how many entries were removed.
+pub fn clear_all(repo: &Repository) -> anyhow::Result<usize> {
+    let mut cleared = 0;
+    for namespace in namespaces(repo) {
+        cleared += clear(repo, &namespace, None)?;
+    }
+    Ok(cleared)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestRepo;
+
+    #[test]
+    fn test_namespaces_are_independent() {
+        let test = TestRepo::new();
+        let repo = &test.repo;
+        set(repo, "prs", "main", "42", None).unwrap();
+        set(repo, "visits", "main", "yesterday", None).unwrap();
+        // A zero TTL expires immediately.
+        set(repo, "prs", "stale", "7", Some(Duration::ZERO)).unwrap();
+
+        assert_eq!(get(repo, "prs", "main").unwrap().value, "42");
+        assert_eq!(get(repo, "visits", "main").unwrap().value, "yesterday");
+        assert_eq!(get(repo, "prs", "stale"), None);
+        let keys: Vec<String> = list(repo, "prs").into_iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, ["main"]);
+        assert_eq!(namespaces(repo), ["prs", "visits"]);
+
+        assert_eq!(clear(repo, "prs", None).unwrap(), 1);
+        assert_eq!(get(repo, "prs", "main"), None);
+        assert_eq!(namespaces(repo), ["visits"]);
+        assert_eq!(clear_all(repo).unwrap(), 1);
+        assert!(namespaces(repo).is_empty());
+    }
+
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("Key", "pr-123").is_ok());
+        assert!(validate_name("Key", "v1.2_rc").is_ok());
+        assert!(validate_name("Key", "").is_err());
+        assert!(validate_name("Key", ".hidden").is_err());
+        assert!(validate_name("Key", "a/b").is_err());
+        assert!(validate_name("Namespace", "..").is_err());
+    }
+
+    #[test]
+    fn test_expiry() {
+        let entry = StateEntry {
+            value: "x".into(),
+            set_at: 100,
+            expires_at: Some(160),
+        };
+        assert!(!entry.is_expired(159));
+        assert!(entry.is_expired(160));
+        let permanent = StateEntry {
+            expires_at: None,
+            ..entry
+        };
+        assert!(!permanent.is_expired(u64::MAX));
+    }
+}
@@ -0,0 +1,171 @@
+//! Opt-in local usage stats, read back by `wt stats`.
+//!
+//! With `[stats] enabled = true` in user config, wt appends one JSON line per
+//! event to `.git/wt/stats/events.jsonl`: each command run (name, duration,
+//! outcome) and each worktree created, removed, or merged. Nothing is sent
+//! anywhere — the file stays in the repository's state directory.
+//!
+//! # Growth control
+//!
+//! Same scheme as the command log: past 1MB the file is renamed to
+//! `events.jsonl.old` and a fresh one started. Reads cover both files.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::git::Repository;
+
+/// Maximum events file size before rotation (1MB).
+const MAX_EVENTS_SIZE: u64 = 1_048_576;
+
+/// Events file for this process, set by [`init`] when stats are enabled.
+static EVENTS_PATH: OnceLock<PathBuf> = OnceLock::new();
+
+/// Something `wt stats` counts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "kebab-case")]
+pub enum StatsEvent {
+    /// A `wt` command ran to completion.
+    Command {
+        /// Subcommand path, e.g. `switch` or `step commit`
+        command: String,
+        duration_ms: u64,
+        success: bool,
+    },
+    WorktreeCreated {
+        branch: String,
+    },
+    WorktreeRemoved {
+        /// `None` for a detached worktree
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        branch: Option<String>,
+    },
+    /// `wt merge` merged `branch` into `target`.
+    Merged {
+        branch: String,
+        target: String,
+    },
+}
+
+/// A recorded event with its timestamp (seconds since the epoch).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatsRecord {
+    pub ts: u64,
+    #[serde(flatten)]
+    pub event: StatsEvent,
+}
+
+/// Path of the events file for `repo`.
+pub fn events_path(repo: &Repository) -> PathBuf {
+    repo.wt_state_dir().join("stats").join("events.jsonl")
+}
+
+/// Start recording for this process if `[stats] enabled` is set.
+///
+/// Call once at startup; until then (and when disabled) [`record`] is a no-op.
+pub fn init(repo: &Repository) {
+    if repo.user_config().stats.enabled() {
+        let _ = EVENTS_PATH.set(events_path(repo));
+    }
+}
+
+/// Record `event`, if [`init`] enabled stats. Failures are ignored.
+pub fn record(event: StatsEvent) {
+    let Some(path) = EVENTS_PATH.get() else {
+        return;
+    };
+    let entry = StatsRecord {
+        ts: crate::utils::epoch_now(),
+        event,
+    };
+    if let Err(e) = append(path, &entry) {
+        tracing::debug!("Failed to record stats event: {e}");
+    }
+}
+
+/// Record a finished command run.
+pub fn record_command(command: &str, duration: Duration, success: bool) {
+    record(StatsEvent::Command {
+        command: command.to_string(),
+        duration_ms: duration.as_millis() as u64,
+        success,
+    });
+}
+
+fn append(path: &Path, entry: &StatsRecord) -> std::io::Result<()> {
+    if let Ok(metadata) = fs::metadata(path)
+        && metadata.len() > MAX_EVENTS_SIZE
+    {
+        fs::rename(path, path.with_extension("jsonl.old"))?;
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut line = serde_json::to_string(entry).map_err(std::io::Error::other)?;
+    line.push('\n');
+    // Single write_all so each JSON line is written atomically
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(line.as_bytes())
+}
+
+/// All recorded events for `repo`, oldest first. Unreadable lines are skipped.
+pub fn read_events(repo: &Repository) -> Vec<StatsRecord> {
+    let path = events_path(repo);
+    let mut records: Vec<StatsRecord> = [path.with_extension("jsonl.old"), path]
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .flat_map(|content| {
+            content
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    // Stable, so same-second events keep their write order
+    records.sort_by_key(|record| record.ts);
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_format() {
+        let record = StatsRecord {
+            ts: 1_700_000_000,
+            event: StatsEvent::Merged {
+                branch: "feature".into(),
+                target: "main".into(),
+            },
+        };
+        let line = serde_json::to_string(&record).unwrap();
+        assert_eq!(
+            line,
+            r#"{"ts":1700000000,"event":"merged","branch":"feature","target":"main"}"#
+        );
+        assert_eq!(serde_json::from_str::<StatsRecord>(&line).unwrap(), record);
+
+        let line = r#"{"ts":1,"event":"worktree-created","branch":"feature"}"#;
+        assert_eq!(
+            serde_json::from_str::<StatsRecord>(line).unwrap().event,
+            StatsEvent::WorktreeCreated {
+                branch: "feature".into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_record_without_init() {
+        // Should silently do nothing when not initialized
+        record_command("list", Duration::from_millis(5), true);
+    }
+}
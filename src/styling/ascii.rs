@@ -0,0 +1,65 @@
+//! `output.ascii`: ASCII stand-ins for the Unicode glyphs worktrunk decorates
+//! output with.
+//!
+//! Message prefixes, `wt list` symbols and headers, and the progress spinner
+//! are Unicode. Some fonts render them poorly, and CJK locales draw many of
+//! them double-width, which pushes `wt list` columns out of line. Each such
+//! glyph is defined as a [`Glyph`] next to its stand-in, and the form is
+//! picked where it's drawn — paths, branch names, file contents, and JSON are
+//! printed as they are.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ASCII: AtomicBool = AtomicBool::new(false);
+
+/// Draw [`Glyph`]s as their ASCII stand-ins from here on.
+pub fn set_ascii(ascii: bool) {
+    ASCII.store(ascii, Ordering::Relaxed);
+}
+
+/// Whether `output.ascii` is on.
+pub fn ascii_active() -> bool {
+    ASCII.load(Ordering::Relaxed)
+}
+
+/// A decorative glyph and its ASCII stand-in, of the same display width.
+///
+/// `Display` draws whichever form `output.ascii` selects, so a `Glyph`
+/// constant can go straight into `cformat!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyph {
+    unicode: &'static str,
+    ascii: &'static str,
+}
+
+impl Glyph {
+    pub const fn new(unicode: &'static str, ascii: &'static str) -> Self {
+        Self { unicode, ascii }
+    }
+
+    /// The form `output.ascii` selects.
+    pub fn as_str(self) -> &'static str {
+        if ascii_active() {
+            self.ascii
+        } else {
+            self.unicode
+        }
+    }
+
+    /// The Unicode form, as documented for JSON output.
+    pub fn unicode(self) -> &'static str {
+        self.unicode
+    }
+
+    /// The ASCII stand-in.
+    pub fn ascii(self) -> &'static str {
+        self.ascii
+    }
+}
+
+impl fmt::Display for Glyph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,50 @@
+//! Whether output is colored.
+//!
+//! Detection is anstream's: color when the stream is a terminal, unless
+//! `NO_COLOR` or `CLICOLOR=0` is set, and always with `CLICOLOR_FORCE`.
+//! `--color=always|never` overrides that for the whole process through
+//! [`set_color_choice`], which every [`print`](super::print) /
+//! [`eprintln`](super::eprintln) call picks up. Output that bypasses anstream
+//! — the progressive `wt list` table, the statusline — asks [`colors_enabled`]
+//! or [`color_choice`] instead, and streamed child processes receive
+//! [`child_color_env`] so hook output follows the flag too.
+
+pub use anstream::ColorChoice;
+
+use super::Stream;
+
+/// Override color detection for every stream for the rest of the process.
+/// `Auto` restores detection.
+pub fn set_color_choice(choice: ColorChoice) {
+    choice.write_global();
+}
+
+/// The choice set by [`set_color_choice`] — `Auto` unless `--color` was given.
+pub fn color_choice() -> ColorChoice {
+    ColorChoice::global()
+}
+
+/// Whether styled output written to `stream` keeps its colors.
+pub fn colors_enabled(stream: Stream) -> bool {
+    let choice = match stream {
+        Stream::Stdout => anstream::AutoStream::choice(&std::io::stdout()),
+        Stream::Stderr => anstream::AutoStream::choice(&std::io::stderr()),
+    };
+    choice != ColorChoice::Never
+}
+
+/// Environment for a child whose output goes straight to our terminal, so
+/// an explicit `--color` reaches it: `(name, Some(value))` to set,
+/// `(name, None)` to remove. Empty under `Auto`, where the child runs its own
+/// detection against the inherited environment.
+pub fn child_color_env() -> &'static [(&'static str, Option<&'static str>)] {
+    match color_choice() {
+        ColorChoice::Always | ColorChoice::AlwaysAnsi => &[
+            ("CLICOLOR_FORCE", Some("1")),
+            ("NO_COLOR", None),
+            ("CLICOLOR", None),
+        ],
+        ColorChoice::Never => &[("NO_COLOR", Some("1")), ("CLICOLOR_FORCE", None)],
+        ColorChoice::Auto => &[],
+    }
+}
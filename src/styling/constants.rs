@@ -31,6 +31,8 @@
 use anstyle::{AnsiColor, Color, Style};
 use color_print::cstr;
 
+use super::Glyph;
+
 // ============================================================================
 // Programmatic Style Constants (for StyledLine, tables, computed styles)
 // ============================================================================
@@ -60,29 +62,30 @@ pub const DEFAULT_HELP_WIDTH: usize = 98;
 // Message Symbols
 // ============================================================================
 //
-// Single-width Unicode symbols for message prefixes with embedded colors.
-// Using `cstr!` to create colored `&'static str` constants that work everywhere.
+// Single-width Unicode symbols for message prefixes with embedded colors, each
+// with the ASCII stand-in `output.ascii` draws instead. Using `cstr!` to create
+// colored `&'static str`s that work everywhere.
 
 /// Progress symbol (cyan ◎)
-pub const PROGRESS_SYMBOL: &str = cstr!("<cyan>◎</>");
+pub const PROGRESS_SYMBOL: Glyph = Glyph::new(cstr!("<cyan>◎</>"), cstr!("<cyan>*</>"));
 
 /// Success symbol (green ✓)
-pub const SUCCESS_SYMBOL: &str = cstr!("<green>✓</>");
+pub const SUCCESS_SYMBOL: Glyph = Glyph::new(cstr!("<green>✓</>"), cstr!("<green>+</>"));
 
 /// Error symbol (red ✗)
-pub const ERROR_SYMBOL: &str = cstr!("<red>✗</>");
+pub const ERROR_SYMBOL: Glyph = Glyph::new(cstr!("<red>✗</>"), cstr!("<red>x</>"));
 
 /// Warning symbol (yellow ▲)
-pub const WARNING_SYMBOL: &str = cstr!("<yellow>▲</>");
+pub const WARNING_SYMBOL: Glyph = Glyph::new(cstr!("<yellow>▲</>"), cstr!("<yellow>!</>"));
 
 /// Hint symbol (dim ↳)
-pub const HINT_SYMBOL: &str = cstr!("<dim>↳</>");
+pub const HINT_SYMBOL: Glyph = Glyph::new(cstr!("<dim>↳</>"), cstr!("<dim>></>"));
 
 /// Info symbol (dim ○) - for neutral status
-pub const INFO_SYMBOL: &str = cstr!("<dim>○</>");
+pub const INFO_SYMBOL: Glyph = Glyph::new(cstr!("<dim>○</>"), cstr!("<dim>o</>"));
 
 /// Prompt symbol (cyan ❯) - for questions requiring user input
-pub const PROMPT_SYMBOL: &str = cstr!("<cyan>❯</>");
+pub const PROMPT_SYMBOL: Glyph = Glyph::new(cstr!("<cyan>❯</>"), cstr!("<cyan>?</>"));
 
 // ============================================================================
 // Formatted Message Type
@@ -1,7 +1,7 @@
 //! Consolidated styling module for terminal output.
 //!
 //! This module uses the anstyle ecosystem:
-//! - anstream for auto-detecting color support
+//! - anstream for auto-detecting color support, overridable with `--color`
 //! - anstyle for composable styling
 //! - Semantic style constants for domain-specific use
 //!
@@ -13,23 +13,35 @@
 //! This separation allows piping (`wt list | grep foo`) without status messages interfering.
 //! Use `println!` for primary output, `eprintln!` for status messages.
 
+mod ascii;
+mod color;
 mod constants;
 mod format;
 mod highlighting;
 mod hyperlink;
 mod line;
+pub mod progress;
 mod suggest;
+mod theme;
 
 use ansi_str::AnsiStr;
 use unicode_width::UnicodeWidthStr;
 
-// Re-exports from anstream (auto-detecting output)
-pub use anstream::{eprint, eprintln, print, println, stderr, stdout};
+// Re-exports from anstream (auto-detecting output). The print macros are our
+// own wrappers so `--quiet` can drop status lines, and `[theme]` recolor
+// output, in one place.
+pub use crate::__styling_eprint as eprint;
+pub use crate::__styling_eprintln as eprintln;
+pub use crate::__styling_print as print;
+pub use crate::__styling_println as println;
+pub use anstream::{stderr, stdout};
 
 // Re-exports from anstyle (for composition)
 pub use anstyle::Style as AnstyleStyle;
 
 // Re-export our public types
+pub use ascii::{Glyph, ascii_active, set_ascii};
+pub use color::{ColorChoice, child_color_env, color_choice, colors_enabled, set_color_choice};
 pub use constants::*;
 pub use format::{
     GUTTER_OVERHEAD, format_bash_with_gutter, format_bash_with_gutter_chopped, format_with_gutter,
@@ -39,12 +51,13 @@ pub use highlighting::format_toml;
 pub use hyperlink::{Stream, hyperlink_stdout, strip_osc8_hyperlinks, supports_hyperlinks};
 pub use line::{StyledLine, StyledString, truncate_visible};
 pub use suggest::{suggest_command, suggest_command_in_dir};
+pub use theme::{THEME_PRESETS, Theme, ThemeColor, ThemeSlot, set_theme, theme_active, themed};
 
 // ============================================================================
 // Verbosity
 // ============================================================================
 
-use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 
 /// Global verbosity level, set at startup.
 /// 0 = normal, 1 = verbose (-v), 2+ = debug (-vv)
@@ -66,6 +79,134 @@ pub fn verbosity() -> u8 {
     VERBOSITY.load(Ordering::Relaxed)
 }
 
+// ============================================================================
+// Quiet mode
+// ============================================================================
+
+/// Global `--quiet` flag, set at startup.
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Whether the previous stderr line was dropped by `--quiet`. Gutter and
+/// blank lines that follow inherit the decision, so a suppressed progress
+/// message doesn't leave its command block behind.
+static SUPPRESSING: AtomicBool = AtomicBool::new(false);
+
+/// Enable quiet mode: progress, success, info, and hint messages on stderr
+/// are dropped. Errors, warnings, prompts, and stdout are unaffected.
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether `--quiet` is active.
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Decide whether `--quiet` drops this stderr line.
+///
+/// Classifies by the leading symbol of the rendered message, so call sites
+/// don't need to know about quiet mode.
+fn quiet_suppresses(line: &str) -> bool {
+    let stripped = line.ansi_strip();
+    if stripped.is_empty() || stripped.starts_with(char::is_whitespace) {
+        return SUPPRESSING.load(Ordering::Relaxed);
+    }
+    let status = stripped.starts_with(['◎', '✓', '○', '↳']);
+    SUPPRESSING.store(status, Ordering::Relaxed);
+    status
+}
+
+#[doc(hidden)]
+pub fn __eprintln_filtered(line: std::fmt::Arguments<'_>) {
+    if !is_quiet() && !progress::is_enabled() && !theme_active() {
+        anstream::eprintln!("{line}");
+        return;
+    }
+    let line = line.to_string();
+    if is_quiet() && quiet_suppresses(&line) {
+        return;
+    }
+    if progress::forward_line(&line) {
+        return;
+    }
+    anstream::eprintln!("{}", themed(&line));
+}
+
+#[doc(hidden)]
+pub fn __print_themed(stream: Stream, newline: bool, args: std::fmt::Arguments<'_>) {
+    let text;
+    let args: &dyn std::fmt::Display = if theme_active() {
+        text = themed(&args.to_string()).into_owned();
+        &text
+    } else {
+        &args
+    };
+    match (stream, newline) {
+        (Stream::Stdout, false) => anstream::print!("{args}"),
+        (Stream::Stdout, true) => anstream::println!("{args}"),
+        (Stream::Stderr, false) => anstream::eprint!("{args}"),
+        (Stream::Stderr, true) => anstream::eprintln!("{args}"),
+    }
+}
+
+/// `eprintln!` over [`anstream`] that honors `--quiet` and `--progress-json`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __styling_eprintln {
+    () => {
+        $crate::styling::__eprintln_filtered(::std::format_args!(""))
+    };
+    ($($arg:tt)*) => {
+        $crate::styling::__eprintln_filtered(::std::format_args!($($arg)*))
+    };
+}
+
+/// `print!` over [`anstream`] that applies `[theme]`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __styling_print {
+    ($($arg:tt)*) => {
+        $crate::styling::__print_themed(
+            $crate::styling::Stream::Stdout,
+            false,
+            ::std::format_args!($($arg)*),
+        )
+    };
+}
+
+/// `println!` over [`anstream`] that applies `[theme]`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __styling_println {
+    () => {
+        $crate::styling::__print_themed(
+            $crate::styling::Stream::Stdout,
+            true,
+            ::std::format_args!(""),
+        )
+    };
+    ($($arg:tt)*) => {
+        $crate::styling::__print_themed(
+            $crate::styling::Stream::Stdout,
+            true,
+            ::std::format_args!($($arg)*),
+        )
+    };
+}
+
+/// `eprint!` over [`anstream`] that applies `[theme]`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __styling_eprint {
+    ($($arg:tt)*) => {
+        $crate::styling::__print_themed(
+            $crate::styling::Stream::Stderr,
+            false,
+            ::std::format_args!($($arg)*),
+        )
+    };
+}
+
 /// Get terminal width and height, or `None` if detection fails (piped context,
 /// no TTY, and no `COLUMNS`).
 ///
@@ -171,6 +312,25 @@ mod tests {
     use anstyle::Style;
     use unicode_width::UnicodeWidthStr;
 
+    #[test]
+    fn quiet_suppresses_status_lines_and_their_gutters() {
+        let suppressed = |line: String| quiet_suppresses(&line);
+        assert!(suppressed(
+            progress_message("Running pre-merge hooks").to_string()
+        ));
+        // Gutter and blank lines follow the message they belong to
+        assert!(suppressed(format_with_gutter("cargo test", None)));
+        assert!(suppressed(String::new()));
+        assert!(suppressed(success_message("Merged").to_string()));
+        assert!(suppressed(hint_message("Run wt list").to_string()));
+        assert!(suppressed(info_message("Nothing to do").to_string()));
+
+        assert!(!suppressed(warning_message("Stale cache").to_string()));
+        assert!(!suppressed(format_with_gutter("details", None)));
+        assert!(!suppressed(error_message("Merge failed").to_string()));
+        assert!(!suppressed(prompt_message("Allow?").to_string()));
+    }
+
     #[test]
     fn terminal_width_for_statusline_returns_a_width() {
         // End-to-end smoke test. Under cargo test, `COLUMNS=80` is set in
@@ -0,0 +1,228 @@
+//! `--progress-json`: newline-delimited progress events for editor integrations.
+//!
+//! Editor plugins render progress bars from structured events instead of
+//! scraping stderr. Each event is one JSON object on its own line:
+//!
+//! - `{"event":"phase","command":"merge","phase":"rebase","percent":33,"message":"Rebasing onto target"}`
+//!   — a long-running command entered a phase. `percent` is the share of the
+//!   command's phases already behind it; `null` when the phase count isn't
+//!   known up front.
+//! - `{"event":"message","level":"progress","message":"Rebasing onto main…"}` —
+//!   a status line with its symbol and styling stripped. `level` is
+//!   `progress`, `success`, `info`, `hint`, `warning`, `error`, `prompt`, or
+//!   `detail` (a gutter block or other text belonging to the previous line).
+//! - `{"event":"done","success":false,"exit_code":5}` — always the last event.
+//!
+//! # Sink
+//!
+//! Bare `--progress-json` writes events to stderr *in place of* the
+//! human-readable status lines. Hooks and other child processes still write
+//! their own output to stderr, so consumers skip lines that don't parse as
+//! JSON. `--progress-json=<FD>` writes events to an inherited file descriptor
+//! instead and leaves stderr as it would be without the flag — the cleaner
+//! channel for plugins that can pass a pipe.
+//!
+//! Call sites only announce phases ([`Phases::enter`], [`items`]); message
+//! events come from the shared `eprintln!` wrapper, so existing output needs
+//! no changes to be forwarded.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::sync::{Mutex, OnceLock};
+
+use ansi_str::AnsiStr;
+use serde_json::json;
+
+/// File descriptor meaning "stderr": bare `--progress-json`.
+pub const STDERR_FD: u32 = 2;
+
+enum Sink {
+    Stderr,
+    Fd(File),
+}
+
+static SINK: OnceLock<Mutex<Sink>> = OnceLock::new();
+
+/// Enable progress events on `fd` ([`STDERR_FD`] for stderr).
+///
+/// Errors when `fd` isn't an open, writable descriptor — or on platforms
+/// without `/dev/fd`, for any descriptor other than stderr.
+pub fn enable(fd: u32) -> io::Result<()> {
+    let sink = if fd == STDERR_FD {
+        Sink::Stderr
+    } else {
+        Sink::Fd(open_fd(fd)?)
+    };
+    let _ = SINK.set(Mutex::new(sink));
+    Ok(())
+}
+
+#[cfg(unix)]
+fn open_fd(fd: u32) -> io::Result<File> {
+    // Reopening through `/dev/fd` duplicates the inherited descriptor without
+    // `unsafe` `from_raw_fd`.
+    std::fs::OpenOptions::new()
+        .write(true)
+        .open(format!("/dev/fd/{fd}"))
+}
+
+#[cfg(not(unix))]
+fn open_fd(fd: u32) -> io::Result<File> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("file descriptor {fd} is not supported on this platform; use stderr"),
+    ))
+}
+
+/// Whether `--progress-json` is active.
+pub fn is_enabled() -> bool {
+    SINK.get().is_some()
+}
+
+fn emit(event: serde_json::Value) {
+    let Some(sink) = SINK.get() else {
+        return;
+    };
+    let mut sink = sink.lock().unwrap_or_else(|e| e.into_inner());
+    // Progress is advisory: a closed pipe must never fail the command.
+    let _ = match &mut *sink {
+        Sink::Stderr => writeln!(io::stderr().lock(), "{event}"),
+        Sink::Fd(file) => writeln!(file, "{event}"),
+    };
+}
+
+/// The ordered phases of one command, so every call site computes `percent`
+/// the same way.
+pub struct Phases {
+    command: &'static str,
+    /// `(phase, message)` pairs in execution order.
+    phases: &'static [(&'static str, &'static str)],
+}
+
+impl Phases {
+    pub const fn new(
+        command: &'static str,
+        phases: &'static [(&'static str, &'static str)],
+    ) -> Self {
+        Self { command, phases }
+    }
+
+    /// Announce that the command entered `phase`. Phases that are skipped
+    /// (e.g. `commit` with a clean worktree) simply never announce, so
+    /// `percent` can jump.
+    pub fn enter(&self, phase: &str) {
+        if !is_enabled() {
+            return;
+        }
+        let position = self.phases.iter().position(|(p, _)| *p == phase);
+        let percent = position.map(|i| i * 100 / self.phases.len());
+        let message = position.map(|i| self.phases[i].1);
+        emit(json!({
+            "event": "phase",
+            "command": self.command,
+            "phase": phase,
+            "percent": percent,
+            "message": message,
+        }));
+    }
+}
+
+/// Announce progress through one phase that works item by item: `done` of
+/// `total` items finished, `message` naming the latest.
+pub fn items(command: &str, phase: &str, done: usize, total: usize, message: &str) {
+    if !is_enabled() {
+        return;
+    }
+    emit(json!({
+        "event": "phase",
+        "command": command,
+        "phase": phase,
+        "percent": done * 100 / total.max(1),
+        "message": message,
+    }));
+}
+
+/// Emit the final event. Called once, as the process finishes.
+pub fn done(exit_code: i32) {
+    emit(json!({
+        "event": "done",
+        "success": exit_code == 0,
+        "exit_code": exit_code,
+    }));
+}
+
+/// Forward one stderr line as a message event. Returns whether the caller
+/// should drop the human-readable line (events replace it on stderr).
+pub(super) fn forward_line(line: &str) -> bool {
+    let Some(sink) = SINK.get() else {
+        return false;
+    };
+    if let Some((level, message)) = classify(&line.ansi_strip()) {
+        emit(json!({
+            "event": "message",
+            "level": level,
+            "message": message,
+        }));
+    }
+    matches!(
+        *sink.lock().unwrap_or_else(|e| e.into_inner()),
+        Sink::Stderr
+    )
+}
+
+/// Split a stripped stderr line into `(level, message)` by its leading
+/// symbol. Blank lines carry nothing and yield `None`.
+fn classify(stripped: &str) -> Option<(&'static str, String)> {
+    if stripped.trim().is_empty() {
+        return None;
+    }
+    let mut chars = stripped.chars();
+    let level = match chars.next() {
+        Some('◎') => "progress",
+        Some('✓') => "success",
+        Some('○') => "info",
+        Some('↳') => "hint",
+        Some('▲') => "warning",
+        Some('✗') => "error",
+        Some('❯') => "prompt",
+        _ => {
+            let detail: Vec<&str> = stripped.lines().map(str::trim).collect();
+            return Some(("detail", detail.join("\n").trim().to_string()));
+        }
+    };
+    Some((level, chars.as_str().trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styling::{
+        error_message, format_with_gutter, progress_message, success_message, warning_message,
+    };
+
+    #[test]
+    fn classifies_status_lines_by_symbol() {
+        let classify_styled = |line: String| classify(&line.ansi_strip());
+        assert_eq!(
+            classify_styled(progress_message("Rebasing onto main").to_string()),
+            Some(("progress", "Rebasing onto main".to_string()))
+        );
+        assert_eq!(
+            classify_styled(success_message("Merged to main").to_string()),
+            Some(("success", "Merged to main".to_string()))
+        );
+        assert_eq!(
+            classify_styled(warning_message("Stale cache").to_string()),
+            Some(("warning", "Stale cache".to_string()))
+        );
+        assert_eq!(
+            classify_styled(error_message("Merge failed").to_string()),
+            Some(("error", "Merge failed".to_string()))
+        );
+        assert_eq!(
+            classify_styled(format_with_gutter("cargo test\ncargo build", None)),
+            Some(("detail", "cargo test\ncargo build".to_string()))
+        );
+        assert_eq!(classify(""), None);
+    }
+}
@@ -0,0 +1,341 @@
+//! The `[theme]` palette: recolor the five colors worktrunk styles with.
+//!
+//! Messages and `wt list` are styled with the basic ANSI colors — cyan for
+//! progress and working-tree changes, green for success and additions, red for
+//! errors and deletions, yellow for warnings, magenta for review requests.
+//! Rather than thread a theme through every call site, a palette set once at
+//! startup ([`set_theme`]) rewrites those colors in the escape codes as output
+//! is written ([`themed`]) — the same single choke point `--color` uses.
+//! Bold, dim, and other attributes pass through unchanged.
+
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+use anstyle::AnsiColor;
+
+/// A palette entry worktrunk styles with, in `[theme]` key order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::EnumIter, strum::IntoStaticStr)]
+#[strum(serialize_all = "lowercase")]
+pub enum ThemeSlot {
+    Cyan,
+    Magenta,
+    Green,
+    Red,
+    Yellow,
+}
+
+impl ThemeSlot {
+    /// The slot an SGR foreground code selects; background codes are `+ 10`.
+    fn from_fg_code(code: u8) -> Option<Self> {
+        match code {
+            31 => Some(Self::Red),
+            32 => Some(Self::Green),
+            33 => Some(Self::Yellow),
+            35 => Some(Self::Magenta),
+            36 => Some(Self::Cyan),
+            _ => None,
+        }
+    }
+}
+
+/// A color a slot is recolored to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeColor {
+    Ansi(AnsiColor),
+    /// An index into the 256-color palette
+    Ansi256(u8),
+    Rgb(u8, u8, u8),
+    /// The terminal's default color — no color at all
+    None,
+}
+
+impl ThemeColor {
+    /// Parse a `[theme]` color: an ANSI name (`blue`, `bright-red`), a
+    /// 256-color index (`208`), `#rrggbb`, or `none`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_ascii_lowercase();
+        if value == "none" {
+            return Some(Self::None);
+        }
+        if let Some(hex) = value.strip_prefix('#') {
+            if hex.len() != 6 {
+                return None;
+            }
+            let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+            return Some(Self::Rgb(channel(0)?, channel(2)?, channel(4)?));
+        }
+        if let Ok(index) = value.parse::<u8>() {
+            return Some(Self::Ansi256(index));
+        }
+        let (bright, name) = match value.strip_prefix("bright-") {
+            Some(name) => (true, name),
+            None => (false, value.as_str()),
+        };
+        let base = ANSI_NAMES.iter().position(|n| *n == name)?;
+        let index = if bright { base + 8 } else { base };
+        Some(Self::Ansi(ANSI_COLORS[index]))
+    }
+
+    /// SGR parameters selecting this color as foreground, or as background
+    /// when `background`.
+    fn sgr(self, background: bool) -> String {
+        let (base, extended) = if background { (40, 48) } else { (30, 38) };
+        match self {
+            Self::Ansi(color) => {
+                let index = color as u8;
+                if index < 8 {
+                    (base + index).to_string()
+                } else {
+                    (base + 60 + index - 8).to_string()
+                }
+            }
+            Self::Ansi256(index) => format!("{extended};5;{index}"),
+            Self::Rgb(r, g, b) => format!("{extended};2;{r};{g};{b}"),
+            Self::None => (base + 9).to_string(),
+        }
+    }
+}
+
+/// Written back in the form [`ThemeColor::parse`] reads.
+impl std::fmt::Display for ThemeColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Ansi(color) => {
+                let index = color as usize;
+                let name = ANSI_NAMES[index % 8];
+                if index < 8 {
+                    f.write_str(name)
+                } else {
+                    write!(f, "bright-{name}")
+                }
+            }
+            Self::Ansi256(index) => write!(f, "{index}"),
+            Self::Rgb(r, g, b) => write!(f, "#{r:02x}{g:02x}{b:02x}"),
+            Self::None => f.write_str("none"),
+        }
+    }
+}
+
+const ANSI_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+const ANSI_COLORS: [AnsiColor; 16] = [
+    AnsiColor::Black,
+    AnsiColor::Red,
+    AnsiColor::Green,
+    AnsiColor::Yellow,
+    AnsiColor::Blue,
+    AnsiColor::Magenta,
+    AnsiColor::Cyan,
+    AnsiColor::White,
+    AnsiColor::BrightBlack,
+    AnsiColor::BrightRed,
+    AnsiColor::BrightGreen,
+    AnsiColor::BrightYellow,
+    AnsiColor::BrightBlue,
+    AnsiColor::BrightMagenta,
+    AnsiColor::BrightCyan,
+    AnsiColor::BrightWhite,
+];
+
+/// Named palettes for `[theme] preset`.
+pub const THEME_PRESETS: &[&str] = &["solarized", "high-contrast", "monochrome"];
+
+/// Replacement colors by slot; `None` leaves a slot as is.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Theme {
+    colors: [Option<ThemeColor>; 5],
+}
+
+impl Theme {
+    /// One of [`THEME_PRESETS`] by name.
+    pub fn preset(name: &str) -> Option<Self> {
+        use ThemeSlot::*;
+        let colors: [(ThemeSlot, ThemeColor); 5] = match name {
+            // Solarized's accent colors, which need a truecolor terminal
+            "solarized" => [
+                (Cyan, ThemeColor::Rgb(0x2a, 0xa1, 0x98)),
+                (Magenta, ThemeColor::Rgb(0xd3, 0x36, 0x82)),
+                (Green, ThemeColor::Rgb(0x85, 0x99, 0x00)),
+                (Red, ThemeColor::Rgb(0xdc, 0x32, 0x2f)),
+                (Yellow, ThemeColor::Rgb(0xb5, 0x89, 0x00)),
+            ],
+            "high-contrast" => [
+                (Cyan, ThemeColor::Ansi(AnsiColor::BrightCyan)),
+                (Magenta, ThemeColor::Ansi(AnsiColor::BrightMagenta)),
+                (Green, ThemeColor::Ansi(AnsiColor::BrightGreen)),
+                (Red, ThemeColor::Ansi(AnsiColor::BrightRed)),
+                (Yellow, ThemeColor::Ansi(AnsiColor::BrightYellow)),
+            ],
+            // Bold, dim, and underline still distinguish what matters
+            "monochrome" => {
+                [Cyan, Magenta, Green, Red, Yellow].map(|slot| (slot, ThemeColor::None))
+            }
+            _ => return None,
+        };
+        let mut theme = Self::default();
+        for (slot, color) in colors {
+            theme.set(slot, color);
+        }
+        Some(theme)
+    }
+
+    /// The color `slot` is recolored to, if any.
+    pub fn get(&self, slot: ThemeSlot) -> Option<ThemeColor> {
+        self.colors[slot as usize]
+    }
+
+    pub fn set(&mut self, slot: ThemeSlot, color: ThemeColor) {
+        self.colors[slot as usize] = Some(color);
+    }
+
+    /// Whether the theme leaves every color as is.
+    pub fn is_default(&self) -> bool {
+        self.colors.iter().all(Option::is_none)
+    }
+
+    /// Rewrite the slot colors in `text`'s SGR escape codes.
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.is_default() || !text.contains('\x1b') {
+            return Cow::Borrowed(text);
+        }
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find("\x1b[") {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+            // An SGR sequence is `ESC [ <digits and ;> m`; anything else
+            // (cursor movement, OSC 8 links) is copied as is.
+            let len = after
+                .find(|c: char| !(c.is_ascii_digit() || c == ';'))
+                .unwrap_or(after.len());
+            if after[len..].starts_with('m') {
+                out.push_str("\x1b[");
+                out.push_str(&self.remap_params(&after[..len]));
+                out.push('m');
+                rest = &after[len + 1..];
+            } else {
+                out.push_str("\x1b[");
+                rest = after;
+            }
+        }
+        out.push_str(rest);
+        Cow::Owned(out)
+    }
+
+    fn remap_params(&self, params: &str) -> String {
+        let mut parts = params.split(';');
+        let mut out: Vec<String> = Vec::new();
+        while let Some(part) = parts.next() {
+            match part.parse::<u8>() {
+                // Extended colors carry their own arguments: copy them through
+                Ok(38 | 48) => {
+                    out.push(part.to_string());
+                    let Some(kind) = parts.next() else { break };
+                    out.push(kind.to_string());
+                    let args = if kind == "5" { 1 } else { 3 };
+                    out.extend(parts.by_ref().take(args).map(str::to_string));
+                }
+                Ok(code @ 31..=36) | Ok(code @ 41..=46) => {
+                    let background = code >= 40;
+                    let fg = if background { code - 10 } else { code };
+                    match ThemeSlot::from_fg_code(fg).and_then(|slot| self.get(slot)) {
+                        Some(color) => out.push(color.sgr(background)),
+                        None => out.push(part.to_string()),
+                    }
+                }
+                _ => out.push(part.to_string()),
+            }
+        }
+        out.join(";")
+    }
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Install the palette for the rest of the process. Later calls are ignored.
+pub fn set_theme(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+/// Whether an installed palette recolors anything.
+pub fn theme_active() -> bool {
+    THEME.get().is_some_and(|theme| !theme.is_default())
+}
+
+/// `text` with the installed palette applied.
+pub fn themed(text: &str) -> Cow<'_, str> {
+    match THEME.get() {
+        Some(theme) => theme.apply(text),
+        None => Cow::Borrowed(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_theme_color() {
+        assert_eq!(
+            ThemeColor::parse("blue"),
+            Some(ThemeColor::Ansi(AnsiColor::Blue))
+        );
+        assert_eq!(
+            ThemeColor::parse("Bright-Red"),
+            Some(ThemeColor::Ansi(AnsiColor::BrightRed))
+        );
+        assert_eq!(ThemeColor::parse("208"), Some(ThemeColor::Ansi256(208)));
+        assert_eq!(
+            ThemeColor::parse("#2aa198"),
+            Some(ThemeColor::Rgb(0x2a, 0xa1, 0x98))
+        );
+        assert_eq!(ThemeColor::parse("none"), Some(ThemeColor::None));
+        assert_eq!(ThemeColor::parse("#fff"), None);
+        assert_eq!(ThemeColor::parse("teal"), None);
+        assert_eq!(ThemeColor::parse("256"), None);
+        for value in ["cyan", "bright-black", "17", "#0a0b0c", "none"] {
+            assert_eq!(ThemeColor::parse(value).unwrap().to_string(), value);
+        }
+    }
+
+    #[test]
+    fn test_apply_remaps_slot_colors_only() {
+        let mut theme = Theme::default();
+        theme.set(ThemeSlot::Green, ThemeColor::Ansi(AnsiColor::Blue));
+        theme.set(ThemeSlot::Cyan, ThemeColor::Rgb(1, 2, 3));
+        theme.set(ThemeSlot::Red, ThemeColor::None);
+
+        // fg, bg, combined with attributes; yellow has no replacement
+        assert_eq!(
+            theme.apply("\x1b[32mok\x1b[39m \x1b[1;36mrun\x1b[0m \x1b[41mx\x1b[33my"),
+            "\x1b[34mok\x1b[39m \x1b[1;38;2;1;2;3mrun\x1b[0m \x1b[49mx\x1b[33my"
+        );
+        // Extended colors' arguments aren't mistaken for slot codes
+        assert_eq!(theme.apply("\x1b[38;5;32mz"), "\x1b[38;5;32mz");
+        assert_eq!(theme.apply("\x1b[38;2;32;36;31mz"), "\x1b[38;2;32;36;31mz");
+        // Non-SGR escapes pass through
+        assert_eq!(theme.apply("\x1b[2K\x1b[1A"), "\x1b[2K\x1b[1A");
+    }
+
+    #[test]
+    fn test_presets() {
+        for name in THEME_PRESETS {
+            let theme = Theme::preset(name).unwrap();
+            assert!(!theme.is_default(), "{name}");
+        }
+        assert_eq!(Theme::preset("dracula"), None);
+        let mono = Theme::preset("monochrome").unwrap();
+        assert_eq!(
+            mono.apply("\x1b[1m\x1b[32mok\x1b[0m"),
+            "\x1b[1m\x1b[39mok\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_default_theme_borrows() {
+        let text = "\x1b[32mok\x1b[39m";
+        assert!(matches!(Theme::default().apply(text), Cow::Borrowed(_)));
+    }
+}
@@ -41,7 +41,7 @@ use worktrunk::styling::INFO_SYMBOL;
 use worktrunk::sync::Semaphore;
 use worktrunk::utils::epoch_now;
 
-use crate::llm::{execute_llm_command, prepare_diff};
+use crate::llm::{LlmClient, prepare_diff};
 
 /// Limits concurrent LLM calls to avoid overwhelming the network / LLM
 /// provider. 8 permits balances parallelism with resource usage — LLM calls
@@ -152,6 +152,14 @@ impl CachedSummary {
         out
     }
 
+    /// Clear the cached summaries of one branch, returning the count removed.
+    pub(crate) fn clear_branch(repo: &Repository, branch: &str) -> anyhow::Result<usize> {
+        let dir = Self::branch_dir(repo, branch);
+        let cleared = cache::clear_json_files(&dir)?;
+        let _ = fs::remove_dir(&dir);
+        Ok(cleared)
+    }
+
     /// Clear all cached summaries, returning the count of `.json` entries removed.
     ///
     /// Summaries are two levels deep (`summary/{branch}/{hash}.json`), so
@@ -322,7 +330,7 @@ pub(crate) fn generate_summary_core(
     // clean `main` branch sits behind up to 8 slow summary calls and misses
     // the picker's collect deadline, surfacing as a `·` in the Summary column.
     let _permit = LLM_SEMAPHORE.acquire();
-    let summary = execute_llm_command(llm_command, &prompt)?;
+    let summary = LlmClient::new(llm_command).execute(&prompt)?;
 
     let cached = CachedSummary {
         summary: summary.clone(),
@@ -35,8 +35,14 @@ use std::path::Path;
 /// `cargo test --test integration` build them automatically and delete this
 /// whole layer — it's blocked on cargo-dist; see `.config/nextest.toml` and
 /// `tests/helpers/mock-stub/Cargo.toml`.
+///
+/// `WORKTRUNK_TEST_MOCK_STUB` overrides the location, for crates that use
+/// this harness through the `test-util` feature and so have no workspace
+/// `mock-stub` build next to their test binary.
 fn mock_stub_binary() -> std::path::PathBuf {
-    let path = super::workspace_bin("mock-stub");
+    let path = std::env::var_os("WORKTRUNK_TEST_MOCK_STUB")
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| super::workspace_bin("mock-stub"));
     assert_mock_stub_present(&path);
     path
 }
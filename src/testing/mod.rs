@@ -1,8 +1,19 @@
 //! Shared test fixtures for worktrunk unit and integration tests.
 //!
-//! This module is `#[doc(hidden)] pub` so both library (`src/`) and binary
-//! (`src/commands/`) unit tests, as well as integration tests (`tests/`), can
-//! use it. Integration tests import via `worktrunk::testing::TestRepo`.
+//! This module is `pub` so both library (`src/`) and binary (`src/commands/`)
+//! unit tests, as well as integration tests (`tests/`), can use it.
+//! Integration tests import via `worktrunk::testing::TestRepo`. It's documented
+//! under the `test-util` feature, which also enables [`snapshot`], so tools
+//! built on `wt` can reuse the harness:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! worktrunk = { version = "…", default-features = false, features = ["test-util"] }
+//! ```
+//!
+//! Outside this crate, point `CARGO_BIN_EXE_wt` at the `wt` binary under test
+//! (see [`wt_bin`]), and `WORKTRUNK_TEST_MOCK_STUB` at a `mock-stub` build for
+//! the mock commands (see [`mock_commands`]).
 //!
 //! ## TestRepo
 //!
@@ -31,6 +42,8 @@
 //! - Thread-safe execution (no global state mutation)
 
 pub mod mock_commands;
+#[cfg(feature = "test-util")]
+pub mod snapshot;
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -386,6 +399,8 @@ where
     }
     cmd.env_remove("NO_COLOR");
     cmd.env_remove("SHELL");
+    // A pinned clock would override `WORKTRUNK_TEST_EPOCH`.
+    cmd.env_remove("WT_NOW");
     // PSModulePath being inherited triggers false PowerShell detection on
     // CI environments where PowerShell Core is installed but not in use.
     cmd.env_remove("PSModulePath");
@@ -1747,6 +1762,31 @@ impl TestRepo {
         self.mock_bin_path = Some(mock_bin);
     }
 
+    /// Setup a mock `jj` for a colocated jj workspace at the repo root.
+    ///
+    /// Creates `.jj/` so worktrunk treats the root as a jj workspace; the mock
+    /// reports change ID `kntqzsqtsvwwrmypsopwvoyzxwxkxorx`, workspace
+    /// `default`, and bookmark `feature`. Use `configure_mock_commands()` to
+    /// put it on PATH.
+    pub fn setup_mock_jj(&mut self) {
+        std::fs::create_dir_all(self.root_path().join(".jj")).unwrap();
+        let mock_bin = self.temp_dir.path().join("mock-bin");
+        std::fs::create_dir_all(&mock_bin).unwrap();
+
+        MockConfig::new("jj")
+            .command(
+                "log -r @",
+                MockResponse::output("kntqzsqtsvwwrmypsopwvoyzxwxkxorx\ndefault@"),
+            )
+            .command(
+                "log -r latest(heads(::@ & bookmarks()))",
+                MockResponse::output("feature"),
+            )
+            .write(&mock_bin);
+
+        self.mock_bin_path = Some(mock_bin);
+    }
+
     /// Add a mock `tea` (installed, no login) to the existing mock bin.
     ///
     /// Call this after `setup_mock_ci_tools_unauthenticated()` to make
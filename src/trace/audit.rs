@@ -0,0 +1,201 @@
+//! Git-call audit for `wt debug git-calls`.
+//!
+//! When [`AUDIT_ENV`] names a file, every git and jj subprocess resolved
+//! through [`CommandTrace`] appends one JSON line to it: the command, the
+//! directory it ran in, its duration, and its exit code. `wt debug git-calls`
+//! sets the variable for the `wt` invocation it wraps — nested `wt` processes
+//! inherit it and append to the same file — then reads the lines back with
+//! [`read_calls`] and groups them with [`summarize`].
+//!
+//! Unlike the trace logs this needs no `-vv` and no log directory, and it
+//! covers only git and jj: the calls whose redundancy is worth hunting.
+
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+use super::CommandTrace;
+
+/// Environment variable naming the file git calls are appended to.
+pub const AUDIT_ENV: &str = "WORKTRUNK_GIT_CALLS";
+
+/// One recorded git or jj subprocess.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitCall {
+    /// Command line, e.g. `git rev-parse --show-toplevel`
+    pub cmd: String,
+    /// Directory the command ran in
+    pub cwd: Option<PathBuf>,
+    pub dur_us: u64,
+    /// Exit code; `None` when the command didn't exit normally or never ran
+    pub exit_code: Option<i32>,
+    pub ok: bool,
+}
+
+/// Calls with the same command line and directory, in order of first use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitCallGroup {
+    pub cmd: String,
+    pub cwd: Option<PathBuf>,
+    pub count: usize,
+    pub total_us: u64,
+    /// Calls that didn't exit 0
+    pub failures: usize,
+}
+
+/// The audit file, opened on first use; `None` when [`AUDIT_ENV`] is unset
+/// or the file can't be opened.
+fn audit_file() -> Option<&'static Mutex<File>> {
+    static FILE: OnceLock<Option<Mutex<File>>> = OnceLock::new();
+    FILE.get_or_init(|| {
+        let path = std::env::var_os(AUDIT_ENV).filter(|path| !path.is_empty())?;
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .ok()
+            .map(Mutex::new)
+    })
+    .as_ref()
+}
+
+/// Whether `cmd` runs git or jj, judged by its program's file name.
+fn is_git_or_jj(cmd: &str) -> bool {
+    let program = cmd.split_whitespace().next().unwrap_or_default();
+    let name = Path::new(program)
+        .file_stem()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    matches!(name, "git" | "jj")
+}
+
+/// Append `trace` to the audit file, if auditing is on and it ran git or jj.
+/// Called by [`CommandTrace`] as it resolves.
+pub(crate) fn record(trace: &CommandTrace, dur_us: u64, ok: bool) {
+    let Some(file) = audit_file() else {
+        return;
+    };
+    if !is_git_or_jj(trace.cmd()) {
+        return;
+    }
+    // A spawn site without a directory runs the child where `wt` runs, and a
+    // relative one resolves from there.
+    let here = std::env::current_dir().ok();
+    let cwd = match (trace.working_dir(), here) {
+        // Collecting components drops the `.` of a discovery path of `.`.
+        (Some(dir), Some(here)) => Some(here.join(dir).components().collect()),
+        (Some(dir), None) => Some(dir.to_path_buf()),
+        (None, here) => here,
+    };
+    let call = GitCall {
+        cmd: trace.cmd().to_string(),
+        cwd,
+        dur_us,
+        exit_code: trace.exit_code(),
+        ok,
+    };
+    let Ok(mut line) = serde_json::to_string(&call) else {
+        return;
+    };
+    line.push('\n');
+    // One write per line under the lock, so concurrent threads never
+    // interleave; O_APPEND keeps nested processes' lines whole.
+    if let Ok(mut file) = file.lock() {
+        let _ = file.write_all(line.as_bytes());
+    }
+}
+
+/// Parse an audit file's lines, skipping any that don't parse.
+pub fn read_calls(contents: &str) -> Vec<GitCall> {
+    contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Group calls by command line and directory, most repeated first (ties by
+/// total time, then first use).
+pub fn summarize(calls: &[GitCall]) -> Vec<GitCallGroup> {
+    let mut groups: Vec<GitCallGroup> = Vec::new();
+    let mut index: HashMap<(&str, Option<&Path>), usize> = HashMap::new();
+    for call in calls {
+        let key = (call.cmd.as_str(), call.cwd.as_deref());
+        let i = *index.entry(key).or_insert_with(|| {
+            groups.push(GitCallGroup {
+                cmd: call.cmd.clone(),
+                cwd: call.cwd.clone(),
+                count: 0,
+                total_us: 0,
+                failures: 0,
+            });
+            groups.len() - 1
+        });
+        let group = &mut groups[i];
+        group.count += 1;
+        group.total_us += call.dur_us;
+        if !call.ok {
+            group.failures += 1;
+        }
+    }
+    // Stable sort keeps first-use order among equals.
+    groups.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| b.total_us.cmp(&a.total_us))
+    });
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(cmd: &str, cwd: &str, dur_us: u64, ok: bool) -> GitCall {
+        GitCall {
+            cmd: cmd.to_string(),
+            cwd: Some(PathBuf::from(cwd)),
+            dur_us,
+            exit_code: Some(if ok { 0 } else { 1 }),
+            ok,
+        }
+    }
+
+    #[test]
+    fn test_is_git_or_jj() {
+        assert!(is_git_or_jj("git status --porcelain"));
+        assert!(is_git_or_jj("/usr/bin/git rev-parse HEAD"));
+        assert!(is_git_or_jj("jj log"));
+        assert!(is_git_or_jj("git.exe status"));
+        assert!(!is_git_or_jj("gh pr view"));
+        assert!(!is_git_or_jj("gitk"));
+    }
+
+    #[test]
+    fn test_summarize_groups_by_command_and_dir() {
+        let calls = vec![
+            call("git status", "/repo", 10, true),
+            call("git rev-parse HEAD", "/repo", 5, true),
+            call("git status", "/repo.feature", 30, true),
+            call("git rev-parse HEAD", "/repo", 7, false),
+        ];
+        let groups = summarize(&calls);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].cmd, "git rev-parse HEAD");
+        assert_eq!((groups[0].count, groups[0].total_us), (2, 12));
+        assert_eq!(groups[0].failures, 1);
+        // Single calls order by time spent.
+        assert_eq!(groups[1].cwd.as_deref(), Some(Path::new("/repo.feature")));
+        assert_eq!(groups[2].cwd.as_deref(), Some(Path::new("/repo")));
+    }
+
+    #[test]
+    fn test_read_calls_skips_bad_lines() {
+        let line = serde_json::to_string(&call("git status", "/repo", 10, true)).unwrap();
+        let calls = read_calls(&format!("{line}\nnot json\n{line}\n"));
+        assert_eq!(calls.len(), 2);
+    }
+}
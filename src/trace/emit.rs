@@ -80,6 +80,7 @@
 
 use std::borrow::Cow;
 use std::fmt::Display;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Instant;
@@ -236,6 +237,13 @@ pub struct CommandTrace {
     /// cache analysis never treats it as a duplicate — its real input isn't in
     /// `cmd`, so two runs with identical `cmd` may be entirely different work.
     reads_stdin: bool,
+    /// Directory the child ran in, when the spawn site set one. Only the
+    /// git-call audit reads it; trace records identify the worktree by
+    /// `context`.
+    cwd: Option<PathBuf>,
+    /// The child's exit code, when the spawn site resolved through
+    /// [`complete_status`](Self::complete_status).
+    exit_code: Option<i32>,
     resolved: bool,
 }
 
@@ -251,10 +259,18 @@ impl CommandTrace {
             tid: thread_id(),
             seq: CMD_SEQ.fetch_add(1, Ordering::Relaxed),
             reads_stdin: false,
+            cwd: None,
+            exit_code: None,
             resolved: false,
         }
     }
 
+    /// Record the directory the child runs in, for the git-call audit.
+    pub fn cwd(mut self, dir: Option<&Path>) -> Self {
+        self.cwd = dir.map(Path::to_path_buf);
+        self
+    }
+
     /// Mark this command as consuming stdin not captured in its command string.
     /// Set by spawn sites that feed a `stdin_bytes` buffer or pipe an upstream
     /// command's output in — the record then carries `stdin=true`, and the
@@ -286,14 +302,32 @@ impl CommandTrace {
         self.context.as_deref()
     }
 
+    /// The directory the child ran in, if the spawn site recorded one.
+    pub(crate) fn working_dir(&self) -> Option<&Path> {
+        self.cwd.as_deref()
+    }
+
+    /// The child's exit code, if known.
+    pub(crate) fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+
     /// The child ran to completion; `success` is its exit status. Emits an
     /// `ok=true`/`ok=false` record with the elapsed duration.
     pub fn complete(&mut self, success: bool) {
         let dur_us = self.start.elapsed().as_micros() as u64;
         command_completed(self, dur_us, success);
+        super::audit::record(self, dur_us, success);
         self.resolved = true;
     }
 
+    /// [`complete`](Self::complete) from the child's exit status, keeping its
+    /// exit code for the git-call audit.
+    pub fn complete_status(&mut self, status: std::process::ExitStatus) {
+        self.exit_code = status.code();
+        self.complete(status.success());
+    }
+
     /// Emit a failed record for a command that never produced a child to hold
     /// a guard against — a precondition failure before spawn, where there is
     /// nothing to time. Equivalent to `new` immediately followed by `fail`.
@@ -309,6 +343,7 @@ impl CommandTrace {
     pub fn fail(&mut self, err: impl Display) {
         let dur_us = self.start.elapsed().as_micros() as u64;
         command_errored(self, dur_us, err);
+        super::audit::record(self, dur_us, false);
         self.resolved = true;
     }
 }
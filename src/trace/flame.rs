@@ -0,0 +1,106 @@
+//! Folded-stacks output for flamegraphs.
+//!
+//! Each line is a `;`-separated stack and a weight, the input format of
+//! `inferno-flamegraph` and Brendan Gregg's `flamegraph.pl`:
+//!
+//! ```text
+//! wt;git;status;main 20000
+//! wt;git;rev-list;feature 4100
+//! ```
+//!
+//! Stacks are built from subprocess commands only: program, subcommands (as
+//! bucketed by [`Profile`](super::Profile)'s command types), then the worktree
+//! context. Weights are total microseconds, so frame widths show where
+//! subprocess time went. Spans are left out — commands often run inside them,
+//! and counting both would double the time.
+
+use std::collections::BTreeMap;
+
+use super::profile::command_type;
+use super::{TraceEntry, TraceEntryKind};
+
+/// Root frame shared by every stack.
+const ROOT: &str = "wt";
+
+/// Render `entries` as folded stacks, one line per distinct stack, sorted by
+/// stack.
+pub fn to_folded(entries: &[TraceEntry]) -> String {
+    let mut stacks: BTreeMap<String, u64> = BTreeMap::new();
+    for entry in entries {
+        let TraceEntryKind::Command {
+            command, duration, ..
+        } = &entry.kind
+        else {
+            continue;
+        };
+        let mut frames = vec![ROOT.to_string()];
+        frames.extend(command_type(command).split(' ').map(frame));
+        if let Some(context) = &entry.context {
+            frames.push(frame(context));
+        }
+        *stacks.entry(frames.join(";")).or_default() += duration.as_micros() as u64;
+    }
+
+    stacks
+        .into_iter()
+        .map(|(stack, us)| format!("{stack} {us}\n"))
+        .collect()
+}
+
+/// A frame can't contain the `;` separator, and a trailing space would be
+/// read as the weight separator.
+fn frame(name: &str) -> String {
+    name.replace(';', ":").replace(' ', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::trace::TraceResult;
+
+    fn cmd(command: &str, ctx: Option<&str>, dur_us: u64) -> TraceEntry {
+        TraceEntry {
+            context: ctx.map(str::to_string),
+            kind: TraceEntryKind::Command {
+                command: command.to_string(),
+                duration: Duration::from_micros(dur_us),
+                result: TraceResult::Completed { success: true },
+                reads_stdin: false,
+            },
+            start_time_us: Some(0),
+            thread_id: Some(1),
+        }
+    }
+
+    #[test]
+    fn folds_commands_by_subcommand_and_context() {
+        let entries = vec![
+            cmd("git status --porcelain", Some("main"), 12_000),
+            cmd("git status --porcelain", Some("main"), 8_000),
+            cmd("git rev-list --count HEAD", Some("feature"), 4_100),
+            cmd("gh pr list --json number", None, 300),
+            TraceEntry {
+                context: None,
+                kind: TraceEntryKind::Span {
+                    name: "user_config_load".to_string(),
+                    duration: Duration::from_micros(2_000),
+                },
+                start_time_us: Some(0),
+                thread_id: Some(1),
+            },
+        ];
+        insta::assert_snapshot!(to_folded(&entries), @r"
+        wt;gh;pr;list 300
+        wt;git;rev-list;feature 4100
+        wt;git;status;main 20000
+        ");
+    }
+
+    #[test]
+    fn escapes_separators_in_frames() {
+        let entries = vec![cmd("git status", Some("my branch;x"), 1)];
+        assert_eq!(to_folded(&entries), "wt;git;status;my_branch:x 1\n");
+    }
+}
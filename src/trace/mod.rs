@@ -1,4 +1,4 @@
-//! Trace log parsing and Chrome Trace Format export.
+//! Trace log parsing, analysis, and export.
 //!
 //! This module provides tools for analyzing `wt-trace` log output to understand
 //! where time is spent during command execution.
@@ -7,7 +7,10 @@
 //!
 //! - **Trace parsing**: Parse `wt-trace` log lines into structured entries
 //! - **Chrome Trace Format**: Export for chrome://tracing or Perfetto visualization
+//! - **Profile**: Time by command type, per-thread utilization, cache misses
+//! - **Folded stacks**: Flamegraph input, from `wt config state logs profile --flame`
 //! - **SQL analysis**: Use Perfetto's trace_processor for queries
+//! - **Git-call audit**: Every git/jj call of one command, from `wt debug git-calls`
 //!
 //! # Usage
 //!
@@ -37,13 +40,16 @@
 //!     AND skeleton.name = 'Skeleton rendered'"
 //! ```
 
+pub mod audit;
 pub mod chrome;
 pub mod emit;
+pub mod flame;
 pub mod parse;
 pub mod profile;
 
 // Re-export main types for convenience
 pub use chrome::to_chrome_trace;
 pub use emit::{CommandTrace, Span, WT_TRACE_TARGET, instant, now_us, thread_id};
+pub use flame::to_folded;
 pub use parse::{TraceEntry, TraceEntryKind, TraceResult, parse_lines};
-pub use profile::{CacheReport, Profile};
+pub use profile::{CacheReport, Profile, ThreadUtilization};
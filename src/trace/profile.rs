@@ -10,7 +10,7 @@
 //!   and [`Profile::slowest`] lists the most expensive individual jobs.
 //! - **How parallel are we?** — [`Profile::parallelism`] is Σ(subprocess time) ÷
 //!   their wall span; [`Profile::peak_concurrency`] is the most subprocesses in
-//!   flight at once.
+//!   flight at once; [`Profile::threads`] is how busy each thread was.
 //! - **Where is work wasted?** — [`CacheReport`] flags commands re-run with the
 //!   same context (a cache miss that should have been a hit). Commands that read
 //!   stdin are excluded — their real input isn't in the command string, so
@@ -96,6 +96,8 @@ pub struct Profile {
     pub peak_concurrency: Option<usize>,
     /// Distinct thread IDs that ran subprocesses.
     pub thread_count: usize,
+    /// Per-thread busy time across commands and spans, busiest first.
+    pub threads: Vec<ThreadUtilization>,
     /// Derived `wt list` latencies from the collect milestones.
     pub key_intervals: KeyIntervals,
     /// Subprocess time grouped by command shape, busiest first.
@@ -145,6 +147,20 @@ impl KeyIntervals {
     }
 }
 
+/// How busy one thread was over the traced span.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ThreadUtilization {
+    pub tid: u64,
+    /// Commands and spans the thread recorded.
+    pub jobs: usize,
+    /// Time covered by at least one of those jobs — a span and the commands
+    /// nested in it count once.
+    #[serde(rename = "busy_us", serialize_with = "ser_dur_us")]
+    pub busy: Duration,
+    /// `busy` as a percentage of [`Profile::traced`].
+    pub busy_pct: f64,
+}
+
 /// Aggregated timing for one command shape (e.g. `git status`).
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct TypeStat {
@@ -252,7 +268,7 @@ pub struct DuplicateContext {
 /// `merge-base`, …) take flags/refs/SHAs as their first operand, so this only
 /// bites branch-name operands in a single invocation, which `wt list` doesn't
 /// emit.
-fn command_type(command: &str) -> String {
+pub(super) fn command_type(command: &str) -> String {
     let mut tokens = command.split_whitespace();
     let Some(program) = tokens.next() else {
         return String::new();
@@ -326,12 +342,22 @@ impl Profile {
         let mut command_total_us = 0u64;
         let mut span_total_us = 0u64;
         let mut by_type_map: BTreeMap<String, (usize, u64, u64)> = BTreeMap::new();
-        let mut threads: HashSet<u64> = HashSet::new();
+        let mut thread_ids: HashSet<u64> = HashSet::new();
         // (start, end) for timed subprocesses — drives parallelism & peak concurrency.
         let mut intervals: Vec<(u64, u64)> = Vec::new();
+        // (start, end) of every timed command and span, per thread.
+        let mut thread_jobs: BTreeMap<u64, Vec<(u64, u64)>> = BTreeMap::new();
         let mut slowest: Vec<Slow> = Vec::new();
 
         for entry in entries {
+            if let (Some(tid), Some(start)) = (entry.thread_id, entry.start_time_us)
+                && !matches!(entry.kind, TraceEntryKind::Instant { .. })
+            {
+                thread_jobs
+                    .entry(tid)
+                    .or_default()
+                    .push((start, start + entry_dur_us(entry)));
+            }
             match &entry.kind {
                 TraceEntryKind::Command {
                     command,
@@ -347,7 +373,7 @@ impl Profile {
                     stat.1 += dur_us;
                     stat.2 = stat.2.max(dur_us);
                     if let Some(tid) = entry.thread_id {
-                        threads.insert(tid);
+                        thread_ids.insert(tid);
                     }
                     if let Some(start) = entry.start_time_us {
                         intervals.push((start, start + dur_us));
@@ -385,6 +411,24 @@ impl Profile {
 
         let (parallelism, peak_concurrency) = concurrency(&intervals);
 
+        let mut threads: Vec<ThreadUtilization> = thread_jobs
+            .into_iter()
+            .map(|(tid, jobs)| {
+                let busy = union_us(jobs.clone());
+                ThreadUtilization {
+                    tid,
+                    jobs: jobs.len(),
+                    busy: Duration::from_micros(busy),
+                    busy_pct: if traced.is_zero() {
+                        0.0
+                    } else {
+                        busy as f64 * 100.0 / traced.as_micros() as f64
+                    },
+                }
+            })
+            .collect();
+        threads.sort_by(|a, b| b.busy.cmp(&a.busy).then_with(|| a.tid.cmp(&b.tid)));
+
         let base = min_start.unwrap_or(0);
         let mut milestones: Vec<(&str, u64)> = entries
             .iter()
@@ -432,7 +476,8 @@ impl Profile {
             span_total: Duration::from_micros(span_total_us),
             parallelism,
             peak_concurrency,
-            thread_count: threads.len(),
+            thread_count: thread_ids.len(),
+            threads,
             key_intervals,
             by_type,
             slowest,
@@ -525,6 +570,30 @@ impl Profile {
             ));
         }
 
+        if !self.threads.is_empty() {
+            out.push('\n');
+            out.push_str(&format_heading("THREADS", None));
+            out.push('\n');
+            let mut rows = vec![vec![
+                "thread".to_string(),
+                "jobs".to_string(),
+                "busy".to_string(),
+                "busy %".to_string(),
+            ]];
+            for thread in &self.threads {
+                rows.push(vec![
+                    thread.tid.to_string(),
+                    thread.jobs.to_string(),
+                    fmt_dur(thread.busy),
+                    format!("{:.0}%", thread.busy_pct),
+                ]);
+            }
+            out.push_str(&render_table(
+                &rows,
+                &[Align::Left, Align::Right, Align::Right, Align::Right],
+            ));
+        }
+
         if !self.slowest.is_empty() {
             out.push('\n');
             out.push_str(&format_heading("SLOWEST CALLS", None));
@@ -765,6 +834,21 @@ fn concurrency(intervals: &[(u64, u64)]) -> (Option<f64>, Option<usize>) {
     (parallelism, Some(peak as usize))
 }
 
+/// Total time covered by a set of intervals, overlaps counted once.
+fn union_us(mut intervals: Vec<(u64, u64)>) -> u64 {
+    intervals.sort_unstable();
+    let mut total = 0;
+    let mut covered_to = 0;
+    for (start, end) in intervals {
+        let start = start.max(covered_to);
+        if end > start {
+            total += end - start;
+            covered_to = end;
+        }
+    }
+    total
+}
+
 #[derive(Clone, Copy)]
 enum Align {
     Left,
@@ -1079,6 +1163,14 @@ mod tests {
           "parallelism": 0.9,
           "peak_concurrency": 1,
           "thread_count": 1,
+          "threads": [
+            {
+              "tid": 1,
+              "jobs": 2,
+              "busy_us": 9000,
+              "busy_pct": 75.0
+            }
+          ],
           "key_intervals": {
             "time_to_skeleton_us": 3000,
             "time_to_first_result_us": null,
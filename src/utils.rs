@@ -63,7 +63,9 @@ pub fn now_iso8601() -> String {
 
 /// Get current Unix timestamp in seconds.
 ///
-/// When `WORKTRUNK_TEST_EPOCH` environment variable is set (by tests), returns that
+/// `WT_NOW` pins the clock for scripts and snapshot tests, as Unix seconds or
+/// an RFC 3339 timestamp (`2025-01-01T00:00:00Z`). Failing that, when the
+/// `WORKTRUNK_TEST_EPOCH` environment variable is set (by tests), returns that
 /// value instead of the actual current time. This enables deterministic test
 /// snapshots.
 ///
@@ -75,9 +77,14 @@ pub fn now_iso8601() -> String {
 /// All code that needs timestamps for display or storage should use this
 /// function rather than `SystemTime::now()` directly.
 pub fn epoch_now() -> u64 {
-    std::env::var("WORKTRUNK_TEST_EPOCH")
+    std::env::var("WT_NOW")
         .ok()
-        .and_then(|val| val.parse::<u64>().ok())
+        .and_then(|val| parse_epoch(&val))
+        .or_else(|| {
+            std::env::var("WORKTRUNK_TEST_EPOCH")
+                .ok()
+                .and_then(|val| val.parse::<u64>().ok())
+        })
         .unwrap_or_else(|| {
             SystemTime::now()
                 .duration_since(UNIX_EPOCH)
@@ -86,10 +93,28 @@ pub fn epoch_now() -> u64 {
         })
 }
 
+/// Parse Unix seconds or an RFC 3339 timestamp, as `WT_NOW` accepts.
+fn parse_epoch(value: &str) -> Option<u64> {
+    let value = value.trim();
+    value.parse::<u64>().ok().or_else(|| {
+        chrono::DateTime::parse_from_rfc3339(value)
+            .ok()
+            .and_then(|dt| u64::try_from(dt.timestamp()).ok())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_epoch() {
+        assert_eq!(parse_epoch("1735689600"), Some(1735689600));
+        assert_eq!(parse_epoch("2025-01-01T00:00:00Z"), Some(1735689600));
+        assert_eq!(parse_epoch("2025-01-01T01:00:00+01:00"), Some(1735689600));
+        assert_eq!(parse_epoch("yesterday"), None);
+    }
+
     #[test]
     fn test_epoch_now_returns_reasonable_timestamp() {
         let now = epoch_now();
@@ -22,6 +22,19 @@ if command -v {{ cmd }} >/dev/null 2>&1 || [[ -n "${WORKTRUNK_BIN:-}" ]]; then
             return
         fi
 
+        # Command substitution or pipeline (`$({{ cmd }} ...)`, `{{ cmd }} ... | jq`): this
+        # function runs in a subshell with stdout captured, so a cd or --execute
+        # payload can't reach the calling shell. Run the binary directly — it
+        # executes --execute itself — and return its exit code as-is.
+        if (( BASH_SUBSHELL > 0 )) && [[ ! -t 1 ]]; then
+            if [[ "$use_source" == true ]]; then
+                WORKTRUNK_COMMAND_SUBSTITUTION=1 cargo run --bin {{ cmd }} --quiet -- "${args[@]}"
+            else
+                WORKTRUNK_COMMAND_SUBSTITUTION=1 command "${WORKTRUNK_BIN:-{{ cmd }}}" "${args[@]}"
+            fi
+            return
+        fi
+
         local cd_file exec_file exit_code=0
         cd_file="$(mktemp)"
         exec_file="$(mktemp)"
@@ -19,6 +19,19 @@ function {{ cmd }}
         echo "{{ cmd }}: command not found" >&2
         return 127
     end
+    # Command substitution (`set p ({{ cmd }} ...)`): fish runs it in this shell,
+    # so a cd would move the calling shell and --execute output would be
+    # captured. Run the binary directly — it executes --execute itself — and
+    # return its exit code as-is.
+    if status is-command-substitution
+        if test $use_source = true
+            env WORKTRUNK_COMMAND_SUBSTITUTION=1 cargo run --bin {{ cmd }} --quiet -- $args
+        else
+            env WORKTRUNK_COMMAND_SUBSTITUTION=1 $WORKTRUNK_BIN $args
+        end
+        return $status
+    end
+
     set -l cd_file (mktemp)
     set -l exec_file (mktemp)
 
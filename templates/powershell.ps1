@@ -73,13 +73,15 @@ if ((Get-Command {{ cmd }} -ErrorAction SilentlyContinue) -or $env:WORKTRUNK_BIN
             Remove-Item $execFile -ErrorAction SilentlyContinue
         }
 
-        # Propagate exit code so $? and $LASTEXITCODE are consistent for scripts/CI
+        # Propagate exit code so $? and $LASTEXITCODE are consistent for scripts/CI.
+        # Don't `return $exitCode`: a function's return value joins its output,
+        # so `$out = {{ cmd }} list --format=json` or `$({{ cmd }} ...)` would capture
+        # a trailing exit code after wt's stdout.
         $global:LASTEXITCODE = $exitCode
         if ($exitCode -ne 0) {
             # Write error to set $? = $false without throwing
             Write-Error "wt exited with code $exitCode" -ErrorAction SilentlyContinue
         }
-        return $exitCode
     }
 
     # Tab completion - generate clap's completer script and eval it
@@ -2,6 +2,7 @@
 //!
 //! This crate provides:
 //! - Benchmark repository setup (used by `benches/list.rs`, `benches/time_to_first_output.rs`)
+//! - Lifecycle operations shared by `benches/lifecycle.rs` and `wt-perf bench`
 //! - Cache invalidation for cold benchmark runs
 //! - Trace analysis utilities
 //! - Shared benchmark helpers (`run_git`, `run_git_ok`, …)
@@ -94,6 +95,23 @@ impl RepoConfig {
         }
     }
 
+    /// Synthetic repo sized along the axes the lifecycle benchmarks sweep:
+    /// `worktrees` (including main) × `commits` on main × `files`.
+    ///
+    /// Linked worktrees sit at main's tip with clean working trees, so every
+    /// one is a `wt step prune` candidate.
+    pub const fn sized(worktrees: usize, commits: usize, files: usize) -> Self {
+        Self {
+            commits_on_main: commits,
+            files,
+            branches: 0,
+            commits_per_branch: 0,
+            worktrees,
+            worktree_commits_ahead: 0,
+            worktree_uncommitted_files: 0,
+        }
+    }
+
     /// Config for testing `wt switch` interactive picker (6 worktrees with varying commits).
     pub const fn picker_test() -> Self {
         Self {
@@ -717,6 +735,95 @@ pub fn create_mixed_repo_at(worktrees: usize, branches: usize, repo: &Path) {
     }
 }
 
+/// Branch `LifecycleOp::SwitchCreate` creates (and `prepare` tears down).
+const BENCH_BRANCH: &str = "bench-new";
+
+/// A `wt` operation timed by `benches/lifecycle.rs` and `wt-perf bench`.
+///
+/// Each op runs from the main worktree against cold caches: [`prepare`]
+/// restores the pre-op state and calls [`invalidate_caches_auto`], and is
+/// meant to run untimed before every measured iteration.
+///
+/// [`prepare`]: Self::prepare
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LifecycleOp {
+    /// `wt switch --create` of a fresh branch (no hooks, no cd)
+    SwitchCreate,
+    /// `wt list`
+    List,
+    /// `wt step prune --dry-run` — candidate detection without removal
+    Prune,
+}
+
+impl LifecycleOp {
+    pub const ALL: [Self; 3] = [Self::SwitchCreate, Self::List, Self::Prune];
+
+    /// Name used in benchmark IDs, `--ops`, and JSON output.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::SwitchCreate => "switch-create",
+            Self::List => "list",
+            Self::Prune => "prune",
+        }
+    }
+
+    /// Inverse of [`name`](Self::name).
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|op| op.name() == s)
+    }
+
+    /// Arguments passed to `wt`.
+    pub fn args(self) -> &'static [&'static str] {
+        match self {
+            Self::SwitchCreate => &[
+                "switch",
+                "--create",
+                BENCH_BRANCH,
+                "--no-cd",
+                "--no-hooks",
+                "--yes",
+            ],
+            Self::List => &["list"],
+            // Fixture worktrees are seconds old; the default `--min-age`
+            // would skip all of them.
+            Self::Prune => &["step", "prune", "--dry-run", "--min-age=0s"],
+        }
+    }
+
+    /// Untimed per-iteration setup: return the repo to its fixture state
+    /// (dropping any worktree a `SwitchCreate` run left behind, so later ops
+    /// see the configured worktree count) and invalidate caches.
+    pub fn prepare(self, repo_path: &Path) {
+        remove_bench_worktree(repo_path);
+        invalidate_caches_auto(repo_path);
+    }
+
+    /// The isolated `wt` command for this op, run from `repo_path`.
+    pub fn command(self, wt: &Path, repo_path: &Path) -> Command {
+        let mut cmd = Command::new(wt);
+        cmd.args(self.args()).current_dir(repo_path);
+        worktrunk::testing::isolate_subprocess_env(&mut cmd, None);
+        cmd
+    }
+}
+
+/// Remove the worktree and branch a previous `SwitchCreate` iteration made.
+/// Best-effort: on the first iteration neither exists.
+fn remove_bench_worktree(repo_path: &Path) {
+    let repo_name = repo_path.file_name().unwrap().to_str().unwrap();
+    let wt_path = repo_path
+        .parent()
+        .unwrap()
+        .join(format!("{repo_name}.{BENCH_BRANCH}"));
+    let _ = run_git_ok(
+        repo_path,
+        &["worktree", "remove", "--force", wt_path.to_str().unwrap()],
+    );
+    let _ = std::fs::remove_dir_all(&wt_path);
+    let _ = run_git_ok(repo_path, &["worktree", "prune"]);
+    let _ = run_git_ok(repo_path, &["branch", "-D", BENCH_BRANCH]);
+}
+
 /// Canonicalize path without Windows `\\?\` prefix.
 pub fn canonicalize(path: &Path) -> std::io::Result<PathBuf> {
     dunce::canonicalize(path)
@@ -729,6 +836,7 @@ pub fn canonicalize(path: &Path) -> std::io::Result<PathBuf> {
 /// - `branches-N` - N branches with 1 commit each
 /// - `branches-N-M` - N branches with M commits each
 /// - `divergent` - many divergent branches (GH #461)
+/// - `sized-W-C-F` - W worktrees × C commits × F files (lifecycle benchmarks)
 /// - `picker-test` - config for wt switch interactive picker testing
 pub fn parse_config(s: &str) -> Option<RepoConfig> {
     if let Some(n) = s.strip_prefix("typical-") {
@@ -752,6 +860,17 @@ pub fn parse_config(s: &str) -> Option<RepoConfig> {
         }
     }
 
+    if let Some(rest) = s.strip_prefix("sized-") {
+        let parts: Vec<usize> = rest
+            .split('-')
+            .map(|p| p.parse().ok())
+            .collect::<Option<_>>()?;
+        let [worktrees, commits, files] = parts.as_slice() else {
+            return None;
+        };
+        return Some(RepoConfig::sized(*worktrees, *commits, *files));
+    }
+
     match s {
         "divergent" => Some(RepoConfig::many_divergent_branches()),
         "picker-test" => Some(RepoConfig::picker_test()),
@@ -806,6 +925,26 @@ mod tests {
         assert_eq!(before.stdout, after.stdout);
     }
 
+    #[test]
+    fn parse_sized_config() {
+        let config = parse_config("sized-4-200-50").unwrap();
+        assert_eq!(
+            (config.worktrees, config.commits_on_main, config.files),
+            (4, 200, 50)
+        );
+        assert_eq!(config.worktree_commits_ahead, 0);
+        assert!(parse_config("sized-4-200").is_none());
+        assert!(parse_config("sized-4-x-50").is_none());
+    }
+
+    #[test]
+    fn lifecycle_op_names_round_trip() {
+        for op in LifecycleOp::ALL {
+            assert_eq!(LifecycleOp::parse(op.name()), Some(op));
+        }
+        assert_eq!(LifecycleOp::parse("remove"), None);
+    }
+
     /// Regression: degenerate `count` values must not panic. `count == 0`
     /// divided into `5000`, and `count > 5000` flooring `step` to 0 for
     /// `step_by`, both panicked before the `max(1)` guards.
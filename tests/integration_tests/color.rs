@@ -0,0 +1,67 @@
+//! Integration tests for `--color=auto|always|never`
+//!
+//! Snapshot commands run with stdout piped and `CLICOLOR_FORCE=1`; `auto`
+//! cases clear it so detection sees the pipe.
+
+use crate::common::{
+    TestRepo, make_snapshot_cmd, make_snapshot_cmd_with_global_flags, repo, setup_snapshot_settings,
+};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+/// Table rendering, on stdout.
+#[rstest]
+#[case::auto("auto")]
+#[case::always("always")]
+#[case::never("never")]
+fn test_color_list(repo: TestRepo, #[case] mode: &str) {
+    let mut cmd = make_snapshot_cmd(&repo, "list", &[&format!("--color={mode}")], None);
+    if mode == "auto" {
+        cmd.env_remove("CLICOLOR_FORCE");
+    }
+    assert_cmd_snapshot!(format!("color_list_{mode}"), cmd);
+}
+
+/// Error formatting, on stderr.
+#[rstest]
+#[case::auto("auto")]
+#[case::always("always")]
+#[case::never("never")]
+fn test_color_error(repo: TestRepo, #[case] mode: &str) {
+    let mut cmd = make_snapshot_cmd(
+        &repo,
+        "switch",
+        &["missing", &format!("--color={mode}")],
+        None,
+    );
+    if mode == "auto" {
+        cmd.env_remove("CLICOLOR_FORCE");
+    }
+    assert_cmd_snapshot!(format!("color_error_{mode}"), cmd);
+}
+
+/// `--color` overrides `NO_COLOR` and `CLICOLOR_FORCE`, and reaches the
+/// environment of commands whose output goes to the terminal.
+#[rstest]
+#[case::always("always")]
+#[case::never("never")]
+fn test_color_hook_env(repo: TestRepo, #[case] mode: &str) {
+    repo.write_project_config(
+        r#"
+[aliases]
+colors = "echo CLICOLOR_FORCE=${CLICOLOR_FORCE:-unset} NO_COLOR=${NO_COLOR:-unset}"
+"#,
+    );
+    repo.commit("Add alias config");
+
+    let settings = setup_snapshot_settings(&repo);
+    let _guard = settings.bind_to_scope();
+
+    let color = format!("--color={mode}");
+    let mut cmd =
+        make_snapshot_cmd_with_global_flags(&repo, "step", &["colors"], None, &["-y", &color]);
+    if mode == "always" {
+        cmd.env("NO_COLOR", "1");
+    }
+    assert_cmd_snapshot!(format!("color_hook_env_{mode}"), cmd);
+}
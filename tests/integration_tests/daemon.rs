@@ -0,0 +1,50 @@
+//! Tests for `wt daemon`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+use std::time::{Duration, Instant};
+
+/// Run `wt daemon <args>` in the repo, returning stderr.
+fn daemon(repo: &TestRepo, args: &[&str]) -> String {
+    let output = repo
+        .wt_command()
+        .arg("daemon")
+        .args(args)
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    assert!(output.status.success(), "{stderr}");
+    stderr
+}
+
+#[rstest]
+fn test_daemon_warms_completion_cache(repo: TestRepo) {
+    let cache = repo
+        .root_path()
+        .join(".git/wt/cache/completion/branches.json");
+
+    assert!(daemon(&repo, &["status"]).contains("not running"));
+    assert!(daemon(&repo, &["start"]).contains("Started daemon"));
+    assert!(daemon(&repo, &["status"]).contains("Daemon is running"));
+    assert!(daemon(&repo, &["start"]).contains("is watching"));
+
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while !cache.exists() && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    let stopped = daemon(&repo, &["stop"]);
+    assert!(cache.exists(), "daemon never wrote {}", cache.display());
+    assert!(stopped.contains("Stopped daemon"), "{stopped}");
+    assert!(daemon(&repo, &["status"]).contains("not running"));
+}
+
+#[rstest]
+fn test_daemon_forget(repo: TestRepo) {
+    assert!(daemon(&repo, &["forget"]).contains("isn't registered"));
+    daemon(&repo, &["start"]);
+    daemon(&repo, &["stop"]);
+    assert!(daemon(&repo, &["status"]).contains("Watching"));
+    assert!(daemon(&repo, &["forget"]).contains("Stopped watching"));
+    assert!(daemon(&repo, &["status"]).contains("No repositories registered"));
+}
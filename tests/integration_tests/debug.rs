@@ -0,0 +1,65 @@
+//! Integration tests for `wt debug`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+/// `wt debug git-calls` passes the audited command's output through and
+/// summarizes its git calls on stderr, counting repeats per directory.
+#[rstest]
+fn test_debug_git_calls_summarizes(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["debug", "git-calls", "--", "list", "--format=json"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+
+    // stdout is the audited command's own, untouched.
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json.is_array(), "{json}");
+
+    assert!(stderr.contains("git/jj calls"), "no summary: {stderr}");
+    assert!(
+        stderr.contains("git worktree list --porcelain"),
+        "worktree list not recorded: {stderr}"
+    );
+}
+
+/// A failing audited command still gets its summary, and its exit code is
+/// passed on.
+#[rstest]
+fn test_debug_git_calls_propagates_exit_code(repo: TestRepo) {
+    let direct = repo
+        .wt_command()
+        .args(["switch", "no-such-branch"])
+        .output()
+        .unwrap();
+    assert!(!direct.status.success());
+
+    let output = repo
+        .wt_command()
+        .args(["debug", "git-calls", "--", "switch", "no-such-branch"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), direct.status.code(), "{stderr}");
+    assert!(stderr.contains("git/jj calls"), "no summary: {stderr}");
+}
+
+/// `wt debug gc` is `wt config state gc`: it drops state whose branch is gone
+/// and keeps the rest.
+#[rstest]
+fn test_debug_gc_sweeps_deleted_branches(repo: TestRepo) {
+    for key in ["worktrunk.state.main.marker", "worktrunk.state.gone.marker"] {
+        repo.run_git(&["config", key, "{}"]);
+    }
+
+    let output = repo.wt_command().args(["debug", "gc"]).output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+
+    let keys = repo.git_output(&["config", "--get-regexp", r"^worktrunk\.state\."]);
+    assert!(keys.contains("worktrunk.state.main.marker"), "{keys}");
+    assert!(!keys.contains("worktrunk.state.gone.marker"), "{keys}");
+}
@@ -155,3 +155,42 @@ fn test_eval_commit_matches_head_sha(repo: TestRepo) {
         "eval commit should match HEAD SHA"
     );
 }
+
+/// jj vars are empty strings on git — defined, so shared templates render.
+#[rstest]
+fn test_eval_jj_vars_empty_on_git(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args([
+            "step",
+            "eval",
+            "{{ change_id }}|{{ bookmark or branch }}|{{ workspace }}",
+        ])
+        .output()
+        .expect("run wt step eval");
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "|main|");
+}
+
+/// In a jj workspace, jj vars come from the `jj` CLI.
+#[rstest]
+fn test_eval_jj_vars(mut repo: TestRepo) {
+    repo.setup_mock_jj();
+    let mut cmd = repo.wt_command();
+    repo.configure_mock_commands(&mut cmd);
+    let output = cmd
+        .args([
+            "step",
+            "eval",
+            "{{ change_id }}|{{ bookmark or branch }}|{{ workspace }}",
+        ])
+        .output()
+        .expect("run wt step eval");
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "kntqzsqtsvwwrmypsopwvoyzxwxkxorx|feature|default"
+    );
+}
@@ -310,6 +310,17 @@ fn display_conflicting_changes() {
     assert_snapshot!("conflicting_changes", err.render());
 }
 
+#[test]
+fn display_target_operation_in_progress() {
+    let err = GitError::TargetOperationInProgress {
+        target_branch: "main".into(),
+        worktree_path: PathBuf::from("/tmp/repo.main"),
+        state: "REBASING 2/5".into(),
+    };
+
+    assert_snapshot!("target_operation_in_progress", err.render());
+}
+
 #[test]
 fn display_not_fast_forward() {
     let err = GitError::NotFastForward {
@@ -348,11 +359,20 @@ fn display_rebase_conflict() {
 
 #[test]
 fn display_not_interactive() {
-    let err = GitError::NotInteractive;
+    let err = GitError::NotInteractive { hashes: vec![] };
 
     assert_snapshot!("not_interactive", err.render());
 }
 
+#[test]
+fn display_not_interactive_with_hashes() {
+    let err = GitError::NotInteractive {
+        hashes: vec!["0123456789abcdef".into(), "fedcba9876543210".into()],
+    };
+
+    assert_snapshot!("not_interactive_with_hashes", err.render());
+}
+
 #[test]
 fn display_llm_command_failed() {
     let err = GitError::LlmCommandFailed {
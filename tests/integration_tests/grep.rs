@@ -0,0 +1,48 @@
+//! Integration tests for `wt grep`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::process::Command;
+
+/// `wt grep` with ripgrep detection forced off, so output doesn't depend on
+/// whether the host has `rg`.
+fn grep_cmd(repo: &TestRepo, args: &[&str]) -> Command {
+    let mut cmd = make_snapshot_cmd(repo, "grep", args, None);
+    cmd.env("WORKTRUNK_TEST_RG_INSTALLED", "0");
+    cmd
+}
+
+#[rstest]
+fn test_grep_across_worktrees(mut repo: TestRepo) {
+    repo.add_worktree_with_commit("alpha", "lib.rs", "fn parse_config() {}\n", "Add parser");
+    let beta = repo.add_worktree("beta");
+    // Untracked files are searched too
+    std::fs::write(beta.join("notes.txt"), "call parse_config here\n").unwrap();
+    repo.add_worktree("gamma");
+
+    assert_cmd_snapshot!(grep_cmd(&repo, &["parse_config"]));
+}
+
+#[rstest]
+fn test_grep_dirty_only(mut repo: TestRepo) {
+    repo.add_worktree_with_commit("alpha", "lib.rs", "fn parse_config() {}\n", "Add parser");
+    let beta = repo.add_worktree("beta");
+    std::fs::write(beta.join("notes.txt"), "call parse_config here\n").unwrap();
+
+    assert_cmd_snapshot!(grep_cmd(&repo, &["--dirty-only", "parse_config"]));
+}
+
+#[rstest]
+fn test_grep_passes_args_through(mut repo: TestRepo) {
+    repo.add_worktree_with_commit("alpha", "lib.rs", "fn Parse_Config() {}\n", "Add parser");
+
+    assert_cmd_snapshot!(grep_cmd(&repo, &["parse_config", "--", "--ignore-case"]));
+}
+
+#[rstest]
+fn test_grep_no_matches(mut repo: TestRepo) {
+    repo.add_worktree("alpha");
+
+    assert_cmd_snapshot!(grep_cmd(&repo, &["parse_config"]));
+}
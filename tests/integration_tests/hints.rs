@@ -0,0 +1,49 @@
+//! Integration tests for `wt hints`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_hints_list(repo: TestRepo) {
+    repo.run_git(&["config", "worktrunk.hints.worktree-path", "1"]);
+    repo.run_git(&["config", "worktrunk.hints.shell-integration", "3"]);
+    repo.run_git(&["config", "worktrunk.hints.retired-hint", "true"]);
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "hints", &[], None));
+}
+
+#[rstest]
+fn test_hints_reset(repo: TestRepo) {
+    repo.run_git(&["config", "worktrunk.hints.worktree-path", "1"]);
+    repo.run_git(&["config", "worktrunk.hints.shell-integration", "3"]);
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "hints",
+        &["reset", "worktree-path"],
+        None
+    ));
+    assert_eq!(
+        repo.git_output(&["config", "--get-regexp", "worktrunk.hints"]),
+        "worktrunk.hints.shell-integration 3"
+    );
+}
+
+/// A daily deprecation warning shows on the first use, then stays quiet.
+#[rstest]
+fn test_daily_notice_shows_once(repo: TestRepo) {
+    let stderr = |branch: &str| {
+        let output = repo
+            .wt_command()
+            .args(["switch", "--create", branch, "--no-verify"])
+            .env("NO_COLOR", "1")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    };
+    assert!(stderr("first").contains("--no-verify is deprecated"));
+    assert!(!stderr("second").contains("--no-verify is deprecated"));
+
+    repo.run_git(&["config", "--unset", "worktrunk.hints.no-verify-deprecated"]);
+    assert!(stderr("third").contains("--no-verify is deprecated"));
+}
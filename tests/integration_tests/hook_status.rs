@@ -0,0 +1,147 @@
+//! Integration tests for background hook run records: `wt hook status` and the
+//! `wt list --full` Hooks column.
+
+use crate::common::{TestRepo, list_snapshots, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+use std::time::{Duration, Instant};
+
+/// Write a finished pipeline record for `branch`, as the runner would.
+fn write_record(repo: &TestRepo, branch: &str, file: &str, record: serde_json::Value) {
+    let dir = repo.root_path().join(".git/wt/hook-status").join(branch);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(file), record.to_string()).unwrap();
+}
+
+/// An hour before the test epoch.
+const STARTED_AT: u64 = 1735776000 - 3600;
+
+fn write_failed_setup(repo: &TestRepo) {
+    write_record(
+        repo,
+        "feature",
+        "project-post-start.json",
+        serde_json::json!({
+            "hook_type": "post-start",
+            "source": "project",
+            "pid": 1,
+            "started_at": STARTED_AT,
+            "commands": [
+                {"name": "install", "state": "succeeded", "started_at": STARTED_AT, "duration_ms": 4200, "exit_code": 0},
+                {"name": "build", "state": "failed", "started_at": STARTED_AT, "duration_ms": 350, "exit_code": 2},
+                {"name": "serve", "state": "skipped"},
+            ],
+        }),
+    );
+    write_record(
+        repo,
+        "feature",
+        "user-post-switch.json",
+        serde_json::json!({
+            "hook_type": "post-switch",
+            "source": "user",
+            "pid": 1,
+            "started_at": STARTED_AT,
+            "commands": [
+                {"name": "tmux", "state": "succeeded", "started_at": STARTED_AT, "duration_ms": 12, "exit_code": 0},
+            ],
+        }),
+    );
+}
+
+#[rstest]
+fn test_hook_status(repo: TestRepo) {
+    write_failed_setup(&repo);
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "hook",
+        &["status", "feature"],
+        None
+    ));
+}
+
+#[rstest]
+fn test_hook_status_none_recorded(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "hook", &["status"], None));
+}
+
+#[rstest]
+fn test_list_full_hooks_column(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.add_worktree("clean");
+    write_failed_setup(&repo);
+    write_record(
+        &repo,
+        "clean",
+        "project-post-start.json",
+        serde_json::json!({
+            "hook_type": "post-start",
+            "source": "project",
+            "pid": 1,
+            "started_at": STARTED_AT,
+            "commands": [
+                {"name": "install", "state": "succeeded", "started_at": STARTED_AT, "duration_ms": 900, "exit_code": 0},
+            ],
+        }),
+    );
+    assert_cmd_snapshot!({
+        let mut cmd = list_snapshots::command(&repo, repo.root_path());
+        cmd.arg("--full");
+        cmd
+    });
+}
+
+/// The runner records each step as it goes: a failing step fails the
+/// pipeline and the steps after it are skipped.
+#[rstest]
+fn test_background_hook_failure_is_recorded(repo: TestRepo) {
+    repo.write_test_config(
+        r#"post-start = [
+    { setup = "echo ready" },
+    { check = "exit 3" },
+    { serve = "echo unreachable" },
+]
+"#,
+    );
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "wt switch --create should succeed");
+
+    let status = || -> serde_json::Value {
+        let output = repo
+            .wt_command()
+            .args(["hook", "status", "feature", "--format=json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice(&output.stdout).unwrap()
+    };
+    let start = Instant::now();
+    let runs = loop {
+        let runs = status();
+        let finished = runs[0]["state"]
+            .as_str()
+            .is_some_and(|state| !matches!(state, "running" | "pending"));
+        if finished || start.elapsed() > Duration::from_secs(30) {
+            break runs;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    };
+
+    assert_eq!(runs[0]["hook_type"], "post-start");
+    assert_eq!(runs[0]["source"], "user");
+    assert_eq!(runs[0]["state"], "failed");
+    let commands = runs[0]["commands"].as_array().unwrap();
+    let states: Vec<&str> = commands
+        .iter()
+        .map(|c| c["state"].as_str().unwrap())
+        .collect();
+    assert_eq!(states, ["succeeded", "failed", "skipped"]);
+    assert_eq!(commands[1]["name"], "check");
+    assert_eq!(commands[1]["exit_code"], 3);
+    assert!(commands[2].get("exit_code").is_none());
+}
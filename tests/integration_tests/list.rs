@@ -102,6 +102,38 @@ fn test_list_multiple_worktrees(mut repo: TestRepo) {
     assert_cmd_snapshot!(list_snapshots::command(&repo, repo.root_path()));
 }
 
+/// `--only-mine` keeps branches whose tip, or most of whose commits since the
+/// merge base, the test user authored; the main worktree always stays.
+#[rstest]
+fn test_list_only_mine(mut repo: TestRepo) {
+    fn commit(repo: &TestRepo, path: &std::path::Path, author: Option<&str>) {
+        let mut args = vec!["commit", "--allow-empty", "-m", "work"];
+        if let Some(author) = author {
+            args.extend(["--author", author]);
+        }
+        repo.run_git_in(path, &args);
+    }
+    let bob = Some("Bob <bob@example.com>");
+
+    let mine_tip = repo.add_worktree("mine-tip");
+    commit(&repo, &mine_tip, bob);
+    commit(&repo, &mine_tip, None);
+    let mostly_mine = repo.add_worktree("mostly-mine");
+    commit(&repo, &mostly_mine, None);
+    commit(&repo, &mostly_mine, None);
+    commit(&repo, &mostly_mine, bob);
+    let theirs = repo.add_worktree("theirs");
+    commit(&repo, &theirs, None);
+    commit(&repo, &theirs, bob);
+    commit(&repo, &theirs, bob);
+
+    assert_cmd_snapshot!({
+        let mut cmd = list_snapshots::command(&repo, repo.root_path());
+        cmd.arg("--only-mine");
+        cmd
+    });
+}
+
 ///
 /// Simulates realistic usage by running switch commands from the correct worktree directories.
 #[rstest]
@@ -225,6 +257,39 @@ fn test_list_json_with_metadata(mut repo: TestRepo) {
     });
 }
 
+#[rstest]
+fn test_list_porcelain(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature, "f.txt", "f", "Add f");
+    std::fs::write(feature.join("dirty.txt"), "x").unwrap();
+    repo.add_worktree("locked-feature");
+    repo.lock_worktree("locked-feature", Some("Testing"));
+    repo.run_git(&["branch", "no-worktree"]);
+
+    assert_cmd_snapshot!({
+        let mut cmd = list_snapshots::command(&repo, repo.root_path());
+        cmd.args(["--porcelain", "--branches"]);
+        cmd
+    });
+}
+
+/// `--summary` counts worktree rows only: the branch without a worktree
+/// doesn't count, and the dirty worktree ahead of main counts in both.
+#[rstest]
+fn test_list_summary(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature, "f.txt", "f", "Add f");
+    std::fs::write(feature.join("dirty.txt"), "x").unwrap();
+    repo.add_worktree("clean");
+    repo.run_git(&["branch", "no-worktree"]);
+
+    assert_cmd_snapshot!({
+        let mut cmd = list_snapshots::command(&repo, repo.root_path());
+        cmd.args(["--summary", "--branches"]);
+        cmd
+    });
+}
+
 /// `repo_url` is derived locally from the primary remote, converting an SSH
 /// remote to its HTTPS web URL without shelling out to a forge.
 #[rstest]
@@ -267,6 +332,88 @@ fn test_list_json_repo_url_from_ssh_remote(repo: TestRepo) {
     );
 }
 
+/// `--output --atomic` writes the same JSON to a file (via a temp file and
+/// rename) and leaves stdout empty.
+#[rstest]
+fn test_list_json_output_file_atomic(repo: TestRepo) {
+    let out_dir = tempfile::tempdir().unwrap();
+    let out_path = out_dir.path().join("wt-list.json");
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--atomic", "--output"])
+        .arg(&out_path)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "wt list --output failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(output.stdout.is_empty());
+
+    let json: Vec<serde_json::Value> =
+        serde_json::from_str(&std::fs::read_to_string(&out_path).unwrap()).unwrap();
+    assert_eq!(json.first().unwrap()["branch"].as_str(), Some("main"));
+    // Only the target remains — the temp file was renamed into place.
+    assert_eq!(std::fs::read_dir(out_dir.path()).unwrap().count(), 1);
+}
+
+#[rstest]
+fn test_list_output_requires_json(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["list", "--output", "wt-list.json"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("--output requires --format=json"),
+        "unexpected stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[rstest]
+fn test_list_changed(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let spike = repo.add_worktree("spike");
+    let changed = |repo: &TestRepo| -> Vec<serde_json::Value> {
+        let output = repo
+            .wt_command()
+            .args(["list", "--changed", "--format=json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        serde_json::from_slice(&output.stdout).unwrap()
+    };
+
+    // First run: every row is new
+    let first = changed(&repo);
+    assert!(first.iter().all(|row| row["change"] == "added"));
+    assert!(first.iter().any(|row| row["branch"] == "spike"));
+
+    // Nothing moved
+    assert!(changed(&repo).is_empty());
+
+    repo.commit_in_worktree(&feature, "feature.txt", "content", "Add feature");
+    std::fs::write(feature.join("scratch.txt"), "wip").unwrap();
+    repo.wt_command()
+        .args(["remove", "spike", "--foreground"])
+        .output()
+        .unwrap();
+    assert!(!spike.exists());
+
+    let rows = changed(&repo);
+    assert_eq!(rows.len(), 2, "{rows:#?}");
+    assert_eq!(rows[0]["change"], "changed");
+    assert_eq!(rows[0]["branch"], "feature");
+    assert_eq!(rows[0]["reasons"], serde_json::json!(["commits", "dirty"]));
+    assert_eq!(rows[0]["item"]["branch"], "feature");
+    assert_eq!(rows[1]["change"], "removed");
+    assert_eq!(rows[1]["branch"], "spike");
+    assert!(rows[1].get("item").is_none());
+}
+
 #[rstest]
 fn test_list_json_configured_azure_generic_remote_is_unknown(repo: TestRepo) {
     repo.run_git(&[
@@ -305,6 +452,56 @@ fn test_list_json_configured_azure_generic_remote_is_unknown(repo: TestRepo) {
     );
 }
 
+/// A branch cut from a base that is later re-cut or deleted reports it in
+/// `base`; branches whose base still holds have no `base` field.
+#[rstest]
+fn test_list_json_base_drift(repo: TestRepo) {
+    repo.run_git(&["branch", "release"]);
+    repo.run_git(&["branch", "tmp-base"]);
+    repo.run_git(&["branch", "stable"]);
+    for (branch, base) in [
+        ("fix", "release"),
+        ("stacked", "tmp-base"),
+        ("steady", "stable"),
+    ] {
+        let output = repo
+            .wt_command()
+            .args(["switch", "--create", branch, "--base", base])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "wt switch --create {branch} failed"
+        );
+    }
+
+    // Re-cut release onto a history that no longer contains its old tip, and
+    // drop tmp-base outright. Only stable still holds.
+    repo.run_git(&["commit", "--amend", "--allow-empty", "-m", "Rewritten"]);
+    repo.run_git(&["branch", "-f", "release", "HEAD"]);
+    repo.run_git(&["branch", "-D", "tmp-base"]);
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--full", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "wt list should succeed");
+
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let row = |branch: &str| {
+        json.iter()
+            .find(|row| row["branch"] == branch)
+            .unwrap_or_else(|| panic!("no row for {branch}"))
+    };
+    assert_eq!(row("fix")["base"]["ref"], "release");
+    assert_eq!(row("fix")["base"]["state"], "rewritten");
+    assert_eq!(row("stacked")["base"]["ref"], "tmp-base");
+    assert_eq!(row("stacked")["base"]["state"], "deleted");
+    assert!(row("steady").get("base").is_none());
+    assert!(row("main").get("base").is_none());
+}
+
 /// This tests the merge commit scenario where content matches main even with different commit history.
 #[rstest]
 fn test_list_json_tree_matches_main_after_merge(mut repo: TestRepo) {
@@ -642,6 +839,33 @@ fn test_list_ordering_rules(mut repo: TestRepo) {
     assert_cmd_snapshot!(list_snapshots::command(&repo, &current_path));
 }
 
+/// `output.ascii` draws the status symbols, headers, arrows, and summary line
+/// in ASCII; branch names and JSON keep their Unicode.
+#[rstest]
+fn test_list_ascii(mut repo: TestRepo) {
+    repo.commit("Initial commit on main");
+    let feature = repo.add_worktree("feature—ü");
+    std::fs::write(feature.join("ahead.txt"), "ahead").unwrap();
+    repo.run_git_in(&feature, &["add", "."]);
+    repo.run_git_in(&feature, &["commit", "-m", "Ahead commit"]);
+    std::fs::write(feature.join("untracked.txt"), "new").unwrap();
+    repo.write_test_config("[output]\nascii = true\n");
+
+    assert_cmd_snapshot!(list_snapshots::command(&repo, repo.root_path()));
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let symbols: Vec<_> = json
+        .iter()
+        .filter_map(|row| row["symbols"].as_str())
+        .collect();
+    assert!(symbols.contains(&"?↑"), "{symbols:?}");
+}
+
 #[rstest]
 fn test_list_with_upstream_tracking(mut repo: TestRepo) {
     repo.commit("Initial commit on main");
@@ -696,7 +920,7 @@ fn test_list_with_upstream_tracking(mut repo: TestRepo) {
     repo.run_git_in(&no_upstream_wt, &["status", "--porcelain"]);
 
     // Scenario 6: Upstream configured but remote ref deleted (git's [gone] state).
-    // Should be treated as no upstream — blank Remote column, no error.
+    // Nothing to count against — `⊘` and `gone` in the Remote column, no error.
     let gone_wt = repo.add_worktree("gone-upstream");
     repo.run_git_in(&gone_wt, &["push", "-u", "origin", "gone-upstream"]);
     repo.run_git(&["push", "origin", "--delete", "gone-upstream"]);
@@ -826,6 +1050,59 @@ fn test_list_branch_stats_stay_local_when_default_ahead_of_upstream(mut repo: Te
     assert_eq!(feature["main"]["behind"].as_u64(), Some(0));
 }
 
+/// Fork workflow: local `main` tracks the fork (`origin/main`), which lags
+/// the canonical repository. With `[remote] upstream` set, the `main↕`
+/// column measures against `upstream/main` instead of the stale fork copy.
+#[rstest]
+fn test_list_branch_stats_use_configured_upstream_remote(mut repo: TestRepo) {
+    repo.commit("c0");
+    repo.setup_remote("main");
+    repo.run_git(&["config", "worktrunk.default-branch", "main"]);
+
+    // A second bare remote holding the canonical repository, three commits
+    // ahead of the fork.
+    let canonical = repo.root_path().parent().unwrap().join("canonical.git");
+    repo.run_git(&[
+        "init",
+        "--bare",
+        "--initial-branch",
+        "main",
+        canonical.to_str().unwrap(),
+    ]);
+    repo.run_git(&["remote", "add", "upstream", canonical.to_str().unwrap()]);
+    repo.commit("canonical 1");
+    repo.commit("canonical 2");
+    repo.commit("canonical 3");
+    repo.run_git(&["push", "upstream", "main"]);
+    repo.run_git(&["reset", "--hard", "HEAD~3"]);
+    repo.run_git(&["fetch", "upstream"]);
+
+    // Feature off the canonical tip, two commits ahead.
+    let feature_wt = repo.add_worktree("feature");
+    repo.run_git_in(&feature_wt, &["reset", "--hard", "upstream/main"]);
+    repo.commit_in_worktree(&feature_wt, "feat.txt", "a\n", "feature commit 1");
+    repo.commit_in_worktree(&feature_wt, "feat.txt", "a\nb\n", "feature commit 2");
+
+    let feature_ahead = |repo: &TestRepo| {
+        let output = repo
+            .wt_command()
+            .args(["list", "--format=json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "wt list should succeed");
+        let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+        let feature = json.iter().find(|w| w["branch"] == "feature").unwrap();
+        feature["main"]["ahead"].as_u64()
+    };
+
+    // Without config, local main and origin/main agree, so the three
+    // canonical commits count as the feature's own.
+    assert_eq!(feature_ahead(&repo), Some(5));
+
+    repo.write_test_config("[remote]\nupstream = \"upstream\"\n");
+    assert_eq!(feature_ahead(&repo), Some(2));
+}
+
 #[rstest]
 fn test_list_primary_on_different_branch(mut repo: TestRepo) {
     repo.switch_primary_to("develop");
@@ -3399,6 +3676,23 @@ fn test_list_nested_worktree_current_indicator(mut repo: TestRepo) {
     assert_cmd_snapshot!(list_snapshots::command(&repo, &nested_worktree));
 }
 
+/// `--tree` groups worktrees by directory and blanks the path prefix each row
+/// shares with the row above.
+#[rstest]
+fn test_list_tree(mut repo: TestRepo) {
+    let nested = repo.root_path().join(".worktrees");
+    repo.add_worktree_at_path("nested-x", &nested.join("nested-x"));
+    repo.add_worktree_at_path("nested-y", &nested.join("nested-y"));
+    let scratch = repo.root_path().parent().unwrap().join("scratch");
+    repo.add_worktree_at_path("spike", &scratch.join("spike"));
+
+    assert_cmd_snapshot!({
+        let mut cmd = list_snapshots::command_with_width(&repo, 150);
+        cmd.arg("--tree");
+        cmd
+    });
+}
+
 /// Tests JSON output for nested worktrees shows is_current on the correct worktree.
 #[rstest]
 fn test_list_nested_worktree_json_is_current(mut repo: TestRepo) {
@@ -3762,3 +4056,66 @@ fn test_list_tolerates_missing_index(mut repo: TestRepo) {
         "wt list must not resurrect the real index"
     );
 }
+
+/// `--full` counts the stashes made on each branch, in the table and JSON.
+#[rstest]
+fn test_list_full_stash_column(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    for note in ["one", "two"] {
+        std::fs::write(feature.join("notes.txt"), note).unwrap();
+        repo.run_git_in(
+            &feature,
+            &["stash", "push", "--include-untracked", "-m", note],
+        );
+    }
+
+    assert_cmd_snapshot!({
+        let mut cmd = list_snapshots::command(&repo, repo.root_path());
+        cmd.arg("--full");
+        cmd
+    });
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--full", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "wt list should succeed");
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let stashes = |branch: &str| {
+        json.iter()
+            .find(|row| row["branch"] == branch)
+            .map(|row| row["stashes"].clone())
+            .unwrap()
+    };
+    assert_eq!(stashes("feature"), 2);
+    assert!(stashes("main").is_null());
+}
+
+/// `--absolute-times` prints commit times instead of ages, and adds them to JSON.
+#[rstest]
+fn test_list_absolute_times(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "list",
+        &["--absolute-times"],
+        None
+    ));
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--absolute-times", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "wt list should succeed");
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json[0]["commit"]["date"], "2025-01-01T08:00:00Z");
+}
+
+/// `WT_NOW` pins the clock ages are measured from.
+#[rstest]
+fn test_list_wt_now(repo: TestRepo) {
+    let mut cmd = make_snapshot_cmd(&repo, "list", &[], None);
+    cmd.env("WT_NOW", "2025-01-08T00:00:00Z");
+    assert_cmd_snapshot!(cmd);
+}
@@ -98,6 +98,30 @@ branches = true
     });
 }
 
+#[rstest]
+fn test_list_config_branch_width(mut repo: TestRepo) {
+    repo.add_worktree("feature/payments-refactor-PROJ-1234");
+    repo.add_worktree("feature/payments-refactor-PROJ-1235");
+
+    fs::write(
+        repo.test_config_path(),
+        r#"[list]
+branch-width = 24
+path-width = 20
+"#,
+    )
+    .unwrap();
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        cmd.arg("list").current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
 #[rstest]
 fn test_list_no_config(repo: TestRepo) {
     // Create a branch without a worktree
@@ -827,6 +851,91 @@ template = "{{ vars.ticket }}"
     assert!(by_branch("feature-b")["columns"].is_null());
 }
 
+#[rstest]
+fn test_list_command_columns(repo: TestRepo) {
+    // Project columns run their command in each worktree; branches without a
+    // worktree get an empty cell.
+    repo.write_project_config(
+        r#"[list.columns.Head]
+command = "echo \"on $(git branch --show-current)\"; echo ignored"
+width = 14
+"#,
+    );
+    repo.commit("Add list column");
+    // Without a remote, the project's approvals are keyed by its path
+    repo.run_git(&["remote", "remove", "origin"]);
+    repo.write_test_approvals(&format!(
+        r#"[projects.'{}']
+approved-commands = ["echo \"on $(git branch --show-current)\"; echo ignored"]
+"#,
+        repo.project_id()
+    ));
+    repo.run_git(&["branch", "no-worktree"]);
+
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        cmd.args(["list", "--branches"])
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd);
+    });
+}
+
+#[rstest]
+fn test_list_command_columns_unapproved(repo: TestRepo) {
+    repo.write_project_config(
+        r#"[list.columns.Head]
+command = "git branch --show-current"
+"#,
+    );
+    repo.commit("Add list column");
+    // Without a remote, the project's approvals are keyed by its path
+    repo.run_git(&["remote", "remove", "origin"]);
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["list", "--format=json"])
+        .current_dir(repo.root_path());
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Skipping unapproved project list columns"),
+        "{stderr}"
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(
+        json.as_array()
+            .unwrap()
+            .iter()
+            .all(|item| item["columns"].is_null())
+    );
+
+    // Once approved, the JSON carries the output
+    repo.write_test_approvals(&format!(
+        r#"[projects.'{}']
+approved-commands = ["git branch --show-current"]
+"#,
+        repo.project_id()
+    ));
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["list", "--format=json"])
+        .current_dir(repo.root_path());
+    let output = cmd.output().unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let feature_a = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "feature-a")
+        .unwrap();
+    assert_eq!(feature_a["columns"]["Head"], "feature-a");
+}
+
 #[rstest]
 fn test_list_custom_column_git_branch(repo: TestRepo) {
     // A git.branch column reads the branch's own git config under
@@ -0,0 +1,91 @@
+//! Integration tests for `wt lock-file`
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+/// Run `wt lock-file` under `sh -c`, so the lock is held by a shell that
+/// exits right after — unlike a direct spawn, held by the test process.
+fn lock_file_via_shell(repo: &TestRepo, cwd: &Path, args: &[&str]) -> Output {
+    let wt = repo.wt_command();
+    let mut cmd = Command::new("sh");
+    for (key, value) in wt.get_envs() {
+        match value {
+            Some(value) => cmd.env(key, value),
+            None => cmd.env_remove(key),
+        };
+    }
+    cmd.arg("-c")
+        .arg(r#""$0" lock-file "$@""#)
+        .arg(wt.get_program())
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .unwrap()
+}
+
+#[rstest]
+fn test_lock_file_take_and_release(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+
+    assert_cmd_snapshot!(
+        "lock_file",
+        make_snapshot_cmd(&repo, "lock-file", &["db/schema"], None)
+    );
+    assert_cmd_snapshot!(
+        "lock_file_already_held",
+        make_snapshot_cmd(&repo, "lock-file", &["db/schema"], None)
+    );
+
+    // Another worktree can neither take, check, nor release it
+    for args in [
+        &["db/schema"][..],
+        &["--check", "db/schema"],
+        &["--release", "db/schema"],
+    ] {
+        let output = lock_file_via_shell(&repo, &feature, args);
+        assert!(!output.status.success(), "{args:?}");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("is locked by"), "{stderr}");
+        assert!(stderr.contains("main"), "{stderr}");
+    }
+
+    assert_cmd_snapshot!(
+        "lock_file_release",
+        make_snapshot_cmd(&repo, "lock-file", &["--release", "db/schema"], None)
+    );
+    let output = lock_file_via_shell(&repo, &feature, &["db/schema"]);
+    assert!(output.status.success());
+}
+
+/// A lock whose holder exited is taken over by the next acquirer.
+#[rstest]
+fn test_lock_file_takes_over_stale(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let output = lock_file_via_shell(&repo, &feature, &["fixtures"]);
+    assert!(output.status.success());
+
+    let output = make_snapshot_cmd(&repo, "lock-file", &["fixtures"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Taking over"), "{stderr}");
+
+    let output = make_snapshot_cmd(&repo, "lock-file", &["--format=json"], None)
+        .output()
+        .unwrap();
+    let locks: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let locks = locks.as_array().unwrap();
+    assert_eq!(locks.len(), 1);
+    assert_eq!(locks[0]["path"], "fixtures");
+    assert_eq!(locks[0]["branch"], "main");
+}
+
+#[rstest]
+fn test_lock_file_list_empty(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "lock-file", &[], None));
+}
@@ -0,0 +1,68 @@
+//! Integration tests for `wt map`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_map_json(mut repo: TestRepo) {
+    repo.add_worktree("feature/auth");
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "map", &[], None));
+}
+
+#[rstest]
+fn test_map_env(mut repo: TestRepo) {
+    repo.add_worktree("feature/auth");
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "map", &["--format=env"], None));
+}
+
+/// A named branch without a worktree maps to where `wt switch` would put it.
+#[rstest]
+fn test_map_named_branches(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "map",
+        &["feature", "not-yet"],
+        None
+    ));
+}
+
+#[rstest]
+fn test_path(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let output = make_snapshot_cmd(&repo, "path", &["feature"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim_end(),
+        feature.to_string_lossy()
+    );
+    // Defaults to the current worktree
+    assert_cmd_snapshot!("path_current", make_snapshot_cmd(&repo, "path", &[], None));
+}
+
+/// `output.ascii` changes decoration only: a path with non-ASCII characters
+/// prints as it is.
+#[rstest]
+fn test_path_ascii(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feat—x→y");
+    assert!(feature.to_string_lossy().contains("feat—x→y"));
+    repo.write_test_config("[output]\nascii = true\n");
+    let output = make_snapshot_cmd(&repo, "path", &["feat—x→y"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim_end(),
+        feature.to_string_lossy()
+    );
+}
+
+/// A branch without a worktree fails rather than printing a path to `cd` into.
+#[rstest]
+fn test_path_no_worktree(repo: TestRepo) {
+    repo.run_git(&["branch", "no-worktree"]);
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "path", &["no-worktree"], None));
+}
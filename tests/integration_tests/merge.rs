@@ -1,5 +1,5 @@
 use crate::common::{
-    SLEEP_FOR_ABSENCE_CHECK, TestRepo, make_snapshot_cmd, merge_scenario,
+    SLEEP_FOR_ABSENCE_CHECK, TestRepo, add_submodule, make_snapshot_cmd, merge_scenario,
     mock_commands::{create_mock_cargo, create_mock_llm_auth},
     repo, repo_with_alternate_primary, repo_with_feature_worktree, repo_with_main_worktree,
     repo_with_multi_commit_feature, repo_with_remote, setup_snapshot_settings, wait_for_file,
@@ -274,6 +274,266 @@ fn test_merge_rebase_conflict(repo: TestRepo) {
     ));
 }
 
+/// Set up `feature` (in its own worktree) whose only commit conflicts with
+/// a later commit on `main`, so `wt merge` stops at the rebase step.
+fn setup_rebase_conflict(repo: &TestRepo) -> PathBuf {
+    std::fs::write(repo.root_path().join("shared.txt"), "initial content\n").unwrap();
+    repo.run_git(&["add", "shared.txt"]);
+    repo.commit("Add shared file");
+    let base_commit = repo.git_output(&["rev-parse", "HEAD"]);
+
+    std::fs::write(repo.root_path().join("shared.txt"), "main version\n").unwrap();
+    repo.run_git(&["add", "shared.txt"]);
+    repo.run_git(&["commit", "-m", "Update shared.txt in main"]);
+
+    let feature_wt = repo.root_path().parent().unwrap().join("repo.feature");
+    repo.run_git(&[
+        "worktree",
+        "add",
+        feature_wt.to_str().unwrap(),
+        "-b",
+        "feature",
+        &base_commit,
+    ]);
+    std::fs::write(feature_wt.join("shared.txt"), "feature version\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "shared.txt"]);
+    repo.run_git_in(
+        &feature_wt,
+        &["commit", "-m", "Update shared.txt in feature"],
+    );
+    feature_wt
+}
+
+#[rstest]
+fn test_merge_abort_after_rebase_conflict(repo: TestRepo) {
+    let feature_wt = setup_rebase_conflict(&repo);
+    let feature_head = repo.git_output(&["rev-parse", "feature"]);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["--abort"],
+        Some(&feature_wt)
+    ));
+
+    // The rebase is gone and the branch is back at its pre-merge commit.
+    let status = repo
+        .git_command()
+        .args(["status", "--porcelain"])
+        .current_dir(&feature_wt)
+        .run()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&status.stdout).trim().is_empty());
+    assert_eq!(repo.git_output(&["rev-parse", "feature"]), feature_head);
+    let journals = repo
+        .git_command()
+        .args(["config", "--get-regexp", r"^worktrunk\.state\..*\.merge$"])
+        .run()
+        .unwrap();
+    assert!(journals.stdout.is_empty());
+}
+
+#[rstest]
+fn test_merge_continue_after_rebase_conflict(repo: TestRepo) {
+    let feature_wt = setup_rebase_conflict(&repo);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-remove"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    // Resolve the conflict and finish the rebase by hand.
+    std::fs::write(feature_wt.join("shared.txt"), "resolved\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "shared.txt"]);
+    repo.run_git_in(
+        &feature_wt,
+        &["-c", "core.editor=true", "rebase", "--continue"],
+    );
+
+    // A fresh merge refuses to start over while the journal is pending.
+    assert_cmd_snapshot!(
+        "merge_pending_journal_refuses",
+        make_snapshot_cmd(&repo, "merge", &["main"], Some(&feature_wt))
+    );
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["--continue"],
+        Some(&feature_wt)
+    ));
+    assert_eq!(
+        repo.git_output(&["rev-parse", "main"]),
+        repo.git_output(&["rev-parse", "feature"])
+    );
+}
+
+/// `auth-ui` cut from `auth`, each with one commit of its own.
+fn setup_stacked_branch(repo: &TestRepo) -> PathBuf {
+    let parent_wt = repo.root_path().parent().unwrap().join("repo.auth");
+    repo.run_git(&["worktree", "add", parent_wt.to_str().unwrap(), "-b", "auth"]);
+    std::fs::write(parent_wt.join("a.txt"), "parent work\n").unwrap();
+    repo.run_git_in(&parent_wt, &["add", "a.txt"]);
+    repo.run_git_in(&parent_wt, &["commit", "-m", "Add a.txt"]);
+
+    // Created from the parent by name, so the reflog records it as the base
+    let feature_wt = repo.root_path().parent().unwrap().join("repo.auth-ui");
+    repo.run_git(&[
+        "worktree",
+        "add",
+        feature_wt.to_str().unwrap(),
+        "-b",
+        "auth-ui",
+        "auth",
+    ]);
+    std::fs::write(feature_wt.join("b.txt"), "stacked work\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "b.txt"]);
+    repo.run_git_in(&feature_wt, &["commit", "-m", "Add b.txt"]);
+    feature_wt
+}
+
+#[rstest]
+fn test_merge_stacked_branch_requires_choice(repo: TestRepo) {
+    let feature_wt = setup_stacked_branch(&repo);
+    let main_head = repo.git_output(&["rev-parse", "main"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main"],
+        Some(&feature_wt)
+    ));
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_head);
+}
+
+#[rstest]
+fn test_merge_stacked_base_rebase(repo: TestRepo) {
+    let feature_wt = setup_stacked_branch(&repo);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--stacked-base=rebase", "--no-remove"],
+        Some(&feature_wt)
+    ));
+    // Only the stacked branch's own commit landed
+    let files = repo.git_output(&["ls-tree", "--name-only", "main"]);
+    assert!(files.contains("b.txt"), "{files}");
+    assert!(!files.contains("a.txt"), "{files}");
+}
+
+#[rstest]
+fn test_merge_stacked_base_parent(repo: TestRepo) {
+    let feature_wt = setup_stacked_branch(&repo);
+    let main_head = repo.git_output(&["rev-parse", "main"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["--stacked-base=parent", "--no-remove"],
+        Some(&feature_wt)
+    ));
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_head);
+    assert_eq!(
+        repo.git_output(&["rev-parse", "auth"]),
+        repo.git_output(&["rev-parse", "auth-ui"])
+    );
+}
+
+#[rstest]
+fn test_merge_stacked_after_parent_merged(repo: TestRepo) {
+    let feature_wt = setup_stacked_branch(&repo);
+    // Once the parent lands, the stacked branch merges without asking
+    repo.run_git(&["merge", "--ff-only", "auth"]);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-remove"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        repo.git_output(&["rev-parse", "main"]),
+        repo.git_output(&["rev-parse", "auth-ui"])
+    );
+}
+
+#[rstest]
+fn test_merge_continue_without_journal(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["--continue"],
+        Some(&feature_wt)
+    ));
+}
+
+/// A queued merge waits while an earlier entry's owner holds its lock, and
+/// proceeds once that entry leaves the queue.
+#[rstest]
+fn test_merge_queue_waits_for_earlier_entry(merge_scenario: (TestRepo, PathBuf)) {
+    use fs2::FileExt;
+    use std::io::Read;
+    use std::process::Stdio;
+
+    let (repo, feature_wt) = merge_scenario;
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+
+    // Another merge, queued first and still running.
+    let queue_dir = repo.root_path().join(".git/wt/merge-queue/main");
+    fs::create_dir_all(&queue_dir).unwrap();
+    let blocker = queue_dir.join("00000000000000000001-1.json");
+    let lock = fs::File::create(blocker.with_extension("lock")).unwrap();
+    lock.lock_exclusive().unwrap();
+    fs::write(&blocker, r#"{"branch":"other","pid":1}"#).unwrap();
+
+    let mut child = repo
+        .wt_command()
+        .args(["merge", "main", "--queue", "--no-remove"])
+        .current_dir(&feature_wt)
+        .env("NO_COLOR", "1")
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(1000));
+    assert!(child.try_wait().unwrap().is_none(), "merge didn't wait");
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+
+    fs::remove_file(&blocker).unwrap();
+    drop(lock);
+    let status = child.wait().unwrap();
+    let mut stderr = String::new();
+    child.stderr.unwrap().read_to_string(&mut stderr).unwrap();
+    assert!(status.success(), "{stderr}");
+    assert!(
+        stderr.contains("Waiting for other to merge into main"),
+        "{stderr}"
+    );
+    assert_eq!(
+        repo.git_output(&["rev-parse", "main"]),
+        repo.git_output(&["rev-parse", "feature"])
+    );
+    // The merge left the queue.
+    assert_eq!(fs::read_dir(&queue_dir).unwrap().count(), 1);
+}
+
 #[rstest]
 fn test_merge_to_default_branch(merge_scenario: (TestRepo, PathBuf)) {
     let (repo, feature_wt) = merge_scenario;
@@ -630,6 +890,65 @@ fn test_merge_pre_merge_command_no_hooks(mut repo: TestRepo) {
     ));
 }
 
+#[rstest]
+fn test_merge_pre_push_hook_failure(mut repo: TestRepo) {
+    // pre-push sees the range being integrated; a failure stops the merge
+    // before the target moves.
+    repo.write_project_config(r#"pre-push = "git log --format=%s {{ commit_range }} && exit 1""#);
+    repo.commit("Add config");
+    let target_commit = repo.git_output(&["rev-parse", "main"]);
+
+    let feature_wt = repo.add_feature();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--yes"],
+        Some(&feature_wt)
+    ));
+
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), target_commit);
+    assert!(feature_wt.exists());
+}
+
+#[rstest]
+fn test_merge_progress_json(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+
+    let output = repo
+        .wt_command()
+        .args(["--progress-json", "merge", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Bare `--progress-json` replaces the status lines on stderr with events.
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let events: Vec<serde_json::Value> = stderr
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    let phases: Vec<&str> = events
+        .iter()
+        .filter(|e| e["event"] == "phase")
+        .map(|e| e["phase"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        phases,
+        ["squash", "rebase", "pre-merge", "merge", "remove"],
+        "{stderr}"
+    );
+    assert!(events.iter().any(|e| e["event"] == "message"
+        && e["level"] == "success"
+        && e["message"].as_str().unwrap().starts_with("Merged to main")));
+    assert_eq!(
+        events.last().unwrap(),
+        &serde_json::json!({"event": "done", "success": true, "exit_code": 0})
+    );
+    assert!(!stderr.contains("✓"), "status lines leaked: {stderr}");
+}
+
 #[rstest]
 fn test_merge_pre_merge_command_named(mut repo: TestRepo) {
     // Create project config with named pre-merge commands
@@ -749,6 +1068,70 @@ fn test_merge_no_verify_deprecated_still_works(mut repo: TestRepo) {
     );
 }
 
+/// A `[merge] verify` command runs after rebasing and before the pre-merge
+/// hooks, and reports how long it took.
+#[rstest]
+fn test_merge_verify_command(mut repo: TestRepo) {
+    repo.write_project_config(r#"pre-merge = "echo pre-merge-ran""#);
+    repo.commit("Add config");
+    repo.write_test_config("[merge]\nverify = \"echo verify-ran\"\n");
+    let feature_wt = repo.add_feature();
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--yes"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    let verified = stderr.find("Verified with").expect(&stderr);
+    assert!(verified < stderr.find("pre-merge-ran").expect(&stderr));
+    assert!(stderr.contains("verify-ran"), "{stderr}");
+}
+
+/// A failing verification command stops the merge with its exit code,
+/// before the target moves.
+#[rstest]
+fn test_merge_verify_command_failure(mut repo: TestRepo) {
+    repo.write_test_config("[merge]\nverify = \"exit 3\"\n");
+    let feature_wt = repo.add_feature();
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--yes"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(3), "{stderr}");
+    assert!(stderr.contains("Verification failed"), "{stderr}");
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+}
+
+/// With a verification command configured, `--no-verify` skips it and
+/// nothing else: hooks still run, and it isn't reported as deprecated.
+#[rstest]
+fn test_merge_no_verify_skips_verify_command(mut repo: TestRepo) {
+    repo.write_project_config(r#"pre-merge = "echo pre-merge-ran""#);
+    repo.commit("Add config");
+    repo.write_test_config("[merge]\nverify = \"exit 1\"\n");
+    let feature_wt = repo.add_feature();
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--yes", "--no-verify"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("pre-merge-ran"), "{stderr}");
+    assert!(!stderr.contains("Verifying with"), "{stderr}");
+    assert!(!stderr.contains("deprecated"), "{stderr}");
+}
+
 #[rstest]
 fn test_merge_post_merge_command_failure(mut repo: TestRepo) {
     // Create project config with failing post-merge command
@@ -937,6 +1320,67 @@ fn test_merge_pre_commit_command_success(mut repo: TestRepo) {
     ));
 }
 
+/// `{{ changed_files }}` in pre-commit lists what the commit will record:
+/// modified and untracked files under the default stage mode, not deletions.
+#[rstest]
+fn test_step_commit_pre_commit_changed_files(mut repo: TestRepo) {
+    repo.write_project_config(
+        r#"pre-commit = "echo {{ changed_files }} > $(git rev-parse --git-common-dir)/changed.txt""#,
+    );
+    fs::write(repo.root_path().join("gone.txt"), "x").unwrap();
+    repo.commit("Add config");
+
+    let feature_wt = repo.add_worktree("feature");
+    fs::remove_file(feature_wt.join("gone.txt")).unwrap();
+    fs::write(feature_wt.join("file.txt"), "changed").unwrap();
+    fs::write(feature_wt.join("new file.txt"), "new").unwrap();
+
+    let output = make_snapshot_cmd(&repo, "step", &["commit", "--yes"], Some(&feature_wt))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "commit failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let recorded = fs::read_to_string(repo.root_path().join(".git/changed.txt")).unwrap();
+    assert_eq!(recorded.trim(), "file.txt new file.txt");
+}
+
+/// `{{ changed_files_json }}` in pre-merge lists what the branch changed
+/// since it forked from the target, across all its commits.
+#[rstest]
+fn test_merge_pre_merge_changed_files(mut repo: TestRepo) {
+    repo.write_project_config(
+        r#"pre-merge = "echo {{ changed_files_json }} > $(git rev-parse --git-common-dir)/changed.json""#,
+    );
+    repo.commit("Add config");
+
+    let feature_wt = repo.add_worktree_with_commit("feature", "a.txt", "a", "Add a");
+    fs::create_dir_all(feature_wt.join("src")).unwrap();
+    fs::write(feature_wt.join("src/b.rs"), "b").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "."]);
+    repo.run_git_in(&feature_wt, &["commit", "-q", "-m", "Add b"]);
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--yes", "--no-remove", "--no-squash"],
+        Some(&feature_wt),
+    )
+    .output()
+    .unwrap();
+    assert!(
+        output.status.success(),
+        "merge failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let recorded = fs::read_to_string(repo.root_path().join(".git/changed.json")).unwrap();
+    assert_eq!(recorded.trim(), r#"["a.txt","src/b.rs"]"#);
+}
+
 #[rstest]
 fn test_merge_pre_commit_command_failure(mut repo: TestRepo) {
     // Create project config with failing pre-commit command
@@ -2182,6 +2626,48 @@ fn test_step_squash_with_both_flags(mut repo: TestRepo) {
     });
 }
 
+/// `Co-authored-by:` trailers on the squashed commits are carried into the
+/// squash commit once each; trailers outside the allowlist are dropped.
+#[rstest]
+fn test_step_squash_preserves_trailers(mut repo: TestRepo) {
+    let feature_wt = repo.add_worktree("feature");
+
+    for (i, trailer) in [
+        "Co-authored-by: Ada <ada@example.com>",
+        "Co-authored-by: Ada <ada@example.com>\nSigned-off-by: Bob <bob@example.com>",
+    ]
+    .iter()
+    .enumerate()
+    {
+        let file = format!("file{i}.txt");
+        fs::write(feature_wt.join(&file), "content").expect("Failed to write file");
+        repo.run_git_in(&feature_wt, &["add", &file]);
+        repo.run_git_in(
+            &feature_wt,
+            &["commit", "-m", &format!("feat: add {file}\n\n{trailer}")],
+        );
+    }
+
+    assert_cmd_snapshot!({
+        let mut cmd = make_snapshot_cmd(&repo, "step", &["squash"], Some(&feature_wt));
+        cmd.env(
+            "WORKTRUNK_COMMIT__GENERATION__COMMAND",
+            "cat >/dev/null && echo 'squash: combined commits'",
+        );
+        cmd
+    });
+    assert_eq!(
+        repo.git_output(&[
+            "-C",
+            feature_wt.to_str().unwrap(),
+            "log",
+            "-1",
+            "--format=%B"
+        ]),
+        "squash: combined commits\n\nCo-authored-by: Ada <ada@example.com>"
+    );
+}
+
 #[rstest]
 fn test_step_squash_no_commits(mut repo: TestRepo) {
     // Test "nothing to squash; no commits ahead" message
@@ -2236,6 +2722,57 @@ fn test_step_commit_with_no_hooks_flag(repo: TestRepo) {
     });
 }
 
+/// `--offline` drops the LLM command, so the commit uses the deterministic
+/// fallback message instead of running (and failing on) the command.
+#[rstest]
+fn test_step_commit_offline_uses_fallback_message(repo: TestRepo) {
+    fs::write(repo.root_path().join("file1.txt"), "content 1").expect("Failed to write file");
+
+    assert_cmd_snapshot!({
+        let mut cmd = make_snapshot_cmd(&repo, "step", &[], None);
+        cmd.args(["commit", "--offline"]);
+        cmd.env("WORKTRUNK_COMMIT__GENERATION__COMMAND", "exit 1");
+        cmd
+    });
+}
+
+/// `[commit.scope]` infers the conventional-commit scope from the directory
+/// with the most changed lines: the LLM prompt gets it as context, and the
+/// fallback message gets it as a prefix.
+#[rstest]
+fn test_step_commit_scope_inference(repo: TestRepo) {
+    let root = repo.root_path();
+    fs::create_dir_all(root.join(".config")).unwrap();
+    fs::write(
+        root.join(".config/wt.toml"),
+        "[commit.scope.map]\n\"apps/web\" = \"web\"\n",
+    )
+    .unwrap();
+    fs::create_dir_all(root.join("apps/web/src")).unwrap();
+    fs::write(root.join("apps/web/src/app.ts"), "a\nb\nc\n").unwrap();
+    fs::create_dir_all(root.join("docs")).unwrap();
+    fs::write(root.join("docs/notes.md"), "notes\n").unwrap();
+    repo.run_git(&["add", "-A"]);
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--show-prompt"])
+        .output()
+        .unwrap();
+    let prompt = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        prompt.contains("Conventional-commit scope: web"),
+        "{prompt}"
+    );
+
+    let output = repo.wt_command().args(["step", "commit"]).output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%s"]),
+        "chore(web): Changes to wt.toml, app.ts & notes.md"
+    );
+}
+
 #[rstest]
 fn test_step_commit_with_stage_tracked_flag(repo: TestRepo) {
     fs::write(repo.root_path().join("tracked.txt"), "initial").expect("Failed to write file");
@@ -2947,6 +3484,70 @@ command = "cat >/dev/null; echo 'simulated LLM failure' >&2 && exit 1"
     );
 }
 
+/// A hung LLM command is killed once `timeout-ms` expires, instead of
+/// blocking the squash indefinitely.
+#[rstest]
+fn test_step_squash_dry_run_llm_timeout(repo_with_multi_commit_feature: TestRepo) {
+    let repo = repo_with_multi_commit_feature;
+    let feature_wt = repo.worktree_path("feature");
+
+    let worktrunk_config = r#"
+[commit.generation]
+command = "cat >/dev/null; sleep 30"
+timeout-ms = 300
+"#;
+    fs::write(repo.test_config_path(), worktrunk_config).unwrap();
+
+    let started = std::time::Instant::now();
+    let output = make_snapshot_cmd(&repo, "step", &["squash", "--dry-run"], Some(feature_wt))
+        .output()
+        .expect("wt step squash --dry-run failed to spawn");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "stderr:\n{stderr}");
+    assert!(
+        stderr.contains("timed out after 300ms"),
+        "stderr:\n{stderr}"
+    );
+    assert!(started.elapsed() < std::time::Duration::from_secs(20));
+}
+
+/// `retries` re-runs a failed LLM command, warning between attempts.
+#[rstest]
+fn test_step_squash_dry_run_llm_retry(repo_with_multi_commit_feature: TestRepo) {
+    let repo = repo_with_multi_commit_feature;
+    let feature_wt = repo.worktree_path("feature");
+
+    // Fails the first time (leaving a marker), succeeds the second
+    let marker = repo.root_path().parent().unwrap().join("llm-attempted");
+    let worktrunk_config = format!(
+        r#"
+[commit.generation]
+command = "cat >/dev/null; if [ -f '{marker}' ]; then echo 'Squash feature work'; else touch '{marker}'; echo 'rate limited' >&2; exit 1; fi"
+retries = 1
+"#,
+        marker = marker.display()
+    );
+    fs::write(repo.test_config_path(), worktrunk_config).unwrap();
+
+    let output = make_snapshot_cmd(&repo, "step", &["squash", "--dry-run"], Some(feature_wt))
+        .output()
+        .expect("wt step squash --dry-run failed to spawn");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "stdout:\n{stdout}\nstderr:\n{stderr}"
+    );
+    assert!(
+        stderr.contains("retrying in 1s (1/1): rate limited"),
+        "stderr:\n{stderr}"
+    );
+    assert!(
+        format!("{stdout}{stderr}").contains("Squash feature work"),
+        "stdout:\n{stdout}\nstderr:\n{stderr}"
+    );
+}
+
 // =============================================================================
 // step rebase tests
 // =============================================================================
@@ -3318,6 +3919,79 @@ fn test_merge_invalid_target(mut repo: TestRepo) {
     ));
 }
 
+#[rstest]
+fn test_merge_tag_target(mut repo: TestRepo) {
+    repo.run_git(&["tag", "v1.0"]);
+    let feature_wt = repo.add_worktree("feature");
+
+    // A tag has no ref to update - should fail before any step runs
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["v1.0"],
+        Some(&feature_wt)
+    ));
+}
+
+/// A target that exists only on a remote gets a local tracking branch, and
+/// the merge updates it without a worktree.
+#[rstest]
+fn test_merge_remote_only_target(mut repo: TestRepo) {
+    repo.setup_remote("main");
+    repo.run_git(&["branch", "release"]);
+    repo.push_branch("release");
+    repo.run_git(&["branch", "-D", "release"]);
+
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "feature.txt", "content", "Add feature");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["release", "--no-remove"],
+        Some(&feature_wt)
+    ));
+
+    assert_eq!(
+        repo.git_output(&["rev-parse", "--abbrev-ref", "release@{upstream}"]),
+        "origin/release"
+    );
+    assert_eq!(
+        repo.git_output(&["rev-parse", "release"]),
+        repo.git_output(&["rev-parse", "feature"])
+    );
+}
+
+/// History-rewriting steps refuse to run on the default branch.
+#[rstest]
+#[case::squash("squash")]
+#[case::rebase("rebase")]
+fn test_step_refuses_default_branch(repo: TestRepo, #[case] step: &str) {
+    repo.run_git(&["branch", "develop"]);
+    assert_cmd_snapshot!(
+        format!("step_refuses_default_branch_{step}"),
+        make_snapshot_cmd(&repo, "step", &[step, "develop"], None)
+    );
+}
+
+/// `--allow-primary` lets the step run on the default branch.
+#[rstest]
+fn test_step_rebase_allow_primary(repo: TestRepo) {
+    repo.run_git(&["branch", "develop"]);
+    let output = repo
+        .wt_command()
+        .args(["step", "rebase", "develop", "--allow-primary"])
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Already up to date with develop"));
+}
+
 #[rstest]
 fn test_step_rebase_invalid_target(mut repo: TestRepo) {
     // Create a feature worktree
@@ -3354,6 +4028,66 @@ fn test_step_rebase_accepts_tag(mut repo: TestRepo) {
     ));
 }
 
+/// Build a two-branch stack: `stack-base` (no worktree) with one commit, and a
+/// `stack-top` worktree with another commit on top; then advance main.
+fn setup_stack(repo: &mut TestRepo) -> std::path::PathBuf {
+    let top_wt = repo.add_worktree_with_commit("stack-top", "base.txt", "base", "Add base");
+    repo.run_git(&["branch", "stack-base", "stack-top"]);
+    fs::write(top_wt.join("top.txt"), "top").unwrap();
+    repo.git_command()
+        .current_dir(&top_wt)
+        .args(["add", "top.txt"])
+        .run()
+        .unwrap();
+    repo.git_command()
+        .current_dir(&top_wt)
+        .args(["commit", "-m", "Add top"])
+        .run()
+        .unwrap();
+    fs::write(repo.root_path().join("main-update.txt"), "main").unwrap();
+    repo.run_git(&["add", "main-update.txt"]);
+    repo.run_git(&["commit", "-m", "Update main"]);
+    top_wt
+}
+
+/// Without `--update-refs`, stacked branches are left behind with a hint.
+#[rstest]
+fn test_step_rebase_stacked_branches_hint(mut repo: TestRepo) {
+    let top_wt = setup_stack(&mut repo);
+    let old_base = repo.git_output(&["rev-parse", "stack-base"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "step", &["rebase"], Some(&top_wt)));
+    assert_eq!(repo.git_output(&["rev-parse", "stack-base"]), old_base);
+}
+
+/// `--update-refs` moves stacked branches with the rebase and reports them.
+#[rstest]
+fn test_step_rebase_update_refs(mut repo: TestRepo) {
+    let top_wt = setup_stack(&mut repo);
+
+    let output = repo
+        .wt_command()
+        .args(["step", "rebase", "--update-refs", "--format=json"])
+        .current_dir(&top_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "step rebase failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).expect("valid JSON");
+    assert_eq!(parsed["outcome"], "rebased");
+    assert_eq!(parsed["updated_refs"], serde_json::json!(["stack-base"]));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Updated stacked branches"));
+
+    // stack-base now sits on top of the updated main, under stack-top
+    let base_parent = repo.git_output(&["rev-parse", "stack-base^"]);
+    assert_eq!(base_parent, repo.git_output(&["rev-parse", "main"]));
+    let top_parent = repo.git_output(&["rev-parse", "stack-top^"]);
+    assert_eq!(top_parent, repo.git_output(&["rev-parse", "stack-base"]));
+}
+
 // =============================================================================
 // Behavior verification: --squash with --no-commit
 // =============================================================================
@@ -3526,6 +4260,48 @@ fn test_merge_ff_flag_overrides_config(merge_scenario: (TestRepo, PathBuf)) {
     );
 }
 
+#[rstest]
+fn test_merge_rebase_others_auto(merge_scenario: (TestRepo, PathBuf)) {
+    let (mut repo, feature_wt) = merge_scenario;
+    fs::write(
+        repo.test_config_path(),
+        "[merge]\nrebase-others = \"auto\"\n",
+    )
+    .unwrap();
+
+    let behind =
+        repo.add_worktree_with_commit("behind", "behind.txt", "behind content", "Add behind");
+    let dirty = repo.add_worktree_with_commit("dirty", "dirty.txt", "dirty content", "Add dirty");
+    fs::write(dirty.join("scratch.txt"), "uncommitted").unwrap();
+    let clash = repo.add_worktree_with_commit(
+        "clash",
+        "feature.txt",
+        "conflicting content",
+        "Add clashing feature file",
+    );
+    let clash_head = repo.git_output(&["rev-parse", "clash"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--no-remove"],
+        Some(&feature_wt)
+    ));
+
+    // The clean sibling now sits on the new target; the others are untouched,
+    // with the conflicting rebase aborted.
+    let main = repo.git_output(&["rev-parse", "main"]);
+    assert_eq!(repo.git_output(&["merge-base", "behind", "main"]), main);
+    assert!(behind.join("feature.txt").exists());
+    assert_ne!(repo.git_output(&["merge-base", "dirty", "main"]), main);
+    assert!(dirty.join("scratch.txt").exists());
+    assert_eq!(repo.git_output(&["rev-parse", "clash"]), clash_head);
+    assert_eq!(
+        repo.git_output(&["-C", clash.to_str().unwrap(), "status", "--porcelain"]),
+        ""
+    );
+}
+
 /// --no-ff with diverged branches: rebase first, then merge commit.
 #[rstest]
 fn test_merge_no_ff_with_rebase(mut repo_with_main_worktree: TestRepo) {
@@ -4072,3 +4848,47 @@ fn test_post_merge_hook_from_rebased_in_config_does_not_run(merge_scenario: (Tes
          rebase must not run — it was never in the frozen plan"
     );
 }
+
+/// After a merge, a followed submodule in the target worktree is fast-forwarded
+/// to the commit the merge recorded, fetched from the feature worktree's clone.
+#[rstest]
+fn test_merge_syncs_followed_submodules(repo: TestRepo) {
+    add_submodule(&repo, "libs/core");
+    repo.write_project_config(
+        r#"[submodules]
+follow = ["libs/core"]
+"#,
+    );
+    repo.commit("Follow libs/core");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let feature = repo.root_path().parent().unwrap().join(format!(
+        "{}.feature",
+        repo.root_path().file_name().unwrap().to_string_lossy()
+    ));
+    let feature_sub = feature.join("libs/core");
+    std::fs::write(feature_sub.join("lib.txt"), "feature lib").unwrap();
+    repo.run_git_in(&feature_sub, &["commit", "-am", "Change lib"]);
+    repo.run_git_in(&feature, &["commit", "-am", "Bump libs/core"]);
+
+    let output = repo
+        .wt_command()
+        .current_dir(&feature)
+        .args(["merge", "main", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        std::fs::read_to_string(repo.root_path().join("libs/core/lib.txt")).unwrap(),
+        "feature lib"
+    );
+}
@@ -11,11 +11,15 @@ pub mod approval_save;
 pub mod approval_ui;
 pub mod approvals;
 pub mod bare_repository;
+pub mod base_snapshot;
 pub mod cache_sharing;
 pub mod ci_status;
+pub mod color;
 pub mod column_alignment_verification;
 pub mod completion;
 pub mod completion_validation;
+pub mod completions_install;
+pub mod config_include;
 pub mod config_init;
 pub mod config_show;
 pub mod config_show_theme;
@@ -23,6 +27,8 @@ pub mod config_state;
 pub mod config_update_pty;
 pub mod configure_shell;
 pub mod custom;
+mod daemon;
+mod debug;
 pub mod default_branch;
 pub mod diagnostic;
 pub mod directives;
@@ -32,40 +38,57 @@ pub mod e2e_shell_post_start;
 pub mod eval;
 pub mod for_each;
 pub mod git_error_display;
+pub mod grep;
 pub mod help;
+pub mod hints;
 pub mod hook_show;
+pub mod hook_status;
 pub mod init;
 pub mod list;
 pub mod list_column_alignment;
 pub mod list_config;
 pub mod list_progressive;
+pub mod lock_file;
+mod map;
 pub mod merge;
 pub mod nushell_default_config_dir;
+pub mod open;
 pub mod output_system_guard;
 pub mod packaged_assets;
+pub mod pin;
 pub mod post_start_commands;
 pub mod push;
 pub mod readme_sync;
 pub mod remove;
+pub mod remove_pty;
+pub mod repair;
+pub mod repo_groups;
 pub mod repository;
 pub mod security;
 pub mod select_config;
+pub mod serve;
+pub mod setup;
 pub mod shell_integration_prompt;
 pub mod shell_integration_windows;
 pub mod shell_powershell;
 pub mod shell_wrapper;
 pub mod snapshot_formatting_guard;
 pub mod spacing_edge_cases;
+pub mod stats;
 pub mod statusline;
 pub mod step_alias;
+pub mod step_clone;
 pub mod step_copy_ignored;
 pub mod step_diff;
+pub mod step_optimize_repo;
 pub mod step_promote;
 pub mod step_prune;
 pub mod step_relocate;
+pub mod step_revert_merge;
 pub mod step_tether;
 pub mod switch;
 pub mod switch_picker;
 pub mod switch_picker_dry_run;
 pub mod user_hooks;
 pub mod version_build;
+pub mod working_directory;
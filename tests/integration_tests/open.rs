@@ -0,0 +1,40 @@
+//! Integration tests for `wt open`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+
+/// An editor command that records what it was asked to open.
+const RECORDING_EDITOR: &str = "[open]\neditor = \"echo {{ branch }} > opened.txt\"\n";
+
+#[rstest]
+fn test_open_existing_worktree(mut repo: TestRepo) {
+    let feature_wt = repo.add_worktree("feature");
+    repo.write_test_config(RECORDING_EDITOR);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "open", &["feature"], None));
+    assert_eq!(
+        fs::read_to_string(feature_wt.join("opened.txt")).unwrap(),
+        "feature\n"
+    );
+}
+
+/// A branch without a worktree gets one first, through the switch pipeline.
+#[rstest]
+fn test_open_creates_worktree(repo: TestRepo) {
+    repo.run_git(&["branch", "no-worktree"]);
+    repo.write_test_config(RECORDING_EDITOR);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "open", &["no-worktree"], None));
+    let path = repo.git_output(&["worktree", "list", "--porcelain"]);
+    let wt_path = path
+        .lines()
+        .filter_map(|line| line.strip_prefix("worktree "))
+        .find(|p| p.ends_with("no-worktree"))
+        .expect("worktree created");
+    assert_eq!(
+        fs::read_to_string(std::path::Path::new(wt_path).join("opened.txt")).unwrap(),
+        "no-worktree\n"
+    );
+}
@@ -33,10 +33,18 @@ const STDOUT_ALLOWED_PATHS: &[&str] = &[
     "list/collect/mod.rs",
     // JSON output for wt list --format=json
     "list/mod.rs",
+    // Branch → worktree path mapping for wt map
+    "map.rs",
     // State data output (branch names, previous worktree, etc.)
     "config/state.rs",
     // Hint list output
     "config/hints.rs",
+    // Approvals snippet for wt config approvals export
+    "config/approvals.rs",
+    // Snapshot list output (table + --format=json)
+    "snapshot.rs",
+    // Lock list output (table + --format=json)
+    "lock_file.rs",
     // Alias introspection output (show / dry-run), intended to be pipeable
     "config/alias.rs",
     // Alias --help hint output (conventional `--help` destination)
@@ -61,16 +69,22 @@ const STDOUT_ALLOWED_PATHS: &[&str] = &[
     "picker/mod.rs",
     // JSON output for wt switch --format=json
     "worktree/switch.rs",
+    // Result table and JSON output for wt switch --batch
+    "worktree/batch.rs",
     // JSON output for wt config show --format=json
     "config/show.rs",
     // Migrated TOML output for wt config update --print (pipeable)
     "config/update.rs",
     // JSON output for wt step for-each --format=json
     "for_each.rs",
+    // Search matches for wt grep (pipeable, like grep)
+    "grep.rs",
     // JSON output for wt merge --format=json
     "merge.rs",
     // JSON output for wt remove --format=json
     "remove.rs",
+    // Stats tables and JSON output for wt stats
+    "stats.rs",
     // Hook listing for wt hook show (paged), and the wt hook --dry-run preview
     "hook_commands.rs",
 ];
@@ -0,0 +1,53 @@
+//! Integration tests for `wt pin` and `wt unpin`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_pin_and_unpin(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+
+    assert_cmd_snapshot!("pin", make_snapshot_cmd(&repo, "pin", &["feature"], None));
+    assert_cmd_snapshot!(
+        "pin_already_pinned",
+        make_snapshot_cmd(&repo, "pin", &["feature"], None)
+    );
+    assert!(
+        repo.git_output(&["config", "worktrunk.state.feature.pinned"])
+            .contains("pinned_at")
+    );
+    assert_cmd_snapshot!(
+        "unpin",
+        make_snapshot_cmd(&repo, "unpin", &["feature"], None)
+    );
+    assert_cmd_snapshot!(
+        "unpin_not_pinned",
+        make_snapshot_cmd(&repo, "unpin", &["feature"], None)
+    );
+}
+
+#[rstest]
+fn test_pin_missing_branch(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "pin", &["missing"], None));
+}
+
+/// Pinned worktrees keep their 📌 in `wt list` and are never pruned, even
+/// once integrated.
+#[rstest]
+fn test_pin_list_and_prune(mut repo: TestRepo) {
+    repo.commit("initial");
+    repo.add_worktree("keep-me");
+    repo.add_worktree("merged");
+
+    let output = make_snapshot_cmd(&repo, "pin", &["keep-me"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    assert_cmd_snapshot!("pin_list", make_snapshot_cmd(&repo, "list", &[], None));
+    assert_cmd_snapshot!(
+        "pin_prune",
+        make_snapshot_cmd(&repo, "step", &["prune", "--dry-run", "--min-age=0s"], None)
+    );
+}
@@ -116,6 +116,30 @@ fn test_push_with_dirty_target(mut repo: TestRepo) {
     );
 }
 
+/// A target worktree in the middle of a merge refuses the push, naming the
+/// operation, and is left alone.
+#[rstest]
+fn test_push_target_mid_merge(mut repo: TestRepo) {
+    let feature_wt = repo.add_worktree_with_commit(
+        "feature",
+        "feature.txt",
+        "feature content",
+        "Add feature file",
+    );
+
+    repo.run_git(&["branch", "side"]);
+    repo.run_git(&["checkout", "-q", "side"]);
+    repo.commit_in_worktree(repo.root_path(), "side.txt", "side", "Add side file");
+    repo.run_git(&["checkout", "-q", "main"]);
+    repo.run_git(&["merge", "--no-ff", "--no-commit", "side"]);
+    let main_head = repo.git_output(&["rev-parse", "main"]);
+
+    snapshot_push("push_target_mid_merge", &repo, &["main"], Some(&feature_wt));
+
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_head);
+    assert!(repo.root_path().join(".git/MERGE_HEAD").exists());
+}
+
 #[rstest]
 fn test_push_dirty_target_autostash(mut repo: TestRepo) {
     // Make main worktree (repo root) dirty with a non-conflicting file
@@ -282,6 +306,65 @@ fn test_push_no_ff(mut repo: TestRepo) {
     assert_eq!(commit_msg, "Merge branch 'feature' into main");
 }
 
+#[rstest]
+fn test_push_pre_push_hook_sees_commit_range(mut repo: TestRepo) {
+    repo.write_project_config(
+        r#"pre-push = "echo '{{ target }} {{ commit_range }}' > pre-push.txt""#,
+    );
+    repo.commit("Add config");
+    let target_commit = repo.git_output(&["rev-parse", "main"]);
+
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+    let head = repo.git_output(&["-C", feature_wt.to_str().unwrap(), "rev-parse", "HEAD"]);
+
+    let output = repo
+        .wt_command()
+        .args(["step", "push", "main", "--yes"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "push failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Runs in the pushing worktree, with the range the push adds to the target.
+    let recorded = std::fs::read_to_string(feature_wt.join("pre-push.txt")).unwrap();
+    assert_eq!(recorded.trim(), format!("main {target_commit}..{head}"));
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), head);
+}
+
+#[rstest]
+fn test_push_pre_push_hook_failure_blocks_push(mut repo: TestRepo) {
+    repo.write_project_config(r#"pre-push = "echo 'missing ticket reference' && exit 1""#);
+    repo.commit("Add config");
+    let target_commit = repo.git_output(&["rev-parse", "main"]);
+
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+
+    snapshot_push(
+        "push_pre_push_hook_failure",
+        &repo,
+        &["main", "--yes"],
+        Some(&feature_wt),
+    );
+
+    // The target never moved.
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), target_commit);
+
+    // --no-hooks bypasses the gate.
+    let output = repo
+        .wt_command()
+        .args(["step", "push", "main", "--no-hooks"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+}
+
 #[rstest]
 fn test_push_with_submodule_recurse_config(mut repo: TestRepo) {
     // Regression test for https://github.com/max-sixty/worktrunk/issues/1604
@@ -1236,6 +1236,8 @@ fn test_config_docs_include_all_sections() {
     // Keys that are bare scalars or internal flags, not TOML section headers
     let non_section_keys: HashSet<&str> = [
         "worktree-path",
+        "state-dir",
+        "network",
         "skip-shell-integration-prompt",
         "skip-commit-generation-prompt",
     ]
@@ -1298,10 +1300,20 @@ fn test_project_config_docs_include_all_sections() {
         .chain(["pre-create".to_string(), "post-create".to_string()])
         .collect();
 
-    // Separate schema keys into section keys and hook keys
+    // Keys that are bare scalars, not TOML section headers
+    let non_section_keys: HashSet<&str> = ["include"].into();
+    for key in &non_section_keys {
+        assert!(
+            project_config_content.contains(&format!("\n{key} = ")),
+            "Config key `{key}` (from ProjectConfig schema) is missing from project config \
+             docs in src/cli/mod.rs."
+        );
+    }
+
+    // Separate schema keys into section keys (excluding hooks and bare scalars)
     let section_keys: Vec<&String> = all_keys
         .iter()
-        .filter(|k| !hook_keys.contains(*k))
+        .filter(|k| !hook_keys.contains(*k) && !non_section_keys.contains(k.as_str()))
         .collect();
 
     // Check non-deprecated sections appear as TOML headers ([key] or [key.something])
@@ -2727,7 +2739,7 @@ fn test_template_variables_table_matches_constants() {
     use std::collections::{BTreeMap, BTreeSet};
     use strum::IntoEnumIterator;
     use worktrunk::config::{
-        ACTIVE_VARS, ALIAS_ARGS_KEY, DEPRECATED_TEMPLATE_VARS, EXEC_BASE_VARS, REPO_VARS,
+        ACTIVE_VARS, ALIAS_ARGS_KEY, DEPRECATED_TEMPLATE_VARS, EXEC_BASE_VARS, JJ_VARS, REPO_VARS,
         ValidationScope, vars_available_in,
     };
     use worktrunk::git::HookType;
@@ -2789,6 +2801,7 @@ fn test_template_variables_table_matches_constants() {
         "repo".into(),
         REPO_VARS.iter().map(|s| s.to_string()).collect(),
     );
+    expected.insert("jj".into(), JJ_VARS.iter().map(|s| s.to_string()).collect());
     // `exec` in the docs = runtime infra vars plus `args` (hook+alias body
     // forwarding). The `hook_type`/`hook_name` names aren't exported as a
     // constant, so they're inlined here — anchoring them to the table row
@@ -2806,6 +2819,7 @@ fn test_template_variables_table_matches_constants() {
     let base: BTreeSet<&&str> = ACTIVE_VARS
         .iter()
         .chain(REPO_VARS.iter())
+        .chain(JJ_VARS.iter())
         .chain(EXEC_BASE_VARS.iter())
         .chain(DEPRECATED_TEMPLATE_VARS.iter())
         .collect();
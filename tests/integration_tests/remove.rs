@@ -155,6 +155,150 @@ fn test_remove_locked_detached_multi(mut repo: TestRepo) {
     ));
 }
 
+/// A process whose cwd is inside the worktree blocks removal; `--force`
+/// removes anyway. Detection is a no-op on Windows.
+#[rstest]
+#[cfg(unix)]
+fn test_remove_worktree_with_running_process(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("busy");
+    let subdir = worktree_path.join("src");
+    std::fs::create_dir_all(&subdir).unwrap();
+
+    let mut child = std::process::Command::new("sleep")
+        .arg("30")
+        .current_dir(&subdir)
+        .spawn()
+        .unwrap();
+
+    let output = repo.wt_command().args(["remove", "busy"]).output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr)
+        .ansi_strip()
+        .into_owned();
+    assert!(!output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Cannot remove busy, 1 process is running in the worktree"),
+        "stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains(&format!("{} sleep", child.id())),
+        "stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains("wt remove --force busy"),
+        "stderr: {stderr}"
+    );
+    assert!(worktree_path.exists());
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--force", "--foreground", "busy"])
+        .output()
+        .unwrap();
+    let _ = child.kill();
+    let _ = child.wait();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!worktree_path.exists());
+}
+
+/// `--discard` leaves the changes alone when another check refuses the
+/// removal: nothing is discarded until the removal is going ahead.
+#[rstest]
+#[cfg(unix)]
+fn test_remove_discard_keeps_changes_when_process_running(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("busy");
+    std::fs::write(worktree_path.join("scratch.txt"), "scratch").unwrap();
+
+    let mut child = std::process::Command::new("sleep")
+        .arg("30")
+        .current_dir(&worktree_path)
+        .spawn()
+        .unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "busy", "--discard", "--yes"])
+        .output()
+        .unwrap();
+    let _ = child.kill();
+    let _ = child.wait();
+    let stderr = String::from_utf8_lossy(&output.stderr)
+        .ansi_strip()
+        .into_owned();
+    assert!(!output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("process is running in the worktree"),
+        "stderr: {stderr}"
+    );
+    assert!(worktree_path.join("scratch.txt").exists());
+}
+
+/// A server a background `post-start` hook left running doesn't block
+/// removal: `wt remove` stops its process group before deleting the worktree.
+#[rstest]
+#[cfg(unix)]
+fn test_remove_stops_background_hook_processes(repo: TestRepo) {
+    let pid_file = repo.root_path().parent().unwrap().join("server.pid");
+    repo.write_test_config(&format!(
+        r#"[post-start]
+serve = "sleep 30 & echo $! > '{}'; wait"
+"#,
+        pid_file.display()
+    ));
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "wt switch --create should succeed");
+
+    let start = std::time::Instant::now();
+    let pid = loop {
+        if let Some(pid) = std::fs::read_to_string(&pid_file)
+            .ok()
+            .filter(|s| s.ends_with('\n'))
+        {
+            break pid.trim().to_string();
+        }
+        assert!(
+            start.elapsed() < std::time::Duration::from_secs(30),
+            "post-start hook never started the server"
+        );
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    };
+    // Running, and not yet reaped, as `ps` sees it.
+    let alive = || {
+        let output = std::process::Command::new("ps")
+            .args(["-o", "stat=", "-p", &pid])
+            .output()
+            .unwrap();
+        let stat = String::from_utf8_lossy(&output.stdout);
+        !stat.trim().is_empty() && !stat.trim_start().starts_with('Z')
+    };
+    assert!(alive());
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(worktree_path.exists());
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "feature"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr)
+        .ansi_strip()
+        .into_owned();
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Stopped processes still running from post-start (user) hooks"),
+        "stderr: {stderr}"
+    );
+    assert!(!alive(), "server should be stopped");
+    assert!(!worktree_path.exists());
+}
+
 #[rstest]
 fn test_remove_by_name_from_main(mut repo: TestRepo) {
     // Create a worktree
@@ -324,6 +468,70 @@ fn test_remove_by_name_dirty_target(mut repo: TestRepo) {
     assert_cmd_snapshot!(make_snapshot_cmd(&repo, "remove", &["feature-dirty"], None));
 }
 
+/// --stash saves uncommitted changes (untracked included) as a named stash,
+/// recorded in state, before removing.
+#[rstest]
+fn test_remove_stash(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature-stash");
+    std::fs::write(worktree_path.join("notes.txt"), "work in progress").unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["feature-stash", "--stash", "--foreground"],
+        None
+    ));
+    assert!(!worktree_path.exists());
+
+    let stashes = repo.git_output(&["stash", "list", "--format=%H %gs"]);
+    let (sha, subject) = stashes.split_once(' ').unwrap();
+    assert!(subject.ends_with("wt remove: feature-stash"), "{stashes}");
+    let entry = std::fs::read_to_string(
+        repo.root_path()
+            .join(".git/wt/state/removed-stash/feature-stash.json"),
+    )
+    .unwrap();
+    assert!(entry.contains(sha), "{entry}");
+
+    // The stash restores the untracked file
+    repo.run_git(&["stash", "apply", sha]);
+    assert_eq!(
+        std::fs::read_to_string(repo.root_path().join("notes.txt")).unwrap(),
+        "work in progress"
+    );
+}
+
+/// --discard shows the diffstat; --yes skips the confirmation.
+#[rstest]
+fn test_remove_discard(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature-discard");
+    std::fs::write(worktree_path.join("file.txt"), "changed\n").unwrap();
+    std::fs::write(worktree_path.join("scratch.txt"), "scratch").unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["feature-discard", "--discard", "--yes", "--foreground"],
+        None
+    ));
+    assert!(!worktree_path.exists());
+}
+
+/// Declining the --discard confirmation keeps the worktree and its changes.
+#[rstest]
+fn test_remove_discard_declined(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature-keep");
+    std::fs::write(worktree_path.join("scratch.txt"), "scratch").unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["feature-keep", "--discard"],
+        None
+    ));
+    assert!(worktree_path.join("scratch.txt").exists());
+}
+
 /// --force allows removal of dirty worktrees (issue #658)
 /// This test: untracked files, branch at same commit as main
 #[rstest]
@@ -809,6 +1017,43 @@ fn test_remove_branch_only_merged(repo: TestRepo) {
     ));
 }
 
+/// Deleting a branch drops its stored state; its merge record stays for
+/// `wt step revert-merge --restore`.
+#[rstest]
+fn test_remove_branch_clears_branch_state(repo: TestRepo) {
+    repo.run_git(&["branch", "feature-state"]);
+    repo.set_marker("feature-state", "🚧");
+    for key in [
+        "worktrunk.state.feature-state.vars.env",
+        "worktrunk.state.feature-state.merged",
+        "worktrunk.state.main.vars.env",
+    ] {
+        repo.run_git(&["config", key, "{}"]);
+    }
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "feature-state"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let output = repo
+        .git_command()
+        .args([
+            "config",
+            "--name-only",
+            "--get-regexp",
+            r"^worktrunk\.state\.",
+        ])
+        .run()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "worktrunk.state.feature-state.merged\nworktrunk.state.main.vars.env\n"
+    );
+}
+
 #[rstest]
 fn test_remove_branch_only_unmerged(repo: TestRepo) {
     // Create a branch with a unique commit (not in main)
@@ -3644,3 +3889,57 @@ fn test_remove_merged_locally_when_upstream_diverged(#[from(repo_with_remote)] r
         "feature branch should be deleted after detection via local main"
     );
 }
+
+/// Deleting a branch that stashes were made on warns and lists them; without
+/// a terminal there's no export prompt.
+#[rstest]
+fn test_remove_warns_about_branch_stashes(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature-stashed");
+    std::fs::write(worktree_path.join("notes.txt"), "idea").unwrap();
+    repo.run_git_in(
+        &worktree_path,
+        &["stash", "push", "--include-untracked", "-m", "idea"],
+    );
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "remove",
+        &["feature-stashed", "--foreground"],
+        None
+    ));
+    assert!(
+        !repo
+            .root_path()
+            .join(".git/wt/stashes/feature-stashed")
+            .exists()
+    );
+}
+
+/// --yes exports the stashes as patches before deleting the branch.
+#[rstest]
+fn test_remove_exports_branch_stashes_with_yes(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature-stashed");
+    std::fs::write(worktree_path.join("notes.txt"), "idea").unwrap();
+    repo.run_git_in(
+        &worktree_path,
+        &["stash", "push", "--include-untracked", "-m", "idea"],
+    );
+    let sha = repo.git_output(&["rev-parse", "--short", "refs/stash"]);
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "feature-stashed", "--foreground", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let patch = std::fs::read_to_string(
+        repo.root_path()
+            .join(format!(".git/wt/stashes/feature-stashed/{sha}.patch")),
+    )
+    .unwrap();
+    assert!(patch.contains("+idea"), "{patch}");
+}
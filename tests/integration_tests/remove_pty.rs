@@ -0,0 +1,78 @@
+#![cfg(all(unix, feature = "shell-integration-tests"))]
+//! PTY tests for `wt remove`'s choices when a removal would keep an unmerged
+//! branch. Without a TTY the branch is kept with a warning; see `remove.rs`.
+
+use crate::common::pty::{build_pty_command, exec_cmd_in_pty_prompted};
+use crate::common::{TestRepo, repo, wt_bin};
+use rstest::rstest;
+
+/// Remove `branch` in a PTY, answering the unmerged-branch prompt with `choice`.
+fn remove_with_choice(repo: &TestRepo, branch: &str, choice: &str) -> (String, i32) {
+    let cmd = build_pty_command(
+        wt_bin().to_str().unwrap(),
+        &["remove", "--foreground", branch],
+        repo.root_path(),
+        &repo.test_env_vars(),
+        None,
+    );
+    exec_cmd_in_pty_prompted(cmd, &[choice], "[1-3")
+}
+
+fn branch_exists(repo: &TestRepo, branch: &str) -> bool {
+    repo.git_command()
+        .args([
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/heads/{branch}"),
+        ])
+        .run()
+        .unwrap()
+        .status
+        .success()
+}
+
+#[rstest]
+fn test_remove_unmerged_archive_then_delete(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature, "a.txt", "a", "Add a");
+    repo.commit_in_worktree(&feature, "b.txt", "b", "Add b");
+    let tip = repo.git_output(&["rev-parse", "--short", "feature"]);
+
+    let (output, exit_code) = remove_with_choice(&repo, "feature", "2\n");
+    assert_eq!(exit_code, 0, "{output}");
+    assert!(output.contains("has 2 commits not in"), "{output}");
+    assert!(output.contains("Archived 2 commits of"), "{output}");
+    assert!(!feature.exists());
+    assert!(!branch_exists(&repo, "feature"));
+
+    // The bundle, named after the branch and its tip, restores the branch
+    let bundles = repo.root_path().join(".git/wt/bundles");
+    let bundle = bundles.join(format!("feature-{tip}.bundle"));
+    assert!(bundle.exists(), "{output}");
+    repo.run_git(&["fetch", bundle.to_str().unwrap(), "feature:feature"]);
+    assert!(branch_exists(&repo, "feature"));
+
+    // Archiving the same branch again keeps the first archive
+    repo.run_git(&["worktree", "add", feature.to_str().unwrap(), "feature"]);
+    let (output, exit_code) = remove_with_choice(&repo, "feature", "2\n");
+    assert_eq!(exit_code, 0, "{output}");
+    assert!(bundle.exists(), "{output}");
+    assert!(
+        bundles.join(format!("feature-{tip}-2.bundle")).exists(),
+        "{output}"
+    );
+}
+
+#[rstest]
+fn test_remove_unmerged_merge_first_skips_removal(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature, "a.txt", "a", "Add a");
+
+    let (output, exit_code) = remove_with_choice(&repo, "feature", "1\n");
+    assert_eq!(exit_code, 0, "{output}");
+    assert!(output.contains("Skipped removing"), "{output}");
+    assert!(output.contains("merge main"), "{output}");
+    assert!(feature.exists());
+    assert!(branch_exists(&repo, "feature"));
+}
@@ -0,0 +1,55 @@
+//! Integration tests for `wt repair`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+use std::path::Path;
+
+/// Point a worktree's `.git` file at where the repository used to live.
+fn unlink(worktree: &Path) {
+    let dot_git = worktree.join(".git");
+    let content = fs::read_to_string(&dot_git).unwrap();
+    let (_, id) = content.trim().rsplit_once("/worktrees/").unwrap();
+    fs::write(
+        &dot_git,
+        format!("gitdir: /old/location/repo/.git/worktrees/{id}\n"),
+    )
+    .unwrap();
+}
+
+#[rstest]
+fn test_repair_relinks_worktrees(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let other = repo.add_worktree("other");
+    unlink(&feature);
+    unlink(&other);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "repair", &[], None));
+    for worktree in [&feature, &other] {
+        let content = fs::read_to_string(worktree.join(".git")).unwrap();
+        assert!(!content.contains("/old/location/"), "{content}");
+    }
+}
+
+#[rstest]
+fn test_repair_nothing_to_do(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "repair", &[], None));
+}
+
+/// Any command notices unlinked worktrees and points at `wt repair`.
+#[rstest]
+fn test_moved_repository_warns(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    unlink(&feature);
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "map", &[], None));
+}
+
+/// From a worktree git can no longer open, the warning names the missing path.
+#[rstest]
+fn test_disconnected_worktree_warns(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    unlink(&feature);
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "map", &[], Some(&feature)));
+}
@@ -0,0 +1,114 @@
+//! Integration tests for `[repo-groups]` and `--group`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+/// Group `backend` holds `repo` by its `[repos]` name and `other` by path.
+fn write_group_config(repo: &TestRepo, other: &TestRepo) {
+    repo.write_test_config(&format!(
+        r#"
+[repos]
+api = "{}"
+
+[repo-groups]
+backend = ["api", "{}"]
+solo = ["api"]
+"#,
+        repo.root_path().display(),
+        other.root_path().display(),
+    ));
+}
+
+fn run_json(repo: &TestRepo, args: &[&str]) -> serde_json::Value {
+    let output = repo.wt_command().args(args).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[rstest]
+fn test_list_group_json(repo: TestRepo) {
+    let other = TestRepo::new();
+    write_group_config(&repo, &other);
+
+    let groups = run_json(&repo, &["list", "--group", "backend", "--format=json"]);
+    let groups = groups.as_array().unwrap();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0]["repo"], "api");
+    assert_eq!(groups[0]["worktrees"].as_array().unwrap().len(), 4);
+    assert_eq!(
+        groups[1]["repo"],
+        other.root_path().display().to_string().as_str()
+    );
+    assert_eq!(groups[1]["worktrees"].as_array().unwrap().len(), 1);
+}
+
+#[rstest]
+fn test_list_group_table(repo: TestRepo) {
+    let other = TestRepo::new();
+    write_group_config(&repo, &other);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "list", &["--group", "solo"], None));
+}
+
+/// `for-each --group` visits every worktree of every repository, tagging
+/// each JSON result with its group entry.
+#[rstest]
+fn test_for_each_group(repo: TestRepo) {
+    let other = TestRepo::new();
+    write_group_config(&repo, &other);
+
+    let results = run_json(
+        &repo,
+        &[
+            "step",
+            "for-each",
+            "--group",
+            "backend",
+            "--format=json",
+            "--",
+            "git",
+            "status",
+            "--short",
+        ],
+    );
+    let repos: Vec<&str> = results
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| result["repo"].as_str().unwrap())
+        .collect();
+    let other_path = other.root_path().display().to_string();
+    assert_eq!(repos, ["api", "api", "api", "api", other_path.as_str()]);
+}
+
+#[rstest]
+fn test_group_unknown(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["for-each", "--group", "missing", "--", "true"],
+        None
+    ));
+}
+
+#[rstest]
+fn test_group_entry_not_a_repo(repo: TestRepo) {
+    repo.write_test_config(
+        r#"
+[repo-groups]
+broken = ["/nonexistent/worktrunk-group-entry"]
+"#,
+    );
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "list",
+        &["--group", "broken"],
+        None
+    ));
+}
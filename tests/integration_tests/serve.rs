@@ -0,0 +1,108 @@
+//! Tests for `wt serve`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use rstest::rstest;
+use serde_json::{Value, json};
+use std::io::Write;
+use std::process::Stdio;
+
+/// Send `requests` to `wt serve` (one per line) and return its responses.
+fn serve(repo: &TestRepo, args: &[&str], requests: &[Value]) -> Vec<Value> {
+    let mut cmd = make_snapshot_cmd(repo, "serve", args, None);
+    cmd.stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let mut child = cmd.spawn().unwrap();
+    {
+        let stdin = child.stdin.as_mut().unwrap();
+        for request in requests {
+            writeln!(stdin, "{request}").unwrap();
+        }
+    }
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+fn call(id: u64, name: &str, arguments: Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": "tools/call",
+        "params": { "name": name, "arguments": arguments },
+    })
+}
+
+#[rstest]
+fn test_serve_lists_allowed_tools(repo: TestRepo) {
+    let responses = serve(
+        &repo,
+        &[],
+        &[
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+            json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" }),
+        ],
+    );
+
+    // The notification gets no response
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0]["result"]["serverInfo"]["name"], "worktrunk");
+    let tools: Vec<&str> = responses[1]["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(tools, ["list", "prune-candidates"]);
+}
+
+#[rstest]
+fn test_serve_calls_tools(repo: TestRepo) {
+    let responses = serve(
+        &repo,
+        &["--allow", "list,switch"],
+        &[
+            call(1, "switch", json!({ "branch": "feature", "create": true })),
+            call(2, "list", json!({})),
+            call(3, "remove", json!({ "branches": ["feature"] })),
+            call(4, "switch", json!({ "branch": "missing" })),
+        ],
+    );
+
+    let switched = &responses[0]["result"];
+    assert_eq!(switched["isError"], false);
+    assert_eq!(switched["structuredContent"]["output"]["action"], "created");
+    assert_eq!(switched["structuredContent"]["output"]["branch"], "feature");
+
+    let listed = &responses[1]["result"]["structuredContent"]["output"];
+    let branches: Vec<&str> = listed
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["branch"].as_str().unwrap())
+        .collect();
+    assert!(branches.contains(&"feature"), "{branches:?}");
+
+    // Not on the allow list: a protocol error, and nothing is removed
+    assert_eq!(responses[2]["error"]["code"], -32602);
+    let path = switched["structuredContent"]["output"]["path"]
+        .as_str()
+        .unwrap();
+    assert!(std::path::Path::new(path).exists());
+
+    // A failing command is a tool error carrying its message
+    let failed = &responses[3]["result"];
+    assert_eq!(failed["isError"], true);
+    assert!(
+        failed["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("No branch named missing"),
+        "{failed}"
+    );
+}
@@ -0,0 +1,52 @@
+use crate::common::{
+    TestRepo, repo, set_temp_home_env, set_xdg_config_path, setup_snapshot_settings_with_home,
+    temp_home, wt_command,
+};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+use tempfile::TempDir;
+
+/// `--yes` runs every step with its default: installs shell integration for
+/// the shells that have config files, keeps the worktree-path, and tests the
+/// configured LLM command.
+#[rstest]
+fn test_setup_yes(mut repo: TestRepo, temp_home: TempDir) {
+    repo.setup_mock_ci_tools_unauthenticated();
+    fs::write(temp_home.path().join(".zshrc"), "# Existing config\n").unwrap();
+    let config_dir = temp_home.path().join(".config").join("worktrunk");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("config.toml"),
+        "[commit.generation]\ncommand = \"echo 'feat: add setup wizard'\"\n",
+    )
+    .unwrap();
+
+    let settings = setup_snapshot_settings_with_home(&repo, &temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        repo.configure_mock_commands(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        set_xdg_config_path(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/zsh");
+        cmd.args(["setup", "--yes"]).current_dir(repo.root_path());
+        assert_cmd_snapshot!(cmd);
+    });
+
+    let zshrc = fs::read_to_string(temp_home.path().join(".zshrc")).unwrap();
+    assert!(zshrc.contains("wt config shell init zsh"), "{zshrc}");
+}
+
+/// Without `--yes`, setup refuses to run when it can't prompt.
+#[rstest]
+fn test_setup_requires_terminal(repo: TestRepo, temp_home: TempDir) {
+    let settings = setup_snapshot_settings_with_home(&repo, &temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.arg("setup").current_dir(repo.root_path());
+        assert_cmd_snapshot!(cmd);
+    });
+}
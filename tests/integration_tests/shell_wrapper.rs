@@ -867,8 +867,8 @@ mod unix_tests {
 
         // Shell-agnostic assertions: these must be true for ALL shells
         assert_eq!(
-            output.exit_code, 1,
-            "{}: Command should fail with exit code 1",
+            output.exit_code, 3,
+            "{}: Command should fail with exit code 3",
             shell
         );
         output.assert_no_directive_leaks();
@@ -1068,6 +1068,75 @@ mod unix_tests {
         });
     }
 
+    /// Test that the wrapper bypasses directives inside a command substitution.
+    ///
+    /// `$(wt ...)` runs the wrapper where a cd can't (bash/zsh) or shouldn't
+    /// (fish) move the calling shell. The binary runs directly: `--execute`
+    /// output lands in the substitution, its exit code is the substitution's
+    /// status, the caller stays put, and there's no "cannot change directory"
+    /// warning.
+    #[rstest]
+    #[case("bash")]
+    #[case("zsh")]
+    #[case("fish")]
+    fn test_wrapper_command_substitution(#[case] shell: &str, repo: TestRepo) {
+        let marker_file = repo.root_path().join(".substitution_test_marker");
+        let marker_quoted = shell_quote(&marker_file.to_string_lossy());
+        let execute = shell_quote(r#"echo "ran in $PWD"; exit 3"#);
+
+        let mut script = String::new();
+        append_wrapper_setup(&mut script, shell, &repo);
+        let (capture, status) = match shell {
+            "fish" => (
+                format!("set out (wt switch --create subst --execute {execute})"),
+                "$status",
+            ),
+            _ => (
+                format!("out=$(wt switch --create subst --execute {execute})"),
+                "$?",
+            ),
+        };
+        script.push_str(&format!(
+            "{capture}\nprintf '%s\\n' \"status={status}\" \"out=$out\" \"pwd=$PWD\" > {marker_quoted}\n"
+        ));
+        let final_script = match shell {
+            "fish" => format!("begin\n{script}\nend 2>&1"),
+            _ => format!("( {script} ) 2>&1"),
+        };
+
+        let config_path = repo.test_config_path().to_string_lossy().to_string();
+        let approvals_path = repo.test_approvals_path().to_string_lossy().to_string();
+        let env_vars = build_test_env_vars(&config_path, &approvals_path);
+        let (combined, _) =
+            exec_in_pty_interactive(shell, &final_script, repo.root_path(), &env_vars, &[]);
+
+        wait_for_file_content(&marker_file);
+        let result = fs::read_to_string(&marker_file).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        let worktree =
+            canonicalize(&repo.root_path().parent().unwrap().join("repo.subst")).unwrap();
+        let root = canonicalize(repo.root_path()).unwrap();
+        assert_eq!(lines[0], "status=3", "{shell}: {result}");
+        assert_eq!(
+            canonicalize(std::path::Path::new(
+                lines[1].strip_prefix("out=ran in ").unwrap()
+            ))
+            .unwrap(),
+            worktree,
+            "{shell}: {result}"
+        );
+        assert_eq!(
+            canonicalize(std::path::Path::new(lines[2].strip_prefix("pwd=").unwrap())).unwrap(),
+            root,
+            "{shell}: {result}"
+        );
+        assert!(
+            !combined.contains("Cannot change directory")
+                && !combined.contains("shell integration"),
+            "{shell}: no cd warning expected.\nOutput:\n{combined}"
+        );
+    }
+
     /// Test that --execute command exit codes are propagated
     /// Verifies that when wt succeeds but the --execute command fails,
     /// the wrapper returns the command's exit code, not wt's.
@@ -3858,8 +3927,8 @@ mod windows_tests {
         let output = exec_through_wrapper("powershell", &repo, "switch", &["--create", "existing"]);
 
         assert_eq!(
-            output.exit_code, 1,
-            "PowerShell: Command should fail with exit code 1"
+            output.exit_code, 3,
+            "PowerShell: Command should fail with exit code 3"
         );
         output.assert_no_directive_leaks();
         assert!(
@@ -0,0 +1,125 @@
+//! Integration tests for `wt stats`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::fs;
+
+/// `WORKTRUNK_TEST_EPOCH`: Thursday 2025-01-02.
+const NOW: u64 = 1735776000;
+const DAY: u64 = 24 * 60 * 60;
+
+fn events_path(repo: &TestRepo) -> std::path::PathBuf {
+    repo.root_path().join(".git/wt/stats/events.jsonl")
+}
+
+#[rstest]
+fn test_stats_empty(repo: TestRepo) {
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "stats", &[], None));
+}
+
+#[rstest]
+fn test_stats_table(repo: TestRepo) {
+    repo.write_test_config("[stats]\nenabled = true\n");
+    let events = [
+        (
+            NOW - 9 * DAY,
+            r#""event":"worktree-created","branch":"auth""#,
+        ),
+        (
+            NOW - 8 * DAY,
+            r#""event":"worktree-created","branch":"docs""#,
+        ),
+        (
+            NOW - 7 * DAY,
+            r#""event":"merged","branch":"docs","target":"main""#,
+        ),
+        (
+            NOW - 7 * DAY,
+            r#""event":"worktree-removed","branch":"docs""#,
+        ),
+        (
+            NOW - 2 * DAY,
+            r#""event":"merged","branch":"auth","target":"main""#,
+        ),
+        (
+            NOW - 2 * DAY,
+            r#""event":"worktree-removed","branch":"auth""#,
+        ),
+        (NOW - DAY, r#""event":"worktree-removed""#),
+        (
+            NOW - DAY,
+            r#""event":"command","command":"switch","duration_ms":420,"success":true"#,
+        ),
+        (
+            NOW - DAY,
+            r#""event":"command","command":"switch","duration_ms":180,"success":false"#,
+        ),
+        (
+            NOW,
+            r#""event":"command","command":"merge","duration_ms":2600,"success":true"#,
+        ),
+    ];
+    let path = events_path(&repo);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let content: String = events
+        .iter()
+        .map(|(ts, fields)| format!("{{\"ts\":{ts},{fields}}}\n"))
+        .collect();
+    fs::write(&path, content).unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "stats", &["--weeks", "3"], None));
+    assert_cmd_snapshot!(
+        "stats_json",
+        make_snapshot_cmd(&repo, "stats", &["--weeks", "2", "--format", "json"], None)
+    );
+}
+
+/// With `[stats] enabled`, commands and worktree churn are recorded.
+#[rstest]
+fn test_stats_records_events(repo: TestRepo) {
+    repo.write_test_config("[stats]\nenabled = true\n");
+
+    for args in [
+        &["switch", "--create", "feature", "--no-cd"][..],
+        &["remove", "feature", "--foreground"][..],
+    ] {
+        let output = repo.wt_command().args(args).output().unwrap();
+        assert!(output.status.success(), "{output:?}");
+    }
+
+    let events = fs::read_to_string(events_path(&repo)).unwrap();
+    let events: Vec<serde_json::Value> = events
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    let summary: Vec<String> = events
+        .iter()
+        .map(|event| match event["event"].as_str().unwrap() {
+            "command" => format!("command {}", event["command"].as_str().unwrap()),
+            other => format!("{other} {}", event["branch"].as_str().unwrap()),
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            "worktree-created feature",
+            "command switch",
+            "worktree-removed feature",
+            "command remove",
+        ]
+    );
+    assert!(events.iter().all(|event| event["ts"] == NOW));
+}
+
+/// Without `[stats] enabled`, nothing is written.
+#[rstest]
+fn test_stats_disabled_records_nothing(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature", "--no-cd"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert!(!events_path(&repo).exists());
+}
@@ -0,0 +1,66 @@
+//! Integration tests for `wt step clone`
+
+use std::path::Path;
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+/// Run `wt step clone` from `<home>/clones`, next to the test repository.
+fn clone(repo: &TestRepo, args: &[&str]) -> String {
+    let dir = repo.home_path().join("clones");
+    std::fs::create_dir_all(&dir).unwrap();
+    let output = repo
+        .wt_command()
+        .current_dir(&dir)
+        .args(["step", "clone"])
+        .args(args)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    assert!(output.status.success(), "clone failed: {stderr}");
+    stderr
+}
+
+fn git_config(dir: &Path, key: &str) -> String {
+    let output = std::process::Command::new("git")
+        .args(["config", "--get", key])
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[rstest]
+fn test_clone_with_reference(repo: TestRepo) {
+    repo.commit("initial");
+    let source = repo.root_path().to_string_lossy().into_owned();
+
+    let stderr = clone(&repo, &["--reference", &source, &source, "borrowed"]);
+    assert!(stderr.contains("Borrowing objects from"), "{stderr}");
+
+    let alternates = std::fs::read_to_string(
+        repo.home_path()
+            .join("clones/borrowed/.git/objects/info/alternates"),
+    )
+    .unwrap();
+    assert!(alternates.contains(".git/objects"), "{alternates}");
+}
+
+#[rstest]
+fn test_clone_partial_into_default_directory(repo: TestRepo) {
+    repo.commit("initial");
+    repo.run_git(&["config", "uploadpack.allowFilter", "true"]);
+    let url = format!("file://{}", repo.root_path().display());
+
+    let stderr = clone(&repo, &["--filter", "blob:none", &url]);
+    assert!(stderr.contains("Partial clone"), "{stderr}");
+
+    // `git clone` would name the directory after the source repository.
+    let name = repo.root_path().file_name().unwrap();
+    let cloned = repo.home_path().join("clones").join(name).join(".git");
+    assert!(cloned.is_dir());
+    assert_eq!(
+        git_config(&cloned, "remote.origin.partialclonefilter"),
+        "blob:none"
+    );
+}
@@ -199,3 +199,49 @@ fn git_status(repo: &TestRepo, dir: &Path) -> String {
         .unwrap();
     String::from_utf8_lossy(&output.stdout).to_string()
 }
+
+/// `diff-branches` compares two worktrees' on-disk states, including
+/// uncommitted and untracked files, without touching either index
+#[rstest]
+fn test_step_diff_branches_uncommitted(mut repo: TestRepo) {
+    let a = repo.add_worktree("attempt-a");
+    let b = repo.add_worktree("attempt-b");
+    fs::write(a.join("shared.txt"), "from a\n").unwrap();
+    fs::write(b.join("shared.txt"), "from b\n").unwrap();
+    repo.run_git_in(&b, &["add", "shared.txt"]);
+    fs::write(b.join("only-b.txt"), "untracked\n").unwrap();
+    let settings = setup_snapshot_settings(&repo);
+    let _guard = settings.bind_to_scope();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["diff-branches", "attempt-a", "attempt-b"],
+        None,
+    ));
+
+    // Neither worktree's index picked up the snapshot
+    let status = repo
+        .git_command()
+        .args(["-C", a.to_str().unwrap(), "status", "--porcelain"])
+        .run()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&status.stdout), "?? shared.txt\n");
+}
+
+/// A branch without a worktree contributes its commit
+#[rstest]
+fn test_step_diff_branches_branch_without_worktree(mut repo: TestRepo) {
+    let feature_path = setup_feature_with_commit(&mut repo);
+    repo.run_git(&["branch", "plain"]);
+    fs::write(feature_path.join("feature.txt"), "edited").unwrap();
+    let settings = setup_snapshot_settings(&repo);
+    let _guard = settings.bind_to_scope();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["diff-branches", "plain", "feature", "--", "--stat"],
+        None,
+    ));
+}
@@ -0,0 +1,43 @@
+//! Integration tests for `wt step optimize-repo`
+
+use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+
+#[rstest]
+fn test_optimize_repo(repo: TestRepo) {
+    repo.commit("initial");
+    repo.run_git(&["repack", "-q"]);
+
+    let _settings_guard = setup_snapshot_settings(&repo).bind_to_scope();
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "step", &["optimize-repo"], None));
+
+    let objects = repo.root_path().join(".git/objects");
+    assert!(objects.join("info/commit-graph").is_file());
+    assert!(objects.join("pack/multi-pack-index").is_file());
+    assert_eq!(
+        repo.git_output(&["config", "--get", "fetch.writeCommitGraph"]),
+        "true"
+    );
+}
+
+#[rstest]
+fn test_optimize_repo_without_packs(repo: TestRepo) {
+    repo.commit("initial");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "optimize-repo"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "optimize-repo failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Loose objects only: the commit-graph is written, a multi-pack-index can't be.
+    let objects = repo.root_path().join(".git/objects");
+    assert!(objects.join("info/commit-graph").is_file());
+    assert!(!objects.join("pack/multi-pack-index").exists());
+}
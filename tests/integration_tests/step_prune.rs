@@ -78,6 +78,27 @@ fn test_prune_removes_merged(mut repo: TestRepo) {
     assert!(!worktree_path.exists(), "Worktree should be fully removed");
 }
 
+/// Prune sweeps state left behind by a branch deleted outside wt
+#[rstest]
+fn test_prune_sweeps_deleted_branch_state(repo: TestRepo) {
+    repo.commit("initial");
+    repo.run_git(&["config", "worktrunk.state.gone.marker", "{}"]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["prune", "--yes", "--min-age=0s"],
+        None
+    ));
+
+    let output = repo
+        .git_command()
+        .args(["config", "--get", "worktrunk.state.gone.marker"])
+        .run()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
 /// Prune skips worktrees with unique commits (not merged)
 #[rstest]
 fn test_prune_skips_unmerged(mut repo: TestRepo) {
@@ -133,6 +154,61 @@ fn test_prune_min_age_skips_young(mut repo: TestRepo) {
     assert!(worktree_path.exists(), "Young worktree should be skipped");
 }
 
+/// An integrated branch whose upstream is gone (deleted on the remote, then
+/// `git fetch --prune`) bypasses the min-age guard; a young branch that was
+/// never pushed keeps it.
+#[rstest]
+fn test_prune_upstream_gone_bypasses_min_age(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let gone_wt = repo.add_worktree("shipped");
+    repo.run_git_in(&gone_wt, &["push", "-u", "origin", "shipped"]);
+    repo.run_git(&["push", "origin", "--delete", "shipped"]);
+    repo.run_git(&["fetch", "--prune", "origin"]);
+    repo.add_worktree("young-branch");
+
+    // Default min-age (1d) — both worktrees are "young" due to the test epoch
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["prune", "--dry-run"],
+        None
+    ));
+}
+
+/// `[step.prune]` rules: `keep` hides a branch from prune, a rule `min-age`
+/// replaces the 1d default, and unmatched branches keep the default.
+#[rstest]
+fn test_prune_rules(mut repo: TestRepo) {
+    repo.commit("initial");
+    repo.write_project_config(
+        r#"
+[[step.prune.rules]]
+branch = "release/*"
+keep = true
+
+[[step.prune.rules]]
+branch = "dependabot/*"
+min-age = "0s"
+"#,
+    );
+
+    repo.add_worktree("release/1.0");
+    repo.add_worktree("dependabot/npm");
+    repo.add_worktree("young-branch");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["prune", "--dry-run"],
+        None
+    ));
+
+    // An explicit --min-age overrides the rule's age but not `keep`
+    assert_cmd_snapshot!(
+        "prune_rules_min_age_flag",
+        make_snapshot_cmd(&repo, "step", &["prune", "--dry-run", "--min-age=1h"], None)
+    );
+}
+
 /// Prune multiple merged worktrees at once
 #[rstest]
 fn test_prune_multiple(mut repo: TestRepo) {
@@ -971,6 +1047,55 @@ fn test_prune_json_orphan_branch(repo: TestRepo) {
     assert_snapshot!(String::from_utf8_lossy(&output.stdout));
 }
 
+/// `--report=<file>.md` records the pruned worktree and branch with commit,
+/// reason, and size.
+#[rstest]
+fn test_prune_report_markdown(mut repo: TestRepo) {
+    repo.commit("initial");
+    repo.add_worktree("merged-a");
+    repo.create_branch("orphan-integrated");
+    let report = repo.root_path().parent().unwrap().join("prune.md");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "prune", "--min-age=0s", "--yes", "--foreground"])
+        .arg(format!("--report={}", report.display()))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    assert_snapshot!(std::fs::read_to_string(&report).unwrap());
+}
+
+/// `--report=-` writes JSON to stdout, and with `--dry-run` lists what would
+/// be pruned without removing it.
+#[rstest]
+fn test_prune_report_json_dry_run(mut repo: TestRepo) {
+    repo.commit("initial");
+    let wt_path = repo.add_worktree("merged-a");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "prune", "--dry-run", "--min-age=0s", "--report=-"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["dry_run"], true);
+    assert_eq!(report["pruned"][0]["branch"], "merged-a");
+    assert_eq!(report["pruned"][0]["reason"], "same-commit");
+    assert!(report["bytes"].as_u64().unwrap() > 0);
+    assert!(wt_path.exists(), "Dry run should not remove worktrees");
+
+    // Both would write to stdout.
+    let output = repo
+        .wt_command()
+        .args(["step", "prune", "--format=json", "--report=-"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
 /// Regression: `wt step prune` ORs over local AND upstream like `wt remove` /
 /// `wt list`. A worktree merged into LOCAL `main` must still be pruned when
 /// `main` and `origin/main` have diverged. Mirrors
@@ -1507,3 +1632,41 @@ exec {real_git} "$@"
     permissions.set_mode(0o755);
     std::fs::set_permissions(&path, permissions).unwrap();
 }
+
+/// Prune skips a merged branch that stashes were made on; --yes exports them
+/// and prunes it.
+#[rstest]
+fn test_prune_skips_branch_with_stashes(mut repo: TestRepo) {
+    repo.commit("initial");
+    let worktree_path = repo.add_worktree("stashed-branch");
+    std::fs::write(worktree_path.join("notes.txt"), "idea").unwrap();
+    repo.run_git_in(
+        &worktree_path,
+        &["stash", "push", "--include-untracked", "-m", "idea"],
+    );
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["prune", "--min-age=0s"],
+        None
+    ));
+    assert!(worktree_path.exists());
+
+    let output = repo
+        .wt_command()
+        .args(["step", "prune", "--yes", "--min-age=0s", "--foreground"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!worktree_path.exists());
+    assert!(
+        repo.root_path()
+            .join(".git/wt/stashes/stashed-branch")
+            .is_dir()
+    );
+}
@@ -0,0 +1,108 @@
+//! Integration tests for `wt step revert-merge`
+
+use crate::common::{TestRepo, make_snapshot_cmd, merge_scenario, repo, setup_snapshot_settings};
+use insta_cmd::assert_cmd_snapshot;
+use rstest::rstest;
+use std::path::PathBuf;
+use worktrunk::git::Repository;
+
+fn merge_feature(repo: &TestRepo, feature_wt: &PathBuf) {
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--yes"])
+        .current_dir(feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "merge failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[rstest]
+fn test_revert_merge_squash(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+    merge_feature(&repo, &feature_wt);
+    assert!(repo.root_path().join("feature.txt").exists());
+
+    let _settings_guard = setup_snapshot_settings(&repo).bind_to_scope();
+    assert_cmd_snapshot!(make_snapshot_cmd(&repo, "step", &["revert-merge"], None));
+
+    assert!(!repo.root_path().join("feature.txt").exists());
+    assert!(
+        repo.git_output(&["log", "-1", "--format=%s", "main"])
+            .starts_with("Revert ")
+    );
+
+    // The record is consumed — a second revert has nothing to undo.
+    assert_cmd_snapshot!(
+        "revert_merge_nothing_recorded",
+        make_snapshot_cmd(&repo, "step", &["revert-merge"], None)
+    );
+}
+
+#[rstest]
+fn test_revert_merge_restore(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+    let feature_head = repo.git_output(&["rev-parse", "feature"]);
+    std::fs::write(feature_wt.join("wip.txt"), "work in progress").unwrap();
+    merge_feature(&repo, &feature_wt);
+
+    let _settings_guard = setup_snapshot_settings(&repo).bind_to_scope();
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["revert-merge", "feature", "--restore"],
+        None
+    ));
+
+    // The branch is back at its pre-merge tip, with the uncommitted file.
+    assert_eq!(repo.git_output(&["rev-parse", "feature"]), feature_head);
+    let path = Repository::at(repo.root_path())
+        .unwrap()
+        .worktree_for_branch("feature")
+        .unwrap()
+        .expect("feature worktree restored");
+    assert_eq!(
+        std::fs::read_to_string(path.join("wip.txt")).unwrap(),
+        "work in progress"
+    );
+    assert!(!repo.root_path().join("feature.txt").exists());
+}
+
+#[rstest]
+fn test_revert_merge_no_ff(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-squash", "--no-ff", "--yes"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let output = repo
+        .wt_command()
+        .args(["step", "revert-merge", "feature"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "revert failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!repo.root_path().join("feature.txt").exists());
+}
+
+#[rstest]
+fn test_revert_merge_unknown_branch(repo: TestRepo) {
+    let _settings_guard = setup_snapshot_settings(&repo).bind_to_scope();
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "step",
+        &["revert-merge", "nope"],
+        None
+    ));
+}
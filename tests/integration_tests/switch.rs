@@ -1,5 +1,5 @@
 use crate::common::{
-    SLEEP_FOR_ABSENCE_CHECK, TestRepo, configure_directive_files, directive_files,
+    SLEEP_FOR_ABSENCE_CHECK, TestRepo, add_submodule, configure_directive_files, directive_files,
     make_snapshot_cmd, make_snapshot_cmd_with_global_flags, repo, repo_with_remote,
     set_temp_home_env, setup_home_snapshot_settings, setup_snapshot_settings, temp_home,
     wait_for_file_content, wt_command,
@@ -130,6 +130,28 @@ fn test_switch_create_existing_branch_error(mut repo: TestRepo) {
     );
 }
 
+/// `--quiet` drops the progress/success/hint lines a successful switch prints.
+#[rstest]
+fn test_switch_create_quiet(repo: TestRepo) {
+    snapshot_switch(
+        "switch_create_quiet",
+        &repo,
+        &["--quiet", "--create", "feature-q"],
+    );
+}
+
+/// `--quiet` keeps the error (and its gutter), and a validation failure exits 3.
+#[rstest]
+fn test_switch_create_existing_quiet(mut repo: TestRepo) {
+    repo.add_worktree("feature-y");
+
+    snapshot_switch(
+        "switch_create_existing_quiet",
+        &repo,
+        &["--quiet", "--create", "feature-y"],
+    );
+}
+
 /// When --execute is passed and the branch already exists, the error hint should
 /// include --execute and trailing args in the suggested command.
 #[rstest]
@@ -391,6 +413,233 @@ fn test_switch_create_from_remote_base_no_upstream(#[from(repo_with_remote)] rep
     );
 }
 
+/// A remote-tracking `--base` is fetched before branching when the last fetch
+/// is stale, so the new branch starts from the upstream tip; `fetch-base =
+/// "off"` and offline mode branch from the ref as it is.
+#[rstest]
+#[case::stale(None, true)]
+#[case::off(Some("[switch]\nfetch-base = \"off\"\n"), false)]
+#[case::offline(Some("network = false\n"), false)]
+fn test_switch_create_fetches_stale_remote_base(
+    #[from(repo_with_remote)] repo: TestRepo,
+    #[case] config: Option<&str>,
+    #[case] fetched: bool,
+) {
+    if let Some(config) = config {
+        repo.write_test_config(config);
+    }
+    let stale = repo.git_output(&["rev-parse", "origin/main"]);
+    // Advance the remote by URL, which leaves `origin/main` behind.
+    repo.commit("upstream change");
+    let upstream = repo.git_output(&["rev-parse", "HEAD"]);
+    let remote = repo.remote_path().unwrap().to_str().unwrap().to_string();
+    repo.run_git(&["push", &remote, "main"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "fresh", "--base", "origin/main"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let expected = if fetched { &upstream } else { &stale };
+    assert_eq!(&repo.git_output(&["rev-parse", "fresh"]), expected);
+}
+
+/// A local base more than `max-base-behind` commits behind its upstream is
+/// fast-forwarded before branching with `--yes`; without it, a non-interactive
+/// switch only warns and branches from the base as it is.
+#[rstest]
+#[case::yes(true)]
+#[case::non_interactive(false)]
+fn test_switch_create_fast_forwards_stale_base(
+    #[from(repo_with_remote)] repo: TestRepo,
+    #[case] yes: bool,
+) {
+    repo.commit("upstream one");
+    repo.commit("upstream two");
+    let upstream = repo.git_output(&["rev-parse", "HEAD"]);
+    repo.run_git(&["push", "origin", "main"]);
+    repo.run_git(&["reset", "--hard", "HEAD~2"]);
+    let stale = repo.git_output(&["rev-parse", "HEAD"]);
+    repo.write_project_config("[switch]\nmax-base-behind = 1\n");
+
+    let mut cmd = repo.wt_command();
+    cmd.args(["switch", "--create", "fresh"]);
+    if yes {
+        cmd.arg("--yes");
+    }
+    let output = cmd.output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("is 2 commits behind"),
+        "missing warning: {stderr}"
+    );
+
+    let expected = if yes { &upstream } else { &stale };
+    assert_eq!(&repo.git_output(&["rev-parse", "main"]), expected);
+    assert_eq!(&repo.git_output(&["rev-parse", "fresh"]), expected);
+}
+
+/// `max-base-behind` fetches a stale upstream before comparing, except
+/// offline, where it compares against the upstream as last fetched.
+#[rstest]
+#[case::online(false)]
+#[case::offline(true)]
+fn test_switch_create_base_behind_offline(
+    #[from(repo_with_remote)] repo: TestRepo,
+    #[case] offline: bool,
+) {
+    let stale = repo.git_output(&["rev-parse", "origin/main"]);
+    // Advance the remote by URL, which leaves `origin/main` behind.
+    repo.commit("upstream change");
+    let remote = repo.remote_path().unwrap().to_str().unwrap().to_string();
+    repo.run_git(&["push", &remote, "main"]);
+    repo.run_git(&["reset", "--hard", &stale]);
+    repo.write_project_config("[switch]\nmax-base-behind = 0\n");
+    if offline {
+        repo.write_test_config("network = false\n");
+    }
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "fresh"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert_eq!(stderr.contains("is 1 commit behind"), !offline, "{stderr}");
+    assert_eq!(
+        repo.git_output(&["rev-parse", "origin/main"]) == stale,
+        offline
+    );
+}
+
+/// `--track` and `[switch] track` choose the upstream of a new branch; the
+/// flags override the config.
+#[rstest]
+#[case::base_flag(None, &["--track", "base"], "main")]
+#[case::remote_flag(None, &["--track", "remote"], "origin/tracked")]
+#[case::remote_config(Some("remote"), &[], "origin/tracked")]
+#[case::no_track_over_config(Some("base"), &["--no-track"], "")]
+fn test_switch_create_track(
+    #[from(repo_with_remote)] repo: TestRepo,
+    #[case] config: Option<&str>,
+    #[case] flags: &[&str],
+    #[case] expected: &str,
+) {
+    if let Some(mode) = config {
+        repo.write_test_config(&format!("[switch]\ntrack = \"{mode}\"\n"));
+    }
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "tracked"])
+        .args(flags)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        repo.git_output(&[
+            "for-each-ref",
+            "--format=%(upstream:short)",
+            "refs/heads/tracked"
+        ]),
+        expected
+    );
+}
+
+#[rstest]
+fn test_switch_create_track_message(#[from(repo_with_remote)] repo: TestRepo) {
+    assert_cmd_snapshot!(
+        "switch_create_track_remote",
+        make_snapshot_cmd(
+            &repo,
+            "switch",
+            &["--create", "tracked", "--track", "remote"],
+            None
+        )
+    );
+}
+
+/// With `auto-wip`, leaving a dirty worktree parks its changes in a WIP
+/// commit, and coming back soft-resets it.
+#[rstest]
+fn test_switch_auto_wip_round_trip(mut repo: TestRepo) {
+    repo.write_test_config("[switch]\nauto-wip = true\n");
+    let feature = repo.add_worktree("feature");
+    let head = repo.git_output(&["rev-parse", "HEAD"]);
+    fs::write(repo.root_path().join("notes.txt"), "draft").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "feature"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%s"]),
+        "wip: saved by wt switch"
+    );
+    assert_eq!(repo.git_output(&["status", "--porcelain"]), "");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "main"])
+        .current_dir(&feature)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(repo.git_output(&["rev-parse", "HEAD"]), head);
+    assert_eq!(repo.git_output(&["status", "--porcelain"]), "A  notes.txt");
+}
+
+/// A WIP commit that fails (here, signing) leaves the staged and unstaged
+/// changes as they were.
+#[rstest]
+fn test_switch_auto_wip_failed_commit_keeps_index(mut repo: TestRepo) {
+    repo.write_test_config("[switch]\nauto-wip = true\n");
+    repo.add_worktree("feature");
+    fs::write(repo.root_path().join("staged.txt"), "staged").unwrap();
+    repo.run_git(&["add", "staged.txt"]);
+    fs::write(repo.root_path().join("untracked.txt"), "untracked").unwrap();
+    repo.run_git(&["config", "commit.gpgsign", "true"]);
+    repo.run_git(&["config", "gpg.program", "false"]);
+    let head = repo.git_output(&["rev-parse", "HEAD"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "feature"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("Could not save uncommitted changes"),
+        "{stderr}"
+    );
+    assert_eq!(repo.git_output(&["rev-parse", "HEAD"]), head);
+    assert_eq!(
+        repo.git_output(&["status", "--porcelain"]),
+        "A  staged.txt\n?? untracked.txt"
+    );
+}
+
 /// When local branch already exists and tracks a remote, should report
 /// "Created worktree for X" NOT "Created branch X (tracking remote)".
 /// This is the bug fix for GitHub issue #656.
@@ -538,6 +787,29 @@ fn test_switch_nonexistent_branch_with_fetch_time(repo: TestRepo) {
     });
 }
 
+#[rstest]
+fn test_switch_partial_branch_name(repo: TestRepo) {
+    repo.run_git(&["branch", "feature/login-page"]);
+    repo.run_git(&["branch", "fix/typo"]);
+    snapshot_switch("switch_partial_branch_name", &repo, &["LOGIN"]);
+}
+
+#[rstest]
+fn test_switch_partial_worktree_dir_name(mut repo: TestRepo) {
+    // `repo.alpha` is the worktree directory; no branch contains "repo."
+    repo.add_worktree("alpha");
+    repo.add_worktree("beta");
+    snapshot_switch("switch_partial_worktree_dir_name", &repo, &["repo.alp"]);
+}
+
+#[rstest]
+fn test_switch_partial_branch_name_ambiguous(repo: TestRepo) {
+    // Without a terminal there's no prompt, so several matches are an error
+    repo.run_git(&["branch", "feature/login-form"]);
+    repo.run_git(&["branch", "fix/login-redirect"]);
+    snapshot_switch("switch_partial_branch_name_ambiguous", &repo, &["login"]);
+}
+
 #[rstest]
 fn test_switch_base_accepts_commitish(repo: TestRepo) {
     // Issue #630: --base should accept any commit-ish, not just branch names
@@ -767,36 +1039,118 @@ fn test_switch_execute_argv_deprecation_warning(repo: TestRepo) {
 }
 // Error tests
 #[rstest]
-fn test_switch_error_missing_worktree_directory(mut repo: TestRepo) {
+fn test_switch_missing_worktree_directory_recreates(mut repo: TestRepo) {
     let wt_path = repo.add_worktree("missing-wt");
 
     // Remove the worktree directory (but leave it registered in git)
     std::fs::remove_dir_all(&wt_path).unwrap();
 
-    // Try to switch to the missing worktree (should fail)
+    // The stale entry is pruned and the worktree recreated
+    snapshot_switch("switch_missing_directory_recreates", &repo, &["missing-wt"]);
+    assert!(wt_path.join(".git").is_file());
+}
+
+/// A removal interrupted after deleting the worktree's `.git` file leaves its
+/// entry behind; switching prunes that entry alone and recreates the worktree.
+#[rstest]
+fn test_switch_recovers_interrupted_removal(mut repo: TestRepo) {
+    let wt_path = repo.add_worktree("half-removed");
+    let other_path = repo.add_worktree("other-missing");
+    fs::remove_file(wt_path.join(".git")).unwrap();
+    fs::remove_dir_all(&other_path).unwrap();
+
+    snapshot_switch(
+        "switch_recovers_interrupted_removal",
+        &repo,
+        &["--clobber", "half-removed"],
+    );
+    assert!(wt_path.join(".git").is_file());
+    // The other stale entry is left for `git worktree prune`
+    let list = repo
+        .git_command()
+        .args(["worktree", "list", "--porcelain"])
+        .run()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&list.stdout).contains("prunable"));
+}
+
+#[rstest]
+fn test_switch_error_missing_worktree_directory_locked(mut repo: TestRepo) {
+    let wt_path = repo.add_worktree("missing-wt");
+    repo.run_git(&["worktree", "lock", wt_path.to_str().unwrap()]);
+    std::fs::remove_dir_all(&wt_path).unwrap();
+
+    // A locked worktree is expected to be missing at times; git keeps it
     snapshot_switch("switch_error_missing_directory", &repo, &["missing-wt"]);
 }
 
-/// Test error when target path is registered to a worktree whose directory is missing.
+/// Test creating at a path registered to a worktree whose directory is missing.
 ///
 /// Scenario: branch "feature/collision" has a worktree at "repo.feature-collision",
-/// but the directory was deleted. Trying to create "feature-collision" (which maps
-/// to the same path) should error about the missing worktree, not try to overwrite.
+/// but the directory was deleted. Creating "feature-collision" (which maps to the
+/// same path) prunes the stale entry and reuses the path.
 #[rstest]
-fn test_switch_error_path_occupied_by_missing_worktree(mut repo: TestRepo) {
+fn test_switch_path_occupied_by_missing_worktree(mut repo: TestRepo) {
     // Create a worktree for "feature/collision" -> path "repo.feature-collision"
     let wt_path = repo.add_worktree("feature/collision");
 
     // Delete the worktree directory (but leave it registered in git)
     std::fs::remove_dir_all(&wt_path).unwrap();
 
-    // Try to create "feature-collision" which maps to the same path
-    // Should fail because the path is registered to a missing worktree
     snapshot_switch(
-        "switch_error_path_occupied_missing",
+        "switch_path_occupied_missing",
         &repo,
         &["--create", "feature-collision"],
     );
+    let head = repo
+        .git_command()
+        .args(["-C", wt_path.to_str().unwrap(), "branch", "--show-current"])
+        .run()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&head.stdout).trim(),
+        "feature-collision"
+    );
+}
+
+/// A `.git` file pointing at a moved repository is relinked with `git worktree repair`.
+#[rstest]
+fn test_switch_repairs_stale_gitdir_link(mut repo: TestRepo) {
+    let wt_path = repo.add_worktree("relink");
+    let dot_git = wt_path.join(".git");
+    let content = fs::read_to_string(&dot_git).unwrap();
+    let (_, id) = content.trim().rsplit_once("/worktrees/").unwrap();
+    fs::write(
+        &dot_git,
+        format!("gitdir: /nonexistent/repo/.git/worktrees/{id}\n"),
+    )
+    .unwrap();
+
+    snapshot_switch("switch_repairs_stale_gitdir", &repo, &["relink"]);
+    let repaired = fs::read_to_string(&dot_git).unwrap();
+    assert!(!repaired.contains("/nonexistent/"), "{repaired}");
+}
+
+/// A leftover `index.lock` is removed once stale; a fresh one only warns.
+#[rstest]
+fn test_switch_clears_stale_index_lock(mut repo: TestRepo) {
+    let wt_path = repo.add_worktree("locked-index");
+    let content = fs::read_to_string(wt_path.join(".git")).unwrap();
+    let lock = Path::new(content.trim().strip_prefix("gitdir: ").unwrap()).join("index.lock");
+
+    fs::write(&lock, "").unwrap();
+    snapshot_switch("switch_warns_fresh_index_lock", &repo, &["locked-index"]);
+    assert!(lock.exists());
+
+    let two_hours_ago = std::time::SystemTime::now() - std::time::Duration::from_secs(2 * 60 * 60);
+    fs::File::options()
+        .write(true)
+        .open(&lock)
+        .unwrap()
+        .set_modified(two_hours_ago)
+        .unwrap();
+    snapshot_switch("switch_clears_stale_index_lock", &repo, &["locked-index"]);
+    assert!(!lock.exists());
 }
 
 #[rstest]
@@ -1439,6 +1793,29 @@ fn test_switch_previous_branch_no_history(repo: TestRepo) {
     snapshot_switch("switch_previous_branch_no_history", &repo, &["-"]);
 }
 
+#[rstest]
+fn test_switch_list_row_shortcut(mut repo: TestRepo) {
+    repo.add_worktree("feature-a");
+
+    // Nothing listed yet in this session
+    snapshot_switch("switch_list_row_no_listing", &repo, &["%2"]);
+
+    // Row 1 is the current (main) worktree, row 2 is feature-a
+    let output = repo.wt_command().arg("list").output().unwrap();
+    assert!(output.status.success());
+    snapshot_switch("switch_list_row", &repo, &["%2"]);
+    snapshot_switch("switch_list_row_out_of_range", &repo, &["%9"]);
+
+    // `wt 2` is shorthand for `wt switch %2`
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(
+            "switch_list_row_bare_number",
+            make_snapshot_cmd(&repo, "2", &[], None)
+        );
+    });
+}
+
 #[rstest]
 fn test_switch_main_branch(repo: TestRepo) {
     // Create a feature branch (use unique name to avoid fixture conflicts)
@@ -2905,6 +3282,24 @@ fn test_switch_pr_same_repo(#[from(repo_with_remote)] mut repo: TestRepo) {
     });
 }
 
+/// Offline, `pr:N` fails before querying the forge.
+#[rstest]
+fn test_switch_pr_offline(#[from(repo_with_remote)] repo: TestRepo) {
+    repo.run_git(&[
+        "remote",
+        "set-url",
+        "origin",
+        "https://github.com/owner/test-repo.git",
+    ]);
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "switch",
+        &["pr:101", "--offline"],
+        None
+    ));
+}
+
 /// Test same-repo PR with a limited fetch refspec (single-branch clone scenario).
 ///
 /// In repos with a limited refspec (e.g., `+refs/heads/main:refs/remotes/origin/main`),
@@ -7052,6 +7447,32 @@ fn test_switch_format_json_already_at(mut repo: TestRepo) {
     assert_eq!(json["branch"], "already-json");
 }
 
+#[rstest]
+fn test_switch_format_json_error(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args([
+            "switch",
+            "missing-json",
+            "--no-cd",
+            "--yes",
+            "--format=json",
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(3));
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["error"]["code"], "branch-not-found");
+    assert_eq!(json["error"]["exit_code"], 3);
+    assert!(
+        json["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("missing-json")
+    );
+}
+
 #[rstest]
 fn test_switch_format_table_rejected_by_clap(repo: TestRepo) {
     let output = repo
@@ -7088,3 +7509,542 @@ cd = false
     // Without any cd flags, config should be respected (no cd directive)
     snapshot_switch("switch_no_cd_config_default", &repo, &["no-cd-config-test"]);
 }
+
+/// Mock `tmux` reporting `windows` from `list-windows`, and put `wt` inside it.
+fn tmux_window_cmd(repo: &TestRepo, args: &[&str], windows: &str) -> std::process::Command {
+    let mock_bin = repo.root_path().join("mock-bin");
+    fs::create_dir_all(&mock_bin).unwrap();
+    MockConfig::new("tmux")
+        .command("list-windows", MockResponse::output(windows))
+        .command("_default", MockResponse::exit(0))
+        .write(&mock_bin);
+
+    let mut cmd = make_snapshot_cmd(repo, "switch", args, None);
+    configure_mock_cli_env(&mut cmd, &mock_bin);
+    cmd.env("TMUX", "/tmp/tmux-1000/default,4242,0");
+    cmd.env_remove("ZELLIJ");
+    cmd
+}
+
+/// `--tmux-window` inside tmux opens a window named after the branch and
+/// leaves the current pane where it is.
+#[rstest]
+fn test_switch_tmux_window_opens_window(repo: TestRepo) {
+    let (cd_path, exec_path, _guard) = directive_files();
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        let mut cmd = tmux_window_cmd(
+            &repo,
+            &["--create", "feature", "--tmux-window"],
+            "@1\tzsh\n",
+        );
+        configure_directive_files(&mut cmd, &cd_path, &exec_path);
+        assert_cmd_snapshot!(cmd);
+    });
+    assert_eq!(fs::read_to_string(&cd_path).unwrap(), "");
+}
+
+/// A window already named after the branch is focused rather than duplicated.
+#[rstest]
+fn test_switch_tmux_window_focuses_existing(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    let settings = setup_snapshot_settings(&repo);
+    settings.bind(|| {
+        assert_cmd_snapshot!(tmux_window_cmd(
+            &repo,
+            &["feature", "--tmux-window"],
+            "@1\tzsh\n@3\tfeature\n",
+        ));
+    });
+}
+
+/// `[switch] tmux-window` outside a multiplexer switches as usual.
+#[rstest]
+fn test_switch_tmux_window_config_outside_multiplexer(repo: TestRepo) {
+    repo.write_test_config(
+        r#"worktree-path = "../{{ repo }}.{{ branch }}"
+
+[switch]
+tmux-window = true
+"#,
+    );
+    repo.run_git(&["branch", "feature"]);
+
+    let (cd_path, exec_path, _guard) = directive_files();
+    let mut cmd = repo.wt_command();
+    cmd.args(["switch", "feature"]);
+    cmd.env_remove("TMUX").env_remove("ZELLIJ");
+    configure_directive_files(&mut cmd, &cd_path, &exec_path);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "switch failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!fs::read_to_string(&cd_path).unwrap().is_empty());
+}
+
+/// A `--create` whose pre-start hook fails leaves a creation marker; the next
+/// switch to the branch warns (no terminal to prompt on), and `--yes` re-runs
+/// the hook and clears the marker.
+#[rstest]
+fn test_switch_create_interrupted_resumes(repo: TestRepo) {
+    repo.write_project_config(r#"pre-start = "test -f {{ repo_path }}/ready""#);
+    let marker = || {
+        repo.git_command()
+            .args(["config", "--get-regexp", r"^worktrunk\.state\..*\.create$"])
+            .run()
+            .unwrap()
+            .stdout
+    };
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "interrupted", "--yes"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(!marker().is_empty());
+
+    snapshot_switch("switch_create_interrupted_warns", &repo, &["interrupted"]);
+    assert!(!marker().is_empty());
+
+    fs::write(repo.root_path().join("ready"), "").unwrap();
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "interrupted", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("Running pre-start project hook"),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(marker().is_empty());
+}
+
+/// A partial worktree with work of its own since creation is never offered
+/// for forced removal: the hint drops `--force` and `-D`.
+#[rstest]
+fn test_switch_create_interrupted_with_commits_not_forced(repo: TestRepo) {
+    repo.write_project_config("pre-start = \"exit 1\"");
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "interrupted", "--yes"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let worktree = repo.root_path().parent().unwrap().join(format!(
+        "{}.interrupted",
+        repo.root_path().file_name().unwrap().to_string_lossy()
+    ));
+    fs::write(worktree.join("work.txt"), "work").unwrap();
+    repo.run_git_in(&worktree, &["add", "work.txt"]);
+    repo.run_git_in(&worktree, &["commit", "-m", "Work"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "interrupted"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("was interrupted"), "{stderr}");
+    assert!(!stderr.contains("--force"), "{stderr}");
+    assert!(!stderr.contains("-D"), "{stderr}");
+}
+
+/// A failed `git worktree add` clears the creation marker, so whatever is at
+/// the path later isn't taken for a partial worktree.
+#[rstest]
+fn test_switch_create_failed_add_clears_marker(repo: TestRepo) {
+    let blocker = repo.root_path().parent().unwrap().join("blocker");
+    fs::write(&blocker, "not a directory").unwrap();
+    repo.write_test_config(r#"worktree-path = "../blocker/{{ branch }}""#);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature", "--yes"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let marker = repo
+        .git_command()
+        .args(["config", "--get-regexp", r"^worktrunk\.state\..*\.create$"])
+        .run()
+        .unwrap()
+        .stdout;
+    assert!(marker.is_empty(), "{}", String::from_utf8_lossy(&marker));
+}
+
+/// `[worktree-git-config]` lands in the new worktree's own config only.
+#[rstest]
+fn test_switch_create_applies_worktree_git_config(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[worktree-git-config]
+user.email = "bot@corp.com"
+commit.gpgsign = false
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "agent", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let worktree = repo.root_path().parent().unwrap().join(format!(
+        "{}.agent",
+        repo.root_path().file_name().unwrap().to_string_lossy()
+    ));
+    let config_in = |dir: &Path, key: &str| {
+        let output = repo
+            .git_command()
+            .args(["config", "--get", key])
+            .current_dir(dir)
+            .run()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    };
+    assert_eq!(config_in(&worktree, "user.email"), "bot@corp.com");
+    assert_eq!(config_in(&worktree, "commit.gpgsign"), "false");
+    assert_ne!(config_in(repo.root_path(), "user.email"), "bot@corp.com");
+}
+
+/// `[scaffold]` files are expanded into the new worktree before pre-start
+/// hooks run; a destination outside the worktree is refused.
+#[rstest]
+fn test_switch_create_scaffolds_files(repo: TestRepo) {
+    repo.write_project_config(
+        r#"pre-start = "test -f .env && test -f .vscode/settings.json"
+
+[scaffold]
+".env" = ".env.example"
+".vscode/settings.json" = "{{ primary_worktree_path }}/settings.template.json"
+"../escape" = ".env.example"
+"#,
+    );
+    fs::write(
+        repo.root_path().join(".env.example"),
+        "BRANCH={{ branch }}\n",
+    )
+    .unwrap();
+    repo.commit("Add scaffold templates");
+    // Untracked, so only reachable through the primary worktree
+    fs::write(
+        repo.root_path().join("settings.template.json"),
+        "{\"title\": \"{{ branch | sanitize }}\"}\n",
+    )
+    .unwrap();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "switch",
+        &["--create", "feature/scaffold", "--yes"],
+        None
+    ));
+
+    let worktree = repo.root_path().parent().unwrap().join(format!(
+        "{}.feature-scaffold",
+        repo.root_path().file_name().unwrap().to_string_lossy()
+    ));
+    assert_eq!(
+        fs::read_to_string(worktree.join(".env")).unwrap(),
+        "BRANCH=feature/scaffold\n"
+    );
+    assert_eq!(
+        fs::read_to_string(worktree.join(".vscode/settings.json")).unwrap(),
+        "{\"title\": \"feature-scaffold\"}\n"
+    );
+    assert!(!worktree.parent().unwrap().join("escape").exists());
+}
+
+/// `[submodules] follow` checks followed submodules out on the worktree's
+/// branch when creating it, and again when switching back to it.
+#[rstest]
+fn test_switch_follows_submodules(repo: TestRepo) {
+    add_submodule(&repo, "libs/core");
+    repo.write_project_config(
+        r#"[submodules]
+follow = ["libs/core"]
+"#,
+    );
+    repo.commit("Follow libs/core");
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "switch",
+        &["--create", "feature"],
+        None
+    ));
+
+    let submodule = repo.root_path().parent().unwrap().join(format!(
+        "{}.feature/libs/core",
+        repo.root_path().file_name().unwrap().to_string_lossy()
+    ));
+    let submodule_branch = || {
+        let output = repo
+            .git_command()
+            .current_dir(&submodule)
+            .args(["branch", "--show-current"])
+            .run()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    };
+    assert_eq!(submodule_branch(), "feature");
+
+    repo.run_git_in(&submodule, &["checkout", "--detach"]);
+    let output = repo
+        .wt_command()
+        .args(["switch", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(submodule_branch(), "feature");
+}
+
+// -- Batch creation --
+
+#[rstest]
+fn test_switch_batch(repo: TestRepo) {
+    repo.write_project_config(
+        r#"[pre-start]
+mark = "echo {{ branch }} > marker"
+"#,
+    );
+    repo.commit("Add pre-start hook");
+    let manifest = repo.root_path().join("batch.toml");
+    fs::write(
+        &manifest,
+        r#"[[worktree]]
+branch = "attempt-{{ n }}"
+count = 2
+
+[[worktree]]
+branch = "docs"
+base = "main"
+"#,
+    )
+    .unwrap();
+
+    snapshot_switch(
+        "switch_batch",
+        &repo,
+        &["--batch", "batch.toml", "--jobs", "1", "--yes"],
+    );
+
+    for branch in ["attempt-1", "attempt-2", "docs"] {
+        let marker = repo
+            .root_path()
+            .parent()
+            .unwrap()
+            .join(format!("repo.{branch}/marker"));
+        assert_eq!(fs::read_to_string(marker).unwrap().trim(), branch);
+    }
+}
+
+#[rstest]
+fn test_switch_batch_json_stdin_reports_hook_failures(repo: TestRepo) {
+    use std::io::Write as _;
+    use std::process::Stdio;
+
+    repo.write_project_config(
+        r#"[pre-start]
+check = "test {{ branch }} != spike-2"
+"#,
+    );
+    repo.commit("Add pre-start hook");
+
+    let mut cmd = repo.wt_command();
+    cmd.args(["switch", "--batch", "-", "--yes", "--format=json"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let mut child = cmd.spawn().unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(br#"{"worktree": [{"branch": "spike-{{ n }}", "count": 3}]}"#)
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(
+        output.status.code(),
+        Some(1),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let hooks: Vec<(&str, &str)> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| {
+            (
+                row["branch"].as_str().unwrap(),
+                row["hooks"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        hooks,
+        [("spike-1", "ok"), ("spike-2", "failed"), ("spike-3", "ok")]
+    );
+    // A failed hook leaves its worktree in place
+    assert!(
+        repo.root_path()
+            .parent()
+            .unwrap()
+            .join("repo.spike-2")
+            .exists()
+    );
+}
+
+#[rstest]
+fn test_switch_batch_conflict_creates_nothing(mut repo: TestRepo) {
+    repo.add_worktree("docs");
+    let manifest = repo.root_path().join("batch.toml");
+    fs::write(
+        &manifest,
+        r#"[[worktree]]
+branch = "first"
+
+[[worktree]]
+branch = "docs"
+"#,
+    )
+    .unwrap();
+
+    snapshot_switch(
+        "switch_batch_conflict",
+        &repo,
+        &["--batch", "batch.toml", "--yes"],
+    );
+
+    assert!(
+        !repo
+            .root_path()
+            .parent()
+            .unwrap()
+            .join("repo.first")
+            .exists()
+    );
+    let output = repo
+        .git_command()
+        .args(["branch", "--list", "first"])
+        .run()
+        .unwrap();
+    assert!(output.stdout.is_empty());
+}
+
+#[rstest]
+fn test_switch_batch_duplicate_branch(repo: TestRepo) {
+    let manifest = repo.root_path().join("batch.toml");
+    fs::write(&manifest, "[[worktree]]\nbranch = \"same\"\ncount = 2\n").unwrap();
+
+    snapshot_switch(
+        "switch_batch_duplicate_branch",
+        &repo,
+        &["--batch", "batch.toml"],
+    );
+}
+
+// ============================================================================
+// jj revsets (colocated jj repos)
+// ============================================================================
+
+/// Make the repo root a colocated jj workspace whose mock `jj` resolves
+/// `revset` to `line` (`commit_id change_id bookmark...`). Returns the mock bin dir.
+fn setup_mock_jj_revset(repo: &TestRepo, revset: &str, line: &str) -> std::path::PathBuf {
+    fs::create_dir_all(repo.root_path().join(".jj")).unwrap();
+    let mock_bin = repo.home_path().join("mock-bin");
+    fs::create_dir_all(&mock_bin).unwrap();
+    MockConfig::new("jj")
+        .command(&format!("log -r {revset}"), MockResponse::output(line))
+        .write(&mock_bin);
+    mock_bin
+}
+
+#[rstest]
+fn test_switch_jj_revset_without_bookmark(repo: TestRepo) {
+    let sha = repo.head_sha();
+    let revset = r#"description(glob:"fix*")"#;
+    let mock_bin = setup_mock_jj_revset(&repo, revset, &format!("{sha} kntqzsqtsvww \n"));
+
+    let mut cmd = repo.wt_command();
+    cmd.args(["switch", revset]);
+    configure_mock_cli_env(&mut cmd, &mock_bin);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // A branch named after the change, at the revision's commit
+    assert_eq!(
+        repo.git_output(&["rev-parse", "refs/heads/kntqzsqtsvww"]),
+        sha
+    );
+    let path = repo.root_path().parent().unwrap().join("repo.kntqzsqtsvww");
+    assert!(path.is_dir(), "worktree not created at {}", path.display());
+}
+
+#[rstest]
+fn test_switch_jj_change_id_with_bookmark(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+    let sha = repo.head_sha();
+    let mock_bin = setup_mock_jj_revset(&repo, "kntq", &format!("{sha} kntqzsqtsvww feature\n"));
+
+    let mut cmd = repo.wt_command();
+    cmd.args(["switch", "kntq"]);
+    configure_mock_cli_env(&mut cmd, &mock_bin);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // The revision's bookmark is switched to; no branch is created
+    assert!(
+        repo.root_path()
+            .parent()
+            .unwrap()
+            .join("repo.feature")
+            .is_dir()
+    );
+    assert!(
+        repo.git_output(&["branch", "--list", "kntqzsqtsvww"])
+            .is_empty()
+    );
+}
+
+#[rstest]
+fn test_switch_create_jj_base(repo: TestRepo) {
+    let sha = repo.head_sha();
+    repo.commit("second");
+    let mock_bin = setup_mock_jj_revset(&repo, "@-", &format!("{sha} kntqzsqtsvww \n"));
+
+    let mut cmd = repo.wt_command();
+    cmd.args(["switch", "--create", "fix", "--base", "@-"]);
+    configure_mock_cli_env(&mut cmd, &mock_bin);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(repo.git_output(&["rev-parse", "refs/heads/fix"]), sha);
+}
@@ -91,6 +91,78 @@ approved-commands = ["echo 'PROJECT_HOOK' >> hook_order.txt"]
     assert_eq!(lines[1], "PROJECT_HOOK", "Project hook should run second");
 }
 
+#[rstest]
+fn test_user_pre_start_when_changed(repo: TestRepo) {
+    fs::write(repo.root_path().join("Cargo.lock"), "v1\n").unwrap();
+    repo.commit("Add lockfile");
+    repo.run_git(&["checkout", "-b", "bumped"]);
+    fs::write(repo.root_path().join("Cargo.lock"), "v2\n").unwrap();
+    repo.commit("Bump lockfile");
+    repo.run_git(&["checkout", "main"]);
+
+    repo.write_test_config(
+        r#"[pre-start]
+install = { command = "echo installed > install_marker.txt", when-changed = ["Cargo.lock"] }
+log = "echo logged > log_marker.txt"
+"#,
+    );
+
+    // Lockfile matches the base → install is skipped, other commands still run
+    snapshot_switch(
+        "user_pre_start_when_changed",
+        &repo,
+        &["--create", "feature"],
+    );
+    let feature_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(feature_path.join("log_marker.txt").exists());
+    assert!(!feature_path.join("install_marker.txt").exists());
+
+    // Lockfile differs from the default branch → install runs
+    repo.wt_command()
+        .args(["switch", "bumped", "--yes"])
+        .output()
+        .unwrap();
+    let bumped_path = repo.root_path().parent().unwrap().join("repo.bumped");
+    assert!(bumped_path.join("install_marker.txt").exists());
+}
+
+#[rstest]
+fn test_user_hooks_cwd(repo: TestRepo) {
+    fs::create_dir_all(repo.root_path().join("infra")).unwrap();
+    fs::write(repo.root_path().join("infra/README"), "infra\n").unwrap();
+    repo.commit("Add infra");
+
+    repo.write_test_config(
+        r#"[pre-start]
+primary = { command = "echo primary > primary_marker.txt", cwd = "{{ repo_path }}" }
+infra = { command = "echo infra > infra_marker.txt", cwd = "infra" }
+
+[post-start]
+background = { command = "echo background > background_marker.txt", cwd = "infra" }
+"#,
+    );
+
+    snapshot_switch("user_hooks_cwd", &repo, &["--create", "feature"]);
+    let feature_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(repo.root_path().join("primary_marker.txt").exists());
+    assert!(feature_path.join("infra/infra_marker.txt").exists());
+    assert!(!feature_path.join("primary_marker.txt").exists());
+    crate::common::wait_for_file(&feature_path.join("infra/background_marker.txt"));
+}
+
+#[rstest]
+fn test_user_hooks_cwd_missing(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[pre-start]
+deploy = { command = "echo deployed > deploy_marker.txt", cwd = "infra/{{ branch }}" }
+"#,
+    );
+
+    snapshot_switch("user_hooks_cwd_missing", &repo, &["--create", "feature"]);
+    let feature_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(!feature_path.join("deploy_marker.txt").exists());
+}
+
 #[rstest]
 fn test_user_hooks_no_approval_required(repo: TestRepo) {
     // Write user config with hook but NO pre-approved commands
@@ -1125,7 +1197,7 @@ broken = "echo {{ does_not_exist }} > should_not_exist.txt"
     assert_snapshot!(log_content, @"
     [31m✗[39m [31mFailed to expand user:broken: undefined value @ line 1[39m
     [107m [0m echo {{ does_not_exist }} > should_not_exist.txt
-    [2m↳[22m [2mAvailable variables: [4margs[24m, [4mbase[24m, [4mbase_worktree_path[24m, [4mbranch[24m, [4mcommit[24m, [4mcwd[24m, [4mdefault_branch[24m, [4mhook_name[24m, [4mhook_type[24m, [4mmain_worktree[24m, [4mmain_worktree_path[24m, [4mprimary_worktree_path[24m, [4mremote[24m, [4mremote_url[24m, [4mrepo[24m, [4mrepo_path[24m, [4mrepo_root[24m, [4mshort_commit[24m, [4mtarget[24m, [4mtarget_worktree_path[24m, [4mupstream[24m, [4mworktree[24m, [4mworktree_name[24m, [4mworktree_path[24m[22m
+    [2m↳[22m [2mAvailable variables: [4margs[24m, [4mbase[24m, [4mbase_worktree_path[24m, [4mbookmark[24m, [4mbranch[24m, [4mchange_id[24m, [4mcommit[24m, [4mcwd[24m, [4mdefault_branch[24m, [4mhook_name[24m, [4mhook_type[24m, [4mmain_worktree[24m, [4mmain_worktree_path[24m, [4mprimary_worktree_path[24m, [4mremote[24m, [4mremote_url[24m, [4mrepo[24m, [4mrepo_path[24m, [4mrepo_root[24m, [4mshort_commit[24m, [4mtarget[24m, [4mtarget_worktree_path[24m, [4mupstream[24m, [4mworkspace[24m, [4mworktree[24m, [4mworktree_name[24m, [4mworktree_path[24m[22m
     ");
 
     // The step never ran.
@@ -2027,6 +2099,85 @@ fn test_hook_dry_run_named_hooks(repo: TestRepo) {
     ));
 }
 
+// ============================================================================
+// --all Tests
+// ============================================================================
+
+/// --all runs the hook once per worktree, each with its own branch
+#[rstest]
+fn test_hook_all_runs_in_every_worktree(repo: TestRepo) {
+    repo.write_project_config(r#"pre-merge = "echo branch={{ branch }}""#);
+
+    let settings = setup_snapshot_settings(&repo);
+    let _guard = settings.bind_to_scope();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "hook",
+        &["pre-merge", "--all", "--yes"],
+        None,
+    ));
+}
+
+/// A failure in one worktree doesn't stop the others; the summary lists it
+#[rstest]
+fn test_hook_all_continues_past_failure(repo: TestRepo) {
+    repo.write_project_config(r#"pre-merge = "test {{ branch }} != feature-b""#);
+
+    let settings = setup_snapshot_settings(&repo);
+    let _guard = settings.bind_to_scope();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "hook",
+        &["pre-merge", "--all", "--yes"],
+        None,
+    ));
+}
+
+/// --all with --dry-run labels each preview with its worktree
+#[rstest]
+fn test_hook_all_dry_run(repo: TestRepo) {
+    repo.write_project_config(r#"post-start = {install = "echo install {{ branch }}"}"#);
+
+    let settings = setup_snapshot_settings(&repo);
+    let _guard = settings.bind_to_scope();
+
+    assert_cmd_snapshot!(make_snapshot_cmd(
+        &repo,
+        "hook",
+        &["post-start", "install", "--all", "--dry-run"],
+        None,
+    ));
+}
+
+/// Background hooks spawn in every worktree
+#[rstest]
+fn test_hook_all_background(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    repo.write_project_config(r#"post-start = "echo {{ branch }} > all_marker.txt""#);
+
+    let mut cmd = crate::common::wt_command();
+    cmd.current_dir(repo.root_path());
+    cmd.env("WORKTRUNK_CONFIG_PATH", repo.test_config_path());
+    cmd.args(["hook", "post-start", "--all", "--yes"]);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    for (path, branch) in [
+        (repo.root_path().to_path_buf(), "main"),
+        (feature_path, "feature"),
+    ] {
+        let marker = path.join("all_marker.txt");
+        wait_for_file_content(&marker);
+        assert_eq!(fs::read_to_string(&marker).unwrap().trim(), branch);
+    }
+}
+
 // ============================================================================
 // Background Hook Execution Tests (post-start, post-switch)
 // ============================================================================
@@ -3898,3 +4049,45 @@ lint = "cargo clippy"
         });
     });
 }
+
+#[rstest]
+fn test_sandbox_clear_env_applies_to_project_hooks_only(repo: TestRepo) {
+    // `[sandbox] clear-env` strips the environment from project hooks, in the
+    // foreground (pre-merge) and in the background runner (post-start); the
+    // user's own hooks keep it
+    repo.write_project_config(
+        r#"pre-merge = "echo ${SANDBOX_SECRET-stripped} > project_fg.txt"
+post-start = "echo ${SANDBOX_SECRET-stripped} > project_bg.txt"
+"#,
+    );
+    repo.write_test_config(
+        r#"pre-merge = "echo ${SANDBOX_SECRET-stripped} > user_fg.txt"
+
+[sandbox]
+clear-env = true
+"#,
+    );
+
+    for hook in ["pre-merge", "post-start"] {
+        let output = repo
+            .wt_command()
+            .env("SANDBOX_SECRET", "leaked")
+            .args(["hook", hook, "--yes"])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{hook} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let read = |name: &str| {
+        let path = repo.root_path().join(name);
+        crate::common::wait_for_file_content(&path);
+        fs::read_to_string(path).unwrap()
+    };
+    assert_eq!(read("project_fg.txt").trim(), "stripped");
+    assert_eq!(read("project_bg.txt").trim(), "stripped");
+    assert_eq!(read("user_fg.txt").trim(), "leaked");
+}
@@ -0,0 +1,102 @@
+//! Commands resolve the same worktree whatever the CWD depth.
+//!
+//! `wt` run from a nested subdirectory, from `.git/`, or from deeper inside
+//! the git dir acts as if run from the owning worktree's root.
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+use std::path::Path;
+
+/// Run `wt` in `dir`, returning (success, stdout, stderr).
+fn run_in(repo: &TestRepo, dir: &Path, args: &[&str]) -> (bool, String, String) {
+    let output = repo
+        .wt_command()
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+#[rstest]
+#[case::nested_subdirectory("src/nested/deep")]
+#[case::git_dir(".git")]
+#[case::inside_git_dir(".git/refs/heads")]
+fn test_commands_match_worktree_root(mut repo: TestRepo, #[case] location: &str) {
+    repo.add_worktree("feature");
+    repo.write_project_config("[pre-start]\nsetup = \"echo setup\"\n");
+    let dir = repo.root_path().join(location);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    for args in [
+        &["list", "--format=json"][..],
+        &["step", "eval", "{{ branch }} {{ worktree_path }}"],
+        &["hook", "show"],
+        &["config", "show"],
+    ] {
+        assert_eq!(
+            run_in(&repo, &dir, args),
+            run_in(&repo, repo.root_path(), args),
+            "`wt {}` from {location} differs from the worktree root",
+            args.join(" ")
+        );
+    }
+}
+
+#[rstest]
+fn test_linked_worktree_git_dir(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let git_dir = repo.root_path().join(".git/worktrees/repo.feature");
+    assert!(git_dir.is_dir(), "{}", git_dir.display());
+
+    let args = ["step", "eval", "{{ branch }} {{ worktree_path }}"];
+    assert_eq!(
+        run_in(&repo, &git_dir, &args),
+        run_in(&repo, &feature, &args)
+    );
+}
+
+/// Switching to the current worktree from a subdirectory moves to its root
+/// without recording a switch, so `wt switch -` still returns to the
+/// previous worktree.
+#[rstest]
+#[case::nested_subdirectory("src/nested")]
+#[case::git_dir(".git/worktrees/repo.feature")]
+fn test_switch_current_keeps_previous(mut repo: TestRepo, #[case] location: &str) {
+    let feature = repo.add_worktree("feature");
+    // The linked worktree's git dir lives under the main worktree's `.git`.
+    let dir = if location.starts_with(".git") {
+        repo.root_path().join(location)
+    } else {
+        feature.join(location)
+    };
+    std::fs::create_dir_all(&dir).unwrap();
+
+    // Records `main` as the previous worktree.
+    let (ok, _, _) = run_in(&repo, repo.root_path(), &["switch", "feature"]);
+    assert!(ok);
+    let (ok, _, stderr) = run_in(&repo, &dir, &["switch", "feature"]);
+    assert!(ok, "{stderr}");
+
+    let (ok, _, stderr) = run_in(&repo, &feature, &["switch", "-"]);
+    assert!(ok, "{stderr}");
+    assert!(stderr.contains("main"), "{stderr}");
+}
+
+#[rstest]
+#[case::root("")]
+#[case::nested_subdirectory("src/nested")]
+#[case::git_dir(".git")]
+fn test_remove_from_location(mut repo: TestRepo, #[case] location: &str) {
+    let feature = repo.add_worktree("feature");
+    let dir = repo.root_path().join(location);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let (ok, _, stderr) = run_in(&repo, &dir, &["remove", "feature", "--foreground"]);
+    assert!(ok, "{stderr}");
+    assert!(!feature.exists());
+}